@@ -1,4 +1,4 @@
--- migrations/YYYY-MM-DD-HHMMSS_create_attributes_table/down.sql
+-- migrations/2026-10-17-221510_create_attributes_table/down.sql
 
 -- Eliminar el trigger si se creó
 -- DROP TRIGGER IF EXISTS update_attributes_updated_at ON attributes;
@@ -1,4 +1,4 @@
--- migrations/YYYY-MM-DD-HHMMSS_create_attributes_table/up.sql
+-- migrations/2026-10-17-221510_create_attributes_table/up.sql
 
 CREATE TABLE attributes (
     id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
@@ -0,0 +1,4 @@
+-- migrations/2026-10-17-231629_create_tuplas_and_attribute_values/down.sql
+
+DROP TABLE IF EXISTS attribute_values;
+DROP TABLE IF EXISTS tuplas;
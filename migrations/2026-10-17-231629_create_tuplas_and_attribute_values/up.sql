@@ -0,0 +1,41 @@
+-- migrations/2026-10-17-231629_create_tuplas_and_attribute_values/up.sql
+
+-- Instancias (registros) de una entidad lógica
+CREATE TABLE tuplas (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    entity_id UUID NOT NULL REFERENCES logical_entities(id) ON DELETE CASCADE,
+    created_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    updated_at TIMESTAMPTZ,
+    status SMALLINT NOT NULL DEFAULT 1
+);
+
+CREATE INDEX idx_tuplas_entity_id ON tuplas(entity_id);
+
+-- Valores de los atributos de cada instancia (EAV).
+-- Solo se rellena la columna correspondiente al tipo de dato del atributo.
+CREATE TABLE attribute_values (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    instance_id UUID NOT NULL REFERENCES tuplas(id) ON DELETE CASCADE,
+    attribute_id UUID NOT NULL REFERENCES attributes(id) ON DELETE CASCADE,
+    string_value TEXT,
+    text_value TEXT,
+    integer_value BIGINT,
+    float_value DOUBLE PRECISION,
+    numeric_value NUMERIC,
+    boolean_value BOOLEAN,
+    datetime_value TIMESTAMPTZ,
+    date_value DATE,
+    time_value TIME,
+    uuid_value UUID,
+    json_value JSONB,
+    binary_value BYTEA,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ,
+
+    -- Un único valor por atributo e instancia
+    CONSTRAINT unique_instance_attribute UNIQUE (instance_id, attribute_id)
+);
+
+CREATE INDEX idx_attribute_values_attribute_id ON attribute_values(attribute_id);
@@ -0,0 +1,5 @@
+-- migrations/2026-10-17-234355_create_entity_schema_versions/down.sql
+
+DROP TRIGGER IF EXISTS entity_schema_versions_immutable ON entity_schema_versions;
+DROP FUNCTION IF EXISTS prevent_schema_version_update();
+DROP TABLE IF EXISTS entity_schema_versions;
@@ -0,0 +1,30 @@
+-- migrations/2026-10-17-234355_create_entity_schema_versions/up.sql
+
+-- Instantáneas inmutables y numeradas de la definición (atributos) de una entidad lógica.
+-- `definition` guarda el nombre de la entidad y, por cada atributo, nombre, tipo de dato,
+-- posición, obligatoriedad, grupo de unicidad, expresión regular, valor por defecto y estado.
+CREATE TABLE entity_schema_versions (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    entity_id UUID NOT NULL REFERENCES logical_entities(id) ON DELETE CASCADE,
+    version INTEGER NOT NULL,
+    definition JSONB NOT NULL,
+    change_summary TEXT,
+    created_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT unique_entity_schema_version UNIQUE (entity_id, version)
+);
+
+CREATE INDEX idx_entity_schema_versions_created_at ON entity_schema_versions(entity_id, created_at);
+
+-- Las versiones no se modifican una vez creadas
+CREATE OR REPLACE FUNCTION prevent_schema_version_update()
+RETURNS TRIGGER AS $$
+BEGIN
+    RAISE EXCEPTION 'entity_schema_versions es de solo inserción';
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER entity_schema_versions_immutable
+BEFORE UPDATE ON entity_schema_versions
+FOR EACH ROW EXECUTE FUNCTION prevent_schema_version_update();
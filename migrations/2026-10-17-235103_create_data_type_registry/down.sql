@@ -0,0 +1,16 @@
+-- migrations/2026-10-17-235103_create_data_type_registry/down.sql
+
+DROP INDEX IF EXISTS idx_data_types_name_lower;
+
+ALTER TABLE data_types
+    DROP CONSTRAINT IF EXISTS data_types_min_max_check,
+    DROP CONSTRAINT IF EXISTS data_types_numeric_scale_check,
+    DROP CONSTRAINT IF EXISTS data_types_max_length_check,
+    DROP CONSTRAINT IF EXISTS data_types_storage_column_check,
+    DROP COLUMN IF EXISTS max_value,
+    DROP COLUMN IF EXISTS min_value,
+    DROP COLUMN IF EXISTS numeric_scale,
+    DROP COLUMN IF EXISTS numeric_precision,
+    DROP COLUMN IF EXISTS max_length,
+    DROP COLUMN IF EXISTS pg_cast,
+    DROP COLUMN IF EXISTS storage_column;
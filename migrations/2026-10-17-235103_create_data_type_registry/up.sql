@@ -0,0 +1,90 @@
+-- migrations/2026-10-17-235103_create_data_type_registry/up.sql
+
+-- Registro de tipos de datos: cada tipo declara la columna de `attribute_values`
+-- donde se almacenan sus valores, el cast de Postgres usado en las vistas y
+-- parámetros opcionales (longitud máxima, precisión/escala, mínimo/máximo).
+ALTER TABLE data_types
+    ADD COLUMN IF NOT EXISTS description TEXT,
+    ADD COLUMN IF NOT EXISTS storage_column TEXT,
+    ADD COLUMN IF NOT EXISTS pg_cast TEXT,
+    ADD COLUMN IF NOT EXISTS max_length INTEGER,
+    ADD COLUMN IF NOT EXISTS numeric_precision SMALLINT,
+    ADD COLUMN IF NOT EXISTS numeric_scale SMALLINT,
+    ADD COLUMN IF NOT EXISTS min_value DOUBLE PRECISION,
+    ADD COLUMN IF NOT EXISTS max_value DOUBLE PRECISION,
+    ADD COLUMN IF NOT EXISTS created_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    ADD COLUMN IF NOT EXISTS created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    ADD COLUMN IF NOT EXISTS updated_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    ADD COLUMN IF NOT EXISTS updated_at TIMESTAMPTZ,
+    ADD COLUMN IF NOT EXISTS status SMALLINT NOT NULL DEFAULT 1;
+
+-- Tipos existentes: se asigna la columna según el nombre (el mapeo que antes estaba en el código)
+UPDATE data_types SET storage_column = CASE LOWER(name)
+        WHEN 'string' THEN 'string_value' WHEN 'varchar' THEN 'string_value' WHEN 'char' THEN 'string_value'
+        WHEN 'text' THEN 'text_value'
+        WHEN 'integer' THEN 'integer_value' WHEN 'int' THEN 'integer_value'
+        WHEN 'bigint' THEN 'integer_value' WHEN 'smallint' THEN 'integer_value'
+        WHEN 'float' THEN 'float_value' WHEN 'double' THEN 'float_value' WHEN 'real' THEN 'float_value'
+        WHEN 'numeric' THEN 'numeric_value' WHEN 'decimal' THEN 'numeric_value'
+        WHEN 'boolean' THEN 'boolean_value' WHEN 'bool' THEN 'boolean_value'
+        WHEN 'datetime' THEN 'datetime_value' WHEN 'timestamp' THEN 'datetime_value'
+        WHEN 'timestamptz' THEN 'datetime_value'
+        WHEN 'date' THEN 'date_value'
+        WHEN 'time' THEN 'time_value'
+        WHEN 'uuid' THEN 'uuid_value'
+        WHEN 'json' THEN 'json_value' WHEN 'jsonb' THEN 'json_value'
+        WHEN 'binary' THEN 'binary_value' WHEN 'bytea' THEN 'binary_value'
+        WHEN 'imagen' THEN 'binary_value' WHEN 'image' THEN 'binary_value'
+        ELSE 'text_value'
+    END
+WHERE storage_column IS NULL;
+
+UPDATE data_types SET pg_cast = CASE storage_column
+        WHEN 'string_value' THEN 'text'
+        WHEN 'text_value' THEN 'text'
+        WHEN 'integer_value' THEN 'bigint'
+        WHEN 'float_value' THEN 'double precision'
+        WHEN 'numeric_value' THEN 'numeric'
+        WHEN 'boolean_value' THEN 'boolean'
+        WHEN 'datetime_value' THEN 'timestamptz'
+        WHEN 'date_value' THEN 'date'
+        WHEN 'time_value' THEN 'time'
+        WHEN 'uuid_value' THEN 'uuid'
+        WHEN 'json_value' THEN 'jsonb'
+        WHEN 'binary_value' THEN 'bytea'
+    END
+WHERE pg_cast IS NULL;
+
+ALTER TABLE data_types
+    ALTER COLUMN storage_column SET NOT NULL,
+    ALTER COLUMN pg_cast SET NOT NULL,
+    ADD CONSTRAINT data_types_storage_column_check CHECK (storage_column IN (
+        'string_value', 'text_value', 'integer_value', 'float_value', 'numeric_value', 'boolean_value',
+        'datetime_value', 'date_value', 'time_value', 'uuid_value', 'json_value', 'binary_value'
+    )),
+    ADD CONSTRAINT data_types_max_length_check CHECK (max_length IS NULL OR max_length > 0),
+    ADD CONSTRAINT data_types_numeric_scale_check CHECK (
+        numeric_scale IS NULL OR (numeric_precision IS NOT NULL AND numeric_scale BETWEEN 0 AND numeric_precision)
+    ),
+    ADD CONSTRAINT data_types_min_max_check CHECK (min_value IS NULL OR max_value IS NULL OR min_value <= max_value);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_data_types_name_lower ON data_types (LOWER(name));
+
+-- Tipos base del registro
+INSERT INTO data_types (name, description, storage_column, pg_cast)
+SELECT v.name, v.description, v.storage_column, v.pg_cast
+FROM (VALUES
+    ('string', 'Texto corto', 'string_value', 'text'),
+    ('text', 'Texto largo', 'text_value', 'text'),
+    ('integer', 'Número entero', 'integer_value', 'bigint'),
+    ('float', 'Número de coma flotante', 'float_value', 'double precision'),
+    ('numeric', 'Número decimal exacto', 'numeric_value', 'numeric'),
+    ('boolean', 'Verdadero o falso', 'boolean_value', 'boolean'),
+    ('datetime', 'Fecha y hora (RFC 3339)', 'datetime_value', 'timestamptz'),
+    ('date', 'Fecha (YYYY-MM-DD)', 'date_value', 'date'),
+    ('time', 'Hora (HH:MM:SS)', 'time_value', 'time'),
+    ('uuid', 'Identificador UUID', 'uuid_value', 'uuid'),
+    ('json', 'Documento JSON', 'json_value', 'jsonb'),
+    ('binary', 'Contenido binario (base64)', 'binary_value', 'bytea')
+) AS v(name, description, storage_column, pg_cast)
+WHERE NOT EXISTS (SELECT 1 FROM data_types dt WHERE LOWER(dt.name) = v.name);
@@ -0,0 +1,14 @@
+-- migrations/2026-10-18-000510_create_attribute_options/down.sql
+
+DROP TABLE IF EXISTS attribute_options;
+
+ALTER TABLE attributes
+    DROP COLUMN IF EXISTS show_option_label;
+
+-- Los atributos que usan tipos de enumeración conservan sus códigos como texto
+UPDATE data_types SET kind = 'scalar' WHERE kind = 'enumeration';
+
+ALTER TABLE data_types
+    DROP CONSTRAINT IF EXISTS data_types_enumeration_storage_check,
+    DROP CONSTRAINT IF EXISTS data_types_kind_check,
+    DROP COLUMN IF EXISTS kind;
@@ -0,0 +1,36 @@
+-- migrations/2026-10-18-000510_create_attribute_options/up.sql
+
+-- Tipos de enumeración: sus valores deben ser códigos de la lista de opciones
+-- del atributo. Los códigos se almacenan como texto.
+ALTER TABLE data_types
+    ADD COLUMN kind TEXT NOT NULL DEFAULT 'scalar',
+    ADD CONSTRAINT data_types_kind_check CHECK (kind IN ('scalar', 'enumeration')),
+    ADD CONSTRAINT data_types_enumeration_storage_check CHECK (
+        kind <> 'enumeration' OR storage_column IN ('string_value', 'text_value')
+    );
+
+-- Las vistas pueden exponer, además del código, la etiqueta de la opción (`<atributo>_label`)
+ALTER TABLE attributes
+    ADD COLUMN show_option_label BOOLEAN NOT NULL DEFAULT FALSE;
+
+-- Lista de opciones de un atributo de tipo enumeración
+CREATE TABLE attribute_options (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    attribute_id UUID NOT NULL REFERENCES attributes(id) ON DELETE CASCADE,
+    code TEXT NOT NULL,
+    label TEXT NOT NULL,
+    sort_order INTEGER NOT NULL DEFAULT 0,
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ,
+
+    CONSTRAINT unique_attribute_option_code UNIQUE (attribute_id, code),
+    CONSTRAINT attribute_options_code_check CHECK (LENGTH(TRIM(code)) > 0)
+);
+
+CREATE INDEX idx_attribute_options_sort ON attribute_options(attribute_id, sort_order);
+
+-- Tipo base de enumeración
+INSERT INTO data_types (name, description, storage_column, pg_cast, kind)
+SELECT 'enum', 'Lista de opciones (código y etiqueta)', 'string_value', 'text', 'enumeration'
+WHERE NOT EXISTS (SELECT 1 FROM data_types dt WHERE LOWER(dt.name) = 'enum');
@@ -0,0 +1,19 @@
+-- migrations/2026-10-18-001439_create_attribute_references/down.sql
+
+DROP INDEX IF EXISTS idx_attribute_values_uuid_value;
+DROP INDEX IF EXISTS idx_attributes_reference_entity_id;
+
+ALTER TABLE attributes
+    DROP CONSTRAINT IF EXISTS attributes_reference_complete_check,
+    DROP CONSTRAINT IF EXISTS attributes_reference_on_delete_check,
+    DROP COLUMN IF EXISTS reference_display_attribute_id,
+    DROP COLUMN IF EXISTS reference_on_delete,
+    DROP COLUMN IF EXISTS reference_entity_id;
+
+-- Los atributos que usan tipos de referencia conservan sus valores como UUID
+UPDATE data_types SET kind = 'scalar' WHERE kind = 'reference';
+
+ALTER TABLE data_types
+    DROP CONSTRAINT IF EXISTS data_types_reference_storage_check,
+    DROP CONSTRAINT IF EXISTS data_types_kind_check,
+    ADD CONSTRAINT data_types_kind_check CHECK (kind IN ('scalar', 'enumeration'));
@@ -0,0 +1,35 @@
+-- migrations/2026-10-18-001439_create_attribute_references/up.sql
+
+-- Tipos de referencia: sus valores son IDs de registros (tuplas) de otra entidad lógica
+ALTER TABLE data_types
+    DROP CONSTRAINT data_types_kind_check,
+    ADD CONSTRAINT data_types_kind_check CHECK (kind IN ('scalar', 'enumeration', 'reference')),
+    ADD CONSTRAINT data_types_reference_storage_check CHECK (
+        kind <> 'reference' OR storage_column = 'uuid_value'
+    );
+
+-- Configuración de la referencia. La entidad referenciada no puede eliminarse
+-- mientras otros atributos la referencien (se comprueba al final de la sentencia,
+-- así una entidad que se referencia a sí misma puede eliminarse).
+ALTER TABLE attributes
+    ADD COLUMN reference_entity_id UUID REFERENCES logical_entities(id),
+    ADD COLUMN reference_on_delete TEXT,
+    ADD COLUMN reference_display_attribute_id UUID REFERENCES attributes(id) ON DELETE SET NULL,
+    ADD CONSTRAINT attributes_reference_on_delete_check CHECK (
+        reference_on_delete IN ('restrict', 'cascade', 'set_null')
+    ),
+    ADD CONSTRAINT attributes_reference_complete_check CHECK (
+        (reference_entity_id IS NULL) = (reference_on_delete IS NULL)
+    );
+
+CREATE INDEX idx_attributes_reference_entity_id ON attributes(reference_entity_id)
+    WHERE reference_entity_id IS NOT NULL;
+
+-- Búsqueda de los registros que referencian una tupla (al eliminarla)
+CREATE INDEX idx_attribute_values_uuid_value ON attribute_values(attribute_id, uuid_value)
+    WHERE uuid_value IS NOT NULL;
+
+-- Tipo base de referencia
+INSERT INTO data_types (name, description, storage_column, pg_cast, kind)
+SELECT 'reference', 'Referencia a un registro de otra entidad', 'uuid_value', 'uuid', 'reference'
+WHERE NOT EXISTS (SELECT 1 FROM data_types dt WHERE LOWER(dt.name) = 'reference');
@@ -0,0 +1,20 @@
+-- migrations/2026-10-18-011423_create_record_search/down.sql
+
+DROP TRIGGER IF EXISTS trg_logical_entities_search ON logical_entities;
+DROP TRIGGER IF EXISTS trg_attributes_search ON attributes;
+DROP TRIGGER IF EXISTS trg_attribute_values_search ON attribute_values;
+
+DROP FUNCTION IF EXISTS logical_entities_search_trigger();
+DROP FUNCTION IF EXISTS attributes_search_trigger();
+DROP FUNCTION IF EXISTS attribute_values_search_trigger();
+DROP FUNCTION IF EXISTS refresh_entity_search_documents(UUID);
+DROP FUNCTION IF EXISTS refresh_record_search_document(UUID);
+
+DROP TABLE IF EXISTS record_search_documents;
+
+ALTER TABLE attributes
+    DROP COLUMN IF EXISTS is_searchable;
+
+ALTER TABLE logical_entities
+    DROP CONSTRAINT IF EXISTS logical_entities_search_language_check,
+    DROP COLUMN IF EXISTS search_language;
@@ -0,0 +1,111 @@
+-- migrations/2026-10-18-011423_create_record_search/up.sql
+
+-- Configuración de búsqueda de texto de cada entidad (nombre de un regconfig de Postgres)
+ALTER TABLE logical_entities
+    ADD COLUMN search_language TEXT NOT NULL DEFAULT 'simple',
+    ADD CONSTRAINT logical_entities_search_language_check CHECK (search_language ~ '^[a-z_]+$');
+
+-- Atributos (string/text) que participan en la búsqueda
+ALTER TABLE attributes
+    ADD COLUMN is_searchable BOOLEAN NOT NULL DEFAULT FALSE;
+
+-- Documento de búsqueda de cada registro: los valores de sus atributos buscables,
+-- procesados con la configuración de la entidad. Lo mantienen los triggers de abajo.
+CREATE TABLE record_search_documents (
+    instance_id UUID PRIMARY KEY REFERENCES tuplas(id) ON DELETE CASCADE,
+    entity_id UUID NOT NULL REFERENCES logical_entities(id) ON DELETE CASCADE,
+    document TSVECTOR NOT NULL
+);
+
+CREATE INDEX idx_record_search_documents_document ON record_search_documents USING GIN (document);
+CREATE INDEX idx_record_search_documents_entity_id ON record_search_documents(entity_id);
+
+-- Recalcula el documento de un registro (lo elimina si no queda texto que indexar)
+CREATE OR REPLACE FUNCTION refresh_record_search_document(p_instance_id UUID) RETURNS VOID AS $$
+DECLARE
+    v_entity_id UUID;
+    v_language REGCONFIG;
+    v_text TEXT;
+BEGIN
+    SELECT t.entity_id, le.search_language::regconfig
+    INTO v_entity_id, v_language
+    FROM tuplas t
+    JOIN logical_entities le ON le.id = t.entity_id
+    WHERE t.id = p_instance_id;
+
+    IF NOT FOUND THEN
+        RETURN;
+    END IF;
+
+    SELECT string_agg(COALESCE(av.string_value, av.text_value), ' ' ORDER BY a.position, a.name)
+    INTO v_text
+    FROM attribute_values av
+    JOIN attributes a ON a.id = av.attribute_id
+    WHERE av.instance_id = p_instance_id
+      AND a.is_searchable
+      AND a.status = 1
+      AND COALESCE(av.string_value, av.text_value) IS NOT NULL;
+
+    IF v_text IS NULL OR v_text = '' THEN
+        DELETE FROM record_search_documents WHERE instance_id = p_instance_id;
+        RETURN;
+    END IF;
+
+    INSERT INTO record_search_documents (instance_id, entity_id, document)
+    VALUES (p_instance_id, v_entity_id, to_tsvector(v_language, v_text))
+    ON CONFLICT (instance_id) DO UPDATE SET document = EXCLUDED.document;
+END;
+$$ LANGUAGE plpgsql;
+
+-- Recalcula los documentos de todos los registros de una entidad
+CREATE OR REPLACE FUNCTION refresh_entity_search_documents(p_entity_id UUID) RETURNS VOID AS $$
+BEGIN
+    PERFORM refresh_record_search_document(t.id) FROM tuplas t WHERE t.entity_id = p_entity_id;
+END;
+$$ LANGUAGE plpgsql;
+
+-- Cambios en los valores: se recalcula el registro afectado
+CREATE OR REPLACE FUNCTION attribute_values_search_trigger() RETURNS TRIGGER AS $$
+BEGIN
+    IF TG_OP IN ('UPDATE', 'DELETE') THEN
+        PERFORM refresh_record_search_document(OLD.instance_id);
+    END IF;
+    IF TG_OP IN ('INSERT', 'UPDATE') AND (TG_OP = 'INSERT' OR NEW.instance_id <> OLD.instance_id) THEN
+        PERFORM refresh_record_search_document(NEW.instance_id);
+    END IF;
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER trg_attribute_values_search
+    AFTER INSERT OR UPDATE OR DELETE ON attribute_values
+    FOR EACH ROW EXECUTE FUNCTION attribute_values_search_trigger();
+
+-- Cambios en los atributos que alteran qué se indexa: se recalcula la entidad
+CREATE OR REPLACE FUNCTION attributes_search_trigger() RETURNS TRIGGER AS $$
+BEGIN
+    PERFORM refresh_entity_search_documents(NEW.entity_id);
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER trg_attributes_search
+    AFTER UPDATE OF is_searchable, status, position ON attributes
+    FOR EACH ROW
+    WHEN (OLD.is_searchable IS DISTINCT FROM NEW.is_searchable
+          OR (NEW.is_searchable AND (OLD.status IS DISTINCT FROM NEW.status OR OLD.position IS DISTINCT FROM NEW.position)))
+    EXECUTE FUNCTION attributes_search_trigger();
+
+-- Cambio de configuración de búsqueda de la entidad: se reindexan sus registros
+CREATE OR REPLACE FUNCTION logical_entities_search_trigger() RETURNS TRIGGER AS $$
+BEGIN
+    PERFORM refresh_entity_search_documents(NEW.id);
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER trg_logical_entities_search
+    AFTER UPDATE OF search_language ON logical_entities
+    FOR EACH ROW
+    WHEN (OLD.search_language IS DISTINCT FROM NEW.search_language)
+    EXECUTE FUNCTION logical_entities_search_trigger();
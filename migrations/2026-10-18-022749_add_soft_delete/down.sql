@@ -0,0 +1,18 @@
+-- migrations/2026-10-18-022749_add_soft_delete/down.sql
+
+-- Las filas que estaban en la papelera vuelven a ser visibles
+DROP INDEX IF EXISTS idx_users_deleted_at;
+DROP INDEX IF EXISTS idx_logical_entities_deleted_at;
+DROP INDEX IF EXISTS idx_tuplas_deleted_at;
+
+ALTER TABLE users
+    DROP COLUMN IF EXISTS deleted_by,
+    DROP COLUMN IF EXISTS deleted_at;
+
+ALTER TABLE logical_entities
+    DROP COLUMN IF EXISTS deleted_by,
+    DROP COLUMN IF EXISTS deleted_at;
+
+ALTER TABLE tuplas
+    DROP COLUMN IF EXISTS deleted_by,
+    DROP COLUMN IF EXISTS deleted_at;
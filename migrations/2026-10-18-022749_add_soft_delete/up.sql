@@ -0,0 +1,24 @@
+-- migrations/2026-10-18-022749_add_soft_delete/up.sql
+
+-- Papelera: las eliminaciones de registros, entidades lógicas y usuarios marcan
+-- la fila (quién y cuándo) en lugar de borrarla. Las consultas y las vistas
+-- generadas excluyen las filas con deleted_at; la purga las borra definitivamente.
+ALTER TABLE tuplas
+    ADD COLUMN deleted_at TIMESTAMPTZ,
+    ADD COLUMN deleted_by UUID REFERENCES users(id) ON DELETE SET NULL;
+
+ALTER TABLE logical_entities
+    ADD COLUMN deleted_at TIMESTAMPTZ,
+    ADD COLUMN deleted_by UUID REFERENCES users(id) ON DELETE SET NULL;
+
+ALTER TABLE users
+    ADD COLUMN deleted_at TIMESTAMPTZ,
+    ADD COLUMN deleted_by UUID REFERENCES users(id) ON DELETE SET NULL;
+
+-- Listado de la papelera y purga por antigüedad
+CREATE INDEX idx_tuplas_deleted_at ON tuplas(entity_id, deleted_at) WHERE deleted_at IS NOT NULL;
+CREATE INDEX idx_logical_entities_deleted_at ON logical_entities(deleted_at) WHERE deleted_at IS NOT NULL;
+CREATE INDEX idx_users_deleted_at ON users(deleted_at) WHERE deleted_at IS NOT NULL;
+
+-- Las vistas existentes siguen incluyendo los registros eliminados hasta que se
+-- regeneran (POST /api/logical-entities/{id}/view o cualquier cambio de la entidad).
@@ -0,0 +1,5 @@
+-- migrations/2026-10-18-023901_create_record_history/down.sql
+
+DROP TRIGGER IF EXISTS record_history_immutable ON record_history;
+DROP FUNCTION IF EXISTS prevent_record_history_update();
+DROP TABLE IF EXISTS record_history;
@@ -0,0 +1,40 @@
+-- migrations/2026-10-18-023901_create_record_history/up.sql
+
+-- Historial de cambios de los registros, a nivel de atributo. Se escribe en la misma
+-- transacción que el cambio. Cada fila es un evento de la tupla (attribute_id NULL:
+-- creación, eliminación, restauración o purga) o el cambio de un atributo (valor
+-- anterior y nuevo en la representación JSON de la API; NULL = sin valor).
+-- Sin claves foráneas: el historial se conserva tras purgar el registro o el atributo.
+CREATE TABLE record_history (
+    id BIGINT GENERATED ALWAYS AS IDENTITY PRIMARY KEY,
+    entity_id UUID NOT NULL,
+    tuple_id UUID NOT NULL,
+    attribute_id UUID,
+    attribute_name TEXT,
+    change_kind TEXT NOT NULL,
+    old_value JSONB,
+    new_value JSONB,
+    changed_by UUID,
+    changed_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    request_id TEXT,
+
+    CONSTRAINT record_history_change_kind_check
+        CHECK (change_kind IN ('create', 'update', 'delete', 'restore', 'purge')),
+    CONSTRAINT record_history_attribute_name_check
+        CHECK ((attribute_id IS NULL) = (attribute_name IS NULL))
+);
+
+-- Historial de un registro (el id da el orden de escritura) y reconstrucción "as-of"
+CREATE INDEX idx_record_history_tuple ON record_history(tuple_id, id);
+
+-- El historial no se modifica una vez escrito
+CREATE OR REPLACE FUNCTION prevent_record_history_update()
+RETURNS TRIGGER AS $$
+BEGIN
+    RAISE EXCEPTION 'record_history es de solo inserción';
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER record_history_immutable
+BEFORE UPDATE ON record_history
+FOR EACH ROW EXECUTE FUNCTION prevent_record_history_update();
@@ -0,0 +1,4 @@
+-- migrations/2026-10-18-024638_add_row_versions/down.sql
+
+ALTER TABLE tuplas DROP COLUMN IF EXISTS version;
+ALTER TABLE users DROP COLUMN IF EXISTS version;
@@ -0,0 +1,10 @@
+-- migrations/2026-10-18-024638_add_row_versions/up.sql
+
+-- Control de concurrencia optimista: cada usuario y cada registro lleva un contador
+-- de versión que se incrementa en cada modificación. La API lo expone como ETag y
+-- rechaza (412) las escrituras con un If-Match que ya no coincide.
+ALTER TABLE users
+    ADD COLUMN version INTEGER NOT NULL DEFAULT 1;
+
+ALTER TABLE tuplas
+    ADD COLUMN version INTEGER NOT NULL DEFAULT 1;
@@ -0,0 +1,4 @@
+-- migrations/2026-10-18-025540_create_entity_views/down.sql
+
+DROP INDEX IF EXISTS idx_record_history_entity;
+DROP TABLE IF EXISTS entity_views;
@@ -0,0 +1,25 @@
+-- migrations/2026-10-18-025540_create_entity_views/up.sql
+
+-- Opciones de la vista de cada entidad lógica y estado de su último refresco.
+-- Sin fila = vista normal. Una vista materializada se refresca bajo demanda, cada
+-- `refresh_interval_secs` segundos (NULL = sin refresco programado) y tras un margen
+-- sin escrituras de registros (ver VIEW_REFRESH_DEBOUNCE_SECS).
+CREATE TABLE entity_views (
+    entity_id UUID PRIMARY KEY REFERENCES logical_entities(id) ON DELETE CASCADE,
+    materialized BOOLEAN NOT NULL DEFAULT FALSE,
+    refresh_interval_secs INTEGER,
+    refresh_status TEXT NOT NULL DEFAULT 'never',
+    last_refresh_started_at TIMESTAMPTZ,
+    last_refreshed_at TIMESTAMPTZ,
+    last_refresh_duration_ms BIGINT,
+    last_refresh_error TEXT,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT entity_views_refresh_interval_check
+        CHECK (refresh_interval_secs IS NULL OR refresh_interval_secs > 0),
+    CONSTRAINT entity_views_refresh_status_check
+        CHECK (refresh_status IN ('never', 'refreshing', 'ready', 'failed'))
+);
+
+-- Última escritura de registros de una entidad (refresco tras escrituras)
+CREATE INDEX idx_record_history_entity ON record_history(entity_id, id);
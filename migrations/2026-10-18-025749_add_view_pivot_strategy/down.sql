@@ -0,0 +1,5 @@
+-- migrations/2026-10-18-025749_add_view_pivot_strategy/down.sql
+
+ALTER TABLE entity_views
+    DROP CONSTRAINT IF EXISTS entity_views_pivot_strategy_check,
+    DROP COLUMN IF EXISTS pivot_strategy;
@@ -0,0 +1,10 @@
+-- migrations/2026-10-18-025749_add_view_pivot_strategy/up.sql
+
+-- Estrategia de pivote de la vista de la entidad: un JOIN por atributo ('joins') o una
+-- sola lectura de attribute_values agrupada por registro ('aggregate').
+-- NULL = automática según el número de atributos activos.
+ALTER TABLE entity_views
+    ADD COLUMN pivot_strategy TEXT,
+    ADD CONSTRAINT entity_views_pivot_strategy_check
+        CHECK (pivot_strategy IS NULL OR pivot_strategy IN ('joins', 'aggregate'));
+
@@ -0,0 +1,45 @@
+-- migrations/2026-10-18-032227_add_physical_storage_mode/down.sql
+
+-- Las entidades en modo físico deben volver a EAV antes de revertir:
+-- sus tablas records_<id> no se eliminan aquí.
+DROP FUNCTION IF EXISTS physical_record_search_trigger() CASCADE;
+
+CREATE OR REPLACE FUNCTION refresh_record_search_document(p_instance_id UUID) RETURNS VOID AS $$
+DECLARE
+    v_entity_id UUID;
+    v_language REGCONFIG;
+    v_text TEXT;
+BEGIN
+    SELECT t.entity_id, le.search_language::regconfig
+    INTO v_entity_id, v_language
+    FROM tuplas t
+    JOIN logical_entities le ON le.id = t.entity_id
+    WHERE t.id = p_instance_id;
+
+    IF NOT FOUND THEN
+        RETURN;
+    END IF;
+
+    SELECT string_agg(COALESCE(av.string_value, av.text_value), ' ' ORDER BY a.position, a.name)
+    INTO v_text
+    FROM attribute_values av
+    JOIN attributes a ON a.id = av.attribute_id
+    WHERE av.instance_id = p_instance_id
+      AND a.is_searchable
+      AND a.status = 1
+      AND COALESCE(av.string_value, av.text_value) IS NOT NULL;
+
+    IF v_text IS NULL OR v_text = '' THEN
+        DELETE FROM record_search_documents WHERE instance_id = p_instance_id;
+        RETURN;
+    END IF;
+
+    INSERT INTO record_search_documents (instance_id, entity_id, document)
+    VALUES (p_instance_id, v_entity_id, to_tsvector(v_language, v_text))
+    ON CONFLICT (instance_id) DO UPDATE SET document = EXCLUDED.document;
+END;
+$$ LANGUAGE plpgsql;
+
+ALTER TABLE logical_entities
+    DROP CONSTRAINT IF EXISTS logical_entities_storage_mode_check,
+    DROP COLUMN IF EXISTS storage_mode;
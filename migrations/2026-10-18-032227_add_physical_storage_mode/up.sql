@@ -0,0 +1,74 @@
+-- migrations/2026-10-18-032227_add_physical_storage_mode/up.sql
+
+-- Dónde guarda cada entidad los valores de sus registros: en attribute_values ('eav')
+-- o en su tabla física records_<id de la entidad> ('physical'), con una columna
+-- a_<id del atributo> por atributo. La aplicación crea y mantiene esas tablas.
+ALTER TABLE logical_entities
+    ADD COLUMN storage_mode TEXT NOT NULL DEFAULT 'eav',
+    ADD CONSTRAINT logical_entities_storage_mode_check CHECK (storage_mode IN ('eav', 'physical'));
+
+-- El documento de búsqueda se calcula de la fuente de valores de la entidad
+CREATE OR REPLACE FUNCTION refresh_record_search_document(p_instance_id UUID) RETURNS VOID AS $$
+DECLARE
+    v_entity_id UUID;
+    v_language REGCONFIG;
+    v_storage_mode TEXT;
+    v_columns TEXT;
+    v_text TEXT;
+BEGIN
+    SELECT t.entity_id, le.search_language::regconfig, le.storage_mode
+    INTO v_entity_id, v_language, v_storage_mode
+    FROM tuplas t
+    JOIN logical_entities le ON le.id = t.entity_id
+    WHERE t.id = p_instance_id;
+
+    IF NOT FOUND THEN
+        RETURN;
+    END IF;
+
+    IF v_storage_mode = 'physical' THEN
+        SELECT string_agg(format('%I', 'a_' || replace(a.id::text, '-', '')), ', ' ORDER BY a.position, a.name)
+        INTO v_columns
+        FROM attributes a
+        WHERE a.entity_id = v_entity_id AND a.is_searchable AND a.status = 1;
+
+        IF v_columns IS NOT NULL THEN
+            EXECUTE format('SELECT concat_ws('' '', %s) FROM %I WHERE id = $1',
+                           v_columns, 'records_' || replace(v_entity_id::text, '-', ''))
+            INTO v_text
+            USING p_instance_id;
+        END IF;
+    ELSE
+        SELECT string_agg(COALESCE(av.string_value, av.text_value), ' ' ORDER BY a.position, a.name)
+        INTO v_text
+        FROM attribute_values av
+        JOIN attributes a ON a.id = av.attribute_id
+        WHERE av.instance_id = p_instance_id
+          AND a.is_searchable
+          AND a.status = 1
+          AND COALESCE(av.string_value, av.text_value) IS NOT NULL;
+    END IF;
+
+    IF v_text IS NULL OR v_text = '' THEN
+        DELETE FROM record_search_documents WHERE instance_id = p_instance_id;
+        RETURN;
+    END IF;
+
+    INSERT INTO record_search_documents (instance_id, entity_id, document)
+    VALUES (p_instance_id, v_entity_id, to_tsvector(v_language, v_text))
+    ON CONFLICT (instance_id) DO UPDATE SET document = EXCLUDED.document;
+END;
+$$ LANGUAGE plpgsql;
+
+-- Trigger de las tablas físicas (lo crea la aplicación junto con cada tabla)
+CREATE OR REPLACE FUNCTION physical_record_search_trigger() RETURNS TRIGGER AS $$
+BEGIN
+    IF TG_OP IN ('UPDATE', 'DELETE') THEN
+        PERFORM refresh_record_search_document(OLD.id);
+    END IF;
+    IF TG_OP = 'INSERT' OR (TG_OP = 'UPDATE' AND NEW.id <> OLD.id) THEN
+        PERFORM refresh_record_search_document(NEW.id);
+    END IF;
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
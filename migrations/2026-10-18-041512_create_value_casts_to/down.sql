@@ -0,0 +1,3 @@
+-- migrations/2026-10-18-041512_create_value_casts_to/down.sql
+
+DROP FUNCTION IF EXISTS value_casts_to(TEXT, REGTYPE);
@@ -0,0 +1,13 @@
+-- migrations/2026-10-18-041512_create_value_casts_to/up.sql
+
+-- Indica si un texto se puede convertir al tipo dado sin error (formato o rango).
+-- Lo usan los cambios de tipo de dato de un atributo, que convierten sus valores con
+-- una sola sentencia y descartan los que no admite la nueva columna.
+CREATE OR REPLACE FUNCTION value_casts_to(p_value TEXT, p_type REGTYPE) RETURNS BOOLEAN AS $$
+BEGIN
+    EXECUTE format('SELECT $1::%s', p_type) USING p_value;
+    RETURN TRUE;
+EXCEPTION WHEN data_exception THEN
+    RETURN FALSE;
+END;
+$$ LANGUAGE plpgsql STABLE;
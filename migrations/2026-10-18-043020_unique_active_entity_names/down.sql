@@ -0,0 +1,3 @@
+-- migrations/2026-10-18-043020_unique_active_entity_names/down.sql
+
+DROP INDEX IF EXISTS idx_logical_entities_active_name;
@@ -0,0 +1,5 @@
+-- migrations/2026-10-18-043020_unique_active_entity_names/up.sql
+
+-- Los nombres de las entidades lógicas son únicos entre las que no están en la
+-- papelera: una entidad eliminada libera su nombre hasta que se restaura.
+CREATE UNIQUE INDEX idx_logical_entities_active_name ON logical_entities(name) WHERE deleted_at IS NULL;
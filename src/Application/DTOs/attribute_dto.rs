@@ -0,0 +1,109 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::Domain::attribute_options::AttributeOption;
+use crate::Domain::attribute_references::ReferenceDeleteAction;
+
+/// Configuración de un atributo de tipo referencia: entidad referenciada, acción
+/// al eliminar el registro referenciado y, opcionalmente, el nombre del atributo
+/// de esa entidad que las vistas muestran junto al ID.
+#[derive(Debug, Clone)]
+pub struct ReferenceDefinitionDto {
+    pub entity_id: Uuid,
+    pub on_delete: ReferenceDeleteAction,
+    pub display_attribute: Option<String>,
+}
+
+/// Datos de un atributo nuevo para una entidad existente.
+/// `options` solo se admite en atributos de tipo enumeración y `reference`
+/// (obligatorio en ellos) en los de tipo referencia.
+#[derive(Debug, Clone)]
+pub struct AddAttributeDto {
+    pub name: String,
+    pub description: Option<String>,
+    pub data_type_name: String,
+    pub position: i16,
+    pub is_required: bool,
+    pub is_unique: Option<i16>,
+    pub default_value: Option<String>,
+    pub validation_regex: Option<String>,
+    pub show_option_label: bool,
+    /// Incluye el atributo (string/text) en la búsqueda de texto de la entidad.
+    pub is_searchable: bool,
+    pub options: Vec<AttributeOption>,
+    pub reference: Option<ReferenceDefinitionDto>,
+    pub created_by: Uuid,
+}
+
+/// Cambios sobre un atributo existente (None = no se modifica).
+/// En los campos anulables, `Some(None)` elimina el valor.
+/// `status`: 1 activo, 0 retirado (los valores almacenados se conservan).
+/// `reference_*` solo se admiten en atributos de tipo referencia.
+#[derive(Debug, Clone, Default)]
+pub struct UpdateAttributeDto {
+    pub name: Option<String>,
+    pub description: Option<Option<String>>,
+    pub is_required: Option<bool>,
+    pub is_unique: Option<Option<i16>>,
+    pub default_value: Option<Option<String>>,
+    pub validation_regex: Option<Option<String>>,
+    pub show_option_label: Option<bool>,
+    pub is_searchable: Option<bool>,
+    pub reference_on_delete: Option<ReferenceDeleteAction>,
+    pub reference_display_attribute: Option<Option<String>>,
+    pub status: Option<i16>,
+    pub updated_by: Uuid,
+}
+
+/// Nueva posición de un atributo.
+#[derive(Debug, Clone, Copy)]
+pub struct AttributePositionDto {
+    pub attribute_id: Uuid,
+    pub position: i16,
+}
+
+/// Nueva lista de opciones de un atributo de tipo enumeración. Las opciones que
+/// no aparecen se eliminan; si algún registro usa su código, desactívelas en su lugar.
+#[derive(Debug, Clone)]
+pub struct ReplaceAttributeOptionsDto {
+    pub options: Vec<AttributeOption>,
+    pub updated_by: Uuid,
+}
+
+/// Cambio del tipo de dato de un atributo.
+/// `dry_run`: solo informa del resultado de la conversión, sin aplicar cambios.
+/// `discard_failures`: elimina los valores que no se pueden convertir en lugar de abortar.
+/// `options`: lista de opciones si el nuevo tipo es una enumeración (vacía = se
+/// conserva la actual); los valores almacenados deben ser códigos activos de la lista.
+#[derive(Debug, Clone)]
+pub struct ChangeAttributeDataTypeDto {
+    pub data_type_name: String,
+    pub options: Vec<AttributeOption>,
+    pub dry_run: bool,
+    pub discard_failures: bool,
+    pub updated_by: Uuid,
+}
+
+/// Valor almacenado que no se pudo convertir al nuevo tipo.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ConversionFailureDto {
+    pub record_id: Uuid,
+    pub value: Value,
+    pub error: String,
+}
+
+/// Resultado del cambio de tipo de dato de un atributo.
+/// `applied` es false en modo dry_run o si hubo fallos sin `discard_failures`.
+/// `failed` solo incluye los primeros fallos; `failed_count` es el total.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct DataTypeChangeReportDto {
+    pub attribute_id: Uuid,
+    pub from_data_type: String,
+    pub to_data_type: String,
+    pub applied: bool,
+    pub converted: usize,
+    pub discarded: usize,
+    pub failed_count: usize,
+    pub failed: Vec<ConversionFailureDto>,
+}
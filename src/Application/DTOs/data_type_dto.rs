@@ -0,0 +1,53 @@
+use uuid::Uuid;
+
+use crate::Domain::data_types::{DataTypeParams, DataTypeKind};
+use crate::Domain::records::StorageColumn;
+
+/// Datos de un tipo de dato nuevo para el registro.
+/// Sin `pg_cast` se usa el tipo de la columna ajustado a los parámetros.
+/// `kind`: `enumeration` restringe los valores a la lista de opciones de cada atributo;
+/// `reference` los convierte en IDs de registros de la entidad que indique el atributo.
+#[derive(Debug, Clone)]
+pub struct CreateDataTypeDto {
+    pub name: String,
+    pub description: Option<String>,
+    pub storage_column: StorageColumn,
+    pub pg_cast: Option<String>,
+    pub params: DataTypeParams,
+    pub kind: DataTypeKind,
+    pub created_by: Uuid,
+}
+
+/// Cambios sobre un tipo de dato (None = no se modifica).
+/// En los campos anulables, `Some(None)` elimina el valor.
+/// `status`: 1 activo, 0 inactivo (no se puede asignar a atributos nuevos).
+#[derive(Debug, Clone, Default)]
+pub struct UpdateDataTypeDto {
+    pub name: Option<String>,
+    pub description: Option<Option<String>>,
+    pub storage_column: Option<StorageColumn>,
+    pub pg_cast: Option<String>,
+    pub max_length: Option<Option<i32>>,
+    pub numeric_precision: Option<Option<i16>>,
+    pub numeric_scale: Option<Option<i16>>,
+    pub min_value: Option<Option<f64>>,
+    pub max_value: Option<Option<f64>>,
+    pub kind: Option<DataTypeKind>,
+    pub status: Option<i16>,
+    pub updated_by: Uuid,
+}
+
+impl UpdateDataTypeDto {
+    /// Indica si el cambio afecta a la definición de almacenamiento (columna, cast,
+    /// parámetros o naturaleza).
+    pub fn changes_storage(&self) -> bool {
+        self.storage_column.is_some()
+            || self.pg_cast.is_some()
+            || self.max_length.is_some()
+            || self.numeric_precision.is_some()
+            || self.numeric_scale.is_some()
+            || self.min_value.is_some()
+            || self.max_value.is_some()
+            || self.kind.is_some()
+    }
+}
@@ -0,0 +1,33 @@
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+/// Petición GraphQL: documento, operación a ejecutar (si el documento tiene varias)
+/// y valores de las variables.
+#[derive(Debug, Clone, Default)]
+pub struct GraphQLRequestDto {
+    pub query: String,
+    pub operation_name: Option<String>,
+    pub variables: Map<String, Value>,
+}
+
+/// Error de la respuesta GraphQL. `path` indica el campo que falló (claves de
+/// respuesta e índices de lista) y `extensions.code` el tipo de error
+/// (GRAPHQL_PARSE_FAILED, GRAPHQL_VALIDATION_FAILED, BAD_USER_INPUT, NOT_FOUND, ...).
+#[derive(Debug, Serialize, Clone)]
+pub struct GraphQLErrorDto {
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<Vec<Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extensions: Option<Value>,
+}
+
+/// Respuesta GraphQL: sin `data` si la consulta no se pudo validar; con `data` y
+/// `errors` si fallaron algunos campos (que quedan a null).
+#[derive(Debug, Serialize, Clone)]
+pub struct GraphQLResponseDto {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<Value>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<GraphQLErrorDto>,
+}
@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::Application::ports::driven::repositories::{LogicalEntityDto, AttributeDto};
+
+/// Entidad lógica con sus atributos (incluye el nombre del tipo de dato de cada uno).
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct LogicalEntityDetailsDto {
+    pub entity: LogicalEntityDto,
+    pub attributes: Vec<AttributeDto>,
+}
+
+/// Página de entidades lógicas.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct LogicalEntityPageDto {
+    pub items: Vec<LogicalEntityDetailsDto>,
+    pub page: i64,
+    pub page_size: i64,
+    pub total: i64,
+}
+
+/// JSON Schema (draft 2020-12) de los registros de una entidad lógica.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct EntityJsonSchemaDto {
+    pub entity_id: Uuid,
+    pub entity_name: String,
+    pub schema: serde_json::Value,
+}
+
+/// Datos de actualización de una entidad lógica (None = no se modifica).
+/// `assign_view`: true crea/regenera la vista de la entidad, false la elimina.
+/// `search_language`: configuración de búsqueda de texto (reindexa los registros).
+#[derive(Debug, Clone)]
+pub struct UpdateLogicalEntityDto {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub assign_view: Option<bool>,
+    pub search_language: Option<String>,
+    pub status: Option<i16>,
+    pub updated_by: Uuid,
+}
@@ -2,8 +2,32 @@ pub mod user_dto;
 pub mod create_user_dto;
 pub mod update_user_dto;
 pub mod auth_dto;
+pub mod logical_entity_dto;
+pub mod record_dto;
+pub mod attribute_dto;
+pub mod schema_version_dto;
+pub mod data_type_dto;
+pub mod schema_bundle_dto;
+pub mod graphql_dto;
+pub mod trash_dto;
+pub mod record_history_dto;
 
 pub use user_dto::UserResponseDto;
 pub use create_user_dto::CreateUserDto;
 pub use update_user_dto::UpdateUserDto;
-pub use auth_dto::{LoginDto, TokenDto};
\ No newline at end of file
+pub use auth_dto::{LoginDto, TokenDto};
+pub use logical_entity_dto::{LogicalEntityDetailsDto, LogicalEntityPageDto, UpdateLogicalEntityDto};
+pub use record_dto::{
+    RecordPageDto, RecordSearchPageDto, RecordUpdateMode, ImportRecordsDto, ImportRowErrorDto, ImportReportDto,
+    RecordExportDto,
+};
+pub use attribute_dto::{
+    AddAttributeDto, UpdateAttributeDto, AttributePositionDto, ChangeAttributeDataTypeDto,
+    ConversionFailureDto, DataTypeChangeReportDto,
+};
+pub use schema_version_dto::SchemaVersionDiffDto;
+pub use data_type_dto::{CreateDataTypeDto, UpdateDataTypeDto};
+pub use schema_bundle_dto::{ImportSchemaBundleDto, SchemaBundleImportReportDto};
+pub use graphql_dto::{GraphQLRequestDto, GraphQLResponseDto, GraphQLErrorDto};
+pub use trash_dto::{TrashPageDto, PurgeSkipDto, PurgeReportDto};
+pub use record_history_dto::{RecordHistoryPageDto, RecordAsOfDto};
@@ -0,0 +1,85 @@
+use futures::stream::BoxStream;
+use serde::{Deserialize, Serialize};
+
+use crate::Application::ports::driven::repositories::{RecordDto, RecordSearchHitDto};
+use crate::Application::errors::application_error::ApplicationError;
+use crate::Domain::record_exports::ExportColumn;
+use crate::Domain::record_imports::{ColumnMapping, ImportErrorPolicy, ImportFormat, ImportIssue, ImportRow};
+use crate::Domain::record_history::ChangeContext;
+
+/// Página de registros de una entidad (paginación por clave).
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct RecordPageDto {
+    pub items: Vec<RecordDto>,
+    pub limit: i64,
+    /// Cursor para pedir la página siguiente; `None` si no hay más registros.
+    pub next_cursor: Option<String>,
+}
+
+/// Página de resultados de la búsqueda de texto (por relevancia).
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct RecordSearchPageDto {
+    pub items: Vec<RecordSearchHitDto>,
+    pub limit: i64,
+    pub next_cursor: Option<String>,
+}
+
+/// Modo de actualización de un registro.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecordUpdateMode {
+    /// PUT: los atributos ausentes en el cuerpo quedan sin valor.
+    Replace,
+    /// PATCH: solo se modifican los atributos presentes (`null` elimina el valor).
+    Merge,
+}
+
+/// Importación masiva de registros desde un archivo CSV o NDJSON, ya leído por filas
+/// (`ImportReader`) a medida que se recibe.
+/// `mapping`: columna del archivo -> nombre de atributo, para el informe de errores.
+/// `dry_run`: solo valida y devuelve el informe, sin insertar.
+#[derive(Debug, Clone)]
+pub struct ImportRecordsDto {
+    pub format: ImportFormat,
+    pub rows: Vec<ImportRow>,
+    /// Filas que no se pudieron leer (JSON no válido, número de campos incorrecto).
+    pub issues: Vec<ImportIssue>,
+    pub mapping: ColumnMapping,
+    pub dry_run: bool,
+    pub on_error: ImportErrorPolicy,
+    /// Autor de la importación, para el historial de los registros creados.
+    pub context: ChangeContext,
+}
+
+/// Error de una fila del archivo de importación.
+/// `column` es la columna del archivo (None si afecta a toda la fila) y `code`
+/// la regla incumplida (invalid_row, required, unique, invalid_type, ...).
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ImportRowErrorDto {
+    pub line: usize,
+    pub column: Option<String>,
+    pub code: String,
+    pub reason: String,
+}
+
+/// Informe de la importación, con los errores ordenados por línea.
+/// `applied` es false en modo dry_run, si no había filas válidas o si hubo
+/// errores con la política all_or_nothing.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ImportReportDto {
+    pub total_rows: usize,
+    pub valid_rows: usize,
+    pub invalid_rows: usize,
+    pub imported: usize,
+    pub dry_run: bool,
+    pub on_error: String,
+    pub applied: bool,
+    pub errors: Vec<ImportRowErrorDto>,
+}
+
+/// Exportación de los registros de una entidad: columnas del archivo (sistema y
+/// atributos en orden de `position`) y lotes de filas leídos bajo demanda.
+/// Cada fila trae un valor de texto (o `None`) por columna.
+pub struct RecordExportDto {
+    pub columns: Vec<ExportColumn>,
+    pub rows: BoxStream<'static, Result<Vec<Vec<Option<String>>>, ApplicationError>>,
+}
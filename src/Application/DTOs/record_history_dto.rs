@@ -0,0 +1,22 @@
+use chrono::{DateTime, Utc};
+use serde_json::{Map, Value};
+use uuid::Uuid;
+
+use crate::Application::ports::driven::repositories::RecordHistoryEntryDto;
+
+/// Página del historial de un registro, de la entrada más reciente a la más antigua.
+/// `next_before` es el cursor de la página siguiente (`None` si no hay más).
+#[derive(Debug, Clone)]
+pub struct RecordHistoryPageDto {
+    pub entries: Vec<RecordHistoryEntryDto>,
+    pub next_before: Option<i64>,
+}
+
+/// Estado de un registro en una fecha, reconstruido a partir del historial.
+#[derive(Debug, Clone)]
+pub struct RecordAsOfDto {
+    pub id: Uuid,
+    pub entity_id: Uuid,
+    pub as_of: DateTime<Utc>,
+    pub values: Map<String, Value>,
+}
@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::Domain::schema_bundles::{BundlePlan, SchemaBundle};
+use crate::Domain::schema_previews::RuleImpact;
+
+/// Importación de un bundle de definiciones.
+/// `dry_run`: solo calcula el plan, sin aplicar cambios.
+/// `prune`: retira los atributos del destino que no están en el bundle (status 0;
+/// los valores almacenados se conservan). Sin él, esas eliminaciones solo se informan.
+#[derive(Debug, Clone)]
+pub struct ImportSchemaBundleDto {
+    pub bundle: SchemaBundle,
+    pub dry_run: bool,
+    pub prune: bool,
+    pub updated_by: Uuid,
+}
+
+/// Resultado de la importación: el plan calculado antes de aplicar y si se aplicó.
+/// `applied` es false en modo dry_run, si el plan está vacío o si tiene conflictos.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SchemaBundleImportReportDto {
+    pub plan: BundlePlan,
+    pub dry_run: bool,
+    pub prune: bool,
+    pub applied: bool,
+}
+
+/// Vista previa de la creación o el cambio de una entidad, sin aplicar nada.
+/// `entity_id` es None si la entidad no existe (se crearía). `view_sql` es la
+/// definición que se generaría si la entidad tiene vista asignada; los IDs que aún
+/// no existen (entidad o atributos nuevos) aparecen como el UUID nulo.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct EntitySchemaPreviewDto {
+    pub entity: String,
+    pub entity_id: Option<Uuid>,
+    pub plan: BundlePlan,
+    pub view_name: Option<String>,
+    pub view_sql: Option<String>,
+    pub rule_impacts: Vec<RuleImpact>,
+    pub warnings: Vec<String>,
+}
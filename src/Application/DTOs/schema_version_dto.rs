@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::Domain::schema_versions::{AttributeDiff, FieldChange};
+
+/// Diferencias entre dos versiones de la definición de una entidad.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SchemaVersionDiffDto {
+    pub entity_id: Uuid,
+    pub from_version: i32,
+    pub to_version: i32,
+    pub entity_name: Option<FieldChange>,
+    pub attributes: Vec<AttributeDiff>,
+}
@@ -0,0 +1,34 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::Domain::trash::TrashKind;
+
+/// Página de la papelera (paginación por número de página, empieza en 1).
+#[derive(Debug, Clone)]
+pub struct TrashPageDto<T> {
+    pub items: Vec<T>,
+    pub page: i64,
+    pub page_size: i64,
+    pub total: i64,
+}
+
+/// Elemento de la papelera que no se pudo purgar (p. ej. un registro vivo lo referencia).
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PurgeSkipDto {
+    pub kind: TrashKind,
+    pub id: Uuid,
+    pub reason: String,
+}
+
+/// Resultado de una purga de la papelera: elementos eliminados definitivamente por
+/// tipo (los registros incluyen los eliminados en cascada) y los que se omitieron.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PurgeReportDto {
+    /// Se purgan los elementos eliminados antes de esta fecha.
+    pub deleted_before: DateTime<Utc>,
+    pub records: usize,
+    pub logical_entities: usize,
+    pub users: usize,
+    pub skipped: Vec<PurgeSkipDto>,
+}
@@ -14,6 +14,7 @@ pub struct UserResponseDto {
     pub updated_by: Option<Uuid>,
     pub updated_at: Option<DateTime<Utc>>,
     pub status: i32,
+    pub version: i32,
 }
 
 // Eliminamos la implementación de Into que creaba dependencia circular
\ No newline at end of file
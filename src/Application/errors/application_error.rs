@@ -1,4 +1,24 @@
 use thiserror::Error;
+use serde::Serialize;
+
+/// Error de validación asociado a un campo concreto (p. ej. un atributo de un registro).
+/// `code` identifica la regla incumplida: required, pattern, unique, invalid_type, ...
+#[derive(Debug, Clone, Serialize)]
+pub struct FieldError {
+    pub field: String,
+    pub code: String,
+    pub message: String,
+}
+
+impl FieldError {
+    pub fn new(field: &str, code: &str, message: impl Into<String>) -> Self {
+        Self {
+            field: field.to_string(),
+            code: code.to_string(),
+            message: message.into(),
+        }
+    }
+}
 
 /// Errores de la capa de aplicación
 /// 
@@ -11,9 +31,16 @@ pub enum ApplicationError {
     
     #[error("Error de validación: {0}")]
     ValidationError(String),
+
+    #[error("Error de validación en {} campo(s)", .0.len())]
+    FieldValidationErrors(Vec<FieldError>),
     
     #[error("Conflicto de datos: {0}")]
     Conflict(String),
+
+    /// La versión del recurso no coincide con la indicada por el cliente (If-Match).
+    #[error("Precondición no cumplida: {0}")]
+    PreconditionFailed(String),
     
     #[error("Error de autenticación: {0}")]
     AuthenticationError(String),
@@ -1,3 +1,3 @@
 pub mod application_error;
 
-pub use application_error::ApplicationError;
\ No newline at end of file
+pub use application_error::{ApplicationError, FieldError};
\ No newline at end of file
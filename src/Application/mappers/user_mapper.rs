@@ -26,6 +26,7 @@ impl UserMapper {
             updated_by: entity.updated_by,
             updated_at: entity.updated_at,
             status: entity.status as i32,
+            version: entity.version,
         }
     }
 
@@ -43,6 +44,7 @@ impl UserMapper {
             updated_by: None,
             updated_at: None,
             status: 1, // Active por defecto
+            version: 1,
         })
     }
 
@@ -3,6 +3,26 @@ use uuid::Uuid;
 use std::error::Error;
 use diesel_async::AsyncPgConnection; // Necesita la conexión async
 
+use crate::Domain::attribute_references::AttributeReference;
+
+/// Cambios parciales sobre un atributo (None = no se modifica).
+/// En las columnas anulables, `Some(None)` deja el valor en NULL.
+#[derive(Debug, Clone, Default)]
+pub struct AttributeChanges {
+    pub name: Option<String>,
+    pub description: Option<Option<String>>,
+    pub data_type_id: Option<Uuid>,
+    pub is_required: Option<bool>,
+    pub position: Option<i16>,
+    pub is_unique: Option<Option<i16>>,
+    pub default_value: Option<Option<String>>,
+    pub validation_regex: Option<Option<String>>,
+    pub show_option_label: Option<bool>,
+    pub is_searchable: Option<bool>,
+    pub reference: Option<Option<AttributeReference>>,
+    pub status: Option<i16>,
+}
+
 /// Driven Port: Define las operaciones de escritura para Atributos.
 /// Se espera implementación con Diesel Async dentro de una transacción UoW.
 #[async_trait]
@@ -20,6 +40,20 @@ pub trait AttributeCommandRepository: Send + Sync {
         is_unique: Option<i16>,
         default_value: Option<&str>,
         validation_regex: Option<&str>,
+        show_option_label: bool,
+        is_searchable: bool,
+        reference: Option<&AttributeReference>,
         created_by: Uuid,
     ) -> Result<Uuid, Box<dyn Error + Send + Sync>>; // Devuelve el ID del nuevo atributo
+
+    /// Actualiza un atributo de la entidad. Devuelve el número de filas afectadas
+    /// (0 si el atributo no pertenece a la entidad).
+    async fn update(
+        &self,
+        conn: &mut AsyncPgConnection,
+        entity_id: Uuid,
+        id: Uuid,
+        changes: &AttributeChanges,
+        updated_by: Uuid,
+    ) -> Result<usize, Box<dyn Error + Send + Sync>>;
 }
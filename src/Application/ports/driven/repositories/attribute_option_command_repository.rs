@@ -0,0 +1,30 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+use std::error::Error;
+use diesel_async::AsyncPgConnection;
+
+use crate::Domain::attribute_options::AttributeOption;
+use crate::Domain::records::StorageColumn;
+
+/// Driven Port: Escritura de las listas de opciones de los atributos de tipo enumeración.
+/// Se espera implementación con Diesel Async dentro de una transacción UoW.
+#[async_trait]
+pub trait AttributeOptionCommandRepository: Send + Sync {
+    /// Sustituye la lista de opciones del atributo: las opciones cuyo código no
+    /// aparece en `options` se eliminan y el resto se crean o actualizan.
+    async fn replace_all(
+        &self,
+        conn: &mut AsyncPgConnection,
+        attribute_id: Uuid,
+        options: &[AttributeOption],
+    ) -> Result<(), Box<dyn Error + Send + Sync>>;
+
+    /// Códigos almacenados en los registros para el atributo y cuántos registros
+    /// usan cada uno.
+    async fn count_values_by_code(
+        &self,
+        conn: &mut AsyncPgConnection,
+        attribute_id: Uuid,
+        column: StorageColumn,
+    ) -> Result<Vec<(String, i64)>, Box<dyn Error + Send + Sync>>;
+}
@@ -0,0 +1,93 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Utc};
+use std::error::Error;
+
+use crate::Domain::data_types::DataTypeStorage;
+use crate::Domain::attribute_options::AttributeOption;
+use crate::Domain::attribute_references::AttributeReference;
+
+/// Atributo de una entidad lógica junto con su tipo de dato: nombre y, según el
+/// registro de tipos, columna de almacenamiento, cast y parámetros. Los atributos
+/// de tipo enumeración incluyen su lista de opciones y los de tipo referencia, la
+/// entidad a la que apuntan.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AttributeDto {
+    pub id: Uuid,
+    pub entity_id: Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    pub data_type_id: Uuid,
+    pub data_type_name: String,
+    pub storage: DataTypeStorage,
+    pub is_required: bool,
+    pub position: i16,
+    pub is_unique: Option<i16>,
+    pub default_value: Option<String>,
+    pub validation_regex: Option<String>,
+    pub show_option_label: bool,
+    pub is_searchable: bool,
+    pub options: Vec<AttributeOption>,
+    pub reference: Option<AttributeReference>,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_by: Option<Uuid>,
+    pub updated_at: Option<DateTime<Utc>>,
+    pub status: i16,
+}
+
+#[cfg(test)]
+impl AttributeDto {
+    /// Atributo activo, opcional y sin restricciones con el tipo base de `column`.
+    pub(crate) fn for_tests(name: &str, column: crate::Domain::records::StorageColumn) -> Self {
+        AttributeDto {
+            id: Uuid::new_v4(),
+            entity_id: Uuid::new_v4(),
+            name: name.to_string(),
+            description: None,
+            data_type_id: Uuid::new_v4(),
+            data_type_name: column.pg_type().to_string(),
+            storage: DataTypeStorage::new(column, None, Default::default()).unwrap(),
+            is_required: false,
+            position: 0,
+            is_unique: None,
+            default_value: None,
+            validation_regex: None,
+            show_option_label: false,
+            is_searchable: false,
+            options: Vec::new(),
+            reference: None,
+            created_by: None,
+            created_at: Utc::now(),
+            updated_by: None,
+            updated_at: None,
+            status: 1,
+        }
+    }
+}
+
+/// Driven Port: Consultas de solo lectura sobre Atributos.
+/// Se espera implementación con SQLx.
+#[async_trait]
+pub trait AttributeQueryRepository: Send + Sync {
+    /// Devuelve los atributos de una entidad ordenados por `position`.
+    async fn find_by_entity_id(
+        &self,
+        entity_id: Uuid
+    ) -> Result<Vec<AttributeDto>, Box<dyn Error + Send + Sync>>;
+
+    /// Devuelve los atributos de varias entidades en una sola consulta, ordenados
+    /// por `position` dentro de cada entidad.
+    async fn find_by_entity_ids(
+        &self,
+        entity_ids: &[Uuid]
+    ) -> Result<Vec<AttributeDto>, Box<dyn Error + Send + Sync>>;
+
+    /// Devuelve los atributos (de cualquier entidad, incluida la propia) que
+    /// referencian registros de `entity_id`.
+    async fn find_referencing(
+        &self,
+        entity_id: Uuid
+    ) -> Result<Vec<AttributeDto>, Box<dyn Error + Send + Sync>>;
+}
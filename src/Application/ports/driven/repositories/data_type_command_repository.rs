@@ -0,0 +1,47 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+use std::error::Error;
+use diesel_async::AsyncPgConnection;
+
+use crate::Domain::data_types::DataTypeStorage;
+
+/// Cambios parciales sobre un tipo de dato (None = no se modifica).
+/// `storage` sustituye completa la definición de almacenamiento (columna, cast y parámetros).
+#[derive(Debug, Clone, Default)]
+pub struct DataTypeChanges {
+    pub name: Option<String>,
+    pub description: Option<Option<String>>,
+    pub storage: Option<DataTypeStorage>,
+    pub status: Option<i16>,
+}
+
+/// Driven Port: Define las operaciones de escritura del registro de tipos de datos.
+/// Se espera implementación con Diesel Async dentro de una transacción UoW.
+#[async_trait]
+pub trait DataTypeCommandRepository: Send + Sync {
+    /// Crea un tipo de dato y devuelve su ID.
+    async fn create(
+        &self,
+        conn: &mut AsyncPgConnection,
+        name: &str,
+        description: Option<&str>,
+        storage: &DataTypeStorage,
+        created_by: Uuid,
+    ) -> Result<Uuid, Box<dyn Error + Send + Sync>>;
+
+    /// Actualiza un tipo de dato. Devuelve el número de filas afectadas.
+    async fn update(
+        &self,
+        conn: &mut AsyncPgConnection,
+        id: Uuid,
+        changes: &DataTypeChanges,
+        updated_by: Uuid,
+    ) -> Result<usize, Box<dyn Error + Send + Sync>>;
+
+    /// Elimina un tipo de dato. Devuelve el número de filas afectadas.
+    async fn delete(
+        &self,
+        conn: &mut AsyncPgConnection,
+        id: Uuid,
+    ) -> Result<usize, Box<dyn Error + Send + Sync>>;
+}
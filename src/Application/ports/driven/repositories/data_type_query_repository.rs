@@ -3,6 +3,8 @@ use async_trait::async_trait;
 use uuid::Uuid;
 use std::error::Error;
 
+use crate::Domain::data_types::DataType;
+
 /// Driven Port: Define cómo buscar información de Tipos de Datos.
 /// Se espera implementación con SQLx.
 #[async_trait]
@@ -12,4 +14,29 @@ pub trait DataTypeQueryRepository: Send + Sync {
         &self,
         name: &str
     ) -> Result<Option<Uuid>, Box<dyn Error + Send + Sync>>;
+
+    /// Devuelve los tipos del registro ordenados por nombre.
+    /// Sin `include_inactive` solo se devuelven los activos (status = 1).
+    async fn find_all(
+        &self,
+        include_inactive: bool,
+    ) -> Result<Vec<DataType>, Box<dyn Error + Send + Sync>>;
+
+    /// Busca un tipo de dato por ID, sea cual sea su estado.
+    async fn find_by_id(
+        &self,
+        id: Uuid,
+    ) -> Result<Option<DataType>, Box<dyn Error + Send + Sync>>;
+
+    /// Busca un tipo de dato por nombre (case-insensitive), sea cual sea su estado.
+    async fn find_by_name(
+        &self,
+        name: &str,
+    ) -> Result<Option<DataType>, Box<dyn Error + Send + Sync>>;
+
+    /// IDs de las entidades con algún atributo (activo o retirado) de este tipo.
+    async fn find_entity_ids_using(
+        &self,
+        id: Uuid,
+    ) -> Result<Vec<Uuid>, Box<dyn Error + Send + Sync>>;
 }
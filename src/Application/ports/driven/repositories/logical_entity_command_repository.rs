@@ -15,6 +15,62 @@ pub trait LogicalEntityCommandRepository: Send + Sync {
         created_by: Uuid,
     ) -> Result<Uuid, Box<dyn Error + Send + Sync>>;
 
-    // async fn update(...) -> Result<(), Box<dyn Error + Send + Sync>>; // Para futuras implementaciones
-    // async fn delete(...) -> Result<(), Box<dyn Error + Send + Sync>>; // Para futuras implementaciones
+    /// Actualiza los campos presentes (None = no se modifica).
+    /// Devuelve el número de filas afectadas (0 si la entidad no existe).
+    async fn update(
+        &self,
+        conn: &mut AsyncPgConnection,
+        id: Uuid,
+        name: Option<&str>,
+        description: Option<&str>,
+        search_language: Option<&str>,
+        status: Option<i16>,
+        updated_by: Uuid,
+    ) -> Result<usize, Box<dyn Error + Send + Sync>>;
+
+    /// Registra (o borra con None) el nombre de la vista generada para la entidad.
+    /// Devuelve el número de filas afectadas (0 si la entidad no existe).
+    async fn set_assign_view(
+        &self,
+        conn: &mut AsyncPgConnection,
+        id: Uuid,
+        view_name: Option<&str>,
+    ) -> Result<usize, Box<dyn Error + Send + Sync>>;
+
+    /// Ejecuta en la transacción el DDL `view_sql` de la vista de la entidad y registra
+    /// `view_name` como su vista, de modo que un fallo del DDL revierte también los metadatos.
+    async fn create_view(
+        &self,
+        conn: &mut AsyncPgConnection,
+        id: Uuid,
+        view_name: &str,
+        view_sql: &str,
+    ) -> Result<usize, Box<dyn Error + Send + Sync>>;
+
+    /// Mueve la entidad a la papelera (deleted_at / deleted_by). Sus atributos y
+    /// registros se conservan hasta la purga.
+    /// Devuelve el número de filas afectadas (0 si no existe o ya está en la papelera).
+    async fn soft_delete(
+        &self,
+        conn: &mut AsyncPgConnection,
+        id: Uuid,
+        deleted_by: Uuid,
+    ) -> Result<usize, Box<dyn Error + Send + Sync>>;
+
+    /// Saca la entidad de la papelera.
+    /// Devuelve el número de filas afectadas (0 si no está en la papelera).
+    async fn restore(
+        &self,
+        conn: &mut AsyncPgConnection,
+        id: Uuid,
+    ) -> Result<usize, Box<dyn Error + Send + Sync>>;
+
+    /// Elimina definitivamente una entidad de la papelera (los atributos y las
+    /// tuplas se eliminan por ON DELETE CASCADE).
+    /// Devuelve el número de filas afectadas (0 si no está en la papelera).
+    async fn delete(
+        &self,
+        conn: &mut AsyncPgConnection,
+        id: Uuid,
+    ) -> Result<usize, Box<dyn Error + Send + Sync>>;
 }
@@ -5,12 +5,18 @@ use chrono::{DateTime, Utc};
 // TODO: Reemplazar Box<dyn Error> con un enum de error de repositorio específico
 use std::error::Error;
 
+use crate::Domain::physical_tables::StorageMode;
+
 #[derive(Debug, Serialize, Deserialize, Clone)] // La implementación añadirá sqlx::FromRow
 pub struct LogicalEntityDto {
     pub id: Uuid,
     pub name: String,
     pub description: Option<String>,
     pub assign_view: Option<String>,
+    /// Configuración de búsqueda de texto de Postgres (`simple`, `english`, `spanish`...).
+    pub search_language: String,
+    /// Dónde se guardan los valores de sus registros (EAV o tabla física).
+    pub storage_mode: StorageMode,
     pub created_by: Option<Uuid>,
     pub created_at: DateTime<Utc>, // Ajustar si SQLx mapea a NaiveDateTime
     pub updated_by: Option<Uuid>,
@@ -18,13 +24,93 @@ pub struct LogicalEntityDto {
     pub status: i16,
 }
 
+#[cfg(test)]
+impl LogicalEntityDto {
+    /// Entidad activa con almacenamiento EAV y sin vista.
+    pub(crate) fn for_tests(name: &str) -> Self {
+        LogicalEntityDto {
+            id: Uuid::new_v4(),
+            name: name.to_string(),
+            description: None,
+            assign_view: None,
+            search_language: "simple".to_string(),
+            storage_mode: StorageMode::Eav,
+            created_by: None,
+            created_at: Utc::now(),
+            updated_by: None,
+            updated_at: None,
+            status: 1,
+        }
+    }
+}
+
+/// Entidad lógica de la papelera: quién la eliminó y cuándo.
+#[derive(Debug, Clone)]
+pub struct DeletedLogicalEntityDto {
+    pub entity: LogicalEntityDto,
+    pub deleted_at: DateTime<Utc>,
+    pub deleted_by: Option<Uuid>,
+}
+
+/// Salvo los métodos de la papelera, las consultas excluyen las entidades
+/// eliminadas (`deleted_at IS NOT NULL`).
 #[async_trait]
 pub trait LogicalEntityQueryRepository: Send + Sync {
+    /// Indica si el nombre está en uso por una entidad que no está en la papelera.
+    /// Las entidades de la papelera no reservan su nombre (índice único parcial
+    /// `idx_logical_entities_active_name`); al restaurarlas se comprueba que siga libre.
     async fn exists_by_name(
         &self,
         name: &str
     ) -> Result<bool, Box<dyn Error + Send + Sync>>;
 
-    // async fn find_by_id(...) -> Result<Option<LogicalEntityDto>, Box<dyn Error + Send + Sync>>; // Para futuras implementaciones
-    // async fn find_all(...) -> Result<Vec<LogicalEntityDto>, Box<dyn Error + Send + Sync>>; // Para futuras implementaciones
+    /// Busca una entidad lógica por su ID.
+    async fn find_by_id(
+        &self,
+        id: Uuid
+    ) -> Result<Option<LogicalEntityDto>, Box<dyn Error + Send + Sync>>;
+
+    /// Busca una entidad lógica por su nombre exacto.
+    async fn find_by_name(
+        &self,
+        name: &str
+    ) -> Result<Option<LogicalEntityDto>, Box<dyn Error + Send + Sync>>;
+
+    /// Lista las entidades lógicas ordenadas por nombre (paginado con limit/offset).
+    async fn find_all(
+        &self,
+        limit: i64,
+        offset: i64
+    ) -> Result<Vec<LogicalEntityDto>, Box<dyn Error + Send + Sync>>;
+
+    /// Cuenta el total de entidades lógicas (para la paginación).
+    async fn count_all(&self) -> Result<i64, Box<dyn Error + Send + Sync>>;
+
+    /// Huella de los metadatos que definen la forma de los registros (entidades,
+    /// atributos y tipos de datos). Cambia cada vez que se modifica alguno de ellos;
+    /// permite invalidar lo que se genera a partir de los metadatos (p. ej. el esquema GraphQL).
+    async fn metadata_fingerprint(&self) -> Result<String, Box<dyn Error + Send + Sync>>;
+
+    /// Entidades lógicas de la papelera, de la más reciente a la más antigua.
+    async fn find_deleted(
+        &self,
+        limit: i64,
+        offset: i64
+    ) -> Result<Vec<DeletedLogicalEntityDto>, Box<dyn Error + Send + Sync>>;
+
+    /// Cuenta las entidades lógicas de la papelera (para la paginación).
+    async fn count_deleted(&self) -> Result<i64, Box<dyn Error + Send + Sync>>;
+
+    /// Busca una entidad lógica de la papelera por su ID.
+    async fn find_deleted_by_id(
+        &self,
+        id: Uuid
+    ) -> Result<Option<DeletedLogicalEntityDto>, Box<dyn Error + Send + Sync>>;
+
+    /// IDs de las entidades lógicas eliminadas antes de `deleted_before`,
+    /// de la más antigua a la más reciente.
+    async fn find_deleted_before(
+        &self,
+        deleted_before: DateTime<Utc>
+    ) -> Result<Vec<Uuid>, Box<dyn Error + Send + Sync>>;
 }
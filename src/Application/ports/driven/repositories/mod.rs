@@ -1,18 +1,46 @@
 // --- User Repositories ---
 pub mod users_repositories;
 // --- AJUSTADO: Exportar solo los traits CQRS ---
-pub use users_repositories::{UserCommandRepository, UserQueryRepository};
+pub use users_repositories::{UserCommandRepository, UserQueryRepository, DeletedUserDto};
 
 // --- Logical Entity Repositories ---
 pub mod logical_entity_command_repository;
 pub mod logical_entity_query_repository;
 pub use logical_entity_command_repository::LogicalEntityCommandRepository;
-pub use logical_entity_query_repository::{LogicalEntityQueryRepository, LogicalEntityDto};
+pub use logical_entity_query_repository::{LogicalEntityQueryRepository, LogicalEntityDto, DeletedLogicalEntityDto};
 
 // --- Attribute Repository ---
 pub mod attribute_command_repository;
-pub use attribute_command_repository::AttributeCommandRepository;
+pub use attribute_command_repository::{AttributeCommandRepository, AttributeChanges};
+pub mod attribute_query_repository;
+pub use attribute_query_repository::{AttributeQueryRepository, AttributeDto};
+pub mod attribute_option_command_repository;
+pub use attribute_option_command_repository::AttributeOptionCommandRepository;
 
 // --- DataType Repository ---
+pub mod data_type_command_repository;
 pub mod data_type_query_repository;
+pub use data_type_command_repository::{DataTypeCommandRepository, DataTypeChanges};
 pub use data_type_query_repository::DataTypeQueryRepository;
+
+// --- Record Repositories ---
+pub mod record_command_repository;
+pub mod record_query_repository;
+pub use record_command_repository::{
+    RecordCommandRepository, AttributeValueMatch, AttributeValueWrite, StoredAttributeValue, ValueConversionCheck, ReferencingTuple, TupleAttributeValue,
+    NewRecordHistoryDto,
+};
+pub use record_query_repository::{
+    RecordQueryRepository, RecordDto, KeyedRecordDto, DeletedRecordDto, RecordSearchHitDto, ExportRecordDto,
+    RecordHistoryEntryDto,
+};
+pub mod record_batch_repository;
+pub use record_batch_repository::{
+    RecordBatchRepository, NewRecordDto, UniqueKeyValuesDto, ReferencedTuplesDto, BatchInsertOutcome,
+};
+
+// --- Schema Version Repositories ---
+pub mod schema_version_command_repository;
+pub mod schema_version_query_repository;
+pub use schema_version_command_repository::SchemaVersionCommandRepository;
+pub use schema_version_query_repository::{SchemaVersionQueryRepository, SchemaVersionDto};
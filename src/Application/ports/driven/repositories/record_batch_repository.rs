@@ -0,0 +1,70 @@
+use async_trait::async_trait;
+use std::collections::HashSet;
+use std::error::Error;
+use uuid::Uuid;
+
+use super::record_command_repository::AttributeValueMatch;
+use crate::Domain::record_history::ChangeContext;
+
+/// Registro nuevo para la inserción en lote: sus valores no nulos ya convertidos
+/// al texto de su columna de almacenamiento.
+#[derive(Debug, Clone)]
+pub struct NewRecordDto {
+    pub values: Vec<AttributeValueMatch>,
+}
+
+/// Valores de un grupo de unicidad (todos los atributos de la clave con valor).
+#[derive(Debug, Clone)]
+pub struct UniqueKeyValuesDto {
+    pub group: i16,
+    pub matches: Vec<AttributeValueMatch>,
+}
+
+/// Tuplas de una entidad referenciadas por los registros a insertar.
+#[derive(Debug, Clone)]
+pub struct ReferencedTuplesDto {
+    pub entity_id: Uuid,
+    pub ids: Vec<Uuid>,
+}
+
+/// Resultado de la inserción en lote.
+#[derive(Debug, Clone, PartialEq)]
+pub enum BatchInsertOutcome {
+    /// IDs de las tuplas creadas, en el orden de los registros.
+    Inserted(Vec<Uuid>),
+    /// Entre la validación y la inserción otro proceso creó un duplicado o eliminó
+    /// una tupla referenciada; no se insertó nada.
+    Conflict(String),
+}
+
+/// Driven Port: operaciones en lote sobre los registros de una entidad.
+/// Se espera implementación con SQLx (inserciones de varias filas por sentencia).
+#[async_trait]
+pub trait RecordBatchRepository: Send + Sync {
+    /// Devuelve las posiciones de `keys` cuyos valores ya usa algún registro de la entidad.
+    async fn find_existing_keys(
+        &self,
+        entity_id: Uuid,
+        keys: &[UniqueKeyValuesDto]
+    ) -> Result<HashSet<usize>, Box<dyn Error + Send + Sync>>;
+
+    /// Devuelve los IDs de `ids` que existen como tuplas de la entidad.
+    async fn find_existing_tuples(
+        &self,
+        entity_id: Uuid,
+        ids: &[Uuid]
+    ) -> Result<HashSet<Uuid>, Box<dyn Error + Send + Sync>>;
+
+    /// Inserta los registros en una única transacción. Antes bloquea los grupos de
+    /// unicidad y las tuplas referenciadas y vuelve a comprobar `unique_keys` y
+    /// `references`: si alguna comprobación falla, no inserta nada. En la misma
+    /// transacción registra la creación de cada registro en el historial (`context`).
+    async fn insert_records(
+        &self,
+        entity_id: Uuid,
+        records: &[NewRecordDto],
+        unique_keys: &[UniqueKeyValuesDto],
+        references: &[ReferencedTuplesDto],
+        context: &ChangeContext
+    ) -> Result<BatchInsertOutcome, Box<dyn Error + Send + Sync>>;
+}
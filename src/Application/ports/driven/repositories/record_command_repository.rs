@@ -0,0 +1,297 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+use std::error::Error;
+use chrono::{DateTime, Utc};
+use diesel_async::AsyncPgConnection;
+
+use crate::Domain::records::StorageColumn;
+use crate::Domain::data_types::DataTypeStorage;
+use crate::Domain::attribute_options::AttributeOption;
+use crate::Domain::record_history::RecordChangeKind;
+use crate::Domain::physical_tables::StorageMode;
+
+/// Condición "el atributo tiene este valor" usada en las comprobaciones de unicidad.
+#[derive(Debug, Clone)]
+pub struct AttributeValueMatch {
+    pub attribute_id: Uuid,
+    pub column: StorageColumn,
+    pub value: String,
+}
+
+/// Valor a escribir para un atributo: `None` elimina el valor almacenado.
+#[derive(Debug, Clone)]
+pub struct AttributeValueWrite {
+    pub attribute_id: Uuid,
+    pub column: StorageColumn,
+    pub value: Option<String>,
+}
+
+/// Valor almacenado de un atributo en una tupla, leído como JSON.
+#[derive(Debug, Clone)]
+pub struct StoredAttributeValue {
+    pub instance_id: Uuid,
+    pub value: serde_json::Value,
+}
+
+/// Comprobación, en la base de datos, de la conversión de los valores de un atributo a
+/// otro tipo de dato.
+#[derive(Debug, Clone)]
+pub struct ValueConversionCheck {
+    /// Valores que se pueden convertir.
+    pub convertible: usize,
+    /// Valores que no se pueden convertir o no cumplen el nuevo tipo.
+    pub failed: usize,
+    /// Primeros valores que fallan, ordenados por tupla.
+    pub failed_sample: Vec<StoredAttributeValue>,
+}
+
+/// Valor de un atributo de una tupla, leído como JSON, con el nombre actual del atributo.
+#[derive(Debug, Clone)]
+pub struct TupleAttributeValue {
+    pub attribute_id: Uuid,
+    pub attribute_name: String,
+    pub value: serde_json::Value,
+}
+
+/// Entrada nueva del historial de un registro. `attribute_id` / `attribute_name`
+/// son `None` en los eventos de la tupla (creación, eliminación, restauración, purga).
+#[derive(Debug, Clone)]
+pub struct NewRecordHistoryDto {
+    pub entity_id: Uuid,
+    pub tuple_id: Uuid,
+    pub attribute_id: Option<Uuid>,
+    pub attribute_name: Option<String>,
+    pub change_kind: RecordChangeKind,
+    pub old_value: Option<serde_json::Value>,
+    pub new_value: Option<serde_json::Value>,
+    pub changed_by: Option<Uuid>,
+    pub request_id: Option<String>,
+}
+
+/// Tupla que referencia a otra a través de un atributo. `deleted_at` indica si
+/// está en la papelera.
+#[derive(Debug, Clone)]
+pub struct ReferencingTuple {
+    pub id: Uuid,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+/// Driven Port: Escritura de registros (tuplas) y de sus valores.
+/// Los valores se guardan en `attribute_values` o en la tabla física de la entidad según
+/// su modo de almacenamiento; los métodos de valores lo resuelven internamente.
+/// Se espera implementación con Diesel Async dentro de la UoW.
+#[async_trait]
+pub trait RecordCommandRepository: Send + Sync {
+    /// Crea la tupla de una entidad y devuelve su ID.
+    async fn create_tuple(
+        &self,
+        conn: &mut AsyncPgConnection,
+        entity_id: Uuid,
+        created_by: Option<Uuid>,
+    ) -> Result<Uuid, Box<dyn Error + Send + Sync>>;
+
+    /// Marca la tupla como modificada (updated_by / updated_at) e incrementa su versión.
+    /// Devuelve el número de filas afectadas (0 si la tupla no existe en la entidad
+    /// o está en la papelera).
+    async fn touch_tuple(
+        &self,
+        conn: &mut AsyncPgConnection,
+        entity_id: Uuid,
+        id: Uuid,
+        updated_by: Option<Uuid>,
+    ) -> Result<usize, Box<dyn Error + Send + Sync>>;
+
+    /// Versión de una tupla viva, bloqueándola (FOR UPDATE) hasta el fin de la
+    /// transacción (comprobación de If-Match). `None` si no existe o está en la papelera.
+    async fn lock_tuple_version(
+        &self,
+        conn: &mut AsyncPgConnection,
+        entity_id: Uuid,
+        id: Uuid,
+    ) -> Result<Option<i32>, Box<dyn Error + Send + Sync>>;
+
+    /// Mueve la tupla a la papelera (deleted_at = hora de la transacción, deleted_by).
+    /// Devuelve el número de filas afectadas (0 si no existe o ya está en la papelera).
+    async fn soft_delete_tuple(
+        &self,
+        conn: &mut AsyncPgConnection,
+        entity_id: Uuid,
+        id: Uuid,
+        deleted_by: Option<Uuid>,
+    ) -> Result<usize, Box<dyn Error + Send + Sync>>;
+
+    /// Fecha de eliminación de una tupla de la papelera, bloqueándola (FOR UPDATE)
+    /// hasta el fin de la transacción. `None` si no existe o no está en la papelera.
+    async fn lock_deleted_tuple(
+        &self,
+        conn: &mut AsyncPgConnection,
+        entity_id: Uuid,
+        id: Uuid,
+    ) -> Result<Option<DateTime<Utc>>, Box<dyn Error + Send + Sync>>;
+
+    /// Saca la tupla de la papelera si se eliminó en `deleted_at`.
+    /// Devuelve el número de filas afectadas.
+    async fn restore_tuple(
+        &self,
+        conn: &mut AsyncPgConnection,
+        entity_id: Uuid,
+        id: Uuid,
+        deleted_at: DateTime<Utc>,
+    ) -> Result<usize, Box<dyn Error + Send + Sync>>;
+
+    /// Elimina definitivamente la tupla (los valores se eliminan por ON DELETE CASCADE).
+    /// Devuelve el número de filas afectadas (0 si la tupla no existe en la entidad).
+    async fn delete_tuple(
+        &self,
+        conn: &mut AsyncPgConnection,
+        entity_id: Uuid,
+        id: Uuid,
+    ) -> Result<usize, Box<dyn Error + Send + Sync>>;
+
+    /// Indica si la tupla existe en la entidad (fuera de la papelera) y la bloquea (FOR KEY SHARE) hasta el
+    /// fin de la transacción, de modo que no pueda eliminarse mientras se guarda una
+    /// referencia a ella.
+    async fn lock_tuple_if_exists(
+        &self,
+        conn: &mut AsyncPgConnection,
+        entity_id: Uuid,
+        id: Uuid,
+    ) -> Result<bool, Box<dyn Error + Send + Sync>>;
+
+    /// Tuplas (incluidas las de la papelera) cuyo valor del atributo (de tipo
+    /// referencia) es `referenced_id`. Bloquea los valores encontrados hasta el
+    /// fin de la transacción.
+    async fn find_tuples_referencing(
+        &self,
+        conn: &mut AsyncPgConnection,
+        attribute_id: Uuid,
+        referenced_id: Uuid,
+    ) -> Result<Vec<ReferencingTuple>, Box<dyn Error + Send + Sync>>;
+
+    /// Inserta o reemplaza el valor de un atributo en su columna tipada.
+    /// `value` es la representación de texto que Postgres convierte al tipo de la columna.
+    async fn upsert_value(
+        &self,
+        conn: &mut AsyncPgConnection,
+        instance_id: Uuid,
+        attribute_id: Uuid,
+        column: StorageColumn,
+        value: &str,
+    ) -> Result<(), Box<dyn Error + Send + Sync>>;
+
+    /// Elimina el valor de un atributo de la tupla (queda como NULL).
+    async fn delete_value(
+        &self,
+        conn: &mut AsyncPgConnection,
+        instance_id: Uuid,
+        attribute_id: Uuid,
+    ) -> Result<usize, Box<dyn Error + Send + Sync>>;
+
+    /// Aplica a la vez varias escrituras sobre una tupla de la entidad. En una tabla
+    /// física se escriben en una sola fila, de modo que las restricciones NOT NULL se
+    /// comprueban con todos los valores del registro.
+    async fn write_values(
+        &self,
+        conn: &mut AsyncPgConnection,
+        entity_id: Uuid,
+        instance_id: Uuid,
+        writes: &[AttributeValueWrite],
+    ) -> Result<(), Box<dyn Error + Send + Sync>>;
+
+    /// Bloquea un grupo de unicidad de la entidad hasta el fin de la transacción,
+    /// serializando las comprobaciones concurrentes sobre la misma clave compuesta.
+    async fn lock_uniqueness_group(
+        &self,
+        conn: &mut AsyncPgConnection,
+        entity_id: Uuid,
+        group: i16,
+    ) -> Result<(), Box<dyn Error + Send + Sync>>;
+
+    /// Indica si otra tupla de la entidad (distinta de `exclude_id`) cumple todas las condiciones.
+    /// Cuenta también las tuplas de la papelera: sus valores siguen reservados hasta la purga.
+    async fn exists_with_values(
+        &self,
+        conn: &mut AsyncPgConnection,
+        entity_id: Uuid,
+        exclude_id: Option<Uuid>,
+        matches: &[AttributeValueMatch],
+    ) -> Result<bool, Box<dyn Error + Send + Sync>>;
+
+    /// Comprueba (bloqueando las filas, sin modificarlas) la conversión de los valores del
+    /// atributo de `from_column` al tipo `to` con las reglas de `value_conversion_sql`.
+    /// La muestra de valores que fallan se limita a `sample_size`.
+    async fn check_value_conversion(
+        &self,
+        conn: &mut AsyncPgConnection,
+        attribute_id: Uuid,
+        from_column: StorageColumn,
+        to: &DataTypeStorage,
+        options: &[AttributeOption],
+        sample_size: usize,
+    ) -> Result<ValueConversionCheck, Box<dyn Error + Send + Sync>>;
+
+    /// Elimina los valores del atributo que no se pueden convertir al tipo `to` y pasa el
+    /// resto a su columna, cada paso en una sola sentencia. Devuelve (convertidos, descartados).
+    async fn convert_values(
+        &self,
+        conn: &mut AsyncPgConnection,
+        attribute_id: Uuid,
+        from_column: StorageColumn,
+        to: &DataTypeStorage,
+        options: &[AttributeOption],
+    ) -> Result<(usize, usize), Box<dyn Error + Send + Sync>>;
+
+    /// Asigna el mismo valor a un atributo en todas las tuplas de la entidad que no lo tengan.
+    /// Devuelve el número de valores insertados.
+    async fn backfill_value(
+        &self,
+        conn: &mut AsyncPgConnection,
+        entity_id: Uuid,
+        attribute_id: Uuid,
+        column: StorageColumn,
+        value: &str,
+    ) -> Result<usize, Box<dyn Error + Send + Sync>>;
+
+    /// Lee (bloqueando las filas) los valores almacenados de una tupla, en la
+    /// representación JSON de la API. Usado para registrar el historial.
+    async fn find_values_by_tuple(
+        &self,
+        conn: &mut AsyncPgConnection,
+        instance_id: Uuid,
+    ) -> Result<Vec<TupleAttributeValue>, Box<dyn Error + Send + Sync>>;
+
+    /// Lleva la tabla física de la entidad al estado que describen sus atributos
+    /// (columnas, NOT NULL, UNIQUE y CHECK), leídos dentro de la transacción.
+    /// No hace nada si la entidad guarda sus valores en EAV. Devuelve el número de
+    /// sentencias aplicadas.
+    async fn sync_physical_table(
+        &self,
+        conn: &mut AsyncPgConnection,
+        entity_id: Uuid,
+    ) -> Result<usize, Box<dyn Error + Send + Sync>>;
+
+    /// Cambia el modo de almacenamiento de la entidad moviendo sus valores (también los
+    /// de los registros en la papelera) entre `attribute_values` y su tabla física.
+    /// Las escrituras de registros de la entidad esperan a que termine la transacción.
+    /// Devuelve el número de filas copiadas (0 si la entidad ya estaba en ese modo).
+    async fn change_storage_mode(
+        &self,
+        conn: &mut AsyncPgConnection,
+        entity_id: Uuid,
+        mode: StorageMode,
+    ) -> Result<usize, Box<dyn Error + Send + Sync>>;
+
+    /// Elimina la tabla física de la entidad, si tiene (purga de la entidad).
+    async fn drop_physical_table(
+        &self,
+        conn: &mut AsyncPgConnection,
+        entity_id: Uuid,
+    ) -> Result<(), Box<dyn Error + Send + Sync>>;
+
+    /// Añade entradas al historial de registros (en el orden recibido).
+    async fn insert_history(
+        &self,
+        conn: &mut AsyncPgConnection,
+        entries: &[NewRecordHistoryDto],
+    ) -> Result<(), Box<dyn Error + Send + Sync>>;
+}
@@ -0,0 +1,225 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use chrono::{DateTime, Utc};
+use futures::stream::BoxStream;
+use std::collections::HashMap;
+use std::error::Error;
+
+use crate::Domain::record_queries::{RecordQuery, RecordSearch, ResolvedFilter, SortKey};
+use crate::Domain::record_history::RecordChangeKind;
+use crate::Domain::records::StorageColumn;
+
+/// Registro (tupla) de una entidad con sus valores indexados por nombre de atributo.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct RecordDto {
+    pub id: Uuid,
+    pub entity_id: Uuid,
+    pub values: Map<String, Value>,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_by: Option<Uuid>,
+    pub updated_at: Option<DateTime<Utc>>,
+    pub status: i16,
+    /// Contador de versión (ETag del registro).
+    pub version: i32,
+}
+
+#[cfg(test)]
+impl RecordDto {
+    /// Registro activo de la entidad, sin valores, en la versión indicada.
+    pub(crate) fn for_tests(entity_id: Uuid, version: i32) -> Self {
+        RecordDto {
+            id: Uuid::new_v4(),
+            entity_id,
+            values: Map::new(),
+            created_by: None,
+            created_at: Utc::now(),
+            updated_by: None,
+            updated_at: None,
+            status: 1,
+            version,
+        }
+    }
+}
+
+/// Registro de una página junto con los valores de sus claves de ordenación
+/// (como texto de Postgres), necesarios para construir el cursor siguiente.
+#[derive(Debug, Clone)]
+pub struct KeyedRecordDto {
+    pub record: RecordDto,
+    pub sort_values: Vec<Option<String>>,
+}
+
+/// Registro de la papelera: quién lo eliminó y cuándo.
+#[derive(Debug, Clone)]
+pub struct DeletedRecordDto {
+    pub record: RecordDto,
+    pub deleted_at: DateTime<Utc>,
+    pub deleted_by: Option<Uuid>,
+}
+
+/// Resultado de la búsqueda de texto: el registro, su relevancia y, por cada
+/// atributo que coincide, un fragmento con los términos marcados (`<mark>...</mark>`).
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct RecordSearchHitDto {
+    pub record: RecordDto,
+    pub rank: f32,
+    pub highlights: Map<String, Value>,
+}
+
+/// Registro para exportación: los valores de los atributos como el texto que
+/// produce Postgres para su columna (numéricos sin pérdida de precisión, fechas
+/// en ISO 8601, JSON como documento y binarios en base64).
+#[derive(Debug, Clone)]
+pub struct ExportRecordDto {
+    pub id: Uuid,
+    pub values: HashMap<String, String>,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_by: Option<Uuid>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+/// Entrada del historial de un registro. `id` crece con cada entrada escrita.
+#[derive(Debug, Clone)]
+pub struct RecordHistoryEntryDto {
+    pub id: i64,
+    pub tuple_id: Uuid,
+    pub attribute_id: Option<Uuid>,
+    pub attribute_name: Option<String>,
+    pub change_kind: RecordChangeKind,
+    pub old_value: Option<Value>,
+    pub new_value: Option<Value>,
+    pub changed_by: Option<Uuid>,
+    pub changed_at: DateTime<Utc>,
+    pub request_id: Option<String>,
+}
+
+/// Driven Port: Consultas de solo lectura sobre registros.
+/// Se espera implementación con SQLx. Salvo los métodos de la papelera, las
+/// consultas excluyen los registros eliminados (`deleted_at IS NOT NULL`).
+#[async_trait]
+pub trait RecordQueryRepository: Send + Sync {
+    /// Busca un registro de la entidad por su ID.
+    async fn find_by_id(
+        &self,
+        entity_id: Uuid,
+        id: Uuid
+    ) -> Result<Option<RecordDto>, Box<dyn Error + Send + Sync>>;
+
+    /// Busca varios registros de la entidad en una sola consulta (carga por lotes
+    /// de referencias). Los IDs inexistentes se omiten; el orden no está garantizado.
+    async fn find_by_ids(
+        &self,
+        entity_id: Uuid,
+        ids: &[Uuid]
+    ) -> Result<Vec<RecordDto>, Box<dyn Error + Send + Sync>>;
+
+    /// Devuelve hasta `query.limit` registros de la entidad que cumplen el filtro,
+    /// en el orden de la consulta y a continuación del cursor (paginación por clave).
+    async fn find_page(
+        &self,
+        entity_id: Uuid,
+        query: &RecordQuery
+    ) -> Result<Vec<KeyedRecordDto>, Box<dyn Error + Send + Sync>>;
+
+    /// Busca registros de la entidad por texto en sus atributos buscables, ordenados
+    /// por relevancia. Devuelve hasta `search.limit` resultados a continuación del cursor.
+    async fn search(
+        &self,
+        entity_id: Uuid,
+        search: &RecordSearch
+    ) -> Result<Vec<RecordSearchHitDto>, Box<dyn Error + Send + Sync>>;
+
+    /// Recorre todos los registros de la entidad que cumplen el filtro, en el orden de
+    /// `sort` y después por `id`, con un cursor del servidor sobre una misma instantánea.
+    /// Cada elemento es un lote de hasta `batch_size` registros; el cursor se cierra
+    /// al terminar o al descartar el stream.
+    fn export(
+        &self,
+        entity_id: Uuid,
+        filter: Option<&ResolvedFilter>,
+        sort: &[SortKey],
+        batch_size: usize
+    ) -> BoxStream<'static, Result<Vec<ExportRecordDto>, Box<dyn Error + Send + Sync>>>;
+
+    /// Cuenta los registros de la entidad, sin los de la papelera.
+    async fn count_by_entity(&self, entity_id: Uuid) -> Result<i64, Box<dyn Error + Send + Sync>>;
+
+    /// Cuenta los registros de la entidad sin valor en el atributo (guardado en `column`).
+    async fn count_missing_values(
+        &self,
+        entity_id: Uuid,
+        attribute_id: Uuid,
+        column: StorageColumn
+    ) -> Result<i64, Box<dyn Error + Send + Sync>>;
+
+    /// Cuenta los registros de la entidad cuyo valor del atributo (como el texto que
+    /// produce Postgres para su columna `column`) no cumple la expresión completa
+    /// `pattern`. La comparación se hace en la base de datos con las expresiones regulares
+    /// de Postgres, que no coinciden del todo con las de `regex` que validan las escrituras:
+    /// el recuento es aproximado. None si Postgres no admite la expresión.
+    async fn count_pattern_mismatches(
+        &self,
+        entity_id: Uuid,
+        attribute_id: Uuid,
+        column: StorageColumn,
+        pattern: &str
+    ) -> Result<Option<i64>, Box<dyn Error + Send + Sync>>;
+
+    /// Cuenta los registros de la entidad (sin los de la papelera) que comparten con otro
+    /// la combinación de valores de `key` (pares atributo / columna). Los registros con
+    /// algún valor NULL en la clave no cuentan.
+    async fn count_duplicated_values(
+        &self,
+        entity_id: Uuid,
+        key: &[(Uuid, StorageColumn)]
+    ) -> Result<i64, Box<dyn Error + Send + Sync>>;
+
+    /// Registros de la papelera de la entidad, del más reciente al más antiguo.
+    async fn find_deleted(
+        &self,
+        entity_id: Uuid,
+        limit: i64,
+        offset: i64
+    ) -> Result<Vec<DeletedRecordDto>, Box<dyn Error + Send + Sync>>;
+
+    /// Cuenta los registros de la papelera de la entidad (para la paginación).
+    async fn count_deleted(&self, entity_id: Uuid) -> Result<i64, Box<dyn Error + Send + Sync>>;
+
+    /// Busca un registro de la papelera de la entidad por su ID.
+    async fn find_deleted_by_id(
+        &self,
+        entity_id: Uuid,
+        id: Uuid
+    ) -> Result<Option<DeletedRecordDto>, Box<dyn Error + Send + Sync>>;
+
+    /// Pares (entity_id, id) de los registros de cualquier entidad eliminados antes
+    /// de `deleted_before`, del más antiguo al más reciente.
+    async fn find_deleted_before(
+        &self,
+        deleted_before: DateTime<Utc>
+    ) -> Result<Vec<(Uuid, Uuid)>, Box<dyn Error + Send + Sync>>;
+
+    /// Historial de un registro de la entidad (también de los purgados), de la
+    /// entrada más reciente a la más antigua. Con `before_id`, solo las entradas
+    /// anteriores a esa.
+    async fn find_history(
+        &self,
+        entity_id: Uuid,
+        tuple_id: Uuid,
+        limit: i64,
+        before_id: Option<i64>
+    ) -> Result<Vec<RecordHistoryEntryDto>, Box<dyn Error + Send + Sync>>;
+
+    /// Entradas del historial de un registro posteriores a `since`, de la más
+    /// reciente a la más antigua.
+    async fn find_history_since(
+        &self,
+        entity_id: Uuid,
+        tuple_id: Uuid,
+        since: DateTime<Utc>
+    ) -> Result<Vec<RecordHistoryEntryDto>, Box<dyn Error + Send + Sync>>;
+}
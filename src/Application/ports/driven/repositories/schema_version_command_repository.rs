@@ -0,0 +1,20 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+use std::error::Error;
+use diesel_async::AsyncPgConnection;
+
+/// Driven Port: Registro de versiones de la definición de una entidad.
+/// Se espera implementación con Diesel Async dentro de la UoW, para que la
+/// instantánea refleje los cambios de la misma transacción.
+#[async_trait]
+pub trait SchemaVersionCommandRepository: Send + Sync {
+    /// Guarda una instantánea de la definición actual de la entidad (nombre y atributos)
+    /// con el siguiente número de versión y lo devuelve.
+    async fn create_snapshot(
+        &self,
+        conn: &mut AsyncPgConnection,
+        entity_id: Uuid,
+        change_summary: Option<&str>,
+        created_by: Uuid,
+    ) -> Result<i32, Box<dyn Error + Send + Sync>>;
+}
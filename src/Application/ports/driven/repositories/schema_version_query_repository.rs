@@ -0,0 +1,44 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Utc};
+use std::error::Error;
+
+use crate::Domain::schema_versions::SchemaSnapshot;
+
+/// Versión numerada de la definición de una entidad.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SchemaVersionDto {
+    pub id: Uuid,
+    pub entity_id: Uuid,
+    pub version: i32,
+    pub definition: SchemaSnapshot,
+    pub change_summary: Option<String>,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Driven Port: Consultas de solo lectura sobre las versiones de una entidad.
+/// Se espera implementación con SQLx.
+#[async_trait]
+pub trait SchemaVersionQueryRepository: Send + Sync {
+    /// Versiones de la entidad, de la más reciente a la más antigua.
+    async fn find_by_entity_id(
+        &self,
+        entity_id: Uuid
+    ) -> Result<Vec<SchemaVersionDto>, Box<dyn Error + Send + Sync>>;
+
+    /// Busca una versión concreta de la entidad.
+    async fn find_version(
+        &self,
+        entity_id: Uuid,
+        version: i32
+    ) -> Result<Option<SchemaVersionDto>, Box<dyn Error + Send + Sync>>;
+
+    /// Última versión creada en o antes de `at` (definición vigente en esa fecha).
+    async fn find_as_of(
+        &self,
+        entity_id: Uuid,
+        at: DateTime<Utc>
+    ) -> Result<Option<SchemaVersionDto>, Box<dyn Error + Send + Sync>>;
+}
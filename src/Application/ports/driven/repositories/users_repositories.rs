@@ -2,8 +2,19 @@ use anyhow::Result;
 use uuid::Uuid;
 use async_trait::async_trait;
 use diesel_async::AsyncPgConnection; // <-- AÑADIDO: Necesario para comandos
+use chrono::{DateTime, Utc};
 use crate::Domain::entities::user::User;
 
+/// Usuario de la papelera: quién lo eliminó y cuándo.
+#[derive(Debug, Clone)]
+pub struct DeletedUserDto {
+    pub id: Uuid,
+    pub username: String,
+    pub email: String,
+    pub deleted_at: DateTime<Utc>,
+    pub deleted_by: Option<Uuid>,
+}
+
 #[async_trait]
 pub trait UserQueryRepository: Send + Sync {
     // Configurar la base de datos para consultas (opcional)
@@ -13,6 +24,13 @@ pub trait UserQueryRepository: Send + Sync {
     async fn find_by_email(&self, email: &str) -> Result<Option<User>>;
     async fn find_by_username(&self, username: &str) -> Result<Option<User>>;
     async fn find_all(&self) -> Result<Vec<User>>;
+
+    // --- Papelera (las consultas anteriores excluyen los usuarios eliminados) ---
+    /// Usuarios de la papelera, del más reciente al más antiguo.
+    async fn find_deleted(&self, limit: i64, offset: i64) -> Result<Vec<DeletedUserDto>>;
+    async fn count_deleted(&self) -> Result<i64>;
+    /// IDs de los usuarios eliminados antes de `deleted_before`.
+    async fn find_deleted_before(&self, deleted_before: DateTime<Utc>) -> Result<Vec<Uuid>>;
 }
 
 /// Port para operaciones de comando (escritura) sobre la entidad User.
@@ -23,7 +41,15 @@ pub trait UserCommandRepository: Send + Sync {
     // --- AJUSTADO: Añadido 'conn' ---
     async fn create(&self, conn: &mut AsyncPgConnection, user: User) -> Result<User>;
     // --- AJUSTADO: Añadido 'conn' ---
+    /// Incrementa la versión; el usuario devuelto lleva la nueva.
     async fn update(&self, conn: &mut AsyncPgConnection, user: User) -> Result<User>;
-    // --- AJUSTADO: Añadido 'conn' ---
-    async fn delete(&self, conn: &mut AsyncPgConnection, id: Uuid) -> Result<()>;
+    /// Versión actual de un usuario no eliminado, bloqueando la fila hasta el final
+    /// de la transacción (comprobación de If-Match). None si no existe.
+    async fn find_version_for_update(&self, conn: &mut AsyncPgConnection, id: Uuid) -> Result<Option<i32>>;
+    /// Mueve el usuario a la papelera (deleted_at / deleted_by).
+    async fn delete(&self, conn: &mut AsyncPgConnection, id: Uuid, deleted_by: Uuid) -> Result<()>;
+    /// Saca el usuario de la papelera. Devuelve el número de filas afectadas.
+    async fn restore(&self, conn: &mut AsyncPgConnection, id: Uuid) -> Result<usize>;
+    /// Elimina definitivamente un usuario de la papelera. Devuelve el número de filas afectadas.
+    async fn purge(&self, conn: &mut AsyncPgConnection, id: Uuid) -> Result<usize>;
 }
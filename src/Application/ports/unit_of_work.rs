@@ -1,38 +1,129 @@
-use std::future::Future;
-use anyhow::Result;
+use std::sync::Arc;
+use anyhow::{anyhow, Result};
 use async_trait::async_trait;
 use diesel_async::AsyncPgConnection;
+use diesel_async::scoped_futures::{ScopedBoxFuture, ScopedFutureExt};
 use super::driven::repositories::{
     LogicalEntityCommandRepository,
     LogicalEntityQueryRepository,
     AttributeCommandRepository,
+    AttributeQueryRepository,
+    AttributeOptionCommandRepository,
+    DataTypeCommandRepository,
     DataTypeQueryRepository,
+    RecordCommandRepository,
+    SchemaVersionCommandRepository,
     UserQueryRepository,
     UserCommandRepository,
 };
 
 #[async_trait]
-// El trait que agrupa los repositorios accesibles DENTRO de una UoW
+// El trait que agrupa los repositorios accesibles DENTRO de una UoW.
+// Los repositorios se devuelven sin tomar prestado el registro (referencias 'static
+// o Arc) para poder pedir después la conexión con `get_diesel_async_conn`.
 pub trait RepositoryRegistry: Send + Sync {
     // User
-    fn user_command_repository(&self) -> &dyn UserCommandRepository; // <-- CAMBIO
-    fn user_query_repository(&self) -> &dyn UserQueryRepository;
+    fn user_command_repository(&self) -> &'static dyn UserCommandRepository; // <-- CAMBIO
+    fn user_query_repository(&self) -> Arc<dyn UserQueryRepository>;
     // Logical Entity
-    fn logical_entity_command_repository(&self) -> &dyn LogicalEntityCommandRepository;
-    fn logical_entity_query_repository(&self) -> &dyn LogicalEntityQueryRepository;
+    fn logical_entity_command_repository(&self) -> &'static dyn LogicalEntityCommandRepository;
+    fn logical_entity_query_repository(&self) -> Arc<dyn LogicalEntityQueryRepository>;
     // Attribute & DataType
-    fn attribute_command_repository(&self) -> &dyn AttributeCommandRepository;
-    fn data_type_query_repository(&self) -> &dyn DataTypeQueryRepository;
+    fn attribute_command_repository(&self) -> &'static dyn AttributeCommandRepository;
+    fn attribute_query_repository(&self) -> Arc<dyn AttributeQueryRepository>;
+    fn attribute_option_command_repository(&self) -> &'static dyn AttributeOptionCommandRepository;
+    fn data_type_query_repository(&self) -> Arc<dyn DataTypeQueryRepository>;
+    fn data_type_command_repository(&self) -> &'static dyn DataTypeCommandRepository;
+    // Records (tuplas)
+    fn record_command_repository(&self) -> &'static dyn RecordCommandRepository;
+    // Versiones de la definición de las entidades
+    fn schema_version_command_repository(&self) -> &'static dyn SchemaVersionCommandRepository;
 
     // --- NUEVO MÉTODO ---
     fn get_diesel_async_conn(&mut self) -> &mut AsyncPgConnection; // <-- AÑADIDO
 }
 
+/// Trabajo que se ejecuta dentro de la transacción. El futuro toma prestado el registro,
+/// por eso se devuelve en una caja con ámbito (`.scope_boxed()`).
+pub type UnitOfWorkTask<'a> = Box<
+    dyn for<'r> FnOnce(&'r mut dyn RepositoryRegistry) -> ScopedBoxFuture<'a, 'r, Result<()>> + Send + 'a
+>;
+
 #[async_trait]
 pub trait UnitOfWork: Send + Sync {
-    async fn execute<F, Fut, R>(&self, work: F) -> Result<R>
+    /// Ejecuta `task` en una transacción: commit si termina bien y rollback si falla.
+    /// El error de `task` se devuelve sin envolver.
+    async fn run<'a>(&'a self, task: UnitOfWorkTask<'a>) -> Result<()>;
+}
+
+// `execute` es genérico en el resultado y no puede formar parte del trait sin perder
+// `dyn UnitOfWork`: se implementa sobre `run` guardando el resultado fuera de la transacción.
+impl<'u> dyn UnitOfWork + 'u {
+    pub async fn execute<'a, F, R>(&'a self, work: F) -> Result<R>
     where
-        F: FnOnce(&mut dyn RepositoryRegistry) -> Fut + Send, // <-- CAMBIO a &mut
-        Fut: Future<Output = Result<R>> + Send,
-        R: Send;
+        F: for<'r> FnOnce(&'r mut dyn RepositoryRegistry) -> ScopedBoxFuture<'a, 'r, Result<R>> + Send + 'a,
+        R: Send + 'a,
+    {
+        let mut output = None;
+        let output_slot = &mut output;
+        self.run(Box::new(move |registry| async move {
+            *output_slot = Some(work(registry).await?);
+            Ok(())
+        }.scope_boxed())).await?;
+        output.ok_or_else(|| anyhow!("Unit of Work finished without a result"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Application::errors::application_error::ApplicationError;
+
+    /// Registro sin conexión: las tareas de estas pruebas no piden repositorios.
+    struct EmptyRegistry;
+
+    impl RepositoryRegistry for EmptyRegistry {
+        fn user_command_repository(&self) -> &'static dyn UserCommandRepository { unreachable!() }
+        fn user_query_repository(&self) -> Arc<dyn UserQueryRepository> { unreachable!() }
+        fn logical_entity_command_repository(&self) -> &'static dyn LogicalEntityCommandRepository { unreachable!() }
+        fn logical_entity_query_repository(&self) -> Arc<dyn LogicalEntityQueryRepository> { unreachable!() }
+        fn attribute_command_repository(&self) -> &'static dyn AttributeCommandRepository { unreachable!() }
+        fn attribute_query_repository(&self) -> Arc<dyn AttributeQueryRepository> { unreachable!() }
+        fn attribute_option_command_repository(&self) -> &'static dyn AttributeOptionCommandRepository { unreachable!() }
+        fn data_type_query_repository(&self) -> Arc<dyn DataTypeQueryRepository> { unreachable!() }
+        fn data_type_command_repository(&self) -> &'static dyn DataTypeCommandRepository { unreachable!() }
+        fn record_command_repository(&self) -> &'static dyn RecordCommandRepository { unreachable!() }
+        fn schema_version_command_repository(&self) -> &'static dyn SchemaVersionCommandRepository { unreachable!() }
+        fn get_diesel_async_conn(&mut self) -> &mut AsyncPgConnection { unreachable!() }
+    }
+
+    struct InlineUnitOfWork;
+
+    #[async_trait]
+    impl UnitOfWork for InlineUnitOfWork {
+        async fn run<'a>(&'a self, task: UnitOfWorkTask<'a>) -> Result<()> {
+            task(&mut EmptyRegistry).await
+        }
+    }
+
+    #[tokio::test]
+    async fn execute_returns_the_task_result() {
+        let uow: Arc<dyn UnitOfWork> = Arc::new(InlineUnitOfWork);
+        let prefix = String::from("registro");
+
+        let value = uow.execute(|_registry| async move { Ok(format!("{} 7", prefix)) }.scope_boxed()).await.unwrap();
+
+        assert_eq!(value, "registro 7");
+    }
+
+    #[tokio::test]
+    async fn execute_returns_the_task_error_unchanged() {
+        let uow: Arc<dyn UnitOfWork> = Arc::new(InlineUnitOfWork);
+
+        let error = uow.execute(|_registry| async move {
+            Err::<(), _>(anyhow!(ApplicationError::Conflict("restrict".to_string())))
+        }.scope_boxed()).await.unwrap_err();
+
+        assert!(matches!(error.downcast::<ApplicationError>(), Ok(ApplicationError::Conflict(_))));
+    }
 }
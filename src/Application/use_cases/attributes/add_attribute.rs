@@ -0,0 +1,201 @@
+// src/Application/use_cases/attributes/add_attribute.rs
+
+use async_trait::async_trait;
+use std::sync::Arc;
+use uuid::Uuid;
+use chrono::Utc;
+use log::{info, debug};
+use anyhow::anyhow;
+
+use crate::Application::dtos::attribute_dto::AddAttributeDto;
+use crate::Application::errors::application_error::ApplicationError;
+use crate::Application::ports::unit_of_work::{UnitOfWork, RepositoryRegistry};
+use diesel_async::scoped_futures::ScopedFutureExt;
+use crate::Application::ports::driven::repositories::{
+    LogicalEntityQueryRepository,
+    AttributeQueryRepository,
+    DataTypeQueryRepository,
+    RecordQueryRepository,
+    AttributeDto,
+};
+use crate::Application::use_cases::records::record_constraints::default_value_for;
+use crate::Application::use_cases::records::record_values::map_uow_error;
+use crate::Domain::records::to_storage_text;
+use crate::Domain::views::ViewRepository;
+use super::attribute_schema::{
+    load_entity_attributes, find_attribute, ensure_name_available, resolve_data_type,
+    resolve_reference, validate_attribute_settings, refresh_view_if_assigned, sync_physical_table,
+};
+
+#[async_trait]
+pub trait AddAttributeUseCase: Send + Sync {
+    /// Añade un atributo a una entidad existente. Si tiene valor por defecto,
+    /// se asigna a los registros existentes. Los atributos de tipo enumeración
+    /// pueden crearse con su lista de opciones y los de tipo referencia indican
+    /// la entidad referenciada.
+    async fn execute(&self, entity_id: Uuid, dto: AddAttributeDto) -> Result<AttributeDto, ApplicationError>;
+}
+
+pub struct AddAttributeUseCaseImpl {
+    le_query_repository: Arc<dyn LogicalEntityQueryRepository>,
+    attribute_query_repository: Arc<dyn AttributeQueryRepository>,
+    data_type_query_repository: Arc<dyn DataTypeQueryRepository>,
+    record_query_repository: Arc<dyn RecordQueryRepository>,
+    view_repository: Arc<dyn ViewRepository>,
+    uow: Arc<dyn UnitOfWork>,
+}
+
+impl AddAttributeUseCaseImpl {
+    pub fn new(
+        le_query_repository: Arc<dyn LogicalEntityQueryRepository>,
+        attribute_query_repository: Arc<dyn AttributeQueryRepository>,
+        data_type_query_repository: Arc<dyn DataTypeQueryRepository>,
+        record_query_repository: Arc<dyn RecordQueryRepository>,
+        view_repository: Arc<dyn ViewRepository>,
+        uow: Arc<dyn UnitOfWork>,
+    ) -> Self {
+        Self {
+            le_query_repository,
+            attribute_query_repository,
+            data_type_query_repository,
+            record_query_repository,
+            view_repository,
+            uow,
+        }
+    }
+}
+
+#[async_trait]
+impl AddAttributeUseCase for AddAttributeUseCaseImpl {
+    async fn execute(&self, entity_id: Uuid, dto: AddAttributeDto) -> Result<AttributeDto, ApplicationError> {
+        info!("Ejecutando caso de uso AddAttribute: entity_id='{}', name='{}'", entity_id, dto.name);
+
+        // 1. Entidad, nombre y tipo de dato
+        let (entity, attributes) = load_entity_attributes(
+            self.le_query_repository.as_ref(),
+            self.attribute_query_repository.as_ref(),
+            entity_id,
+        ).await?;
+        ensure_name_available(&attributes, &dto.name, None)?;
+        let (data_type_id, storage) = resolve_data_type(self.data_type_query_repository.as_ref(), &dto.data_type_name).await?;
+        let reference = match &dto.reference {
+            Some(definition) => Some(resolve_reference(
+                self.le_query_repository.as_ref(),
+                self.attribute_query_repository.as_ref(),
+                &dto.name,
+                definition,
+            ).await?),
+            None => None,
+        };
+
+        // 2. Configuración del atributo (se valida con la misma forma que se persistirá)
+        let candidate = AttributeDto {
+            id: Uuid::nil(),
+            entity_id,
+            name: dto.name.clone(),
+            description: dto.description.clone(),
+            data_type_id,
+            data_type_name: dto.data_type_name.clone(),
+            storage,
+            is_required: dto.is_required,
+            position: dto.position,
+            is_unique: dto.is_unique,
+            default_value: dto.default_value.clone(),
+            validation_regex: dto.validation_regex.clone(),
+            show_option_label: dto.show_option_label,
+            is_searchable: dto.is_searchable,
+            options: dto.options.clone(),
+            reference: reference.clone(),
+            created_by: Some(dto.created_by),
+            created_at: Utc::now(),
+            updated_by: None,
+            updated_at: None,
+            status: 1,
+        };
+        validate_attribute_settings(&candidate)?;
+        let column = candidate.storage.column;
+
+        // 3. Los registros existentes reciben el valor por defecto; un atributo
+        //    obligatorio sin valor por defecto los dejaría inválidos.
+        let backfill = match default_value_for(&candidate, column) {
+            Some(default) => to_storage_text(column, &default)
+                .map_err(|e| ApplicationError::ValidationError(e.to_string()))?,
+            None => None,
+        };
+        if dto.is_required && backfill.is_none() {
+            let existing = self.record_query_repository
+                .count_by_entity(entity_id)
+                .await
+                .map_err(|e| ApplicationError::InfrastructureError(format!("Error al contar registros: {}", e)))?;
+            if existing > 0 {
+                return Err(ApplicationError::ValidationError(format!(
+                    "El atributo obligatorio '{}' necesita un valor por defecto: la entidad tiene {} registros",
+                    dto.name, existing
+                )));
+            }
+        }
+
+        // 4. Crear el atributo y asignar el valor por defecto en la misma transacción
+        let dto_clone = dto.clone();
+        let attribute_id = self.uow.execute(move |registry: &mut dyn RepositoryRegistry| async move {
+            let attribute_cmd_repo = registry.attribute_command_repository();
+            let record_cmd_repo = registry.record_command_repository();
+            let option_cmd_repo = registry.attribute_option_command_repository();
+            let schema_version_repo = registry.schema_version_command_repository();
+            let conn = registry.get_diesel_async_conn();
+
+            let attribute_id = attribute_cmd_repo.create(
+                conn,
+                entity_id,
+                data_type_id,
+                &dto_clone.name,
+                dto_clone.description.as_deref(),
+                dto_clone.is_required,
+                dto_clone.position,
+                dto_clone.is_unique,
+                dto_clone.default_value.as_deref(),
+                dto_clone.validation_regex.as_deref(),
+                dto_clone.show_option_label,
+                dto_clone.is_searchable,
+                reference.as_ref(),
+                dto_clone.created_by,
+            ).await.map_err(|e| anyhow!("Failed to create attribute '{}': {}", dto_clone.name, e))?;
+
+            if !dto_clone.options.is_empty() {
+                option_cmd_repo.replace_all(conn, attribute_id, &dto_clone.options).await
+                    .map_err(|e| anyhow!("Failed to create options of attribute '{}': {}", dto_clone.name, e))?;
+            }
+
+            if let Some(value) = backfill.as_deref() {
+                let filled = record_cmd_repo
+                    .backfill_value(conn, entity_id, attribute_id, column, value)
+                    .await
+                    .map_err(|e| anyhow!("Failed to backfill attribute '{}': {}", dto_clone.name, e))?;
+                debug!("Valor por defecto de '{}' asignado a {} registros", dto_clone.name, filled);
+            }
+            sync_physical_table(record_cmd_repo, conn, entity_id).await?;
+
+            let summary = format!("Alta del atributo '{}'", dto_clone.name);
+            schema_version_repo.create_snapshot(conn, entity_id, Some(&summary), dto_clone.created_by).await
+                .map_err(|e| anyhow!("Failed to record schema version of entity {}: {}", entity_id, e))?;
+            Ok(attribute_id)
+        }.scope_boxed()).await.map_err(|e| map_uow_error(e, "alta de atributo"))?;
+        info!("Atributo '{}' ({}) añadido a la entidad {}", dto.name, attribute_id, entity_id);
+
+        // 5. Regenerar la vista y devolver el atributo creado
+        refresh_view_if_assigned(
+            self.le_query_repository.as_ref(),
+            self.attribute_query_repository.as_ref(),
+            self.view_repository.as_ref(),
+            self.uow.as_ref(),
+            &entity,
+        ).await?;
+
+        let (_, attributes) = load_entity_attributes(
+            self.le_query_repository.as_ref(),
+            self.attribute_query_repository.as_ref(),
+            entity_id,
+        ).await?;
+        find_attribute(&attributes, attribute_id).cloned()
+    }
+}
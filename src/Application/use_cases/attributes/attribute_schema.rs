@@ -0,0 +1,458 @@
+// src/Application/use_cases/attributes/attribute_schema.rs
+//
+// Validaciones y pasos comunes a los casos de uso que modifican los atributos
+// de una entidad existente (alta, cambios, reordenación, cambio de tipo,
+// listas de opciones y referencias).
+
+use regex::Regex;
+use uuid::Uuid;
+use diesel_async::AsyncPgConnection;
+use log::{debug, error};
+use anyhow::anyhow;
+
+use crate::Application::dtos::attribute_dto::{ConversionFailureDto, ReferenceDefinitionDto};
+use crate::Application::errors::application_error::ApplicationError;
+use crate::Application::ports::unit_of_work::UnitOfWork;
+use crate::Application::ports::driven::repositories::{
+    LogicalEntityQueryRepository,
+    AttributeQueryRepository,
+    DataTypeQueryRepository,
+    RecordCommandRepository,
+    AttributeOptionCommandRepository,
+    LogicalEntityDto,
+    AttributeDto,
+};
+use crate::Domain::attribute_options::{AttributeOption, validate_options, check_option_code};
+use crate::Domain::attribute_references::{AttributeReference, validate_reference};
+use crate::Domain::data_types::DataTypeStorage;
+use crate::Domain::errors::{DomainError, DomainResult};
+use crate::Domain::physical_tables::StorageMode;
+use crate::Domain::records::{StorageColumn, to_storage_text};
+use crate::Domain::sql_identifiers::validate_attribute_name;
+use crate::Domain::views::ViewRepository;
+use crate::Application::use_cases::logical_entities::entity_view::sync_entity_view;
+use crate::Application::use_cases::records::record_constraints::default_value_for;
+
+/// Busca la entidad y todos sus atributos (incluidos los retirados).
+pub(crate) async fn load_entity_attributes(
+    le_query_repository: &dyn LogicalEntityQueryRepository,
+    attribute_query_repository: &dyn AttributeQueryRepository,
+    entity_id: Uuid,
+) -> Result<(LogicalEntityDto, Vec<AttributeDto>), ApplicationError> {
+    let entity = le_query_repository
+        .find_by_id(entity_id)
+        .await
+        .map_err(|e| ApplicationError::InfrastructureError(format!("Error al buscar entidad lógica: {}", e)))?
+        .ok_or_else(|| ApplicationError::NotFound(format!("Entidad lógica con ID {} no encontrada", entity_id)))?;
+
+    let attributes = attribute_query_repository
+        .find_by_entity_id(entity_id)
+        .await
+        .map_err(|e| {
+            error!("Error al consultar atributos de la entidad {}: {}", entity_id, e);
+            ApplicationError::InfrastructureError(format!("Error al consultar atributos: {}", e))
+        })?;
+
+    Ok((entity, attributes))
+}
+
+/// Devuelve el atributo de la entidad con el ID indicado.
+pub(crate) fn find_attribute(attributes: &[AttributeDto], attribute_id: Uuid) -> Result<&AttributeDto, ApplicationError> {
+    attributes
+        .iter()
+        .find(|a| a.id == attribute_id)
+        .ok_or_else(|| ApplicationError::NotFound(format!("Atributo con ID {} no encontrado en la entidad", attribute_id)))
+}
+
+/// Un nombre nuevo debe ser un identificador SQL válido (ver `sql_identifiers`) y no
+/// puede repetirse en la entidad (incluye atributos retirados, ya que la restricción
+/// UNIQUE(entity_id, name) los considera). Mantener el nombre actual siempre se admite.
+pub(crate) fn ensure_name_available(
+    attributes: &[AttributeDto],
+    name: &str,
+    exclude_id: Option<Uuid>,
+) -> Result<(), ApplicationError> {
+    let unchanged = attributes.iter().any(|a| Some(a.id) == exclude_id && a.name == name);
+    if !unchanged {
+        validate_attribute_name(name).map_err(|e| ApplicationError::ValidationError(e.to_string()))?;
+    }
+    if attributes.iter().any(|a| a.name == name && Some(a.id) != exclude_id) {
+        return Err(ApplicationError::Conflict(format!("Ya existe un atributo con el nombre '{}' en la entidad", name)));
+    }
+    Ok(())
+}
+
+/// Resuelve un tipo de dato activo del registro por nombre: su ID y su definición
+/// de almacenamiento (columna, cast y parámetros).
+pub(crate) async fn resolve_data_type(
+    data_type_query_repository: &dyn DataTypeQueryRepository,
+    data_type_name: &str,
+) -> Result<(Uuid, DataTypeStorage), ApplicationError> {
+    let data_type = data_type_query_repository
+        .find_by_name(data_type_name)
+        .await
+        .map_err(|e| ApplicationError::InfrastructureError(format!("Error al buscar tipo de dato '{}': {}", data_type_name, e)))?
+        .filter(|dt| dt.status == 1)
+        .ok_or_else(|| ApplicationError::ValidationError(format!("Tipo de dato '{}' no encontrado", data_type_name)))?;
+    Ok((data_type.id, data_type.storage))
+}
+
+/// Resuelve la configuración de referencia recibida: la entidad referenciada debe
+/// existir y el atributo a mostrar, si se indica, ser un atributo activo de ella.
+pub(crate) async fn resolve_reference(
+    le_query_repository: &dyn LogicalEntityQueryRepository,
+    attribute_query_repository: &dyn AttributeQueryRepository,
+    attribute_name: &str,
+    definition: &ReferenceDefinitionDto,
+) -> Result<AttributeReference, ApplicationError> {
+    let (target, target_attributes) = load_entity_attributes(
+        le_query_repository,
+        attribute_query_repository,
+        definition.entity_id,
+    ).await.map_err(|e| match e {
+        ApplicationError::NotFound(_) => ApplicationError::ValidationError(format!(
+            "La entidad referenciada por '{}' ({}) no existe", attribute_name, definition.entity_id
+        )),
+        other => other,
+    })?;
+
+    let display_attribute_id = match definition.display_attribute.as_deref() {
+        Some(display) => Some(
+            target_attributes.iter()
+                .find(|a| a.name == display && a.status == 1)
+                .map(|a| a.id)
+                .ok_or_else(|| ApplicationError::ValidationError(format!(
+                    "La entidad '{}' no tiene un atributo activo '{}' que mostrar en '{}'", target.name, display, attribute_name
+                )))?,
+        ),
+        None => None,
+    };
+
+    Ok(AttributeReference { entity_id: target.id, on_delete: definition.on_delete, display_attribute_id })
+}
+
+/// Un atributo de tipo referencia necesita su configuración (`reference`) y solo
+/// esos atributos la admiten. Sin valor por defecto: apuntaría a un registro concreto.
+pub(crate) fn validate_reference_settings(
+    name: &str,
+    storage: &DataTypeStorage,
+    reference: Option<&AttributeReference>,
+    is_required: bool,
+    default_value: Option<&str>,
+) -> Result<(), ApplicationError> {
+    match reference {
+        None if storage.is_reference() => Err(ApplicationError::ValidationError(format!(
+            "El atributo '{}' es de tipo referencia y necesita la entidad referenciada", name
+        ))),
+        None => Ok(()),
+        Some(_) if !storage.is_reference() => Err(ApplicationError::ValidationError(format!(
+            "El atributo '{}' no es de tipo referencia y no admite configuración de referencia", name
+        ))),
+        Some(reference) => {
+            validate_reference(reference, is_required).map_err(|e| {
+                ApplicationError::ValidationError(format!("La referencia de '{}' no es válida: {}", name, e))
+            })?;
+            if default_value.is_some_and(|d| !d.is_empty()) {
+                return Err(ApplicationError::ValidationError(format!(
+                    "El atributo de referencia '{}' no admite valor por defecto", name
+                )));
+            }
+            Ok(())
+        },
+    }
+}
+
+/// Solo los atributos de texto (columnas string/text) pueden incluirse en la búsqueda.
+pub(crate) fn validate_searchable(name: &str, storage: &DataTypeStorage, is_searchable: bool) -> Result<(), ApplicationError> {
+    if is_searchable && !is_text_column(storage.column) {
+        return Err(ApplicationError::ValidationError(format!(
+            "El atributo '{}' no es de texto y no puede incluirse en la búsqueda (is_searchable)", name
+        )));
+    }
+    Ok(())
+}
+
+/// Columnas que indexa la búsqueda de texto.
+pub(crate) fn is_text_column(column: StorageColumn) -> bool {
+    matches!(column, StorageColumn::String | StorageColumn::Text)
+}
+
+/// Valida la configuración de un atributo: grupo de unicidad, expresión regular,
+/// lista de opciones, referencia, búsqueda y que el valor por defecto sea válido para su tipo
+/// de dato (`attribute.storage`) y, en las enumeraciones, una opción activa.
+pub(crate) fn validate_attribute_settings(attribute: &AttributeDto) -> Result<(), ApplicationError> {
+    let column = attribute.storage.column;
+    if let Some(group) = attribute.is_unique {
+        if !(0..=10).contains(&group) {
+            return Err(ApplicationError::ValidationError(format!("is_unique {} inválido (valores permitidos: 0-10)", group)));
+        }
+    }
+    if let Some(pattern) = attribute.validation_regex.as_deref().filter(|p| !p.is_empty()) {
+        Regex::new(&format!("^(?:{})$", pattern)).map_err(|e| {
+            ApplicationError::ValidationError(format!("La expresión de validación de '{}' no es válida: {}", attribute.name, e))
+        })?;
+    }
+    if !attribute.options.is_empty() && !attribute.storage.is_enumeration() {
+        return Err(ApplicationError::ValidationError(format!(
+            "El atributo '{}' no es de tipo enumeración y no admite lista de opciones", attribute.name
+        )));
+    }
+    validate_options(&attribute.options).map_err(|e| {
+        ApplicationError::ValidationError(format!("Las opciones de '{}' no son válidas: {}", attribute.name, e))
+    })?;
+    validate_reference_settings(
+        &attribute.name,
+        &attribute.storage,
+        attribute.reference.as_ref(),
+        attribute.is_required,
+        attribute.default_value.as_deref(),
+    )?;
+    validate_searchable(&attribute.name, &attribute.storage, attribute.is_searchable)?;
+    if let Some(default) = default_value_for(attribute, column) {
+        let text = to_storage_text(column, &default).map_err(|e| {
+            ApplicationError::ValidationError(format!("El valor por defecto de '{}' no es válido: {}", attribute.name, e))
+        })?;
+        if let Some(text) = text {
+            check_typed_value(&attribute.storage, &attribute.options, &text).map_err(|e| {
+                ApplicationError::ValidationError(format!("El valor por defecto de '{}' no es válido: {}", attribute.name, e))
+            })?;
+        }
+    }
+    Ok(())
+}
+
+/// Comprueba un valor, en su representación de almacenamiento, contra los
+/// parámetros del tipo y, en las enumeraciones, contra las opciones activas.
+fn check_typed_value(
+    storage: &DataTypeStorage,
+    options: &[AttributeOption],
+    text: &str,
+) -> DomainResult<()> {
+    storage.check_value(text)?;
+    if storage.is_enumeration() {
+        check_option_code(options, text)?;
+    }
+    Ok(())
+}
+
+/// Máximo de valores que no se pueden convertir que se devuelven en el informe de un
+/// cambio de tipo; el total se informa aparte.
+pub(crate) const MAX_REPORTED_FAILURES: usize = 100;
+
+/// Resultado de comprobar la conversión de los valores almacenados de un atributo.
+pub(crate) struct ValueConversion {
+    /// Valores que cambian de columna.
+    pub converted: usize,
+    /// Valores que no se pueden convertir.
+    pub failed: usize,
+    /// Los primeros `MAX_REPORTED_FAILURES` fallos, ordenados por tupla.
+    pub failed_sample: Vec<ConversionFailureDto>,
+}
+
+/// Misma columna y sin parámetros ni opciones que comprobar: no hay nada que convertir.
+fn conversion_needed(from_column: StorageColumn, to: &DataTypeStorage) -> bool {
+    from_column != to.column || to.params != Default::default() || to.is_enumeration()
+}
+
+/// Comprueba en la base de datos la conversión de los valores almacenados del atributo
+/// al nuevo tipo de dato (columna, parámetros y, en las enumeraciones, las opciones del
+/// atributo), sin modificar nada. Debe ejecutarse dentro de la UoW que aplicará el cambio.
+/// Si la columna no cambia, solo se comprueban los parámetros y las opciones.
+pub(crate) async fn plan_value_conversion(
+    record_command_repository: &dyn RecordCommandRepository,
+    conn: &mut AsyncPgConnection,
+    attribute_id: Uuid,
+    from_column: StorageColumn,
+    to: &DataTypeStorage,
+    options: &[AttributeOption],
+) -> anyhow::Result<ValueConversion> {
+    if !conversion_needed(from_column, to) {
+        return Ok(ValueConversion { converted: 0, failed: 0, failed_sample: Vec::new() });
+    }
+
+    let check = record_command_repository
+        .check_value_conversion(conn, attribute_id, from_column, to, options, MAX_REPORTED_FAILURES).await
+        .map_err(|e| anyhow!("Failed to check conversion of attribute {}: {}", attribute_id, e))?;
+    debug!(
+        "Conversión del atributo {} ({:?} -> {:?}): {} valores convertibles, {} fallos",
+        attribute_id, from_column, to.column, check.convertible, check.failed
+    );
+
+    // El motivo de cada fallo de la muestra se obtiene con las mismas reglas que las escrituras
+    let failed_sample = check.failed_sample.into_iter()
+        .map(|item| ConversionFailureDto {
+            record_id: item.instance_id,
+            error: conversion_error(to, options, &item.value),
+            value: item.value,
+        })
+        .collect();
+    Ok(ValueConversion {
+        // En la misma columna los valores válidos se quedan donde están
+        converted: if from_column == to.column { 0 } else { check.convertible },
+        failed: check.failed,
+        failed_sample,
+    })
+}
+
+/// Motivo por el que un valor no se puede convertir al tipo `to`.
+fn conversion_error(to: &DataTypeStorage, options: &[AttributeOption], value: &serde_json::Value) -> String {
+    let checked = to_storage_text(to.column, value).and_then(|text| match text {
+        Some(text) => check_typed_value(to, options, &text),
+        None => Ok(()),
+    });
+    match checked {
+        Err(e) => e.to_string(),
+        Ok(()) => format!("el valor no se puede convertir a la columna {}", to.column.column_name()),
+    }
+}
+
+/// Mueve los valores convertibles a la nueva columna y elimina los demás, con una
+/// sentencia por paso. Devuelve el número de valores descartados.
+pub(crate) async fn apply_value_conversion(
+    record_command_repository: &dyn RecordCommandRepository,
+    conn: &mut AsyncPgConnection,
+    attribute_id: Uuid,
+    from_column: StorageColumn,
+    to: &DataTypeStorage,
+    options: &[AttributeOption],
+) -> anyhow::Result<usize> {
+    if !conversion_needed(from_column, to) {
+        return Ok(0);
+    }
+
+    let (converted, discarded) = record_command_repository
+        .convert_values(conn, attribute_id, from_column, to, options).await
+        .map_err(|e| anyhow!("Failed to convert values of attribute {}: {}", attribute_id, e))?;
+    debug!("Atributo {}: {} valores convertidos, {} descartados", attribute_id, converted, discarded);
+    Ok(discarded)
+}
+
+/// Sustituye la lista de opciones de un atributo de tipo enumeración. Falla si
+/// se elimina un código que algún registro usa: esas opciones deben desactivarse.
+/// Debe ejecutarse dentro de la UoW.
+pub(crate) async fn replace_attribute_options(
+    option_command_repository: &dyn AttributeOptionCommandRepository,
+    conn: &mut AsyncPgConnection,
+    attribute: &AttributeDto,
+    options: &[AttributeOption],
+) -> anyhow::Result<()> {
+    let in_use = option_command_repository
+        .count_values_by_code(conn, attribute.id, attribute.storage.column)
+        .await
+        .map_err(|e| anyhow!("Failed to count stored codes of attribute {}: {}", attribute.id, e))?;
+    let removed: Vec<String> = in_use.iter()
+        .filter(|(code, _)| !options.iter().any(|o| &o.code == code))
+        .map(|(code, total)| format!("'{}' ({} registros)", code, total))
+        .collect();
+    if !removed.is_empty() {
+        return Err(anyhow!(ApplicationError::Conflict(format!(
+            "Las opciones {} de '{}' están en uso; desactívelas (is_active = false) en lugar de eliminarlas",
+            removed.join(", "), attribute.name
+        ))));
+    }
+
+    option_command_repository.replace_all(conn, attribute.id, options).await
+        .map_err(|e| anyhow!("Failed to replace options of attribute {}: {}", attribute.id, e))?;
+    debug!("Opciones del atributo {} sustituidas ({} opciones)", attribute.id, options.len());
+    Ok(())
+}
+
+/// Rechaza mover los valores de un atributo a otra columna de almacenamiento en las
+/// entidades con tabla física: la columna física es tipada y no se convierte en sitio.
+pub(crate) fn ensure_storage_column_change_allowed(
+    entity: &LogicalEntityDto,
+    attribute: &AttributeDto,
+    to_column: StorageColumn,
+) -> Result<(), ApplicationError> {
+    if entity.storage_mode == StorageMode::Physical && attribute.storage.column != to_column {
+        return Err(ApplicationError::Conflict(format!(
+            "La entidad '{}' guarda sus registros en una tabla física: pásela a almacenamiento EAV \
+             para cambiar '{}' de {} a {}",
+            entity.name, attribute.name, attribute.storage.column.column_name(), to_column.column_name()
+        )));
+    }
+    Ok(())
+}
+
+/// Lleva la tabla física de la entidad (si guarda sus valores en una) al estado de sus
+/// atributos. Debe ejecutarse dentro de la UoW, tras modificar los atributos: un cambio
+/// que la tabla o sus registros no admiten se rechaza como conflicto.
+pub(crate) async fn sync_physical_table(
+    record_command_repository: &dyn RecordCommandRepository,
+    conn: &mut AsyncPgConnection,
+    entity_id: Uuid,
+) -> anyhow::Result<()> {
+    let applied = record_command_repository.sync_physical_table(conn, entity_id).await.map_err(|e| {
+        match e.downcast_ref::<DomainError>() {
+            Some(DomainError::InvalidState(message)) => anyhow!(ApplicationError::Conflict(message.clone())),
+            _ => anyhow!("Failed to sync physical table of entity {}: {}", entity_id, e),
+        }
+    })?;
+    if applied > 0 {
+        debug!("Tabla física de la entidad {} sincronizada ({} cambios)", entity_id, applied);
+    }
+    Ok(())
+}
+
+/// Regenera la vista de la entidad si tiene una asignada.
+pub(crate) async fn refresh_view_if_assigned(
+    le_query_repository: &dyn LogicalEntityQueryRepository,
+    attribute_query_repository: &dyn AttributeQueryRepository,
+    view_repository: &dyn ViewRepository,
+    uow: &dyn UnitOfWork,
+    entity: &LogicalEntityDto,
+) -> Result<(), ApplicationError> {
+    if entity.assign_view.is_none() {
+        debug!("La entidad {} no tiene vista asignada", entity.id);
+        return Ok(());
+    }
+    sync_entity_view(le_query_repository, attribute_query_repository, view_repository, uow, entity.id)
+        .await
+        .map(|_| ())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Domain::data_types::DataTypeKind;
+
+    #[test]
+    fn test_default_value_must_match_data_type() {
+        let mut attr = AttributeDto::for_tests("qty", StorageColumn::Integer);
+        attr.default_value = Some("10".to_string());
+        assert!(validate_attribute_settings(&attr).is_ok());
+
+        attr.default_value = Some("diez".to_string());
+        assert!(validate_attribute_settings(&attr).is_err());
+
+        // Los parámetros del tipo también se aplican al valor por defecto
+        attr.default_value = Some("10".to_string());
+        attr.storage.params.max_value = Some(5.0);
+        assert!(validate_attribute_settings(&attr).is_err());
+    }
+
+    #[test]
+    fn test_enumeration_default_must_be_an_active_option() {
+        let mut attr = AttributeDto::for_tests("status", StorageColumn::String);
+        attr.options = vec![AttributeOption { code: "open".to_string(), label: "Open".to_string(), sort_order: 0, is_active: true }];
+        assert!(validate_attribute_settings(&attr).is_err(), "las opciones requieren un tipo de enumeración");
+
+        attr.storage = attr.storage.clone().with_kind(DataTypeKind::Enumeration).unwrap();
+        attr.default_value = Some("open".to_string());
+        assert!(validate_attribute_settings(&attr).is_ok());
+
+        attr.default_value = Some("closed".to_string());
+        assert!(validate_attribute_settings(&attr).is_err());
+    }
+
+    #[test]
+    fn test_name_conflicts_include_retired_attributes() {
+        let mut retired = AttributeDto::for_tests("code", StorageColumn::String);
+        retired.status = 0;
+        let attributes = vec![retired.clone(), AttributeDto::for_tests("name", StorageColumn::String)];
+
+        assert!(matches!(ensure_name_available(&attributes, "code", None), Err(ApplicationError::Conflict(_))));
+        assert!(ensure_name_available(&attributes, "code", Some(retired.id)).is_ok());
+        assert!(ensure_name_available(&attributes, " ", None).is_err());
+    }
+}
@@ -0,0 +1,180 @@
+// src/Application/use_cases/attributes/change_attribute_data_type.rs
+
+use async_trait::async_trait;
+use std::sync::Arc;
+use uuid::Uuid;
+use log::{info, warn};
+use anyhow::anyhow;
+
+use crate::Application::dtos::attribute_dto::{ChangeAttributeDataTypeDto, DataTypeChangeReportDto};
+use crate::Application::errors::application_error::ApplicationError;
+use crate::Application::ports::unit_of_work::{UnitOfWork, RepositoryRegistry};
+use diesel_async::scoped_futures::ScopedFutureExt;
+use crate::Application::ports::driven::repositories::{
+    LogicalEntityQueryRepository,
+    AttributeQueryRepository,
+    DataTypeQueryRepository,
+    AttributeChanges,
+};
+use crate::Application::use_cases::records::record_values::map_uow_error;
+use crate::Domain::views::ViewRepository;
+use super::attribute_schema::{
+    load_entity_attributes, find_attribute, resolve_data_type, validate_attribute_settings, is_text_column,
+    plan_value_conversion, apply_value_conversion, refresh_view_if_assigned, ensure_storage_column_change_allowed,
+    sync_physical_table,
+};
+
+#[async_trait]
+pub trait ChangeAttributeDataTypeUseCase: Send + Sync {
+    /// Cambia el tipo de dato de un atributo convirtiendo los valores almacenados.
+    /// Devuelve el informe de conversión (incluidos los valores que no se pudieron convertir).
+    async fn execute(&self, entity_id: Uuid, attribute_id: Uuid, dto: ChangeAttributeDataTypeDto) -> Result<DataTypeChangeReportDto, ApplicationError>;
+}
+
+pub struct ChangeAttributeDataTypeUseCaseImpl {
+    le_query_repository: Arc<dyn LogicalEntityQueryRepository>,
+    attribute_query_repository: Arc<dyn AttributeQueryRepository>,
+    data_type_query_repository: Arc<dyn DataTypeQueryRepository>,
+    view_repository: Arc<dyn ViewRepository>,
+    uow: Arc<dyn UnitOfWork>,
+}
+
+impl ChangeAttributeDataTypeUseCaseImpl {
+    pub fn new(
+        le_query_repository: Arc<dyn LogicalEntityQueryRepository>,
+        attribute_query_repository: Arc<dyn AttributeQueryRepository>,
+        data_type_query_repository: Arc<dyn DataTypeQueryRepository>,
+        view_repository: Arc<dyn ViewRepository>,
+        uow: Arc<dyn UnitOfWork>,
+    ) -> Self {
+        Self { le_query_repository, attribute_query_repository, data_type_query_repository, view_repository, uow }
+    }
+}
+
+#[async_trait]
+impl ChangeAttributeDataTypeUseCase for ChangeAttributeDataTypeUseCaseImpl {
+    async fn execute(&self, entity_id: Uuid, attribute_id: Uuid, dto: ChangeAttributeDataTypeDto) -> Result<DataTypeChangeReportDto, ApplicationError> {
+        info!(
+            "Ejecutando caso de uso ChangeAttributeDataType: entity_id='{}', attribute_id='{}', tipo='{}', dry_run={}",
+            entity_id, attribute_id, dto.data_type_name, dto.dry_run
+        );
+
+        // 1. Atributo actual y tipos de origen/destino
+        let (entity, attributes) = load_entity_attributes(
+            self.le_query_repository.as_ref(),
+            self.attribute_query_repository.as_ref(),
+            entity_id,
+        ).await?;
+        let current = find_attribute(&attributes, attribute_id)?.clone();
+        let from_column = current.storage.column;
+        let (data_type_id, to_storage) = resolve_data_type(self.data_type_query_repository.as_ref(), &dto.data_type_name).await?;
+        let to_column = to_storage.column;
+
+        // El valor por defecto y la expresión de validación deben seguir siendo válidos
+        let mut retyped = current.clone();
+        retyped.data_type_id = data_type_id;
+        retyped.data_type_name = dto.data_type_name.clone();
+        retyped.storage = to_storage.clone();
+        // Las opciones solo tienen sentido en las enumeraciones
+        if !to_storage.is_enumeration() {
+            retyped.options = Vec::new();
+        } else if !dto.options.is_empty() {
+            retyped.options = dto.options.clone();
+        }
+        // Los valores existentes no pueden validarse como registros de otra entidad:
+        // las referencias se crean como atributos nuevos
+        if to_storage.is_reference() && !current.storage.is_reference() {
+            return Err(ApplicationError::ValidationError(format!(
+                "El atributo '{}' no puede convertirse a un tipo referencia; cree un atributo de referencia nuevo",
+                current.name
+            )));
+        }
+        if !to_storage.is_reference() {
+            retyped.reference = None;
+        }
+        // Fuera de las columnas de texto el atributo deja de participar en la búsqueda
+        if !is_text_column(to_column) {
+            retyped.is_searchable = false;
+        }
+        validate_attribute_settings(&retyped)?;
+        ensure_storage_column_change_allowed(&entity, &current, to_column)?;
+        let options_changed = retyped.options != current.options;
+        let reference_change = (retyped.reference != current.reference).then(|| retyped.reference.clone());
+        let searchable_change = (retyped.is_searchable != current.is_searchable).then_some(retyped.is_searchable);
+
+        // 2. Convertir (y, si procede, reescribir) los valores dentro de una transacción
+        let dto_clone = dto.clone();
+        let report = self.uow.execute(move |registry: &mut dyn RepositoryRegistry| async move {
+            let attribute_cmd_repo = registry.attribute_command_repository();
+            let record_cmd_repo = registry.record_command_repository();
+            let option_cmd_repo = registry.attribute_option_command_repository();
+            let schema_version_repo = registry.schema_version_command_repository();
+            let conn = registry.get_diesel_async_conn();
+
+            let conversion = plan_value_conversion(record_cmd_repo, conn, attribute_id, from_column, &to_storage, &retyped.options).await?;
+
+            let mut report = DataTypeChangeReportDto {
+                attribute_id,
+                from_data_type: current.data_type_name.clone(),
+                to_data_type: dto_clone.data_type_name.clone(),
+                applied: false,
+                converted: conversion.converted,
+                discarded: 0,
+                failed_count: conversion.failed,
+                failed: conversion.failed_sample,
+            };
+            if dto_clone.dry_run || (report.failed_count > 0 && !dto_clone.discard_failures) {
+                return Ok(report);
+            }
+
+            report.discarded = apply_value_conversion(
+                record_cmd_repo, conn, attribute_id, from_column, &to_storage, &retyped.options,
+            ).await?;
+
+            let changes = AttributeChanges {
+                data_type_id: Some(data_type_id),
+                is_searchable: searchable_change,
+                reference: reference_change,
+                ..Default::default()
+            };
+            attribute_cmd_repo.update(conn, entity_id, attribute_id, &changes, dto_clone.updated_by).await
+                .map_err(|e| anyhow!("Failed to update data type of attribute {}: {}", attribute_id, e))?;
+            // Los valores ya se han comprobado contra la nueva lista
+            if options_changed {
+                option_cmd_repo.replace_all(conn, attribute_id, &retyped.options).await
+                    .map_err(|e| anyhow!("Failed to replace options of attribute {}: {}", attribute_id, e))?;
+            }
+            // Parámetros y opciones del nuevo tipo en las restricciones de la tabla física
+            sync_physical_table(record_cmd_repo, conn, entity_id).await?;
+
+            let summary = format!("Cambio de tipo de '{}': {} -> {}", current.name, current.data_type_name, dto_clone.data_type_name);
+            schema_version_repo.create_snapshot(conn, entity_id, Some(&summary), dto_clone.updated_by).await
+                .map_err(|e| anyhow!("Failed to record schema version of entity {}: {}", entity_id, e))?;
+
+            report.applied = true;
+            Ok(report)
+        }.scope_boxed()).await.map_err(|e| map_uow_error(e, "cambio de tipo de dato"))?;
+
+        if !report.applied {
+            if report.failed_count > 0 {
+                warn!("{} valores del atributo {} no se pueden convertir a '{}'", report.failed_count, attribute_id, dto.data_type_name);
+            }
+            return Ok(report);
+        }
+        info!(
+            "Atributo {} convertido a '{}': {} valores convertidos, {} descartados",
+            attribute_id, dto.data_type_name, report.converted, report.discarded
+        );
+
+        // 3. La columna de la vista cambia de tipo
+        refresh_view_if_assigned(
+            self.le_query_repository.as_ref(),
+            self.attribute_query_repository.as_ref(),
+            self.view_repository.as_ref(),
+            self.uow.as_ref(),
+            &entity,
+        ).await?;
+
+        Ok(report)
+    }
+}
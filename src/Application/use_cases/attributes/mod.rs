@@ -0,0 +1,12 @@
+pub(crate) mod attribute_schema;
+pub mod add_attribute;
+pub mod update_attribute;
+pub mod reorder_attributes;
+pub mod change_attribute_data_type;
+pub mod replace_attribute_options;
+
+pub use add_attribute::{AddAttributeUseCase, AddAttributeUseCaseImpl};
+pub use update_attribute::{UpdateAttributeUseCase, UpdateAttributeUseCaseImpl};
+pub use reorder_attributes::{ReorderAttributesUseCase, ReorderAttributesUseCaseImpl};
+pub use change_attribute_data_type::{ChangeAttributeDataTypeUseCase, ChangeAttributeDataTypeUseCaseImpl};
+pub use replace_attribute_options::{ReplaceAttributeOptionsUseCase, ReplaceAttributeOptionsUseCaseImpl};
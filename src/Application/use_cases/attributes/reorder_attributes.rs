@@ -0,0 +1,127 @@
+// src/Application/use_cases/attributes/reorder_attributes.rs
+
+use async_trait::async_trait;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use uuid::Uuid;
+use log::info;
+use anyhow::anyhow;
+
+use crate::Application::dtos::attribute_dto::AttributePositionDto;
+use crate::Application::errors::application_error::ApplicationError;
+use crate::Application::ports::unit_of_work::{UnitOfWork, RepositoryRegistry};
+use diesel_async::scoped_futures::ScopedFutureExt;
+use crate::Application::ports::driven::repositories::{
+    LogicalEntityQueryRepository,
+    AttributeQueryRepository,
+    AttributeChanges,
+    AttributeDto,
+};
+use crate::Application::use_cases::records::record_values::map_uow_error;
+use crate::Domain::views::ViewRepository;
+use super::attribute_schema::{load_entity_attributes, find_attribute, refresh_view_if_assigned};
+
+/// Rango de posiciones admitido (igual que en la creación de entidades).
+const MAX_POSITION: i16 = 100;
+
+#[async_trait]
+pub trait ReorderAttributesUseCase: Send + Sync {
+    /// Cambia la posición de los atributos indicados y devuelve todos los atributos
+    /// de la entidad ordenados por posición.
+    async fn execute(&self, entity_id: Uuid, positions: Vec<AttributePositionDto>, updated_by: Uuid) -> Result<Vec<AttributeDto>, ApplicationError>;
+}
+
+pub struct ReorderAttributesUseCaseImpl {
+    le_query_repository: Arc<dyn LogicalEntityQueryRepository>,
+    attribute_query_repository: Arc<dyn AttributeQueryRepository>,
+    view_repository: Arc<dyn ViewRepository>,
+    uow: Arc<dyn UnitOfWork>,
+}
+
+impl ReorderAttributesUseCaseImpl {
+    pub fn new(
+        le_query_repository: Arc<dyn LogicalEntityQueryRepository>,
+        attribute_query_repository: Arc<dyn AttributeQueryRepository>,
+        view_repository: Arc<dyn ViewRepository>,
+        uow: Arc<dyn UnitOfWork>,
+    ) -> Self {
+        Self { le_query_repository, attribute_query_repository, view_repository, uow }
+    }
+}
+
+#[async_trait]
+impl ReorderAttributesUseCase for ReorderAttributesUseCaseImpl {
+    async fn execute(&self, entity_id: Uuid, positions: Vec<AttributePositionDto>, updated_by: Uuid) -> Result<Vec<AttributeDto>, ApplicationError> {
+        info!("Ejecutando caso de uso ReorderAttributes: entity_id='{}', cambios={}", entity_id, positions.len());
+
+        if positions.is_empty() {
+            return Err(ApplicationError::ValidationError("Debe indicarse al menos una posición".to_string()));
+        }
+
+        let (entity, attributes) = load_entity_attributes(
+            self.le_query_repository.as_ref(),
+            self.attribute_query_repository.as_ref(),
+            entity_id,
+        ).await?;
+
+        // 1. Cada atributo una sola vez, perteneciente a la entidad y con posición válida
+        let mut seen = HashSet::new();
+        for item in &positions {
+            if !seen.insert(item.attribute_id) {
+                return Err(ApplicationError::ValidationError(format!("El atributo {} aparece más de una vez", item.attribute_id)));
+            }
+            if !(0..=MAX_POSITION).contains(&item.position) {
+                return Err(ApplicationError::ValidationError(format!("Posición {} inválida (0-{})", item.position, MAX_POSITION)));
+            }
+            find_attribute(&attributes, item.attribute_id)?;
+        }
+
+        // 2. El orden final de los atributos activos no puede tener posiciones repetidas
+        let requested: HashMap<Uuid, i16> = positions.iter().map(|p| (p.attribute_id, p.position)).collect();
+        let mut taken: HashMap<i16, &str> = HashMap::new();
+        for attribute in attributes.iter().filter(|a| a.status == 1) {
+            let position = requested.get(&attribute.id).copied().unwrap_or(attribute.position);
+            if let Some(other) = taken.insert(position, &attribute.name) {
+                return Err(ApplicationError::ValidationError(format!(
+                    "Los atributos '{}' y '{}' quedarían en la misma posición {}", other, attribute.name, position
+                )));
+            }
+        }
+
+        // 3. Aplicar todas las posiciones en una transacción
+        self.uow.execute(move |registry: &mut dyn RepositoryRegistry| async move {
+            let attribute_cmd_repo = registry.attribute_command_repository();
+            let schema_version_repo = registry.schema_version_command_repository();
+            let conn = registry.get_diesel_async_conn();
+
+            for item in positions {
+                let changes = AttributeChanges { position: Some(item.position), ..Default::default() };
+                attribute_cmd_repo
+                    .update(conn, entity_id, item.attribute_id, &changes, updated_by)
+                    .await
+                    .map_err(|e| anyhow!("Failed to update position of attribute {}: {}", item.attribute_id, e))?;
+            }
+
+            schema_version_repo.create_snapshot(conn, entity_id, Some("Reordenación de atributos"), updated_by).await
+                .map_err(|e| anyhow!("Failed to record schema version of entity {}: {}", entity_id, e))?;
+            Ok(())
+        }.scope_boxed()).await.map_err(|e| map_uow_error(e, "reordenación de atributos"))?;
+        info!("Atributos de la entidad {} reordenados", entity_id);
+
+        // 4. El orden de las columnas de la vista sigue a `position`
+        refresh_view_if_assigned(
+            self.le_query_repository.as_ref(),
+            self.attribute_query_repository.as_ref(),
+            self.view_repository.as_ref(),
+            self.uow.as_ref(),
+            &entity,
+        ).await?;
+
+        let (_, attributes) = load_entity_attributes(
+            self.le_query_repository.as_ref(),
+            self.attribute_query_repository.as_ref(),
+            entity_id,
+        ).await?;
+        Ok(attributes)
+    }
+}
@@ -0,0 +1,103 @@
+// src/Application/use_cases/attributes/replace_attribute_options.rs
+
+use async_trait::async_trait;
+use std::sync::Arc;
+use uuid::Uuid;
+use log::info;
+use anyhow::anyhow;
+
+use crate::Application::dtos::attribute_dto::ReplaceAttributeOptionsDto;
+use crate::Application::errors::application_error::ApplicationError;
+use crate::Application::ports::unit_of_work::{UnitOfWork, RepositoryRegistry};
+use diesel_async::scoped_futures::ScopedFutureExt;
+use crate::Application::ports::driven::repositories::{
+    LogicalEntityQueryRepository,
+    AttributeQueryRepository,
+    AttributeDto,
+};
+use crate::Application::use_cases::records::record_values::map_uow_error;
+use super::attribute_schema::{
+    load_entity_attributes, find_attribute, validate_attribute_settings, replace_attribute_options,
+    sync_physical_table,
+};
+
+#[async_trait]
+pub trait ReplaceAttributeOptionsUseCase: Send + Sync {
+    /// Sustituye la lista de opciones de un atributo de tipo enumeración. Las
+    /// opciones en uso no se pueden eliminar, solo desactivar.
+    async fn execute(&self, entity_id: Uuid, attribute_id: Uuid, dto: ReplaceAttributeOptionsDto) -> Result<AttributeDto, ApplicationError>;
+}
+
+pub struct ReplaceAttributeOptionsUseCaseImpl {
+    le_query_repository: Arc<dyn LogicalEntityQueryRepository>,
+    attribute_query_repository: Arc<dyn AttributeQueryRepository>,
+    uow: Arc<dyn UnitOfWork>,
+}
+
+impl ReplaceAttributeOptionsUseCaseImpl {
+    pub fn new(
+        le_query_repository: Arc<dyn LogicalEntityQueryRepository>,
+        attribute_query_repository: Arc<dyn AttributeQueryRepository>,
+        uow: Arc<dyn UnitOfWork>,
+    ) -> Self {
+        Self { le_query_repository, attribute_query_repository, uow }
+    }
+}
+
+#[async_trait]
+impl ReplaceAttributeOptionsUseCase for ReplaceAttributeOptionsUseCaseImpl {
+    async fn execute(&self, entity_id: Uuid, attribute_id: Uuid, dto: ReplaceAttributeOptionsDto) -> Result<AttributeDto, ApplicationError> {
+        info!(
+            "Ejecutando caso de uso ReplaceAttributeOptions: entity_id='{}', attribute_id='{}', opciones={}",
+            entity_id, attribute_id, dto.options.len()
+        );
+
+        // 1. Atributo actual; el valor por defecto debe seguir siendo una opción activa
+        let (_, attributes) = load_entity_attributes(
+            self.le_query_repository.as_ref(),
+            self.attribute_query_repository.as_ref(),
+            entity_id,
+        ).await?;
+        let current = find_attribute(&attributes, attribute_id)?.clone();
+        if !current.storage.is_enumeration() {
+            return Err(ApplicationError::ValidationError(format!(
+                "El atributo '{}' es de tipo '{}', que no es una enumeración", current.name, current.data_type_name
+            )));
+        }
+        let mut updated = current.clone();
+        updated.options = dto.options.clone();
+        validate_attribute_settings(&updated)?;
+
+        if updated.options == current.options {
+            info!("Las opciones del atributo {} no cambian", attribute_id);
+            return Ok(current);
+        }
+
+        // 2. Sustituir la lista y registrar la versión
+        let updated_by = dto.updated_by;
+        self.uow.execute(move |registry: &mut dyn RepositoryRegistry| async move {
+            let option_cmd_repo = registry.attribute_option_command_repository();
+            let record_cmd_repo = registry.record_command_repository();
+            let schema_version_repo = registry.schema_version_command_repository();
+            let conn = registry.get_diesel_async_conn();
+
+            replace_attribute_options(option_cmd_repo, conn, &current, &updated.options).await?;
+            // El CHECK de la columna física sigue la lista de códigos
+            sync_physical_table(record_cmd_repo, conn, entity_id).await?;
+
+            let summary = format!("Opciones del atributo '{}' modificadas", current.name);
+            schema_version_repo.create_snapshot(conn, entity_id, Some(&summary), updated_by).await
+                .map_err(|e| anyhow!("Failed to record schema version of entity {}: {}", entity_id, e))?;
+            Ok(())
+        }.scope_boxed()).await.map_err(|e| map_uow_error(e, "sustitución de opciones"))?;
+        info!("Opciones del atributo {} de la entidad {} sustituidas", attribute_id, entity_id);
+
+        // Las etiquetas se resuelven en la vista con un JOIN: no hace falta regenerarla
+        let (_, attributes) = load_entity_attributes(
+            self.le_query_repository.as_ref(),
+            self.attribute_query_repository.as_ref(),
+            entity_id,
+        ).await?;
+        find_attribute(&attributes, attribute_id).cloned()
+    }
+}
@@ -0,0 +1,173 @@
+// src/Application/use_cases/attributes/update_attribute.rs
+
+use async_trait::async_trait;
+use std::sync::Arc;
+use uuid::Uuid;
+use log::info;
+use anyhow::anyhow;
+
+use crate::Application::dtos::attribute_dto::{UpdateAttributeDto, ReferenceDefinitionDto};
+use crate::Application::errors::application_error::ApplicationError;
+use crate::Application::ports::unit_of_work::{UnitOfWork, RepositoryRegistry};
+use diesel_async::scoped_futures::ScopedFutureExt;
+use crate::Application::ports::driven::repositories::{
+    LogicalEntityQueryRepository,
+    AttributeQueryRepository,
+    AttributeChanges,
+    AttributeDto,
+};
+use crate::Application::use_cases::records::record_values::map_uow_error;
+use crate::Domain::views::ViewRepository;
+use super::attribute_schema::{
+    load_entity_attributes, find_attribute, ensure_name_available,
+    resolve_reference, validate_attribute_settings, refresh_view_if_assigned, sync_physical_table,
+};
+
+#[async_trait]
+pub trait UpdateAttributeUseCase: Send + Sync {
+    /// Renombra, reconfigura o retira/reactiva un atributo de la entidad.
+    async fn execute(&self, entity_id: Uuid, attribute_id: Uuid, dto: UpdateAttributeDto) -> Result<AttributeDto, ApplicationError>;
+}
+
+pub struct UpdateAttributeUseCaseImpl {
+    le_query_repository: Arc<dyn LogicalEntityQueryRepository>,
+    attribute_query_repository: Arc<dyn AttributeQueryRepository>,
+    view_repository: Arc<dyn ViewRepository>,
+    uow: Arc<dyn UnitOfWork>,
+}
+
+impl UpdateAttributeUseCaseImpl {
+    pub fn new(
+        le_query_repository: Arc<dyn LogicalEntityQueryRepository>,
+        attribute_query_repository: Arc<dyn AttributeQueryRepository>,
+        view_repository: Arc<dyn ViewRepository>,
+        uow: Arc<dyn UnitOfWork>,
+    ) -> Self {
+        Self { le_query_repository, attribute_query_repository, view_repository, uow }
+    }
+}
+
+#[async_trait]
+impl UpdateAttributeUseCase for UpdateAttributeUseCaseImpl {
+    async fn execute(&self, entity_id: Uuid, attribute_id: Uuid, dto: UpdateAttributeDto) -> Result<AttributeDto, ApplicationError> {
+        info!("Ejecutando caso de uso UpdateAttribute: entity_id='{}', attribute_id='{}'", entity_id, attribute_id);
+
+        if let Some(status) = dto.status {
+            if status != 0 && status != 1 {
+                return Err(ApplicationError::ValidationError(format!("status {} inválido (valores permitidos: 0, 1)", status)));
+            }
+        }
+
+        // 1. Entidad y atributo actuales
+        let (entity, attributes) = load_entity_attributes(
+            self.le_query_repository.as_ref(),
+            self.attribute_query_repository.as_ref(),
+            entity_id,
+        ).await?;
+        let current = find_attribute(&attributes, attribute_id)?;
+        if let Some(name) = &dto.name {
+            ensure_name_available(&attributes, name, Some(attribute_id))?;
+        }
+
+        // 2. Validar la configuración resultante con el tipo de dato actual
+        let mut updated = current.clone();
+        if let Some(name) = &dto.name { updated.name = name.clone(); }
+        if let Some(description) = &dto.description { updated.description = description.clone(); }
+        if let Some(is_required) = dto.is_required { updated.is_required = is_required; }
+        if let Some(is_unique) = dto.is_unique { updated.is_unique = is_unique; }
+        if let Some(default_value) = &dto.default_value { updated.default_value = default_value.clone(); }
+        if let Some(validation_regex) = &dto.validation_regex { updated.validation_regex = validation_regex.clone(); }
+        if let Some(show_option_label) = dto.show_option_label { updated.show_option_label = show_option_label; }
+        if let Some(is_searchable) = dto.is_searchable { updated.is_searchable = is_searchable; }
+        if let Some(status) = dto.status { updated.status = status; }
+        let reference_changed = dto.reference_on_delete.is_some() || dto.reference_display_attribute.is_some();
+        if reference_changed {
+            let mut reference = current.reference.clone().ok_or_else(|| ApplicationError::ValidationError(format!(
+                "El atributo '{}' no es de tipo referencia", current.name
+            )))?;
+            if let Some(on_delete) = dto.reference_on_delete { reference.on_delete = on_delete; }
+            match &dto.reference_display_attribute {
+                Some(Some(display)) => {
+                    let definition = ReferenceDefinitionDto {
+                        entity_id: reference.entity_id,
+                        on_delete: reference.on_delete,
+                        display_attribute: Some(display.clone()),
+                    };
+                    reference.display_attribute_id = resolve_reference(
+                        self.le_query_repository.as_ref(),
+                        self.attribute_query_repository.as_ref(),
+                        &updated.name,
+                        &definition,
+                    ).await?.display_attribute_id;
+                },
+                Some(None) => reference.display_attribute_id = None,
+                None => {},
+            }
+            updated.reference = Some(reference);
+        }
+        validate_attribute_settings(&updated)?;
+
+        // 3. Persistir los cambios
+        let changes = AttributeChanges {
+            name: dto.name.clone(),
+            description: dto.description.clone(),
+            is_required: dto.is_required,
+            is_unique: dto.is_unique,
+            default_value: dto.default_value.clone(),
+            validation_regex: dto.validation_regex.clone(),
+            show_option_label: dto.show_option_label,
+            is_searchable: dto.is_searchable,
+            reference: reference_changed.then(|| updated.reference.clone()),
+            status: dto.status,
+            ..Default::default()
+        };
+        let updated_by = dto.updated_by;
+        let summary = match dto.status {
+            Some(0) if current.status != 0 => format!("Retirada del atributo '{}'", current.name),
+            Some(1) if current.status != 1 => format!("Reactivación del atributo '{}'", current.name),
+            _ => match &dto.name {
+                Some(name) if name != &current.name => format!("Atributo '{}' renombrado a '{}'", current.name, name),
+                _ => format!("Modificación del atributo '{}'", current.name),
+            },
+        };
+        self.uow.execute(move |registry: &mut dyn RepositoryRegistry| async move {
+            let attribute_cmd_repo = registry.attribute_command_repository();
+            let record_cmd_repo = registry.record_command_repository();
+            let schema_version_repo = registry.schema_version_command_repository();
+            let conn = registry.get_diesel_async_conn();
+
+            let affected = attribute_cmd_repo
+                .update(conn, entity_id, attribute_id, &changes, updated_by)
+                .await
+                .map_err(|e| anyhow!("Failed to update attribute {}: {}", attribute_id, e))?;
+            if affected == 0 {
+                return Err(anyhow!(ApplicationError::NotFound(format!("Atributo con ID {} no encontrado en la entidad", attribute_id))));
+            }
+            // Obligatoriedad, unicidad, estado y nombre se reflejan en la tabla física
+            sync_physical_table(record_cmd_repo, conn, entity_id).await?;
+
+            schema_version_repo.create_snapshot(conn, entity_id, Some(&summary), updated_by).await
+                .map_err(|e| anyhow!("Failed to record schema version of entity {}: {}", entity_id, e))?;
+            Ok(())
+        }.scope_boxed()).await.map_err(|e| map_uow_error(e, "actualización de atributo"))?;
+        info!("Atributo {} de la entidad {} actualizado", attribute_id, entity_id);
+
+        // 4. Nombre, estado y columnas de etiqueta y de referencia forman parte de la vista
+        if dto.name.is_some() || dto.status.is_some() || dto.show_option_label.is_some() || dto.reference_display_attribute.is_some() {
+            refresh_view_if_assigned(
+                self.le_query_repository.as_ref(),
+                self.attribute_query_repository.as_ref(),
+                self.view_repository.as_ref(),
+                self.uow.as_ref(),
+                &entity,
+            ).await?;
+        }
+
+        let (_, attributes) = load_entity_attributes(
+            self.le_query_repository.as_ref(),
+            self.attribute_query_repository.as_ref(),
+            entity_id,
+        ).await?;
+        find_attribute(&attributes, attribute_id).cloned()
+    }
+}
@@ -0,0 +1,57 @@
+// src/Application/use_cases/data_types/create_data_type.rs
+
+use async_trait::async_trait;
+use std::sync::Arc;
+use log::info;
+use anyhow::anyhow;
+
+use crate::Application::dtos::data_type_dto::CreateDataTypeDto;
+use crate::Application::errors::application_error::ApplicationError;
+use crate::Application::ports::unit_of_work::{UnitOfWork, RepositoryRegistry};
+use diesel_async::scoped_futures::ScopedFutureExt;
+use crate::Application::ports::driven::repositories::DataTypeQueryRepository;
+use crate::Application::use_cases::records::record_values::map_uow_error;
+use crate::Domain::data_types::{DataType, DataTypeStorage};
+use super::find_data_types::{load_data_type, ensure_data_type_name_available};
+
+#[async_trait]
+pub trait CreateDataTypeUseCase: Send + Sync {
+    /// Registra un tipo de dato nuevo con su columna de almacenamiento, cast y parámetros.
+    async fn execute(&self, dto: CreateDataTypeDto) -> Result<DataType, ApplicationError>;
+}
+
+pub struct CreateDataTypeUseCaseImpl {
+    data_type_query_repository: Arc<dyn DataTypeQueryRepository>,
+    uow: Arc<dyn UnitOfWork>,
+}
+
+impl CreateDataTypeUseCaseImpl {
+    pub fn new(data_type_query_repository: Arc<dyn DataTypeQueryRepository>, uow: Arc<dyn UnitOfWork>) -> Self {
+        Self { data_type_query_repository, uow }
+    }
+}
+
+#[async_trait]
+impl CreateDataTypeUseCase for CreateDataTypeUseCaseImpl {
+    async fn execute(&self, dto: CreateDataTypeDto) -> Result<DataType, ApplicationError> {
+        info!("Ejecutando caso de uso CreateDataType: name='{}'", dto.name);
+
+        // 1. Nombre único y definición de almacenamiento válida
+        ensure_data_type_name_available(self.data_type_query_repository.as_ref(), &dto.name, None).await?;
+        let storage = DataTypeStorage::new(dto.storage_column, dto.pg_cast.as_deref(), dto.params.clone())
+            .and_then(|storage| storage.with_kind(dto.kind))
+            .map_err(|e| ApplicationError::ValidationError(format!("Tipo de dato '{}' no válido: {}", dto.name, e)))?;
+
+        // 2. Persistir
+        let id = self.uow.execute(move |registry: &mut dyn RepositoryRegistry| async move {
+            let cmd_repo = registry.data_type_command_repository();
+            let conn = registry.get_diesel_async_conn();
+
+            cmd_repo.create(conn, &dto.name, dto.description.as_deref(), &storage, dto.created_by).await
+                .map_err(|e| anyhow!("Failed to create data type '{}': {}", dto.name, e))
+        }.scope_boxed()).await.map_err(|e| map_uow_error(e, "alta de tipo de dato"))?;
+
+        info!("Tipo de dato creado con ID {}", id);
+        load_data_type(self.data_type_query_repository.as_ref(), id).await
+    }
+}
@@ -0,0 +1,66 @@
+// src/Application/use_cases/data_types/delete_data_type.rs
+
+use async_trait::async_trait;
+use std::sync::Arc;
+use uuid::Uuid;
+use log::info;
+use anyhow::anyhow;
+
+use crate::Application::errors::application_error::ApplicationError;
+use crate::Application::ports::unit_of_work::{UnitOfWork, RepositoryRegistry};
+use diesel_async::scoped_futures::ScopedFutureExt;
+use crate::Application::ports::driven::repositories::DataTypeQueryRepository;
+use crate::Application::use_cases::records::record_values::map_uow_error;
+use super::find_data_types::load_data_type;
+
+#[async_trait]
+pub trait DeleteDataTypeUseCase: Send + Sync {
+    /// Elimina un tipo de dato que ningún atributo usa. Para retirar un tipo
+    /// en uso se desactiva (status = 0).
+    async fn execute(&self, id: Uuid) -> Result<(), ApplicationError>;
+}
+
+pub struct DeleteDataTypeUseCaseImpl {
+    data_type_query_repository: Arc<dyn DataTypeQueryRepository>,
+    uow: Arc<dyn UnitOfWork>,
+}
+
+impl DeleteDataTypeUseCaseImpl {
+    pub fn new(data_type_query_repository: Arc<dyn DataTypeQueryRepository>, uow: Arc<dyn UnitOfWork>) -> Self {
+        Self { data_type_query_repository, uow }
+    }
+}
+
+#[async_trait]
+impl DeleteDataTypeUseCase for DeleteDataTypeUseCaseImpl {
+    async fn execute(&self, id: Uuid) -> Result<(), ApplicationError> {
+        info!("Ejecutando caso de uso DeleteDataType: id='{}'", id);
+
+        let data_type = load_data_type(self.data_type_query_repository.as_ref(), id).await?;
+        let entity_ids = self.data_type_query_repository
+            .find_entity_ids_using(id)
+            .await
+            .map_err(|e| ApplicationError::InfrastructureError(format!("Error al consultar el uso del tipo de dato: {}", e)))?;
+        if !entity_ids.is_empty() {
+            return Err(ApplicationError::Conflict(format!(
+                "El tipo de dato '{}' está en uso en {} entidad(es); desactívelo en lugar de eliminarlo",
+                data_type.name, entity_ids.len()
+            )));
+        }
+
+        self.uow.execute(move |registry: &mut dyn RepositoryRegistry| async move {
+            let cmd_repo = registry.data_type_command_repository();
+            let conn = registry.get_diesel_async_conn();
+
+            let affected = cmd_repo.delete(conn, id).await
+                .map_err(|e| anyhow!("Failed to delete data type {}: {}", id, e))?;
+            if affected == 0 {
+                return Err(anyhow!(ApplicationError::NotFound(format!("Tipo de dato con ID {} no encontrado", id))));
+            }
+            Ok(())
+        }.scope_boxed()).await.map_err(|e| map_uow_error(e, "baja de tipo de dato"))?;
+
+        info!("Tipo de dato '{}' eliminado", data_type.name);
+        Ok(())
+    }
+}
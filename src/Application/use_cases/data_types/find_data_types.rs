@@ -0,0 +1,95 @@
+// src/Application/use_cases/data_types/find_data_types.rs
+
+use async_trait::async_trait;
+use std::sync::Arc;
+use uuid::Uuid;
+use log::info;
+
+use crate::Application::errors::application_error::ApplicationError;
+use crate::Application::ports::driven::repositories::DataTypeQueryRepository;
+use crate::Domain::data_types::DataType;
+
+// --- Traits de los Casos de Uso ---
+#[async_trait]
+pub trait ListDataTypesUseCase: Send + Sync {
+    /// Tipos del registro ordenados por nombre; los inactivos solo si se piden.
+    async fn execute(&self, include_inactive: bool) -> Result<Vec<DataType>, ApplicationError>;
+}
+
+#[async_trait]
+pub trait FindDataTypeUseCase: Send + Sync {
+    async fn execute(&self, id: Uuid) -> Result<DataType, ApplicationError>;
+}
+
+/// Busca un tipo de dato por ID.
+pub(crate) async fn load_data_type(
+    data_type_query_repository: &dyn DataTypeQueryRepository,
+    id: Uuid,
+) -> Result<DataType, ApplicationError> {
+    data_type_query_repository
+        .find_by_id(id)
+        .await
+        .map_err(|e| ApplicationError::InfrastructureError(format!("Error al buscar tipo de dato: {}", e)))?
+        .ok_or_else(|| ApplicationError::NotFound(format!("Tipo de dato con ID {} no encontrado", id)))
+}
+
+/// El nombre de un tipo de dato no puede estar vacío ni repetirse (sin distinguir mayúsculas).
+pub(crate) async fn ensure_data_type_name_available(
+    data_type_query_repository: &dyn DataTypeQueryRepository,
+    name: &str,
+    exclude_id: Option<Uuid>,
+) -> Result<(), ApplicationError> {
+    if name.trim().is_empty() {
+        return Err(ApplicationError::ValidationError("El nombre del tipo de dato no puede estar vacío".to_string()));
+    }
+    let existing = data_type_query_repository
+        .find_by_name(name)
+        .await
+        .map_err(|e| ApplicationError::InfrastructureError(format!("Error al buscar tipo de dato '{}': {}", name, e)))?;
+    if existing.map(|dt| Some(dt.id) != exclude_id).unwrap_or(false) {
+        return Err(ApplicationError::Conflict(format!("Ya existe un tipo de dato con el nombre '{}'", name)));
+    }
+    Ok(())
+}
+
+// --- Implementación: listado ---
+pub struct ListDataTypesUseCaseImpl {
+    data_type_query_repository: Arc<dyn DataTypeQueryRepository>,
+}
+
+impl ListDataTypesUseCaseImpl {
+    pub fn new(data_type_query_repository: Arc<dyn DataTypeQueryRepository>) -> Self {
+        Self { data_type_query_repository }
+    }
+}
+
+#[async_trait]
+impl ListDataTypesUseCase for ListDataTypesUseCaseImpl {
+    async fn execute(&self, include_inactive: bool) -> Result<Vec<DataType>, ApplicationError> {
+        info!("Ejecutando caso de uso ListDataTypes: include_inactive={}", include_inactive);
+
+        self.data_type_query_repository
+            .find_all(include_inactive)
+            .await
+            .map_err(|e| ApplicationError::InfrastructureError(format!("Error al listar tipos de datos: {}", e)))
+    }
+}
+
+// --- Implementación: búsqueda por ID ---
+pub struct FindDataTypeUseCaseImpl {
+    data_type_query_repository: Arc<dyn DataTypeQueryRepository>,
+}
+
+impl FindDataTypeUseCaseImpl {
+    pub fn new(data_type_query_repository: Arc<dyn DataTypeQueryRepository>) -> Self {
+        Self { data_type_query_repository }
+    }
+}
+
+#[async_trait]
+impl FindDataTypeUseCase for FindDataTypeUseCaseImpl {
+    async fn execute(&self, id: Uuid) -> Result<DataType, ApplicationError> {
+        info!("Ejecutando caso de uso FindDataType: id='{}'", id);
+        load_data_type(self.data_type_query_repository.as_ref(), id).await
+    }
+}
@@ -0,0 +1,9 @@
+pub mod find_data_types;
+pub mod create_data_type;
+pub mod update_data_type;
+pub mod delete_data_type;
+
+pub use find_data_types::{ListDataTypesUseCase, ListDataTypesUseCaseImpl, FindDataTypeUseCase, FindDataTypeUseCaseImpl};
+pub use create_data_type::{CreateDataTypeUseCase, CreateDataTypeUseCaseImpl};
+pub use update_data_type::{UpdateDataTypeUseCase, UpdateDataTypeUseCaseImpl};
+pub use delete_data_type::{DeleteDataTypeUseCase, DeleteDataTypeUseCaseImpl};
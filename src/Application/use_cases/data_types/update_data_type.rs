@@ -0,0 +1,173 @@
+// src/Application/use_cases/data_types/update_data_type.rs
+
+use async_trait::async_trait;
+use std::sync::Arc;
+use uuid::Uuid;
+use log::{info, debug};
+use anyhow::anyhow;
+
+use crate::Application::dtos::data_type_dto::UpdateDataTypeDto;
+use crate::Application::errors::application_error::ApplicationError;
+use crate::Application::ports::unit_of_work::{UnitOfWork, RepositoryRegistry};
+use diesel_async::scoped_futures::ScopedFutureExt;
+use crate::Application::ports::driven::repositories::{
+    LogicalEntityQueryRepository,
+    AttributeQueryRepository,
+    DataTypeQueryRepository,
+    DataTypeChanges,
+};
+use crate::Application::use_cases::attributes::attribute_schema::{refresh_view_if_assigned, sync_physical_table};
+use crate::Application::use_cases::records::record_values::map_uow_error;
+use crate::Domain::data_types::{DataType, DataTypeStorage, DataTypeParams};
+use crate::Domain::views::ViewRepository;
+use super::find_data_types::{load_data_type, ensure_data_type_name_available};
+
+#[async_trait]
+pub trait UpdateDataTypeUseCase: Send + Sync {
+    /// Modifica un tipo de dato. Si cambian el cast o los parámetros se regeneran
+    /// las vistas de las entidades que lo usan; la columna de almacenamiento y la
+    /// naturaleza (escalar o enumeración) solo pueden cambiar mientras ningún
+    /// atributo lo use.
+    async fn execute(&self, id: Uuid, dto: UpdateDataTypeDto) -> Result<DataType, ApplicationError>;
+}
+
+pub struct UpdateDataTypeUseCaseImpl {
+    le_query_repository: Arc<dyn LogicalEntityQueryRepository>,
+    attribute_query_repository: Arc<dyn AttributeQueryRepository>,
+    data_type_query_repository: Arc<dyn DataTypeQueryRepository>,
+    view_repository: Arc<dyn ViewRepository>,
+    uow: Arc<dyn UnitOfWork>,
+}
+
+impl UpdateDataTypeUseCaseImpl {
+    pub fn new(
+        le_query_repository: Arc<dyn LogicalEntityQueryRepository>,
+        attribute_query_repository: Arc<dyn AttributeQueryRepository>,
+        data_type_query_repository: Arc<dyn DataTypeQueryRepository>,
+        view_repository: Arc<dyn ViewRepository>,
+        uow: Arc<dyn UnitOfWork>,
+    ) -> Self {
+        Self { le_query_repository, attribute_query_repository, data_type_query_repository, view_repository, uow }
+    }
+}
+
+/// Nueva definición de almacenamiento a partir de la actual y los cambios pedidos.
+/// Si el cast actual es el derivado de la columna y los parámetros, y no se indica
+/// uno nuevo, se vuelve a derivar (p. ej. `varchar(50)` -> `varchar(80)`).
+fn merge_storage(current: &DataTypeStorage, dto: &UpdateDataTypeDto) -> Result<DataTypeStorage, ApplicationError> {
+    let column = dto.storage_column.unwrap_or(current.column);
+    let params = DataTypeParams {
+        max_length: dto.max_length.unwrap_or(current.params.max_length),
+        numeric_precision: dto.numeric_precision.unwrap_or(current.params.numeric_precision),
+        numeric_scale: dto.numeric_scale.unwrap_or(current.params.numeric_scale),
+        min_value: dto.min_value.unwrap_or(current.params.min_value),
+        max_value: dto.max_value.unwrap_or(current.params.max_value),
+    };
+    let derived = current.pg_cast == DataTypeStorage::default_cast(current.column, &current.params);
+    let pg_cast = match &dto.pg_cast {
+        Some(pg_cast) => Some(pg_cast.as_str()),
+        None if derived => None,
+        None => Some(current.pg_cast.as_str()),
+    };
+    DataTypeStorage::new(column, pg_cast, params)
+        .and_then(|storage| storage.with_kind(dto.kind.unwrap_or(current.kind)))
+        .map_err(|e| ApplicationError::ValidationError(format!("Definición de tipo de dato no válida: {}", e)))
+}
+
+#[async_trait]
+impl UpdateDataTypeUseCase for UpdateDataTypeUseCaseImpl {
+    async fn execute(&self, id: Uuid, dto: UpdateDataTypeDto) -> Result<DataType, ApplicationError> {
+        info!("Ejecutando caso de uso UpdateDataType: id='{}'", id);
+
+        if let Some(status) = dto.status {
+            if status != 0 && status != 1 {
+                return Err(ApplicationError::ValidationError(format!("status {} inválido (valores permitidos: 0, 1)", status)));
+            }
+        }
+
+        // 1. Tipo actual, nombre y nueva definición
+        let current = load_data_type(self.data_type_query_repository.as_ref(), id).await?;
+        if let Some(name) = &dto.name {
+            ensure_data_type_name_available(self.data_type_query_repository.as_ref(), name, Some(id)).await?;
+        }
+        let storage = if dto.changes_storage() {
+            Some(merge_storage(&current.storage, &dto)?).filter(|s| *s != current.storage)
+        } else {
+            None
+        };
+
+        // 2. Los valores ya almacenados están en la columna actual
+        let entity_ids = self.data_type_query_repository
+            .find_entity_ids_using(id)
+            .await
+            .map_err(|e| ApplicationError::InfrastructureError(format!("Error al consultar el uso del tipo de dato: {}", e)))?;
+        if let Some(storage) = storage.as_ref().filter(|s| s.column != current.storage.column) {
+            if !entity_ids.is_empty() {
+                return Err(ApplicationError::Conflict(format!(
+                    "El tipo de dato '{}' está en uso: no se puede cambiar su columna de {} a {}; \
+                     cambie el tipo de los atributos que lo usan",
+                    current.name, current.storage.column.column_name(), storage.column.column_name()
+                )));
+            }
+        }
+        if let Some(storage) = storage.as_ref().filter(|s| s.kind != current.storage.kind) {
+            if !entity_ids.is_empty() {
+                return Err(ApplicationError::Conflict(format!(
+                    "El tipo de dato '{}' está en uso: no se puede cambiar de {} a {}; \
+                     cambie el tipo de los atributos que lo usan",
+                    current.name, current.storage.kind.as_str(), storage.kind.as_str()
+                )));
+            }
+        }
+
+        // 3. Persistir
+        let changes = DataTypeChanges {
+            name: dto.name.clone(),
+            description: dto.description.clone(),
+            storage: storage.clone(),
+            status: dto.status,
+        };
+        let updated_by = dto.updated_by;
+        let synced_entities = if storage.is_some() { entity_ids.clone() } else { Vec::new() };
+        self.uow.execute(move |registry: &mut dyn RepositoryRegistry| async move {
+            let cmd_repo = registry.data_type_command_repository();
+            let record_cmd_repo = registry.record_command_repository();
+            let conn = registry.get_diesel_async_conn();
+
+            let affected = cmd_repo.update(conn, id, &changes, updated_by).await
+                .map_err(|e| anyhow!("Failed to update data type {}: {}", id, e))?;
+            if affected == 0 {
+                return Err(anyhow!(ApplicationError::NotFound(format!("Tipo de dato con ID {} no encontrado", id))));
+            }
+            // Los parámetros del tipo fijan las restricciones de las tablas físicas que lo usan
+            for entity_id in synced_entities {
+                sync_physical_table(record_cmd_repo, conn, entity_id).await?;
+            }
+            Ok(())
+        }.scope_boxed()).await.map_err(|e| map_uow_error(e, "actualización de tipo de dato"))?;
+
+        // 4. El cast de las columnas de las vistas que usan el tipo puede haber cambiado
+        if storage.is_some() {
+            for entity_id in entity_ids {
+                let Some(entity) = self.le_query_repository
+                    .find_by_id(entity_id)
+                    .await
+                    .map_err(|e| ApplicationError::InfrastructureError(format!("Error al buscar entidad lógica: {}", e)))?
+                else {
+                    continue;
+                };
+                debug!("Regenerando la vista de la entidad {} tras cambiar el tipo de dato {}", entity_id, id);
+                refresh_view_if_assigned(
+                    self.le_query_repository.as_ref(),
+                    self.attribute_query_repository.as_ref(),
+                    self.view_repository.as_ref(),
+                    self.uow.as_ref(),
+                    &entity,
+                ).await?;
+            }
+        }
+
+        info!("Tipo de dato {} actualizado", id);
+        load_data_type(self.data_type_query_repository.as_ref(), id).await
+    }
+}
@@ -0,0 +1,534 @@
+// src/Application/use_cases/graphql/execute_graphql.rs
+
+use async_trait::async_trait;
+use futures::future::{BoxFuture, FutureExt};
+use serde_json::{json, Map, Value};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::RwLock;
+use uuid::Uuid;
+use log::{debug, error, info};
+
+use crate::Application::dtos::graphql_dto::{GraphQLErrorDto, GraphQLRequestDto, GraphQLResponseDto};
+use crate::Application::dtos::logical_entity_dto::LogicalEntityDetailsDto;
+use crate::Application::dtos::record_dto::RecordUpdateMode;
+use crate::Application::errors::application_error::ApplicationError;
+use crate::Application::ports::driven::repositories::{
+    LogicalEntityQueryRepository,
+    AttributeQueryRepository,
+    RecordQueryRepository,
+    RecordDto,
+    AttributeDto,
+};
+use crate::Application::use_cases::logical_entities::list_logical_entities::MAX_PAGE_SIZE;
+use crate::Application::use_cases::records::{
+    CreateRecordUseCase,
+    FindRecordUseCase,
+    ListRecordsUseCase,
+    ListRecordsParams,
+    UpdateRecordUseCase,
+    DeleteRecordUseCase,
+};
+use crate::Domain::errors::DomainError;
+use crate::Domain::graphql::schema::{MUTATION_TYPE, QUERY_TYPE};
+use crate::Domain::graphql::{
+    parse_document, plan_operation, resolve_meta_field, EntityRef, FieldResolver, GraphQLAttribute, GraphQLEntity,
+    OperationType, PlannedField, Schema, DEFAULT_PAGE_SIZE,
+};
+use crate::Domain::record_queries::SystemField;
+use crate::Domain::record_history::ChangeContext;
+
+/// Tiempo durante el que se usa el esquema generado sin volver a consultar la huella de
+/// los metadatos: los cambios de cualquier instancia se ven como mucho con este retraso.
+const SCHEMA_TTL: Duration = Duration::from_secs(5);
+
+/// Esquema generado, con la huella de los metadatos a partir de los que se generó.
+struct CachedSchema {
+    fingerprint: String,
+    schema: Arc<Schema>,
+    checked_at: Instant,
+}
+
+#[async_trait]
+pub trait ExecuteGraphQLUseCase: Send + Sync {
+    /// Ejecuta una operación GraphQL sobre los registros de las entidades lógicas.
+    /// Los errores de la consulta y de cada campo se devuelven en la respuesta;
+    /// solo los fallos al generar el esquema se devuelven como `Err`.
+    /// `context` identifica al autor de las mutaciones en el historial de registros.
+    async fn execute(&self, request: GraphQLRequestDto, context: ChangeContext) -> Result<GraphQLResponseDto, ApplicationError>;
+}
+
+pub struct ExecuteGraphQLUseCaseImpl {
+    le_query_repository: Arc<dyn LogicalEntityQueryRepository>,
+    attribute_query_repository: Arc<dyn AttributeQueryRepository>,
+    record_query_repository: Arc<dyn RecordQueryRepository>,
+    find_record_use_case: Arc<dyn FindRecordUseCase>,
+    list_records_use_case: Arc<dyn ListRecordsUseCase>,
+    create_record_use_case: Arc<dyn CreateRecordUseCase>,
+    update_record_use_case: Arc<dyn UpdateRecordUseCase>,
+    delete_record_use_case: Arc<dyn DeleteRecordUseCase>,
+    /// Último esquema generado.
+    schema_cache: RwLock<Option<CachedSchema>>,
+}
+
+impl ExecuteGraphQLUseCaseImpl {
+    pub fn new(
+        le_query_repository: Arc<dyn LogicalEntityQueryRepository>,
+        attribute_query_repository: Arc<dyn AttributeQueryRepository>,
+        record_query_repository: Arc<dyn RecordQueryRepository>,
+        find_record_use_case: Arc<dyn FindRecordUseCase>,
+        list_records_use_case: Arc<dyn ListRecordsUseCase>,
+        create_record_use_case: Arc<dyn CreateRecordUseCase>,
+        update_record_use_case: Arc<dyn UpdateRecordUseCase>,
+        delete_record_use_case: Arc<dyn DeleteRecordUseCase>,
+    ) -> Self {
+        Self {
+            le_query_repository,
+            attribute_query_repository,
+            record_query_repository,
+            find_record_use_case,
+            list_records_use_case,
+            create_record_use_case,
+            update_record_use_case,
+            delete_record_use_case,
+            schema_cache: RwLock::new(None),
+        }
+    }
+
+    /// Esquema vigente. Pasado `SCHEMA_TTL` se comprueba la huella de los metadatos
+    /// (entidades, atributos o tipos de datos) y, si ha cambiado, se regenera, sea cual
+    /// sea la instancia que los modificó.
+    async fn schema(&self) -> Result<Arc<Schema>, ApplicationError> {
+        if let Some(cached) = self.schema_cache.read().await.as_ref() {
+            if cached.checked_at.elapsed() < SCHEMA_TTL {
+                return Ok(cached.schema.clone());
+            }
+        }
+
+        let fingerprint = self.le_query_repository
+            .metadata_fingerprint()
+            .await
+            .map_err(|e| ApplicationError::InfrastructureError(format!("Error al consultar la huella de los metadatos: {}", e)))?;
+
+        if let Some(cached) = self.schema_cache.write().await.as_mut().filter(|c| c.fingerprint == fingerprint) {
+            cached.checked_at = Instant::now();
+            return Ok(cached.schema.clone());
+        }
+
+        info!("Generando esquema GraphQL (huella de metadatos {})", fingerprint);
+        let schema = Arc::new(self.build_schema().await?);
+        *self.schema_cache.write().await = Some(CachedSchema { fingerprint, schema: schema.clone(), checked_at: Instant::now() });
+        Ok(schema)
+    }
+
+    async fn build_schema(&self) -> Result<Schema, ApplicationError> {
+        // find_all devuelve las entidades ordenadas por nombre
+        let mut details = Vec::new();
+        let mut offset = 0;
+        loop {
+            let page = self.le_query_repository
+                .find_all(MAX_PAGE_SIZE, offset)
+                .await
+                .map_err(|e| ApplicationError::InfrastructureError(format!("Error al listar entidades lógicas: {}", e)))?;
+            let done = (page.len() as i64) < MAX_PAGE_SIZE;
+            offset += page.len() as i64;
+
+            // Atributos de todas las entidades de la página en una sola consulta
+            let entities: Vec<_> = page.into_iter().filter(|e| e.status == 1).collect();
+            let entity_ids: Vec<Uuid> = entities.iter().map(|e| e.id).collect();
+            let found = match entity_ids.is_empty() {
+                true => Vec::new(),
+                false => self.attribute_query_repository
+                    .find_by_entity_ids(&entity_ids)
+                    .await
+                    .map_err(|e| {
+                        error!("Error al consultar atributos de las entidades {:?}: {}", entity_ids, e);
+                        ApplicationError::InfrastructureError(format!("Error al consultar atributos: {}", e))
+                    })?,
+            };
+            let mut attributes: HashMap<Uuid, Vec<AttributeDto>> = HashMap::new();
+            for attribute in found {
+                attributes.entry(attribute.entity_id).or_default().push(attribute);
+            }
+            details.extend(entities.into_iter().map(|entity| {
+                let attributes = attributes.remove(&entity.id).unwrap_or_default();
+                LogicalEntityDetailsDto { entity, attributes }
+            }));
+
+            if done {
+                break;
+            }
+        }
+
+        let graphql_entities: Vec<GraphQLEntity<'_>> = details.iter()
+            .map(|d| GraphQLEntity {
+                id: d.entity.id,
+                name: &d.entity.name,
+                description: d.entity.description.as_deref(),
+                attributes: d.attributes.iter()
+                    .filter(|a| a.status == 1)
+                    .map(|a| GraphQLAttribute {
+                        name: &a.name,
+                        description: a.description.as_deref(),
+                        storage: &a.storage,
+                        is_required: a.is_required,
+                        has_default: a.default_value.is_some(),
+                        reference_entity_id: a.reference.as_ref().map(|r| r.entity_id),
+                    })
+                    .collect(),
+            })
+            .collect();
+        let schema = Schema::build(&graphql_entities);
+        debug!("Esquema GraphQL con {} entidades ({} tipos)", schema.entities().len(), schema.types().len());
+        Ok(schema)
+    }
+
+    /// Resuelve un campo de `Query` o `Mutation`. Si falla, el campo queda a null
+    /// y el error se añade a `errors`.
+    async fn resolve_root_field(
+        &self,
+        schema: &Schema,
+        root_type: &str,
+        field: &PlannedField,
+        context: &ChangeContext,
+        errors: &mut Vec<GraphQLErrorDto>,
+    ) -> Value {
+        let path = vec![json!(field.response_key)];
+        let result = match &field.resolver {
+            FieldResolver::TypeName => Ok(json!(root_type)),
+            FieldResolver::Introspection => Ok(resolve_meta_field(schema, field)),
+            FieldResolver::EntityNames => Ok(json!(schema.entities().iter().map(|e| e.name.as_str()).collect::<Vec<_>>())),
+            FieldResolver::FindRecord(entity) => self.find_record(schema, entity, field, &path, errors).await,
+            FieldResolver::ListRecords(entity) => self.list_records(schema, entity, field, &path, errors).await,
+            FieldResolver::CreateRecord(entity) => {
+                let values = attribute_values(schema, &format!("{}Input", entity.type_name), field.argument("input"));
+                match self.create_record_use_case.execute(&entity.name, values, context.clone()).await {
+                    Ok(record) => Ok(self.complete_record(schema, entity, record, &path, field, errors).await),
+                    Err(e) => Err(e),
+                }
+            },
+            FieldResolver::UpdateRecord(entity) => match record_id(field) {
+                Ok(id) => {
+                    let values = attribute_values(schema, &format!("{}Patch", entity.type_name), field.argument("input"));
+                    match self.update_record_use_case.execute(&entity.name, id, values, RecordUpdateMode::Merge, context.clone(), None).await {
+                        Ok(record) => Ok(self.complete_record(schema, entity, record, &path, field, errors).await),
+                        Err(e) => Err(e),
+                    }
+                },
+                Err(e) => Err(e),
+            },
+            FieldResolver::DeleteRecord(entity) => match record_id(field) {
+                Ok(id) => self.delete_record_use_case.execute(&entity.name, id, context.clone(), None).await.map(|_| json!(id.to_string())),
+                Err(e) => Err(e),
+            },
+            _ => Ok(Value::Null),
+        };
+
+        result.unwrap_or_else(|e| {
+            errors.push(graphql_error(e, path));
+            Value::Null
+        })
+    }
+
+    async fn find_record(
+        &self,
+        schema: &Schema,
+        entity: &EntityRef,
+        field: &PlannedField,
+        path: &[Value],
+        errors: &mut Vec<GraphQLErrorDto>,
+    ) -> Result<Value, ApplicationError> {
+        let id = record_id(field)?;
+        match self.find_record_use_case.execute(&entity.name, id).await {
+            Ok(record) => Ok(self.complete_record(schema, entity, record, path, field, errors).await),
+            // Un registro inexistente es null, no un error
+            Err(ApplicationError::NotFound(_)) => Ok(Value::Null),
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn list_records(
+        &self,
+        schema: &Schema,
+        entity: &EntityRef,
+        field: &PlannedField,
+        path: &[Value],
+        errors: &mut Vec<GraphQLErrorDto>,
+    ) -> Result<Value, ApplicationError> {
+        let text = |name: &str| field.argument(name).and_then(Value::as_str).map(str::to_string);
+        let params = ListRecordsParams {
+            filter: text("filter"),
+            sort: text("sort"),
+            limit: field.argument("first").and_then(Value::as_i64).unwrap_or(DEFAULT_PAGE_SIZE),
+            after: text("after"),
+        };
+        let mut page = self.list_records_use_case.execute(&entity.name, params).await?;
+
+        let mut object = Map::new();
+        for sub_field in &field.selection {
+            let value = match sub_field.resolver {
+                FieldResolver::TypeName => json!(format!("{}Page", entity.type_name)),
+                FieldResolver::PageNextCursor => json!(page.next_cursor),
+                FieldResolver::PageItems => {
+                    let items = std::mem::take(&mut page.items);
+                    let paths = (0..items.len())
+                        .map(|i| child_path(&child_path(path, json!(sub_field.response_key)), json!(i)))
+                        .collect();
+                    let (values, item_errors) = self
+                        .complete_records(schema, &entity.type_name, items, paths, &sub_field.selection)
+                        .await;
+                    errors.extend(item_errors);
+                    Value::Array(values)
+                },
+                _ => Value::Null,
+            };
+            object.insert(sub_field.response_key.clone(), value);
+        }
+        Ok(Value::Object(object))
+    }
+
+    async fn complete_record(
+        &self,
+        schema: &Schema,
+        entity: &EntityRef,
+        record: RecordDto,
+        path: &[Value],
+        field: &PlannedField,
+        errors: &mut Vec<GraphQLErrorDto>,
+    ) -> Value {
+        let (mut values, record_errors) = self
+            .complete_records(schema, &entity.type_name, vec![record], vec![path.to_vec()], &field.selection)
+            .await;
+        errors.extend(record_errors);
+        values.pop().unwrap_or(Value::Null)
+    }
+
+    /// Construye la respuesta de varios registros del mismo tipo. Los registros
+    /// referenciados se cargan con una sola consulta por campo y nivel (no una por
+    /// registro), y se completan a su vez de forma recursiva.
+    /// `paths`: ruta de respuesta de cada registro, para los errores.
+    fn complete_records<'a>(
+        &'a self,
+        schema: &'a Schema,
+        type_name: &'a str,
+        records: Vec<RecordDto>,
+        paths: Vec<Vec<Value>>,
+        selection: &'a [PlannedField],
+    ) -> BoxFuture<'a, (Vec<Value>, Vec<GraphQLErrorDto>)> {
+        async move {
+            let mut errors = Vec::new();
+            // clave de respuesta -> (id -> registro referenciado ya completado)
+            let mut loaded: HashMap<&str, HashMap<Uuid, Value>> = HashMap::new();
+
+            for field in selection {
+                let FieldResolver::Reference { attribute, entity } = &field.resolver else {
+                    continue;
+                };
+                if !needs_record(field) {
+                    continue;
+                }
+
+                // IDs referenciados (sin repetir), con la ruta de su primera aparición
+                let mut seen = HashSet::new();
+                let mut ids = Vec::new();
+                let mut first_paths = Vec::new();
+                for (record, path) in records.iter().zip(&paths) {
+                    if let Some(id) = reference_id(record, attribute) {
+                        if seen.insert(id) {
+                            ids.push(id);
+                            first_paths.push(child_path(path, json!(field.response_key)));
+                        }
+                    }
+                }
+                if ids.is_empty() {
+                    loaded.insert(field.response_key.as_str(), HashMap::new());
+                    continue;
+                }
+
+                debug!("Cargando {} registros de '{}' referenciados por '{}'", ids.len(), entity.name, attribute);
+                let targets = match self.record_query_repository.find_by_ids(entity.id, &ids).await {
+                    Ok(targets) => targets,
+                    Err(e) => {
+                        errors.push(graphql_error(
+                            ApplicationError::InfrastructureError(format!("Error al cargar registros referenciados: {}", e)),
+                            first_paths[0].clone(),
+                        ));
+                        loaded.insert(field.response_key.as_str(), HashMap::new());
+                        continue;
+                    },
+                };
+
+                let target_ids: Vec<Uuid> = targets.iter().map(|t| t.id).collect();
+                let target_paths = target_ids.iter()
+                    .map(|id| ids.iter().position(|i| i == id).map(|i| first_paths[i].clone()).unwrap_or_default())
+                    .collect();
+                let (values, target_errors) = self
+                    .complete_records(schema, &entity.type_name, targets, target_paths, &field.selection)
+                    .await;
+                errors.extend(target_errors);
+                loaded.insert(field.response_key.as_str(), target_ids.into_iter().zip(values).collect());
+            }
+
+            let values = records.iter()
+                .map(|record| {
+                    let mut object = Map::new();
+                    for field in selection {
+                        let value = match &field.resolver {
+                            FieldResolver::TypeName => json!(type_name),
+                            FieldResolver::System(system) => system_value(record, *system),
+                            FieldResolver::Attribute(attribute) => record.values.get(attribute).cloned().unwrap_or(Value::Null),
+                            FieldResolver::Reference { attribute, entity } => match reference_id(record, attribute) {
+                                None => Value::Null,
+                                // Un registro referenciado que ya no existe se devuelve como null
+                                Some(id) => match loaded.get(field.response_key.as_str()) {
+                                    Some(targets) => targets.get(&id).cloned().unwrap_or(Value::Null),
+                                    None => id_only(field, id, &entity.type_name),
+                                },
+                            },
+                            _ => Value::Null,
+                        };
+                        object.insert(field.response_key.clone(), value);
+                    }
+                    Value::Object(object)
+                })
+                .collect();
+            (values, errors)
+        }
+        .boxed()
+    }
+}
+
+#[async_trait]
+impl ExecuteGraphQLUseCase for ExecuteGraphQLUseCaseImpl {
+    async fn execute(&self, request: GraphQLRequestDto, context: ChangeContext) -> Result<GraphQLResponseDto, ApplicationError> {
+        info!("Ejecutando caso de uso ExecuteGraphQL: operation={:?}", request.operation_name);
+
+        let schema = self.schema().await?;
+        let document = match parse_document(&request.query) {
+            Ok(document) => document,
+            Err(e) => return Ok(request_error(e, "GRAPHQL_PARSE_FAILED")),
+        };
+        let operation = match plan_operation(&schema, &document, request.operation_name.as_deref(), &request.variables) {
+            Ok(operation) => operation,
+            Err(e) => return Ok(request_error(e, "GRAPHQL_VALIDATION_FAILED")),
+        };
+
+        let root_type = match operation.operation {
+            OperationType::Query => QUERY_TYPE,
+            OperationType::Mutation => MUTATION_TYPE,
+        };
+        // Los campos raíz se resuelven en orden (las mutaciones deben ejecutarse en serie)
+        let mut errors = Vec::new();
+        let mut data = Map::new();
+        for field in &operation.fields {
+            let value = self.resolve_root_field(&schema, root_type, field, &context, &mut errors).await;
+            data.insert(field.response_key.clone(), value);
+        }
+
+        if !errors.is_empty() {
+            info!("Operación GraphQL completada con {} errores", errors.len());
+        }
+        Ok(GraphQLResponseDto { data: Some(Value::Object(data)), errors })
+    }
+}
+
+/// ¿El campo de referencia necesita leer el registro referenciado? No hace
+/// falta si solo se piden `id` y `__typename`.
+fn needs_record(field: &PlannedField) -> bool {
+    field.selection.iter().any(|f| !matches!(f.resolver, FieldResolver::TypeName | FieldResolver::System(SystemField::Id)))
+}
+
+fn child_path(path: &[Value], key: Value) -> Vec<Value> {
+    let mut child = path.to_vec();
+    child.push(key);
+    child
+}
+
+fn id_only(field: &PlannedField, id: Uuid, type_name: &str) -> Value {
+    let object: Map<String, Value> = field.selection.iter()
+        .map(|f| {
+            let value = match f.resolver {
+                FieldResolver::TypeName => json!(type_name),
+                _ => json!(id.to_string()),
+            };
+            (f.response_key.clone(), value)
+        })
+        .collect();
+    Value::Object(object)
+}
+
+fn reference_id(record: &RecordDto, attribute: &str) -> Option<Uuid> {
+    record.values.get(attribute)
+        .and_then(Value::as_str)
+        .and_then(|id| Uuid::parse_str(id).ok())
+}
+
+fn system_value(record: &RecordDto, field: SystemField) -> Value {
+    match field {
+        SystemField::Id => json!(record.id.to_string()),
+        SystemField::CreatedAt => json!(record.created_at),
+        SystemField::CreatedBy => json!(record.created_by),
+        SystemField::UpdatedAt => json!(record.updated_at),
+        SystemField::UpdatedBy => json!(record.updated_by),
+        SystemField::Status => json!(record.status),
+    }
+}
+
+fn record_id(field: &PlannedField) -> Result<Uuid, ApplicationError> {
+    let id = field.argument("id").and_then(Value::as_str).unwrap_or_default();
+    Uuid::parse_str(id).map_err(|_| ApplicationError::ValidationError(format!("'{}' no es un ID de registro válido", id)))
+}
+
+/// Valores de un objeto de entrada (`<Tipo>Input` o `<Tipo>Patch`) indexados por
+/// nombre de atributo, que es lo que esperan los casos de uso de registros.
+fn attribute_values(schema: &Schema, input_type: &str, input: Option<&Value>) -> Map<String, Value> {
+    let Some(fields) = schema.type_def(input_type).map(|t| &t.input_fields) else {
+        return Map::new();
+    };
+    input.and_then(Value::as_object)
+        .map(|object| object.iter()
+            .filter_map(|(name, value)| {
+                fields.iter()
+                    .find(|f| f.name == *name)
+                    .and_then(|f| f.attribute.clone())
+                    .map(|attribute| (attribute, value.clone()))
+            })
+            .collect())
+        .unwrap_or_default()
+}
+
+/// Respuesta sin `data` para una consulta que no se pudo analizar o validar.
+fn request_error(error: DomainError, code: &str) -> GraphQLResponseDto {
+    let message = match error {
+        DomainError::ValidationError(message) => message,
+        other => other.to_string(),
+    };
+    GraphQLResponseDto {
+        data: None,
+        errors: vec![GraphQLErrorDto { message, path: None, extensions: Some(json!({ "code": code })) }],
+    }
+}
+
+fn graphql_error(error: ApplicationError, path: Vec<Value>) -> GraphQLErrorDto {
+    let (code, message, fields) = match error {
+        ApplicationError::NotFound(message) => ("NOT_FOUND", message, None),
+        ApplicationError::ValidationError(message) => ("BAD_USER_INPUT", message, None),
+        ApplicationError::FieldValidationErrors(fields) => {
+            ("BAD_USER_INPUT", "Uno o más campos no son válidos".to_string(), Some(json!(fields)))
+        },
+        ApplicationError::Conflict(message) => ("CONFLICT", message, None),
+        ApplicationError::PreconditionFailed(message) => ("PRECONDITION_FAILED", message, None),
+        ApplicationError::AuthenticationError(message) => ("UNAUTHENTICATED", message, None),
+        ApplicationError::AuthorizationError(message) => ("FORBIDDEN", message, None),
+        ApplicationError::InfrastructureError(message) | ApplicationError::UnexpectedError(message) => {
+            ("INTERNAL_SERVER_ERROR", message, None)
+        },
+    };
+    let mut extensions = Map::new();
+    extensions.insert("code".to_string(), json!(code));
+    if let Some(fields) = fields {
+        extensions.insert("fields".to_string(), fields);
+    }
+    GraphQLErrorDto { message, path: Some(path), extensions: Some(Value::Object(extensions)) }
+}
@@ -0,0 +1,3 @@
+pub mod execute_graphql;
+
+pub use execute_graphql::{ExecuteGraphQLUseCase, ExecuteGraphQLUseCaseImpl};
@@ -6,17 +6,26 @@ use uuid::Uuid;
 use thiserror::Error;
 use log::{error, info, debug}; // Añadido debug
 use anyhow::{Result, anyhow, Context}; // Necesario para UoW y errores
-use std::collections::HashSet; // Para validar nombres de atributo duplicados
+use std::collections::{HashMap, HashSet}; // Para validar nombres de atributo duplicados
 
 // --- Importar Ports ---
 use crate::Application::ports::unit_of_work::{UnitOfWork, RepositoryRegistry};
+use diesel_async::scoped_futures::ScopedFutureExt;
 use crate::Application::ports::driven::repositories::{
     LogicalEntityCommandRepository, // Para crear la entidad
     AttributeCommandRepository,     // Para crear los atributos
     DataTypeQueryRepository,        // Para buscar ID de tipo de dato
-    // Opcional: LogicalEntityQueryRepository si quieres verificar existencia de entidad primero
-    // LogicalEntityQueryRepository,
+    LogicalEntityQueryRepository,   // Para resolver las referencias y su atributo a mostrar
+    AttributeQueryRepository,
 };
+use crate::Application::dtos::attribute_dto::ReferenceDefinitionDto;
+use crate::Application::errors::application_error::ApplicationError;
+use crate::Application::use_cases::attributes::attribute_schema::{resolve_reference, validate_reference_settings, validate_searchable};
+use crate::Domain::attribute_references::AttributeReference;
+use crate::Domain::sql_identifiers::{validate_entity_name, validate_attribute_name};
+use crate::Domain::physical_tables::StorageMode;
+use crate::Domain::views::{AttributeInfo, ViewPivotStrategy, generate_view_sql, view_name_for};
+use super::entity_view::{ReferencedEntities, load_referenced_entities, reference_display};
 
 // --- 1. Comando de Entrada (Como se definió antes) ---
 #[derive(Debug, Clone)]
@@ -29,12 +38,18 @@ pub struct AttributeDefinitionCommand {
     pub is_unique: Option<i16>,
     pub default_value: Option<String>,
     pub validation_regex: Option<String>,
+    /// Incluye el atributo (string/text) en la búsqueda de texto de la entidad.
+    pub is_searchable: bool,
+    /// Solo en atributos de tipo referencia: la entidad referenciada debe existir.
+    pub reference: Option<ReferenceDefinitionDto>,
 }
 
 #[derive(Debug, Clone)]
 pub struct CreateEntityWithAttributesCommand {
     pub entity_name: String,
     pub attributes: Vec<AttributeDefinitionCommand>,
+    /// Si es true, se genera la vista de la entidad y se registra en assign_view.
+    pub assign_view: bool,
     pub created_by_user_id: Uuid,
 }
 
@@ -55,6 +70,28 @@ pub enum CreateEntityError {
     Unexpected(String),
 }
 
+// Conversión hacia el error común de aplicación (usado por ErrorAdapter)
+impl From<CreateEntityError> for crate::Application::errors::application_error::ApplicationError {
+    fn from(err: CreateEntityError) -> Self {
+        use crate::Application::errors::application_error::ApplicationError;
+        match err {
+            CreateEntityError::ValidationError(msg) => ApplicationError::ValidationError(msg),
+            CreateEntityError::DataTypeNotFound(_) => ApplicationError::ValidationError(err.to_string()),
+            CreateEntityError::EntityConflict(_) | CreateEntityError::AttributeConflict { .. } => ApplicationError::Conflict(err.to_string()),
+            CreateEntityError::DatabaseError(msg) => ApplicationError::InfrastructureError(msg),
+            CreateEntityError::Unexpected(msg) => ApplicationError::UnexpectedError(msg),
+        }
+    }
+}
+
+/// Índice único parcial de los nombres de las entidades que no están en la papelera.
+const ACTIVE_NAME_INDEX: &str = "idx_logical_entities_active_name";
+
+/// Indica si el error (o alguna de sus causas) es la violación de `ACTIVE_NAME_INDEX`.
+fn is_name_conflict(error: &(dyn std::error::Error + 'static)) -> bool {
+    std::iter::successors(Some(error), |e| e.source()).any(|e| e.to_string().contains(ACTIVE_NAME_INDEX))
+}
+
 // --- 3. Trait del Caso de Uso (Como se definió antes) ---
 #[async_trait]
 pub trait CreateEntityWithAttributesUseCase: Send + Sync {
@@ -64,11 +101,17 @@ pub trait CreateEntityWithAttributesUseCase: Send + Sync {
 // --- 4. Implementación del Caso de Uso (COMPLETADA) ---
 pub struct CreateEntityWithAttributesUseCaseImpl {
     uow: Arc<dyn UnitOfWork>,
+    le_query_repository: Arc<dyn LogicalEntityQueryRepository>,
+    attribute_query_repository: Arc<dyn AttributeQueryRepository>,
 }
 
 impl CreateEntityWithAttributesUseCaseImpl {
-    pub fn new(uow: Arc<dyn UnitOfWork>) -> Self {
-        Self { uow }
+    pub fn new(
+        uow: Arc<dyn UnitOfWork>,
+        le_query_repository: Arc<dyn LogicalEntityQueryRepository>,
+        attribute_query_repository: Arc<dyn AttributeQueryRepository>,
+    ) -> Self {
+        Self { uow, le_query_repository, attribute_query_repository }
     }
 }
 
@@ -78,35 +121,68 @@ impl CreateEntityWithAttributesUseCase for CreateEntityWithAttributesUseCaseImpl
         info!("Executing CreateEntityWithAttributesUseCase for entity: {}", command.entity_name);
 
         // --- Validación de Negocio Inicial ---
-        // 1. Verificar nombres de atributo duplicados en el request
+        // 1. Los nombres acaban en la vista: deben ser identificadores SQL válidos y sin duplicados
+        validate_entity_name(&command.entity_name).map_err(|e| CreateEntityError::ValidationError(e.to_string()))?;
         let mut attribute_names = HashSet::new();
         for attr in &command.attributes {
+            validate_attribute_name(&attr.name).map_err(|e| CreateEntityError::ValidationError(e.to_string()))?;
             if !attribute_names.insert(attr.name.clone()) {
                 let error_msg = format!("Duplicate attribute name '{}' provided in the request for entity '{}'", attr.name, command.entity_name);
                 error!("{}", error_msg);
                 return Err(CreateEntityError::ValidationError(error_msg));
             }
         }
-        // 2. Otras validaciones si son necesarias (ej: posición única, etc.)
+        // 2. Resolver las referencias a otras entidades (deben existir previamente)
+        let mut references: HashMap<String, AttributeReference> = HashMap::new();
+        for attr in &command.attributes {
+            if let Some(definition) = &attr.reference {
+                let reference = resolve_reference(
+                    self.le_query_repository.as_ref(),
+                    self.attribute_query_repository.as_ref(),
+                    &attr.name,
+                    definition,
+                ).await.map_err(|e| match e {
+                    ApplicationError::ValidationError(msg) => CreateEntityError::ValidationError(msg),
+                    other => CreateEntityError::DatabaseError(other.to_string()),
+                })?;
+                references.insert(attr.name.clone(), reference);
+            }
+        }
+        // 3. La vista se genera dentro de la transacción: las entidades referenciadas (y sus
+        //    atributos a mostrar) se cargan antes, porque ya existen y no cambian con la creación
+        let referenced: ReferencedEntities = if command.assign_view {
+            load_referenced_entities(
+                self.le_query_repository.as_ref(),
+                self.attribute_query_repository.as_ref(),
+                Uuid::nil(),
+                references.values(),
+            ).await.map_err(|e| CreateEntityError::DatabaseError(e.to_string()))?
+        } else {
+            HashMap::new()
+        };
+        // 4. Otras validaciones si son necesarias (ej: posición única, etc.)
 
         // Clonar datos necesarios para la clausura 'async move'
         let entity_name_clone = command.entity_name.clone();
         let attributes_clone = command.attributes.clone();
         let user_id_clone = command.created_by_user_id;
+        let assign_view = command.assign_view;
 
         // --- Ejecutar dentro de la Unidad de Trabajo ---
-        let result = self.uow.execute(move |registry: &dyn RepositoryRegistry| async move {
+        let result = self.uow.execute(move |registry: &mut dyn RepositoryRegistry| async move {
             debug!("Inside Unit of Work for creating entity '{}'", entity_name_clone);
 
             // Obtener repositorios necesarios DESDE el registry
             let entity_cmd_repo = registry.logical_entity_command_repository();
             let attribute_cmd_repo = registry.attribute_command_repository();
             let data_type_query_repo = registry.data_type_query_repository();
-            // Opcional: let entity_query_repo = registry.logical_entity_query_repository();
+            let schema_version_repo = registry.schema_version_command_repository();
+            let entity_query_repo = registry.logical_entity_query_repository();
+            let conn = registry.get_diesel_async_conn();
 
-            // --- (Opcional) Verificar si la entidad ya existe ---
-            // Descomentar si se añade LogicalEntityQueryRepository a la UoW y se implementa
-            /*
+            // --- Verificar si la entidad ya existe ---
+            // Solo cuentan las entidades fuera de la papelera; dos creaciones simultáneas
+            // las resuelve el índice único parcial de logical_entities.name
             match entity_query_repo.exists_by_name(&entity_name_clone).await {
                 Ok(true) => {
                     let err = CreateEntityError::EntityConflict(entity_name_clone.clone());
@@ -120,16 +196,14 @@ impl CreateEntityWithAttributesUseCase for CreateEntityWithAttributesUseCaseImpl
                     return Err(anyhow!(err));
                 }
             }
-            */
 
             // --- Crear la Entidad ---
             debug!("Attempting to create entity '{}'", entity_name_clone);
             let new_entity_id = match entity_cmd_repo.create(
-                // La conexión se pasa aquí si el trait/impl lo requiere
-                // conn, // <--- Descomentar y pasar si es necesario
+                conn,
                 &entity_name_clone,
                 None, // Descripción no viene del comando principal, podría añadirse
-                None, // Assign view tampoco
+                None, // La vista se registra al crearla, al final de la transacción
                 user_id_clone,
             ).await {
                 Ok(id) => {
@@ -137,22 +211,25 @@ impl CreateEntityWithAttributesUseCase for CreateEntityWithAttributesUseCaseImpl
                     id
                 },
                 Err(e) => {
-                    // Podríamos intentar detectar si el error es por conflicto de nombre
-                    // basado en el mensaje de error de la BD (frágil) o código de error.
-                    // Por ahora, lo tratamos como error genérico de BD.
-                    let err = CreateEntityError::DatabaseError(format!("Failed to create entity: {}", e));
+                    // Otra creación con el mismo nombre pudo confirmarse tras la comprobación
+                    let err = match is_name_conflict(&*e) {
+                        true => CreateEntityError::EntityConflict(entity_name_clone.clone()),
+                        false => CreateEntityError::DatabaseError(format!("Failed to create entity: {}", e)),
+                    };
                     error!("{}", err);
                     return Err(anyhow!(err));
                 }
             };
 
             // --- Crear los Atributos (Iterar) ---
+            // (ID, nombre, posición y almacenamiento) de cada atributo, para generar la vista
+            let mut created_attributes = Vec::with_capacity(attributes_clone.len());
             for attr_cmd in attributes_clone {
                 debug!("Processing attribute '{}' for entity '{}'", attr_cmd.name, entity_name_clone);
 
-                // 1. Buscar ID del Tipo de Dato por nombre
-                let data_type_id = match data_type_query_repo.find_id_by_name(&attr_cmd.data_type_name).await {
-                    Ok(Some(id)) => id,
+                // 1. Buscar el Tipo de Dato por nombre (ID y definición de almacenamiento)
+                let data_type = match data_type_query_repo.find_by_name(&attr_cmd.data_type_name).await {
+                    Ok(Some(data_type)) => data_type,
                     Ok(None) => {
                         let err = CreateEntityError::DataTypeNotFound(attr_cmd.data_type_name.clone());
                         error!("Data type lookup failed: {}", err);
@@ -164,11 +241,26 @@ impl CreateEntityWithAttributesUseCase for CreateEntityWithAttributesUseCaseImpl
                         return Err(anyhow!(err));
                     }
                 };
+                let data_type_id = data_type.id;
                 debug!("Found data_type_id: {} for name '{}'", data_type_id, attr_cmd.data_type_name);
 
+                // Los atributos de tipo referencia necesitan su configuración (y solo ellos la admiten)
+                let reference = references.get(&attr_cmd.name);
+                if let Err(e) = validate_reference_settings(
+                    &attr_cmd.name,
+                    &data_type.storage,
+                    reference,
+                    attr_cmd.is_required,
+                    attr_cmd.default_value.as_deref(),
+                ).and_then(|_| validate_searchable(&attr_cmd.name, &data_type.storage, attr_cmd.is_searchable)) {
+                    let err = CreateEntityError::ValidationError(e.to_string());
+                    error!("{}", err);
+                    return Err(anyhow!(err));
+                }
+
                 // 2. Crear el Atributo
                 match attribute_cmd_repo.create(
-                    // conn, // <--- Descomentar y pasar si es necesario
+                    conn,
                     new_entity_id,
                     data_type_id,
                     &attr_cmd.name,
@@ -178,10 +270,14 @@ impl CreateEntityWithAttributesUseCase for CreateEntityWithAttributesUseCaseImpl
                     attr_cmd.is_unique,
                     attr_cmd.default_value.as_deref(),
                     attr_cmd.validation_regex.as_deref(),
+                    false,
+                    attr_cmd.is_searchable,
+                    reference,
                     user_id_clone,
                 ).await {
                     Ok(attr_id) => {
                         info!("Attribute '{}' created with ID: {} for entity '{}'", attr_cmd.name, attr_id, entity_name_clone);
+                        created_attributes.push((attr_id, attr_cmd.name, attr_cmd.position, data_type.storage));
                     }
                     Err(e) => {
                         // Intentar detectar conflicto UNIQUE(entity_id, name)
@@ -196,14 +292,56 @@ impl CreateEntityWithAttributesUseCase for CreateEntityWithAttributesUseCaseImpl
                 }
             }
 
+            // --- Versión inicial de la definición ---
+            if let Err(e) = schema_version_repo.create_snapshot(conn, new_entity_id, Some("Versión inicial"), user_id_clone).await {
+                let err = CreateEntityError::DatabaseError(format!("Failed to record schema version: {}", e));
+                error!("{}", err);
+                return Err(anyhow!(err));
+            }
+
+            // --- Generar la vista de la entidad (si se solicitó) ---
+            // El DDL se ejecuta en la misma transacción: si falla, no queda la entidad sin su vista.
+            // Una entidad nueva guarda sus valores en EAV y usa las opciones de vista por defecto.
+            if assign_view {
+                let attributes_info: Vec<AttributeInfo<'_>> = created_attributes
+                    .iter()
+                    .map(|(attribute_id, name, position, storage)| AttributeInfo {
+                        attribute_id: *attribute_id,
+                        name: name.as_str(),
+                        position: *position,
+                        storage,
+                        show_option_label: false,
+                        reference_display: reference_display(references.get(name), (new_entity_id, Default::default(), StorageMode::Eav), &referenced),
+                    })
+                    .collect();
+                let pivot = ViewPivotStrategy::resolve(None, attributes_info.len());
+                let view_name = view_name_for(&entity_name_clone);
+                let view_sql = match generate_view_sql(new_entity_id, &entity_name_clone, &attributes_info, false, pivot, StorageMode::Eav) {
+                    Ok(sql) => sql,
+                    Err(e) => {
+                        let err = CreateEntityError::ValidationError(format!("The view of entity '{}' could not be generated: {}", entity_name_clone, e));
+                        error!("{}", err);
+                        return Err(anyhow!(err));
+                    }
+                };
+                debug!("SQL de la vista '{}' (pivote {}):\n{}", view_name, pivot.as_str(), view_sql);
+
+                if let Err(e) = entity_cmd_repo.create_view(conn, new_entity_id, &view_name, &view_sql).await {
+                    let err = CreateEntityError::DatabaseError(format!("Failed to create view '{}': {}", view_name, e));
+                    error!("{}", err);
+                    return Err(anyhow!(err));
+                }
+                info!("Vista '{}' creada para la entidad {}", view_name, new_entity_id);
+            }
+
             // Si todo fue bien, retornar el ID de la entidad creada
             Ok(new_entity_id)
 
-        }).await; // Espera a que la UoW termine (commit/rollback)
+        }.scope_boxed()).await; // Espera a que la UoW termine (commit/rollback)
 
         // Mapear el resultado de la UoW (Result<Uuid, anyhow::Error>)
         // al tipo de retorno del caso de uso (Result<Uuid, CreateEntityError>)
-        result.map_err(|e| {
+        let entity_id = result.map_err(|e| {
             // Intenta convertir el error de anyhow de nuevo a CreateEntityError
             match e.downcast::<CreateEntityError>() {
                 Ok(app_err) => app_err, // Si ya era nuestro error, lo devolvemos
@@ -213,7 +351,9 @@ impl CreateEntityWithAttributesUseCase for CreateEntityWithAttributesUseCaseImpl
                     CreateEntityError::Unexpected(other_err.to_string())
                 }
             }
-        })
+        })?;
+
+        Ok(entity_id)
     }
 }
 
@@ -0,0 +1,113 @@
+// src/Application/use_cases/logical_entities/delete_logical_entity.rs
+
+use async_trait::async_trait;
+use std::sync::Arc;
+use uuid::Uuid;
+use log::{info, error};
+use anyhow::anyhow;
+
+use crate::Application::errors::application_error::ApplicationError;
+use crate::Application::ports::unit_of_work::{UnitOfWork, RepositoryRegistry};
+use diesel_async::scoped_futures::ScopedFutureExt;
+use crate::Application::ports::driven::repositories::LogicalEntityQueryRepository;
+use crate::Domain::views::ViewRepository;
+use super::entity_view::remove_entity_view;
+
+#[async_trait]
+pub trait DeleteLogicalEntityUseCase: Send + Sync {
+    /// Mueve la entidad a la papelera y elimina su vista (se regenera al restaurarla).
+    async fn execute(&self, id: Uuid, deleted_by: Uuid) -> Result<(), ApplicationError>;
+}
+
+pub struct DeleteLogicalEntityUseCaseImpl {
+    le_query_repository: Arc<dyn LogicalEntityQueryRepository>,
+    view_repository: Arc<dyn ViewRepository>,
+    uow: Arc<dyn UnitOfWork>,
+}
+
+impl DeleteLogicalEntityUseCaseImpl {
+    pub fn new(
+        le_query_repository: Arc<dyn LogicalEntityQueryRepository>,
+        view_repository: Arc<dyn ViewRepository>,
+        uow: Arc<dyn UnitOfWork>,
+    ) -> Self {
+        Self { le_query_repository, view_repository, uow }
+    }
+}
+
+#[async_trait]
+impl DeleteLogicalEntityUseCase for DeleteLogicalEntityUseCaseImpl {
+    async fn execute(&self, id: Uuid, deleted_by: Uuid) -> Result<(), ApplicationError> {
+        info!("Ejecutando caso de uso DeleteLogicalEntity: id='{}'", id);
+
+        // Nombre de la vista asignada (si existe) para eliminarla junto con la entidad;
+        // assign_view se conserva para regenerarla al restaurar
+        let view_name = self.le_query_repository
+            .find_by_id(id)
+            .await
+            .map_err(|e| ApplicationError::InfrastructureError(format!("Error al buscar entidad lógica: {}", e)))?
+            .ok_or_else(|| ApplicationError::NotFound(format!("Entidad lógica con ID {} no encontrada", id)))?
+            .assign_view;
+
+        self.uow.execute(move |registry: &mut dyn RepositoryRegistry| async move {
+            let attribute_query_repo = registry.attribute_query_repository();
+            let cmd_repo = registry.logical_entity_command_repository();
+            let conn = registry.get_diesel_async_conn();
+
+            // Las referencias desde la propia entidad se eliminan con ella
+            let referencing: Vec<String> = attribute_query_repo.find_referencing(id).await
+                .map_err(|e| anyhow!("Failed to load attributes referencing entity {}: {}", id, e))?
+                .into_iter()
+                .filter(|a| a.entity_id != id)
+                .map(|a| format!("'{}'", a.name))
+                .collect();
+            if !referencing.is_empty() {
+                return Err(anyhow!(ApplicationError::Conflict(format!(
+                    "La entidad lógica {} está referenciada por los atributos {} de otras entidades",
+                    id, referencing.join(", ")
+                ))));
+            }
+
+            let affected = cmd_repo.soft_delete(conn, id, deleted_by).await
+                .map_err(|e| anyhow!("Failed to move logical entity {} to trash: {}", id, e))?;
+
+            if affected == 0 {
+                return Err(anyhow!(ApplicationError::NotFound(format!("Entidad lógica con ID {} no encontrada", id))));
+            }
+            Ok(())
+        }.scope_boxed()).await.map_err(|e| match e.downcast::<ApplicationError>() {
+            Ok(app_err) => app_err,
+            Err(other) => {
+                error!("Error durante la Unidad de Trabajo al eliminar la entidad {}: {:?}", id, other);
+                ApplicationError::InfrastructureError(format!("Error en transacción al eliminar entidad lógica: {}", other))
+            }
+        })?;
+
+        info!("Entidad lógica {} movida a la papelera", id);
+
+        remove_entity_view(self.view_repository.as_ref(), self.uow.as_ref(), id, view_name.as_deref(), false).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Application::ports::driven::repositories::LogicalEntityDto;
+    use crate::Application::use_cases::test_support::{FailingUnitOfWork, EntityQueryStub, UnusedViewRepository};
+
+    #[tokio::test]
+    async fn entity_referenced_by_other_entities_is_a_conflict() {
+        let entity = LogicalEntityDto::for_tests("clientes");
+        let id = entity.id;
+        // La vista solo se elimina si la entidad llega a la papelera
+        let use_case = DeleteLogicalEntityUseCaseImpl::new(
+            Arc::new(EntityQueryStub { entity }),
+            Arc::new(UnusedViewRepository),
+            Arc::new(FailingUnitOfWork::new(ApplicationError::Conflict("referenciada".to_string()))),
+        );
+
+        let result = use_case.execute(id, Uuid::new_v4()).await;
+
+        assert!(matches!(result, Err(ApplicationError::Conflict(_))));
+    }
+}
@@ -0,0 +1,128 @@
+// src/Application/use_cases/logical_entities/entity_json_schema.rs
+
+use async_trait::async_trait;
+use serde_json::Value;
+use std::sync::Arc;
+use uuid::Uuid;
+use log::info;
+
+use crate::Application::dtos::logical_entity_dto::{EntityJsonSchemaDto, LogicalEntityDetailsDto};
+use crate::Application::errors::application_error::ApplicationError;
+use crate::Application::ports::driven::repositories::{LogicalEntityQueryRepository, AttributeQueryRepository};
+use crate::Domain::json_schemas::{SchemaAttribute, entity_json_schema};
+use super::find_logical_entity::load_entity_details;
+use super::list_logical_entities::MAX_PAGE_SIZE;
+
+/// JSON Schema de la entidad a partir de sus atributos activos. Los atributos
+/// retirados no admiten valores nuevos: no forman parte del esquema.
+fn json_schema_for(details: &LogicalEntityDetailsDto) -> Value {
+    let attributes: Vec<SchemaAttribute<'_>> = details.attributes
+        .iter()
+        .filter(|a| a.status == 1)
+        .map(|a| SchemaAttribute {
+            name: &a.name,
+            description: a.description.as_deref(),
+            position: a.position,
+            storage: &a.storage,
+            is_required: a.is_required,
+            default_value: a.default_value.as_deref(),
+            validation_regex: a.validation_regex.as_deref(),
+            options: &a.options,
+        })
+        .collect();
+
+    entity_json_schema(&details.entity.name, details.entity.description.as_deref(), &attributes)
+}
+
+#[async_trait]
+pub trait EntityJsonSchemaUseCase: Send + Sync {
+    /// Genera el JSON Schema (draft 2020-12) de los registros de la entidad
+    /// a partir de sus atributos activos.
+    async fn execute(&self, entity_id: Uuid) -> Result<Value, ApplicationError>;
+}
+
+#[async_trait]
+pub trait ListEntityJsonSchemasUseCase: Send + Sync {
+    /// JSON Schema de cada entidad lógica activa, ordenados por nombre de entidad.
+    async fn execute(&self) -> Result<Vec<EntityJsonSchemaDto>, ApplicationError>;
+}
+
+// --- Implementación: una entidad ---
+pub struct EntityJsonSchemaUseCaseImpl {
+    le_query_repository: Arc<dyn LogicalEntityQueryRepository>,
+    attribute_query_repository: Arc<dyn AttributeQueryRepository>,
+}
+
+impl EntityJsonSchemaUseCaseImpl {
+    pub fn new(
+        le_query_repository: Arc<dyn LogicalEntityQueryRepository>,
+        attribute_query_repository: Arc<dyn AttributeQueryRepository>,
+    ) -> Self {
+        Self { le_query_repository, attribute_query_repository }
+    }
+}
+
+#[async_trait]
+impl EntityJsonSchemaUseCase for EntityJsonSchemaUseCaseImpl {
+    async fn execute(&self, entity_id: Uuid) -> Result<Value, ApplicationError> {
+        info!("Ejecutando caso de uso EntityJsonSchema: id='{}'", entity_id);
+
+        let entity = self.le_query_repository
+            .find_by_id(entity_id)
+            .await
+            .map_err(|e| ApplicationError::InfrastructureError(format!("Error al buscar entidad lógica: {}", e)))?
+            .ok_or_else(|| ApplicationError::NotFound(format!("Entidad lógica con ID {} no encontrada", entity_id)))?;
+        let details = load_entity_details(self.attribute_query_repository.as_ref(), entity).await?;
+
+        Ok(json_schema_for(&details))
+    }
+}
+
+// --- Implementación: todas las entidades activas ---
+pub struct ListEntityJsonSchemasUseCaseImpl {
+    le_query_repository: Arc<dyn LogicalEntityQueryRepository>,
+    attribute_query_repository: Arc<dyn AttributeQueryRepository>,
+}
+
+impl ListEntityJsonSchemasUseCaseImpl {
+    pub fn new(
+        le_query_repository: Arc<dyn LogicalEntityQueryRepository>,
+        attribute_query_repository: Arc<dyn AttributeQueryRepository>,
+    ) -> Self {
+        Self { le_query_repository, attribute_query_repository }
+    }
+}
+
+#[async_trait]
+impl ListEntityJsonSchemasUseCase for ListEntityJsonSchemasUseCaseImpl {
+    async fn execute(&self) -> Result<Vec<EntityJsonSchemaDto>, ApplicationError> {
+        info!("Ejecutando caso de uso ListEntityJsonSchemas");
+
+        // find_all devuelve las entidades ordenadas por nombre
+        let mut entities = Vec::new();
+        let mut offset = 0;
+        loop {
+            let page = self.le_query_repository
+                .find_all(MAX_PAGE_SIZE, offset)
+                .await
+                .map_err(|e| ApplicationError::InfrastructureError(format!("Error al listar entidades lógicas: {}", e)))?;
+            let done = (page.len() as i64) < MAX_PAGE_SIZE;
+            offset += page.len() as i64;
+            entities.extend(page.into_iter().filter(|e| e.status == 1));
+            if done {
+                break;
+            }
+        }
+
+        let mut schemas = Vec::with_capacity(entities.len());
+        for entity in entities {
+            let details = load_entity_details(self.attribute_query_repository.as_ref(), entity).await?;
+            schemas.push(EntityJsonSchemaDto {
+                entity_id: details.entity.id,
+                entity_name: details.entity.name.clone(),
+                schema: json_schema_for(&details),
+            });
+        }
+        Ok(schemas)
+    }
+}
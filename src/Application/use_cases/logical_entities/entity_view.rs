@@ -0,0 +1,285 @@
+// src/Application/use_cases/logical_entities/entity_view.rs
+
+use async_trait::async_trait;
+use std::sync::Arc;
+use std::collections::HashMap;
+use std::future::Future;
+use uuid::Uuid;
+use log::{info, debug, error};
+use anyhow::anyhow;
+
+use crate::Application::errors::application_error::ApplicationError;
+use crate::Application::ports::unit_of_work::{UnitOfWork, RepositoryRegistry};
+use diesel_async::scoped_futures::ScopedFutureExt;
+use crate::Application::ports::driven::repositories::{
+    LogicalEntityQueryRepository,
+    AttributeQueryRepository,
+    AttributeDto,
+};
+use crate::Domain::attribute_references::AttributeReference;
+use crate::Domain::errors::DomainError;
+use crate::Domain::physical_tables::StorageMode;
+use crate::Domain::views::{
+    ViewRepository, AttributeInfo, ReferenceDisplay, ViewPivotStrategy, generate_view_sql, materialized_view_index_sql,
+    view_name_for,
+};
+
+/// Regenera la vista de la entidad a partir de sus atributos activos y registra
+/// su nombre en `logical_entities.assign_view`. Si la entidad cambió de nombre,
+/// la vista anterior se elimina. Según las opciones de la entidad se crea una vista
+/// normal o una materializada (ya poblada), con la estrategia de pivote configurada
+/// o la automática. Devuelve el nombre de la vista.
+/// Compartido por los casos de uso que modifican la definición de una entidad.
+pub(crate) async fn sync_entity_view(
+    le_query_repository: &dyn LogicalEntityQueryRepository,
+    attribute_query_repository: &dyn AttributeQueryRepository,
+    view_repository: &dyn ViewRepository,
+    uow: &dyn UnitOfWork,
+    entity_id: Uuid,
+) -> Result<String, ApplicationError> {
+    let entity = le_query_repository
+        .find_by_id(entity_id)
+        .await
+        .map_err(|e| ApplicationError::InfrastructureError(format!("Error al buscar entidad lógica: {}", e)))?
+        .ok_or_else(|| ApplicationError::NotFound(format!("Entidad lógica con ID {} no encontrada", entity_id)))?;
+
+    let attributes = attribute_query_repository
+        .find_by_entity_id(entity_id)
+        .await
+        .map_err(|e| ApplicationError::InfrastructureError(format!("Error al consultar atributos: {}", e)))?;
+
+    let references = attributes.iter().filter(|a| a.status == 1).filter_map(|a| a.reference.as_ref());
+    let referenced = load_referenced_entities(le_query_repository, attribute_query_repository, entity_id, references).await?;
+    let display_of = |a: &AttributeDto| {
+        let display = reference_display(a.reference.as_ref(), (entity_id, attributes.as_slice(), entity.storage_mode), &referenced);
+        if display.is_none() && a.reference.as_ref().is_some_and(|r| r.display_attribute_id.is_some()) {
+            debug!("El atributo a mostrar de '{}' no está activo; se omite su columna", a.name);
+        }
+        display
+    };
+
+    let attributes_info: Vec<AttributeInfo<'_>> = attributes
+        .iter()
+        .filter(|a| a.status == 1)
+        .map(|a| AttributeInfo {
+            attribute_id: a.id,
+            name: &a.name,
+            position: a.position,
+            storage: &a.storage,
+            show_option_label: a.show_option_label,
+            reference_display: display_of(a),
+        })
+        .collect();
+
+    let (settings, _) = view_repository
+        .find_view_settings(entity_id)
+        .await
+        .map_err(|e| ApplicationError::InfrastructureError(format!("Error al consultar las opciones de la vista: {}", e)))?;
+
+    let pivot = ViewPivotStrategy::resolve(settings.pivot_strategy, attributes_info.len());
+    let view_name = view_name_for(&entity.name);
+    let view_sql = generate_view_sql(entity.id, &entity.name, &attributes_info, settings.materialized, pivot, entity.storage_mode)
+        .map_err(|e| ApplicationError::ValidationError(format!("No se pudo generar la vista de '{}': {}", entity.name, e)))?;
+    debug!("SQL de la vista '{}' (pivote {}):\n{}", view_name, pivot.as_str(), view_sql);
+
+    // La vista anterior (p. ej. tras renombrar la entidad) deja de ser válida
+    if let Some(previous) = entity.assign_view.as_deref().filter(|v| *v != view_name) {
+        drop_view(view_repository, previous).await?;
+    }
+
+    if settings.materialized {
+        // Crear la vista materializada la deja poblada: cuenta como un refresco
+        let index_sql = materialized_view_index_sql(&entity.name);
+        track_refresh(
+            view_repository,
+            entity_id,
+            view_repository.create_materialized_view(&view_name, &view_sql, &index_sql),
+        ).await?.map_err(|e| {
+            error!("Error al crear la vista materializada '{}': {}", view_name, e);
+            ApplicationError::InfrastructureError(format!("Error al crear la vista materializada '{}': {}", view_name, e))
+        })?;
+    } else {
+        view_repository
+            .create_or_replace_view(&view_name, &view_sql)
+            .await
+            .map_err(|e| {
+                error!("Error al crear la vista '{}': {}", view_name, e);
+                ApplicationError::InfrastructureError(format!("Error al crear la vista '{}': {}", view_name, e))
+            })?;
+    }
+
+    if entity.assign_view.as_deref() != Some(view_name.as_str()) {
+        // Sin registrar, la vista quedaría huérfana: se elimina antes de devolver el error
+        if let Err(e) = set_assign_view(uow, entity_id, Some(view_name.clone())).await {
+            if let Err(drop_error) = drop_view(view_repository, &view_name).await {
+                error!("No se pudo eliminar la vista sin registrar '{}': {}", view_name, drop_error);
+            }
+            return Err(e);
+        }
+    }
+
+    info!("Vista '{}' sincronizada para la entidad {}", view_name, entity_id);
+    Ok(view_name)
+}
+
+/// Atributos y modo de almacenamiento de las entidades referenciadas, por ID.
+pub(crate) type ReferencedEntities = HashMap<Uuid, (Vec<AttributeDto>, StorageMode)>;
+
+/// Carga las entidades de las referencias con atributo a mostrar, salvo la propia
+/// entidad (`entity_id`), cuyos atributos ya tiene quien llama.
+pub(crate) async fn load_referenced_entities<'r>(
+    le_query_repository: &dyn LogicalEntityQueryRepository,
+    attribute_query_repository: &dyn AttributeQueryRepository,
+    entity_id: Uuid,
+    references: impl Iterator<Item = &'r AttributeReference>,
+) -> Result<ReferencedEntities, ApplicationError> {
+    let mut referenced: ReferencedEntities = HashMap::new();
+    for reference in references {
+        if reference.display_attribute_id.is_none() || reference.entity_id == entity_id || referenced.contains_key(&reference.entity_id) {
+            continue;
+        }
+        let target_attributes = attribute_query_repository
+            .find_by_entity_id(reference.entity_id)
+            .await
+            .map_err(|e| ApplicationError::InfrastructureError(format!("Error al consultar atributos: {}", e)))?;
+        let target_storage = le_query_repository
+            .find_by_id(reference.entity_id)
+            .await
+            .map_err(|e| ApplicationError::InfrastructureError(format!("Error al buscar entidad lógica: {}", e)))?
+            .map(|target| target.storage_mode)
+            .unwrap_or_default();
+        referenced.insert(reference.entity_id, (target_attributes, target_storage));
+    }
+    Ok(referenced)
+}
+
+/// Columna del atributo a mostrar de una referencia. `own` es la propia entidad (ID,
+/// atributos y modo de almacenamiento), para las referencias a sí misma. Un atributo a
+/// mostrar retirado no genera columna.
+pub(crate) fn reference_display<'a>(
+    reference: Option<&AttributeReference>,
+    own: (Uuid, &'a [AttributeDto], StorageMode),
+    referenced: &'a ReferencedEntities,
+) -> Option<ReferenceDisplay<'a>> {
+    let reference = reference?;
+    let display_id = reference.display_attribute_id?;
+    let (entity_id, own_attributes, own_storage) = own;
+    let (target_attributes, target_storage) = if reference.entity_id == entity_id {
+        (own_attributes, own_storage)
+    } else {
+        let (target_attributes, target_storage) = referenced.get(&reference.entity_id)?;
+        (target_attributes.as_slice(), *target_storage)
+    };
+    target_attributes.iter().find(|t| t.id == display_id && t.status == 1).map(|display| ReferenceDisplay {
+        attribute_id: display.id,
+        storage: &display.storage,
+        entity_id: reference.entity_id,
+        entity_storage: target_storage,
+    })
+}
+
+/// Elimina la vista de la entidad (si tiene) y, si la entidad sigue existiendo,
+/// limpia `logical_entities.assign_view`.
+pub(crate) async fn remove_entity_view(
+    view_repository: &dyn ViewRepository,
+    uow: &dyn UnitOfWork,
+    entity_id: Uuid,
+    view_name: Option<&str>,
+    entity_exists: bool,
+) -> Result<(), ApplicationError> {
+    let Some(view_name) = view_name else {
+        return Ok(());
+    };
+
+    drop_view(view_repository, view_name).await?;
+    if entity_exists {
+        set_assign_view(uow, entity_id, None).await?;
+    }
+
+    info!("Vista '{}' eliminada para la entidad {}", view_name, entity_id);
+    Ok(())
+}
+
+async fn drop_view(view_repository: &dyn ViewRepository, view_name: &str) -> Result<(), ApplicationError> {
+    view_repository.drop_view(view_name).await.map_err(|e| {
+        error!("Error al eliminar la vista '{}': {}", view_name, e);
+        ApplicationError::InfrastructureError(format!("Error al eliminar la vista '{}': {}", view_name, e))
+    })
+}
+
+/// Ejecuta una operación que puebla la vista materializada de la entidad (creación o
+/// refresco), registrando su inicio y su resultado. El error de la propia operación
+/// se devuelve en el resultado interno.
+pub(crate) async fn track_refresh<F>(
+    view_repository: &dyn ViewRepository,
+    entity_id: Uuid,
+    operation: F,
+) -> Result<Result<(), DomainError>, ApplicationError>
+where
+    F: Future<Output = Result<(), DomainError>>,
+{
+    let map_err = |e: DomainError| {
+        error!("Error al registrar el refresco de la vista de la entidad {}: {}", entity_id, e);
+        ApplicationError::InfrastructureError(format!("Error al registrar el refresco de la vista: {}", e))
+    };
+
+    view_repository.mark_refresh_started(entity_id).await.map_err(map_err)?;
+    let result = operation.await;
+    let failure = result.as_ref().err().map(|e| e.to_string());
+    view_repository.mark_refresh_finished(entity_id, failure.as_deref()).await.map_err(map_err)?;
+    Ok(result)
+}
+
+async fn set_assign_view(uow: &dyn UnitOfWork, entity_id: Uuid, view_name: Option<String>) -> Result<(), ApplicationError> {
+    uow.execute(move |registry: &mut dyn RepositoryRegistry| async move {
+        let cmd_repo = registry.logical_entity_command_repository();
+        let conn = registry.get_diesel_async_conn();
+
+        cmd_repo.set_assign_view(conn, entity_id, view_name.as_deref()).await
+            .map_err(|e| anyhow!("Failed to set assign_view of logical entity {}: {}", entity_id, e))?;
+        Ok(())
+    }.scope_boxed()).await.map_err(|e| {
+        error!("Error durante la Unidad de Trabajo al registrar la vista de la entidad {}: {:?}", entity_id, e);
+        ApplicationError::InfrastructureError(format!("Error en transacción al registrar la vista: {}", e))
+    })
+}
+
+// --- Caso de uso: regeneración manual de la vista ---
+#[async_trait]
+pub trait RefreshEntityViewUseCase: Send + Sync {
+    /// Regenera la vista de la entidad y devuelve su nombre.
+    async fn execute(&self, entity_id: Uuid) -> Result<String, ApplicationError>;
+}
+
+pub struct RefreshEntityViewUseCaseImpl {
+    le_query_repository: Arc<dyn LogicalEntityQueryRepository>,
+    attribute_query_repository: Arc<dyn AttributeQueryRepository>,
+    view_repository: Arc<dyn ViewRepository>,
+    uow: Arc<dyn UnitOfWork>,
+}
+
+impl RefreshEntityViewUseCaseImpl {
+    pub fn new(
+        le_query_repository: Arc<dyn LogicalEntityQueryRepository>,
+        attribute_query_repository: Arc<dyn AttributeQueryRepository>,
+        view_repository: Arc<dyn ViewRepository>,
+        uow: Arc<dyn UnitOfWork>,
+    ) -> Self {
+        Self { le_query_repository, attribute_query_repository, view_repository, uow }
+    }
+}
+
+#[async_trait]
+impl RefreshEntityViewUseCase for RefreshEntityViewUseCaseImpl {
+    async fn execute(&self, entity_id: Uuid) -> Result<String, ApplicationError> {
+        info!("Ejecutando caso de uso RefreshEntityView: id='{}'", entity_id);
+
+        sync_entity_view(
+            self.le_query_repository.as_ref(),
+            self.attribute_query_repository.as_ref(),
+            self.view_repository.as_ref(),
+            self.uow.as_ref(),
+            entity_id,
+        ).await
+    }
+}
@@ -0,0 +1,103 @@
+// src/Application/use_cases/logical_entities/find_logical_entity.rs
+
+use async_trait::async_trait;
+use std::sync::Arc;
+use uuid::Uuid;
+use log::{info, debug, error};
+
+use crate::Application::dtos::logical_entity_dto::LogicalEntityDetailsDto;
+use crate::Application::errors::application_error::ApplicationError;
+use crate::Application::ports::driven::repositories::{
+    LogicalEntityQueryRepository,
+    AttributeQueryRepository,
+    LogicalEntityDto,
+};
+
+// --- Traits de los Casos de Uso ---
+#[async_trait]
+pub trait FindLogicalEntityByIdUseCase: Send + Sync {
+    async fn execute(&self, id: Uuid) -> Result<LogicalEntityDetailsDto, ApplicationError>;
+}
+
+#[async_trait]
+pub trait FindLogicalEntityByNameUseCase: Send + Sync {
+    async fn execute(&self, name: &str) -> Result<LogicalEntityDetailsDto, ApplicationError>;
+}
+
+/// Completa una entidad con sus atributos (y nombres de tipo de dato).
+/// Compartido por los casos de uso de consulta, listado y actualización.
+pub(crate) async fn load_entity_details(
+    attribute_query_repository: &dyn AttributeQueryRepository,
+    entity: LogicalEntityDto,
+) -> Result<LogicalEntityDetailsDto, ApplicationError> {
+    debug!("Cargando atributos de la entidad '{}' ({})", entity.name, entity.id);
+    let attributes = attribute_query_repository
+        .find_by_entity_id(entity.id)
+        .await
+        .map_err(|e| {
+            error!("Error al consultar atributos de la entidad {}: {}", entity.id, e);
+            ApplicationError::InfrastructureError(format!("Error al consultar atributos: {}", e))
+        })?;
+
+    Ok(LogicalEntityDetailsDto { entity, attributes })
+}
+
+// --- Implementación: búsqueda por ID ---
+pub struct FindLogicalEntityByIdUseCaseImpl {
+    le_query_repository: Arc<dyn LogicalEntityQueryRepository>,
+    attribute_query_repository: Arc<dyn AttributeQueryRepository>,
+}
+
+impl FindLogicalEntityByIdUseCaseImpl {
+    pub fn new(
+        le_query_repository: Arc<dyn LogicalEntityQueryRepository>,
+        attribute_query_repository: Arc<dyn AttributeQueryRepository>,
+    ) -> Self {
+        Self { le_query_repository, attribute_query_repository }
+    }
+}
+
+#[async_trait]
+impl FindLogicalEntityByIdUseCase for FindLogicalEntityByIdUseCaseImpl {
+    async fn execute(&self, id: Uuid) -> Result<LogicalEntityDetailsDto, ApplicationError> {
+        info!("Ejecutando caso de uso FindLogicalEntityById: id='{}'", id);
+
+        let entity = self.le_query_repository
+            .find_by_id(id)
+            .await
+            .map_err(|e| ApplicationError::InfrastructureError(format!("Error al buscar entidad lógica: {}", e)))?
+            .ok_or_else(|| ApplicationError::NotFound(format!("Entidad lógica con ID {} no encontrada", id)))?;
+
+        load_entity_details(self.attribute_query_repository.as_ref(), entity).await
+    }
+}
+
+// --- Implementación: búsqueda por nombre ---
+pub struct FindLogicalEntityByNameUseCaseImpl {
+    le_query_repository: Arc<dyn LogicalEntityQueryRepository>,
+    attribute_query_repository: Arc<dyn AttributeQueryRepository>,
+}
+
+impl FindLogicalEntityByNameUseCaseImpl {
+    pub fn new(
+        le_query_repository: Arc<dyn LogicalEntityQueryRepository>,
+        attribute_query_repository: Arc<dyn AttributeQueryRepository>,
+    ) -> Self {
+        Self { le_query_repository, attribute_query_repository }
+    }
+}
+
+#[async_trait]
+impl FindLogicalEntityByNameUseCase for FindLogicalEntityByNameUseCaseImpl {
+    async fn execute(&self, name: &str) -> Result<LogicalEntityDetailsDto, ApplicationError> {
+        info!("Ejecutando caso de uso FindLogicalEntityByName: name='{}'", name);
+
+        let entity = self.le_query_repository
+            .find_by_name(name)
+            .await
+            .map_err(|e| ApplicationError::InfrastructureError(format!("Error al buscar entidad lógica: {}", e)))?
+            .ok_or_else(|| ApplicationError::NotFound(format!("Entidad lógica '{}' no encontrada", name)))?;
+
+        load_entity_details(self.attribute_query_repository.as_ref(), entity).await
+    }
+}
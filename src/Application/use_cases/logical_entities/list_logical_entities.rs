@@ -0,0 +1,95 @@
+// src/Application/use_cases/logical_entities/list_logical_entities.rs
+
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+use uuid::Uuid;
+use log::{info, error};
+
+use crate::Application::dtos::logical_entity_dto::{LogicalEntityDetailsDto, LogicalEntityPageDto};
+use crate::Application::errors::application_error::ApplicationError;
+use crate::Application::ports::driven::repositories::{
+    LogicalEntityQueryRepository,
+    AttributeDto,
+    AttributeQueryRepository,
+};
+
+/// Tamaño máximo de página permitido para el listado.
+pub const MAX_PAGE_SIZE: i64 = 100;
+
+/// Desplazamiento de la página `page` (desde 1). Falla si no cabe en un i64.
+/// Compartido por los listados paginados por número de página.
+pub(crate) fn page_offset(page: i64, page_size: i64) -> Result<i64, ApplicationError> {
+    (page - 1)
+        .checked_mul(page_size)
+        .ok_or_else(|| ApplicationError::ValidationError("page está fuera de rango".to_string()))
+}
+
+#[async_trait]
+pub trait ListLogicalEntitiesUseCase: Send + Sync {
+    /// `page` empieza en 1.
+    async fn execute(&self, page: i64, page_size: i64) -> Result<LogicalEntityPageDto, ApplicationError>;
+}
+
+pub struct ListLogicalEntitiesUseCaseImpl {
+    le_query_repository: Arc<dyn LogicalEntityQueryRepository>,
+    attribute_query_repository: Arc<dyn AttributeQueryRepository>,
+}
+
+impl ListLogicalEntitiesUseCaseImpl {
+    pub fn new(
+        le_query_repository: Arc<dyn LogicalEntityQueryRepository>,
+        attribute_query_repository: Arc<dyn AttributeQueryRepository>,
+    ) -> Self {
+        Self { le_query_repository, attribute_query_repository }
+    }
+}
+
+#[async_trait]
+impl ListLogicalEntitiesUseCase for ListLogicalEntitiesUseCaseImpl {
+    async fn execute(&self, page: i64, page_size: i64) -> Result<LogicalEntityPageDto, ApplicationError> {
+        if page < 1 {
+            return Err(ApplicationError::ValidationError("page debe ser mayor o igual a 1".to_string()));
+        }
+        if page_size < 1 || page_size > MAX_PAGE_SIZE {
+            return Err(ApplicationError::ValidationError(format!("page_size debe estar entre 1 y {}", MAX_PAGE_SIZE)));
+        }
+        info!("Ejecutando caso de uso ListLogicalEntities: page={}, page_size={}", page, page_size);
+
+        let offset = page_offset(page, page_size)?;
+
+        let total = self.le_query_repository
+            .count_all()
+            .await
+            .map_err(|e| ApplicationError::InfrastructureError(format!("Error al contar entidades lógicas: {}", e)))?;
+
+        let entities = self.le_query_repository
+            .find_all(page_size, offset)
+            .await
+            .map_err(|e| ApplicationError::InfrastructureError(format!("Error al listar entidades lógicas: {}", e)))?;
+
+        // Atributos de todas las entidades de la página en una sola consulta
+        let entity_ids: Vec<Uuid> = entities.iter().map(|e| e.id).collect();
+        let found = self.attribute_query_repository
+            .find_by_entity_ids(&entity_ids)
+            .await
+            .map_err(|e| {
+                error!("Error al consultar atributos de las entidades {:?}: {}", entity_ids, e);
+                ApplicationError::InfrastructureError(format!("Error al consultar atributos: {}", e))
+            })?;
+        let mut attributes: HashMap<Uuid, Vec<AttributeDto>> = HashMap::new();
+        for attribute in found {
+            attributes.entry(attribute.entity_id).or_default().push(attribute);
+        }
+
+        let items = entities
+            .into_iter()
+            .map(|entity| {
+                let attributes = attributes.remove(&entity.id).unwrap_or_default();
+                LogicalEntityDetailsDto { entity, attributes }
+            })
+            .collect();
+
+        Ok(LogicalEntityPageDto { items, page, page_size, total })
+    }
+}
@@ -0,0 +1,231 @@
+// src/Application/use_cases/logical_entities/materialized_view.rs
+
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use std::sync::Arc;
+use uuid::Uuid;
+use log::{info, warn, error};
+
+use crate::Application::errors::application_error::ApplicationError;
+use crate::Application::ports::unit_of_work::UnitOfWork;
+use crate::Application::ports::driven::repositories::{
+    LogicalEntityQueryRepository, AttributeQueryRepository, LogicalEntityDto,
+};
+use crate::Application::use_cases::logical_entities::entity_view::{sync_entity_view, track_refresh};
+use crate::Domain::views::{
+    refresh_due, EntityViewSettings, RefreshTrigger, ViewRefreshState, ViewRepository,
+};
+
+/// Opciones y estado del refresco de la vista de una entidad.
+#[derive(Debug, Clone)]
+pub struct EntityViewStatusDto {
+    pub entity_id: Uuid,
+    /// None si la entidad no tiene vista asignada
+    pub view_name: Option<String>,
+    pub settings: EntityViewSettings,
+    pub state: ViewRefreshState,
+}
+
+/// Resultado de una pasada del refresco automático.
+#[derive(Debug, Clone, Default)]
+pub struct RefreshDueReport {
+    pub refreshed: Vec<(Uuid, RefreshTrigger)>,
+    pub failed: Vec<(Uuid, String)>,
+}
+
+async fn find_entity(
+    le_query_repository: &dyn LogicalEntityQueryRepository,
+    entity_id: Uuid,
+) -> Result<LogicalEntityDto, ApplicationError> {
+    le_query_repository
+        .find_by_id(entity_id)
+        .await
+        .map_err(|e| ApplicationError::InfrastructureError(format!("Error al buscar entidad lógica: {}", e)))?
+        .ok_or_else(|| ApplicationError::NotFound(format!("Entidad lógica con ID {} no encontrada", entity_id)))
+}
+
+async fn view_status(
+    view_repository: &dyn ViewRepository,
+    entity_id: Uuid,
+    view_name: Option<String>,
+) -> Result<EntityViewStatusDto, ApplicationError> {
+    let (settings, state) = view_repository
+        .find_view_settings(entity_id)
+        .await
+        .map_err(|e| ApplicationError::InfrastructureError(format!("Error al consultar las opciones de la vista: {}", e)))?;
+    Ok(EntityViewStatusDto { entity_id, view_name, settings, state })
+}
+
+// --- Caso de uso: estado de la vista ---
+#[async_trait]
+pub trait EntityViewStatusUseCase: Send + Sync {
+    async fn execute(&self, entity_id: Uuid) -> Result<EntityViewStatusDto, ApplicationError>;
+}
+
+pub struct EntityViewStatusUseCaseImpl {
+    le_query_repository: Arc<dyn LogicalEntityQueryRepository>,
+    view_repository: Arc<dyn ViewRepository>,
+}
+
+impl EntityViewStatusUseCaseImpl {
+    pub fn new(le_query_repository: Arc<dyn LogicalEntityQueryRepository>, view_repository: Arc<dyn ViewRepository>) -> Self {
+        Self { le_query_repository, view_repository }
+    }
+}
+
+#[async_trait]
+impl EntityViewStatusUseCase for EntityViewStatusUseCaseImpl {
+    async fn execute(&self, entity_id: Uuid) -> Result<EntityViewStatusDto, ApplicationError> {
+        let entity = find_entity(self.le_query_repository.as_ref(), entity_id).await?;
+        view_status(self.view_repository.as_ref(), entity_id, entity.assign_view).await
+    }
+}
+
+// --- Caso de uso: opciones de la vista (normal o materializada) ---
+#[async_trait]
+pub trait UpdateEntityViewSettingsUseCase: Send + Sync {
+    /// Guarda las opciones y, si cambia el tipo de vista o la estrategia de pivote,
+    /// vuelve a crear la vista de la entidad.
+    async fn execute(&self, entity_id: Uuid, settings: EntityViewSettings) -> Result<EntityViewStatusDto, ApplicationError>;
+}
+
+pub struct UpdateEntityViewSettingsUseCaseImpl {
+    le_query_repository: Arc<dyn LogicalEntityQueryRepository>,
+    attribute_query_repository: Arc<dyn AttributeQueryRepository>,
+    view_repository: Arc<dyn ViewRepository>,
+    uow: Arc<dyn UnitOfWork>,
+}
+
+impl UpdateEntityViewSettingsUseCaseImpl {
+    pub fn new(
+        le_query_repository: Arc<dyn LogicalEntityQueryRepository>,
+        attribute_query_repository: Arc<dyn AttributeQueryRepository>,
+        view_repository: Arc<dyn ViewRepository>,
+        uow: Arc<dyn UnitOfWork>,
+    ) -> Self {
+        Self { le_query_repository, attribute_query_repository, view_repository, uow }
+    }
+}
+
+#[async_trait]
+impl UpdateEntityViewSettingsUseCase for UpdateEntityViewSettingsUseCaseImpl {
+    async fn execute(&self, entity_id: Uuid, settings: EntityViewSettings) -> Result<EntityViewStatusDto, ApplicationError> {
+        info!("Ejecutando caso de uso UpdateEntityViewSettings: id='{}', {:?}", entity_id, settings);
+        settings.validate().map_err(|e| ApplicationError::ValidationError(e.to_string()))?;
+
+        let entity = find_entity(self.le_query_repository.as_ref(), entity_id).await?;
+        let (current, _) = self.view_repository
+            .find_view_settings(entity_id)
+            .await
+            .map_err(|e| ApplicationError::InfrastructureError(format!("Error al consultar las opciones de la vista: {}", e)))?;
+
+        self.view_repository
+            .save_view_settings(entity_id, &settings)
+            .await
+            .map_err(|e| ApplicationError::InfrastructureError(format!("Error al guardar las opciones de la vista: {}", e)))?;
+
+        // Cambiar entre vista normal y materializada o de estrategia de pivote exige volver a crearla
+        let mut view_name = entity.assign_view;
+        let recreate = current.materialized != settings.materialized || current.pivot_strategy != settings.pivot_strategy;
+        if view_name.is_some() && recreate {
+            view_name = Some(sync_entity_view(
+                self.le_query_repository.as_ref(),
+                self.attribute_query_repository.as_ref(),
+                self.view_repository.as_ref(),
+                self.uow.as_ref(),
+                entity_id,
+            ).await?);
+        }
+
+        view_status(self.view_repository.as_ref(), entity_id, view_name).await
+    }
+}
+
+// --- Caso de uso: refresco de vistas materializadas ---
+#[async_trait]
+pub trait RefreshMaterializedViewUseCase: Send + Sync {
+    /// Refresca ahora la vista materializada de la entidad.
+    async fn execute(&self, entity_id: Uuid) -> Result<EntityViewStatusDto, ApplicationError>;
+
+    /// Refresca las vistas materializadas que lo necesiten: por su intervalo programado
+    /// o porque sus registros cambiaron y llevan `debounce` sin cambiar.
+    async fn execute_due(&self, debounce: Option<Duration>) -> Result<RefreshDueReport, ApplicationError>;
+}
+
+pub struct RefreshMaterializedViewUseCaseImpl {
+    le_query_repository: Arc<dyn LogicalEntityQueryRepository>,
+    view_repository: Arc<dyn ViewRepository>,
+}
+
+impl RefreshMaterializedViewUseCaseImpl {
+    pub fn new(le_query_repository: Arc<dyn LogicalEntityQueryRepository>, view_repository: Arc<dyn ViewRepository>) -> Self {
+        Self { le_query_repository, view_repository }
+    }
+}
+
+#[async_trait]
+impl RefreshMaterializedViewUseCase for RefreshMaterializedViewUseCaseImpl {
+    async fn execute(&self, entity_id: Uuid) -> Result<EntityViewStatusDto, ApplicationError> {
+        info!("Ejecutando caso de uso RefreshMaterializedView: id='{}'", entity_id);
+
+        let entity = find_entity(self.le_query_repository.as_ref(), entity_id).await?;
+        let Some(view_name) = entity.assign_view else {
+            return Err(ApplicationError::Conflict(format!("La entidad {} no tiene vista asignada", entity_id)));
+        };
+        let (settings, _) = self.view_repository
+            .find_view_settings(entity_id)
+            .await
+            .map_err(|e| ApplicationError::InfrastructureError(format!("Error al consultar las opciones de la vista: {}", e)))?;
+        if !settings.materialized {
+            return Err(ApplicationError::Conflict(format!("La vista '{}' no es una vista materializada", view_name)));
+        }
+
+        track_refresh(
+            self.view_repository.as_ref(),
+            entity_id,
+            self.view_repository.refresh_materialized_view(&view_name),
+        ).await?.map_err(|e| {
+            error!("Error al refrescar la vista materializada '{}': {}", view_name, e);
+            ApplicationError::InfrastructureError(format!("Error al refrescar la vista materializada '{}': {}", view_name, e))
+        })?;
+
+        info!("Vista materializada '{}' refrescada ({:?})", view_name, RefreshTrigger::Manual);
+        view_status(self.view_repository.as_ref(), entity_id, Some(view_name)).await
+    }
+
+    async fn execute_due(&self, debounce: Option<Duration>) -> Result<RefreshDueReport, ApplicationError> {
+        let candidates = self.view_repository
+            .find_materialized_views()
+            .await
+            .map_err(|e| ApplicationError::InfrastructureError(format!("Error al consultar las vistas materializadas: {}", e)))?;
+
+        let mut report = RefreshDueReport::default();
+        for candidate in candidates {
+            let Some(trigger) = refresh_due(&candidate.settings, &candidate.state, candidate.last_write_at, debounce, Utc::now()) else {
+                continue;
+            };
+
+            // Un fallo en una vista no impide refrescar las demás
+            let result = track_refresh(
+                self.view_repository.as_ref(),
+                candidate.entity_id,
+                self.view_repository.refresh_materialized_view(&candidate.view_name),
+            ).await;
+            match result {
+                Ok(Ok(())) => {
+                    info!("Vista materializada '{}' refrescada ({:?})", candidate.view_name, trigger);
+                    report.refreshed.push((candidate.entity_id, trigger));
+                },
+                Ok(Err(e)) => {
+                    warn!("No se pudo refrescar la vista materializada '{}': {}", candidate.view_name, e);
+                    report.failed.push((candidate.entity_id, e.to_string()));
+                },
+                Err(e) => {
+                    warn!("No se pudo registrar el refresco de la vista '{}': {}", candidate.view_name, e);
+                    report.failed.push((candidate.entity_id, e.to_string()));
+                },
+            }
+        }
+        Ok(report)
+    }
+}
@@ -1,9 +1,55 @@
 pub mod create_logical_entity;
+pub mod find_logical_entity;
+pub mod list_logical_entities;
+pub mod update_logical_entity;
+pub mod delete_logical_entity;
+pub mod entity_view;
+pub mod materialized_view;
+pub mod entity_json_schema;
+pub mod trash_logical_entities;
+pub mod storage_mode;
+
 pub use create_logical_entity::{
 AttributeDefinitionCommand,
 CreateEntityWithAttributesCommand,
 //CreateEntityWithAttributesError,
+CreateEntityError,
 CreateEntityWithAttributesUseCase,
 CreateEntityWithAttributesUseCaseImpl,
 };
+pub use find_logical_entity::{
+FindLogicalEntityByIdUseCase,
+FindLogicalEntityByIdUseCaseImpl,
+FindLogicalEntityByNameUseCase,
+FindLogicalEntityByNameUseCaseImpl,
+};
+pub use list_logical_entities::{ListLogicalEntitiesUseCase, ListLogicalEntitiesUseCaseImpl};
+pub use update_logical_entity::{UpdateLogicalEntityUseCase, UpdateLogicalEntityUseCaseImpl};
+pub use delete_logical_entity::{DeleteLogicalEntityUseCase, DeleteLogicalEntityUseCaseImpl};
+pub use entity_view::{RefreshEntityViewUseCase, RefreshEntityViewUseCaseImpl};
+pub use materialized_view::{
+EntityViewStatusDto,
+RefreshDueReport,
+EntityViewStatusUseCase,
+EntityViewStatusUseCaseImpl,
+UpdateEntityViewSettingsUseCase,
+UpdateEntityViewSettingsUseCaseImpl,
+RefreshMaterializedViewUseCase,
+RefreshMaterializedViewUseCaseImpl,
+};
+pub use entity_json_schema::{
+EntityJsonSchemaUseCase,
+EntityJsonSchemaUseCaseImpl,
+ListEntityJsonSchemasUseCase,
+ListEntityJsonSchemasUseCaseImpl,
+};
+pub use trash_logical_entities::{
+ListDeletedLogicalEntitiesUseCase,
+ListDeletedLogicalEntitiesUseCaseImpl,
+RestoreLogicalEntityUseCase,
+RestoreLogicalEntityUseCaseImpl,
+PurgeLogicalEntityUseCase,
+PurgeLogicalEntityUseCaseImpl,
+};
+pub use storage_mode::{StorageModeChangeDto, ChangeStorageModeUseCase, ChangeStorageModeUseCaseImpl};
 // No exportar los traits de repositorio desde aquí
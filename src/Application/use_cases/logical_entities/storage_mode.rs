@@ -0,0 +1,113 @@
+// src/Application/use_cases/logical_entities/storage_mode.rs
+
+use async_trait::async_trait;
+use std::collections::BTreeSet;
+use std::sync::Arc;
+use uuid::Uuid;
+use log::{info, debug};
+use anyhow::anyhow;
+
+use crate::Application::errors::application_error::ApplicationError;
+use crate::Application::ports::unit_of_work::{UnitOfWork, RepositoryRegistry};
+use diesel_async::scoped_futures::ScopedFutureExt;
+use crate::Application::ports::driven::repositories::{LogicalEntityQueryRepository, AttributeQueryRepository};
+use crate::Application::use_cases::attributes::attribute_schema::refresh_view_if_assigned;
+use crate::Application::use_cases::records::record_values::map_uow_error;
+use crate::Domain::errors::DomainError;
+use crate::Domain::physical_tables::StorageMode;
+use crate::Domain::views::ViewRepository;
+
+/// Resultado de un cambio de modo de almacenamiento.
+#[derive(Debug, Clone)]
+pub struct StorageModeChangeDto {
+    pub entity_id: Uuid,
+    pub storage_mode: StorageMode,
+    /// Registros (modo físico) o valores (modo EAV) copiados; 0 si la entidad ya estaba en ese modo
+    pub migrated: usize,
+}
+
+#[async_trait]
+pub trait ChangeStorageModeUseCase: Send + Sync {
+    /// Mueve los valores de los registros de la entidad entre `attribute_values` y su
+    /// tabla física en una transacción. Las escrituras de registros de la entidad esperan
+    /// a que termine; las lecturas siguen viendo el modo anterior hasta el COMMIT.
+    async fn execute(&self, entity_id: Uuid, mode: StorageMode) -> Result<StorageModeChangeDto, ApplicationError>;
+}
+
+pub struct ChangeStorageModeUseCaseImpl {
+    le_query_repository: Arc<dyn LogicalEntityQueryRepository>,
+    attribute_query_repository: Arc<dyn AttributeQueryRepository>,
+    view_repository: Arc<dyn ViewRepository>,
+    uow: Arc<dyn UnitOfWork>,
+}
+
+impl ChangeStorageModeUseCaseImpl {
+    pub fn new(
+        le_query_repository: Arc<dyn LogicalEntityQueryRepository>,
+        attribute_query_repository: Arc<dyn AttributeQueryRepository>,
+        view_repository: Arc<dyn ViewRepository>,
+        uow: Arc<dyn UnitOfWork>,
+    ) -> Self {
+        Self { le_query_repository, attribute_query_repository, view_repository, uow }
+    }
+}
+
+#[async_trait]
+impl ChangeStorageModeUseCase for ChangeStorageModeUseCaseImpl {
+    async fn execute(&self, entity_id: Uuid, mode: StorageMode) -> Result<StorageModeChangeDto, ApplicationError> {
+        info!("Ejecutando caso de uso ChangeStorageMode: id='{}', modo='{}'", entity_id, mode.as_str());
+
+        let entity = self.le_query_repository
+            .find_by_id(entity_id)
+            .await
+            .map_err(|e| ApplicationError::InfrastructureError(format!("Error al buscar entidad lógica: {}", e)))?
+            .ok_or_else(|| ApplicationError::NotFound(format!("Entidad lógica con ID {} no encontrada", entity_id)))?;
+
+        // 1. Copiar los valores y cambiar el modo en una transacción
+        let migrated = self.uow.execute(move |registry: &mut dyn RepositoryRegistry| async move {
+            let record_cmd_repo = registry.record_command_repository();
+            let conn = registry.get_diesel_async_conn();
+
+            record_cmd_repo.change_storage_mode(conn, entity_id, mode).await.map_err(|e| {
+                match e.downcast_ref::<DomainError>() {
+                    Some(DomainError::InvalidState(message)) => anyhow!(ApplicationError::Conflict(message.clone())),
+                    _ => anyhow!("Failed to change storage mode of entity {}: {}", entity_id, e),
+                }
+            })
+        }.scope_boxed()).await.map_err(|e| map_uow_error(e, "cambio de modo de almacenamiento"))?;
+
+        if migrated == 0 && entity.storage_mode == mode {
+            debug!("La entidad {} ya guardaba sus registros en modo '{}'", entity_id, mode.as_str());
+            return Ok(StorageModeChangeDto { entity_id, storage_mode: mode, migrated });
+        }
+        info!("Entidad {} en modo '{}' ({} copiados)", entity_id, mode.as_str(), migrated);
+
+        // 2. Las vistas de la entidad y de las que muestran sus registros leen del almacenamiento anterior
+        let referencing: BTreeSet<Uuid> = self.attribute_query_repository
+            .find_referencing(entity_id)
+            .await
+            .map_err(|e| ApplicationError::InfrastructureError(format!("Error al consultar atributos: {}", e)))?
+            .into_iter()
+            .map(|a| a.entity_id)
+            .filter(|id| *id != entity_id)
+            .collect();
+        for id in std::iter::once(entity_id).chain(referencing) {
+            let Some(affected) = self.le_query_repository
+                .find_by_id(id)
+                .await
+                .map_err(|e| ApplicationError::InfrastructureError(format!("Error al buscar entidad lógica: {}", e)))?
+            else {
+                continue;
+            };
+            refresh_view_if_assigned(
+                self.le_query_repository.as_ref(),
+                self.attribute_query_repository.as_ref(),
+                self.view_repository.as_ref(),
+                self.uow.as_ref(),
+                &affected,
+            ).await?;
+        }
+
+        Ok(StorageModeChangeDto { entity_id, storage_mode: mode, migrated })
+    }
+}
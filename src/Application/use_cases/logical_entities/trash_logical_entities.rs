@@ -0,0 +1,216 @@
+// src/Application/use_cases/logical_entities/trash_logical_entities.rs
+//
+// Papelera de entidades lógicas: listado, restauración y purga. Una entidad de la
+// papelera conserva sus atributos y registros; la purga los elimina con ella.
+
+use async_trait::async_trait;
+use std::sync::Arc;
+use uuid::Uuid;
+use log::info;
+use anyhow::anyhow;
+
+use crate::Application::dtos::logical_entity_dto::LogicalEntityDetailsDto;
+use crate::Application::dtos::trash_dto::TrashPageDto;
+use crate::Application::errors::application_error::ApplicationError;
+use crate::Application::ports::unit_of_work::{UnitOfWork, RepositoryRegistry};
+use diesel_async::scoped_futures::ScopedFutureExt;
+use crate::Application::use_cases::records::record_values::map_uow_error;
+use crate::Application::ports::driven::repositories::{
+    LogicalEntityQueryRepository,
+    AttributeQueryRepository,
+    DeletedLogicalEntityDto,
+};
+use crate::Domain::views::ViewRepository;
+use super::entity_view::sync_entity_view;
+use super::find_logical_entity::load_entity_details;
+use super::list_logical_entities::{MAX_PAGE_SIZE, page_offset};
+
+// --- Traits de los Casos de Uso ---
+#[async_trait]
+pub trait ListDeletedLogicalEntitiesUseCase: Send + Sync {
+    /// `page` empieza en 1.
+    async fn execute(&self, page: i64, page_size: i64) -> Result<TrashPageDto<DeletedLogicalEntityDto>, ApplicationError>;
+}
+
+#[async_trait]
+pub trait RestoreLogicalEntityUseCase: Send + Sync {
+    /// Saca la entidad de la papelera y regenera su vista si tenía una.
+    async fn execute(&self, id: Uuid) -> Result<LogicalEntityDetailsDto, ApplicationError>;
+}
+
+#[async_trait]
+pub trait PurgeLogicalEntityUseCase: Send + Sync {
+    /// Elimina definitivamente una entidad de la papelera con sus atributos y registros.
+    async fn execute(&self, id: Uuid) -> Result<(), ApplicationError>;
+}
+
+/// Purga una entidad de la papelera en su propia transacción. Compartido con la
+/// purga por antigüedad. Devuelve 0 si la entidad ya no está en la papelera.
+pub(crate) async fn purge_logical_entity(uow: &dyn UnitOfWork, id: Uuid) -> Result<usize, ApplicationError> {
+    uow.execute(move |registry: &mut dyn RepositoryRegistry| async move {
+        let attribute_query_repo = registry.attribute_query_repository();
+        let cmd_repo = registry.logical_entity_command_repository();
+        let record_cmd_repo = registry.record_command_repository();
+        let conn = registry.get_diesel_async_conn();
+
+        // Los atributos de otras entidades que la referencian impiden borrarla
+        let referencing: Vec<String> = attribute_query_repo.find_referencing(id).await
+            .map_err(|e| anyhow!("Failed to load attributes referencing entity {}: {}", id, e))?
+            .into_iter()
+            .filter(|a| a.entity_id != id)
+            .map(|a| format!("'{}'", a.name))
+            .collect();
+        if !referencing.is_empty() {
+            return Err(anyhow!(ApplicationError::Conflict(format!(
+                "La entidad lógica {} está referenciada por los atributos {} de otras entidades",
+                id, referencing.join(", ")
+            ))));
+        }
+
+        let purged = cmd_repo.delete(conn, id).await
+            .map_err(|e| anyhow!("Failed to purge logical entity {}: {}", id, e))?;
+        // La tabla física (si la tenía) no se borra en cascada con la entidad
+        if purged > 0 {
+            record_cmd_repo.drop_physical_table(conn, id).await
+                .map_err(|e| anyhow!("Failed to drop physical table of entity {}: {}", id, e))?;
+        }
+        Ok(purged)
+    }.scope_boxed()).await.map_err(|e| map_uow_error(e, "purgar entidad lógica"))
+}
+
+// --- Implementación: listado de la papelera ---
+pub struct ListDeletedLogicalEntitiesUseCaseImpl {
+    le_query_repository: Arc<dyn LogicalEntityQueryRepository>,
+}
+
+impl ListDeletedLogicalEntitiesUseCaseImpl {
+    pub fn new(le_query_repository: Arc<dyn LogicalEntityQueryRepository>) -> Self {
+        Self { le_query_repository }
+    }
+}
+
+#[async_trait]
+impl ListDeletedLogicalEntitiesUseCase for ListDeletedLogicalEntitiesUseCaseImpl {
+    async fn execute(&self, page: i64, page_size: i64) -> Result<TrashPageDto<DeletedLogicalEntityDto>, ApplicationError> {
+        if page < 1 {
+            return Err(ApplicationError::ValidationError("page debe ser mayor o igual a 1".to_string()));
+        }
+        if page_size < 1 || page_size > MAX_PAGE_SIZE {
+            return Err(ApplicationError::ValidationError(format!("page_size debe estar entre 1 y {}", MAX_PAGE_SIZE)));
+        }
+        info!("Ejecutando caso de uso ListDeletedLogicalEntities: page={}, page_size={}", page, page_size);
+
+        let total = self.le_query_repository
+            .count_deleted()
+            .await
+            .map_err(|e| ApplicationError::InfrastructureError(format!("Error al contar la papelera: {}", e)))?;
+
+        let items = self.le_query_repository
+            .find_deleted(page_size, page_offset(page, page_size)?)
+            .await
+            .map_err(|e| ApplicationError::InfrastructureError(format!("Error al listar la papelera: {}", e)))?;
+
+        Ok(TrashPageDto { items, page, page_size, total })
+    }
+}
+
+// --- Implementación: restauración ---
+pub struct RestoreLogicalEntityUseCaseImpl {
+    le_query_repository: Arc<dyn LogicalEntityQueryRepository>,
+    attribute_query_repository: Arc<dyn AttributeQueryRepository>,
+    view_repository: Arc<dyn ViewRepository>,
+    uow: Arc<dyn UnitOfWork>,
+}
+
+impl RestoreLogicalEntityUseCaseImpl {
+    pub fn new(
+        le_query_repository: Arc<dyn LogicalEntityQueryRepository>,
+        attribute_query_repository: Arc<dyn AttributeQueryRepository>,
+        view_repository: Arc<dyn ViewRepository>,
+        uow: Arc<dyn UnitOfWork>,
+    ) -> Self {
+        Self { le_query_repository, attribute_query_repository, view_repository, uow }
+    }
+}
+
+#[async_trait]
+impl RestoreLogicalEntityUseCase for RestoreLogicalEntityUseCaseImpl {
+    async fn execute(&self, id: Uuid) -> Result<LogicalEntityDetailsDto, ApplicationError> {
+        info!("Ejecutando caso de uso RestoreLogicalEntity: id='{}'", id);
+
+        let not_found = || ApplicationError::NotFound(format!("Entidad lógica con ID {} no encontrada en la papelera", id));
+        let deleted = self.le_query_repository
+            .find_deleted_by_id(id)
+            .await
+            .map_err(|e| ApplicationError::InfrastructureError(format!("Error al buscar entidad lógica: {}", e)))?
+            .ok_or_else(not_found)?;
+
+        // Las entidades de la papelera no reservan su nombre: puede haberlo tomado otra
+        let name_taken = self.le_query_repository
+            .exists_by_name(&deleted.entity.name)
+            .await
+            .map_err(|e| ApplicationError::InfrastructureError(format!("Error al comprobar el nombre de la entidad: {}", e)))?;
+        if name_taken {
+            return Err(ApplicationError::Conflict(format!(
+                "Ya existe una entidad lógica con el nombre '{}'; renómbrela antes de restaurar esta", deleted.entity.name
+            )));
+        }
+
+        self.uow.execute(move |registry: &mut dyn RepositoryRegistry| async move {
+            let cmd_repo = registry.logical_entity_command_repository();
+            let conn = registry.get_diesel_async_conn();
+
+            let affected = cmd_repo.restore(conn, id).await
+                .map_err(|e| anyhow!("Failed to restore logical entity {}: {}", id, e))?;
+            if affected == 0 {
+                return Err(anyhow!(ApplicationError::NotFound(format!("Entidad lógica con ID {} no encontrada en la papelera", id))));
+            }
+            Ok(())
+        }.scope_boxed()).await.map_err(|e| map_uow_error(e, "restaurar entidad lógica"))?;
+
+        info!("Entidad lógica '{}' ({}) restaurada", deleted.entity.name, id);
+
+        // La vista se eliminó al mover la entidad a la papelera
+        if deleted.entity.assign_view.is_some() {
+            sync_entity_view(
+                self.le_query_repository.as_ref(),
+                self.attribute_query_repository.as_ref(),
+                self.view_repository.as_ref(),
+                self.uow.as_ref(),
+                id,
+            ).await?;
+        }
+
+        let entity = self.le_query_repository
+            .find_by_id(id)
+            .await
+            .map_err(|e| ApplicationError::InfrastructureError(format!("Error al buscar entidad lógica: {}", e)))?
+            .ok_or_else(not_found)?;
+        load_entity_details(self.attribute_query_repository.as_ref(), entity).await
+    }
+}
+
+// --- Implementación: purga de una entidad ---
+pub struct PurgeLogicalEntityUseCaseImpl {
+    uow: Arc<dyn UnitOfWork>,
+}
+
+impl PurgeLogicalEntityUseCaseImpl {
+    pub fn new(uow: Arc<dyn UnitOfWork>) -> Self {
+        Self { uow }
+    }
+}
+
+#[async_trait]
+impl PurgeLogicalEntityUseCase for PurgeLogicalEntityUseCaseImpl {
+    async fn execute(&self, id: Uuid) -> Result<(), ApplicationError> {
+        info!("Ejecutando caso de uso PurgeLogicalEntity: id='{}'", id);
+
+        if purge_logical_entity(self.uow.as_ref(), id).await? == 0 {
+            return Err(ApplicationError::NotFound(format!("Entidad lógica con ID {} no encontrada en la papelera", id)));
+        }
+
+        info!("Entidad lógica {} purgada", id);
+        Ok(())
+    }
+}
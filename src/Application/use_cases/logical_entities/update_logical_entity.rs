@@ -0,0 +1,142 @@
+// src/Application/use_cases/logical_entities/update_logical_entity.rs
+
+use async_trait::async_trait;
+use std::sync::Arc;
+use uuid::Uuid;
+use log::{info, debug, error};
+use anyhow::anyhow;
+
+use crate::Application::dtos::logical_entity_dto::{LogicalEntityDetailsDto, UpdateLogicalEntityDto};
+use crate::Application::errors::application_error::ApplicationError;
+use crate::Application::ports::unit_of_work::{UnitOfWork, RepositoryRegistry};
+use diesel_async::scoped_futures::ScopedFutureExt;
+use crate::Application::ports::driven::repositories::{
+    LogicalEntityQueryRepository,
+    AttributeQueryRepository,
+};
+use crate::Domain::record_queries::validate_search_language;
+use crate::Domain::sql_identifiers::validate_entity_name;
+use crate::Domain::views::ViewRepository;
+use super::find_logical_entity::load_entity_details;
+use super::entity_view::{sync_entity_view, remove_entity_view};
+
+#[async_trait]
+pub trait UpdateLogicalEntityUseCase: Send + Sync {
+    async fn execute(&self, id: Uuid, dto: UpdateLogicalEntityDto) -> Result<LogicalEntityDetailsDto, ApplicationError>;
+}
+
+pub struct UpdateLogicalEntityUseCaseImpl {
+    le_query_repository: Arc<dyn LogicalEntityQueryRepository>,
+    attribute_query_repository: Arc<dyn AttributeQueryRepository>,
+    view_repository: Arc<dyn ViewRepository>,
+    uow: Arc<dyn UnitOfWork>,
+}
+
+impl UpdateLogicalEntityUseCaseImpl {
+    pub fn new(
+        le_query_repository: Arc<dyn LogicalEntityQueryRepository>,
+        attribute_query_repository: Arc<dyn AttributeQueryRepository>,
+        view_repository: Arc<dyn ViewRepository>,
+        uow: Arc<dyn UnitOfWork>,
+    ) -> Self {
+        Self { le_query_repository, attribute_query_repository, view_repository, uow }
+    }
+}
+
+#[async_trait]
+impl UpdateLogicalEntityUseCase for UpdateLogicalEntityUseCaseImpl {
+    async fn execute(&self, id: Uuid, dto: UpdateLogicalEntityDto) -> Result<LogicalEntityDetailsDto, ApplicationError> {
+        info!("Ejecutando caso de uso UpdateLogicalEntity: id='{}'", id);
+
+        // 1. Validaciones básicas
+        if let Some(name) = &dto.name {
+            if name.trim().is_empty() {
+                return Err(ApplicationError::ValidationError("El nombre de la entidad no puede estar vacío".to_string()));
+            }
+        }
+        if let Some(status) = dto.status {
+            if status != 0 && status != 1 {
+                return Err(ApplicationError::ValidationError(format!("status {} inválido (valores permitidos: 0, 1)", status)));
+            }
+        }
+        if let Some(language) = &dto.search_language {
+            validate_search_language(language).map_err(|e| ApplicationError::ValidationError(e.to_string()))?;
+        }
+
+        // 2. Verificar que la entidad existe
+        let current = self.le_query_repository
+            .find_by_id(id)
+            .await
+            .map_err(|e| ApplicationError::InfrastructureError(format!("Error al buscar entidad lógica: {}", e)))?
+            .ok_or_else(|| ApplicationError::NotFound(format!("Entidad lógica con ID {} no encontrada", id)))?;
+
+        // 3. Verificar que el nuevo nombre sea válido y no esté en uso por otra entidad
+        if let Some(name) = &dto.name {
+            if name != &current.name {
+                validate_entity_name(name).map_err(|e| ApplicationError::ValidationError(e.to_string()))?;
+                debug!("Verificando unicidad del nombre '{}'", name);
+                let existing = self.le_query_repository
+                    .find_by_name(name)
+                    .await
+                    .map_err(|e| ApplicationError::InfrastructureError(format!("Error al buscar entidad lógica: {}", e)))?;
+                if existing.map(|e| e.id != id).unwrap_or(false) {
+                    return Err(ApplicationError::Conflict(format!("Ya existe una entidad lógica con el nombre '{}'", name)));
+                }
+            }
+        }
+
+        // 4. Actualizar dentro de la UoW
+        let dto_clone = dto.clone();
+        self.uow.execute(move |registry: &mut dyn RepositoryRegistry| async move {
+            let cmd_repo = registry.logical_entity_command_repository();
+            let conn = registry.get_diesel_async_conn();
+
+            let affected = cmd_repo.update(
+                conn,
+                id,
+                dto_clone.name.as_deref(),
+                dto_clone.description.as_deref(),
+                dto_clone.search_language.as_deref(),
+                dto_clone.status,
+                dto_clone.updated_by,
+            ).await.map_err(|e| anyhow!("Failed to update logical entity {}: {}", id, e))?;
+
+            if affected == 0 {
+                return Err(anyhow!(ApplicationError::NotFound(format!("Entidad lógica con ID {} no encontrada", id))));
+            }
+            Ok(())
+        }.scope_boxed()).await.map_err(|e| match e.downcast::<ApplicationError>() {
+            Ok(app_err) => app_err,
+            Err(other) => {
+                error!("Error durante la Unidad de Trabajo al actualizar la entidad {}: {:?}", id, other);
+                ApplicationError::InfrastructureError(format!("Error en transacción al actualizar entidad lógica: {}", other))
+            }
+        })?;
+        info!("Entidad lógica {} actualizada", id);
+
+        // 5. Mantener la vista sincronizada (nombre de la vista = nombre de la entidad)
+        let has_view = current.assign_view.is_some();
+        let wants_view = dto.assign_view.unwrap_or(has_view);
+        let renamed = dto.name.as_ref().map(|n| n != &current.name).unwrap_or(false);
+        if wants_view && (!has_view || renamed || dto.assign_view == Some(true)) {
+            sync_entity_view(
+                self.le_query_repository.as_ref(),
+                self.attribute_query_repository.as_ref(),
+                self.view_repository.as_ref(),
+                self.uow.as_ref(),
+                id,
+            ).await?;
+        } else if !wants_view && has_view {
+            remove_entity_view(self.view_repository.as_ref(), self.uow.as_ref(), id, current.assign_view.as_deref(), true).await?;
+        }
+
+        // 6. Devolver la entidad actualizada
+        let updated = self.le_query_repository
+            .find_by_id(id)
+            .await
+            .map_err(|e| ApplicationError::InfrastructureError(format!("Error al buscar entidad lógica: {}", e)))?
+            .ok_or_else(|| ApplicationError::NotFound(format!("Entidad lógica con ID {} no encontrada", id)))?;
+
+        load_entity_details(self.attribute_query_repository.as_ref(), updated).await
+    }
+}
@@ -1,6 +1,16 @@
 pub mod user;
 pub mod traits;
 pub mod logical_entities;
+pub mod records;
+pub mod attributes;
+pub mod schema_versions;
+pub mod data_types;
+pub mod schema_bundles;
+pub mod graphql;
+pub mod trash;
+
+#[cfg(test)]
+pub(crate) mod test_support;
 
 // Reexportar traits para facilitar su uso
 pub use traits::*;
\ No newline at end of file
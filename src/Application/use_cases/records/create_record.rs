@@ -0,0 +1,116 @@
+// src/Application/use_cases/records/create_record.rs
+
+use async_trait::async_trait;
+use std::sync::Arc;
+use serde_json::{Map, Value};
+use log::info;
+use anyhow::anyhow;
+
+use crate::Application::dtos::record_dto::RecordUpdateMode;
+use crate::Domain::record_history::ChangeContext;
+use crate::Application::errors::application_error::ApplicationError;
+use crate::Application::ports::unit_of_work::{UnitOfWork, RepositoryRegistry};
+use diesel_async::scoped_futures::ScopedFutureExt;
+use crate::Application::ports::driven::repositories::{
+    LogicalEntityQueryRepository,
+    AttributeQueryRepository,
+    RecordQueryRepository,
+    RecordDto,
+};
+use super::record_values::{resolve_entity, prepare_record_values, enforce_unique_keys, apply_value_writes, map_uow_error};
+use super::record_references::enforce_references;
+use super::record_history::{load_values, history_entries, write_history};
+use crate::Domain::record_history::RecordChangeKind;
+
+#[async_trait]
+pub trait CreateRecordUseCase: Send + Sync {
+    async fn execute(&self, entity_name: &str, values: Map<String, Value>, context: ChangeContext) -> Result<RecordDto, ApplicationError>;
+}
+
+pub struct CreateRecordUseCaseImpl {
+    le_query_repository: Arc<dyn LogicalEntityQueryRepository>,
+    attribute_query_repository: Arc<dyn AttributeQueryRepository>,
+    record_query_repository: Arc<dyn RecordQueryRepository>,
+    uow: Arc<dyn UnitOfWork>,
+}
+
+impl CreateRecordUseCaseImpl {
+    pub fn new(
+        le_query_repository: Arc<dyn LogicalEntityQueryRepository>,
+        attribute_query_repository: Arc<dyn AttributeQueryRepository>,
+        record_query_repository: Arc<dyn RecordQueryRepository>,
+        uow: Arc<dyn UnitOfWork>,
+    ) -> Self {
+        Self { le_query_repository, attribute_query_repository, record_query_repository, uow }
+    }
+}
+
+#[async_trait]
+impl CreateRecordUseCase for CreateRecordUseCaseImpl {
+    async fn execute(&self, entity_name: &str, values: Map<String, Value>, context: ChangeContext) -> Result<RecordDto, ApplicationError> {
+        info!("Ejecutando caso de uso CreateRecord: entity='{}'", entity_name);
+
+        // 1. Resolver entidad y atributos, y aplicar las reglas de los atributos
+        let (entity, attributes) = resolve_entity(
+            self.le_query_repository.as_ref(),
+            self.attribute_query_repository.as_ref(),
+            entity_name,
+        ).await?;
+        let prepared = prepare_record_values(&attributes, &values, None, RecordUpdateMode::Replace)?;
+
+        // 2. Comprobar unicidad y crear la tupla y sus valores en una única transacción
+        let entity_id = entity.id;
+        let record_id = self.uow.execute(move |registry: &mut dyn RepositoryRegistry| async move {
+            let record_repo = registry.record_command_repository();
+            let conn = registry.get_diesel_async_conn();
+
+            enforce_unique_keys(record_repo, conn, entity_id, None, &prepared.unique_keys).await?;
+            enforce_references(record_repo, conn, &prepared.references).await?;
+            let record_id = record_repo.create_tuple(conn, entity_id, context.actor).await
+                .map_err(|e| anyhow!("Failed to create tuple for entity {}: {}", entity_id, e))?;
+            apply_value_writes(record_repo, conn, entity_id, record_id, &prepared.writes).await?;
+
+            // Historial: creación y valores iniciales (incluidos los por defecto)
+            let after = load_values(record_repo, conn, record_id).await?;
+            let entries = history_entries(&context, entity_id, record_id, RecordChangeKind::Create, &[], &after);
+            write_history(record_repo, conn, entries).await?;
+            Ok(record_id)
+        }.scope_boxed()).await.map_err(|e| map_uow_error(e, "crear registro"))?;
+        info!("Registro {} creado en la entidad '{}'", record_id, entity_name);
+
+        // 3. Devolver el registro creado
+        self.record_query_repository
+            .find_by_id(entity_id, record_id)
+            .await
+            .map_err(|e| ApplicationError::InfrastructureError(format!("Error al buscar registro: {}", e)))?
+            .ok_or_else(|| ApplicationError::NotFound(format!("Registro con ID {} no encontrado", record_id)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Application::errors::application_error::FieldError;
+    use crate::Application::ports::driven::repositories::LogicalEntityDto;
+    use crate::Application::use_cases::records::record_constraints::REFERENCE_NOT_FOUND;
+    use crate::Application::use_cases::test_support::{FailingUnitOfWork, EntityQueryStub, AttributeQueryStub, RecordQueryStub};
+
+    #[tokio::test]
+    async fn dangling_reference_is_a_validation_error() {
+        let entity = LogicalEntityDto::for_tests("pedidos");
+        let record = RecordDto::for_tests(entity.id, 1);
+        // Error de enforce_references: la tupla referenciada no existe
+        let errors = vec![FieldError::new("cliente", REFERENCE_NOT_FOUND, "no existe")];
+        let use_case = CreateRecordUseCaseImpl::new(
+            Arc::new(EntityQueryStub { entity }),
+            Arc::new(AttributeQueryStub { attributes: Vec::new() }),
+            Arc::new(RecordQueryStub { record }),
+            Arc::new(FailingUnitOfWork::new(ApplicationError::FieldValidationErrors(errors))),
+        );
+
+        match use_case.execute("pedidos", Map::new(), ChangeContext::system()).await {
+            Err(ApplicationError::FieldValidationErrors(errors)) => assert_eq!(errors[0].field, "cliente"),
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,130 @@
+// src/Application/use_cases/records/delete_record.rs
+
+use async_trait::async_trait;
+use std::sync::Arc;
+use uuid::Uuid;
+use log::info;
+use anyhow::anyhow;
+
+use crate::Domain::record_history::ChangeContext;
+use crate::Domain::concurrency::VersionPrecondition;
+use crate::Application::errors::application_error::ApplicationError;
+use crate::Application::ports::unit_of_work::{UnitOfWork, RepositoryRegistry};
+use diesel_async::scoped_futures::ScopedFutureExt;
+use crate::Application::ports::driven::repositories::{
+    LogicalEntityQueryRepository,
+    AttributeQueryRepository,
+};
+use super::record_values::{resolve_entity, map_uow_error, check_record_version};
+use super::record_references::{delete_tuple_with_references, TupleRemoval};
+
+#[async_trait]
+pub trait DeleteRecordUseCase: Send + Sync {
+    /// Mueve el registro a la papelera (junto con los que lo referencian en cascada).
+    /// Con `precondition` (If-Match), solo si la versión del registro coincide.
+    async fn execute(&self, entity_name: &str, id: Uuid, context: ChangeContext, precondition: Option<VersionPrecondition>) -> Result<(), ApplicationError>;
+}
+
+pub struct DeleteRecordUseCaseImpl {
+    le_query_repository: Arc<dyn LogicalEntityQueryRepository>,
+    attribute_query_repository: Arc<dyn AttributeQueryRepository>,
+    uow: Arc<dyn UnitOfWork>,
+}
+
+impl DeleteRecordUseCaseImpl {
+    pub fn new(
+        le_query_repository: Arc<dyn LogicalEntityQueryRepository>,
+        attribute_query_repository: Arc<dyn AttributeQueryRepository>,
+        uow: Arc<dyn UnitOfWork>,
+    ) -> Self {
+        Self { le_query_repository, attribute_query_repository, uow }
+    }
+}
+
+#[async_trait]
+impl DeleteRecordUseCase for DeleteRecordUseCaseImpl {
+    async fn execute(&self, entity_name: &str, id: Uuid, context: ChangeContext, precondition: Option<VersionPrecondition>) -> Result<(), ApplicationError> {
+        info!("Ejecutando caso de uso DeleteRecord: entity='{}', id='{}'", entity_name, id);
+
+        let (entity, _) = resolve_entity(
+            self.le_query_repository.as_ref(),
+            self.attribute_query_repository.as_ref(),
+            entity_name,
+        ).await?;
+
+        let entity_id = entity.id;
+        let deleted = self.uow.execute(move |registry: &mut dyn RepositoryRegistry| async move {
+            let attribute_query_repo = registry.attribute_query_repository();
+            let record_repo = registry.record_command_repository();
+            let conn = registry.get_diesel_async_conn();
+
+            if let Some(precondition) = &precondition {
+                check_record_version(record_repo, conn, entity_id, id, Some(precondition), None).await?;
+            }
+
+            // Aplica el on_delete de los atributos que referencian el registro
+            let removal = TupleRemoval::SoftDelete;
+            let deleted = delete_tuple_with_references(attribute_query_repo.as_ref(), record_repo, conn, entity_id, id, removal, &context).await?;
+            if deleted == 0 {
+                return Err(anyhow!(ApplicationError::NotFound(format!("Registro con ID {} no encontrado", id))));
+            }
+            Ok(deleted)
+        }.scope_boxed()).await.map_err(|e| map_uow_error(e, "eliminar registro"))?;
+
+        info!("Registro {} de la entidad '{}' movido a la papelera ({} registros en total)", id, entity_name, deleted);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Application::ports::driven::repositories::LogicalEntityDto;
+    use crate::Application::use_cases::test_support::{FailingUnitOfWork, EntityQueryStub, AttributeQueryStub};
+
+    /// Caso de uso sobre la entidad "clientes" cuya UoW falla con `error`.
+    fn use_case(error: ApplicationError) -> DeleteRecordUseCaseImpl {
+        DeleteRecordUseCaseImpl::new(
+            Arc::new(EntityQueryStub { entity: LogicalEntityDto::for_tests("clientes") }),
+            Arc::new(AttributeQueryStub { attributes: Vec::new() }),
+            Arc::new(FailingUnitOfWork::new(error)),
+        )
+    }
+
+    #[tokio::test]
+    async fn missing_record_is_not_found() {
+        let use_case = use_case(ApplicationError::NotFound("registro".to_string()));
+
+        let result = use_case.execute("clientes", Uuid::new_v4(), ChangeContext::system(), None).await;
+
+        assert!(matches!(result, Err(ApplicationError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn stale_if_match_is_a_failed_precondition() {
+        let use_case = use_case(ApplicationError::PreconditionFailed("registro".to_string()));
+        let precondition = VersionPrecondition::from_if_match("\"2\"");
+
+        let result = use_case.execute("clientes", Uuid::new_v4(), ChangeContext::system(), Some(precondition)).await;
+
+        assert!(matches!(result, Err(ApplicationError::PreconditionFailed(_))));
+    }
+
+    #[tokio::test]
+    async fn restrict_reference_is_a_conflict() {
+        let use_case = use_case(ApplicationError::Conflict("restrict".to_string()));
+
+        let result = use_case.execute("clientes", Uuid::new_v4(), ChangeContext::system(), None).await;
+
+        assert!(matches!(result, Err(ApplicationError::Conflict(_))));
+    }
+
+    #[tokio::test]
+    async fn unknown_entity_is_not_found() {
+        let use_case = use_case(ApplicationError::InfrastructureError("no debe llegar".to_string()));
+
+        let result = use_case.execute("pedidos", Uuid::new_v4(), ChangeContext::system(), None).await;
+
+        assert!(matches!(result, Err(ApplicationError::NotFound(_))));
+    }
+}
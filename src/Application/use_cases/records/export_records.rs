@@ -0,0 +1,109 @@
+// src/Application/use_cases/records/export_records.rs
+//
+// Exportación de todos los registros de una entidad (opcionalmente filtrados).
+// Los registros se leen por lotes con un cursor del servidor, de modo que nunca
+// se carga el resultado completo en memoria.
+
+use async_trait::async_trait;
+use chrono::SecondsFormat;
+use futures::stream::StreamExt;
+use std::sync::Arc;
+use log::{error, info};
+
+use crate::Application::dtos::record_dto::RecordExportDto;
+use crate::Application::errors::application_error::ApplicationError;
+use crate::Application::ports::driven::repositories::{
+    LogicalEntityQueryRepository,
+    AttributeQueryRepository,
+    RecordQueryRepository,
+    ExportRecordDto,
+};
+use crate::Domain::errors::DomainError;
+use crate::Domain::record_exports::{ExportColumn, ExportValueKind};
+use crate::Domain::record_queries::QueryAttribute;
+use super::find_record::{build_query, ListRecordsParams};
+use super::record_values::resolve_entity;
+
+/// Registros leídos del cursor en cada lote.
+pub const EXPORT_BATCH_SIZE: usize = 1_000;
+
+#[async_trait]
+pub trait ExportRecordsUseCase: Send + Sync {
+    /// Prepara la exportación de los registros que cumplen `filter`, en el orden de `sort`
+    /// (misma sintaxis que el listado). Las filas se leen al consumir el stream.
+    async fn execute(&self, entity_name: &str, filter: Option<String>, sort: Option<String>) -> Result<RecordExportDto, ApplicationError>;
+}
+
+pub struct ExportRecordsUseCaseImpl {
+    le_query_repository: Arc<dyn LogicalEntityQueryRepository>,
+    attribute_query_repository: Arc<dyn AttributeQueryRepository>,
+    record_query_repository: Arc<dyn RecordQueryRepository>,
+}
+
+impl ExportRecordsUseCaseImpl {
+    pub fn new(
+        le_query_repository: Arc<dyn LogicalEntityQueryRepository>,
+        attribute_query_repository: Arc<dyn AttributeQueryRepository>,
+        record_query_repository: Arc<dyn RecordQueryRepository>,
+    ) -> Self {
+        Self { le_query_repository, attribute_query_repository, record_query_repository }
+    }
+}
+
+#[async_trait]
+impl ExportRecordsUseCase for ExportRecordsUseCaseImpl {
+    async fn execute(&self, entity_name: &str, filter: Option<String>, sort: Option<String>) -> Result<RecordExportDto, ApplicationError> {
+        info!("Ejecutando caso de uso ExportRecords: entity='{}', filter={:?}, sort={:?}", entity_name, filter, sort);
+
+        let (entity, mut attributes) = resolve_entity(
+            self.le_query_repository.as_ref(),
+            self.attribute_query_repository.as_ref(),
+            entity_name,
+        ).await?;
+        attributes.sort_by(|a, b| a.position.cmp(&b.position).then_with(|| a.name.cmp(&b.name)));
+
+        let query_attributes: Vec<QueryAttribute> = attributes.iter()
+            .map(|a| QueryAttribute { id: a.id, name: a.name.clone(), column: a.storage.column })
+            .collect();
+        let params = ListRecordsParams { filter, sort, limit: 0, after: None };
+        let query = build_query(&params, &query_attributes).map_err(|e| match e {
+            DomainError::ValidationError(message) => ApplicationError::ValidationError(message),
+            other => ApplicationError::ValidationError(other.to_string()),
+        })?;
+
+        // Columnas: id, atributos por posición y columnas de auditoría
+        let attribute_names: Vec<String> = attributes.iter().map(|a| a.name.clone()).collect();
+        let mut columns = vec![ExportColumn::new("id", ExportValueKind::Text)];
+        columns.extend(attributes.iter().map(|a| ExportColumn::new(&a.name, ExportValueKind::from(a.storage.column))));
+        for name in ["created_by", "created_at", "updated_by", "updated_at"] {
+            columns.push(ExportColumn::new(name, ExportValueKind::Text));
+        }
+
+        let entity_name = entity_name.to_string();
+        let rows = self.record_query_repository
+            .export(entity.id, query.filter.as_ref(), &query.sort, EXPORT_BATCH_SIZE)
+            .map(move |batch| match batch {
+                Ok(records) => Ok(records.into_iter().map(|record| export_row(&attribute_names, record)).collect()),
+                Err(e) => {
+                    error!("Error al exportar registros de '{}': {}", entity_name, e);
+                    Err(ApplicationError::InfrastructureError(format!("Error al exportar registros: {}", e)))
+                },
+            })
+            .boxed();
+
+        Ok(RecordExportDto { columns, rows })
+    }
+}
+
+/// Valores de un registro en el orden de las columnas de la exportación.
+fn export_row(attribute_names: &[String], mut record: ExportRecordDto) -> Vec<Option<String>> {
+    let timestamp = |t: chrono::DateTime<chrono::Utc>| t.to_rfc3339_opts(SecondsFormat::Micros, true);
+    let mut row = Vec::with_capacity(attribute_names.len() + 5);
+    row.push(Some(record.id.to_string()));
+    row.extend(attribute_names.iter().map(|name| record.values.remove(name)));
+    row.push(record.created_by.map(|id| id.to_string()));
+    row.push(Some(timestamp(record.created_at)));
+    row.push(record.updated_by.map(|id| id.to_string()));
+    row.push(record.updated_at.map(timestamp));
+    row
+}
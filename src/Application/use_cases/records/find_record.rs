@@ -0,0 +1,163 @@
+// src/Application/use_cases/records/find_record.rs
+
+use async_trait::async_trait;
+use std::sync::Arc;
+use uuid::Uuid;
+use log::info;
+
+use crate::Application::dtos::record_dto::RecordPageDto;
+use crate::Application::errors::application_error::ApplicationError;
+use crate::Application::ports::driven::repositories::{
+    LogicalEntityQueryRepository,
+    AttributeQueryRepository,
+    RecordQueryRepository,
+    RecordDto,
+};
+use crate::Domain::errors::DomainError;
+use crate::Domain::record_queries::{
+    parse_filter, parse_sort, resolve_filter, sort_signature, QueryAttribute, RecordCursor, RecordQuery,
+};
+use super::record_values::resolve_entity;
+
+/// Tamaño máximo de página permitido para el listado de registros.
+pub const MAX_PAGE_SIZE: i64 = 100;
+
+/// Parámetros del listado de registros, tal como llegan de la API.
+#[derive(Debug, Clone, Default)]
+pub struct ListRecordsParams {
+    /// Expresión de filtro (`status eq 'open' and amount gt 100`).
+    pub filter: Option<String>,
+    /// Claves de ordenación separadas por comas; `-` indica descendente.
+    pub sort: Option<String>,
+    pub limit: i64,
+    /// Cursor opaco devuelto como `next_cursor` en la página anterior.
+    pub after: Option<String>,
+}
+
+// --- Traits de los Casos de Uso ---
+#[async_trait]
+pub trait FindRecordUseCase: Send + Sync {
+    async fn execute(&self, entity_name: &str, id: Uuid) -> Result<RecordDto, ApplicationError>;
+}
+
+#[async_trait]
+pub trait ListRecordsUseCase: Send + Sync {
+    async fn execute(&self, entity_name: &str, params: ListRecordsParams) -> Result<RecordPageDto, ApplicationError>;
+}
+
+// --- Implementación: búsqueda por ID ---
+pub struct FindRecordUseCaseImpl {
+    le_query_repository: Arc<dyn LogicalEntityQueryRepository>,
+    attribute_query_repository: Arc<dyn AttributeQueryRepository>,
+    record_query_repository: Arc<dyn RecordQueryRepository>,
+}
+
+impl FindRecordUseCaseImpl {
+    pub fn new(
+        le_query_repository: Arc<dyn LogicalEntityQueryRepository>,
+        attribute_query_repository: Arc<dyn AttributeQueryRepository>,
+        record_query_repository: Arc<dyn RecordQueryRepository>,
+    ) -> Self {
+        Self { le_query_repository, attribute_query_repository, record_query_repository }
+    }
+}
+
+#[async_trait]
+impl FindRecordUseCase for FindRecordUseCaseImpl {
+    async fn execute(&self, entity_name: &str, id: Uuid) -> Result<RecordDto, ApplicationError> {
+        info!("Ejecutando caso de uso FindRecord: entity='{}', id='{}'", entity_name, id);
+
+        let (entity, _) = resolve_entity(
+            self.le_query_repository.as_ref(),
+            self.attribute_query_repository.as_ref(),
+            entity_name,
+        ).await?;
+
+        self.record_query_repository
+            .find_by_id(entity.id, id)
+            .await
+            .map_err(|e| ApplicationError::InfrastructureError(format!("Error al buscar registro: {}", e)))?
+            .ok_or_else(|| ApplicationError::NotFound(format!("Registro con ID {} no encontrado en '{}'", id, entity_name)))
+    }
+}
+
+// --- Implementación: listado paginado ---
+pub struct ListRecordsUseCaseImpl {
+    le_query_repository: Arc<dyn LogicalEntityQueryRepository>,
+    attribute_query_repository: Arc<dyn AttributeQueryRepository>,
+    record_query_repository: Arc<dyn RecordQueryRepository>,
+}
+
+impl ListRecordsUseCaseImpl {
+    pub fn new(
+        le_query_repository: Arc<dyn LogicalEntityQueryRepository>,
+        attribute_query_repository: Arc<dyn AttributeQueryRepository>,
+        record_query_repository: Arc<dyn RecordQueryRepository>,
+    ) -> Self {
+        Self { le_query_repository, attribute_query_repository, record_query_repository }
+    }
+}
+
+#[async_trait]
+impl ListRecordsUseCase for ListRecordsUseCaseImpl {
+    async fn execute(&self, entity_name: &str, params: ListRecordsParams) -> Result<RecordPageDto, ApplicationError> {
+        info!("Ejecutando caso de uso ListRecords: entity='{}', filter={:?}, sort={:?}, limit={}", entity_name, params.filter, params.sort, params.limit);
+
+        if params.limit < 1 || params.limit > MAX_PAGE_SIZE {
+            return Err(ApplicationError::ValidationError(format!("limit debe estar entre 1 y {}", MAX_PAGE_SIZE)));
+        }
+
+        let (entity, attributes) = resolve_entity(
+            self.le_query_repository.as_ref(),
+            self.attribute_query_repository.as_ref(),
+            entity_name,
+        ).await?;
+
+        let query_attributes: Vec<QueryAttribute> = attributes.iter()
+            .map(|a| QueryAttribute { id: a.id, name: a.name.clone(), column: a.storage.column })
+            .collect();
+
+        let mut query = build_query(&params, &query_attributes).map_err(|e| match e {
+            DomainError::ValidationError(message) => ApplicationError::ValidationError(message),
+            other => ApplicationError::ValidationError(other.to_string()),
+        })?;
+        // Se pide un registro de más para saber si hay página siguiente
+        query.limit = params.limit + 1;
+
+        let mut rows = self.record_query_repository
+            .find_page(entity.id, &query)
+            .await
+            .map_err(|e| ApplicationError::InfrastructureError(format!("Error al listar registros: {}", e)))?;
+
+        let next_cursor = if rows.len() as i64 > params.limit {
+            rows.truncate(params.limit as usize);
+            rows.last().map(|last| RecordCursor {
+                sort: sort_signature(&query.sort),
+                values: last.sort_values.clone(),
+                id: last.record.id,
+            }.encode())
+        } else {
+            None
+        };
+
+        Ok(RecordPageDto {
+            items: rows.into_iter().map(|row| row.record).collect(),
+            limit: params.limit,
+            next_cursor,
+        })
+    }
+}
+
+/// Analiza y resuelve filtro, ordenación y cursor contra los atributos de la entidad.
+pub(super) fn build_query(params: &ListRecordsParams, attributes: &[QueryAttribute]) -> Result<RecordQuery, DomainError> {
+    let filter = match params.filter.as_deref().map(str::trim).filter(|f| !f.is_empty()) {
+        Some(text) => Some(resolve_filter(&parse_filter(text)?, attributes)?),
+        None => None,
+    };
+    let sort = parse_sort(params.sort.as_deref(), attributes)?;
+    let after = match params.after.as_deref().filter(|a| !a.is_empty()) {
+        Some(token) => Some(RecordCursor::decode(token, &sort_signature(&sort), sort.len())?),
+        None => None,
+    };
+    Ok(RecordQuery { filter, sort, limit: params.limit, after })
+}
@@ -0,0 +1,251 @@
+// src/Application/use_cases/records/import_records.rs
+//
+// Importación masiva de registros desde CSV o NDJSON. Cada fila se valida con
+// las mismas reglas que el alta individual; además se comprueban los valores
+// únicos repetidos dentro del archivo y, contra la base de datos, la unicidad
+// y las referencias. Las filas válidas se insertan en lotes.
+
+use async_trait::async_trait;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::Arc;
+use serde_json::{Map, Value};
+use uuid::Uuid;
+use log::{debug, info, warn};
+
+use crate::Application::dtos::record_dto::{ImportRecordsDto, ImportReportDto, ImportRowErrorDto, RecordUpdateMode};
+use crate::Application::errors::application_error::{ApplicationError, FieldError};
+use crate::Application::ports::driven::repositories::{
+    LogicalEntityQueryRepository,
+    AttributeQueryRepository,
+    RecordBatchRepository,
+    AttributeValueMatch,
+    AttributeDto,
+    NewRecordDto,
+    UniqueKeyValuesDto,
+    ReferencedTuplesDto,
+    BatchInsertOutcome,
+};
+use crate::Domain::record_imports::{ImportErrorPolicy, ImportFormat};
+use crate::Domain::records::StorageColumn;
+use super::record_values::{resolve_entity, prepare_record_values, PreparedRecord};
+use super::record_constraints::{unique_violation, INVALID_TYPE, REFERENCE_NOT_FOUND, UNIQUE};
+
+/// Código de los errores que afectan a toda la fila (formato, número de campos).
+const INVALID_ROW: &str = "invalid_row";
+
+#[async_trait]
+pub trait ImportRecordsUseCase: Send + Sync {
+    /// Valida e importa las filas del archivo en la entidad y devuelve el informe por fila.
+    async fn execute(&self, entity_name: &str, import: ImportRecordsDto) -> Result<ImportReportDto, ApplicationError>;
+}
+
+pub struct ImportRecordsUseCaseImpl {
+    le_query_repository: Arc<dyn LogicalEntityQueryRepository>,
+    attribute_query_repository: Arc<dyn AttributeQueryRepository>,
+    record_batch_repository: Arc<dyn RecordBatchRepository>,
+}
+
+impl ImportRecordsUseCaseImpl {
+    pub fn new(
+        le_query_repository: Arc<dyn LogicalEntityQueryRepository>,
+        attribute_query_repository: Arc<dyn AttributeQueryRepository>,
+        record_batch_repository: Arc<dyn RecordBatchRepository>,
+    ) -> Self {
+        Self { le_query_repository, attribute_query_repository, record_batch_repository }
+    }
+}
+
+/// Fila que superó la validación de sus valores.
+struct Candidate {
+    line: usize,
+    prepared: PreparedRecord,
+    valid: bool,
+}
+
+#[async_trait]
+impl ImportRecordsUseCase for ImportRecordsUseCaseImpl {
+    async fn execute(&self, entity_name: &str, import: ImportRecordsDto) -> Result<ImportReportDto, ApplicationError> {
+        info!(
+            "Ejecutando caso de uso ImportRecords: entity='{}', format={}, dry_run={}, on_error={}",
+            entity_name, import.format.as_str(), import.dry_run, import.on_error.as_str()
+        );
+        // 1. Resolver la entidad (el archivo ya llega leído por filas)
+        let mapping = import.mapping;
+        let (entity, attributes) = resolve_entity(
+            self.le_query_repository.as_ref(),
+            self.attribute_query_repository.as_ref(),
+            entity_name,
+        ).await?;
+        let (rows, issues) = (import.rows, import.issues);
+        let total_rows = rows.len() + issues.len();
+        debug!("Archivo leído: {} filas ({} con errores de formato)", total_rows, issues.len());
+
+        let mut errors: Vec<ImportRowErrorDto> = issues
+            .into_iter()
+            .map(|issue| ImportRowErrorDto { line: issue.line, column: issue.column, code: INVALID_ROW.to_string(), reason: issue.reason })
+            .collect();
+        let row_errors = |line: usize, field_errors: Vec<FieldError>| -> Vec<ImportRowErrorDto> {
+            field_errors
+                .into_iter()
+                .map(|e| ImportRowErrorDto { line, column: Some(mapping.column_for(&e.field)), code: e.code, reason: e.message })
+                .collect()
+        };
+
+        // 2. Validar los valores de cada fila con las reglas de los atributos
+        let mut candidates = Vec::with_capacity(rows.len());
+        for row in rows {
+            let mut values = row.values;
+            if import.format == ImportFormat::Csv {
+                if let Err(error) = parse_json_cells(&attributes, &mut values) {
+                    errors.extend(row_errors(row.line, vec![error]));
+                    continue;
+                }
+            }
+            match prepare_record_values(&attributes, &values, None, RecordUpdateMode::Replace) {
+                Ok(prepared) => candidates.push(Candidate { line: row.line, prepared, valid: true }),
+                Err(ApplicationError::FieldValidationErrors(field_errors)) => errors.extend(row_errors(row.line, field_errors)),
+                Err(other) => return Err(other),
+            }
+        }
+
+        // 3. Valores únicos repetidos dentro del archivo (la primera aparición es válida)
+        let mut first_seen: HashMap<(i16, Vec<String>), usize> = HashMap::new();
+        for candidate in candidates.iter_mut() {
+            let duplicated = candidate.prepared.unique_keys.iter().find_map(|key| {
+                let values: Vec<String> = key.matches.iter().map(|m| m.value.clone()).collect();
+                first_seen.get(&(key.group, values)).map(|line| (key, *line))
+            });
+            if let Some((key, line)) = duplicated {
+                errors.push(ImportRowErrorDto {
+                    line: candidate.line,
+                    column: Some(mapping.column_for(&key.fields[0])),
+                    code: UNIQUE.to_string(),
+                    reason: format!("Valor repetido de '{}': coincide con la línea {}", key.fields.join(", "), line),
+                });
+                candidate.valid = false;
+                continue;
+            }
+            for key in &candidate.prepared.unique_keys {
+                let values: Vec<String> = key.matches.iter().map(|m| m.value.clone()).collect();
+                first_seen.insert((key.group, values), candidate.line);
+            }
+        }
+
+        // 4. Unicidad y referencias contra los registros existentes
+        let mut key_owners = Vec::new();
+        let mut keys = Vec::new();
+        for (index, candidate) in candidates.iter().enumerate().filter(|(_, c)| c.valid) {
+            for key in &candidate.prepared.unique_keys {
+                key_owners.push((index, key.group, key.fields.clone()));
+                keys.push(UniqueKeyValuesDto { group: key.group, matches: key.matches.clone() });
+            }
+        }
+        if !keys.is_empty() {
+            let existing = self.record_batch_repository
+                .find_existing_keys(entity.id, &keys)
+                .await
+                .map_err(|e| ApplicationError::InfrastructureError(format!("Error al comprobar valores únicos: {}", e)))?;
+            let mut positions: Vec<usize> = existing.into_iter().collect();
+            positions.sort();
+            for position in positions {
+                let (index, group, fields) = &key_owners[position];
+                let candidate = &mut candidates[*index];
+                errors.extend(row_errors(candidate.line, unique_violation(*group, fields)));
+                candidate.valid = false;
+            }
+        }
+
+        let mut referenced: BTreeMap<Uuid, HashSet<Uuid>> = BTreeMap::new();
+        for candidate in candidates.iter().filter(|c| c.valid) {
+            for reference in &candidate.prepared.references {
+                referenced.entry(reference.entity_id).or_default().insert(reference.tuple_id);
+            }
+        }
+        for (referenced_entity_id, ids) in &referenced {
+            let ids: Vec<Uuid> = ids.iter().copied().collect();
+            let existing = self.record_batch_repository
+                .find_existing_tuples(*referenced_entity_id, &ids)
+                .await
+                .map_err(|e| ApplicationError::InfrastructureError(format!("Error al comprobar referencias: {}", e)))?;
+            for candidate in candidates.iter_mut().filter(|c| c.valid) {
+                let missing: Vec<FieldError> = candidate.prepared.references
+                    .iter()
+                    .filter(|r| r.entity_id == *referenced_entity_id && !existing.contains(&r.tuple_id))
+                    .map(|r| FieldError::new(&r.field, REFERENCE_NOT_FOUND, format!("El registro referenciado {} no existe", r.tuple_id)))
+                    .collect();
+                if !missing.is_empty() {
+                    errors.extend(row_errors(candidate.line, missing));
+                    candidate.valid = false;
+                }
+            }
+        }
+
+        errors.sort_by_key(|e| e.line);
+        let valid: Vec<&PreparedRecord> = candidates.iter().filter(|c| c.valid).map(|c| &c.prepared).collect();
+        let mut report = ImportReportDto {
+            total_rows,
+            valid_rows: valid.len(),
+            invalid_rows: total_rows - valid.len(),
+            imported: 0,
+            dry_run: import.dry_run,
+            on_error: import.on_error.as_str().to_string(),
+            applied: false,
+            errors,
+        };
+
+        // 5. Insertar las filas válidas en lotes (salvo dry_run o errores con all_or_nothing)
+        if import.dry_run || valid.is_empty() {
+            return Ok(report);
+        }
+        if report.invalid_rows > 0 && import.on_error == ImportErrorPolicy::AllOrNothing {
+            warn!("Importación en '{}' cancelada: {} filas con errores (all_or_nothing)", entity_name, report.invalid_rows);
+            return Ok(report);
+        }
+
+        let records: Vec<NewRecordDto> = valid
+            .iter()
+            .map(|prepared| NewRecordDto {
+                values: prepared.writes
+                    .iter()
+                    .filter_map(|w| w.value.clone().map(|value| AttributeValueMatch { attribute_id: w.attribute_id, column: w.column, value }))
+                    .collect(),
+            })
+            .collect();
+        let unique_keys: Vec<UniqueKeyValuesDto> = valid
+            .iter()
+            .flat_map(|prepared| prepared.unique_keys.iter())
+            .map(|key| UniqueKeyValuesDto { group: key.group, matches: key.matches.clone() })
+            .collect();
+        let references: Vec<ReferencedTuplesDto> = referenced
+            .into_iter()
+            .map(|(entity_id, ids)| ReferencedTuplesDto { entity_id, ids: ids.into_iter().collect() })
+            .collect();
+
+        let outcome = self.record_batch_repository
+            .insert_records(entity.id, &records, &unique_keys, &references, &import.context)
+            .await
+            .map_err(|e| ApplicationError::InfrastructureError(format!("Error al insertar registros: {}", e)))?;
+        match outcome {
+            BatchInsertOutcome::Inserted(ids) => {
+                report.imported = ids.len();
+                report.applied = true;
+                info!("{} registros importados en la entidad '{}'", ids.len(), entity_name);
+                Ok(report)
+            },
+            BatchInsertOutcome::Conflict(message) => Err(ApplicationError::Conflict(message)),
+        }
+    }
+}
+
+/// En CSV todos los valores llegan como texto: los de atributos JSON se
+/// interpretan como documento JSON antes de validarlos.
+fn parse_json_cells(attributes: &[AttributeDto], values: &mut Map<String, Value>) -> Result<(), FieldError> {
+    for attribute in attributes.iter().filter(|a| a.storage.column == StorageColumn::Json) {
+        if let Some(Value::String(text)) = values.get(&attribute.name) {
+            let document = serde_json::from_str::<Value>(text)
+                .map_err(|e| FieldError::new(&attribute.name, INVALID_TYPE, format!("JSON no válido: {}", e)))?;
+            values.insert(attribute.name.clone(), document);
+        }
+    }
+    Ok(())
+}
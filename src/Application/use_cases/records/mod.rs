@@ -0,0 +1,41 @@
+pub(crate) mod record_values;
+pub(crate) mod record_constraints;
+pub(crate) mod record_references;
+pub(crate) mod record_history;
+pub mod create_record;
+pub mod find_record;
+pub mod update_record;
+pub mod delete_record;
+pub mod search_records;
+pub mod import_records;
+pub mod export_records;
+pub mod trash_records;
+pub mod record_history_queries;
+
+pub use create_record::{CreateRecordUseCase, CreateRecordUseCaseImpl};
+pub use find_record::{
+FindRecordUseCase,
+FindRecordUseCaseImpl,
+ListRecordsUseCase,
+ListRecordsUseCaseImpl,
+ListRecordsParams,
+};
+pub use update_record::{UpdateRecordUseCase, UpdateRecordUseCaseImpl};
+pub use delete_record::{DeleteRecordUseCase, DeleteRecordUseCaseImpl};
+pub use search_records::{SearchRecordsUseCase, SearchRecordsUseCaseImpl};
+pub use import_records::{ImportRecordsUseCase, ImportRecordsUseCaseImpl};
+pub use export_records::{ExportRecordsUseCase, ExportRecordsUseCaseImpl};
+pub use trash_records::{
+ListDeletedRecordsUseCase,
+ListDeletedRecordsUseCaseImpl,
+RestoreRecordUseCase,
+RestoreRecordUseCaseImpl,
+PurgeRecordUseCase,
+PurgeRecordUseCaseImpl,
+};
+pub use record_history_queries::{
+RecordHistoryUseCase,
+RecordHistoryUseCaseImpl,
+FindRecordAsOfUseCase,
+FindRecordAsOfUseCaseImpl,
+};
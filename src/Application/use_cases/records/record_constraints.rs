@@ -0,0 +1,159 @@
+// src/Application/use_cases/records/record_constraints.rs
+//
+// Reglas declaradas en los atributos (is_required, default_value, validation_regex,
+// is_unique, lista de opciones, referencias) y en su tipo de dato (parámetros del registro de
+// tipos) que se aplican al escribir registros.
+
+use regex::Regex;
+use serde_json::Value;
+
+use crate::Application::errors::application_error::FieldError;
+use crate::Application::ports::driven::repositories::AttributeDto;
+use crate::Domain::attribute_options::check_option_code;
+use crate::Domain::errors::DomainError;
+use crate::Domain::records::StorageColumn;
+
+// --- Códigos de error por campo ---
+pub(crate) const UNKNOWN_ATTRIBUTE: &str = "unknown_attribute";
+pub(crate) const INVALID_TYPE: &str = "invalid_type";
+pub(crate) const TYPE_CONSTRAINT: &str = "type_constraint";
+pub(crate) const REQUIRED: &str = "required";
+pub(crate) const PATTERN: &str = "pattern";
+pub(crate) const INVALID_OPTION: &str = "invalid_option";
+pub(crate) const REFERENCE_NOT_FOUND: &str = "reference_not_found";
+pub(crate) const UNIQUE: &str = "unique";
+
+/// Valor por defecto del atributo como JSON, listo para convertirse a su columna.
+/// `default_value` se guarda como texto: para JSON se interpreta como documento,
+/// para el resto de tipos se pasa como cadena y lo convierte `to_storage_text`.
+pub(crate) fn default_value_for(attribute: &AttributeDto, column: StorageColumn) -> Option<Value> {
+    let default = attribute.default_value.as_deref()?;
+    match column {
+        StorageColumn::Json => Some(serde_json::from_str(default).unwrap_or_else(|_| Value::String(default.to_string()))),
+        _ => Some(Value::String(default.to_string())),
+    }
+}
+
+/// Valor obligatorio ausente (tras aplicar el valor por defecto).
+pub(crate) fn check_required(attribute: &AttributeDto, value: Option<&Value>) -> Option<FieldError> {
+    let missing = value.map(Value::is_null).unwrap_or(true);
+    if attribute.is_required && missing {
+        return Some(FieldError::new(&attribute.name, REQUIRED, format!("El atributo '{}' es obligatorio", attribute.name)));
+    }
+    None
+}
+
+/// Comprueba el valor (en su representación de almacenamiento) contra `validation_regex`.
+/// La expresión debe coincidir con el valor completo.
+pub(crate) fn check_pattern(attribute: &AttributeDto, text: &str) -> Option<FieldError> {
+    let pattern = attribute.validation_regex.as_deref().filter(|p| !p.is_empty())?;
+    match Regex::new(&format!("^(?:{})$", pattern)) {
+        Ok(regex) if regex.is_match(text) => None,
+        Ok(_) => Some(FieldError::new(
+            &attribute.name,
+            PATTERN,
+            format!("El valor '{}' no cumple el formato requerido ({})", text, pattern),
+        )),
+        Err(e) => Some(FieldError::new(
+            &attribute.name,
+            PATTERN,
+            format!("La expresión de validación del atributo no es válida: {}", e),
+        )),
+    }
+}
+
+/// Comprueba el valor (en su representación de almacenamiento) contra los parámetros
+/// del tipo de dato: longitud máxima, precisión/escala y mínimo/máximo.
+pub(crate) fn check_type_params(attribute: &AttributeDto, text: &str) -> Option<FieldError> {
+    attribute.storage.check_value(text).err().map(|e| {
+        let message = match e {
+            DomainError::ValidationError(message) => message,
+            other => other.to_string(),
+        };
+        FieldError::new(&attribute.name, TYPE_CONSTRAINT, message)
+    })
+}
+
+/// En los atributos de tipo enumeración, el valor debe ser el código de una opción activa.
+pub(crate) fn check_option(attribute: &AttributeDto, text: &str) -> Option<FieldError> {
+    if !attribute.storage.is_enumeration() {
+        return None;
+    }
+    check_option_code(&attribute.options, text).err().map(|e| {
+        let message = match e {
+            DomainError::ValidationError(message) => message,
+            other => other.to_string(),
+        };
+        FieldError::new(&attribute.name, INVALID_OPTION, message)
+    })
+}
+
+/// Errores de unicidad para todos los atributos que forman la clave de un grupo.
+pub(crate) fn unique_violation(group: i16, fields: &[String]) -> Vec<FieldError> {
+    let message = if fields.len() == 1 {
+        format!("Ya existe un registro con el mismo valor de '{}'", fields[0])
+    } else {
+        format!("Ya existe un registro con la misma combinación de ({}) (grupo de unicidad {})", fields.join(", "), group)
+    };
+    fields.iter().map(|field| FieldError::new(field, UNIQUE, message.clone())).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use crate::Domain::attribute_options::AttributeOption;
+    use crate::Domain::data_types::DataTypeKind;
+
+    #[test]
+    fn test_default_value_for_json_is_parsed() {
+        let mut attr = AttributeDto::for_tests("config", StorageColumn::Json);
+        attr.default_value = Some("{\"a\": 1}".to_string());
+        assert_eq!(default_value_for(&attr, StorageColumn::Json), Some(json!({"a": 1})));
+
+        let mut attr = AttributeDto::for_tests("qty", StorageColumn::Integer);
+        attr.default_value = Some("0".to_string());
+        assert_eq!(default_value_for(&attr, StorageColumn::Integer), Some(json!("0")));
+    }
+
+    #[test]
+    fn test_check_required() {
+        let mut attr = AttributeDto::for_tests("code", StorageColumn::String);
+        attr.is_required = true;
+        assert_eq!(check_required(&attr, None).map(|e| e.code), Some(REQUIRED.to_string()));
+        assert!(check_required(&attr, Some(&Value::Null)).is_some());
+        assert!(check_required(&attr, Some(&json!("A1"))).is_none());
+    }
+
+    #[test]
+    fn test_check_pattern_matches_whole_value() {
+        let mut attr = AttributeDto::for_tests("code", StorageColumn::String);
+        attr.validation_regex = Some("[A-Z]{2}[0-9]+".to_string());
+        assert!(check_pattern(&attr, "AB12").is_none());
+        assert!(check_pattern(&attr, "xAB12").is_some());
+        assert!(check_pattern(&attr, "AB12x").is_some());
+    }
+
+    #[test]
+    fn test_check_type_params_uses_data_type_registry() {
+        let mut attr = AttributeDto::for_tests("code", StorageColumn::String);
+        attr.storage.params.max_length = Some(4);
+        assert!(check_type_params(&attr, "AB12").is_none());
+        assert_eq!(check_type_params(&attr, "AB123").map(|e| e.code), Some(TYPE_CONSTRAINT.to_string()));
+    }
+
+    #[test]
+    fn test_check_option_only_applies_to_enumerations() {
+        let mut attr = AttributeDto::for_tests("status", StorageColumn::String);
+        assert!(check_option(&attr, "anything").is_none());
+
+        attr.storage = attr.storage.clone().with_kind(DataTypeKind::Enumeration).unwrap();
+        attr.options = vec![
+            AttributeOption { code: "open".to_string(), label: "Open".to_string(), sort_order: 0, is_active: true },
+            AttributeOption { code: "legacy".to_string(), label: "Legacy".to_string(), sort_order: 1, is_active: false },
+        ];
+        assert!(check_option(&attr, "open").is_none());
+        assert_eq!(check_option(&attr, "legacy").map(|e| e.code), Some(INVALID_OPTION.to_string()));
+        assert!(check_option(&attr, "closed").is_some());
+    }
+}
@@ -0,0 +1,93 @@
+// src/Application/use_cases/records/record_history.rs
+//
+// Escritura del historial de los registros. Se ejecuta dentro de la UoW del cambio,
+// de modo que el historial se confirma (o se descarta) junto con él.
+//
+// Cada cambio registra un evento de la tupla (salvo las modificaciones) y una entrada
+// por atributo cuyo valor cambia. Al mover un registro a la papelera se registra la
+// pérdida de todos sus valores y al restaurarlo su recuperación, de modo que el estado
+// de un registro en cualquier fecha se puede reconstruir deshaciendo las entradas
+// posteriores (ver `Domain::record_history::rewind`).
+
+use std::collections::HashMap;
+use diesel_async::AsyncPgConnection;
+use serde_json::Value;
+use uuid::Uuid;
+use anyhow::anyhow;
+
+use crate::Domain::record_history::ChangeContext;
+use crate::Application::ports::driven::repositories::{
+    RecordCommandRepository,
+    TupleAttributeValue,
+    NewRecordHistoryDto,
+};
+use crate::Domain::record_history::{diff_values, RecordChangeKind};
+
+/// Valores almacenados de una tupla, leídos dentro de la transacción.
+pub(crate) async fn load_values(
+    record_command_repository: &dyn RecordCommandRepository,
+    conn: &mut AsyncPgConnection,
+    tuple_id: Uuid,
+) -> anyhow::Result<Vec<TupleAttributeValue>> {
+    record_command_repository
+        .find_values_by_tuple(conn, tuple_id)
+        .await
+        .map_err(|e| anyhow!("Failed to load values of tuple {}: {}", tuple_id, e))
+}
+
+/// Entradas del historial de un cambio de la tupla: el evento de la tupla (salvo en las
+/// modificaciones) y una entrada por cada atributo cuyo valor difiere entre `before` y `after`.
+pub(crate) fn history_entries(
+    context: &ChangeContext,
+    entity_id: Uuid,
+    tuple_id: Uuid,
+    kind: RecordChangeKind,
+    before: &[TupleAttributeValue],
+    after: &[TupleAttributeValue],
+) -> Vec<NewRecordHistoryDto> {
+    let entry = |attribute_id: Option<Uuid>, attribute_name: Option<String>, old_value: Option<Value>, new_value: Option<Value>| {
+        NewRecordHistoryDto {
+            entity_id,
+            tuple_id,
+            attribute_id,
+            attribute_name,
+            change_kind: kind,
+            old_value,
+            new_value,
+            changed_by: context.actor,
+            request_id: context.request_id.clone(),
+        }
+    };
+
+    let mut entries = Vec::new();
+    if kind != RecordChangeKind::Update {
+        entries.push(entry(None, None, None, None));
+    }
+
+    let names: HashMap<Uuid, &str> = before.iter().chain(after)
+        .map(|v| (v.attribute_id, v.attribute_name.as_str()))
+        .collect();
+    let by_attribute = |values: &[TupleAttributeValue]| -> HashMap<Uuid, Value> {
+        values.iter().map(|v| (v.attribute_id, v.value.clone())).collect()
+    };
+    for change in diff_values(&by_attribute(before), &by_attribute(after)) {
+        let name = names.get(&change.attribute_id).map(|n| n.to_string());
+        entries.push(entry(Some(change.attribute_id), name, change.old_value, change.new_value));
+    }
+    entries
+}
+
+/// Escribe las entradas en el historial (no hace nada si no hay entradas).
+pub(crate) async fn write_history(
+    record_command_repository: &dyn RecordCommandRepository,
+    conn: &mut AsyncPgConnection,
+    entries: Vec<NewRecordHistoryDto>,
+) -> anyhow::Result<()> {
+    if entries.is_empty() {
+        return Ok(());
+    }
+    record_command_repository
+        .insert_history(conn, &entries)
+        .await
+        .map_err(|e| anyhow!("Failed to write {} record history entries: {}", entries.len(), e))
+}
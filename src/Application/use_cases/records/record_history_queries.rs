@@ -0,0 +1,181 @@
+// src/Application/use_cases/records/record_history_queries.rs
+//
+// Consultas del historial de un registro: listado de sus cambios y reconstrucción
+// de su estado en una fecha ("as-of").
+
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+use chrono::{DateTime, Utc};
+use serde_json::Map;
+use uuid::Uuid;
+use log::info;
+
+use crate::Application::dtos::record_history_dto::{RecordHistoryPageDto, RecordAsOfDto};
+use crate::Application::errors::application_error::ApplicationError;
+use crate::Application::ports::driven::repositories::{
+    LogicalEntityQueryRepository,
+    AttributeQueryRepository,
+    RecordQueryRepository,
+};
+use crate::Domain::record_history::{rewind, RecordChange, RecordState};
+use super::find_record::MAX_PAGE_SIZE;
+use super::record_values::resolve_entity;
+
+// --- Traits de los Casos de Uso ---
+#[async_trait]
+pub trait RecordHistoryUseCase: Send + Sync {
+    /// Cambios del registro, del más reciente al más antiguo. `before` es el cursor
+    /// devuelto en la página anterior. También lista el historial de los registros purgados.
+    async fn execute(&self, entity_name: &str, id: Uuid, limit: i64, before: Option<i64>) -> Result<RecordHistoryPageDto, ApplicationError>;
+}
+
+#[async_trait]
+pub trait FindRecordAsOfUseCase: Send + Sync {
+    /// Valores del registro en la fecha `at`. NotFound si en esa fecha no existía
+    /// o estaba en la papelera.
+    async fn execute(&self, entity_name: &str, id: Uuid, at: DateTime<Utc>) -> Result<RecordAsOfDto, ApplicationError>;
+}
+
+fn infrastructure_error(e: Box<dyn std::error::Error + Send + Sync>) -> ApplicationError {
+    ApplicationError::InfrastructureError(format!("Error al consultar el historial del registro: {}", e))
+}
+
+// --- Implementación: historial ---
+pub struct RecordHistoryUseCaseImpl {
+    le_query_repository: Arc<dyn LogicalEntityQueryRepository>,
+    attribute_query_repository: Arc<dyn AttributeQueryRepository>,
+    record_query_repository: Arc<dyn RecordQueryRepository>,
+}
+
+impl RecordHistoryUseCaseImpl {
+    pub fn new(
+        le_query_repository: Arc<dyn LogicalEntityQueryRepository>,
+        attribute_query_repository: Arc<dyn AttributeQueryRepository>,
+        record_query_repository: Arc<dyn RecordQueryRepository>,
+    ) -> Self {
+        Self { le_query_repository, attribute_query_repository, record_query_repository }
+    }
+}
+
+#[async_trait]
+impl RecordHistoryUseCase for RecordHistoryUseCaseImpl {
+    async fn execute(&self, entity_name: &str, id: Uuid, limit: i64, before: Option<i64>) -> Result<RecordHistoryPageDto, ApplicationError> {
+        if limit < 1 || limit > MAX_PAGE_SIZE {
+            return Err(ApplicationError::ValidationError(format!("limit debe estar entre 1 y {}", MAX_PAGE_SIZE)));
+        }
+        info!("Ejecutando caso de uso RecordHistory: entity='{}', id='{}', limit={}, before={:?}", entity_name, id, limit, before);
+
+        let (entity, _) = resolve_entity(
+            self.le_query_repository.as_ref(),
+            self.attribute_query_repository.as_ref(),
+            entity_name,
+        ).await?;
+
+        // Se pide una entrada más para saber si hay página siguiente
+        let mut entries = self.record_query_repository
+            .find_history(entity.id, id, limit + 1, before)
+            .await
+            .map_err(infrastructure_error)?;
+        let next_before = if entries.len() as i64 > limit {
+            entries.truncate(limit as usize);
+            entries.last().map(|entry| entry.id)
+        } else {
+            None
+        };
+
+        // Sin historial, el registro debe existir (los anteriores al historial no tienen entradas)
+        if entries.is_empty() && before.is_none() {
+            let live = self.record_query_repository.find_by_id(entity.id, id).await.map_err(infrastructure_error)?;
+            let trashed = match live {
+                Some(_) => None,
+                None => self.record_query_repository.find_deleted_by_id(entity.id, id).await.map_err(infrastructure_error)?,
+            };
+            if live.is_none() && trashed.is_none() {
+                return Err(ApplicationError::NotFound(format!("Registro con ID {} no encontrado en '{}'", id, entity_name)));
+            }
+        }
+
+        Ok(RecordHistoryPageDto { entries, next_before })
+    }
+}
+
+// --- Implementación: estado en una fecha ---
+pub struct FindRecordAsOfUseCaseImpl {
+    le_query_repository: Arc<dyn LogicalEntityQueryRepository>,
+    attribute_query_repository: Arc<dyn AttributeQueryRepository>,
+    record_query_repository: Arc<dyn RecordQueryRepository>,
+}
+
+impl FindRecordAsOfUseCaseImpl {
+    pub fn new(
+        le_query_repository: Arc<dyn LogicalEntityQueryRepository>,
+        attribute_query_repository: Arc<dyn AttributeQueryRepository>,
+        record_query_repository: Arc<dyn RecordQueryRepository>,
+    ) -> Self {
+        Self { le_query_repository, attribute_query_repository, record_query_repository }
+    }
+}
+
+#[async_trait]
+impl FindRecordAsOfUseCase for FindRecordAsOfUseCaseImpl {
+    async fn execute(&self, entity_name: &str, id: Uuid, at: DateTime<Utc>) -> Result<RecordAsOfDto, ApplicationError> {
+        info!("Ejecutando caso de uso FindRecordAsOf: entity='{}', id='{}', at={}", entity_name, id, at);
+
+        let (entity, attributes) = resolve_entity(
+            self.le_query_repository.as_ref(),
+            self.attribute_query_repository.as_ref(),
+            entity_name,
+        ).await?;
+        let not_found = || ApplicationError::NotFound(format!("El registro {} no existía en '{}' en {}", id, entity_name, at));
+
+        // 1. Estado actual: los registros de la papelera o purgados no tienen valores
+        let attribute_ids: HashMap<&str, Uuid> = attributes.iter().map(|a| (a.name.as_str(), a.id)).collect();
+        let mut names: HashMap<Uuid, String> = attributes.iter().map(|a| (a.id, a.name.clone())).collect();
+        let (current, created_at) = match self.record_query_repository.find_by_id(entity.id, id).await.map_err(infrastructure_error)? {
+            Some(record) => {
+                let values = record.values.iter()
+                    .filter_map(|(name, value)| attribute_ids.get(name.as_str()).map(|attribute_id| (*attribute_id, value.clone())))
+                    .collect();
+                (RecordState { exists: true, values }, Some(record.created_at))
+            },
+            None => {
+                let trashed = self.record_query_repository.find_deleted_by_id(entity.id, id).await.map_err(infrastructure_error)?;
+                (RecordState::default(), trashed.map(|deleted| deleted.record.created_at))
+            },
+        };
+        if created_at.is_some_and(|created_at| created_at > at) {
+            return Err(not_found());
+        }
+
+        // 2. Deshacer los cambios posteriores a la fecha
+        let entries = self.record_query_repository
+            .find_history_since(entity.id, id, at)
+            .await
+            .map_err(infrastructure_error)?;
+        let changes: Vec<RecordChange> = entries.iter()
+            .map(|entry| RecordChange {
+                attribute_id: entry.attribute_id,
+                kind: entry.change_kind,
+                old_value: entry.old_value.clone(),
+                new_value: entry.new_value.clone(),
+            })
+            .collect();
+        let state = rewind(current, &changes);
+        if !state.exists {
+            return Err(not_found());
+        }
+
+        // Los atributos eliminados desde entonces conservan el nombre del historial
+        for entry in &entries {
+            if let (Some(attribute_id), Some(name)) = (entry.attribute_id, &entry.attribute_name) {
+                names.entry(attribute_id).or_insert_with(|| name.clone());
+            }
+        }
+        let values: Map<String, _> = state.values.into_iter()
+            .filter_map(|(attribute_id, value)| names.get(&attribute_id).map(|name| (name.clone(), value)))
+            .collect();
+
+        Ok(RecordAsOfDto { id, entity_id: entity.id, as_of: at, values })
+    }
+}
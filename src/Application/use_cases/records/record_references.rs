@@ -0,0 +1,302 @@
+// src/Application/use_cases/records/record_references.rs
+//
+// Integridad de los atributos de tipo referencia: al escribir un registro, las
+// tuplas referenciadas deben existir; al eliminarlo, se aplica el `on_delete`
+// de cada atributo que lo referencia (restrict, cascade o set_null).
+//
+// La eliminación de la API mueve los registros a la papelera y la purga los borra
+// definitivamente; restaurar un registro devuelve también los que se eliminaron
+// con él en cascada. Cada tupla afectada (también en cascada) se registra en el
+// historial con el mismo `ChangeContext`.
+
+use std::collections::{HashMap, HashSet};
+use diesel_async::AsyncPgConnection;
+use serde_json::{json, Map, Value};
+use uuid::Uuid;
+use log::debug;
+use anyhow::anyhow;
+
+use crate::Domain::record_history::ChangeContext;
+use crate::Application::errors::application_error::{ApplicationError, FieldError};
+use crate::Application::ports::driven::repositories::{
+    AttributeQueryRepository,
+    RecordCommandRepository,
+    ReferencingTuple,
+    AttributeDto,
+    NewRecordHistoryDto,
+};
+use crate::Domain::attribute_references::ReferenceDeleteAction;
+use crate::Domain::record_history::RecordChangeKind;
+use super::record_constraints::REFERENCE_NOT_FOUND;
+use super::record_history::{load_values, history_entries, write_history};
+
+/// Tupla referenciada por un valor que se va a escribir.
+#[derive(Debug, Clone)]
+pub(crate) struct ReferenceCheck {
+    pub field: String,
+    pub entity_id: Uuid,
+    pub tuple_id: Uuid,
+}
+
+/// Tuplas referenciadas por los valores almacenados de un registro (p. ej. al
+/// restaurarlo de la papelera).
+pub(crate) fn stored_references(attributes: &[AttributeDto], values: &Map<String, Value>) -> Vec<ReferenceCheck> {
+    attributes
+        .iter()
+        .filter_map(|attribute| {
+            let reference = attribute.reference.as_ref()?;
+            let tuple_id = values.get(&attribute.name)?.as_str().and_then(|v| Uuid::parse_str(v).ok())?;
+            Some(ReferenceCheck { field: attribute.name.clone(), entity_id: reference.entity_id, tuple_id })
+        })
+        .collect()
+}
+
+/// Comprueba, dentro de la transacción de la UoW, que las tuplas referenciadas
+/// existen en su entidad (fuera de la papelera). Quedan bloqueadas hasta el fin de la transacción para
+/// que no se eliminen antes de guardar la referencia.
+pub(crate) async fn enforce_references(
+    record_command_repository: &dyn RecordCommandRepository,
+    conn: &mut AsyncPgConnection,
+    references: &[ReferenceCheck],
+) -> anyhow::Result<()> {
+    let mut errors = Vec::new();
+    for reference in references {
+        let exists = record_command_repository
+            .lock_tuple_if_exists(conn, reference.entity_id, reference.tuple_id)
+            .await
+            .map_err(|e| anyhow!("Failed to check referenced tuple {}: {}", reference.tuple_id, e))?;
+        if !exists {
+            errors.push(FieldError::new(
+                &reference.field,
+                REFERENCE_NOT_FOUND,
+                format!("El registro referenciado {} no existe", reference.tuple_id),
+            ));
+        }
+    }
+
+    if !errors.is_empty() {
+        return Err(anyhow!(ApplicationError::FieldValidationErrors(errors)));
+    }
+    Ok(())
+}
+
+/// Forma de eliminar una tupla.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum TupleRemoval {
+    /// Mover a la papelera: `restrict` solo cuenta los registros vivos que la
+    /// referencian, `cascade` mueve también esos registros a la papelera y
+    /// `set_null` se aplaza a la purga, para que la restauración no pierda datos.
+    SoftDelete,
+    /// Borrado definitivo: `set_null` y `cascade` se aplican a todos los registros
+    /// que la referencian (también a los de la papelera); `restrict` aborta si algún
+    /// registro vivo la referencia y vacía la referencia en los de la papelera.
+    Purge,
+}
+
+/// Elimina una tupla aplicando el `on_delete` de los atributos que la referencian
+/// según `removal` (ver `TupleRemoval`); `restrict` aborta con Conflict.
+/// Debe ejecutarse dentro de la UoW: si algún paso falla, no se elimina nada.
+/// Al purgar, la tupla inicial debe estar en la papelera.
+/// Devuelve el número de tuplas eliminadas (0 si la tupla no existe o, al moverla
+/// a la papelera, si ya estaba en ella).
+pub(crate) async fn delete_tuple_with_references(
+    attribute_query_repository: &dyn AttributeQueryRepository,
+    record_command_repository: &dyn RecordCommandRepository,
+    conn: &mut AsyncPgConnection,
+    entity_id: Uuid,
+    id: Uuid,
+    removal: TupleRemoval,
+    context: &ChangeContext,
+) -> anyhow::Result<usize> {
+    // Atributos que referencian cada entidad (se consultan una sola vez)
+    let mut referencing: HashMap<Uuid, Vec<AttributeDto>> = HashMap::new();
+    // (entidad, tupla, si está fuera de la papelera)
+    let mut pending = vec![(entity_id, id, matches!(removal, TupleRemoval::SoftDelete))];
+    let mut visited = HashSet::new();
+    let mut deleted = 0;
+
+    while let Some((entity_id, id, live)) = pending.pop() {
+        // Las referencias circulares no vuelven a visitar una tupla ya eliminada
+        if !visited.insert(id) {
+            continue;
+        }
+
+        load_referencing(attribute_query_repository, &mut referencing, entity_id).await?;
+        for attribute in &referencing[&entity_id] {
+            let Some(reference) = &attribute.reference else { continue };
+            let tuples: Vec<ReferencingTuple> = record_command_repository
+                .find_tuples_referencing(conn, attribute.id, id)
+                .await
+                .map_err(|e| anyhow!("Failed to find tuples referencing {}: {}", id, e))?
+                .into_iter()
+                .filter(|t| !visited.contains(&t.id))
+                // Al mover a la papelera, los registros que ya están en ella no cuentan
+                .filter(|t| matches!(removal, TupleRemoval::Purge) || t.deleted_at.is_none())
+                .collect();
+            if tuples.is_empty() {
+                continue;
+            }
+
+            match (reference.on_delete, removal) {
+                (ReferenceDeleteAction::Restrict, _) => {
+                    let live = tuples.iter().filter(|t| t.deleted_at.is_none()).count();
+                    if live > 0 {
+                        return Err(anyhow!(ApplicationError::Conflict(format!(
+                            "El registro {} está referenciado por {} registro(s) a través del atributo '{}'",
+                            id, live, attribute.name
+                        ))));
+                    }
+                    // Purga: solo quedan registros de la papelera, que pierden la referencia
+                    clear_references(record_command_repository, conn, attribute, id, &tuples, context).await?;
+                },
+                (ReferenceDeleteAction::SetNull, TupleRemoval::SoftDelete) => {
+                    debug!("Referencia '{}' a {} se conserva en {} registros hasta la purga", attribute.name, id, tuples.len());
+                },
+                (ReferenceDeleteAction::SetNull, TupleRemoval::Purge) => {
+                    clear_references(record_command_repository, conn, attribute, id, &tuples, context).await?;
+                    debug!("Referencia '{}' a {} vaciada en {} registros", attribute.name, id, tuples.len());
+                },
+                (ReferenceDeleteAction::Cascade, _) => {
+                    debug!("Eliminando en cascada {} registros que referencian {} ('{}')", tuples.len(), id, attribute.name);
+                    pending.extend(tuples.into_iter().map(|t| (attribute.entity_id, t.id, t.deleted_at.is_none())));
+                },
+            }
+        }
+
+        // Los registros de la papelera ya no tienen valores en el historial
+        let before = if live { load_values(record_command_repository, conn, id).await? } else { Vec::new() };
+        let (affected, kind) = match removal {
+            TupleRemoval::SoftDelete => (
+                record_command_repository
+                    .soft_delete_tuple(conn, entity_id, id, context.actor).await
+                    .map_err(|e| anyhow!("Failed to move tuple {} to trash: {}", id, e))?,
+                RecordChangeKind::Delete,
+            ),
+            TupleRemoval::Purge => (
+                record_command_repository
+                    .delete_tuple(conn, entity_id, id).await
+                    .map_err(|e| anyhow!("Failed to delete tuple {}: {}", id, e))?,
+                RecordChangeKind::Purge,
+            ),
+        };
+        if affected > 0 {
+            let entries = history_entries(context, entity_id, id, kind, &before, &[]);
+            write_history(record_command_repository, conn, entries).await?;
+        }
+        deleted += affected;
+    }
+    Ok(deleted)
+}
+
+/// Saca de la papelera una tupla junto con las que se movieron a ella en cascada
+/// (las que la referencian por un atributo `cascade` con la misma fecha de eliminación).
+/// Las tuplas que referencia la tupla restaurada deben existir fuera de la papelera.
+/// Debe ejecutarse dentro de la UoW. Devuelve el número de tuplas restauradas
+/// (0 si la tupla no está en la papelera).
+pub(crate) async fn restore_tuple_with_references(
+    attribute_query_repository: &dyn AttributeQueryRepository,
+    record_command_repository: &dyn RecordCommandRepository,
+    conn: &mut AsyncPgConnection,
+    entity_id: Uuid,
+    id: Uuid,
+    references: &[ReferenceCheck],
+    context: &ChangeContext,
+) -> anyhow::Result<usize> {
+    let deleted_at = record_command_repository
+        .lock_deleted_tuple(conn, entity_id, id)
+        .await
+        .map_err(|e| anyhow!("Failed to lock deleted tuple {}: {}", id, e))?;
+    let Some(deleted_at) = deleted_at else {
+        return Ok(0);
+    };
+    enforce_references(record_command_repository, conn, references).await?;
+
+    let mut referencing: HashMap<Uuid, Vec<AttributeDto>> = HashMap::new();
+    let mut pending = vec![(entity_id, id)];
+    let mut visited = HashSet::new();
+    let mut restored = 0;
+
+    while let Some((entity_id, id)) = pending.pop() {
+        if !visited.insert(id) {
+            continue;
+        }
+
+        load_referencing(attribute_query_repository, &mut referencing, entity_id).await?;
+        for attribute in &referencing[&entity_id] {
+            let cascades = attribute.reference.as_ref()
+                .is_some_and(|r| r.on_delete == ReferenceDeleteAction::Cascade);
+            if !cascades {
+                continue;
+            }
+            let tuples = record_command_repository
+                .find_tuples_referencing(conn, attribute.id, id)
+                .await
+                .map_err(|e| anyhow!("Failed to find tuples referencing {}: {}", id, e))?;
+            pending.extend(
+                tuples.into_iter()
+                    .filter(|t| t.deleted_at == Some(deleted_at) && !visited.contains(&t.id))
+                    .map(|t| (attribute.entity_id, t.id)),
+            );
+        }
+
+        let affected = record_command_repository.restore_tuple(conn, entity_id, id, deleted_at).await
+            .map_err(|e| anyhow!("Failed to restore tuple {}: {}", id, e))?;
+        if affected > 0 {
+            let after = load_values(record_command_repository, conn, id).await?;
+            let entries = history_entries(context, entity_id, id, RecordChangeKind::Restore, &[], &after);
+            write_history(record_command_repository, conn, entries).await?;
+        }
+        restored += affected;
+    }
+    Ok(restored)
+}
+
+/// Carga (una sola vez por entidad) los atributos que referencian la entidad.
+async fn load_referencing(
+    attribute_query_repository: &dyn AttributeQueryRepository,
+    referencing: &mut HashMap<Uuid, Vec<AttributeDto>>,
+    entity_id: Uuid,
+) -> anyhow::Result<()> {
+    if !referencing.contains_key(&entity_id) {
+        let attributes = attribute_query_repository
+            .find_referencing(entity_id)
+            .await
+            .map_err(|e| anyhow!("Failed to load attributes referencing entity {}: {}", entity_id, e))?;
+        referencing.insert(entity_id, attributes);
+    }
+    Ok(())
+}
+
+/// Borra el valor de la referencia (a `referenced_id`) en las tuplas indicadas.
+/// En el historial solo cambian los registros vivos: los de la papelera ya no tienen valores.
+async fn clear_references(
+    record_command_repository: &dyn RecordCommandRepository,
+    conn: &mut AsyncPgConnection,
+    attribute: &AttributeDto,
+    referenced_id: Uuid,
+    tuples: &[ReferencingTuple],
+    context: &ChangeContext,
+) -> anyhow::Result<()> {
+    let mut entries = Vec::new();
+    for tuple in tuples {
+        record_command_repository.delete_value(conn, tuple.id, attribute.id).await
+            .map_err(|e| anyhow!("Failed to clear reference of attribute {} in tuple {}: {}", attribute.id, tuple.id, e))?;
+        if tuple.deleted_at.is_none() {
+            // El registro cambia: nueva versión (su ETag deja de valer)
+            record_command_repository.touch_tuple(conn, attribute.entity_id, tuple.id, context.actor).await
+                .map_err(|e| anyhow!("Failed to update tuple {}: {}", tuple.id, e))?;
+            entries.push(NewRecordHistoryDto {
+                entity_id: attribute.entity_id,
+                tuple_id: tuple.id,
+                attribute_id: Some(attribute.id),
+                attribute_name: Some(attribute.name.clone()),
+                change_kind: RecordChangeKind::Update,
+                old_value: Some(json!(referenced_id.to_string())),
+                new_value: None,
+                changed_by: context.actor,
+                request_id: context.request_id.clone(),
+            });
+        }
+    }
+    write_history(record_command_repository, conn, entries).await
+}
@@ -0,0 +1,259 @@
+// src/Application/use_cases/records/record_values.rs
+
+use std::collections::{BTreeMap, HashSet};
+use diesel_async::AsyncPgConnection;
+use serde_json::{Map, Value};
+use uuid::Uuid;
+use log::{debug, error};
+use anyhow::anyhow;
+
+use crate::Application::dtos::record_dto::RecordUpdateMode;
+use crate::Application::errors::application_error::{ApplicationError, FieldError};
+use crate::Application::ports::driven::repositories::{
+    LogicalEntityQueryRepository,
+    AttributeQueryRepository,
+    RecordCommandRepository,
+    AttributeValueMatch,
+    AttributeValueWrite,
+    LogicalEntityDto,
+    AttributeDto,
+};
+use crate::Domain::records::to_storage_text;
+use crate::Domain::concurrency::VersionPrecondition;
+use super::record_references::ReferenceCheck;
+use super::record_constraints::{
+    default_value_for, check_required, check_pattern, check_type_params, check_option, unique_violation,
+    UNKNOWN_ATTRIBUTE, INVALID_TYPE,
+};
+
+/// Resuelve una entidad por nombre junto con sus atributos activos.
+/// Compartido por todos los casos de uso de registros.
+pub(crate) async fn resolve_entity(
+    le_query_repository: &dyn LogicalEntityQueryRepository,
+    attribute_query_repository: &dyn AttributeQueryRepository,
+    entity_name: &str,
+) -> Result<(LogicalEntityDto, Vec<AttributeDto>), ApplicationError> {
+    let entity = le_query_repository
+        .find_by_name(entity_name)
+        .await
+        .map_err(|e| ApplicationError::InfrastructureError(format!("Error al buscar entidad lógica: {}", e)))?
+        .ok_or_else(|| ApplicationError::NotFound(format!("Entidad lógica '{}' no encontrada", entity_name)))?;
+
+    debug!("Cargando atributos de la entidad '{}' ({})", entity.name, entity.id);
+    let mut attributes = attribute_query_repository
+        .find_by_entity_id(entity.id)
+        .await
+        .map_err(|e| {
+            error!("Error al consultar atributos de la entidad {}: {}", entity.id, e);
+            ApplicationError::InfrastructureError(format!("Error al consultar atributos: {}", e))
+        })?;
+    attributes.retain(|a| a.status == 1);
+
+    Ok((entity, attributes))
+}
+
+/// Clave compuesta de un grupo de unicidad con los valores finales del registro.
+#[derive(Debug, Clone)]
+pub(crate) struct UniqueKey {
+    pub group: i16,
+    pub fields: Vec<String>,
+    pub matches: Vec<AttributeValueMatch>,
+}
+
+/// Resultado de validar un registro: escrituras a aplicar, claves únicas y
+/// tuplas referenciadas a comprobar.
+#[derive(Debug, Clone)]
+pub(crate) struct PreparedRecord {
+    pub writes: Vec<AttributeValueWrite>,
+    pub unique_keys: Vec<UniqueKey>,
+    pub references: Vec<ReferenceCheck>,
+}
+
+/// Traduce el objeto JSON recibido (clave = nombre de atributo) a escrituras tipadas,
+/// aplicando las reglas de los atributos: valores por defecto, obligatoriedad,
+/// `validation_regex`, claves de los grupos de unicidad y tuplas referenciadas.
+///
+/// - `Replace` (alta y PUT): los atributos ausentes o `null` toman su valor por defecto.
+/// - `Merge` (PATCH): solo se escriben los atributos presentes (`null` elimina el valor);
+///   el resto conserva el valor de `existing` para calcular las claves únicas.
+///
+/// Todas las violaciones se devuelven juntas como `FieldValidationErrors`.
+pub(crate) fn prepare_record_values(
+    attributes: &[AttributeDto],
+    values: &Map<String, Value>,
+    existing: Option<&Map<String, Value>>,
+    mode: RecordUpdateMode,
+) -> Result<PreparedRecord, ApplicationError> {
+    let mut errors: Vec<FieldError> = Vec::new();
+
+    let known: HashSet<&str> = attributes.iter().map(|a| a.name.as_str()).collect();
+    let mut unknown: Vec<&String> = values.keys().filter(|k| !known.contains(k.as_str())).collect();
+    unknown.sort();
+    for key in unknown {
+        errors.push(FieldError::new(key, UNKNOWN_ATTRIBUTE, format!("El atributo '{}' no existe en la entidad", key)));
+    }
+
+    let mut writes = Vec::with_capacity(attributes.len());
+    let mut references = Vec::new();
+    // grupo -> (atributos de la clave, valores finales, ¿se modifica en esta operación?)
+    let mut groups: BTreeMap<i16, (Vec<String>, Vec<Option<AttributeValueMatch>>, bool)> = BTreeMap::new();
+
+    for attribute in attributes {
+        // Columna de almacenamiento según el registro de tipos de datos
+        let column = attribute.storage.column;
+
+        // Valor efectivo del atributo y si debe escribirse
+        let (write, value) = match values.get(&attribute.name) {
+            Some(value) if !value.is_null() => (true, Some(value.clone())),
+            // null explícito en PATCH: elimina el valor
+            Some(_) if mode == RecordUpdateMode::Merge => (true, None),
+            None if mode == RecordUpdateMode::Merge => {
+                (false, existing.and_then(|e| e.get(&attribute.name)).filter(|v| !v.is_null()).cloned())
+            },
+            _ => (true, default_value_for(attribute, column)),
+        };
+
+        if write {
+            if let Some(error) = check_required(attribute, value.as_ref()) {
+                errors.push(error);
+                continue;
+            }
+        }
+
+        let text = match value.as_ref().map(|v| to_storage_text(column, v)).transpose() {
+            Ok(text) => text.flatten(),
+            Err(e) => {
+                errors.push(FieldError::new(&attribute.name, INVALID_TYPE, e.to_string()));
+                continue;
+            }
+        };
+
+        if write {
+            if let Some(error) = text.as_deref().and_then(|t| check_type_params(attribute, t)) {
+                errors.push(error);
+                continue;
+            }
+            if let Some(error) = text.as_deref().and_then(|t| check_option(attribute, t)) {
+                errors.push(error);
+                continue;
+            }
+            if let Some(error) = text.as_deref().and_then(|t| check_pattern(attribute, t)) {
+                errors.push(error);
+                continue;
+            }
+            // Los valores de una referencia ya son UUID válidos (columna uuid_value)
+            if let (Some(reference), Some(tuple_id)) = (&attribute.reference, text.as_deref().and_then(|t| Uuid::parse_str(t).ok())) {
+                references.push(ReferenceCheck { field: attribute.name.clone(), entity_id: reference.entity_id, tuple_id });
+            }
+            writes.push(AttributeValueWrite { attribute_id: attribute.id, column, value: text.clone() });
+        }
+
+        // is_unique NULL o 0 = sin restricción; 1-10 = grupo de unicidad (UniquenessGroup)
+        if let Some(group) = attribute.is_unique.filter(|g| (1..=10).contains(g)) {
+            let entry = groups.entry(group).or_insert_with(|| (Vec::new(), Vec::new(), false));
+            entry.0.push(attribute.name.clone());
+            entry.1.push(text.map(|value| AttributeValueMatch { attribute_id: attribute.id, column, value }));
+            entry.2 |= write;
+        }
+    }
+
+    if !errors.is_empty() {
+        return Err(ApplicationError::FieldValidationErrors(errors));
+    }
+
+    // Solo se comprueban los grupos modificados y completos (NULL no participa en la unicidad)
+    let unique_keys = groups
+        .into_iter()
+        .filter(|(_, (_, _, touched))| *touched)
+        .filter_map(|(group, (fields, matches, _))| {
+            let matches: Option<Vec<AttributeValueMatch>> = matches.into_iter().collect();
+            matches.map(|matches| UniqueKey { group, fields, matches })
+        })
+        .collect();
+
+    Ok(PreparedRecord { writes, unique_keys, references })
+}
+
+/// Comprueba los grupos de unicidad dentro de la transacción de la UoW.
+/// Cada grupo se bloquea antes de consultar para evitar duplicados concurrentes.
+pub(crate) async fn enforce_unique_keys(
+    record_command_repository: &dyn RecordCommandRepository,
+    conn: &mut AsyncPgConnection,
+    entity_id: Uuid,
+    exclude_id: Option<Uuid>,
+    unique_keys: &[UniqueKey],
+) -> anyhow::Result<()> {
+    let mut errors = Vec::new();
+    for key in unique_keys {
+        record_command_repository
+            .lock_uniqueness_group(conn, entity_id, key.group)
+            .await
+            .map_err(|e| anyhow!("Failed to lock uniqueness group {}: {}", key.group, e))?;
+        let duplicated = record_command_repository
+            .exists_with_values(conn, entity_id, exclude_id, &key.matches)
+            .await
+            .map_err(|e| anyhow!("Failed to check uniqueness group {}: {}", key.group, e))?;
+        if duplicated {
+            errors.extend(unique_violation(key.group, &key.fields));
+        }
+    }
+
+    if !errors.is_empty() {
+        return Err(anyhow!(ApplicationError::FieldValidationErrors(errors)));
+    }
+    Ok(())
+}
+
+/// Aplica las escrituras sobre una tupla dentro de la transacción de la UoW.
+pub(crate) async fn apply_value_writes(
+    record_command_repository: &dyn RecordCommandRepository,
+    conn: &mut AsyncPgConnection,
+    entity_id: Uuid,
+    instance_id: Uuid,
+    writes: &[AttributeValueWrite],
+) -> anyhow::Result<()> {
+    record_command_repository
+        .write_values(conn, entity_id, instance_id, writes)
+        .await
+        .map_err(|e| anyhow!("Failed to store values of tuple {}: {}", instance_id, e))
+}
+
+/// Error de If-Match no cumplido para un registro.
+pub(crate) fn record_precondition_failed(id: Uuid, current: i32) -> ApplicationError {
+    ApplicationError::PreconditionFailed(format!("El registro {} ha sido modificado (versión actual {})", id, current))
+}
+
+/// Bloquea la tupla y comprueba su versión dentro de la UoW. `read_version` es la versión
+/// leída antes de la transacción: si cambió, otra petición modificó el registro entretanto.
+pub(crate) async fn check_record_version(
+    record_command_repository: &dyn RecordCommandRepository,
+    conn: &mut AsyncPgConnection,
+    entity_id: Uuid,
+    id: Uuid,
+    precondition: Option<&VersionPrecondition>,
+    read_version: Option<i32>,
+) -> anyhow::Result<()> {
+    let current = record_command_repository.lock_tuple_version(conn, entity_id, id).await
+        .map_err(|e| anyhow!("Failed to lock tuple {}: {}", id, e))?
+        .ok_or_else(|| anyhow!(ApplicationError::NotFound(format!("Registro con ID {} no encontrado", id))))?;
+    if precondition.is_some_and(|precondition| !precondition.is_met(current)) {
+        return Err(anyhow!(record_precondition_failed(id, current)));
+    }
+    if read_version.is_some_and(|read_version| read_version != current) {
+        return Err(anyhow!(ApplicationError::Conflict(format!(
+            "El registro {} ha sido modificado por otra petición; vuelva a intentarlo", id
+        ))));
+    }
+    Ok(())
+}
+
+/// Convierte el error de la UoW en ApplicationError (conservando los errores de aplicación).
+pub(crate) fn map_uow_error(e: anyhow::Error, context: &str) -> ApplicationError {
+    match e.downcast::<ApplicationError>() {
+        Ok(app_err) => app_err,
+        Err(other) => {
+            error!("Error durante la Unidad de Trabajo ({}): {:?}", context, other);
+            ApplicationError::InfrastructureError(format!("Error en transacción ({}): {}", context, other))
+        }
+    }
+}
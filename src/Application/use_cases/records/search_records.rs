@@ -0,0 +1,87 @@
+// src/Application/use_cases/records/search_records.rs
+
+use async_trait::async_trait;
+use std::sync::Arc;
+use log::{debug, info};
+
+use crate::Application::dtos::record_dto::RecordSearchPageDto;
+use crate::Application::errors::application_error::ApplicationError;
+use crate::Application::ports::driven::repositories::{
+    LogicalEntityQueryRepository,
+    AttributeQueryRepository,
+    RecordQueryRepository,
+};
+use crate::Domain::errors::DomainError;
+use crate::Domain::record_queries::{RecordCursor, RecordSearch};
+use super::find_record::MAX_PAGE_SIZE;
+use super::record_values::resolve_entity;
+
+#[async_trait]
+pub trait SearchRecordsUseCase: Send + Sync {
+    /// Busca registros por texto en los atributos buscables de la entidad, por relevancia.
+    /// `after` es el `next_cursor` de la página anterior.
+    async fn execute(&self, entity_name: &str, text: &str, limit: i64, after: Option<&str>) -> Result<RecordSearchPageDto, ApplicationError>;
+}
+
+pub struct SearchRecordsUseCaseImpl {
+    le_query_repository: Arc<dyn LogicalEntityQueryRepository>,
+    attribute_query_repository: Arc<dyn AttributeQueryRepository>,
+    record_query_repository: Arc<dyn RecordQueryRepository>,
+}
+
+impl SearchRecordsUseCaseImpl {
+    pub fn new(
+        le_query_repository: Arc<dyn LogicalEntityQueryRepository>,
+        attribute_query_repository: Arc<dyn AttributeQueryRepository>,
+        record_query_repository: Arc<dyn RecordQueryRepository>,
+    ) -> Self {
+        Self { le_query_repository, attribute_query_repository, record_query_repository }
+    }
+}
+
+#[async_trait]
+impl SearchRecordsUseCase for SearchRecordsUseCaseImpl {
+    async fn execute(&self, entity_name: &str, text: &str, limit: i64, after: Option<&str>) -> Result<RecordSearchPageDto, ApplicationError> {
+        info!("Ejecutando caso de uso SearchRecords: entity='{}', q='{}', limit={}", entity_name, text, limit);
+
+        if limit < 1 || limit > MAX_PAGE_SIZE {
+            return Err(ApplicationError::ValidationError(format!("limit debe estar entre 1 y {}", MAX_PAGE_SIZE)));
+        }
+        let mut search = RecordSearch::new(text, limit, after).map_err(|e| match e {
+            DomainError::ValidationError(message) => ApplicationError::ValidationError(message),
+            other => ApplicationError::ValidationError(other.to_string()),
+        })?;
+
+        let (entity, attributes) = resolve_entity(
+            self.le_query_repository.as_ref(),
+            self.attribute_query_repository.as_ref(),
+            entity_name,
+        ).await?;
+        if !attributes.iter().any(|a| a.is_searchable) {
+            return Err(ApplicationError::ValidationError(format!(
+                "La entidad '{}' no tiene atributos buscables (is_searchable)", entity_name
+            )));
+        }
+        debug!("Buscando en '{}' con la configuración '{}'", entity.name, entity.search_language);
+
+        // Se pide un resultado de más para saber si hay página siguiente
+        search.limit = limit + 1;
+        let mut hits = self.record_query_repository
+            .search(entity.id, &search)
+            .await
+            .map_err(|e| ApplicationError::InfrastructureError(format!("Error al buscar registros: {}", e)))?;
+
+        let next_cursor = if hits.len() as i64 > limit {
+            hits.truncate(limit as usize);
+            hits.last().map(|last| RecordCursor {
+                sort: RecordSearch::signature(&search.text),
+                values: vec![Some(last.rank.to_string())],
+                id: last.record.id,
+            }.encode())
+        } else {
+            None
+        };
+
+        Ok(RecordSearchPageDto { items: hits, limit, next_cursor })
+    }
+}
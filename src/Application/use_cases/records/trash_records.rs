@@ -0,0 +1,220 @@
+// src/Application/use_cases/records/trash_records.rs
+//
+// Papelera de registros: listado, restauración y purga de los registros eliminados.
+
+use async_trait::async_trait;
+use std::sync::Arc;
+use uuid::Uuid;
+use log::info;
+use anyhow::anyhow;
+
+use crate::Application::dtos::trash_dto::TrashPageDto;
+use crate::Domain::record_history::ChangeContext;
+use crate::Application::errors::application_error::ApplicationError;
+use crate::Application::ports::unit_of_work::{UnitOfWork, RepositoryRegistry};
+use diesel_async::scoped_futures::ScopedFutureExt;
+use crate::Application::ports::driven::repositories::{
+    LogicalEntityQueryRepository,
+    AttributeQueryRepository,
+    RecordQueryRepository,
+    DeletedRecordDto,
+    RecordDto,
+};
+use super::find_record::MAX_PAGE_SIZE;
+use crate::Application::use_cases::logical_entities::list_logical_entities::page_offset;
+use super::record_values::{resolve_entity, map_uow_error};
+use super::record_references::{
+    delete_tuple_with_references, restore_tuple_with_references, stored_references, TupleRemoval,
+};
+
+// --- Traits de los Casos de Uso ---
+#[async_trait]
+pub trait ListDeletedRecordsUseCase: Send + Sync {
+    /// `page` empieza en 1.
+    async fn execute(&self, entity_name: &str, page: i64, page_size: i64) -> Result<TrashPageDto<DeletedRecordDto>, ApplicationError>;
+}
+
+#[async_trait]
+pub trait RestoreRecordUseCase: Send + Sync {
+    /// Saca el registro de la papelera (con los eliminados en cascada junto a él)
+    /// y lo devuelve.
+    async fn execute(&self, entity_name: &str, id: Uuid, context: ChangeContext) -> Result<RecordDto, ApplicationError>;
+}
+
+#[async_trait]
+pub trait PurgeRecordUseCase: Send + Sync {
+    /// Elimina definitivamente un registro de la papelera. Devuelve el número de
+    /// registros eliminados (incluidos los eliminados en cascada).
+    async fn execute(&self, entity_name: &str, id: Uuid, context: ChangeContext) -> Result<usize, ApplicationError>;
+}
+
+/// Purga un registro de la papelera en su propia transacción. Compartido con la
+/// purga por antigüedad. Devuelve 0 si el registro ya no está en la papelera.
+pub(crate) async fn purge_record(
+    uow: &dyn UnitOfWork,
+    entity_id: Uuid,
+    id: Uuid,
+    context: ChangeContext,
+) -> Result<usize, ApplicationError> {
+    uow.execute(move |registry: &mut dyn RepositoryRegistry| async move {
+        let attribute_query_repo = registry.attribute_query_repository();
+        let record_repo = registry.record_command_repository();
+        let conn = registry.get_diesel_async_conn();
+
+        // Solo se purgan registros de la papelera
+        let deleted_at = record_repo.lock_deleted_tuple(conn, entity_id, id).await
+            .map_err(|e| anyhow!("Failed to lock deleted tuple {}: {}", id, e))?;
+        if deleted_at.is_none() {
+            return Ok(0);
+        }
+        delete_tuple_with_references(attribute_query_repo.as_ref(), record_repo, conn, entity_id, id, TupleRemoval::Purge, &context).await
+    }.scope_boxed()).await.map_err(|e| map_uow_error(e, "purgar registro"))
+}
+
+// --- Implementación: listado de la papelera ---
+pub struct ListDeletedRecordsUseCaseImpl {
+    le_query_repository: Arc<dyn LogicalEntityQueryRepository>,
+    attribute_query_repository: Arc<dyn AttributeQueryRepository>,
+    record_query_repository: Arc<dyn RecordQueryRepository>,
+}
+
+impl ListDeletedRecordsUseCaseImpl {
+    pub fn new(
+        le_query_repository: Arc<dyn LogicalEntityQueryRepository>,
+        attribute_query_repository: Arc<dyn AttributeQueryRepository>,
+        record_query_repository: Arc<dyn RecordQueryRepository>,
+    ) -> Self {
+        Self { le_query_repository, attribute_query_repository, record_query_repository }
+    }
+}
+
+#[async_trait]
+impl ListDeletedRecordsUseCase for ListDeletedRecordsUseCaseImpl {
+    async fn execute(&self, entity_name: &str, page: i64, page_size: i64) -> Result<TrashPageDto<DeletedRecordDto>, ApplicationError> {
+        if page < 1 {
+            return Err(ApplicationError::ValidationError("page debe ser mayor o igual a 1".to_string()));
+        }
+        if page_size < 1 || page_size > MAX_PAGE_SIZE {
+            return Err(ApplicationError::ValidationError(format!("page_size debe estar entre 1 y {}", MAX_PAGE_SIZE)));
+        }
+        info!("Ejecutando caso de uso ListDeletedRecords: entity='{}', page={}, page_size={}", entity_name, page, page_size);
+
+        let (entity, _) = resolve_entity(
+            self.le_query_repository.as_ref(),
+            self.attribute_query_repository.as_ref(),
+            entity_name,
+        ).await?;
+
+        let total = self.record_query_repository
+            .count_deleted(entity.id)
+            .await
+            .map_err(|e| ApplicationError::InfrastructureError(format!("Error al contar la papelera: {}", e)))?;
+
+        let items = self.record_query_repository
+            .find_deleted(entity.id, page_size, page_offset(page, page_size)?)
+            .await
+            .map_err(|e| ApplicationError::InfrastructureError(format!("Error al listar la papelera: {}", e)))?;
+
+        Ok(TrashPageDto { items, page, page_size, total })
+    }
+}
+
+// --- Implementación: restauración ---
+pub struct RestoreRecordUseCaseImpl {
+    le_query_repository: Arc<dyn LogicalEntityQueryRepository>,
+    attribute_query_repository: Arc<dyn AttributeQueryRepository>,
+    record_query_repository: Arc<dyn RecordQueryRepository>,
+    uow: Arc<dyn UnitOfWork>,
+}
+
+impl RestoreRecordUseCaseImpl {
+    pub fn new(
+        le_query_repository: Arc<dyn LogicalEntityQueryRepository>,
+        attribute_query_repository: Arc<dyn AttributeQueryRepository>,
+        record_query_repository: Arc<dyn RecordQueryRepository>,
+        uow: Arc<dyn UnitOfWork>,
+    ) -> Self {
+        Self { le_query_repository, attribute_query_repository, record_query_repository, uow }
+    }
+}
+
+#[async_trait]
+impl RestoreRecordUseCase for RestoreRecordUseCaseImpl {
+    async fn execute(&self, entity_name: &str, id: Uuid, context: ChangeContext) -> Result<RecordDto, ApplicationError> {
+        info!("Ejecutando caso de uso RestoreRecord: entity='{}', id='{}'", entity_name, id);
+
+        let (entity, attributes) = resolve_entity(
+            self.le_query_repository.as_ref(),
+            self.attribute_query_repository.as_ref(),
+            entity_name,
+        ).await?;
+
+        let not_found = || ApplicationError::NotFound(format!("Registro con ID {} no encontrado en la papelera de '{}'", id, entity_name));
+        let deleted = self.record_query_repository
+            .find_deleted_by_id(entity.id, id)
+            .await
+            .map_err(|e| ApplicationError::InfrastructureError(format!("Error al buscar registro: {}", e)))?
+            .ok_or_else(not_found)?;
+
+        // Los registros a los que apunta deben seguir existiendo fuera de la papelera
+        let references = stored_references(&attributes, &deleted.record.values);
+        let entity_id = entity.id;
+        let restored = self.uow.execute(move |registry: &mut dyn RepositoryRegistry| async move {
+            let attribute_query_repo = registry.attribute_query_repository();
+            let record_repo = registry.record_command_repository();
+            let conn = registry.get_diesel_async_conn();
+
+            let restored = restore_tuple_with_references(attribute_query_repo.as_ref(), record_repo, conn, entity_id, id, &references, &context).await?;
+            if restored == 0 {
+                return Err(anyhow!(ApplicationError::NotFound(format!("Registro con ID {} no encontrado en la papelera", id))));
+            }
+            Ok(restored)
+        }.scope_boxed()).await.map_err(|e| map_uow_error(e, "restaurar registro"))?;
+
+        info!("Registro {} de la entidad '{}' restaurado ({} registros en total)", id, entity_name, restored);
+
+        self.record_query_repository
+            .find_by_id(entity.id, id)
+            .await
+            .map_err(|e| ApplicationError::InfrastructureError(format!("Error al buscar registro: {}", e)))?
+            .ok_or_else(not_found)
+    }
+}
+
+// --- Implementación: purga de un registro ---
+pub struct PurgeRecordUseCaseImpl {
+    le_query_repository: Arc<dyn LogicalEntityQueryRepository>,
+    attribute_query_repository: Arc<dyn AttributeQueryRepository>,
+    uow: Arc<dyn UnitOfWork>,
+}
+
+impl PurgeRecordUseCaseImpl {
+    pub fn new(
+        le_query_repository: Arc<dyn LogicalEntityQueryRepository>,
+        attribute_query_repository: Arc<dyn AttributeQueryRepository>,
+        uow: Arc<dyn UnitOfWork>,
+    ) -> Self {
+        Self { le_query_repository, attribute_query_repository, uow }
+    }
+}
+
+#[async_trait]
+impl PurgeRecordUseCase for PurgeRecordUseCaseImpl {
+    async fn execute(&self, entity_name: &str, id: Uuid, context: ChangeContext) -> Result<usize, ApplicationError> {
+        info!("Ejecutando caso de uso PurgeRecord: entity='{}', id='{}'", entity_name, id);
+
+        let (entity, _) = resolve_entity(
+            self.le_query_repository.as_ref(),
+            self.attribute_query_repository.as_ref(),
+            entity_name,
+        ).await?;
+
+        let purged = purge_record(self.uow.as_ref(), entity.id, id, context).await?;
+        if purged == 0 {
+            return Err(ApplicationError::NotFound(format!("Registro con ID {} no encontrado en la papelera de '{}'", id, entity_name)));
+        }
+
+        info!("Registro {} de la entidad '{}' purgado ({} registros en total)", id, entity_name, purged);
+        Ok(purged)
+    }
+}
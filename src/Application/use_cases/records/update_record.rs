@@ -0,0 +1,194 @@
+// src/Application/use_cases/records/update_record.rs
+
+use async_trait::async_trait;
+use std::sync::Arc;
+use serde_json::{Map, Value};
+use uuid::Uuid;
+use log::info;
+use anyhow::anyhow;
+
+use crate::Application::dtos::record_dto::RecordUpdateMode;
+use crate::Domain::record_history::ChangeContext;
+use crate::Domain::concurrency::VersionPrecondition;
+use crate::Application::errors::application_error::ApplicationError;
+use crate::Application::ports::unit_of_work::{UnitOfWork, RepositoryRegistry};
+use diesel_async::scoped_futures::ScopedFutureExt;
+use crate::Application::ports::driven::repositories::{
+    LogicalEntityQueryRepository,
+    AttributeQueryRepository,
+    RecordQueryRepository,
+    RecordDto,
+};
+use super::record_values::{
+    resolve_entity, prepare_record_values, enforce_unique_keys, apply_value_writes, map_uow_error,
+    check_record_version, record_precondition_failed,
+};
+use super::record_references::enforce_references;
+use super::record_history::{load_values, history_entries, write_history};
+use crate::Domain::record_history::RecordChangeKind;
+
+#[async_trait]
+pub trait UpdateRecordUseCase: Send + Sync {
+    async fn execute(
+        &self,
+        entity_name: &str,
+        id: Uuid,
+        values: Map<String, Value>,
+        mode: RecordUpdateMode,
+        context: ChangeContext,
+        precondition: Option<VersionPrecondition>,
+    ) -> Result<RecordDto, ApplicationError>;
+}
+
+pub struct UpdateRecordUseCaseImpl {
+    le_query_repository: Arc<dyn LogicalEntityQueryRepository>,
+    attribute_query_repository: Arc<dyn AttributeQueryRepository>,
+    record_query_repository: Arc<dyn RecordQueryRepository>,
+    uow: Arc<dyn UnitOfWork>,
+}
+
+impl UpdateRecordUseCaseImpl {
+    pub fn new(
+        le_query_repository: Arc<dyn LogicalEntityQueryRepository>,
+        attribute_query_repository: Arc<dyn AttributeQueryRepository>,
+        record_query_repository: Arc<dyn RecordQueryRepository>,
+        uow: Arc<dyn UnitOfWork>,
+    ) -> Self {
+        Self { le_query_repository, attribute_query_repository, record_query_repository, uow }
+    }
+}
+
+#[async_trait]
+impl UpdateRecordUseCase for UpdateRecordUseCaseImpl {
+    async fn execute(
+        &self,
+        entity_name: &str,
+        id: Uuid,
+        values: Map<String, Value>,
+        mode: RecordUpdateMode,
+        context: ChangeContext,
+        precondition: Option<VersionPrecondition>,
+    ) -> Result<RecordDto, ApplicationError> {
+        info!("Ejecutando caso de uso UpdateRecord: entity='{}', id='{}', mode={:?}", entity_name, id, mode);
+
+        // 1. Resolver entidad, atributos y registro actual
+        let (entity, attributes) = resolve_entity(
+            self.le_query_repository.as_ref(),
+            self.attribute_query_repository.as_ref(),
+            entity_name,
+        ).await?;
+        let entity_id = entity.id;
+        let current = self.record_query_repository
+            .find_by_id(entity_id, id)
+            .await
+            .map_err(|e| ApplicationError::InfrastructureError(format!("Error al buscar registro: {}", e)))?
+            .ok_or_else(|| ApplicationError::NotFound(format!("Registro con ID {} no encontrado en '{}'", id, entity_name)))?;
+        if precondition.as_ref().is_some_and(|precondition| !precondition.is_met(current.version)) {
+            return Err(record_precondition_failed(id, current.version));
+        }
+        let read_version = current.version;
+
+        // 2. Aplicar las reglas de los atributos (PATCH conserva los valores actuales)
+        let prepared = prepare_record_values(&attributes, &values, Some(&current.values), mode)?;
+
+        // 3. Comprobar unicidad y actualizar la tupla y sus valores en una única transacción
+        self.uow.execute(move |registry: &mut dyn RepositoryRegistry| async move {
+            let record_repo = registry.record_command_repository();
+            let conn = registry.get_diesel_async_conn();
+
+            // Los valores se prepararon con la versión leída: si cambió, se perderían cambios
+            check_record_version(record_repo, conn, entity_id, id, precondition.as_ref(), Some(read_version)).await?;
+            let affected = record_repo.touch_tuple(conn, entity_id, id, context.actor).await
+                .map_err(|e| anyhow!("Failed to update tuple {}: {}", id, e))?;
+            if affected == 0 {
+                return Err(anyhow!(ApplicationError::NotFound(format!("Registro con ID {} no encontrado", id))));
+            }
+            enforce_unique_keys(record_repo, conn, entity_id, Some(id), &prepared.unique_keys).await?;
+            enforce_references(record_repo, conn, &prepared.references).await?;
+
+            // Historial: solo los atributos cuyo valor cambia
+            let before = load_values(record_repo, conn, id).await?;
+            apply_value_writes(record_repo, conn, entity_id, id, &prepared.writes).await?;
+            let after = load_values(record_repo, conn, id).await?;
+            let entries = history_entries(&context, entity_id, id, RecordChangeKind::Update, &before, &after);
+            write_history(record_repo, conn, entries).await?;
+            Ok(())
+        }.scope_boxed()).await.map_err(|e| map_uow_error(e, "actualizar registro"))?;
+        info!("Registro {} actualizado en la entidad '{}'", id, entity_name);
+
+        // 4. Devolver el registro actualizado
+        self.record_query_repository
+            .find_by_id(entity_id, id)
+            .await
+            .map_err(|e| ApplicationError::InfrastructureError(format!("Error al buscar registro: {}", e)))?
+            .ok_or_else(|| ApplicationError::NotFound(format!("Registro con ID {} no encontrado", id)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Application::errors::application_error::FieldError;
+    use crate::Application::ports::driven::repositories::LogicalEntityDto;
+    use crate::Application::use_cases::test_support::{FailingUnitOfWork, EntityQueryStub, AttributeQueryStub, RecordQueryStub};
+
+    /// Caso de uso sobre un registro de "clientes" en la versión 3 cuya UoW falla con `error`.
+    fn use_case(error: ApplicationError) -> (UpdateRecordUseCaseImpl, Arc<FailingUnitOfWork>, Uuid) {
+        let entity = LogicalEntityDto::for_tests("clientes");
+        let record = RecordDto::for_tests(entity.id, 3);
+        let id = record.id;
+        let uow = Arc::new(FailingUnitOfWork::new(error));
+        let use_case = UpdateRecordUseCaseImpl::new(
+            Arc::new(EntityQueryStub { entity }),
+            Arc::new(AttributeQueryStub { attributes: Vec::new() }),
+            Arc::new(RecordQueryStub { record }),
+            uow.clone(),
+        );
+        (use_case, uow, id)
+    }
+
+    async fn update(use_case: &UpdateRecordUseCaseImpl, id: Uuid) -> Result<RecordDto, ApplicationError> {
+        use_case.execute("clientes", id, Map::new(), RecordUpdateMode::Merge, ChangeContext::system(), None).await
+    }
+
+    #[tokio::test]
+    async fn record_deleted_during_the_transaction_is_not_found() {
+        let (use_case, _, id) = use_case(ApplicationError::NotFound("registro".to_string()));
+
+        assert!(matches!(update(&use_case, id).await, Err(ApplicationError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn duplicated_unique_key_is_a_validation_error() {
+        let errors = vec![FieldError::new("email", "unique", "duplicado")];
+        let (use_case, _, id) = use_case(ApplicationError::FieldValidationErrors(errors));
+
+        match update(&use_case, id).await {
+            Err(ApplicationError::FieldValidationErrors(errors)) => assert_eq!(errors[0].field, "email"),
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn stale_if_match_is_a_failed_precondition() {
+        let (use_case, uow, id) = use_case(ApplicationError::InfrastructureError("no debe llegar".to_string()));
+        let precondition = VersionPrecondition::from_if_match("\"2\"");
+
+        let result = use_case.execute("clientes", id, Map::new(), RecordUpdateMode::Merge, ChangeContext::system(), Some(precondition)).await;
+
+        assert!(matches!(result, Err(ApplicationError::PreconditionFailed(_))));
+        assert_eq!(uow.calls(), 0);
+    }
+
+    #[tokio::test]
+    async fn unknown_attribute_is_rejected_before_the_transaction() {
+        let (use_case, uow, id) = use_case(ApplicationError::InfrastructureError("no debe llegar".to_string()));
+        let mut values = Map::new();
+        values.insert("desconocido".to_string(), Value::from(1));
+
+        let result = use_case.execute("clientes", id, values, RecordUpdateMode::Merge, ChangeContext::system(), None).await;
+
+        assert!(matches!(result, Err(ApplicationError::FieldValidationErrors(_))));
+        assert_eq!(uow.calls(), 0);
+    }
+}
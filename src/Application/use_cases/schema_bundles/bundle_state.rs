@@ -0,0 +1,113 @@
+// src/Application/use_cases/schema_bundles/bundle_state.rs
+//
+// Conversión de las definiciones del entorno (entidades, atributos y tipos de
+// datos) a su forma de bundle, donde todo se identifica por nombre.
+
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::Application::dtos::logical_entity_dto::LogicalEntityDetailsDto;
+use crate::Application::errors::application_error::ApplicationError;
+use crate::Application::ports::driven::repositories::AttributeDto;
+use crate::Application::use_cases::logical_entities::{FindLogicalEntityByIdUseCase, FindLogicalEntityByNameUseCase};
+use crate::Domain::data_types::DataType;
+use crate::Domain::schema_bundles::{BundleAttribute, BundleDataType, BundleEntity, BundleReference};
+
+pub(super) fn bundle_data_type(data_type: &DataType) -> BundleDataType {
+    BundleDataType {
+        name: data_type.name.clone(),
+        description: data_type.description.clone(),
+        storage_column: data_type.storage.column,
+        pg_cast: data_type.storage.pg_cast.clone(),
+        kind: data_type.storage.kind,
+        params: data_type.storage.params.clone(),
+    }
+}
+
+/// Busca una entidad por nombre; `None` si no existe.
+pub(super) async fn find_entity(
+    find_by_name: &dyn FindLogicalEntityByNameUseCase,
+    name: &str,
+) -> Result<Option<LogicalEntityDetailsDto>, ApplicationError> {
+    match find_by_name.execute(name).await {
+        Ok(details) => Ok(Some(details)),
+        Err(ApplicationError::NotFound(_)) => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+/// Traduce IDs de entidades referenciadas a nombres (con caché, ya que varias
+/// referencias suelen apuntar a la misma entidad).
+pub(super) struct ReferenceNames<'a> {
+    find_by_id: &'a dyn FindLogicalEntityByIdUseCase,
+    entities: HashMap<Uuid, LogicalEntityDetailsDto>,
+}
+
+impl<'a> ReferenceNames<'a> {
+    pub(super) fn new(find_by_id: &'a dyn FindLogicalEntityByIdUseCase) -> Self {
+        Self { find_by_id, entities: HashMap::new() }
+    }
+
+    async fn entity(&mut self, id: Uuid) -> Result<&LogicalEntityDetailsDto, ApplicationError> {
+        if !self.entities.contains_key(&id) {
+            let details = self.find_by_id.execute(id).await?;
+            self.entities.insert(id, details);
+        }
+        Ok(&self.entities[&id])
+    }
+
+    /// Entidad con sus atributos. `include_retired`: incluye los atributos retirados
+    /// (marcados como `retired`), necesarios para comparar con el bundle.
+    pub(super) async fn bundle_entity(
+        &mut self,
+        details: &LogicalEntityDetailsDto,
+        include_retired: bool,
+    ) -> Result<BundleEntity, ApplicationError> {
+        let mut attributes = Vec::with_capacity(details.attributes.len());
+        for attribute in details.attributes.iter().filter(|a| include_retired || a.status == 1) {
+            attributes.push(self.bundle_attribute(attribute).await?);
+        }
+        attributes.sort_by_key(|a| (a.retired, a.position, a.name.clone()));
+
+        Ok(BundleEntity {
+            name: details.entity.name.clone(),
+            description: details.entity.description.clone(),
+            search_language: details.entity.search_language.clone(),
+            assign_view: details.entity.assign_view.is_some(),
+            attributes,
+        })
+    }
+
+    async fn bundle_attribute(&mut self, attribute: &AttributeDto) -> Result<BundleAttribute, ApplicationError> {
+        let reference = match &attribute.reference {
+            None => None,
+            Some(reference) => {
+                let target = self.entity(reference.entity_id).await?;
+                let display_attribute = reference.display_attribute_id
+                    .and_then(|id| target.attributes.iter().find(|a| a.id == id))
+                    .map(|a| a.name.clone());
+                Some(BundleReference {
+                    entity: target.entity.name.clone(),
+                    on_delete: reference.on_delete,
+                    display_attribute,
+                })
+            },
+        };
+
+        Ok(BundleAttribute {
+            name: attribute.name.clone(),
+            description: attribute.description.clone(),
+            data_type: attribute.data_type_name.clone(),
+            position: attribute.position,
+            is_required: attribute.is_required,
+            is_unique: attribute.is_unique,
+            default_value: attribute.default_value.clone(),
+            validation_regex: attribute.validation_regex.clone(),
+            show_option_label: attribute.show_option_label,
+            is_searchable: attribute.is_searchable,
+            options: attribute.options.clone(),
+            reference,
+            retired: attribute.status != 1,
+        })
+    }
+}
@@ -0,0 +1,90 @@
+// src/Application/use_cases/schema_bundles/export_schema_bundle.rs
+
+use async_trait::async_trait;
+use chrono::Utc;
+use std::collections::BTreeSet;
+use std::sync::Arc;
+use log::info;
+
+use crate::Application::errors::application_error::ApplicationError;
+use crate::Application::use_cases::data_types::ListDataTypesUseCase;
+use crate::Application::use_cases::logical_entities::{
+    FindLogicalEntityByIdUseCase, FindLogicalEntityByNameUseCase, ListLogicalEntitiesUseCase,
+};
+use crate::Application::use_cases::logical_entities::list_logical_entities::MAX_PAGE_SIZE;
+use crate::Domain::schema_bundles::SchemaBundle;
+use super::bundle_state::{bundle_data_type, ReferenceNames};
+
+#[async_trait]
+pub trait ExportSchemaBundleUseCase: Send + Sync {
+    /// Exporta las entidades indicadas (todas si la lista está vacía) con sus
+    /// atributos activos y los tipos de datos que estos usan.
+    async fn execute(&self, entity_names: Vec<String>) -> Result<SchemaBundle, ApplicationError>;
+}
+
+pub struct ExportSchemaBundleUseCaseImpl {
+    find_le_by_name_use_case: Arc<dyn FindLogicalEntityByNameUseCase>,
+    find_le_by_id_use_case: Arc<dyn FindLogicalEntityByIdUseCase>,
+    list_le_use_case: Arc<dyn ListLogicalEntitiesUseCase>,
+    list_data_types_use_case: Arc<dyn ListDataTypesUseCase>,
+}
+
+impl ExportSchemaBundleUseCaseImpl {
+    pub fn new(
+        find_le_by_name_use_case: Arc<dyn FindLogicalEntityByNameUseCase>,
+        find_le_by_id_use_case: Arc<dyn FindLogicalEntityByIdUseCase>,
+        list_le_use_case: Arc<dyn ListLogicalEntitiesUseCase>,
+        list_data_types_use_case: Arc<dyn ListDataTypesUseCase>,
+    ) -> Self {
+        Self { find_le_by_name_use_case, find_le_by_id_use_case, list_le_use_case, list_data_types_use_case }
+    }
+}
+
+#[async_trait]
+impl ExportSchemaBundleUseCase for ExportSchemaBundleUseCaseImpl {
+    async fn execute(&self, entity_names: Vec<String>) -> Result<SchemaBundle, ApplicationError> {
+        info!("Ejecutando caso de uso ExportSchemaBundle: entidades={:?}", entity_names);
+
+        let mut details = Vec::new();
+        if entity_names.is_empty() {
+            let mut page = 1;
+            loop {
+                let result = self.list_le_use_case.execute(page, MAX_PAGE_SIZE).await?;
+                let done = result.items.is_empty() || page * MAX_PAGE_SIZE >= result.total;
+                details.extend(result.items);
+                if done {
+                    break;
+                }
+                page += 1;
+            }
+        } else {
+            let unique: BTreeSet<String> = entity_names.into_iter().map(|n| n.trim().to_string()).collect();
+            for name in unique.iter().filter(|n| !n.is_empty()) {
+                details.push(self.find_le_by_name_use_case.execute(name).await?);
+            }
+        }
+        details.sort_by(|a, b| a.entity.name.cmp(&b.entity.name));
+
+        let mut references = ReferenceNames::new(self.find_le_by_id_use_case.as_ref());
+        let mut entities = Vec::with_capacity(details.len());
+        for entity in &details {
+            entities.push(references.bundle_entity(entity, false).await?);
+        }
+
+        // Solo los tipos de datos que usan los atributos exportados
+        let used: BTreeSet<&str> = entities.iter()
+            .flat_map(|e| e.attributes.iter().map(|a| a.data_type.as_str()))
+            .collect();
+        let mut data_types: Vec<_> = self.list_data_types_use_case.execute(true).await?
+            .iter()
+            .filter(|d| used.contains(d.name.as_str()))
+            .map(bundle_data_type)
+            .collect();
+        data_types.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let mut bundle = SchemaBundle::new(data_types, entities);
+        bundle.exported_at = Some(Utc::now());
+        info!("Bundle exportado: {} entidades, {} tipos de datos", bundle.entities.len(), bundle.data_types.len());
+        Ok(bundle)
+    }
+}
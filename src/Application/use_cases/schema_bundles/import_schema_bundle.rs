@@ -0,0 +1,428 @@
+// src/Application/use_cases/schema_bundles/import_schema_bundle.rs
+//
+// Importación de un bundle: se compara con las definiciones del destino (por
+// nombre) y, salvo en dry_run o si hay conflictos, se aplica el plan con los
+// mismos casos de uso que la API REST. Cada paso se confirma por separado (y
+// registra su versión de esquema): si uno falla, los anteriores quedan aplicados
+// y volver a importar el bundle continúa desde ese punto.
+
+use async_trait::async_trait;
+use std::collections::{BTreeSet, HashMap};
+use std::sync::Arc;
+use uuid::Uuid;
+use log::{error, info, warn};
+
+use crate::Application::dtos::attribute_dto::{
+    AddAttributeDto, AttributePositionDto, ChangeAttributeDataTypeDto, ReferenceDefinitionDto,
+    ReplaceAttributeOptionsDto, UpdateAttributeDto,
+};
+use crate::Application::dtos::data_type_dto::{CreateDataTypeDto, UpdateDataTypeDto};
+use crate::Application::dtos::logical_entity_dto::UpdateLogicalEntityDto;
+use crate::Application::dtos::schema_bundle_dto::{ImportSchemaBundleDto, SchemaBundleImportReportDto};
+use crate::Application::errors::application_error::ApplicationError;
+use crate::Application::use_cases::attributes::{
+    AddAttributeUseCase, UpdateAttributeUseCase, ReorderAttributesUseCase, ChangeAttributeDataTypeUseCase,
+    ReplaceAttributeOptionsUseCase,
+};
+use crate::Application::use_cases::data_types::{ListDataTypesUseCase, CreateDataTypeUseCase, UpdateDataTypeUseCase};
+use crate::Application::use_cases::logical_entities::{
+    AttributeDefinitionCommand, CreateEntityWithAttributesCommand, CreateEntityWithAttributesUseCase,
+    FindLogicalEntityByIdUseCase, FindLogicalEntityByNameUseCase, UpdateLogicalEntityUseCase,
+};
+use crate::Domain::data_types::DataType;
+use crate::Domain::errors::DomainError;
+use crate::Domain::schema_bundles::{
+    plan_bundle, BundleAttribute, BundleEntity, BundlePlan, PlanAction, PlanTarget, SchemaBundle,
+};
+use super::bundle_state::{bundle_data_type, find_entity, ReferenceNames};
+
+#[async_trait]
+pub trait ImportSchemaBundleUseCase: Send + Sync {
+    /// Calcula el plan de cambios del bundle y, si no es un dry_run y no hay
+    /// conflictos, lo aplica. El informe siempre incluye el plan calculado.
+    async fn execute(&self, dto: ImportSchemaBundleDto) -> Result<SchemaBundleImportReportDto, ApplicationError>;
+}
+
+pub struct ImportSchemaBundleUseCaseImpl {
+    find_le_by_name_use_case: Arc<dyn FindLogicalEntityByNameUseCase>,
+    find_le_by_id_use_case: Arc<dyn FindLogicalEntityByIdUseCase>,
+    create_le_use_case: Arc<dyn CreateEntityWithAttributesUseCase>,
+    update_le_use_case: Arc<dyn UpdateLogicalEntityUseCase>,
+    list_data_types_use_case: Arc<dyn ListDataTypesUseCase>,
+    create_data_type_use_case: Arc<dyn CreateDataTypeUseCase>,
+    update_data_type_use_case: Arc<dyn UpdateDataTypeUseCase>,
+    add_attribute_use_case: Arc<dyn AddAttributeUseCase>,
+    update_attribute_use_case: Arc<dyn UpdateAttributeUseCase>,
+    reorder_attributes_use_case: Arc<dyn ReorderAttributesUseCase>,
+    change_attribute_data_type_use_case: Arc<dyn ChangeAttributeDataTypeUseCase>,
+    replace_attribute_options_use_case: Arc<dyn ReplaceAttributeOptionsUseCase>,
+}
+
+impl ImportSchemaBundleUseCaseImpl {
+    pub fn new(
+        find_le_by_name_use_case: Arc<dyn FindLogicalEntityByNameUseCase>,
+        find_le_by_id_use_case: Arc<dyn FindLogicalEntityByIdUseCase>,
+        create_le_use_case: Arc<dyn CreateEntityWithAttributesUseCase>,
+        update_le_use_case: Arc<dyn UpdateLogicalEntityUseCase>,
+        list_data_types_use_case: Arc<dyn ListDataTypesUseCase>,
+        create_data_type_use_case: Arc<dyn CreateDataTypeUseCase>,
+        update_data_type_use_case: Arc<dyn UpdateDataTypeUseCase>,
+        add_attribute_use_case: Arc<dyn AddAttributeUseCase>,
+        update_attribute_use_case: Arc<dyn UpdateAttributeUseCase>,
+        reorder_attributes_use_case: Arc<dyn ReorderAttributesUseCase>,
+        change_attribute_data_type_use_case: Arc<dyn ChangeAttributeDataTypeUseCase>,
+        replace_attribute_options_use_case: Arc<dyn ReplaceAttributeOptionsUseCase>,
+    ) -> Self {
+        Self {
+            find_le_by_name_use_case,
+            find_le_by_id_use_case,
+            create_le_use_case,
+            update_le_use_case,
+            list_data_types_use_case,
+            create_data_type_use_case,
+            update_data_type_use_case,
+            add_attribute_use_case,
+            update_attribute_use_case,
+            reorder_attributes_use_case,
+            change_attribute_data_type_use_case,
+            replace_attribute_options_use_case,
+        }
+    }
+
+    /// Definición actual del destino: todos los tipos de datos, las entidades del
+    /// bundle que ya existen y las entidades que referencian sus atributos.
+    async fn current_state(&self, bundle: &SchemaBundle, data_types: &HashMap<String, DataType>) -> Result<SchemaBundle, ApplicationError> {
+        let mut names: BTreeSet<&str> = bundle.entities.iter().map(|e| e.name.as_str()).collect();
+        names.extend(bundle.entities.iter()
+            .flat_map(|e| e.attributes.iter())
+            .filter_map(|a| a.reference.as_ref().map(|r| r.entity.as_str())));
+
+        let mut references = ReferenceNames::new(self.find_le_by_id_use_case.as_ref());
+        let mut entities = Vec::new();
+        for name in names {
+            if let Some(details) = find_entity(self.find_le_by_name_use_case.as_ref(), name).await? {
+                entities.push(references.bundle_entity(&details, true).await?);
+            }
+        }
+        Ok(SchemaBundle::new(data_types.values().map(bundle_data_type).collect(), entities))
+    }
+
+    async fn apply(
+        &self,
+        bundle: &SchemaBundle,
+        plan: &BundlePlan,
+        data_types: &HashMap<String, DataType>,
+        prune: bool,
+        user: Uuid,
+    ) -> Result<(), ApplicationError> {
+        // 1. Tipos de datos (antes que los atributos que los usan)
+        for data_type in &bundle.data_types {
+            let step = format!("tipo de dato '{}'", data_type.name);
+            match data_types.get(&data_type.name) {
+                None => {
+                    let dto = CreateDataTypeDto {
+                        name: data_type.name.clone(),
+                        description: data_type.description.clone(),
+                        storage_column: data_type.storage_column,
+                        pg_cast: Some(data_type.pg_cast.clone()),
+                        params: data_type.params.clone(),
+                        kind: data_type.kind,
+                        created_by: user,
+                    };
+                    self.create_data_type_use_case.execute(dto).await.map_err(in_step(step))?;
+                },
+                Some(existing) if has_step(plan, PlanTarget::DataType, PlanAction::Change, None, &data_type.name) => {
+                    let params = &data_type.params;
+                    let dto = UpdateDataTypeDto {
+                        name: None,
+                        description: Some(data_type.description.clone()),
+                        storage_column: Some(data_type.storage_column),
+                        pg_cast: Some(data_type.pg_cast.clone()),
+                        max_length: Some(params.max_length),
+                        numeric_precision: Some(params.numeric_precision),
+                        numeric_scale: Some(params.numeric_scale),
+                        min_value: Some(params.min_value),
+                        max_value: Some(params.max_value),
+                        kind: Some(data_type.kind),
+                        status: None,
+                        updated_by: user,
+                    };
+                    self.update_data_type_use_case.execute(existing.id, dto).await.map_err(in_step(step))?;
+                },
+                Some(_) => {},
+            }
+        }
+
+        // 2. Entidades nuevas. Los atributos de referencia se añaden al final, cuando
+        //    ya existen todas las entidades (y atributos) a los que pueden apuntar.
+        for entity in bundle.entities.iter().filter(|e| has_step(plan, PlanTarget::Entity, PlanAction::Add, None, &e.name)) {
+            let command = CreateEntityWithAttributesCommand {
+                entity_name: entity.name.clone(),
+                attributes: entity.attributes.iter()
+                    .filter(|a| !a.retired && a.reference.is_none())
+                    .map(attribute_command)
+                    .collect(),
+                assign_view: entity.assign_view,
+                created_by_user_id: user,
+            };
+            self.create_le_use_case.execute(command).await
+                .map_err(ApplicationError::from)
+                .map_err(in_step(format!("entidad '{}'", entity.name)))?;
+            info!("Entidad '{}' creada desde el bundle", entity.name);
+        }
+
+        // 3. Resto de cambios de cada entidad (incluidas las recién creadas:
+        //    descripción, opciones, etiquetas...)
+        let mut pending = Vec::new();
+        for entity in &bundle.entities {
+            self.reconcile_entity(entity, prune, user, &mut pending).await?;
+        }
+
+        // 4. Atributos de referencia nuevos
+        for (entity_id, entity_name, attribute) in pending {
+            self.add_attribute(entity_id, attribute, user).await
+                .map_err(in_step(format!("atributo '{}.{}'", entity_name, attribute.name)))?;
+        }
+        Ok(())
+    }
+
+    /// Aplica los cambios de una entidad existente. Los atributos de referencia que
+    /// faltan se devuelven en `pending` para añadirlos al final.
+    async fn reconcile_entity<'b>(
+        &self,
+        entity: &'b BundleEntity,
+        prune: bool,
+        user: Uuid,
+        pending: &mut Vec<(Uuid, &'b str, &'b BundleAttribute)>,
+    ) -> Result<(), ApplicationError> {
+        let step = |what: &str| format!("entidad '{}'{}", entity.name, what);
+        let details = self.find_le_by_name_use_case.execute(&entity.name).await.map_err(in_step(step("")))?;
+        let entity_id = details.entity.id;
+        let mut references = ReferenceNames::new(self.find_le_by_id_use_case.as_ref());
+        let before = references.bundle_entity(&details, true).await?;
+        let ids: HashMap<&str, Uuid> = details.attributes.iter().map(|a| (a.name.as_str(), a.id)).collect();
+
+        let dto = UpdateLogicalEntityDto {
+            name: None,
+            description: entity.description.clone().filter(|d| before.description.as_ref() != Some(d)),
+            assign_view: (entity.assign_view != before.assign_view).then_some(entity.assign_view),
+            search_language: (entity.search_language != before.search_language).then(|| entity.search_language.clone()),
+            status: None,
+            updated_by: user,
+        };
+        if dto.description.is_some() || dto.assign_view.is_some() || dto.search_language.is_some() {
+            self.update_le_use_case.execute(entity_id, dto).await.map_err(in_step(step("")))?;
+        }
+
+        // Los atributos que no están en el bundle se retiran antes de mover o añadir
+        // otros, de modo que sus posiciones queden libres
+        if prune {
+            for attribute in before.attributes.iter().filter(|a| !a.retired && entity.attribute(&a.name).is_none()) {
+                let dto = UpdateAttributeDto { status: Some(0), updated_by: user, ..Default::default() };
+                self.update_attribute_use_case.execute(entity_id, ids[attribute.name.as_str()], dto).await
+                    .map_err(in_step(step(&format!(", atributo '{}'", attribute.name))))?;
+                info!("Atributo '{}.{}' retirado (no está en el bundle)", entity.name, attribute.name);
+            }
+        }
+
+        let mut positions = Vec::new();
+        let mut missing = Vec::new();
+        for attribute in &entity.attributes {
+            match before.attribute(&attribute.name) {
+                None if attribute.retired => {},
+                None if attribute.reference.is_some() => pending.push((entity_id, entity.name.as_str(), attribute)),
+                None => missing.push(attribute),
+                Some(existing) => {
+                    let attribute_id = ids[attribute.name.as_str()];
+                    self.update_attribute(entity_id, attribute_id, existing, attribute, user).await
+                        .map_err(in_step(step(&format!(", atributo '{}'", attribute.name))))?;
+                    if !attribute.retired && attribute.position != existing.position {
+                        positions.push(AttributePositionDto { attribute_id, position: attribute.position });
+                    }
+                },
+            }
+        }
+        if !positions.is_empty() {
+            self.reorder_attributes_use_case.execute(entity_id, positions, user).await
+                .map_err(in_step(step(", posiciones de los atributos")))?;
+        }
+        for attribute in missing {
+            self.add_attribute(entity_id, attribute, user).await
+                .map_err(in_step(step(&format!(", atributo '{}'", attribute.name))))?;
+        }
+        Ok(())
+    }
+
+    /// Cambios de un atributo existente, salvo la posición (se aplica junto a las demás).
+    async fn update_attribute(
+        &self,
+        entity_id: Uuid,
+        attribute_id: Uuid,
+        before: &BundleAttribute,
+        after: &BundleAttribute,
+        user: Uuid,
+    ) -> Result<(), ApplicationError> {
+        let mut options_replaced = false;
+        if before.data_type != after.data_type {
+            let dto = ChangeAttributeDataTypeDto {
+                data_type_name: after.data_type.clone(),
+                options: after.options.clone(),
+                dry_run: false,
+                discard_failures: false,
+                updated_by: user,
+            };
+            let report = self.change_attribute_data_type_use_case.execute(entity_id, attribute_id, dto).await?;
+            if !report.applied {
+                return Err(ApplicationError::ValidationError(format!(
+                    "{} valores no se pueden convertir de '{}' a '{}'",
+                    report.failed_count, before.data_type, after.data_type
+                )));
+            }
+            options_replaced = !after.options.is_empty();
+        }
+
+        if !options_replaced && before.options != after.options {
+            let dto = ReplaceAttributeOptionsDto { options: after.options.clone(), updated_by: user };
+            self.replace_attribute_options_use_case.execute(entity_id, attribute_id, dto).await?;
+        }
+
+        // Resto de campos: solo se envían los que cambian
+        let mut rest = before.clone();
+        rest.data_type = after.data_type.clone();
+        rest.options = after.options.clone();
+        rest.position = after.position;
+        if rest == *after {
+            return Ok(());
+        }
+        let reference = match (&before.reference, &after.reference) {
+            (Some(old), Some(new)) => Some((old, new)),
+            _ => None,
+        };
+        let dto = UpdateAttributeDto {
+            name: None,
+            description: changed(&before.description, &after.description),
+            is_required: changed(&before.is_required, &after.is_required),
+            is_unique: changed(&before.is_unique, &after.is_unique),
+            default_value: changed(&before.default_value, &after.default_value),
+            validation_regex: changed(&before.validation_regex, &after.validation_regex),
+            show_option_label: changed(&before.show_option_label, &after.show_option_label),
+            is_searchable: changed(&before.is_searchable, &after.is_searchable),
+            reference_on_delete: reference.and_then(|(old, new)| changed(&old.on_delete, &new.on_delete)),
+            reference_display_attribute: reference.and_then(|(old, new)| changed(&old.display_attribute, &new.display_attribute)),
+            status: changed(&before.retired, &after.retired).map(|retired| if retired { 0 } else { 1 }),
+            updated_by: user,
+        };
+        self.update_attribute_use_case.execute(entity_id, attribute_id, dto).await?;
+        Ok(())
+    }
+
+    async fn add_attribute(&self, entity_id: Uuid, attribute: &BundleAttribute, user: Uuid) -> Result<(), ApplicationError> {
+        let reference = match &attribute.reference {
+            None => None,
+            Some(reference) => {
+                let target = self.find_le_by_name_use_case.execute(&reference.entity).await?;
+                Some(ReferenceDefinitionDto {
+                    entity_id: target.entity.id,
+                    on_delete: reference.on_delete,
+                    display_attribute: reference.display_attribute.clone(),
+                })
+            },
+        };
+        let dto = AddAttributeDto {
+            name: attribute.name.clone(),
+            description: attribute.description.clone(),
+            data_type_name: attribute.data_type.clone(),
+            position: attribute.position,
+            is_required: attribute.is_required,
+            is_unique: attribute.is_unique,
+            default_value: attribute.default_value.clone(),
+            validation_regex: attribute.validation_regex.clone(),
+            show_option_label: attribute.show_option_label,
+            is_searchable: attribute.is_searchable,
+            options: attribute.options.clone(),
+            reference,
+            created_by: user,
+        };
+        self.add_attribute_use_case.execute(entity_id, dto).await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ImportSchemaBundleUseCase for ImportSchemaBundleUseCaseImpl {
+    async fn execute(&self, dto: ImportSchemaBundleDto) -> Result<SchemaBundleImportReportDto, ApplicationError> {
+        let ImportSchemaBundleDto { bundle, dry_run, prune, updated_by } = dto;
+        info!(
+            "Ejecutando caso de uso ImportSchemaBundle: {} entidades, {} tipos de datos, dry_run={}, prune={}",
+            bundle.entities.len(), bundle.data_types.len(), dry_run, prune
+        );
+
+        bundle.validate().map_err(|e| match e {
+            DomainError::ValidationError(message) => ApplicationError::ValidationError(message),
+            other => ApplicationError::ValidationError(other.to_string()),
+        })?;
+
+        let data_types: HashMap<String, DataType> = self.list_data_types_use_case.execute(true).await?
+            .into_iter()
+            .map(|d| (d.name.clone(), d))
+            .collect();
+        let current = self.current_state(&bundle, &data_types).await?;
+        let plan = plan_bundle(&current, &bundle);
+        info!(
+            "Plan del bundle: {} altas, {} cambios, {} eliminaciones, {} conflictos",
+            plan.count(PlanAction::Add), plan.count(PlanAction::Change), plan.count(PlanAction::Remove), plan.conflicts.len()
+        );
+
+        let mut report = SchemaBundleImportReportDto { plan, dry_run, prune, applied: false };
+        if dry_run || report.plan.is_empty() {
+            return Ok(report);
+        }
+        if !report.plan.conflicts.is_empty() {
+            warn!("El bundle no se aplica: el plan tiene {} conflictos", report.plan.conflicts.len());
+            return Ok(report);
+        }
+
+        self.apply(&bundle, &report.plan, &data_types, prune, updated_by).await?;
+        report.applied = true;
+        info!("Bundle aplicado correctamente");
+        Ok(report)
+    }
+}
+
+fn has_step(plan: &BundlePlan, target: PlanTarget, action: PlanAction, entity: Option<&str>, name: &str) -> bool {
+    plan.steps.iter().any(|s| s.target == target && s.action == action && s.entity.as_deref() == entity && s.name == name)
+}
+
+fn attribute_command(attribute: &BundleAttribute) -> AttributeDefinitionCommand {
+    AttributeDefinitionCommand {
+        name: attribute.name.clone(),
+        description: attribute.description.clone(),
+        data_type_name: attribute.data_type.clone(),
+        position: attribute.position,
+        is_required: attribute.is_required,
+        is_unique: attribute.is_unique,
+        default_value: attribute.default_value.clone(),
+        validation_regex: attribute.validation_regex.clone(),
+        is_searchable: attribute.is_searchable,
+        reference: None,
+    }
+}
+
+/// `Some(after)` si el valor cambia.
+fn changed<T: PartialEq + Clone>(before: &T, after: &T) -> Option<T> {
+    (before != after).then(|| after.clone())
+}
+
+/// Añade al mensaje de error el paso del plan en el que se produjo.
+fn in_step(step: String) -> impl FnOnce(ApplicationError) -> ApplicationError {
+    move |e| {
+        error!("Error al aplicar el bundle ({}): {}", step, e);
+        match e {
+            ApplicationError::NotFound(message) => ApplicationError::NotFound(format!("{}: {}", step, message)),
+            ApplicationError::ValidationError(message) => ApplicationError::ValidationError(format!("{}: {}", step, message)),
+            ApplicationError::Conflict(message) => ApplicationError::Conflict(format!("{}: {}", step, message)),
+            ApplicationError::InfrastructureError(message) => ApplicationError::InfrastructureError(format!("{}: {}", step, message)),
+            other => other,
+        }
+    }
+}
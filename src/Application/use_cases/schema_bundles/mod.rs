@@ -0,0 +1,8 @@
+mod bundle_state;
+pub mod export_schema_bundle;
+pub mod import_schema_bundle;
+pub mod preview_entity_schema;
+
+pub use export_schema_bundle::{ExportSchemaBundleUseCase, ExportSchemaBundleUseCaseImpl};
+pub use import_schema_bundle::{ImportSchemaBundleUseCase, ImportSchemaBundleUseCaseImpl};
+pub use preview_entity_schema::{PreviewEntitySchemaUseCase, PreviewEntitySchemaUseCaseImpl};
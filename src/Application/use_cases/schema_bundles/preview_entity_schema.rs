@@ -0,0 +1,282 @@
+// src/Application/use_cases/schema_bundles/preview_entity_schema.rs
+//
+// Vista previa de la creación o el cambio de una entidad descrita en la forma de
+// bundle: el plan de cambios (los atributos que faltan se retirarían), el SQL de la
+// vista que se generaría, cuántos registros existentes incumplirían las reglas nuevas
+// y avisos sobre la definición. Solo lee: no se aplica ni se guarda nada.
+
+use async_trait::async_trait;
+use regex::Regex;
+use std::collections::{BTreeSet, HashMap};
+use std::error::Error;
+use std::sync::Arc;
+use uuid::Uuid;
+use log::{info, debug};
+
+use crate::Application::dtos::logical_entity_dto::LogicalEntityDetailsDto;
+use crate::Application::dtos::schema_bundle_dto::EntitySchemaPreviewDto;
+use crate::Application::errors::application_error::ApplicationError;
+use crate::Application::ports::driven::repositories::RecordQueryRepository;
+use crate::Application::use_cases::data_types::ListDataTypesUseCase;
+use crate::Application::use_cases::logical_entities::{FindLogicalEntityByIdUseCase, FindLogicalEntityByNameUseCase};
+use crate::Domain::data_types::DataType;
+use crate::Domain::records::StorageColumn;
+use crate::Domain::schema_bundles::{plan_bundle, BundleAttribute, BundleEntity, PlanAction, SchemaBundle};
+use crate::Domain::schema_previews::{
+    definition_conflicts, preview_warnings, rule_checks, ImpactRule, RuleCheck, RuleImpact,
+};
+use crate::Domain::views::{
+    AttributeInfo, EntityViewSettings, ReferenceDisplay, ViewPivotStrategy, ViewRepository, generate_view_sql,
+    view_name_for,
+};
+use super::bundle_state::{bundle_data_type, find_entity, ReferenceNames};
+
+#[async_trait]
+pub trait PreviewEntitySchemaUseCase: Send + Sync {
+    /// Calcula qué ocurriría al crear la entidad (si no existe con ese nombre) o al
+    /// cambiarla a la definición indicada, sin aplicar nada.
+    async fn execute(&self, entity: BundleEntity) -> Result<EntitySchemaPreviewDto, ApplicationError>;
+}
+
+pub struct PreviewEntitySchemaUseCaseImpl {
+    find_le_by_name_use_case: Arc<dyn FindLogicalEntityByNameUseCase>,
+    find_le_by_id_use_case: Arc<dyn FindLogicalEntityByIdUseCase>,
+    list_data_types_use_case: Arc<dyn ListDataTypesUseCase>,
+    record_query_repository: Arc<dyn RecordQueryRepository>,
+    view_repository: Arc<dyn ViewRepository>,
+}
+
+impl PreviewEntitySchemaUseCaseImpl {
+    pub fn new(
+        find_le_by_name_use_case: Arc<dyn FindLogicalEntityByNameUseCase>,
+        find_le_by_id_use_case: Arc<dyn FindLogicalEntityByIdUseCase>,
+        list_data_types_use_case: Arc<dyn ListDataTypesUseCase>,
+        record_query_repository: Arc<dyn RecordQueryRepository>,
+        view_repository: Arc<dyn ViewRepository>,
+    ) -> Self {
+        Self {
+            find_le_by_name_use_case,
+            find_le_by_id_use_case,
+            list_data_types_use_case,
+            record_query_repository,
+            view_repository,
+        }
+    }
+
+    /// SQL que generaría `generate_view_sql` con la definición deseada. Los atributos
+    /// nuevos (y la entidad, si es nueva) usan el UUID nulo como ID. Si no se puede
+    /// generar, el motivo se añade a `conflicts`.
+    async fn view_sql(
+        &self,
+        desired: &BundleEntity,
+        details: Option<&LogicalEntityDetailsDto>,
+        existing: &HashMap<String, LogicalEntityDetailsDto>,
+        data_types: &HashMap<String, DataType>,
+        conflicts: &mut Vec<String>,
+    ) -> Result<Option<String>, ApplicationError> {
+        let entity_id = details.map_or(Uuid::nil(), |d| d.entity.id);
+        let storage_mode = details.map(|d| d.entity.storage_mode).unwrap_or_default();
+        let settings = match details {
+            Some(details) => self.view_repository
+                .find_view_settings(details.entity.id)
+                .await
+                .map_err(|e| ApplicationError::InfrastructureError(format!("Error al consultar las opciones de la vista: {}", e)))?
+                .0,
+            None => EntityViewSettings::default(),
+        };
+        let attribute_id = |name: &str| {
+            details
+                .and_then(|d| d.attributes.iter().find(|a| a.name == name))
+                .map_or(Uuid::nil(), |a| a.id)
+        };
+
+        let mut attributes: Vec<&BundleAttribute> = desired.attributes.iter().filter(|a| !a.retired).collect();
+        attributes.sort_by_key(|a| (a.position, a.name.clone()));
+        let mut attributes_info = Vec::with_capacity(attributes.len());
+        for attribute in attributes {
+            // El plan ya informa de los tipos de datos que no existen
+            let Some(data_type) = data_types.get(&attribute.data_type) else {
+                debug!("Sin vista previa de la vista de '{}': el tipo '{}' no existe", desired.name, attribute.data_type);
+                return Ok(None);
+            };
+            let reference_display = attribute.reference.as_ref().and_then(|reference| {
+                let display = reference.display_attribute.as_deref()?;
+                if reference.entity == desired.name {
+                    let target = desired.attribute(display).filter(|t| !t.retired)?;
+                    return Some(ReferenceDisplay {
+                        attribute_id: attribute_id(display),
+                        storage: &data_types.get(&target.data_type)?.storage,
+                        entity_id,
+                        entity_storage: storage_mode,
+                    });
+                }
+                let target = existing.get(&reference.entity)?;
+                let shown = target.attributes.iter().find(|a| a.name == display && a.status == 1)?;
+                Some(ReferenceDisplay {
+                    attribute_id: shown.id,
+                    storage: &shown.storage,
+                    entity_id: target.entity.id,
+                    entity_storage: target.entity.storage_mode,
+                })
+            });
+            attributes_info.push(AttributeInfo {
+                attribute_id: attribute_id(&attribute.name),
+                name: &attribute.name,
+                position: attribute.position,
+                storage: &data_type.storage,
+                show_option_label: attribute.show_option_label,
+                reference_display,
+            });
+        }
+
+        let pivot = ViewPivotStrategy::resolve(settings.pivot_strategy, attributes_info.len());
+        match generate_view_sql(entity_id, &desired.name, &attributes_info, settings.materialized, pivot, storage_mode) {
+            Ok(sql) => Ok(Some(sql)),
+            Err(e) => {
+                conflicts.push(format!("entidad '{}': no se puede generar la vista: {}", desired.name, e));
+                Ok(None)
+            },
+        }
+    }
+
+    /// Cuenta los registros de la entidad que incumplirían cada regla. Las expresiones
+    /// de validación se evalúan en Postgres sobre el valor completo, como al escribir
+    /// registros; el motor no es el mismo, así que esos recuentos se avisan como aproximados.
+    async fn rule_impacts(
+        &self,
+        details: &LogicalEntityDetailsDto,
+        checks: Vec<RuleCheck>,
+        conflicts: &mut Vec<String>,
+        warnings: &mut Vec<String>,
+    ) -> Result<Vec<RuleImpact>, ApplicationError> {
+        let entity_id = details.entity.id;
+        let stored = |name: &str| details.attributes.iter().find(|a| a.name == name);
+        let infrastructure = |e: Box<dyn Error + Send + Sync>| {
+            ApplicationError::InfrastructureError(format!("Error al consultar los registros de la entidad: {}", e))
+        };
+
+        let mut impacts = Vec::with_capacity(checks.len());
+        for check in checks {
+            let violations = match check.rule {
+                ImpactRule::Required => match stored(&check.attributes[0]) {
+                    Some(attribute) => self.record_query_repository
+                        .count_missing_values(entity_id, attribute.id, attribute.storage.column)
+                        .await
+                        .map_err(infrastructure)?,
+                    // Atributo nuevo sin valor por defecto: ningún registro lo tendría
+                    None => self.record_query_repository.count_by_entity(entity_id).await.map_err(infrastructure)?,
+                },
+                ImpactRule::Regex => {
+                    let (Some(attribute), Some(pattern)) = (stored(&check.attributes[0]), check.pattern.as_deref()) else {
+                        continue;
+                    };
+                    // Al escribir registros la expresión se compila con `regex`
+                    if let Err(e) = Regex::new(&format!("^(?:{})$", pattern)) {
+                        conflicts.push(format!(
+                            "atributo '{}.{}': la expresión de validación no es válida: {}", details.entity.name, attribute.name, e
+                        ));
+                        continue;
+                    }
+                    // Se cuenta en la base de datos, sin traer los valores
+                    let count = self.record_query_repository
+                        .count_pattern_mismatches(entity_id, attribute.id, attribute.storage.column, pattern)
+                        .await
+                        .map_err(infrastructure)?;
+                    match count {
+                        Some(count) => {
+                            warnings.push(format!(
+                                "atributo '{}.{}': el recuento de valores que no cumplen la expresión de validación es \
+                                 aproximado: se calcula con las expresiones regulares de Postgres, que no coinciden del \
+                                 todo con las que validan las escrituras",
+                                details.entity.name, attribute.name
+                            ));
+                            count
+                        },
+                        None => {
+                            conflicts.push(format!(
+                                "atributo '{}.{}': la expresión de validación no se puede evaluar en la base de datos",
+                                details.entity.name, attribute.name
+                            ));
+                            continue;
+                        },
+                    }
+                },
+                ImpactRule::Unique => {
+                    let key: Vec<(Uuid, StorageColumn)> = check.attributes
+                        .iter()
+                        .filter_map(|name| stored(name))
+                        .map(|a| (a.id, a.storage.column))
+                        .collect();
+                    self.record_query_repository
+                        .count_duplicated_values(entity_id, &key)
+                        .await
+                        .map_err(infrastructure)?
+                },
+            };
+            impacts.push(RuleImpact { check, violations });
+        }
+        Ok(impacts)
+    }
+}
+
+#[async_trait]
+impl PreviewEntitySchemaUseCase for PreviewEntitySchemaUseCaseImpl {
+    async fn execute(&self, desired: BundleEntity) -> Result<EntitySchemaPreviewDto, ApplicationError> {
+        info!("Ejecutando caso de uso PreviewEntitySchema: entity='{}', {} atributos", desired.name, desired.attributes.len());
+
+        let data_types: HashMap<String, DataType> = self.list_data_types_use_case.execute(true).await?
+            .into_iter()
+            .map(|d| (d.name.clone(), d))
+            .collect();
+
+        // 1. Definición actual: la entidad (si existe) y las que referencian sus atributos
+        let mut names: BTreeSet<&str> = BTreeSet::from([desired.name.as_str()]);
+        names.extend(desired.attributes.iter().filter_map(|a| a.reference.as_ref().map(|r| r.entity.as_str())));
+        let mut references = ReferenceNames::new(self.find_le_by_id_use_case.as_ref());
+        let mut existing: HashMap<String, LogicalEntityDetailsDto> = HashMap::new();
+        let mut entities = Vec::new();
+        for name in names {
+            if let Some(details) = find_entity(self.find_le_by_name_use_case.as_ref(), name).await? {
+                entities.push(references.bundle_entity(&details, true).await?);
+                existing.insert(name.to_string(), details);
+            }
+        }
+        let current = SchemaBundle::new(data_types.values().map(bundle_data_type).collect(), entities);
+        let before = current.entity(&desired.name);
+        let details = existing.get(&desired.name);
+
+        // 2. Plan de cambios, definiciones que no se podrían guardar y avisos
+        let mut plan = plan_bundle(&current, &SchemaBundle::new(Vec::new(), vec![desired.clone()]));
+        plan.conflicts.extend(definition_conflicts(before, &desired));
+        let mut warnings = preview_warnings(before, &desired);
+
+        // 3. Vista que se generaría
+        let view_sql = if desired.assign_view {
+            self.view_sql(&desired, details, &existing, &data_types, &mut plan.conflicts).await?
+        } else {
+            None
+        };
+
+        // 4. Registros existentes que incumplirían las reglas nuevas
+        let rule_impacts = match details {
+            Some(details) => self.rule_impacts(details, rule_checks(before, &desired), &mut plan.conflicts, &mut warnings).await?,
+            None => Vec::new(),
+        };
+
+        info!(
+            "Vista previa de '{}': {} altas, {} cambios, {} eliminaciones, {} conflictos, {} reglas comprobadas, {} avisos",
+            desired.name, plan.count(PlanAction::Add), plan.count(PlanAction::Change), plan.count(PlanAction::Remove),
+            plan.conflicts.len(), rule_impacts.len(), warnings.len()
+        );
+
+        Ok(EntitySchemaPreviewDto {
+            entity: desired.name.clone(),
+            entity_id: details.map(|d| d.entity.id),
+            plan,
+            view_name: desired.assign_view.then(|| view_name_for(&desired.name)),
+            view_sql,
+            rule_impacts,
+            warnings,
+        })
+    }
+}
@@ -0,0 +1,47 @@
+// src/Application/use_cases/schema_versions/diff_schema_versions.rs
+
+use async_trait::async_trait;
+use std::sync::Arc;
+use uuid::Uuid;
+use log::info;
+
+use crate::Application::dtos::schema_version_dto::SchemaVersionDiffDto;
+use crate::Application::errors::application_error::ApplicationError;
+use crate::Application::ports::driven::repositories::SchemaVersionQueryRepository;
+use crate::Domain::schema_versions::diff_snapshots;
+use super::find_schema_version::load_version;
+
+#[async_trait]
+pub trait DiffSchemaVersionsUseCase: Send + Sync {
+    /// Compara dos versiones de la entidad atributo por atributo.
+    async fn execute(&self, entity_id: Uuid, from_version: i32, to_version: i32) -> Result<SchemaVersionDiffDto, ApplicationError>;
+}
+
+pub struct DiffSchemaVersionsUseCaseImpl {
+    schema_version_query_repository: Arc<dyn SchemaVersionQueryRepository>,
+}
+
+impl DiffSchemaVersionsUseCaseImpl {
+    pub fn new(schema_version_query_repository: Arc<dyn SchemaVersionQueryRepository>) -> Self {
+        Self { schema_version_query_repository }
+    }
+}
+
+#[async_trait]
+impl DiffSchemaVersionsUseCase for DiffSchemaVersionsUseCaseImpl {
+    async fn execute(&self, entity_id: Uuid, from_version: i32, to_version: i32) -> Result<SchemaVersionDiffDto, ApplicationError> {
+        info!("Ejecutando caso de uso DiffSchemaVersions: entity_id='{}', {} -> {}", entity_id, from_version, to_version);
+
+        let from = load_version(self.schema_version_query_repository.as_ref(), entity_id, from_version).await?;
+        let to = load_version(self.schema_version_query_repository.as_ref(), entity_id, to_version).await?;
+        let diff = diff_snapshots(&from.definition, &to.definition);
+
+        Ok(SchemaVersionDiffDto {
+            entity_id,
+            from_version,
+            to_version,
+            entity_name: diff.entity_name,
+            attributes: diff.attributes,
+        })
+    }
+}
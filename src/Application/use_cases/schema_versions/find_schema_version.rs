@@ -0,0 +1,134 @@
+// src/Application/use_cases/schema_versions/find_schema_version.rs
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use uuid::Uuid;
+use log::info;
+
+use crate::Application::errors::application_error::ApplicationError;
+use crate::Application::ports::driven::repositories::{
+    LogicalEntityQueryRepository,
+    SchemaVersionQueryRepository,
+    SchemaVersionDto,
+};
+
+// --- Traits de los Casos de Uso ---
+#[async_trait]
+pub trait ListSchemaVersionsUseCase: Send + Sync {
+    /// Versiones de la entidad, de la más reciente a la más antigua.
+    async fn execute(&self, entity_id: Uuid) -> Result<Vec<SchemaVersionDto>, ApplicationError>;
+}
+
+#[async_trait]
+pub trait FindSchemaVersionUseCase: Send + Sync {
+    async fn execute(&self, entity_id: Uuid, version: i32) -> Result<SchemaVersionDto, ApplicationError>;
+}
+
+#[async_trait]
+pub trait FindSchemaVersionAsOfUseCase: Send + Sync {
+    /// Definición vigente de la entidad en la fecha indicada.
+    async fn execute(&self, entity_id: Uuid, at: DateTime<Utc>) -> Result<SchemaVersionDto, ApplicationError>;
+}
+
+/// Verifica que la entidad existe (para distinguir "entidad inexistente" de "sin versiones").
+pub(crate) async fn ensure_entity_exists(
+    le_query_repository: &dyn LogicalEntityQueryRepository,
+    entity_id: Uuid,
+) -> Result<(), ApplicationError> {
+    le_query_repository
+        .find_by_id(entity_id)
+        .await
+        .map_err(|e| ApplicationError::InfrastructureError(format!("Error al buscar entidad lógica: {}", e)))?
+        .ok_or_else(|| ApplicationError::NotFound(format!("Entidad lógica con ID {} no encontrada", entity_id)))?;
+    Ok(())
+}
+
+/// Busca una versión concreta de la entidad.
+pub(crate) async fn load_version(
+    schema_version_query_repository: &dyn SchemaVersionQueryRepository,
+    entity_id: Uuid,
+    version: i32,
+) -> Result<SchemaVersionDto, ApplicationError> {
+    schema_version_query_repository
+        .find_version(entity_id, version)
+        .await
+        .map_err(|e| ApplicationError::InfrastructureError(format!("Error al buscar versión de la entidad: {}", e)))?
+        .ok_or_else(|| ApplicationError::NotFound(format!("La entidad {} no tiene versión {}", entity_id, version)))
+}
+
+// --- Implementación: listado ---
+pub struct ListSchemaVersionsUseCaseImpl {
+    le_query_repository: Arc<dyn LogicalEntityQueryRepository>,
+    schema_version_query_repository: Arc<dyn SchemaVersionQueryRepository>,
+}
+
+impl ListSchemaVersionsUseCaseImpl {
+    pub fn new(
+        le_query_repository: Arc<dyn LogicalEntityQueryRepository>,
+        schema_version_query_repository: Arc<dyn SchemaVersionQueryRepository>,
+    ) -> Self {
+        Self { le_query_repository, schema_version_query_repository }
+    }
+}
+
+#[async_trait]
+impl ListSchemaVersionsUseCase for ListSchemaVersionsUseCaseImpl {
+    async fn execute(&self, entity_id: Uuid) -> Result<Vec<SchemaVersionDto>, ApplicationError> {
+        info!("Ejecutando caso de uso ListSchemaVersions: entity_id='{}'", entity_id);
+
+        ensure_entity_exists(self.le_query_repository.as_ref(), entity_id).await?;
+        self.schema_version_query_repository
+            .find_by_entity_id(entity_id)
+            .await
+            .map_err(|e| ApplicationError::InfrastructureError(format!("Error al consultar versiones de la entidad: {}", e)))
+    }
+}
+
+// --- Implementación: versión concreta ---
+pub struct FindSchemaVersionUseCaseImpl {
+    schema_version_query_repository: Arc<dyn SchemaVersionQueryRepository>,
+}
+
+impl FindSchemaVersionUseCaseImpl {
+    pub fn new(schema_version_query_repository: Arc<dyn SchemaVersionQueryRepository>) -> Self {
+        Self { schema_version_query_repository }
+    }
+}
+
+#[async_trait]
+impl FindSchemaVersionUseCase for FindSchemaVersionUseCaseImpl {
+    async fn execute(&self, entity_id: Uuid, version: i32) -> Result<SchemaVersionDto, ApplicationError> {
+        info!("Ejecutando caso de uso FindSchemaVersion: entity_id='{}', version={}", entity_id, version);
+        load_version(self.schema_version_query_repository.as_ref(), entity_id, version).await
+    }
+}
+
+// --- Implementación: versión vigente en una fecha ---
+pub struct FindSchemaVersionAsOfUseCaseImpl {
+    le_query_repository: Arc<dyn LogicalEntityQueryRepository>,
+    schema_version_query_repository: Arc<dyn SchemaVersionQueryRepository>,
+}
+
+impl FindSchemaVersionAsOfUseCaseImpl {
+    pub fn new(
+        le_query_repository: Arc<dyn LogicalEntityQueryRepository>,
+        schema_version_query_repository: Arc<dyn SchemaVersionQueryRepository>,
+    ) -> Self {
+        Self { le_query_repository, schema_version_query_repository }
+    }
+}
+
+#[async_trait]
+impl FindSchemaVersionAsOfUseCase for FindSchemaVersionAsOfUseCaseImpl {
+    async fn execute(&self, entity_id: Uuid, at: DateTime<Utc>) -> Result<SchemaVersionDto, ApplicationError> {
+        info!("Ejecutando caso de uso FindSchemaVersionAsOf: entity_id='{}', at='{}'", entity_id, at);
+
+        ensure_entity_exists(self.le_query_repository.as_ref(), entity_id).await?;
+        self.schema_version_query_repository
+            .find_as_of(entity_id, at)
+            .await
+            .map_err(|e| ApplicationError::InfrastructureError(format!("Error al buscar versión de la entidad: {}", e)))?
+            .ok_or_else(|| ApplicationError::NotFound(format!("La entidad {} no tenía ninguna versión registrada en {}", entity_id, at)))
+    }
+}
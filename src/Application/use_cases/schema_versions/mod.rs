@@ -0,0 +1,14 @@
+pub mod find_schema_version;
+pub mod diff_schema_versions;
+pub mod rollback_schema_version;
+
+pub use find_schema_version::{
+ListSchemaVersionsUseCase,
+ListSchemaVersionsUseCaseImpl,
+FindSchemaVersionUseCase,
+FindSchemaVersionUseCaseImpl,
+FindSchemaVersionAsOfUseCase,
+FindSchemaVersionAsOfUseCaseImpl,
+};
+pub use diff_schema_versions::{DiffSchemaVersionsUseCase, DiffSchemaVersionsUseCaseImpl};
+pub use rollback_schema_version::{RollbackSchemaVersionUseCase, RollbackSchemaVersionUseCaseImpl};
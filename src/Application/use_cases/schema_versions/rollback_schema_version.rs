@@ -0,0 +1,285 @@
+// src/Application/use_cases/schema_versions/rollback_schema_version.rs
+
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+use uuid::Uuid;
+use log::{info, debug};
+use anyhow::anyhow;
+
+use crate::Application::errors::application_error::ApplicationError;
+use crate::Application::ports::unit_of_work::{UnitOfWork, RepositoryRegistry};
+use diesel_async::scoped_futures::ScopedFutureExt;
+use crate::Application::ports::driven::repositories::{
+    LogicalEntityQueryRepository,
+    AttributeQueryRepository,
+    DataTypeQueryRepository,
+    SchemaVersionQueryRepository,
+    AttributeChanges,
+    AttributeDto,
+    SchemaVersionDto,
+};
+use crate::Application::use_cases::attributes::attribute_schema::{
+    load_entity_attributes, plan_value_conversion, apply_value_conversion, replace_attribute_options,
+    refresh_view_if_assigned, ensure_storage_column_change_allowed, sync_physical_table,
+};
+use crate::Application::use_cases::records::record_values::map_uow_error;
+use crate::Domain::data_types::DataTypeStorage;
+use crate::Domain::records::StorageColumn;
+use crate::Domain::schema_versions::AttributeSnapshot;
+use crate::Domain::views::ViewRepository;
+use super::find_schema_version::load_version;
+
+#[async_trait]
+pub trait RollbackSchemaVersionUseCase: Send + Sync {
+    /// Restaura los atributos de la entidad a la definición de una versión anterior.
+    /// Los atributos posteriores a esa versión se retiran (sus valores se conservan)
+    /// y el resultado se registra como una versión nueva, que se devuelve.
+    async fn execute(&self, entity_id: Uuid, version: i32, updated_by: Uuid) -> Result<SchemaVersionDto, ApplicationError>;
+}
+
+pub struct RollbackSchemaVersionUseCaseImpl {
+    le_query_repository: Arc<dyn LogicalEntityQueryRepository>,
+    attribute_query_repository: Arc<dyn AttributeQueryRepository>,
+    data_type_query_repository: Arc<dyn DataTypeQueryRepository>,
+    schema_version_query_repository: Arc<dyn SchemaVersionQueryRepository>,
+    view_repository: Arc<dyn ViewRepository>,
+    uow: Arc<dyn UnitOfWork>,
+}
+
+impl RollbackSchemaVersionUseCaseImpl {
+    pub fn new(
+        le_query_repository: Arc<dyn LogicalEntityQueryRepository>,
+        attribute_query_repository: Arc<dyn AttributeQueryRepository>,
+        data_type_query_repository: Arc<dyn DataTypeQueryRepository>,
+        schema_version_query_repository: Arc<dyn SchemaVersionQueryRepository>,
+        view_repository: Arc<dyn ViewRepository>,
+        uow: Arc<dyn UnitOfWork>,
+    ) -> Self {
+        Self {
+            le_query_repository,
+            attribute_query_repository,
+            data_type_query_repository,
+            schema_version_query_repository,
+            view_repository,
+            uow,
+        }
+    }
+}
+
+/// Paso de restauración de un atributo de la versión de destino.
+struct RestoreStep {
+    target: AttributeSnapshot,
+    current: Option<AttributeDto>,
+    /// (columna actual, tipo de destino) cuando cambia el tipo de dato.
+    retype: Option<(StorageColumn, DataTypeStorage)>,
+}
+
+/// Definición de almacenamiento del tipo de dato de un atributo de la versión,
+/// según el registro actual. El tipo debe seguir existiendo.
+async fn target_storage(
+    data_type_query_repository: &dyn DataTypeQueryRepository,
+    snapshot: &AttributeSnapshot,
+) -> Result<DataTypeStorage, ApplicationError> {
+    data_type_query_repository
+        .find_by_id(snapshot.data_type_id)
+        .await
+        .map_err(|e| ApplicationError::InfrastructureError(format!("Error al buscar tipo de dato '{}': {}", snapshot.data_type_name, e)))?
+        .map(|dt| dt.storage)
+        .ok_or_else(|| ApplicationError::ValidationError(format!(
+            "El tipo de dato '{}' del atributo '{}' ya no existe en el registro",
+            snapshot.data_type_name, snapshot.name
+        )))
+}
+
+fn full_changes(target: &AttributeSnapshot, name: Option<String>) -> AttributeChanges {
+    AttributeChanges {
+        name,
+        description: Some(target.description.clone()),
+        data_type_id: Some(target.data_type_id),
+        is_required: Some(target.is_required),
+        position: Some(target.position),
+        is_unique: Some(target.is_unique),
+        default_value: Some(target.default_value.clone()),
+        validation_regex: Some(target.validation_regex.clone()),
+        show_option_label: Some(target.show_option_label),
+        is_searchable: Some(target.is_searchable),
+        reference: Some(target.reference.clone()),
+        status: Some(target.status),
+    }
+}
+
+#[async_trait]
+impl RollbackSchemaVersionUseCase for RollbackSchemaVersionUseCaseImpl {
+    async fn execute(&self, entity_id: Uuid, version: i32, updated_by: Uuid) -> Result<SchemaVersionDto, ApplicationError> {
+        info!("Ejecutando caso de uso RollbackSchemaVersion: entity_id='{}', version={}", entity_id, version);
+
+        // 1. Definición actual y de destino
+        let (entity, attributes) = load_entity_attributes(
+            self.le_query_repository.as_ref(),
+            self.attribute_query_repository.as_ref(),
+            entity_id,
+        ).await?;
+        let target = load_version(self.schema_version_query_repository.as_ref(), entity_id, version).await?;
+
+        let mut current_by_id: HashMap<Uuid, AttributeDto> = attributes.into_iter().map(|a| (a.id, a)).collect();
+        let mut steps = Vec::with_capacity(target.definition.attributes.len());
+        for snapshot in &target.definition.attributes {
+            let current = current_by_id.remove(&snapshot.attribute_id);
+            let retype = match &current {
+                Some(c) if c.data_type_id == snapshot.data_type_id => None,
+                Some(c) => {
+                    let to_storage = target_storage(self.data_type_query_repository.as_ref(), snapshot).await?;
+                    // Igual que en el cambio de tipo: los valores no se validan como registros referenciados
+                    if to_storage.is_reference() && !c.storage.is_reference() {
+                        return Err(ApplicationError::ValidationError(format!(
+                            "El atributo '{}' no puede volver a ser una referencia: sus valores actuales no son registros de la entidad referenciada",
+                            c.name
+                        )));
+                    }
+                    ensure_storage_column_change_allowed(&entity, c, to_storage.column)?;
+                    Some((c.storage.column, to_storage))
+                },
+                None => {
+                    target_storage(self.data_type_query_repository.as_ref(), snapshot).await?;
+                    None
+                },
+            };
+            // La entidad referenciada debe seguir existiendo
+            if let Some(reference) = snapshot.reference.as_ref().filter(|r| r.entity_id != entity_id) {
+                let exists = self.le_query_repository
+                    .find_by_id(reference.entity_id)
+                    .await
+                    .map_err(|e| ApplicationError::InfrastructureError(format!("Error al buscar entidad lógica: {}", e)))?
+                    .is_some();
+                if !exists {
+                    return Err(ApplicationError::ValidationError(format!(
+                        "La entidad {} referenciada por '{}' ya no existe", reference.entity_id, snapshot.name
+                    )));
+                }
+            }
+            steps.push(RestoreStep { target: snapshot.clone(), current, retype });
+        }
+        // Lo que queda son atributos creados después de la versión de destino
+        let newer: Vec<AttributeDto> = current_by_id.into_values().collect();
+        for step in &steps {
+            if let Some(other) = newer.iter().find(|a| a.name == step.target.name) {
+                return Err(ApplicationError::Conflict(format!(
+                    "El atributo '{}' ({}) creado después de la versión {} usa el nombre '{}'; renómbrelo antes de restaurar",
+                    other.name, other.id, version, step.target.name
+                )));
+            }
+        }
+
+        // 2. Restaurar dentro de una transacción
+        let new_version = self.uow.execute(move |registry: &mut dyn RepositoryRegistry| async move {
+            let attribute_cmd_repo = registry.attribute_command_repository();
+            let record_cmd_repo = registry.record_command_repository();
+            let option_cmd_repo = registry.attribute_option_command_repository();
+            let schema_version_repo = registry.schema_version_command_repository();
+            let conn = registry.get_diesel_async_conn();
+
+            // Nombres temporales para los renombrados: evita choques con UNIQUE(entity_id, name)
+            // cuando dos atributos intercambian sus nombres.
+            for step in &steps {
+                let Some(current) = step.current.as_ref().filter(|c| c.name != step.target.name) else { continue };
+                let changes = AttributeChanges {
+                    name: Some(format!("{}__rollback_{}", current.name, current.id.simple())),
+                    ..Default::default()
+                };
+                attribute_cmd_repo.update(conn, entity_id, current.id, &changes, updated_by).await
+                    .map_err(|e| anyhow!("Failed to rename attribute {}: {}", current.id, e))?;
+            }
+
+            for step in &steps {
+                let target = &step.target;
+                match &step.current {
+                    Some(current) => {
+                        if let Some((from_column, to_storage)) = &step.retype {
+                            let conversion = plan_value_conversion(
+                                record_cmd_repo, conn, current.id, *from_column, to_storage, &target.options,
+                            ).await?;
+                            if conversion.failed > 0 {
+                                return Err(anyhow!(ApplicationError::ValidationError(format!(
+                                    "{} valores de '{}' no se pueden convertir a '{}'; conviértalos o descártelos antes de restaurar",
+                                    conversion.failed, current.name, target.data_type_name
+                                ))));
+                            }
+                            apply_value_conversion(
+                                record_cmd_repo, conn, current.id, *from_column, to_storage, &target.options,
+                            ).await?;
+                        }
+                        let name = (current.name != target.name).then(|| target.name.clone());
+                        attribute_cmd_repo.update(conn, entity_id, current.id, &full_changes(target, name), updated_by).await
+                            .map_err(|e| anyhow!("Failed to restore attribute {}: {}", current.id, e))?;
+
+                        if current.options != target.options {
+                            if step.retype.is_none() && current.storage.is_enumeration() {
+                                replace_attribute_options(option_cmd_repo, conn, current, &target.options).await?;
+                            } else {
+                                // Con cambio de tipo los valores ya se han comprobado contra las opciones
+                                // de destino; si deja de ser una enumeración, los códigos se conservan como texto
+                                option_cmd_repo.replace_all(conn, current.id, &target.options).await
+                                    .map_err(|e| anyhow!("Failed to restore options of attribute {}: {}", current.id, e))?;
+                            }
+                        }
+                    },
+                    None => {
+                        // El atributo ya no existe: se vuelve a crear (con un ID nuevo)
+                        let attribute_id = attribute_cmd_repo.create(
+                            conn,
+                            entity_id,
+                            target.data_type_id,
+                            &target.name,
+                            target.description.as_deref(),
+                            target.is_required,
+                            target.position,
+                            target.is_unique,
+                            target.default_value.as_deref(),
+                            target.validation_regex.as_deref(),
+                            target.show_option_label,
+                            target.is_searchable,
+                            target.reference.as_ref(),
+                            updated_by,
+                        ).await.map_err(|e| anyhow!("Failed to recreate attribute '{}': {}", target.name, e))?;
+                        if !target.options.is_empty() {
+                            option_cmd_repo.replace_all(conn, attribute_id, &target.options).await
+                                .map_err(|e| anyhow!("Failed to restore options of attribute '{}': {}", target.name, e))?;
+                        }
+                        if target.status != 1 {
+                            let changes = AttributeChanges { status: Some(target.status), ..Default::default() };
+                            attribute_cmd_repo.update(conn, entity_id, attribute_id, &changes, updated_by).await
+                                .map_err(|e| anyhow!("Failed to restore status of attribute '{}': {}", target.name, e))?;
+                        }
+                        debug!("Atributo '{}' recreado con ID {}", target.name, attribute_id);
+                    },
+                }
+            }
+
+            for attribute in newer.iter().filter(|a| a.status != 0) {
+                let changes = AttributeChanges { status: Some(0), ..Default::default() };
+                attribute_cmd_repo.update(conn, entity_id, attribute.id, &changes, updated_by).await
+                    .map_err(|e| anyhow!("Failed to retire attribute {}: {}", attribute.id, e))?;
+            }
+
+            sync_physical_table(record_cmd_repo, conn, entity_id).await?;
+
+            let summary = format!("Restauración de la versión {}", version);
+            let new_version = schema_version_repo.create_snapshot(conn, entity_id, Some(&summary), updated_by).await
+                .map_err(|e| anyhow!("Failed to record schema version of entity {}: {}", entity_id, e))?;
+            Ok(new_version)
+        }.scope_boxed()).await.map_err(|e| map_uow_error(e, "restauración de versión"))?;
+        info!("Entidad {} restaurada a la versión {} (nueva versión {})", entity_id, version, new_version);
+
+        // 3. Regenerar la vista con la definición restaurada
+        refresh_view_if_assigned(
+            self.le_query_repository.as_ref(),
+            self.attribute_query_repository.as_ref(),
+            self.view_repository.as_ref(),
+            self.uow.as_ref(),
+            &entity,
+        ).await?;
+
+        load_version(self.schema_version_query_repository.as_ref(), entity_id, new_version).await
+    }
+}
@@ -0,0 +1,184 @@
+// src/Application/use_cases/test_support.rs
+//
+// Dobles de prueba compartidos por las pruebas de los casos de uso. Los repositorios
+// solo responden a las consultas que los casos de uso hacen antes de la UoW.
+
+use std::error::Error;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use anyhow::anyhow;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use futures::stream::BoxStream;
+use uuid::Uuid;
+
+use crate::Application::errors::application_error::ApplicationError;
+use crate::Application::ports::unit_of_work::{UnitOfWork, UnitOfWorkTask};
+use crate::Application::ports::driven::repositories::{
+    LogicalEntityQueryRepository,
+    AttributeQueryRepository,
+    RecordQueryRepository,
+    LogicalEntityDto,
+    DeletedLogicalEntityDto,
+    AttributeDto,
+    RecordDto,
+    KeyedRecordDto,
+    DeletedRecordDto,
+    RecordSearchHitDto,
+    ExportRecordDto,
+    RecordHistoryEntryDto,
+};
+use crate::Domain::errors::DomainError;
+use crate::Domain::record_queries::{RecordQuery, RecordSearch, ResolvedFilter, SortKey};
+use crate::Domain::records::StorageColumn;
+use crate::Domain::views::ViewRepository;
+use crate::Domain::views::materialized::{EntityViewSettings, MaterializedViewCandidate, ViewRefreshState};
+
+type RepoResult<T> = Result<T, Box<dyn Error + Send + Sync>>;
+
+/// UoW sin base de datos: no ejecuta la tarea y devuelve el error que habría producido
+/// dentro de la transacción, tal como lo haría la UoW real.
+pub(crate) struct FailingUnitOfWork {
+    error: ApplicationError,
+    calls: AtomicUsize,
+}
+
+impl FailingUnitOfWork {
+    pub(crate) fn new(error: ApplicationError) -> Self {
+        Self { error, calls: AtomicUsize::new(0) }
+    }
+
+    /// Número de transacciones iniciadas.
+    pub(crate) fn calls(&self) -> usize {
+        self.calls.load(Ordering::SeqCst)
+    }
+}
+
+#[async_trait]
+impl UnitOfWork for FailingUnitOfWork {
+    async fn run<'a>(&'a self, _task: UnitOfWorkTask<'a>) -> anyhow::Result<()> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        Err(anyhow!(self.error.clone()))
+    }
+}
+
+/// Repositorio de entidades con una sola entidad.
+pub(crate) struct EntityQueryStub {
+    pub entity: LogicalEntityDto,
+}
+
+#[async_trait]
+impl LogicalEntityQueryRepository for EntityQueryStub {
+    async fn exists_by_name(&self, name: &str) -> RepoResult<bool> {
+        Ok(self.entity.name == name)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> RepoResult<Option<LogicalEntityDto>> {
+        Ok(Some(self.entity.clone()).filter(|entity| entity.id == id))
+    }
+
+    async fn find_by_name(&self, name: &str) -> RepoResult<Option<LogicalEntityDto>> {
+        Ok(Some(self.entity.clone()).filter(|entity| entity.name == name))
+    }
+
+    async fn find_all(&self, _limit: i64, _offset: i64) -> RepoResult<Vec<LogicalEntityDto>> { unimplemented!() }
+    async fn count_all(&self) -> RepoResult<i64> { unimplemented!() }
+    async fn metadata_fingerprint(&self) -> RepoResult<String> { unimplemented!() }
+    async fn find_deleted(&self, _limit: i64, _offset: i64) -> RepoResult<Vec<DeletedLogicalEntityDto>> { unimplemented!() }
+    async fn count_deleted(&self) -> RepoResult<i64> { unimplemented!() }
+    async fn find_deleted_by_id(&self, _id: Uuid) -> RepoResult<Option<DeletedLogicalEntityDto>> { unimplemented!() }
+    async fn find_deleted_before(&self, _deleted_before: DateTime<Utc>) -> RepoResult<Vec<Uuid>> { unimplemented!() }
+}
+
+/// Repositorio de atributos con una lista fija.
+pub(crate) struct AttributeQueryStub {
+    pub attributes: Vec<AttributeDto>,
+}
+
+#[async_trait]
+impl AttributeQueryRepository for AttributeQueryStub {
+    async fn find_by_entity_id(&self, entity_id: Uuid) -> RepoResult<Vec<AttributeDto>> {
+        Ok(self.attributes.iter().filter(|a| a.entity_id == entity_id).cloned().collect())
+    }
+
+    async fn find_by_entity_ids(&self, entity_ids: &[Uuid]) -> RepoResult<Vec<AttributeDto>> {
+        Ok(self.attributes.iter().filter(|a| entity_ids.contains(&a.entity_id)).cloned().collect())
+    }
+
+    async fn find_referencing(&self, _entity_id: Uuid) -> RepoResult<Vec<AttributeDto>> { unimplemented!() }
+}
+
+/// Repositorio de registros con un solo registro.
+pub(crate) struct RecordQueryStub {
+    pub record: RecordDto,
+}
+
+#[async_trait]
+impl RecordQueryRepository for RecordQueryStub {
+    async fn find_by_id(&self, entity_id: Uuid, id: Uuid) -> RepoResult<Option<RecordDto>> {
+        Ok(Some(self.record.clone()).filter(|record| record.entity_id == entity_id && record.id == id))
+    }
+
+    async fn find_by_ids(&self, _entity_id: Uuid, _ids: &[Uuid]) -> RepoResult<Vec<RecordDto>> { unimplemented!() }
+    async fn find_page(&self, _entity_id: Uuid, _query: &RecordQuery) -> RepoResult<Vec<KeyedRecordDto>> { unimplemented!() }
+    async fn search(&self, _entity_id: Uuid, _search: &RecordSearch) -> RepoResult<Vec<RecordSearchHitDto>> { unimplemented!() }
+
+    fn export(
+        &self,
+        _entity_id: Uuid,
+        _filter: Option<&ResolvedFilter>,
+        _sort: &[SortKey],
+        _batch_size: usize
+    ) -> BoxStream<'static, RepoResult<Vec<ExportRecordDto>>> {
+        unimplemented!()
+    }
+
+    async fn count_by_entity(&self, _entity_id: Uuid) -> RepoResult<i64> { unimplemented!() }
+    async fn count_missing_values(&self, _entity_id: Uuid, _attribute_id: Uuid, _column: StorageColumn) -> RepoResult<i64> { unimplemented!() }
+
+    async fn count_pattern_mismatches(
+        &self,
+        _entity_id: Uuid,
+        _attribute_id: Uuid,
+        _column: StorageColumn,
+        _pattern: &str
+    ) -> RepoResult<Option<i64>> {
+        unimplemented!()
+    }
+
+    async fn count_duplicated_values(&self, _entity_id: Uuid, _key: &[(Uuid, StorageColumn)]) -> RepoResult<i64> { unimplemented!() }
+    async fn find_deleted(&self, _entity_id: Uuid, _limit: i64, _offset: i64) -> RepoResult<Vec<DeletedRecordDto>> { unimplemented!() }
+    async fn count_deleted(&self, _entity_id: Uuid) -> RepoResult<i64> { unimplemented!() }
+    async fn find_deleted_by_id(&self, _entity_id: Uuid, _id: Uuid) -> RepoResult<Option<DeletedRecordDto>> { unimplemented!() }
+    async fn find_deleted_before(&self, _deleted_before: DateTime<Utc>) -> RepoResult<Vec<(Uuid, Uuid)>> { unimplemented!() }
+
+    async fn find_history(
+        &self,
+        _entity_id: Uuid,
+        _tuple_id: Uuid,
+        _limit: i64,
+        _before_id: Option<i64>
+    ) -> RepoResult<Vec<RecordHistoryEntryDto>> {
+        unimplemented!()
+    }
+
+    async fn find_history_since(&self, _entity_id: Uuid, _tuple_id: Uuid, _since: DateTime<Utc>) -> RepoResult<Vec<RecordHistoryEntryDto>> {
+        unimplemented!()
+    }
+}
+
+/// Repositorio de vistas para los casos en que no debe tocarse ninguna vista: cualquier
+/// llamada hace fallar la prueba.
+pub(crate) struct UnusedViewRepository;
+
+#[async_trait]
+impl ViewRepository for UnusedViewRepository {
+    async fn create_or_replace_view(&self, _view_name: &str, _view_sql: &str) -> Result<(), DomainError> { unimplemented!() }
+    async fn create_materialized_view(&self, _view_name: &str, _view_sql: &str, _index_sql: &str) -> Result<(), DomainError> { unimplemented!() }
+    async fn refresh_materialized_view(&self, _view_name: &str) -> Result<(), DomainError> { unimplemented!() }
+    async fn drop_view(&self, _view_name: &str) -> Result<(), DomainError> { unimplemented!() }
+    async fn find_view_settings(&self, _entity_id: Uuid) -> Result<(EntityViewSettings, ViewRefreshState), DomainError> { unimplemented!() }
+    async fn save_view_settings(&self, _entity_id: Uuid, _settings: &EntityViewSettings) -> Result<(), DomainError> { unimplemented!() }
+    async fn mark_refresh_started(&self, _entity_id: Uuid) -> Result<(), DomainError> { unimplemented!() }
+    async fn mark_refresh_finished(&self, _entity_id: Uuid, _error: Option<&str>) -> Result<(), DomainError> { unimplemented!() }
+    async fn find_materialized_views(&self) -> Result<Vec<MaterializedViewCandidate>, DomainError> { unimplemented!() }
+}
@@ -5,6 +5,7 @@ use crate::Application::dtos::create_user_dto::CreateUserDto;
 use crate::Application::dtos::update_user_dto::UpdateUserDto;
 use crate::Application::dtos::auth_dto::{LoginDto, TokenDto};
 use crate::Application::errors::application_error::ApplicationError;
+use crate::Domain::concurrency::VersionPrecondition;
 
 #[async_trait]
 pub trait CreateUserUseCase: Send + Sync {
@@ -28,12 +29,12 @@ pub trait FindAllUsersUseCase: Send + Sync {
 
 #[async_trait]
 pub trait UpdateUserUseCase: Send + Sync {
-    async fn execute(&self, id: Uuid, dto: UpdateUserDto, updated_by: Option<Uuid>) -> Result<UserResponseDto, ApplicationError>;
+    async fn execute(&self, id: Uuid, dto: UpdateUserDto, updated_by: Option<Uuid>, precondition: Option<VersionPrecondition>) -> Result<UserResponseDto, ApplicationError>;
 }
 
 #[async_trait]
 pub trait DeleteUserUseCase: Send + Sync {
-    async fn execute(&self, id: Uuid) -> Result<(), ApplicationError>;
+    async fn execute(&self, id: Uuid, deleted_by: Uuid, precondition: Option<VersionPrecondition>) -> Result<(), ApplicationError>;
 }
 
 
@@ -0,0 +1,3 @@
+pub mod purge_trash;
+
+pub use purge_trash::{PurgeTrashUseCase, PurgeTrashUseCaseImpl};
@@ -0,0 +1,116 @@
+// src/Application/use_cases/trash/purge_trash.rs
+//
+// Purga por antigüedad de toda la papelera (registros, entidades lógicas y usuarios).
+// Cada elemento se purga en su propia transacción: los que no se pueden borrar
+// (p. ej. un registro vivo los referencia con `restrict`) se omiten y se informan.
+
+use async_trait::async_trait;
+use std::sync::Arc;
+use chrono::Utc;
+use uuid::Uuid;
+use log::{info, warn};
+
+use crate::Application::dtos::trash_dto::{PurgeReportDto, PurgeSkipDto};
+use crate::Domain::record_history::ChangeContext;
+use crate::Application::errors::application_error::ApplicationError;
+use crate::Application::ports::unit_of_work::UnitOfWork;
+use crate::Application::ports::driven::repositories::{
+    LogicalEntityQueryRepository,
+    RecordQueryRepository,
+    UserQueryRepository,
+};
+use crate::Application::use_cases::records::trash_records::purge_record;
+use crate::Application::use_cases::logical_entities::trash_logical_entities::purge_logical_entity;
+use crate::Application::use_cases::user::trash::purge_user;
+use crate::Domain::errors::DomainError;
+use crate::Domain::trash::{purge_cutoff, TrashKind};
+
+#[async_trait]
+pub trait PurgeTrashUseCase: Send + Sync {
+    /// Elimina definitivamente los elementos que llevan en la papelera más de
+    /// `older_than_days` días (0 = toda la papelera). `context` se guarda en el
+    /// historial de los registros purgados.
+    async fn execute(&self, older_than_days: i64, context: ChangeContext) -> Result<PurgeReportDto, ApplicationError>;
+}
+
+pub struct PurgeTrashUseCaseImpl {
+    le_query_repository: Arc<dyn LogicalEntityQueryRepository>,
+    record_query_repository: Arc<dyn RecordQueryRepository>,
+    user_query_repository: Arc<dyn UserQueryRepository>,
+    uow: Arc<dyn UnitOfWork>,
+}
+
+impl PurgeTrashUseCaseImpl {
+    pub fn new(
+        le_query_repository: Arc<dyn LogicalEntityQueryRepository>,
+        record_query_repository: Arc<dyn RecordQueryRepository>,
+        user_query_repository: Arc<dyn UserQueryRepository>,
+        uow: Arc<dyn UnitOfWork>,
+    ) -> Self {
+        Self { le_query_repository, record_query_repository, user_query_repository, uow }
+    }
+}
+
+/// Anota el resultado de purgar un elemento: suma los eliminados o lo añade a los omitidos.
+fn tally(result: Result<usize, ApplicationError>, kind: TrashKind, id: Uuid, count: &mut usize, skipped: &mut Vec<PurgeSkipDto>) {
+    match result {
+        Ok(purged) => *count += purged,
+        Err(e) => {
+            warn!("No se pudo purgar {} {}: {}", kind.as_str(), id, e);
+            let reason = match e {
+                ApplicationError::Conflict(message) => message,
+                other => other.to_string(),
+            };
+            skipped.push(PurgeSkipDto { kind, id, reason });
+        },
+    }
+}
+
+#[async_trait]
+impl PurgeTrashUseCase for PurgeTrashUseCaseImpl {
+    async fn execute(&self, older_than_days: i64, context: ChangeContext) -> Result<PurgeReportDto, ApplicationError> {
+        let deleted_before = purge_cutoff(Utc::now(), older_than_days).map_err(|e| match e {
+            DomainError::ValidationError(message) => ApplicationError::ValidationError(message),
+            other => ApplicationError::ValidationError(other.to_string()),
+        })?;
+        info!("Ejecutando caso de uso PurgeTrash: eliminados antes de {}", deleted_before);
+
+        let mut report = PurgeReportDto { deleted_before, records: 0, logical_entities: 0, users: 0, skipped: Vec::new() };
+
+        // 1. Registros (los eliminados en cascada junto a otro se purgan con él)
+        let records = self.record_query_repository
+            .find_deleted_before(deleted_before)
+            .await
+            .map_err(|e| ApplicationError::InfrastructureError(format!("Error al listar la papelera de registros: {}", e)))?;
+        for (entity_id, id) in records {
+            let result = purge_record(self.uow.as_ref(), entity_id, id, context.clone()).await;
+            tally(result, TrashKind::Record, id, &mut report.records, &mut report.skipped);
+        }
+
+        // 2. Entidades lógicas (con sus atributos y registros)
+        let entities = self.le_query_repository
+            .find_deleted_before(deleted_before)
+            .await
+            .map_err(|e| ApplicationError::InfrastructureError(format!("Error al listar la papelera de entidades: {}", e)))?;
+        for id in entities {
+            let result = purge_logical_entity(self.uow.as_ref(), id).await;
+            tally(result, TrashKind::LogicalEntity, id, &mut report.logical_entities, &mut report.skipped);
+        }
+
+        // 3. Usuarios (las referencias de auditoría quedan en NULL)
+        let users = self.user_query_repository
+            .find_deleted_before(deleted_before)
+            .await
+            .map_err(|e| ApplicationError::InfrastructureError(format!("Error al listar la papelera de usuarios: {}", e)))?;
+        for id in users {
+            let result = purge_user(self.uow.as_ref(), id).await;
+            tally(result, TrashKind::User, id, &mut report.users, &mut report.skipped);
+        }
+
+        info!(
+            "Papelera purgada: {} registros, {} entidades lógicas, {} usuarios ({} omitidos)",
+            report.records, report.logical_entities, report.users, report.skipped.len()
+        );
+        Ok(report)
+    }
+}
@@ -17,6 +17,7 @@ use crate::Application::ports::driven::repositories::{
 };
 use crate::Application::ports::driven::AuthServicePort;
 use crate::Application::ports::unit_of_work::{UnitOfWork, RepositoryRegistry};
+use diesel_async::scoped_futures::ScopedFutureExt;
 use crate::Application::validators::user_validator::UserValidator;
 use crate::Domain::entities::user::User;
 
@@ -109,7 +110,7 @@ impl CreateUserUseCase for CreateUserUseCaseImpl {
                     .context("Failed to create user within Unit of Work")?;
                 debug!("Dentro de UoW: Usuario creado en BD con ID: {}", result.id);
                 Ok(result)
-            }
+            }.scope_boxed()
         }).await.map_err(|e| ApplicationError::UnitOfWorkError(format!("Unit of Work execution failed: {}", e)))?;
         info!("Unidad de Trabajo completada. Usuario creado con ID: {}", created_user.id);
 
@@ -14,6 +14,7 @@ use crate::Application::mappers::user_mapper::UserMapper;
 use crate::Application::ports::driven::repositories::{UserQueryRepository, UserCommandRepository};
 use crate::Application::ports::driven::AuthServicePort;
 use crate::Application::ports::unit_of_work::{UnitOfWork, RepositoryRegistry};
+use diesel_async::scoped_futures::ScopedFutureExt;
 use crate::Domain::entities::user::User;
 use crate::Application::validators::user_validator::UserValidator;
 
@@ -117,7 +118,7 @@ impl CreateUserWithPreferencesUseCaseImpl {
                 if !prefs_to_create.is_empty() { /* ... lógica placeholder ... */ }
 
                 Ok(created_user_in_tx)
-            }
+            }.scope_boxed()
         }).await.map_err(|e| ApplicationError::UnitOfWorkError(format!("Unit of Work execution failed: {}", e)))?;
         info!("Unidad de Trabajo completada. Usuario creado con ID: {}", created_user.id);
 
@@ -3,7 +3,10 @@ use crate::Application::errors::application_error::ApplicationError;
 use crate::Application::ports::driven::repositories::UserCommandRepository;
 // --- UoW ---
 use crate::Application::ports::unit_of_work::{UnitOfWork, RepositoryRegistry};
+use diesel_async::scoped_futures::ScopedFutureExt;
 // --------------------
+use crate::Domain::concurrency::VersionPrecondition;
+use super::update::check_user_version;
 use std::sync::Arc;
 use uuid::Uuid;
 use async_trait::async_trait;
@@ -13,7 +16,8 @@ use log::{info, debug, error}; // Añadir logs
 // --- Trait del Caso de Uso (si existe en traits/delete.rs) ---
 #[async_trait]
 pub trait DeleteUserUseCase: Send + Sync {
-    async fn execute(&self, id: Uuid) -> Result<(), ApplicationError>;
+    /// Mueve el usuario a la papelera. Con `precondition` (If-Match), solo si su versión coincide.
+    async fn execute(&self, id: Uuid, deleted_by: Uuid, precondition: Option<VersionPrecondition>) -> Result<(), ApplicationError>;
 }
 // -----------------------------------------------------------
 
@@ -40,7 +44,7 @@ impl DeleteUserUseCaseImpl {
     }
 
     // Mover lógica principal aquí
-    pub async fn execute(&self, id: Uuid) -> Result<(), ApplicationError> {
+    pub async fn execute(&self, id: Uuid, deleted_by: Uuid, precondition: Option<VersionPrecondition>) -> Result<(), ApplicationError> {
         info!("Ejecutando caso de uso DeleteUser: id='{}'", id);
 
         // --- Ejecutar dentro de UoW ---
@@ -49,17 +53,25 @@ impl DeleteUserUseCaseImpl {
             let cmd_repo = registry.user_command_repository();
             let conn = registry.get_diesel_async_conn();
 
-            // Llamar al método delete pasando la conexión.
+            if let Some(precondition) = &precondition {
+                check_user_version(cmd_repo, conn, id, Some(precondition), None).await?;
+            }
+
+            // Llamar al método delete pasando la conexión (eliminación lógica).
             // El repo devuelve Result<()>, que incluye error si no se encuentra.
-            cmd_repo.delete(conn, id).await
+            cmd_repo.delete(conn, id, deleted_by).await
                 .context(format!("Failed to delete user {} within Unit of Work", id))?; // Usar Result de anyhow
 
-            debug!("Usuario eliminado en BD (dentro de UoW): {}", id);
+            debug!("Usuario movido a la papelera (dentro de UoW): {}", id);
             Ok(()) // Devolver Ok(()) si la operación fue exitosa dentro de la UoW
-        })
+        }.scope_boxed())
         .await // Esperar a que la UoW termine
         .map_err(|e| { // Mapear error de UoW a ApplicationError
             error!("Error durante la Unidad de Trabajo al eliminar usuario {}: {:?}", id, e);
+            let e = match e.downcast::<ApplicationError>() {
+                Ok(app_err) => return app_err,
+                Err(e) => e,
+            };
             // Intentar detectar si el error original fue "NotFound"
             if e.to_string().contains("no encontrado para eliminar") {
                  ApplicationError::NotFound(format!("Usuario con ID {} no encontrado para eliminar", id))
@@ -77,7 +89,7 @@ impl DeleteUserUseCaseImpl {
 // Implementar el trait (si existe)
 #[async_trait]
 impl crate::Application::use_cases::traits::DeleteUserUseCase for DeleteUserUseCaseImpl {
-    async fn execute(&self, id: Uuid) -> Result<(), ApplicationError> {
-        self.execute(id).await
+    async fn execute(&self, id: Uuid, deleted_by: Uuid, precondition: Option<VersionPrecondition>) -> Result<(), ApplicationError> {
+        self.execute(id, deleted_by, precondition).await
     }
 }
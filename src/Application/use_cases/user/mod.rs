@@ -7,6 +7,7 @@ pub mod delete;
 pub mod login;
 pub mod create_with_preferences;
 pub mod find_by_username_optimized;
+pub mod trash;
 
 
 pub use create::CreateUserUseCase;
@@ -18,3 +19,4 @@ pub use delete::DeleteUserUseCase;
 pub use login::LoginUseCase;
 pub use create_with_preferences::CreateUserWithPreferencesUseCase;
 pub use find_by_username_optimized::FindUserByUsernameOptimizedUseCase;
+pub use trash::{ListDeletedUsersUseCase, RestoreUserUseCase, PurgeUserUseCase};
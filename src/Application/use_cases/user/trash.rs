@@ -0,0 +1,158 @@
+// src/Application/use_cases/user/trash.rs
+//
+// Papelera de usuarios: listado, restauración y purga de los usuarios eliminados.
+
+use async_trait::async_trait;
+use std::sync::Arc;
+use uuid::Uuid;
+use log::info;
+use anyhow::anyhow;
+
+use crate::Application::dtos::trash_dto::TrashPageDto;
+use crate::Application::dtos::user_dto::UserResponseDto;
+use crate::Application::errors::application_error::ApplicationError;
+use crate::Application::mappers::user_mapper::UserMapper;
+use crate::Application::ports::driven::repositories::{UserQueryRepository, DeletedUserDto};
+use crate::Application::ports::unit_of_work::{UnitOfWork, RepositoryRegistry};
+use diesel_async::scoped_futures::ScopedFutureExt;
+use crate::Application::use_cases::records::record_values::map_uow_error;
+use crate::Application::use_cases::logical_entities::list_logical_entities::page_offset;
+
+/// Tamaño máximo de página del listado de la papelera de usuarios.
+pub const MAX_PAGE_SIZE: i64 = 100;
+
+// --- Traits de los Casos de Uso ---
+#[async_trait]
+pub trait ListDeletedUsersUseCase: Send + Sync {
+    /// `page` empieza en 1.
+    async fn execute(&self, page: i64, page_size: i64) -> Result<TrashPageDto<DeletedUserDto>, ApplicationError>;
+}
+
+#[async_trait]
+pub trait RestoreUserUseCase: Send + Sync {
+    async fn execute(&self, id: Uuid) -> Result<UserResponseDto, ApplicationError>;
+}
+
+#[async_trait]
+pub trait PurgeUserUseCase: Send + Sync {
+    /// Elimina definitivamente un usuario de la papelera.
+    async fn execute(&self, id: Uuid) -> Result<(), ApplicationError>;
+}
+
+/// Purga un usuario de la papelera en su propia transacción. Compartido con la
+/// purga por antigüedad. Devuelve 0 si el usuario ya no está en la papelera.
+pub(crate) async fn purge_user(uow: &dyn UnitOfWork, id: Uuid) -> Result<usize, ApplicationError> {
+    uow.execute(move |registry: &mut dyn RepositoryRegistry| async move {
+        let cmd_repo = registry.user_command_repository();
+        let conn = registry.get_diesel_async_conn();
+
+        cmd_repo.purge(conn, id).await
+            .map_err(|e| anyhow!("Failed to purge user {}: {}", id, e))
+    }.scope_boxed()).await.map_err(|e| map_uow_error(e, "purgar usuario"))
+}
+
+// --- Implementación: listado de la papelera ---
+pub struct ListDeletedUsersUseCaseImpl {
+    user_query_repository: Arc<dyn UserQueryRepository>,
+}
+
+impl ListDeletedUsersUseCaseImpl {
+    pub fn new(user_query_repository: Arc<dyn UserQueryRepository>) -> Self {
+        Self { user_query_repository }
+    }
+}
+
+#[async_trait]
+impl ListDeletedUsersUseCase for ListDeletedUsersUseCaseImpl {
+    async fn execute(&self, page: i64, page_size: i64) -> Result<TrashPageDto<DeletedUserDto>, ApplicationError> {
+        if page < 1 {
+            return Err(ApplicationError::ValidationError("page debe ser mayor o igual a 1".to_string()));
+        }
+        if page_size < 1 || page_size > MAX_PAGE_SIZE {
+            return Err(ApplicationError::ValidationError(format!("page_size debe estar entre 1 y {}", MAX_PAGE_SIZE)));
+        }
+        info!("Ejecutando caso de uso ListDeletedUsers: page={}, page_size={}", page, page_size);
+
+        let total = self.user_query_repository
+            .count_deleted()
+            .await
+            .map_err(|e| ApplicationError::InfrastructureError(format!("Error al contar la papelera: {}", e)))?;
+
+        let items = self.user_query_repository
+            .find_deleted(page_size, page_offset(page, page_size)?)
+            .await
+            .map_err(|e| ApplicationError::InfrastructureError(format!("Error al listar la papelera: {}", e)))?;
+
+        Ok(TrashPageDto { items, page, page_size, total })
+    }
+}
+
+// --- Implementación: restauración ---
+pub struct RestoreUserUseCaseImpl {
+    user_query_repository: Arc<dyn UserQueryRepository>,
+    unit_of_work: Arc<dyn UnitOfWork>,
+    user_mapper: Arc<UserMapper>,
+}
+
+impl RestoreUserUseCaseImpl {
+    pub fn new(
+        user_query_repository: Arc<dyn UserQueryRepository>,
+        unit_of_work: Arc<dyn UnitOfWork>,
+        user_mapper: Arc<UserMapper>,
+    ) -> Self {
+        Self { user_query_repository, unit_of_work, user_mapper }
+    }
+}
+
+#[async_trait]
+impl RestoreUserUseCase for RestoreUserUseCaseImpl {
+    async fn execute(&self, id: Uuid) -> Result<UserResponseDto, ApplicationError> {
+        info!("Ejecutando caso de uso RestoreUser: id='{}'", id);
+
+        self.unit_of_work.execute(move |registry: &mut dyn RepositoryRegistry| async move {
+            let cmd_repo = registry.user_command_repository();
+            let conn = registry.get_diesel_async_conn();
+
+            let affected = cmd_repo.restore(conn, id).await
+                .map_err(|e| anyhow!("Failed to restore user {}: {}", id, e))?;
+            if affected == 0 {
+                return Err(anyhow!(ApplicationError::NotFound(format!("Usuario con ID {} no encontrado en la papelera", id))));
+            }
+            Ok(())
+        }.scope_boxed()).await.map_err(|e| map_uow_error(e, "restaurar usuario"))?;
+
+        info!("Usuario {} restaurado", id);
+
+        let user = self.user_query_repository
+            .find_by_id(id)
+            .await
+            .map_err(|e| ApplicationError::InfrastructureError(format!("Error al buscar usuario: {}", e)))?
+            .ok_or_else(|| ApplicationError::NotFound(format!("Usuario con ID {} no encontrado", id)))?;
+        Ok(self.user_mapper.to_dto(user))
+    }
+}
+
+// --- Implementación: purga de un usuario ---
+pub struct PurgeUserUseCaseImpl {
+    unit_of_work: Arc<dyn UnitOfWork>,
+}
+
+impl PurgeUserUseCaseImpl {
+    pub fn new(unit_of_work: Arc<dyn UnitOfWork>) -> Self {
+        Self { unit_of_work }
+    }
+}
+
+#[async_trait]
+impl PurgeUserUseCase for PurgeUserUseCaseImpl {
+    async fn execute(&self, id: Uuid) -> Result<(), ApplicationError> {
+        info!("Ejecutando caso de uso PurgeUser: id='{}'", id);
+
+        if purge_user(self.unit_of_work.as_ref(), id).await? == 0 {
+            return Err(ApplicationError::NotFound(format!("Usuario con ID {} no encontrado en la papelera", id)));
+        }
+
+        info!("Usuario {} purgado", id);
+        Ok(())
+    }
+}
@@ -7,9 +7,12 @@ use crate::Application::ports::driven::repositories::{UserQueryRepository, UserC
 use crate::Application::ports::driven::AuthServicePort;
 // --- UoW ---
 use crate::Application::ports::unit_of_work::{UnitOfWork, RepositoryRegistry};
+use diesel_async::scoped_futures::ScopedFutureExt;
 // --------------------
 use crate::Application::validators::user_validator::UserValidator;
 use crate::Domain::entities::user::User; // Importar entidad
+use crate::Domain::concurrency::VersionPrecondition;
+use diesel_async::AsyncPgConnection;
 use chrono::Utc;
 use uuid::Uuid;
 use std::sync::Arc;
@@ -20,10 +23,37 @@ use anyhow::{Context, anyhow}; // Añadir anyhow y Context
 // --- Trait del Caso de Uso (si existe en traits/update.rs) ---
 #[async_trait]
 pub trait UpdateUserUseCase: Send + Sync {
-    async fn execute(&self, id: Uuid, update_dto: UpdateUserDto, updated_by: Option<Uuid>) -> Result<UserResponseDto, ApplicationError>;
+    async fn execute(&self, id: Uuid, update_dto: UpdateUserDto, updated_by: Option<Uuid>, precondition: Option<VersionPrecondition>) -> Result<UserResponseDto, ApplicationError>;
 }
 // -----------------------------------------------------------
 
+/// Bloquea el usuario y comprueba su versión dentro de la UoW. `read_version` es la
+/// versión leída antes de la transacción: si cambió, otra petición lo modificó entretanto.
+pub(crate) async fn check_user_version(
+    cmd_repo: &dyn UserCommandRepository,
+    conn: &mut AsyncPgConnection,
+    id: Uuid,
+    precondition: Option<&VersionPrecondition>,
+    read_version: Option<i32>,
+) -> anyhow::Result<()> {
+    let current = cmd_repo.find_version_for_update(conn, id).await?
+        .ok_or_else(|| anyhow!(ApplicationError::NotFound(format!("Usuario con ID {} no encontrado", id))))?;
+    if let Some(precondition) = precondition {
+        if !precondition.is_met(current) {
+            warn!("If-Match no coincide con la versión {} del usuario {}", current, id);
+            return Err(anyhow!(ApplicationError::PreconditionFailed(format!(
+                "El usuario {} ha sido modificado (versión actual {})", id, current
+            ))));
+        }
+    }
+    if read_version.is_some_and(|read_version| read_version != current) {
+        return Err(anyhow!(ApplicationError::Conflict(format!(
+            "El usuario {} ha sido modificado por otra petición; vuelva a intentarlo", id
+        ))));
+    }
+    Ok(())
+}
+
 // Renombrar struct a Impl
 pub struct UpdateUserUseCaseImpl {
     // --- Dependencias Correctas ---
@@ -56,7 +86,7 @@ impl UpdateUserUseCaseImpl {
     }
 
     // Mover lógica principal aquí
-    pub async fn execute(&self, id: Uuid, update_dto: UpdateUserDto, updated_by: Option<Uuid>) -> Result<UserResponseDto, ApplicationError> {
+    pub async fn execute(&self, id: Uuid, update_dto: UpdateUserDto, updated_by: Option<Uuid>, precondition: Option<VersionPrecondition>) -> Result<UserResponseDto, ApplicationError> {
         debug!("Iniciando caso de uso para actualizar usuario ID: {}", id);
 
         // 1. Validar campos DTO
@@ -75,6 +105,13 @@ impl UpdateUserUseCaseImpl {
             .ok_or_else(|| ApplicationError::NotFound(format!("Usuario con ID {} no encontrado", id)))?;
         debug!("Usuario encontrado: {}", user.id);
 
+        // Respuesta rápida a un If-Match obsoleto (se vuelve a comprobar con la fila bloqueada)
+        if precondition.as_ref().is_some_and(|precondition| !precondition.is_met(user.version)) {
+            return Err(ApplicationError::PreconditionFailed(format!(
+                "El usuario {} ha sido modificado (versión actual {})", id, user.version
+            )));
+        }
+
         // 3. Validar email único si se está actualizando (usando Query Repo)
         if let Some(email) = &update_dto.email {
             debug!("Verificando unicidad de email: {}", email);
@@ -121,6 +158,9 @@ impl UpdateUserUseCaseImpl {
                 let cmd_repo = registry.user_command_repository();
                 let conn = registry.get_diesel_async_conn();
 
+                // Sin esta comprobación, la última escritura sobrescribe los cambios concurrentes
+                check_user_version(cmd_repo, conn, user_to_update.id, precondition.as_ref(), Some(user_to_update.version)).await?;
+
                 debug!("Dentro de UoW: Llamando a cmd_repo.update...");
                 // Llamar al método update del repo de comando, pasando la conexión
                 let result = cmd_repo.update(conn, user_to_update).await
@@ -128,10 +168,13 @@ impl UpdateUserUseCaseImpl {
 
                 debug!("Dentro de UoW: Usuario actualizado en BD con ID: {}", result.id);
                 Ok(result) // Devolver la entidad User actualizada
+            }.scope_boxed()
+        }).await.map_err(|e| match e.downcast::<ApplicationError>() {
+            Ok(app_err) => app_err,
+            Err(e) => {
+                error!("Error durante la Unidad de Trabajo al actualizar usuario {}: {:?}", id, e);
+                ApplicationError::InfrastructureError(format!("Error en transacción al actualizar usuario: {}", e))
             }
-        }).await.map_err(|e| {
-            error!("Error durante la Unidad de Trabajo al actualizar usuario {}: {:?}", id, e);
-            ApplicationError::InfrastructureError(format!("Error en transacción al actualizar usuario: {}", e))
         })?;
         info!("Unidad de Trabajo completada. Usuario actualizado con ID: {}", updated_user_entity.id);
 
@@ -143,7 +186,101 @@ impl UpdateUserUseCaseImpl {
 // Implementar el trait (si existe)
 #[async_trait]
 impl crate::Application::use_cases::traits::UpdateUserUseCase for UpdateUserUseCaseImpl {
-    async fn execute(&self, id: Uuid, update_dto: UpdateUserDto, updated_by: Option<Uuid>) -> Result<UserResponseDto, ApplicationError> {
-        self.execute(id, update_dto, updated_by).await
+    async fn execute(&self, id: Uuid, update_dto: UpdateUserDto, updated_by: Option<Uuid>, precondition: Option<VersionPrecondition>) -> Result<UserResponseDto, ApplicationError> {
+        self.execute(id, update_dto, updated_by, precondition).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Application::ports::driven::repositories::DeletedUserDto;
+    use crate::Application::use_cases::test_support::FailingUnitOfWork;
+    use chrono::DateTime;
+
+    /// Repositorio de usuarios con un solo usuario.
+    struct UserQueryStub {
+        user: User,
+    }
+
+    #[async_trait]
+    impl UserQueryRepository for UserQueryStub {
+        fn set_database(&mut self, _database_name: &str) {}
+        async fn find_by_id(&self, id: Uuid) -> anyhow::Result<Option<User>> {
+            Ok(Some(self.user.clone()).filter(|user| user.id == id))
+        }
+        async fn find_by_email(&self, _email: &str) -> anyhow::Result<Option<User>> { unimplemented!() }
+        async fn find_by_username(&self, _username: &str) -> anyhow::Result<Option<User>> { unimplemented!() }
+        async fn find_all(&self) -> anyhow::Result<Vec<User>> { unimplemented!() }
+        async fn find_deleted(&self, _limit: i64, _offset: i64) -> anyhow::Result<Vec<DeletedUserDto>> { unimplemented!() }
+        async fn count_deleted(&self) -> anyhow::Result<i64> { unimplemented!() }
+        async fn find_deleted_before(&self, _deleted_before: DateTime<Utc>) -> anyhow::Result<Vec<Uuid>> { unimplemented!() }
+    }
+
+    /// Las escrituras solo se hacen dentro de la UoW, que en estas pruebas no llega a ejecutarlas.
+    struct UserCommandStub;
+
+    #[async_trait]
+    impl UserCommandRepository for UserCommandStub {
+        async fn create(&self, _conn: &mut AsyncPgConnection, _user: User) -> anyhow::Result<User> { unimplemented!() }
+        async fn update(&self, _conn: &mut AsyncPgConnection, _user: User) -> anyhow::Result<User> { unimplemented!() }
+        async fn find_version_for_update(&self, _conn: &mut AsyncPgConnection, _id: Uuid) -> anyhow::Result<Option<i32>> { unimplemented!() }
+        async fn delete(&self, _conn: &mut AsyncPgConnection, _id: Uuid, _deleted_by: Uuid) -> anyhow::Result<()> { unimplemented!() }
+        async fn restore(&self, _conn: &mut AsyncPgConnection, _id: Uuid) -> anyhow::Result<usize> { unimplemented!() }
+        async fn purge(&self, _conn: &mut AsyncPgConnection, _id: Uuid) -> anyhow::Result<usize> { unimplemented!() }
+    }
+
+    struct AuthServiceStub;
+
+    #[async_trait]
+    impl AuthServicePort for AuthServiceStub {
+        fn hash_password(&self, _password: &str) -> anyhow::Result<String> { unimplemented!() }
+        fn verify_password(&self, _password: &str, _hash: &str) -> anyhow::Result<bool> { unimplemented!() }
+        async fn generate_token(&self, _user_id: Uuid) -> anyhow::Result<String> { unimplemented!() }
+        async fn validate_token(&self, _token: &str) -> anyhow::Result<Uuid> { unimplemented!() }
+    }
+
+    /// Caso de uso sobre un usuario en la versión 3 cuya UoW falla con `error`.
+    fn use_case(error: ApplicationError) -> (UpdateUserUseCaseImpl, Arc<FailingUnitOfWork>, Uuid) {
+        let mut user = User::new(
+            "ana".to_string(), "Ana".to_string(), "García".to_string(),
+            "ana@example.com".to_string(), "hash".to_string(), None,
+        ).unwrap();
+        user.version = 3;
+        let id = user.id;
+        let uow = Arc::new(FailingUnitOfWork::new(error));
+        let use_case = UpdateUserUseCaseImpl::new(
+            Arc::new(UserCommandStub),
+            Arc::new(UserQueryStub { user }),
+            Arc::new(AuthServiceStub),
+            uow.clone(),
+            Arc::new(UserMapper::new()),
+        );
+        (use_case, uow, id)
+    }
+
+    fn no_changes() -> UpdateUserDto {
+        UpdateUserDto { first_name: None, last_name: None, email: None, password: None }
+    }
+
+    #[tokio::test]
+    async fn stale_if_match_is_a_failed_precondition() {
+        let (use_case, uow, id) = use_case(ApplicationError::InfrastructureError("no debe llegar".to_string()));
+        let precondition = VersionPrecondition::from_if_match("\"2\"");
+
+        let result = use_case.execute(id, no_changes(), None, Some(precondition)).await;
+
+        assert!(matches!(result, Err(ApplicationError::PreconditionFailed(_))));
+        assert_eq!(uow.calls(), 0);
+    }
+
+    #[tokio::test]
+    async fn version_changed_during_the_transaction_is_a_failed_precondition() {
+        let (use_case, _, id) = use_case(ApplicationError::PreconditionFailed("usuario".to_string()));
+        let precondition = VersionPrecondition::from_if_match("\"3\"");
+
+        let result = use_case.execute(id, no_changes(), None, Some(precondition)).await;
+
+        assert!(matches!(result, Err(ApplicationError::PreconditionFailed(_))));
     }
 }
@@ -7,7 +7,15 @@ use crate::Presentation::api::controllers::{
     AuthController,
     UserController,
     HealthController,
-    LogicalEntityController
+    LogicalEntityController,
+    RecordController,
+    AttributeController,
+    SchemaVersionController,
+    DataTypeController,
+    SchemaBundleController,
+    DocsController,
+    GraphQLController,
+    TrashController,
 };
 
 /// Estado compartido de la aplicación que proporciona acceso a todas las dependencias
@@ -18,6 +26,14 @@ pub struct AppState {
     pub user_controller_data: web::Data<UserController>, // Cambiado &lt; a <
     pub health_controller_data: web::Data<HealthController>, // Cambiado &lt; a <
     pub logical_entity_controller_data: web::Data<LogicalEntityController>, // Cambiado &lt; a <
+    pub record_controller_data: web::Data<RecordController>,
+    pub attribute_controller_data: web::Data<AttributeController>,
+    pub schema_version_controller_data: web::Data<SchemaVersionController>,
+    pub data_type_controller_data: web::Data<DataTypeController>,
+    pub schema_bundle_controller_data: web::Data<SchemaBundleController>,
+    pub docs_controller_data: web::Data<DocsController>,
+    pub graphql_controller_data: web::Data<GraphQLController>,
+    pub trash_controller_data: web::Data<TrashController>,
 }
 
 impl AppState {
@@ -39,11 +55,43 @@ impl AppState {
         let logical_entity_controller_arc: Arc<LogicalEntityController> = registry.get_arc::<LogicalEntityController>()
             .expect("LogicalEntityController no registrado");
 
+        let record_controller_arc = registry.get_arc::<RecordController>()
+            .expect("RecordController no registrado");
+
+        let attribute_controller_arc = registry.get_arc::<AttributeController>()
+            .expect("AttributeController no registrado");
+
+        let schema_version_controller_arc = registry.get_arc::<SchemaVersionController>()
+            .expect("SchemaVersionController no registrado");
+
+        let data_type_controller_arc = registry.get_arc::<DataTypeController>()
+            .expect("DataTypeController no registrado");
+
+        let schema_bundle_controller_arc = registry.get_arc::<SchemaBundleController>()
+            .expect("SchemaBundleController no registrado");
+
+        let docs_controller_arc = registry.get_arc::<DocsController>()
+            .expect("DocsController no registrado");
+
+        let graphql_controller_arc = registry.get_arc::<GraphQLController>()
+            .expect("GraphQLController no registrado");
+
+        let trash_controller_arc = registry.get_arc::<TrashController>()
+            .expect("TrashController no registrado");
+
         // Crear web::Data usando los Arc
         let auth_controller_data = web::Data::from(auth_controller_arc);
         let user_controller_data = web::Data::from(user_controller_arc);
         let health_controller_data = web::Data::from(health_controller_arc);
         let logical_entity_controller_data = web::Data::from(logical_entity_controller_arc);
+        let record_controller_data = web::Data::from(record_controller_arc);
+        let attribute_controller_data = web::Data::from(attribute_controller_arc);
+        let schema_version_controller_data = web::Data::from(schema_version_controller_arc);
+        let data_type_controller_data = web::Data::from(data_type_controller_arc);
+        let schema_bundle_controller_data = web::Data::from(schema_bundle_controller_arc);
+        let docs_controller_data = web::Data::from(docs_controller_arc);
+        let graphql_controller_data = web::Data::from(graphql_controller_arc);
+        let trash_controller_data = web::Data::from(trash_controller_arc);
 
         AppState {
             registry: Arc::new(registry),
@@ -51,6 +99,14 @@ impl AppState {
             user_controller_data,
             health_controller_data,
             logical_entity_controller_data,
+            record_controller_data,
+            attribute_controller_data,
+            schema_version_controller_data,
+            data_type_controller_data,
+            schema_bundle_controller_data,
+            docs_controller_data,
+            graphql_controller_data,
+            trash_controller_data,
         }
     }
 
@@ -85,7 +141,15 @@ impl AppState {
         F: FnOnce(web::Data<AuthController>, // Cambiado &lt; a <
             web::Data<UserController>, // Cambiado &lt; a <
             web::Data<HealthController>, // Cambiado &lt; a <
-            web::Data<LogicalEntityController> // Cambiado &lt; a <
+            web::Data<LogicalEntityController>, // Cambiado &lt; a <
+            web::Data<RecordController>,
+            web::Data<AttributeController>,
+            web::Data<SchemaVersionController>,
+            web::Data<DataTypeController>,
+            web::Data<SchemaBundleController>,
+            web::Data<DocsController>,
+            web::Data<GraphQLController>,
+            web::Data<TrashController>
         ) -> T,
     {
         debug!("Configurando aplicación Actix con controladores");
@@ -93,7 +157,15 @@ impl AppState {
             self.auth_controller_data.clone(),
             self.user_controller_data.clone(),
             self.health_controller_data.clone(),
-            self.logical_entity_controller_data.clone()
+            self.logical_entity_controller_data.clone(),
+            self.record_controller_data.clone(),
+            self.attribute_controller_data.clone(),
+            self.schema_version_controller_data.clone(),
+            self.data_type_controller_data.clone(),
+            self.schema_bundle_controller_data.clone(),
+            self.docs_controller_data.clone(),
+            self.graphql_controller_data.clone(),
+            self.trash_controller_data.clone()
         )
     }
 }
@@ -3,7 +3,9 @@
 
 use crate::Container::builder::ContainerBuilder;
 use crate::Presentation::api::controllers::{
-    AuthController, UserController, HealthController, LogicalEntityController
+    AuthController, UserController, HealthController, LogicalEntityController, RecordController,
+    AttributeController, SchemaVersionController, DataTypeController, SchemaBundleController,
+    DocsController, GraphQLController, TrashController,
 };
 // --- Importar Traits de Casos de Uso ---
 use crate::Application::use_cases::traits::{ // Traits de User/Auth
@@ -11,8 +13,38 @@ use crate::Application::use_cases::traits::{ // Traits de User/Auth
     FindAllUsersUseCase, UpdateUserUseCase, DeleteUserUseCase,
     // Añadir otros traits generales si existen
 };
+use crate::Application::use_cases::user::{ListDeletedUsersUseCase, RestoreUserUseCase, PurgeUserUseCase};
 // --- CORREGIDO: Importar trait de Logical Entity desde su módulo ---
-use crate::Application::use_cases::logical_entities::CreateEntityWithAttributesUseCase;
+use crate::Application::use_cases::logical_entities::{
+    CreateEntityWithAttributesUseCase, FindLogicalEntityByIdUseCase, FindLogicalEntityByNameUseCase,
+    ListLogicalEntitiesUseCase, UpdateLogicalEntityUseCase, DeleteLogicalEntityUseCase,
+    RefreshEntityViewUseCase, EntityJsonSchemaUseCase, ListEntityJsonSchemasUseCase,
+    ListDeletedLogicalEntitiesUseCase, RestoreLogicalEntityUseCase, PurgeLogicalEntityUseCase,
+    EntityViewStatusUseCase, UpdateEntityViewSettingsUseCase, RefreshMaterializedViewUseCase,
+    ChangeStorageModeUseCase,
+};
+use crate::Application::use_cases::records::{
+    CreateRecordUseCase, FindRecordUseCase, ListRecordsUseCase, UpdateRecordUseCase, DeleteRecordUseCase,
+    SearchRecordsUseCase,
+    ImportRecordsUseCase,
+    ExportRecordsUseCase,
+    ListDeletedRecordsUseCase, RestoreRecordUseCase, PurgeRecordUseCase,
+    RecordHistoryUseCase, FindRecordAsOfUseCase,
+};
+use crate::Application::use_cases::attributes::{
+    AddAttributeUseCase, UpdateAttributeUseCase, ReorderAttributesUseCase, ChangeAttributeDataTypeUseCase,
+    ReplaceAttributeOptionsUseCase,
+};
+use crate::Application::use_cases::schema_versions::{
+    ListSchemaVersionsUseCase, FindSchemaVersionUseCase, FindSchemaVersionAsOfUseCase,
+    DiffSchemaVersionsUseCase, RollbackSchemaVersionUseCase,
+};
+use crate::Application::use_cases::data_types::{
+    ListDataTypesUseCase, FindDataTypeUseCase, CreateDataTypeUseCase, UpdateDataTypeUseCase, DeleteDataTypeUseCase,
+};
+use crate::Application::use_cases::schema_bundles::{ExportSchemaBundleUseCase, ImportSchemaBundleUseCase, PreviewEntitySchemaUseCase};
+use crate::Application::use_cases::graphql::ExecuteGraphQLUseCase;
+use crate::Application::use_cases::trash::PurgeTrashUseCase;
 // -----------------------------------------------------------------
 use std::sync::Arc;
 use anyhow::Result;
@@ -36,10 +68,119 @@ pub async fn register_controller_dependencies(builder: &mut ContainerBuilder) ->
         .expect("UpdateUserUseCase not registered.");
     let delete_user_uc = builder.registry().get_arc::<dyn DeleteUserUseCase>()
         .expect("DeleteUserUseCase not registered.");
+    let list_deleted_users_uc = builder.registry().get_arc::<dyn ListDeletedUsersUseCase>()
+        .expect("ListDeletedUsersUseCase not registered.");
+    let restore_user_uc = builder.registry().get_arc::<dyn RestoreUserUseCase>()
+        .expect("RestoreUserUseCase not registered.");
+    let purge_user_uc = builder.registry().get_arc::<dyn PurgeUserUseCase>()
+        .expect("PurgeUserUseCase not registered.");
 
     // Obtener el trait correcto (la ruta de import ahora es correcta)
     let create_le_uc = builder.registry().get_arc::<dyn CreateEntityWithAttributesUseCase>()
         .expect("CreateEntityWithAttributesUseCase not registered.");
+    let find_le_by_id_uc = builder.registry().get_arc::<dyn FindLogicalEntityByIdUseCase>()
+        .expect("FindLogicalEntityByIdUseCase not registered.");
+    let find_le_by_name_uc = builder.registry().get_arc::<dyn FindLogicalEntityByNameUseCase>()
+        .expect("FindLogicalEntityByNameUseCase not registered.");
+    let list_le_uc = builder.registry().get_arc::<dyn ListLogicalEntitiesUseCase>()
+        .expect("ListLogicalEntitiesUseCase not registered.");
+    let update_le_uc = builder.registry().get_arc::<dyn UpdateLogicalEntityUseCase>()
+        .expect("UpdateLogicalEntityUseCase not registered.");
+    let delete_le_uc = builder.registry().get_arc::<dyn DeleteLogicalEntityUseCase>()
+        .expect("DeleteLogicalEntityUseCase not registered.");
+    let refresh_le_view_uc = builder.registry().get_arc::<dyn RefreshEntityViewUseCase>()
+        .expect("RefreshEntityViewUseCase not registered.");
+    let le_view_status_uc = builder.registry().get_arc::<dyn EntityViewStatusUseCase>()
+        .expect("EntityViewStatusUseCase not registered.");
+    let update_le_view_settings_uc = builder.registry().get_arc::<dyn UpdateEntityViewSettingsUseCase>()
+        .expect("UpdateEntityViewSettingsUseCase not registered.");
+    let refresh_le_materialized_view_uc = builder.registry().get_arc::<dyn RefreshMaterializedViewUseCase>()
+        .expect("RefreshMaterializedViewUseCase not registered.");
+    let change_le_storage_mode_uc = builder.registry().get_arc::<dyn ChangeStorageModeUseCase>()
+        .expect("ChangeStorageModeUseCase not registered.");
+    let le_json_schema_uc = builder.registry().get_arc::<dyn EntityJsonSchemaUseCase>()
+        .expect("EntityJsonSchemaUseCase not registered.");
+    let list_le_json_schemas_uc = builder.registry().get_arc::<dyn ListEntityJsonSchemasUseCase>()
+        .expect("ListEntityJsonSchemasUseCase not registered.");
+    let list_deleted_le_uc = builder.registry().get_arc::<dyn ListDeletedLogicalEntitiesUseCase>()
+        .expect("ListDeletedLogicalEntitiesUseCase not registered.");
+    let restore_le_uc = builder.registry().get_arc::<dyn RestoreLogicalEntityUseCase>()
+        .expect("RestoreLogicalEntityUseCase not registered.");
+    let purge_le_uc = builder.registry().get_arc::<dyn PurgeLogicalEntityUseCase>()
+        .expect("PurgeLogicalEntityUseCase not registered.");
+
+    let create_record_uc = builder.registry().get_arc::<dyn CreateRecordUseCase>()
+        .expect("CreateRecordUseCase not registered.");
+    let find_record_uc = builder.registry().get_arc::<dyn FindRecordUseCase>()
+        .expect("FindRecordUseCase not registered.");
+    let list_records_uc = builder.registry().get_arc::<dyn ListRecordsUseCase>()
+        .expect("ListRecordsUseCase not registered.");
+    let search_records_uc = builder.registry().get_arc::<dyn SearchRecordsUseCase>()
+        .expect("SearchRecordsUseCase not registered.");
+    let import_records_uc = builder.registry().get_arc::<dyn ImportRecordsUseCase>()
+        .expect("ImportRecordsUseCase not registered.");
+    let export_records_uc = builder.registry().get_arc::<dyn ExportRecordsUseCase>()
+        .expect("ExportRecordsUseCase not registered.");
+    let update_record_uc = builder.registry().get_arc::<dyn UpdateRecordUseCase>()
+        .expect("UpdateRecordUseCase not registered.");
+    let delete_record_uc = builder.registry().get_arc::<dyn DeleteRecordUseCase>()
+        .expect("DeleteRecordUseCase not registered.");
+    let list_deleted_records_uc = builder.registry().get_arc::<dyn ListDeletedRecordsUseCase>()
+        .expect("ListDeletedRecordsUseCase not registered.");
+    let restore_record_uc = builder.registry().get_arc::<dyn RestoreRecordUseCase>()
+        .expect("RestoreRecordUseCase not registered.");
+    let purge_record_uc = builder.registry().get_arc::<dyn PurgeRecordUseCase>()
+        .expect("PurgeRecordUseCase not registered.");
+    let record_history_uc = builder.registry().get_arc::<dyn RecordHistoryUseCase>()
+        .expect("RecordHistoryUseCase not registered.");
+    let find_record_as_of_uc = builder.registry().get_arc::<dyn FindRecordAsOfUseCase>()
+        .expect("FindRecordAsOfUseCase not registered.");
+
+    let add_attribute_uc = builder.registry().get_arc::<dyn AddAttributeUseCase>()
+        .expect("AddAttributeUseCase not registered.");
+    let update_attribute_uc = builder.registry().get_arc::<dyn UpdateAttributeUseCase>()
+        .expect("UpdateAttributeUseCase not registered.");
+    let reorder_attributes_uc = builder.registry().get_arc::<dyn ReorderAttributesUseCase>()
+        .expect("ReorderAttributesUseCase not registered.");
+    let change_attribute_data_type_uc = builder.registry().get_arc::<dyn ChangeAttributeDataTypeUseCase>()
+        .expect("ChangeAttributeDataTypeUseCase not registered.");
+    let replace_attribute_options_uc = builder.registry().get_arc::<dyn ReplaceAttributeOptionsUseCase>()
+        .expect("ReplaceAttributeOptionsUseCase not registered.");
+
+    let list_schema_versions_uc = builder.registry().get_arc::<dyn ListSchemaVersionsUseCase>()
+        .expect("ListSchemaVersionsUseCase not registered.");
+    let find_schema_version_uc = builder.registry().get_arc::<dyn FindSchemaVersionUseCase>()
+        .expect("FindSchemaVersionUseCase not registered.");
+    let find_schema_version_as_of_uc = builder.registry().get_arc::<dyn FindSchemaVersionAsOfUseCase>()
+        .expect("FindSchemaVersionAsOfUseCase not registered.");
+    let diff_schema_versions_uc = builder.registry().get_arc::<dyn DiffSchemaVersionsUseCase>()
+        .expect("DiffSchemaVersionsUseCase not registered.");
+    let rollback_schema_version_uc = builder.registry().get_arc::<dyn RollbackSchemaVersionUseCase>()
+        .expect("RollbackSchemaVersionUseCase not registered.");
+
+    let list_data_types_uc = builder.registry().get_arc::<dyn ListDataTypesUseCase>()
+        .expect("ListDataTypesUseCase not registered.");
+    let find_data_type_uc = builder.registry().get_arc::<dyn FindDataTypeUseCase>()
+        .expect("FindDataTypeUseCase not registered.");
+    let create_data_type_uc = builder.registry().get_arc::<dyn CreateDataTypeUseCase>()
+        .expect("CreateDataTypeUseCase not registered.");
+    let update_data_type_uc = builder.registry().get_arc::<dyn UpdateDataTypeUseCase>()
+        .expect("UpdateDataTypeUseCase not registered.");
+    let delete_data_type_uc = builder.registry().get_arc::<dyn DeleteDataTypeUseCase>()
+        .expect("DeleteDataTypeUseCase not registered.");
+
+    let export_schema_bundle_uc = builder.registry().get_arc::<dyn ExportSchemaBundleUseCase>()
+        .expect("ExportSchemaBundleUseCase not registered.");
+    let import_schema_bundle_uc = builder.registry().get_arc::<dyn ImportSchemaBundleUseCase>()
+        .expect("ImportSchemaBundleUseCase not registered.");
+    let preview_entity_schema_uc = builder.registry().get_arc::<dyn PreviewEntitySchemaUseCase>()
+        .expect("PreviewEntitySchemaUseCase not registered.");
+
+    let execute_graphql_uc = builder.registry().get_arc::<dyn ExecuteGraphQLUseCase>()
+        .expect("ExecuteGraphQLUseCase not registered.");
+
+    let purge_trash_uc = builder.registry().get_arc::<dyn PurgeTrashUseCase>()
+        .expect("PurgeTrashUseCase not registered.");
     // ------------------------------------------
     // ... obtener otros casos de uso ...
     // ------------------------------------
@@ -56,15 +197,102 @@ pub async fn register_controller_dependencies(builder: &mut ContainerBuilder) ->
         find_all_users_uc,
         update_user_uc,
         delete_user_uc,
+        list_deleted_users_uc,
+        restore_user_uc,
+        purge_user_uc,
     ));
     builder.register_arc_service(user_controller);
     debug!("UserController registrado.");
 
     // Pasar el trait correcto al constructor
-    let le_controller = Arc::new(LogicalEntityController::new(create_le_uc /*, otros LE UCs */));
+    let le_controller = Arc::new(LogicalEntityController::new(
+        create_le_uc,
+        find_le_by_id_uc,
+        find_le_by_name_uc,
+        list_le_uc,
+        update_le_uc,
+        delete_le_uc,
+        refresh_le_view_uc,
+        le_view_status_uc,
+        update_le_view_settings_uc,
+        refresh_le_materialized_view_uc,
+        le_json_schema_uc,
+        list_deleted_le_uc,
+        restore_le_uc,
+        purge_le_uc,
+        change_le_storage_mode_uc,
+    ));
     builder.register_arc_service(le_controller);
     debug!("LogicalEntityController registrado.");
 
+    let record_controller = Arc::new(RecordController::new(
+        create_record_uc,
+        find_record_uc,
+        list_records_uc,
+        search_records_uc,
+        import_records_uc,
+        export_records_uc,
+        update_record_uc,
+        delete_record_uc,
+        list_deleted_records_uc,
+        restore_record_uc,
+        purge_record_uc,
+        record_history_uc,
+        find_record_as_of_uc,
+    ));
+    builder.register_arc_service(record_controller);
+    debug!("RecordController registrado.");
+
+    let attribute_controller = Arc::new(AttributeController::new(
+        add_attribute_uc,
+        update_attribute_uc,
+        reorder_attributes_uc,
+        change_attribute_data_type_uc,
+        replace_attribute_options_uc,
+    ));
+    builder.register_arc_service(attribute_controller);
+    debug!("AttributeController registrado.");
+
+    let schema_version_controller = Arc::new(SchemaVersionController::new(
+        list_schema_versions_uc,
+        find_schema_version_uc,
+        find_schema_version_as_of_uc,
+        diff_schema_versions_uc,
+        rollback_schema_version_uc,
+    ));
+    builder.register_arc_service(schema_version_controller);
+    debug!("SchemaVersionController registrado.");
+
+    let data_type_controller = Arc::new(DataTypeController::new(
+        list_data_types_uc,
+        find_data_type_uc,
+        create_data_type_uc,
+        update_data_type_uc,
+        delete_data_type_uc,
+    ));
+    builder.register_arc_service(data_type_controller);
+    debug!("DataTypeController registrado.");
+
+    let schema_bundle_controller = Arc::new(SchemaBundleController::new(
+        export_schema_bundle_uc,
+        import_schema_bundle_uc,
+        preview_entity_schema_uc,
+    ));
+    builder.register_arc_service(schema_bundle_controller);
+    debug!("SchemaBundleController registrado.");
+
+    let docs_controller = Arc::new(DocsController::new(list_le_json_schemas_uc));
+    builder.register_arc_service(docs_controller);
+    debug!("DocsController registrado.");
+
+    let graphql_controller = Arc::new(GraphQLController::new(execute_graphql_uc));
+    builder.register_arc_service(graphql_controller);
+    debug!("GraphQLController registrado.");
+
+    let trash_controller = Arc::new(TrashController::new(purge_trash_uc));
+    builder.register_arc_service(trash_controller);
+    debug!("TrashController registrado.");
+
     // Health Controller
     let db_monitor = builder.registry().get_arc::<crate::Infrastructure::monitoring::DatabaseHealthMonitor>()
         .expect("DatabaseHealthMonitor not registered.");
@@ -0,0 +1,73 @@
+use std::sync::Arc;
+use anyhow::Result;
+use log::{info, debug};
+
+use crate::Container::builder::ContainerBuilder;
+// --- Traits y Structs Necesarias ---
+use crate::Application::ports::driven::repositories::{
+    LogicalEntityQueryRepository, AttributeQueryRepository, DataTypeQueryRepository,
+};
+use crate::Application::ports::unit_of_work::UnitOfWork;
+use crate::Domain::views::ViewRepository;
+use crate::Application::use_cases::data_types::{
+    ListDataTypesUseCase, ListDataTypesUseCaseImpl,
+    FindDataTypeUseCase, FindDataTypeUseCaseImpl,
+    CreateDataTypeUseCase, CreateDataTypeUseCaseImpl,
+    UpdateDataTypeUseCase, UpdateDataTypeUseCaseImpl,
+    DeleteDataTypeUseCase, DeleteDataTypeUseCaseImpl,
+};
+
+pub struct DataTypeModule;
+
+impl DataTypeModule {
+    /// Registra los casos de uso del registro de tipos de datos.
+    /// El controlador se construye en controller_module a partir de estos casos de uso.
+    pub fn register(builder: &mut ContainerBuilder) -> Result<()> {
+        debug!("Registrando componentes del módulo de DataTypes...");
+
+        // --- Obtener Dependencias ---
+        let le_query_repository = builder.registry().get_arc::<dyn LogicalEntityQueryRepository>()
+            .expect("LogicalEntityQueryRepository not registered. Ensure RepositoryModule runs before DataTypeModule.");
+        let attribute_query_repository = builder.registry().get_arc::<dyn AttributeQueryRepository>()
+            .expect("AttributeQueryRepository not registered. Ensure RepositoryModule runs before DataTypeModule.");
+        let data_type_query_repository = builder.registry().get_arc::<dyn DataTypeQueryRepository>()
+            .expect("DataTypeQueryRepository not registered. Ensure RepositoryModule runs before DataTypeModule.");
+        let view_repository = builder.registry().get_arc::<dyn ViewRepository>()
+            .expect("ViewRepository not registered. Ensure RepositoryModule runs before DataTypeModule.");
+        let unit_of_work = builder.registry().get_arc::<dyn UnitOfWork>()
+            .expect("UnitOfWork not registered. Ensure DatabaseModule runs before DataTypeModule.");
+        // --------------------------
+
+        // --- Registrar Casos de Uso ---
+        let list_uc = Arc::new(ListDataTypesUseCaseImpl::new(data_type_query_repository.clone()));
+        builder.register_arc_service::<dyn ListDataTypesUseCase>(list_uc);
+
+        let find_uc = Arc::new(FindDataTypeUseCaseImpl::new(data_type_query_repository.clone()));
+        builder.register_arc_service::<dyn FindDataTypeUseCase>(find_uc);
+
+        let create_uc = Arc::new(CreateDataTypeUseCaseImpl::new(
+            data_type_query_repository.clone(),
+            unit_of_work.clone(),
+        ));
+        builder.register_arc_service::<dyn CreateDataTypeUseCase>(create_uc);
+
+        let update_uc = Arc::new(UpdateDataTypeUseCaseImpl::new(
+            le_query_repository.clone(),
+            attribute_query_repository.clone(),
+            data_type_query_repository.clone(),
+            view_repository.clone(),
+            unit_of_work.clone(),
+        ));
+        builder.register_arc_service::<dyn UpdateDataTypeUseCase>(update_uc);
+
+        let delete_uc = Arc::new(DeleteDataTypeUseCaseImpl::new(
+            data_type_query_repository.clone(),
+            unit_of_work.clone(),
+        ));
+        builder.register_arc_service::<dyn DeleteDataTypeUseCase>(delete_uc);
+        debug!("Casos de uso de DataTypes registrados.");
+
+        info!("Módulo de DataTypes registrado correctamente.");
+        Ok(())
+    }
+}
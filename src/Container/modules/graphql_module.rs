@@ -0,0 +1,60 @@
+use std::sync::Arc;
+use anyhow::Result;
+use log::{info, debug};
+
+use crate::Container::builder::ContainerBuilder;
+// --- Traits y Structs Necesarias ---
+use crate::Application::ports::driven::repositories::{
+    LogicalEntityQueryRepository, AttributeQueryRepository, RecordQueryRepository,
+};
+use crate::Application::use_cases::records::{
+    CreateRecordUseCase, FindRecordUseCase, ListRecordsUseCase, UpdateRecordUseCase, DeleteRecordUseCase,
+};
+use crate::Application::use_cases::graphql::{ExecuteGraphQLUseCase, ExecuteGraphQLUseCaseImpl};
+
+pub struct GraphQLModule;
+
+impl GraphQLModule {
+    /// Registra el caso de uso del endpoint GraphQL. Las operaciones sobre registros
+    /// se delegan en los casos de uso de registros, por lo que este módulo se registra
+    /// después de RecordModule.
+    pub fn register(builder: &mut ContainerBuilder) -> Result<()> {
+        debug!("Registrando componentes del módulo de GraphQL...");
+
+        // --- Obtener Dependencias ---
+        let le_query_repository = builder.registry().get_arc::<dyn LogicalEntityQueryRepository>()
+            .expect("LogicalEntityQueryRepository not registered. Ensure RepositoryModule runs before GraphQLModule.");
+        let attribute_query_repository = builder.registry().get_arc::<dyn AttributeQueryRepository>()
+            .expect("AttributeQueryRepository not registered. Ensure RepositoryModule runs before GraphQLModule.");
+        let record_query_repository = builder.registry().get_arc::<dyn RecordQueryRepository>()
+            .expect("RecordQueryRepository not registered. Ensure RepositoryModule runs before GraphQLModule.");
+        let find_record_uc = builder.registry().get_arc::<dyn FindRecordUseCase>()
+            .expect("FindRecordUseCase not registered. Ensure RecordModule runs before GraphQLModule.");
+        let list_records_uc = builder.registry().get_arc::<dyn ListRecordsUseCase>()
+            .expect("ListRecordsUseCase not registered. Ensure RecordModule runs before GraphQLModule.");
+        let create_record_uc = builder.registry().get_arc::<dyn CreateRecordUseCase>()
+            .expect("CreateRecordUseCase not registered. Ensure RecordModule runs before GraphQLModule.");
+        let update_record_uc = builder.registry().get_arc::<dyn UpdateRecordUseCase>()
+            .expect("UpdateRecordUseCase not registered. Ensure RecordModule runs before GraphQLModule.");
+        let delete_record_uc = builder.registry().get_arc::<dyn DeleteRecordUseCase>()
+            .expect("DeleteRecordUseCase not registered. Ensure RecordModule runs before GraphQLModule.");
+        // --------------------------
+
+        // --- Registrar Casos de Uso ---
+        let execute_uc = Arc::new(ExecuteGraphQLUseCaseImpl::new(
+            le_query_repository,
+            attribute_query_repository,
+            record_query_repository,
+            find_record_uc,
+            list_records_uc,
+            create_record_uc,
+            update_record_uc,
+            delete_record_uc,
+        ));
+        builder.register_arc_service::<dyn ExecuteGraphQLUseCase>(execute_uc);
+        debug!("Caso de uso de GraphQL registrado.");
+
+        info!("Módulo de GraphQL registrado correctamente.");
+        Ok(())
+    }
+}
@@ -5,58 +5,259 @@ use log::{info, debug};
 use crate::Container::builder::ContainerBuilder;
 // --- Traits y Structs Necesarias ---
 use crate::Application::ports::driven::repositories::{
-    LogicalEntityCommandRepository, LogicalEntityQueryRepository
+    LogicalEntityCommandRepository, LogicalEntityQueryRepository, AttributeQueryRepository,
+    DataTypeQueryRepository, RecordQueryRepository, SchemaVersionQueryRepository,
 };
 use crate::Application::ports::unit_of_work::UnitOfWork;
-use crate::Application::use_cases::logical_entity::create::{ // Asumiendo esta ruta
-    CreateLogicalEntityUseCaseImpl, CreateLogicalEntityUseCase
+use crate::Domain::views::ViewRepository;
+use crate::Application::use_cases::logical_entities::{
+    CreateEntityWithAttributesUseCase, CreateEntityWithAttributesUseCaseImpl,
+    FindLogicalEntityByIdUseCase, FindLogicalEntityByIdUseCaseImpl,
+    FindLogicalEntityByNameUseCase, FindLogicalEntityByNameUseCaseImpl,
+    ListLogicalEntitiesUseCase, ListLogicalEntitiesUseCaseImpl,
+    UpdateLogicalEntityUseCase, UpdateLogicalEntityUseCaseImpl,
+    DeleteLogicalEntityUseCase, DeleteLogicalEntityUseCaseImpl,
+    RefreshEntityViewUseCase, RefreshEntityViewUseCaseImpl,
+    EntityViewStatusUseCase, EntityViewStatusUseCaseImpl,
+    UpdateEntityViewSettingsUseCase, UpdateEntityViewSettingsUseCaseImpl,
+    RefreshMaterializedViewUseCase, RefreshMaterializedViewUseCaseImpl,
+    EntityJsonSchemaUseCase, EntityJsonSchemaUseCaseImpl,
+    ListEntityJsonSchemasUseCase, ListEntityJsonSchemasUseCaseImpl,
+    ListDeletedLogicalEntitiesUseCase, ListDeletedLogicalEntitiesUseCaseImpl,
+    RestoreLogicalEntityUseCase, RestoreLogicalEntityUseCaseImpl,
+    PurgeLogicalEntityUseCase, PurgeLogicalEntityUseCaseImpl,
+    ChangeStorageModeUseCase, ChangeStorageModeUseCaseImpl,
+};
+use crate::Application::use_cases::attributes::{
+    AddAttributeUseCase, AddAttributeUseCaseImpl,
+    UpdateAttributeUseCase, UpdateAttributeUseCaseImpl,
+    ReorderAttributesUseCase, ReorderAttributesUseCaseImpl,
+    ChangeAttributeDataTypeUseCase, ChangeAttributeDataTypeUseCaseImpl,
+    ReplaceAttributeOptionsUseCase, ReplaceAttributeOptionsUseCaseImpl,
+};
+use crate::Application::use_cases::schema_versions::{
+    ListSchemaVersionsUseCase, ListSchemaVersionsUseCaseImpl,
+    FindSchemaVersionUseCase, FindSchemaVersionUseCaseImpl,
+    FindSchemaVersionAsOfUseCase, FindSchemaVersionAsOfUseCaseImpl,
+    DiffSchemaVersionsUseCase, DiffSchemaVersionsUseCaseImpl,
+    RollbackSchemaVersionUseCase, RollbackSchemaVersionUseCaseImpl,
 };
 use crate::Infrastructure::repositories::LogicalEntityCommandRepositoryImpl; // ZST
-use crate::Presentation::api::controllers::LogicalEntityController; // Controlador
 
 pub struct LogicalEntityModule;
 
 impl LogicalEntityModule {
+    /// Registra los casos de uso de Logical Entity, de la evolución de sus atributos
+    /// y de las versiones de su definición.
+    /// El controlador se construye en controller_module a partir de estos casos de uso.
     pub fn register(builder: &mut ContainerBuilder) -> Result<()> {
         debug!("Registrando componentes del módulo de Logical Entity...");
 
         // --- Obtener Dependencias ---
         let le_query_repository = builder.registry().get_arc::<dyn LogicalEntityQueryRepository>()
-            .expect("LogicalEntityQueryRepository not registered.");
+            .expect("LogicalEntityQueryRepository not registered. Ensure RepositoryModule runs before LogicalEntityModule.");
+        let attribute_query_repository = builder.registry().get_arc::<dyn AttributeQueryRepository>()
+            .expect("AttributeQueryRepository not registered. Ensure RepositoryModule runs before LogicalEntityModule.");
+        let data_type_query_repository = builder.registry().get_arc::<dyn DataTypeQueryRepository>()
+            .expect("DataTypeQueryRepository not registered. Ensure RepositoryModule runs before LogicalEntityModule.");
+        let record_query_repository = builder.registry().get_arc::<dyn RecordQueryRepository>()
+            .expect("RecordQueryRepository not registered. Ensure RepositoryModule runs before LogicalEntityModule.");
+        let schema_version_query_repository = builder.registry().get_arc::<dyn SchemaVersionQueryRepository>()
+            .expect("SchemaVersionQueryRepository not registered. Ensure RepositoryModule runs before LogicalEntityModule.");
+        let view_repository = builder.registry().get_arc::<dyn ViewRepository>()
+            .expect("ViewRepository not registered. Ensure RepositoryModule runs before LogicalEntityModule.");
         let unit_of_work = builder.registry().get_arc::<dyn UnitOfWork>()
-            .expect("UnitOfWork not registered.");
+            .expect("UnitOfWork not registered. Ensure DatabaseModule runs before LogicalEntityModule.");
         // --------------------------
 
         // --- Registrar LE Command Repo (ZST) ---
-        let le_command_repository = if let Some(repo) = builder.registry().get_arc::<dyn LogicalEntityCommandRepository>() {
-            repo
-        } else {
+        if builder.registry().get_arc::<dyn LogicalEntityCommandRepository>().is_none() {
             debug!("LogicalEntityCommandRepository no encontrado, registrando ahora...");
             let repo = Arc::new(LogicalEntityCommandRepositoryImpl::new());
-            builder.register_arc_service::<dyn LogicalEntityCommandRepository>(repo.clone());
-            repo
-        };
+            builder.register_arc_service::<dyn LogicalEntityCommandRepository>(repo);
+        }
         // -------------------------------------
 
-        // --- Registrar Caso de Uso ---
-        let create_le_use_case_impl = Arc::new(
-            CreateLogicalEntityUseCaseImpl::new(
-                le_command_repository.clone(),
-                le_query_repository.clone(), // Para validación de nombre, etc.
-                unit_of_work.clone() // Inyectar UoW
-            )
-        );
-        builder.register_arc_service::<dyn CreateLogicalEntityUseCase>(create_le_use_case_impl.clone());
-        debug!("CreateLogicalEntityUseCase registrado.");
-
-
-        // --- Registrar Controlador ---
-        let le_controller = Arc::new(
-            LogicalEntityController::new(create_le_use_case_impl /*, otros casos de uso LE */)
-        );
-        builder.register_arc_service(le_controller);
-        debug!("LogicalEntityController registrado.");
-        // ---------------------------
+        // --- Registrar Casos de Uso ---
+        let create_uc = Arc::new(CreateEntityWithAttributesUseCaseImpl::new(
+            unit_of_work.clone(),
+            le_query_repository.clone(),
+            attribute_query_repository.clone(),
+        ));
+        builder.register_arc_service::<dyn CreateEntityWithAttributesUseCase>(create_uc);
+
+        let find_by_id_uc = Arc::new(FindLogicalEntityByIdUseCaseImpl::new(
+            le_query_repository.clone(),
+            attribute_query_repository.clone(),
+        ));
+        builder.register_arc_service::<dyn FindLogicalEntityByIdUseCase>(find_by_id_uc);
+
+        let find_by_name_uc = Arc::new(FindLogicalEntityByNameUseCaseImpl::new(
+            le_query_repository.clone(),
+            attribute_query_repository.clone(),
+        ));
+        builder.register_arc_service::<dyn FindLogicalEntityByNameUseCase>(find_by_name_uc);
+
+        let list_uc = Arc::new(ListLogicalEntitiesUseCaseImpl::new(
+            le_query_repository.clone(),
+            attribute_query_repository.clone(),
+        ));
+        builder.register_arc_service::<dyn ListLogicalEntitiesUseCase>(list_uc);
+
+        let update_uc = Arc::new(UpdateLogicalEntityUseCaseImpl::new(
+            le_query_repository.clone(),
+            attribute_query_repository.clone(),
+            view_repository.clone(),
+            unit_of_work.clone(),
+        ));
+        builder.register_arc_service::<dyn UpdateLogicalEntityUseCase>(update_uc);
+
+        let delete_uc = Arc::new(DeleteLogicalEntityUseCaseImpl::new(
+            le_query_repository.clone(),
+            view_repository.clone(),
+            unit_of_work.clone(),
+        ));
+        builder.register_arc_service::<dyn DeleteLogicalEntityUseCase>(delete_uc);
+
+        let refresh_view_uc = Arc::new(RefreshEntityViewUseCaseImpl::new(
+            le_query_repository.clone(),
+            attribute_query_repository.clone(),
+            view_repository.clone(),
+            unit_of_work.clone(),
+        ));
+        builder.register_arc_service::<dyn RefreshEntityViewUseCase>(refresh_view_uc);
+
+        // Vistas materializadas: opciones, estado y refresco
+        let view_status_uc = Arc::new(EntityViewStatusUseCaseImpl::new(
+            le_query_repository.clone(),
+            view_repository.clone(),
+        ));
+        builder.register_arc_service::<dyn EntityViewStatusUseCase>(view_status_uc);
+
+        let view_settings_uc = Arc::new(UpdateEntityViewSettingsUseCaseImpl::new(
+            le_query_repository.clone(),
+            attribute_query_repository.clone(),
+            view_repository.clone(),
+            unit_of_work.clone(),
+        ));
+        builder.register_arc_service::<dyn UpdateEntityViewSettingsUseCase>(view_settings_uc);
+
+        let refresh_materialized_view_uc = Arc::new(RefreshMaterializedViewUseCaseImpl::new(
+            le_query_repository.clone(),
+            view_repository.clone(),
+        ));
+        builder.register_arc_service::<dyn RefreshMaterializedViewUseCase>(refresh_materialized_view_uc);
+
+        // Modo de almacenamiento (EAV o tabla física)
+        let storage_mode_uc = Arc::new(ChangeStorageModeUseCaseImpl::new(
+            le_query_repository.clone(),
+            attribute_query_repository.clone(),
+            view_repository.clone(),
+            unit_of_work.clone(),
+        ));
+        builder.register_arc_service::<dyn ChangeStorageModeUseCase>(storage_mode_uc);
+
+        let json_schema_uc = Arc::new(EntityJsonSchemaUseCaseImpl::new(
+            le_query_repository.clone(),
+            attribute_query_repository.clone(),
+        ));
+        builder.register_arc_service::<dyn EntityJsonSchemaUseCase>(json_schema_uc);
+
+        let list_json_schemas_uc = Arc::new(ListEntityJsonSchemasUseCaseImpl::new(
+            le_query_repository.clone(),
+            attribute_query_repository.clone(),
+        ));
+        builder.register_arc_service::<dyn ListEntityJsonSchemasUseCase>(list_json_schemas_uc);
+
+        // Papelera de entidades lógicas
+        let list_deleted_uc = Arc::new(ListDeletedLogicalEntitiesUseCaseImpl::new(le_query_repository.clone()));
+        builder.register_arc_service::<dyn ListDeletedLogicalEntitiesUseCase>(list_deleted_uc);
+
+        let restore_uc = Arc::new(RestoreLogicalEntityUseCaseImpl::new(
+            le_query_repository.clone(),
+            attribute_query_repository.clone(),
+            view_repository.clone(),
+            unit_of_work.clone(),
+        ));
+        builder.register_arc_service::<dyn RestoreLogicalEntityUseCase>(restore_uc);
+
+        let purge_uc = Arc::new(PurgeLogicalEntityUseCaseImpl::new(unit_of_work.clone()));
+        builder.register_arc_service::<dyn PurgeLogicalEntityUseCase>(purge_uc);
+        debug!("Casos de uso de Logical Entity registrados.");
+
+        // --- Evolución del esquema (atributos) ---
+        let add_attribute_uc = Arc::new(AddAttributeUseCaseImpl::new(
+            le_query_repository.clone(),
+            attribute_query_repository.clone(),
+            data_type_query_repository.clone(),
+            record_query_repository.clone(),
+            view_repository.clone(),
+            unit_of_work.clone(),
+        ));
+        builder.register_arc_service::<dyn AddAttributeUseCase>(add_attribute_uc);
+
+        let update_attribute_uc = Arc::new(UpdateAttributeUseCaseImpl::new(
+            le_query_repository.clone(),
+            attribute_query_repository.clone(),
+            view_repository.clone(),
+            unit_of_work.clone(),
+        ));
+        builder.register_arc_service::<dyn UpdateAttributeUseCase>(update_attribute_uc);
+
+        let reorder_attributes_uc = Arc::new(ReorderAttributesUseCaseImpl::new(
+            le_query_repository.clone(),
+            attribute_query_repository.clone(),
+            view_repository.clone(),
+            unit_of_work.clone(),
+        ));
+        builder.register_arc_service::<dyn ReorderAttributesUseCase>(reorder_attributes_uc);
+
+        let change_data_type_uc = Arc::new(ChangeAttributeDataTypeUseCaseImpl::new(
+            le_query_repository.clone(),
+            attribute_query_repository.clone(),
+            data_type_query_repository.clone(),
+            view_repository.clone(),
+            unit_of_work.clone(),
+        ));
+        builder.register_arc_service::<dyn ChangeAttributeDataTypeUseCase>(change_data_type_uc);
+
+        let replace_options_uc = Arc::new(ReplaceAttributeOptionsUseCaseImpl::new(
+            le_query_repository.clone(),
+            attribute_query_repository.clone(),
+            unit_of_work.clone(),
+        ));
+        builder.register_arc_service::<dyn ReplaceAttributeOptionsUseCase>(replace_options_uc);
+        debug!("Casos de uso de atributos registrados.");
+
+        // --- Versiones de la definición ---
+        let list_versions_uc = Arc::new(ListSchemaVersionsUseCaseImpl::new(
+            le_query_repository.clone(),
+            schema_version_query_repository.clone(),
+        ));
+        builder.register_arc_service::<dyn ListSchemaVersionsUseCase>(list_versions_uc);
+
+        let find_version_uc = Arc::new(FindSchemaVersionUseCaseImpl::new(schema_version_query_repository.clone()));
+        builder.register_arc_service::<dyn FindSchemaVersionUseCase>(find_version_uc);
+
+        let find_version_as_of_uc = Arc::new(FindSchemaVersionAsOfUseCaseImpl::new(
+            le_query_repository.clone(),
+            schema_version_query_repository.clone(),
+        ));
+        builder.register_arc_service::<dyn FindSchemaVersionAsOfUseCase>(find_version_as_of_uc);
+
+        let diff_versions_uc = Arc::new(DiffSchemaVersionsUseCaseImpl::new(schema_version_query_repository.clone()));
+        builder.register_arc_service::<dyn DiffSchemaVersionsUseCase>(diff_versions_uc);
+
+        let rollback_version_uc = Arc::new(RollbackSchemaVersionUseCaseImpl::new(
+            le_query_repository.clone(),
+            attribute_query_repository.clone(),
+            data_type_query_repository.clone(),
+            schema_version_query_repository.clone(),
+            view_repository.clone(),
+            unit_of_work.clone(),
+        ));
+        builder.register_arc_service::<dyn RollbackSchemaVersionUseCase>(rollback_version_uc);
+        debug!("Casos de uso de versiones registrados.");
 
         info!("Módulo de Logical Entity registrado correctamente.");
         Ok(())
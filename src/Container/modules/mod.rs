@@ -5,6 +5,12 @@ pub mod health_module;
 pub mod database_module;
 pub mod repository_module;
 pub mod controller_module;
+pub mod logical_entity_module;
+pub mod record_module;
+pub mod data_type_module;
+pub mod schema_bundle_module;
+pub mod graphql_module;
+pub mod trash_module;
 
 use crate::Container::builder::ContainerBuilder;
 use anyhow::Result;
@@ -24,6 +30,18 @@ pub async fn register_all(builder: &mut ContainerBuilder) -> Result<()> {
     auth_module::AuthModule::register(builder)?;
     // 4. User (registra UserCommandRepo y casos de uso de User, depende de AuthService y UserQueryRepository)
     user_module::UserModule::register(builder)?;
+    // 4b. Logical Entity (registra casos de uso de entidades lógicas, depende de repos de consulta y UoW)
+    logical_entity_module::LogicalEntityModule::register(builder)?;
+    // 4c. Records (registra casos de uso de registros/tuplas, depende de repos de consulta y UoW)
+    record_module::RecordModule::register(builder)?;
+    // 4d. Data Types (registro de tipos de datos, depende de repos de consulta, vistas y UoW)
+    data_type_module::DataTypeModule::register(builder)?;
+    // 4e. Schema Bundles (se apoyan en los casos de uso de entidades, atributos y tipos de datos)
+    schema_bundle_module::SchemaBundleModule::register(builder)?;
+    // 4f. GraphQL (esquema generado a partir de las entidades; delega en los casos de uso de registros)
+    graphql_module::GraphQLModule::register(builder)?;
+    // 4g. Papelera (purga por antigüedad de registros, entidades lógicas y usuarios)
+    trash_module::TrashModule::register(builder)?;
     // 5. Controllers (dependen de Casos de Uso registrados por los módulos anteriores)
     controller_module::register_controller_dependencies(builder).await?;
     // 6. Health (depende de monitores, etc.)
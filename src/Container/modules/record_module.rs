@@ -0,0 +1,149 @@
+use std::sync::Arc;
+use anyhow::Result;
+use log::{info, debug};
+
+use crate::Container::builder::ContainerBuilder;
+// --- Traits y Structs Necesarias ---
+use crate::Application::ports::driven::repositories::{
+    LogicalEntityQueryRepository, AttributeQueryRepository, RecordQueryRepository, RecordBatchRepository,
+};
+use crate::Application::ports::unit_of_work::UnitOfWork;
+use crate::Application::use_cases::records::{
+    CreateRecordUseCase, CreateRecordUseCaseImpl,
+    FindRecordUseCase, FindRecordUseCaseImpl,
+    ListRecordsUseCase, ListRecordsUseCaseImpl,
+    UpdateRecordUseCase, UpdateRecordUseCaseImpl,
+    DeleteRecordUseCase, DeleteRecordUseCaseImpl,
+    SearchRecordsUseCase, SearchRecordsUseCaseImpl,
+    ImportRecordsUseCase, ImportRecordsUseCaseImpl,
+    ExportRecordsUseCase, ExportRecordsUseCaseImpl,
+    ListDeletedRecordsUseCase, ListDeletedRecordsUseCaseImpl,
+    RestoreRecordUseCase, RestoreRecordUseCaseImpl,
+    PurgeRecordUseCase, PurgeRecordUseCaseImpl,
+    RecordHistoryUseCase, RecordHistoryUseCaseImpl,
+    FindRecordAsOfUseCase, FindRecordAsOfUseCaseImpl,
+};
+
+pub struct RecordModule;
+
+impl RecordModule {
+    /// Registra los casos de uso de registros (tuplas).
+    /// El controlador se construye en controller_module a partir de estos casos de uso.
+    pub fn register(builder: &mut ContainerBuilder) -> Result<()> {
+        debug!("Registrando componentes del módulo de Records...");
+
+        // --- Obtener Dependencias ---
+        let le_query_repository = builder.registry().get_arc::<dyn LogicalEntityQueryRepository>()
+            .expect("LogicalEntityQueryRepository not registered. Ensure RepositoryModule runs before RecordModule.");
+        let attribute_query_repository = builder.registry().get_arc::<dyn AttributeQueryRepository>()
+            .expect("AttributeQueryRepository not registered. Ensure RepositoryModule runs before RecordModule.");
+        let record_query_repository = builder.registry().get_arc::<dyn RecordQueryRepository>()
+            .expect("RecordQueryRepository not registered. Ensure RepositoryModule runs before RecordModule.");
+        let record_batch_repository = builder.registry().get_arc::<dyn RecordBatchRepository>()
+            .expect("RecordBatchRepository not registered. Ensure RepositoryModule runs before RecordModule.");
+        let unit_of_work = builder.registry().get_arc::<dyn UnitOfWork>()
+            .expect("UnitOfWork not registered. Ensure DatabaseModule runs before RecordModule.");
+        // --------------------------
+
+        // --- Registrar Casos de Uso ---
+        let create_uc = Arc::new(CreateRecordUseCaseImpl::new(
+            le_query_repository.clone(),
+            attribute_query_repository.clone(),
+            record_query_repository.clone(),
+            unit_of_work.clone(),
+        ));
+        builder.register_arc_service::<dyn CreateRecordUseCase>(create_uc);
+
+        let find_uc = Arc::new(FindRecordUseCaseImpl::new(
+            le_query_repository.clone(),
+            attribute_query_repository.clone(),
+            record_query_repository.clone(),
+        ));
+        builder.register_arc_service::<dyn FindRecordUseCase>(find_uc);
+
+        let list_uc = Arc::new(ListRecordsUseCaseImpl::new(
+            le_query_repository.clone(),
+            attribute_query_repository.clone(),
+            record_query_repository.clone(),
+        ));
+        builder.register_arc_service::<dyn ListRecordsUseCase>(list_uc);
+
+        let search_uc = Arc::new(SearchRecordsUseCaseImpl::new(
+            le_query_repository.clone(),
+            attribute_query_repository.clone(),
+            record_query_repository.clone(),
+        ));
+        builder.register_arc_service::<dyn SearchRecordsUseCase>(search_uc);
+
+        let import_uc = Arc::new(ImportRecordsUseCaseImpl::new(
+            le_query_repository.clone(),
+            attribute_query_repository.clone(),
+            record_batch_repository.clone(),
+        ));
+        builder.register_arc_service::<dyn ImportRecordsUseCase>(import_uc);
+
+        let export_uc = Arc::new(ExportRecordsUseCaseImpl::new(
+            le_query_repository.clone(),
+            attribute_query_repository.clone(),
+            record_query_repository.clone(),
+        ));
+        builder.register_arc_service::<dyn ExportRecordsUseCase>(export_uc);
+
+        let update_uc = Arc::new(UpdateRecordUseCaseImpl::new(
+            le_query_repository.clone(),
+            attribute_query_repository.clone(),
+            record_query_repository.clone(),
+            unit_of_work.clone(),
+        ));
+        builder.register_arc_service::<dyn UpdateRecordUseCase>(update_uc);
+
+        let delete_uc = Arc::new(DeleteRecordUseCaseImpl::new(
+            le_query_repository.clone(),
+            attribute_query_repository.clone(),
+            unit_of_work.clone(),
+        ));
+        builder.register_arc_service::<dyn DeleteRecordUseCase>(delete_uc);
+
+        // Papelera de registros
+        let list_deleted_uc = Arc::new(ListDeletedRecordsUseCaseImpl::new(
+            le_query_repository.clone(),
+            attribute_query_repository.clone(),
+            record_query_repository.clone(),
+        ));
+        builder.register_arc_service::<dyn ListDeletedRecordsUseCase>(list_deleted_uc);
+
+        let restore_uc = Arc::new(RestoreRecordUseCaseImpl::new(
+            le_query_repository.clone(),
+            attribute_query_repository.clone(),
+            record_query_repository.clone(),
+            unit_of_work.clone(),
+        ));
+        builder.register_arc_service::<dyn RestoreRecordUseCase>(restore_uc);
+
+        let purge_uc = Arc::new(PurgeRecordUseCaseImpl::new(
+            le_query_repository.clone(),
+            attribute_query_repository.clone(),
+            unit_of_work.clone(),
+        ));
+        builder.register_arc_service::<dyn PurgeRecordUseCase>(purge_uc);
+
+        // Historial de registros
+        let history_uc = Arc::new(RecordHistoryUseCaseImpl::new(
+            le_query_repository.clone(),
+            attribute_query_repository.clone(),
+            record_query_repository.clone(),
+        ));
+        builder.register_arc_service::<dyn RecordHistoryUseCase>(history_uc);
+
+        let as_of_uc = Arc::new(FindRecordAsOfUseCaseImpl::new(
+            le_query_repository.clone(),
+            attribute_query_repository.clone(),
+            record_query_repository.clone(),
+        ));
+        builder.register_arc_service::<dyn FindRecordAsOfUseCase>(as_of_uc);
+        debug!("Casos de uso de Records registrados.");
+
+        info!("Módulo de Records registrado correctamente.");
+        Ok(())
+    }
+}
@@ -2,6 +2,11 @@ use crate::Infrastructure::repositories::{ // Implementaciones
     UserQueryRepositorySqlx,
     LogicalEntityQueryRepositoryImpl,
     DataTypeQueryRepositoryImpl,
+    AttributeQueryRepositoryImpl,
+    RecordQueryRepositoryImpl,
+    SchemaVersionQueryRepositoryImpl,
+    SqlxViewRepository,
+    BatchRepository,
     // Añadir otras implementaciones de consulta si existen
 };
 // --- CORREGIDO: Usar ruta completa o 'super::super::ports' ---
@@ -9,9 +14,15 @@ use crate::Application::ports::driven::repositories::{ // Traits
     UserQueryRepository,
     LogicalEntityQueryRepository,
     DataTypeQueryRepository,
+    AttributeQueryRepository,
+    RecordQueryRepository,
+    SchemaVersionQueryRepository,
+    RecordBatchRepository,
     // Añadir otros traits de consulta si existen
 };
 
+use crate::Domain::views::ViewRepository;
+use crate::Infrastructure::repositories::sqlx_batch_repository::DEFAULT_BATCH_SIZE;
 use crate::Container::builder::ContainerBuilder;
 use std::sync::Arc;
 use sqlx::PgPool as SqlxPool; // Alias consistente
@@ -38,6 +49,30 @@ pub async fn register_repository_dependencies(builder: &mut ContainerBuilder) ->
     builder.register_arc_service::<dyn DataTypeQueryRepository>(dt_query_repo);
     debug!("DataTypeQueryRepository (SQLx) registrado.");
 
+    // --- Attribute ---
+    let attr_query_repo = Arc::new(AttributeQueryRepositoryImpl::with_pool(sqlx_pool.clone()));
+    builder.register_arc_service::<dyn AttributeQueryRepository>(attr_query_repo);
+    debug!("AttributeQueryRepository (SQLx) registrado.");
+
+    // --- Records (tuplas) ---
+    let record_query_repo = Arc::new(RecordQueryRepositoryImpl::with_pool(sqlx_pool.clone()));
+    builder.register_arc_service::<dyn RecordQueryRepository>(record_query_repo);
+    debug!("RecordQueryRepository (SQLx) registrado.");
+
+    let record_batch_repo = Arc::new(BatchRepository::with_pool(sqlx_pool.clone(), DEFAULT_BATCH_SIZE));
+    builder.register_arc_service::<dyn RecordBatchRepository>(record_batch_repo);
+    debug!("RecordBatchRepository (SQLx) registrado.");
+
+    // --- Versiones de la definición de las entidades ---
+    let schema_version_query_repo = Arc::new(SchemaVersionQueryRepositoryImpl::with_pool(sqlx_pool.clone()));
+    builder.register_arc_service::<dyn SchemaVersionQueryRepository>(schema_version_query_repo);
+    debug!("SchemaVersionQueryRepository (SQLx) registrado.");
+
+    // --- Vistas por entidad ---
+    let view_repo = Arc::new(SqlxViewRepository::new(sqlx_pool.as_ref().clone()));
+    builder.register_arc_service::<dyn ViewRepository>(view_repo);
+    debug!("ViewRepository (SQLx) registrado.");
+
     // --- Registrar otros repositorios de consulta aquí ---
 
     Ok(())
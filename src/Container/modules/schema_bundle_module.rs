@@ -0,0 +1,104 @@
+use std::sync::Arc;
+use anyhow::Result;
+use log::{info, debug};
+
+use crate::Container::builder::ContainerBuilder;
+use crate::Application::ports::driven::repositories::RecordQueryRepository;
+use crate::Domain::views::ViewRepository;
+// --- Casos de uso de los que dependen los bundles ---
+use crate::Application::use_cases::logical_entities::{
+    CreateEntityWithAttributesUseCase, FindLogicalEntityByIdUseCase, FindLogicalEntityByNameUseCase,
+    ListLogicalEntitiesUseCase, UpdateLogicalEntityUseCase,
+};
+use crate::Application::use_cases::attributes::{
+    AddAttributeUseCase, UpdateAttributeUseCase, ReorderAttributesUseCase, ChangeAttributeDataTypeUseCase,
+    ReplaceAttributeOptionsUseCase,
+};
+use crate::Application::use_cases::data_types::{ListDataTypesUseCase, CreateDataTypeUseCase, UpdateDataTypeUseCase};
+use crate::Application::use_cases::schema_bundles::{
+    ExportSchemaBundleUseCase, ExportSchemaBundleUseCaseImpl,
+    ImportSchemaBundleUseCase, ImportSchemaBundleUseCaseImpl,
+    PreviewEntitySchemaUseCase, PreviewEntitySchemaUseCaseImpl,
+};
+
+pub struct SchemaBundleModule;
+
+impl SchemaBundleModule {
+    /// Registra los casos de uso de exportación, importación y vista previa de bundles. Se apoyan
+    /// en los casos de uso de entidades, atributos y tipos de datos, por lo que este
+    /// módulo se registra después de LogicalEntityModule y DataTypeModule.
+    pub fn register(builder: &mut ContainerBuilder) -> Result<()> {
+        debug!("Registrando componentes del módulo de SchemaBundles...");
+
+        // --- Obtener Dependencias ---
+        let find_le_by_name_uc = builder.registry().get_arc::<dyn FindLogicalEntityByNameUseCase>()
+            .expect("FindLogicalEntityByNameUseCase not registered. Ensure LogicalEntityModule runs before SchemaBundleModule.");
+        let find_le_by_id_uc = builder.registry().get_arc::<dyn FindLogicalEntityByIdUseCase>()
+            .expect("FindLogicalEntityByIdUseCase not registered. Ensure LogicalEntityModule runs before SchemaBundleModule.");
+        let list_le_uc = builder.registry().get_arc::<dyn ListLogicalEntitiesUseCase>()
+            .expect("ListLogicalEntitiesUseCase not registered. Ensure LogicalEntityModule runs before SchemaBundleModule.");
+        let create_le_uc = builder.registry().get_arc::<dyn CreateEntityWithAttributesUseCase>()
+            .expect("CreateEntityWithAttributesUseCase not registered. Ensure LogicalEntityModule runs before SchemaBundleModule.");
+        let update_le_uc = builder.registry().get_arc::<dyn UpdateLogicalEntityUseCase>()
+            .expect("UpdateLogicalEntityUseCase not registered. Ensure LogicalEntityModule runs before SchemaBundleModule.");
+        let add_attribute_uc = builder.registry().get_arc::<dyn AddAttributeUseCase>()
+            .expect("AddAttributeUseCase not registered. Ensure LogicalEntityModule runs before SchemaBundleModule.");
+        let update_attribute_uc = builder.registry().get_arc::<dyn UpdateAttributeUseCase>()
+            .expect("UpdateAttributeUseCase not registered. Ensure LogicalEntityModule runs before SchemaBundleModule.");
+        let reorder_attributes_uc = builder.registry().get_arc::<dyn ReorderAttributesUseCase>()
+            .expect("ReorderAttributesUseCase not registered. Ensure LogicalEntityModule runs before SchemaBundleModule.");
+        let change_attribute_data_type_uc = builder.registry().get_arc::<dyn ChangeAttributeDataTypeUseCase>()
+            .expect("ChangeAttributeDataTypeUseCase not registered. Ensure LogicalEntityModule runs before SchemaBundleModule.");
+        let replace_attribute_options_uc = builder.registry().get_arc::<dyn ReplaceAttributeOptionsUseCase>()
+            .expect("ReplaceAttributeOptionsUseCase not registered. Ensure LogicalEntityModule runs before SchemaBundleModule.");
+        let list_data_types_uc = builder.registry().get_arc::<dyn ListDataTypesUseCase>()
+            .expect("ListDataTypesUseCase not registered. Ensure DataTypeModule runs before SchemaBundleModule.");
+        let create_data_type_uc = builder.registry().get_arc::<dyn CreateDataTypeUseCase>()
+            .expect("CreateDataTypeUseCase not registered. Ensure DataTypeModule runs before SchemaBundleModule.");
+        let update_data_type_uc = builder.registry().get_arc::<dyn UpdateDataTypeUseCase>()
+            .expect("UpdateDataTypeUseCase not registered. Ensure DataTypeModule runs before SchemaBundleModule.");
+        let record_query_repository = builder.registry().get_arc::<dyn RecordQueryRepository>()
+            .expect("RecordQueryRepository not registered. Ensure RepositoryModule runs before SchemaBundleModule.");
+        let view_repository = builder.registry().get_arc::<dyn ViewRepository>()
+            .expect("ViewRepository not registered. Ensure RepositoryModule runs before SchemaBundleModule.");
+        // --------------------------
+
+        // --- Registrar Casos de Uso ---
+        let export_uc = Arc::new(ExportSchemaBundleUseCaseImpl::new(
+            find_le_by_name_uc.clone(),
+            find_le_by_id_uc.clone(),
+            list_le_uc,
+            list_data_types_uc.clone(),
+        ));
+        builder.register_arc_service::<dyn ExportSchemaBundleUseCase>(export_uc);
+
+        let preview_uc = Arc::new(PreviewEntitySchemaUseCaseImpl::new(
+            find_le_by_name_uc.clone(),
+            find_le_by_id_uc.clone(),
+            list_data_types_uc.clone(),
+            record_query_repository,
+            view_repository,
+        ));
+        builder.register_arc_service::<dyn PreviewEntitySchemaUseCase>(preview_uc);
+
+        let import_uc = Arc::new(ImportSchemaBundleUseCaseImpl::new(
+            find_le_by_name_uc,
+            find_le_by_id_uc,
+            create_le_uc,
+            update_le_uc,
+            list_data_types_uc,
+            create_data_type_uc,
+            update_data_type_uc,
+            add_attribute_uc,
+            update_attribute_uc,
+            reorder_attributes_uc,
+            change_attribute_data_type_uc,
+            replace_attribute_options_uc,
+        ));
+        builder.register_arc_service::<dyn ImportSchemaBundleUseCase>(import_uc);
+        debug!("Casos de uso de SchemaBundles registrados.");
+
+        info!("Módulo de SchemaBundles registrado correctamente.");
+        Ok(())
+    }
+}
@@ -0,0 +1,45 @@
+use std::sync::Arc;
+use anyhow::Result;
+use log::{info, debug};
+
+use crate::Container::builder::ContainerBuilder;
+// --- Traits y Structs Necesarias ---
+use crate::Application::ports::driven::repositories::{
+    LogicalEntityQueryRepository, RecordQueryRepository, UserQueryRepository,
+};
+use crate::Application::ports::unit_of_work::UnitOfWork;
+use crate::Application::use_cases::trash::{PurgeTrashUseCase, PurgeTrashUseCaseImpl};
+
+pub struct TrashModule;
+
+impl TrashModule {
+    /// Registra el caso de uso de purga de la papelera por antigüedad. Lo usan
+    /// el endpoint DELETE /api/trash y la tarea programada de retención.
+    pub fn register(builder: &mut ContainerBuilder) -> Result<()> {
+        debug!("Registrando componentes del módulo de la papelera...");
+
+        // --- Obtener Dependencias ---
+        let le_query_repository = builder.registry().get_arc::<dyn LogicalEntityQueryRepository>()
+            .expect("LogicalEntityQueryRepository not registered. Ensure RepositoryModule runs before TrashModule.");
+        let record_query_repository = builder.registry().get_arc::<dyn RecordQueryRepository>()
+            .expect("RecordQueryRepository not registered. Ensure RepositoryModule runs before TrashModule.");
+        let user_query_repository = builder.registry().get_arc::<dyn UserQueryRepository>()
+            .expect("UserQueryRepository not registered. Ensure RepositoryModule runs before TrashModule.");
+        let unit_of_work = builder.registry().get_arc::<dyn UnitOfWork>()
+            .expect("UnitOfWork not registered. Ensure DatabaseModule runs before TrashModule.");
+        // --------------------------
+
+        // --- Registrar Casos de Uso ---
+        let purge_uc = Arc::new(PurgeTrashUseCaseImpl::new(
+            le_query_repository,
+            record_query_repository,
+            user_query_repository,
+            unit_of_work,
+        ));
+        builder.register_arc_service::<dyn PurgeTrashUseCase>(purge_uc);
+        debug!("Caso de uso de la papelera registrado.");
+
+        info!("Módulo de la papelera registrado correctamente.");
+        Ok(())
+    }
+}
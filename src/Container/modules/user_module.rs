@@ -10,6 +10,11 @@ use crate::Application::use_cases::user::find_by_username_optimized::FindUserByU
 use crate::Application::use_cases::user::find_all::FindAllUsersUseCaseImpl;
 use crate::Application::use_cases::user::update::UpdateUserUseCaseImpl;
 use crate::Application::use_cases::user::delete::DeleteUserUseCaseImpl;
+use crate::Application::use_cases::user::trash::{
+    ListDeletedUsersUseCase, ListDeletedUsersUseCaseImpl,
+    RestoreUserUseCase, RestoreUserUseCaseImpl,
+    PurgeUserUseCase, PurgeUserUseCaseImpl,
+};
 use crate::Application::use_cases::traits::{
     CreateUserUseCase, FindUserByIdUseCase, FindUserByUsernameUseCase,
     FindAllUsersUseCase, UpdateUserUseCase, DeleteUserUseCase,
@@ -30,6 +35,9 @@ struct UserUseCases {
     find_all: Arc<dyn FindAllUsersUseCase>,
     update_user: Arc<dyn UpdateUserUseCase>,
     delete_user: Arc<dyn DeleteUserUseCase>,
+    list_deleted: Arc<dyn ListDeletedUsersUseCase>,
+    restore_user: Arc<dyn RestoreUserUseCase>,
+    purge_user: Arc<dyn PurgeUserUseCase>,
 }
 
 pub struct UserModule;
@@ -129,6 +137,26 @@ impl UserModule {
         );
         builder.register_arc_service::<dyn DeleteUserUseCase>(delete_user_use_case_impl.clone());
 
+        // Papelera de usuarios
+        let list_deleted_users_use_case_impl = Arc::new(
+            ListDeletedUsersUseCaseImpl::new(user_query_repository.clone())
+        );
+        builder.register_arc_service::<dyn ListDeletedUsersUseCase>(list_deleted_users_use_case_impl.clone());
+
+        let restore_user_use_case_impl = Arc::new(
+            RestoreUserUseCaseImpl::new(
+                user_query_repository.clone(),
+                unit_of_work.clone(),
+                user_mapper.clone()
+            )
+        );
+        builder.register_arc_service::<dyn RestoreUserUseCase>(restore_user_use_case_impl.clone());
+
+        let purge_user_use_case_impl = Arc::new(
+            PurgeUserUseCaseImpl::new(unit_of_work.clone())
+        );
+        builder.register_arc_service::<dyn PurgeUserUseCase>(purge_user_use_case_impl.clone());
+
         debug!("Casos de uso de usuarios registrados");
         Ok(UserUseCases {
             create_user: create_user_use_case_impl,
@@ -137,6 +165,9 @@ impl UserModule {
             find_all: find_all_users_use_case_impl,
             update_user: update_user_use_case_impl,
             delete_user: delete_user_use_case_impl,
+            list_deleted: list_deleted_users_use_case_impl,
+            restore_user: restore_user_use_case_impl,
+            purge_user: purge_user_use_case_impl,
         })
     }
 
@@ -152,6 +183,9 @@ impl UserModule {
             use_cases.find_all,
             use_cases.update_user,
             use_cases.delete_user,
+            use_cases.list_deleted,
+            use_cases.restore_user,
+            use_cases.purge_user,
         ));
         // Registrar el tipo concreto UserController, ya que AppState lo espera así.
         builder.register_arc_service(user_controller);
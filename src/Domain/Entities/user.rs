@@ -51,7 +51,9 @@ pub struct User {
     pub created_at: DateTime<Utc>,
     pub created_by: Option<Uuid>,
     pub updated_at: Option<DateTime<Utc>>,
-    pub updated_by: Option<Uuid>
+    pub updated_by: Option<Uuid>,
+    /// Contador de versión (control de concurrencia optimista, se publica como ETag).
+    pub version: i32,
 }
 
 impl User {
@@ -98,6 +100,7 @@ impl User {
             created_by,
             updated_at: None,
             updated_by: None,
+            version: 1,
         })
     }
     
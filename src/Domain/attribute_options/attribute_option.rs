@@ -0,0 +1,87 @@
+// src/Domain/attribute_options/attribute_option.rs
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+use crate::Domain::errors::{DomainError, DomainResult};
+
+const MAX_CODE_LENGTH: usize = 100;
+
+/// Opción de un atributo de tipo enumeración. El código es lo que se almacena
+/// en los registros; la etiqueta y el orden son de presentación. Una opción
+/// inactiva no admite valores nuevos, pero los registros que ya la usan la conservan.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AttributeOption {
+    pub code: String,
+    pub label: String,
+    pub sort_order: i32,
+    pub is_active: bool,
+}
+
+/// Comprueba una lista completa de opciones: códigos y etiquetas no vacíos y
+/// códigos únicos dentro del atributo.
+pub fn validate_options(options: &[AttributeOption]) -> DomainResult<()> {
+    let mut codes = HashSet::new();
+    for option in options {
+        if option.code.trim().is_empty() {
+            return Err(invalid("el código de una opción no puede estar vacío"));
+        }
+        if option.code != option.code.trim() {
+            return Err(invalid(&format!("el código '{}' no puede empezar ni terminar con espacios", option.code)));
+        }
+        if option.code.chars().count() > MAX_CODE_LENGTH {
+            return Err(invalid(&format!("el código '{}' supera los {} caracteres", option.code, MAX_CODE_LENGTH)));
+        }
+        if option.label.trim().is_empty() {
+            return Err(invalid(&format!("la opción '{}' necesita una etiqueta", option.code)));
+        }
+        if !codes.insert(option.code.as_str()) {
+            return Err(invalid(&format!("el código '{}' está repetido", option.code)));
+        }
+    }
+    Ok(())
+}
+
+/// Comprueba que `code` es una opción activa de la lista.
+pub fn check_option_code(options: &[AttributeOption], code: &str) -> DomainResult<()> {
+    match options.iter().find(|o| o.code == code) {
+        Some(option) if option.is_active => Ok(()),
+        Some(_) => Err(invalid(&format!("la opción '{}' está desactivada", code))),
+        None if options.is_empty() => Err(invalid("el atributo no tiene opciones definidas")),
+        None => Err(invalid(&format!(
+            "'{}' no es una opción válida (admitidas: {})",
+            code,
+            options.iter().filter(|o| o.is_active).map(|o| o.code.as_str()).collect::<Vec<_>>().join(", ")
+        ))),
+    }
+}
+
+fn invalid(message: &str) -> DomainError {
+    DomainError::ValidationError(message.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn option(code: &str, is_active: bool) -> AttributeOption {
+        AttributeOption { code: code.to_string(), label: code.to_uppercase(), sort_order: 0, is_active }
+    }
+
+    #[test]
+    fn test_validate_options_rejects_duplicates_and_blank_codes() {
+        assert!(validate_options(&[option("a", true), option("b", false)]).is_ok());
+        assert!(validate_options(&[option("a", true), option("a", false)]).is_err());
+        assert!(validate_options(&[option(" ", true)]).is_err());
+        assert!(validate_options(&[option(" a", true)]).is_err());
+    }
+
+    #[test]
+    fn test_check_option_code_only_accepts_active_options() {
+        let options = [option("open", true), option("legacy", false)];
+        assert!(check_option_code(&options, "open").is_ok());
+        assert!(check_option_code(&options, "legacy").is_err());
+        assert!(check_option_code(&options, "OPEN").is_err());
+        assert!(check_option_code(&[], "open").is_err());
+    }
+}
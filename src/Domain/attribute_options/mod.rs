@@ -0,0 +1,6 @@
+// src/Domain/attribute_options/mod.rs
+// Listas de opciones (código, etiqueta, orden y estado) de los atributos de tipo enumeración.
+
+pub mod attribute_option;
+
+pub use attribute_option::{AttributeOption, validate_options, check_option_code};
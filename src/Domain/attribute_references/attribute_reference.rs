@@ -0,0 +1,80 @@
+// src/Domain/attribute_references/attribute_reference.rs
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::Domain::errors::{DomainError, DomainResult};
+
+/// Qué ocurre con los registros que referencian una tupla cuando esta se elimina.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReferenceDeleteAction {
+    /// La eliminación se rechaza mientras existan referencias.
+    #[default]
+    Restrict,
+    /// Los registros que referencian la tupla se eliminan con ella.
+    Cascade,
+    /// Se borra el valor de la referencia en los registros que la usan.
+    SetNull,
+}
+
+impl ReferenceDeleteAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReferenceDeleteAction::Restrict => "restrict",
+            ReferenceDeleteAction::Cascade => "cascade",
+            ReferenceDeleteAction::SetNull => "set_null",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "restrict" => Some(ReferenceDeleteAction::Restrict),
+            "cascade" => Some(ReferenceDeleteAction::Cascade),
+            "set_null" => Some(ReferenceDeleteAction::SetNull),
+            _ => None,
+        }
+    }
+}
+
+/// Configuración de un atributo de tipo referencia. Sus valores son IDs de tuplas
+/// de `entity_id`; `display_attribute_id` es el atributo de esa entidad que las
+/// vistas muestran junto al ID (`<atributo>_display`).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AttributeReference {
+    pub entity_id: Uuid,
+    #[serde(default)]
+    pub on_delete: ReferenceDeleteAction,
+    #[serde(default)]
+    pub display_attribute_id: Option<Uuid>,
+}
+
+/// Comprueba que la configuración es coherente con el resto del atributo: un
+/// atributo obligatorio no puede quedar vacío al eliminar la tupla referenciada.
+pub fn validate_reference(reference: &AttributeReference, is_required: bool) -> DomainResult<()> {
+    if is_required && reference.on_delete == ReferenceDeleteAction::SetNull {
+        return Err(DomainError::ValidationError(
+            "un atributo obligatorio no admite on_delete = set_null".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_null_is_rejected_for_required_attributes() {
+        let reference = AttributeReference {
+            entity_id: Uuid::new_v4(),
+            on_delete: ReferenceDeleteAction::SetNull,
+            display_attribute_id: None,
+        };
+        assert!(validate_reference(&reference, false).is_ok());
+        assert!(validate_reference(&reference, true).is_err());
+
+        let reference = AttributeReference { on_delete: ReferenceDeleteAction::Cascade, ..reference };
+        assert!(validate_reference(&reference, true).is_ok());
+    }
+}
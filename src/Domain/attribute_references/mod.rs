@@ -0,0 +1,6 @@
+// src/Domain/attribute_references/mod.rs
+// Atributos que referencian registros (tuplas) de otra entidad lógica, a modo de clave foránea.
+
+pub mod attribute_reference;
+
+pub use attribute_reference::{AttributeReference, ReferenceDeleteAction, validate_reference};
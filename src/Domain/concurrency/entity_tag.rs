@@ -0,0 +1,91 @@
+// src/Domain/concurrency/entity_tag.rs
+//
+// ETags derivados del contador de versión (`"3"`). If-Match usa la comparación
+// fuerte (las etiquetas débiles nunca coinciden) e If-None-Match la débil (RFC 9110).
+
+/// ETag fuerte de una versión.
+pub fn entity_tag(version: i32) -> String {
+    format!("\"{}\"", version)
+}
+
+/// Interpreta una etiqueta de la lista: (es débil, versión). Las etiquetas que no
+/// genera la API se ignoran y, por tanto, nunca coinciden.
+fn parse_tag(tag: &str) -> Option<(bool, i32)> {
+    let tag = tag.trim();
+    let (weak, tag) = match tag.strip_prefix("W/") {
+        Some(rest) => (true, rest),
+        None => (false, tag),
+    };
+    let version = tag.strip_prefix('"')?.strip_suffix('"')?.parse().ok()?;
+    Some((weak, version))
+}
+
+/// Precondición de escritura enviada en la cabecera If-Match.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VersionPrecondition {
+    /// `If-Match: *`: basta con que el recurso exista.
+    Any,
+    /// Versiones aceptadas (vacía si ninguna etiqueta es una versión válida).
+    Versions(Vec<i32>),
+}
+
+impl VersionPrecondition {
+    pub fn from_if_match(header: &str) -> Self {
+        if header.trim() == "*" {
+            return VersionPrecondition::Any;
+        }
+        let versions = header.split(',')
+            .filter_map(parse_tag)
+            .filter(|(weak, _)| !weak)
+            .map(|(_, version)| version)
+            .collect();
+        VersionPrecondition::Versions(versions)
+    }
+
+    /// Indica si la versión actual del recurso cumple la precondición.
+    pub fn is_met(&self, current: i32) -> bool {
+        match self {
+            VersionPrecondition::Any => true,
+            VersionPrecondition::Versions(versions) => versions.contains(&current),
+        }
+    }
+}
+
+/// Indica si la cabecera If-None-Match coincide con la versión actual
+/// (en un GET, el cliente ya tiene esa representación: 304).
+pub fn if_none_match_matches(header: &str, current: i32) -> bool {
+    if header.trim() == "*" {
+        return true;
+    }
+    header.split(',')
+        .filter_map(parse_tag)
+        .any(|(_, version)| version == current)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn entity_tag_is_quoted_version() {
+        assert_eq!(entity_tag(3), "\"3\"");
+    }
+
+    #[test]
+    fn if_match_uses_strong_comparison() {
+        assert_eq!(VersionPrecondition::from_if_match("*"), VersionPrecondition::Any);
+        assert!(VersionPrecondition::from_if_match("\"2\", \"3\"").is_met(3));
+        assert!(!VersionPrecondition::from_if_match("\"2\"").is_met(3));
+        assert!(!VersionPrecondition::from_if_match("W/\"3\"").is_met(3));
+        assert!(!VersionPrecondition::from_if_match("\"abc\"").is_met(3));
+    }
+
+    #[test]
+    fn if_none_match_uses_weak_comparison() {
+        assert!(if_none_match_matches("*", 1));
+        assert!(if_none_match_matches("W/\"4\"", 4));
+        assert!(if_none_match_matches("\"1\", \"4\"", 4));
+        assert!(!if_none_match_matches("\"5\"", 4));
+        assert!(!if_none_match_matches("4", 4));
+    }
+}
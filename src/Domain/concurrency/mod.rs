@@ -0,0 +1,7 @@
+// src/Domain/concurrency/mod.rs
+// Control de concurrencia optimista: los usuarios y los registros llevan un contador
+// de versión que se publica como ETag y se comprueba con If-Match / If-None-Match.
+
+pub mod entity_tag;
+
+pub use entity_tag::{entity_tag, if_none_match_matches, VersionPrecondition};
@@ -1,9 +1,370 @@
+// src/Domain/data_types/data_type.rs
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
-use serde::{Deserialize, Serialize}; // Si se necesita serializar
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)] // Añadir sqlx::FromRow si se usa query_as! directamente
+use crate::Domain::errors::{DomainError, DomainResult};
+use crate::Domain::records::StorageColumn;
+
+/// Tipos de Postgres que admiten modificadores (`varchar(50)`, `numeric(12,2)`).
+const CASTS_WITH_LENGTH: [&str; 4] = ["varchar", "character varying", "char", "character"];
+const CASTS_WITH_PRECISION: [&str; 2] = ["numeric", "decimal"];
+
+/// Parámetros opcionales de un tipo de dato. Cada parámetro solo tiene sentido
+/// para algunas columnas de almacenamiento (ver `validate_for`).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DataTypeParams {
+    pub max_length: Option<i32>,
+    pub numeric_precision: Option<i16>,
+    pub numeric_scale: Option<i16>,
+    pub min_value: Option<f64>,
+    pub max_value: Option<f64>,
+}
+
+impl DataTypeParams {
+    /// Comprueba que los parámetros son coherentes entre sí y con la columna.
+    pub fn validate_for(&self, column: StorageColumn) -> DomainResult<()> {
+        if let Some(max_length) = self.max_length {
+            if !matches!(column, StorageColumn::String | StorageColumn::Text) {
+                return Err(invalid("max_length solo se admite en tipos de texto"));
+            }
+            if max_length <= 0 {
+                return Err(invalid("max_length debe ser mayor que 0"));
+            }
+        }
+
+        if self.numeric_precision.is_some() || self.numeric_scale.is_some() {
+            if column != StorageColumn::Numeric {
+                return Err(invalid("numeric_precision y numeric_scale solo se admiten en tipos decimales"));
+            }
+            let precision = self.numeric_precision
+                .ok_or_else(|| invalid("numeric_scale requiere numeric_precision"))?;
+            if !(1..=1000).contains(&precision) {
+                return Err(invalid("numeric_precision debe estar entre 1 y 1000"));
+            }
+            if let Some(scale) = self.numeric_scale {
+                if !(0..=precision).contains(&scale) {
+                    return Err(invalid("numeric_scale debe estar entre 0 y numeric_precision"));
+                }
+            }
+        }
+
+        if self.min_value.is_some() || self.max_value.is_some() {
+            if !is_numeric(column) {
+                return Err(invalid("min_value y max_value solo se admiten en tipos numéricos"));
+            }
+            if self.min_value.into_iter().chain(self.max_value).any(|v| !v.is_finite()) {
+                return Err(invalid("min_value y max_value deben ser números finitos"));
+            }
+            if let (Some(min), Some(max)) = (self.min_value, self.max_value) {
+                if min > max {
+                    return Err(invalid("min_value no puede ser mayor que max_value"));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Naturaleza de un tipo de dato. Los valores de un tipo `enumeration` deben ser
+/// códigos de la lista de opciones de cada atributo (`attribute_options`); los de un
+/// tipo `reference`, IDs de registros (tuplas) de la entidad referenciada.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DataTypeKind {
+    #[default]
+    Scalar,
+    Enumeration,
+    Reference,
+}
+
+impl DataTypeKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DataTypeKind::Scalar => "scalar",
+            DataTypeKind::Enumeration => "enumeration",
+            DataTypeKind::Reference => "reference",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "scalar" => Some(DataTypeKind::Scalar),
+            "enumeration" => Some(DataTypeKind::Enumeration),
+            "reference" => Some(DataTypeKind::Reference),
+            _ => None,
+        }
+    }
+}
+
+/// Cómo se almacena un tipo de dato y cómo se expone en las vistas.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DataTypeStorage {
+    pub column: StorageColumn,
+    pub pg_cast: String,
+    pub params: DataTypeParams,
+    #[serde(default)]
+    pub kind: DataTypeKind,
+}
+
+impl DataTypeStorage {
+    /// Construye y valida la definición. Sin `pg_cast` se usa el tipo de la columna,
+    /// ajustado a los parámetros (`varchar(n)` o `numeric(p,s)`).
+    pub fn new(column: StorageColumn, pg_cast: Option<&str>, params: DataTypeParams) -> DomainResult<Self> {
+        let pg_cast = match pg_cast.map(normalize_cast).filter(|c| !c.is_empty()) {
+            Some(cast) => cast,
+            None => Self::default_cast(column, &params),
+        };
+        let storage = Self { column, pg_cast, params, kind: DataTypeKind::Scalar };
+        storage.validate()?;
+        Ok(storage)
+    }
+
+    /// Cambia la naturaleza del tipo. Los códigos de una enumeración se guardan
+    /// como texto y las referencias, como UUID.
+    pub fn with_kind(mut self, kind: DataTypeKind) -> DomainResult<Self> {
+        self.kind = kind;
+        self.validate()?;
+        Ok(self)
+    }
+
+    pub fn is_enumeration(&self) -> bool {
+        self.kind == DataTypeKind::Enumeration
+    }
+
+    pub fn is_reference(&self) -> bool {
+        self.kind == DataTypeKind::Reference
+    }
+
+    /// Cast por defecto: el tipo de la columna, ajustado a los parámetros.
+    pub fn default_cast(column: StorageColumn, params: &DataTypeParams) -> String {
+        match (column, params.max_length, params.numeric_precision) {
+            (StorageColumn::String, Some(max_length), _) => format!("varchar({})", max_length),
+            (StorageColumn::Numeric, _, Some(precision)) => {
+                format!("numeric({},{})", precision, params.numeric_scale.unwrap_or(0))
+            },
+            _ => column.pg_type().to_string(),
+        }
+    }
+
+    pub fn validate(&self) -> DomainResult<()> {
+        validate_cast(self.column, &self.pg_cast)?;
+        if self.is_enumeration() && !matches!(self.column, StorageColumn::String | StorageColumn::Text) {
+            return Err(invalid("las enumeraciones solo se admiten en columnas de texto"));
+        }
+        if self.is_reference() && self.column != StorageColumn::Uuid {
+            return Err(invalid("las referencias solo se admiten en la columna uuid_value"));
+        }
+        self.params.validate_for(self.column)
+    }
+
+    /// Expresión de la columna en la vista de la entidad (`av_0.numeric_value::numeric(12,2)`).
+    /// El cast se incrusta en el SQL: solo se admiten los validados por `validate_cast`.
+    pub fn view_expression(&self, alias: &str) -> String {
+        self.cast_expression(&format!("{}.{}", alias, self.column.column_name()))
+    }
+
+    /// Aplica el cast del tipo a una expresión que ya tiene el tipo de la columna de
+    /// almacenamiento (p. ej. la columna pivotada `pv.v_0` de la vista por agregación).
+    pub fn cast_expression(&self, expression: &str) -> String {
+        if self.pg_cast == self.column.pg_type() {
+            expression.to_string()
+        } else {
+            format!("{}::{}", expression, self.pg_cast)
+        }
+    }
+
+    /// Comprueba un valor, ya en su representación de almacenamiento (ver `to_storage_text`),
+    /// contra los parámetros del tipo.
+    pub fn check_value(&self, text: &str) -> DomainResult<()> {
+        let params = &self.params;
+
+        if let Some(max_length) = params.max_length {
+            let length = text.chars().count();
+            if length > max_length as usize {
+                return Err(invalid(&format!("el valor tiene {} caracteres y el máximo es {}", length, max_length)));
+            }
+        }
+
+        if self.column == StorageColumn::Numeric {
+            if let Some((int_digits, frac_digits)) = decimal_digits(text) {
+                let scale = params.numeric_scale.unwrap_or(0);
+                if params.numeric_scale.is_some() && frac_digits > scale as usize {
+                    return Err(invalid(&format!("el valor admite como máximo {} decimales", scale)));
+                }
+                if let Some(precision) = params.numeric_precision {
+                    let max_int_digits = (precision - scale) as usize;
+                    if int_digits > max_int_digits {
+                        return Err(invalid(&format!("el valor admite como máximo {} dígitos enteros", max_int_digits)));
+                    }
+                }
+            }
+        }
+
+        if is_numeric(self.column) {
+            if let Ok(number) = text.trim().parse::<f64>() {
+                if let Some(min) = params.min_value.filter(|min| number < *min) {
+                    return Err(invalid(&format!("el valor {} es menor que el mínimo permitido ({})", text, min)));
+                }
+                if let Some(max) = params.max_value.filter(|max| number > *max) {
+                    return Err(invalid(&format!("el valor {} es mayor que el máximo permitido ({})", text, max)));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Tipo de dato del registro (`data_types`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DataType {
     pub id: Uuid,
     pub name: String,
-    // pub description: Option<String>, // Opcional
+    pub description: Option<String>,
+    pub storage: DataTypeStorage,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_by: Option<Uuid>,
+    pub updated_at: Option<DateTime<Utc>>,
+    pub status: i16,
+}
+
+fn invalid(message: &str) -> DomainError {
+    DomainError::ValidationError(message.to_string())
+}
+
+fn is_numeric(column: StorageColumn) -> bool {
+    matches!(column, StorageColumn::Integer | StorageColumn::Float | StorageColumn::Numeric)
+}
+
+/// Minúsculas, espacios simples y sin espacios alrededor de los modificadores.
+fn normalize_cast(cast: &str) -> String {
+    cast.to_lowercase()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .replace(" (", "(")
+        .replace("( ", "(")
+        .replace(" )", ")")
+        .replace(" ,", ",")
+        .replace(", ", ",")
+}
+
+/// El cast debe ser un tipo compatible con la columna, con modificadores numéricos
+/// opcionales: `varchar(50)`, `numeric(12,2)`. Cualquier otro texto se rechaza.
+fn validate_cast(column: StorageColumn, cast: &str) -> DomainResult<()> {
+    let (base, modifiers) = match cast.split_once('(') {
+        Some((base, rest)) => {
+            let inner = rest.strip_suffix(')')
+                .ok_or_else(|| invalid(&format!("cast '{}' mal formado", cast)))?;
+            (base, Some(inner))
+        },
+        None => (cast, None),
+    };
+
+    if !column.compatible_casts().contains(&base) {
+        return Err(invalid(&format!(
+            "el cast '{}' no es compatible con la columna {} (admitidos: {})",
+            cast, column.column_name(), column.compatible_casts().join(", ")
+        )));
+    }
+
+    if let Some(inner) = modifiers {
+        let numbers: Vec<&str> = inner.split(',').collect();
+        let max_numbers = if CASTS_WITH_PRECISION.contains(&base) {
+            2
+        } else if CASTS_WITH_LENGTH.contains(&base) {
+            1
+        } else {
+            0
+        };
+        let well_formed = numbers.iter().all(|n| !n.is_empty() && n.len() <= 4 && n.chars().all(|c| c.is_ascii_digit()));
+        if numbers.len() > max_numbers || !well_formed {
+            return Err(invalid(&format!("modificadores no válidos en el cast '{}'", cast)));
+        }
+    }
+    Ok(())
+}
+
+/// Dígitos significativos (enteros, decimales) de un literal decimal simple.
+/// Devuelve `None` para otras notaciones (p. ej. exponencial).
+fn decimal_digits(text: &str) -> Option<(usize, usize)> {
+    let unsigned = text.trim().trim_start_matches(['-', '+']);
+    let (int_part, frac_part) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+    if !int_part.chars().chain(frac_part.chars()).all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    Some((int_part.trim_start_matches('0').len(), frac_part.trim_end_matches('0').len()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_derives_cast_from_params_and_rejects_unsafe_casts() {
+        let params = DataTypeParams { numeric_precision: Some(12), numeric_scale: Some(2), ..Default::default() };
+        let storage = DataTypeStorage::new(StorageColumn::Numeric, None, params).unwrap();
+        assert_eq!(storage.pg_cast, "numeric(12,2)");
+        assert_eq!(storage.view_expression("av_0"), "av_0.numeric_value::numeric(12,2)");
+
+        let storage = DataTypeStorage::new(StorageColumn::String, Some("VARCHAR ( 50 )"), DataTypeParams::default()).unwrap();
+        assert_eq!(storage.pg_cast, "varchar(50)");
+
+        assert!(DataTypeStorage::new(StorageColumn::Integer, Some("text; DROP TABLE users"), DataTypeParams::default()).is_err());
+        assert!(DataTypeStorage::new(StorageColumn::Binary, Some("uuid"), DataTypeParams::default()).is_err());
+        assert!(DataTypeStorage::new(StorageColumn::Integer, Some("bigint(10)"), DataTypeParams::default()).is_err());
+    }
+
+    #[test]
+    fn test_enumeration_requires_text_column() {
+        let storage = DataTypeStorage::new(StorageColumn::String, None, DataTypeParams::default()).unwrap();
+        assert!(storage.with_kind(DataTypeKind::Enumeration).unwrap().is_enumeration());
+
+        let storage = DataTypeStorage::new(StorageColumn::Integer, None, DataTypeParams::default()).unwrap();
+        assert!(storage.with_kind(DataTypeKind::Enumeration).is_err());
+    }
+
+    #[test]
+    fn test_reference_requires_uuid_column() {
+        let storage = DataTypeStorage::new(StorageColumn::Uuid, None, DataTypeParams::default()).unwrap();
+        assert!(storage.with_kind(DataTypeKind::Reference).unwrap().is_reference());
+
+        let storage = DataTypeStorage::new(StorageColumn::String, None, DataTypeParams::default()).unwrap();
+        assert!(storage.with_kind(DataTypeKind::Reference).is_err());
+    }
+
+    #[test]
+    fn test_params_must_match_column() {
+        let params = DataTypeParams { max_length: Some(10), ..Default::default() };
+        assert!(params.validate_for(StorageColumn::Integer).is_err());
+
+        let params = DataTypeParams { numeric_precision: Some(4), numeric_scale: Some(6), ..Default::default() };
+        assert!(params.validate_for(StorageColumn::Numeric).is_err());
+
+        let params = DataTypeParams { min_value: Some(10.0), max_value: Some(1.0), ..Default::default() };
+        assert!(params.validate_for(StorageColumn::Float).is_err());
+    }
+
+    #[test]
+    fn test_check_value_applies_params() {
+        let params = DataTypeParams { max_length: Some(3), ..Default::default() };
+        let storage = DataTypeStorage::new(StorageColumn::String, None, params).unwrap();
+        assert!(storage.check_value("abc").is_ok());
+        assert!(storage.check_value("abcd").is_err());
+
+        let params = DataTypeParams {
+            numeric_precision: Some(5),
+            numeric_scale: Some(2),
+            min_value: Some(0.0),
+            ..Default::default()
+        };
+        let storage = DataTypeStorage::new(StorageColumn::Numeric, None, params).unwrap();
+        assert!(storage.check_value("999.99").is_ok());
+        assert!(storage.check_value("1.50").is_ok());
+        assert!(storage.check_value("1.505").is_err());
+        assert!(storage.check_value("1000").is_err());
+        assert!(storage.check_value("-1").is_err());
+    }
 }
@@ -0,0 +1,6 @@
+// src/Domain/data_types/mod.rs
+// Registro de tipos de datos: columna de almacenamiento, cast de Postgres y parámetros.
+
+pub mod data_type;
+
+pub use data_type::{DataType, DataTypeStorage, DataTypeParams, DataTypeKind};
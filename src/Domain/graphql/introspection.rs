@@ -0,0 +1,248 @@
+// src/Domain/graphql/introspection.rs
+//
+// Introspección del esquema (`__schema`, `__type` y `__typename`): los tipos
+// `__Schema`, `__Type`, ... se resuelven directamente sobre el modelo del esquema,
+// sin acceso a datos.
+
+use serde_json::{json, Map, Value};
+
+use super::parser::TypeRef;
+use super::planner::PlannedField;
+use super::schema::{
+    DirectiveDef, FieldDef, FieldResolver, InputValueDef, Schema, TypeDef, TypeKind,
+};
+
+const TYPE_KINDS: [&str; 8] = ["SCALAR", "OBJECT", "INTERFACE", "UNION", "ENUM", "INPUT_OBJECT", "LIST", "NON_NULL"];
+
+const DIRECTIVE_LOCATIONS: [&str; 19] = [
+    "QUERY", "MUTATION", "SUBSCRIPTION", "FIELD", "FRAGMENT_DEFINITION", "FRAGMENT_SPREAD", "INLINE_FRAGMENT",
+    "VARIABLE_DEFINITION", "SCHEMA", "SCALAR", "OBJECT", "FIELD_DEFINITION", "ARGUMENT_DEFINITION", "INTERFACE",
+    "UNION", "ENUM", "ENUM_VALUE", "INPUT_OBJECT", "INPUT_FIELD_DEFINITION",
+];
+
+/// Campos de introspección del tipo raíz de consultas (`__schema` y `__type`).
+/// No se listan entre los campos de `Query`.
+pub fn meta_field(name: &str) -> Option<FieldDef> {
+    match name {
+        "__schema" => Some(field("__schema", non_null("__Schema"))),
+        "__type" => Some(field("__type", named("__Type")).with_args(vec![
+            InputValueDef::new("name", non_null("String"), None),
+        ])),
+        _ => None,
+    }
+}
+
+/// Tipos del sistema de introspección.
+pub(super) fn introspection_types() -> Vec<TypeDef> {
+    let list_of = |name: &str| TypeRef::non_null(TypeRef::list(non_null(name)));
+    let nullable_list_of = |name: &str| TypeRef::list(non_null(name));
+    let include_deprecated = || vec![InputValueDef::new("includeDeprecated", named("Boolean"), None).with_default("false")];
+    let deprecation = || vec![
+        field("isDeprecated", non_null("Boolean")),
+        field("deprecationReason", named("String")),
+    ];
+
+    vec![
+        TypeDef::object("__Schema", None, vec![
+            field("description", named("String")),
+            field("types", list_of("__Type")),
+            field("queryType", non_null("__Type")),
+            field("mutationType", named("__Type")),
+            field("subscriptionType", named("__Type")),
+            field("directives", list_of("__Directive")),
+        ]),
+        TypeDef::object("__Type", None, vec![
+            field("kind", non_null("__TypeKind")),
+            field("name", named("String")),
+            field("description", named("String")),
+            field("specifiedByURL", named("String")),
+            field("fields", nullable_list_of("__Field")).with_args(include_deprecated()),
+            field("interfaces", nullable_list_of("__Type")),
+            field("possibleTypes", nullable_list_of("__Type")),
+            field("enumValues", nullable_list_of("__EnumValue")).with_args(include_deprecated()),
+            field("inputFields", nullable_list_of("__InputValue")).with_args(include_deprecated()),
+            field("ofType", named("__Type")),
+            field("isOneOf", named("Boolean")),
+        ]),
+        TypeDef::object("__Field", None, [
+            vec![
+                field("name", non_null("String")),
+                field("description", named("String")),
+                field("args", list_of("__InputValue")).with_args(include_deprecated()),
+                field("type", non_null("__Type")),
+            ],
+            deprecation(),
+        ].concat()),
+        TypeDef::object("__InputValue", None, [
+            vec![
+                field("name", non_null("String")),
+                field("description", named("String")),
+                field("type", non_null("__Type")),
+                field("defaultValue", named("String")),
+            ],
+            deprecation(),
+        ].concat()),
+        TypeDef::object("__EnumValue", None, [
+            vec![
+                field("name", non_null("String")),
+                field("description", named("String")),
+            ],
+            deprecation(),
+        ].concat()),
+        TypeDef::object("__Directive", None, vec![
+            field("name", non_null("String")),
+            field("description", named("String")),
+            field("locations", list_of("__DirectiveLocation")),
+            field("args", list_of("__InputValue")).with_args(include_deprecated()),
+            field("isRepeatable", non_null("Boolean")),
+        ]),
+        TypeDef::enumeration("__TypeKind", None, &TYPE_KINDS),
+        TypeDef::enumeration("__DirectiveLocation", None, &DIRECTIVE_LOCATIONS),
+    ]
+}
+
+fn field(name: &str, ty: TypeRef) -> FieldDef {
+    FieldDef::new(name, ty, FieldResolver::Introspection, None)
+}
+
+fn named(name: &str) -> TypeRef {
+    TypeRef::named(name)
+}
+
+fn non_null(name: &str) -> TypeRef {
+    TypeRef::non_null(TypeRef::named(name))
+}
+
+/// Objeto del sistema de introspección.
+#[derive(Clone, Copy)]
+enum Node<'s> {
+    Schema,
+    Type(TypeNode<'s>),
+    Field(&'s FieldDef),
+    InputValue(&'s InputValueDef),
+    EnumValue(&'s str),
+    Directive(&'s DirectiveDef),
+}
+
+/// Tipo con nombre, o lista / no nulo del tipo interior.
+#[derive(Clone, Copy)]
+enum TypeNode<'s> {
+    Named(&'s TypeDef),
+    List(&'s TypeRef),
+    NonNull(&'s TypeRef),
+}
+
+impl<'s> Node<'s> {
+    fn typename(&self) -> &'static str {
+        match self {
+            Node::Schema => "__Schema",
+            Node::Type(_) => "__Type",
+            Node::Field(_) => "__Field",
+            Node::InputValue(_) => "__InputValue",
+            Node::EnumValue(_) => "__EnumValue",
+            Node::Directive(_) => "__Directive",
+        }
+    }
+}
+
+/// Resuelve `__schema` o `__type(name)` con la selección ya planificada.
+pub fn resolve_meta_field(schema: &Schema, field: &PlannedField) -> Value {
+    match field.name.as_str() {
+        "__schema" => resolve(schema, Node::Schema, &field.selection),
+        "__type" => field.arguments.get("name")
+            .and_then(Value::as_str)
+            .and_then(|name| schema.type_def(name))
+            .map(|t| resolve(schema, Node::Type(TypeNode::Named(t)), &field.selection))
+            .unwrap_or(Value::Null),
+        _ => Value::Null,
+    }
+}
+
+fn resolve(schema: &Schema, node: Node<'_>, selection: &[PlannedField]) -> Value {
+    let mut object = Map::new();
+    for field in selection {
+        let value = match field.resolver {
+            FieldResolver::TypeName => json!(node.typename()),
+            _ => resolve_field(schema, node, field),
+        };
+        object.insert(field.response_key.clone(), value);
+    }
+    Value::Object(object)
+}
+
+fn type_node<'s>(schema: &'s Schema, ty: &'s TypeRef) -> Option<Node<'s>> {
+    match ty {
+        TypeRef::Named(name) => schema.type_def(name).map(|t| Node::Type(TypeNode::Named(t))),
+        TypeRef::List(inner) => Some(Node::Type(TypeNode::List(inner))),
+        TypeRef::NonNull(inner) => Some(Node::Type(TypeNode::NonNull(inner))),
+    }
+}
+
+fn resolve_field(schema: &Schema, node: Node<'_>, field: &PlannedField) -> Value {
+    let one = |node: Option<Node<'_>>| node.map(|n| resolve(schema, n, &field.selection)).unwrap_or(Value::Null);
+    let many = |nodes: Vec<Node<'_>>| Value::Array(nodes.into_iter().map(|n| resolve(schema, n, &field.selection)).collect());
+    let text = |value: Option<&str>| value.map(|v| json!(v)).unwrap_or(Value::Null);
+    let name = field.name.as_str();
+
+    match node {
+        Node::Schema => match name {
+            "types" => many(schema.types().iter().map(|t| Node::Type(TypeNode::Named(t))).collect()),
+            "queryType" => one(Some(Node::Type(TypeNode::Named(schema.query_type())))),
+            "mutationType" => one(schema.mutation_type().map(|t| Node::Type(TypeNode::Named(t)))),
+            "directives" => many(schema.directives().iter().map(Node::Directive).collect()),
+            _ => Value::Null,
+        },
+        Node::Type(TypeNode::Named(t)) => match name {
+            "kind" => json!(match t.kind {
+                TypeKind::Scalar => "SCALAR",
+                TypeKind::Object => "OBJECT",
+                TypeKind::InputObject => "INPUT_OBJECT",
+                TypeKind::Enum => "ENUM",
+            }),
+            "name" => json!(t.name),
+            "description" => text(t.description.as_deref()),
+            "fields" if t.kind == TypeKind::Object => many(t.fields.iter().map(Node::Field).collect()),
+            "interfaces" if t.kind == TypeKind::Object => json!([]),
+            "enumValues" if t.kind == TypeKind::Enum => {
+                many(t.enum_values.iter().map(|v| Node::EnumValue(v.as_str())).collect())
+            },
+            "inputFields" if t.kind == TypeKind::InputObject => many(t.input_fields.iter().map(Node::InputValue).collect()),
+            "isOneOf" if t.kind == TypeKind::InputObject => json!(false),
+            _ => Value::Null,
+        },
+        Node::Type(TypeNode::List(inner)) | Node::Type(TypeNode::NonNull(inner)) => match name {
+            "kind" => json!(if matches!(node, Node::Type(TypeNode::List(_))) { "LIST" } else { "NON_NULL" }),
+            "ofType" => one(type_node(schema, inner)),
+            _ => Value::Null,
+        },
+        Node::Field(f) => match name {
+            "name" => json!(f.name),
+            "description" => text(f.description.as_deref()),
+            "args" => many(f.args.iter().map(Node::InputValue).collect()),
+            "type" => one(type_node(schema, &f.ty)),
+            "isDeprecated" => json!(false),
+            _ => Value::Null,
+        },
+        Node::InputValue(v) => match name {
+            "name" => json!(v.name),
+            "description" => text(v.description.as_deref()),
+            "type" => one(type_node(schema, &v.ty)),
+            "defaultValue" => text(v.default_value.as_deref()),
+            "isDeprecated" => json!(false),
+            _ => Value::Null,
+        },
+        Node::EnumValue(v) => match name {
+            "name" => json!(v),
+            "isDeprecated" => json!(false),
+            _ => Value::Null,
+        },
+        Node::Directive(d) => match name {
+            "name" => json!(d.name),
+            "description" => json!(d.description),
+            "locations" => json!(d.locations),
+            "args" => many(d.args.iter().map(Node::InputValue).collect()),
+            "isRepeatable" => json!(false),
+            _ => Value::Null,
+        },
+    }
+}
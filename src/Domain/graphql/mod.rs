@@ -0,0 +1,16 @@
+// src/Domain/graphql/mod.rs
+// GraphQL sobre las entidades lógicas: análisis de documentos, esquema generado
+// a partir de los metadatos, planificación (validación y conversión de argumentos)
+// e introspección. La ejecución contra los registros está en la capa de aplicación.
+
+pub mod parser;
+pub mod schema;
+pub mod planner;
+pub mod introspection;
+
+pub use parser::{parse_document, Document, OperationType, TypeRef};
+pub use schema::{
+    EntityRef, FieldResolver, GraphQLAttribute, GraphQLEntity, Schema, DEFAULT_PAGE_SIZE,
+};
+pub use planner::{plan_operation, PlannedField, PlannedOperation, MAX_QUERY_DEPTH};
+pub use introspection::resolve_meta_field;
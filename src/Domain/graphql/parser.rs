@@ -0,0 +1,826 @@
+// src/Domain/graphql/parser.rs
+//
+// Analizador de documentos ejecutables de GraphQL (October 2021): operaciones
+// query y mutation, variables, alias, argumentos, fragmentos (con nombre y en
+// línea) y directivas. Las definiciones de tipos (SDL) no se admiten: el esquema
+// se genera a partir de los metadatos (ver `schema`).
+
+use crate::Domain::errors::{DomainError, DomainResult};
+
+use super::planner::MAX_QUERY_DEPTH;
+
+/// Longitud máxima del documento, en caracteres.
+pub const MAX_DOCUMENT_LENGTH: usize = 100_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OperationType {
+    Query,
+    Mutation,
+}
+
+impl OperationType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OperationType::Query => "query",
+            OperationType::Mutation => "mutation",
+        }
+    }
+}
+
+/// Referencia a un tipo: `Nombre`, `[Tipo]` o `Tipo!`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TypeRef {
+    Named(String),
+    List(Box<TypeRef>),
+    NonNull(Box<TypeRef>),
+}
+
+impl TypeRef {
+    pub fn named(name: &str) -> Self {
+        TypeRef::Named(name.to_string())
+    }
+
+    pub fn non_null(inner: TypeRef) -> Self {
+        TypeRef::NonNull(Box::new(inner))
+    }
+
+    pub fn list(inner: TypeRef) -> Self {
+        TypeRef::List(Box::new(inner))
+    }
+
+    /// Nombre del tipo sin listas ni `!`.
+    pub fn base_name(&self) -> &str {
+        match self {
+            TypeRef::Named(name) => name,
+            TypeRef::List(inner) | TypeRef::NonNull(inner) => inner.base_name(),
+        }
+    }
+
+    pub fn is_non_null(&self) -> bool {
+        matches!(self, TypeRef::NonNull(_))
+    }
+}
+
+impl std::fmt::Display for TypeRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TypeRef::Named(name) => write!(f, "{}", name),
+            TypeRef::List(inner) => write!(f, "[{}]", inner),
+            TypeRef::NonNull(inner) => write!(f, "{}!", inner),
+        }
+    }
+}
+
+/// Valor escrito en el documento (argumentos, valores por defecto y directivas).
+#[derive(Debug, Clone, PartialEq)]
+pub enum InputValue {
+    Variable(String),
+    Int(i64),
+    Float(f64),
+    String(String),
+    Boolean(bool),
+    Null,
+    Enum(String),
+    List(Vec<InputValue>),
+    Object(Vec<(String, InputValue)>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Directive {
+    pub name: String,
+    pub arguments: Vec<(String, InputValue)>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Field {
+    pub alias: Option<String>,
+    pub name: String,
+    pub arguments: Vec<(String, InputValue)>,
+    pub directives: Vec<Directive>,
+    pub selection_set: Vec<Selection>,
+}
+
+impl Field {
+    /// Clave del campo en la respuesta: el alias o, si no hay, el nombre.
+    pub fn response_key(&self) -> &str {
+        self.alias.as_deref().unwrap_or(&self.name)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Selection {
+    Field(Field),
+    FragmentSpread {
+        name: String,
+        directives: Vec<Directive>,
+    },
+    InlineFragment {
+        type_condition: Option<String>,
+        directives: Vec<Directive>,
+        selection_set: Vec<Selection>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct VariableDefinition {
+    pub name: String,
+    pub ty: TypeRef,
+    pub default_value: Option<InputValue>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct OperationDefinition {
+    pub operation: OperationType,
+    pub name: Option<String>,
+    pub variables: Vec<VariableDefinition>,
+    pub directives: Vec<Directive>,
+    pub selection_set: Vec<Selection>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FragmentDefinition {
+    pub name: String,
+    pub type_condition: String,
+    pub directives: Vec<Directive>,
+    pub selection_set: Vec<Selection>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Document {
+    pub operations: Vec<OperationDefinition>,
+    pub fragments: Vec<FragmentDefinition>,
+}
+
+impl Document {
+    /// Operación a ejecutar: la indicada por `operation_name` o, si no se indica,
+    /// la única del documento.
+    pub fn operation(&self, operation_name: Option<&str>) -> DomainResult<&OperationDefinition> {
+        match operation_name.filter(|n| !n.is_empty()) {
+            Some(name) => self.operations.iter()
+                .find(|o| o.name.as_deref() == Some(name))
+                .ok_or_else(|| invalid(&format!("la operación '{}' no existe en el documento", name))),
+            None if self.operations.len() == 1 => Ok(&self.operations[0]),
+            None => Err(invalid("el documento tiene varias operaciones: indique operationName")),
+        }
+    }
+
+    pub fn fragment(&self, name: &str) -> Option<&FragmentDefinition> {
+        self.fragments.iter().find(|f| f.name == name)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Punctuator(char),
+    Spread,
+    Name(String),
+    Int(String),
+    Float(String),
+    Str(String),
+}
+
+/// Analiza un documento ejecutable.
+pub fn parse_document(input: &str) -> DomainResult<Document> {
+    if input.chars().count() > MAX_DOCUMENT_LENGTH {
+        return Err(invalid(&format!("el documento admite como máximo {} caracteres", MAX_DOCUMENT_LENGTH)));
+    }
+    let tokens = tokenize(input)?;
+    if tokens.is_empty() {
+        return Err(invalid("el documento está vacío"));
+    }
+    let mut parser = Parser { tokens, position: 0, depth: 0 };
+    let mut document = Document::default();
+    while parser.peek().is_some() {
+        match parser.peek() {
+            Some(Token::Name(keyword)) if keyword == "fragment" => {
+                let fragment = parser.parse_fragment()?;
+                if document.fragment(&fragment.name).is_some() {
+                    return Err(invalid(&format!("el fragmento '{}' está definido más de una vez", fragment.name)));
+                }
+                document.fragments.push(fragment);
+            },
+            _ => document.operations.push(parser.parse_operation()?),
+        }
+    }
+
+    if document.operations.is_empty() {
+        return Err(invalid("el documento no contiene ninguna operación"));
+    }
+    if document.operations.len() > 1 && document.operations.iter().any(|o| o.name.is_none()) {
+        return Err(invalid("las operaciones sin nombre deben ser la única del documento"));
+    }
+    for (i, operation) in document.operations.iter().enumerate() {
+        if let Some(name) = &operation.name {
+            if document.operations[..i].iter().any(|o| o.name.as_ref() == Some(name)) {
+                return Err(invalid(&format!("la operación '{}' está definida más de una vez", name)));
+            }
+        }
+    }
+    Ok(document)
+}
+
+/// Analiza un valor constante suelto (p. ej. el valor por defecto de un argumento).
+pub fn parse_value_literal(input: &str) -> DomainResult<InputValue> {
+    let mut parser = Parser { tokens: tokenize(input)?, position: 0, depth: 0 };
+    let value = parser.parse_value(true)?;
+    if parser.peek().is_some() {
+        return Err(invalid(&format!("se esperaba el final del valor y se encontró {}", parser.describe_next())));
+    }
+    Ok(value)
+}
+
+fn tokenize(input: &str) -> DomainResult<Vec<Token>> {
+    let chars: Vec<char> = input.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        match c {
+            // Espacios, comas y BOM no son significativos
+            c if c.is_whitespace() || c == ',' || c == '\u{feff}' => i += 1,
+            '#' => {
+                while i < chars.len() && chars[i] != '\n' && chars[i] != '\r' {
+                    i += 1;
+                }
+            },
+            '!' | '$' | '&' | '(' | ')' | ':' | '=' | '@' | '[' | ']' | '{' | '|' | '}' => {
+                tokens.push(Token::Punctuator(c));
+                i += 1;
+            },
+            '.' => {
+                if chars.get(i + 1) == Some(&'.') && chars.get(i + 2) == Some(&'.') {
+                    tokens.push(Token::Spread);
+                    i += 3;
+                } else {
+                    return Err(invalid("se esperaba '...'"));
+                }
+            },
+            '"' => {
+                if chars.get(i + 1) == Some(&'"') && chars.get(i + 2) == Some(&'"') {
+                    let (text, next) = read_block_string(&chars, i + 3)?;
+                    tokens.push(Token::Str(text));
+                    i = next;
+                } else {
+                    let (text, next) = read_string(&chars, i + 1)?;
+                    tokens.push(Token::Str(text));
+                    i = next;
+                }
+            },
+            c if c == '-' || c.is_ascii_digit() => {
+                let (token, next) = read_number(&chars, i)?;
+                tokens.push(token);
+                i = next;
+            },
+            c if c.is_ascii_alphabetic() || c == '_' => {
+                let start = i;
+                while i < chars.len() && (chars[i].is_ascii_alphanumeric() || chars[i] == '_') {
+                    i += 1;
+                }
+                tokens.push(Token::Name(chars[start..i].iter().collect()));
+            },
+            other => return Err(invalid(&format!("carácter inesperado '{}'", other))),
+        }
+    }
+    Ok(tokens)
+}
+
+/// Lee un número: `-?(0|[1-9][0-9]*)(\.[0-9]+)?([eE][+-]?[0-9]+)?`.
+fn read_number(chars: &[char], start: usize) -> DomainResult<(Token, usize)> {
+    let mut i = start;
+    if chars[i] == '-' {
+        i += 1;
+    }
+    let digits = |i: &mut usize| {
+        let from = *i;
+        while *i < chars.len() && chars[*i].is_ascii_digit() {
+            *i += 1;
+        }
+        *i - from
+    };
+    let integer_start = i;
+    if digits(&mut i) == 0 {
+        return Err(invalid("número inválido"));
+    }
+    if chars[integer_start] == '0' && i - integer_start > 1 {
+        return Err(invalid("los números no pueden empezar por 0"));
+    }
+    let mut is_float = false;
+    if chars.get(i) == Some(&'.') {
+        i += 1;
+        is_float = true;
+        if digits(&mut i) == 0 {
+            return Err(invalid("número inválido: faltan decimales"));
+        }
+    }
+    if matches!(chars.get(i), Some('e') | Some('E')) {
+        i += 1;
+        is_float = true;
+        if matches!(chars.get(i), Some('+') | Some('-')) {
+            i += 1;
+        }
+        if digits(&mut i) == 0 {
+            return Err(invalid("número inválido: falta el exponente"));
+        }
+    }
+    // Un número no puede ir seguido de un nombre ni de un punto
+    if matches!(chars.get(i), Some(c) if c.is_ascii_alphabetic() || *c == '_' || *c == '.') {
+        return Err(invalid("número inválido"));
+    }
+    let text: String = chars[start..i].iter().collect();
+    Ok((if is_float { Token::Float(text) } else { Token::Int(text) }, i))
+}
+
+/// Lee una cadena entre comillas (ya consumida la de apertura), con sus escapes.
+fn read_string(chars: &[char], start: usize) -> DomainResult<(String, usize)> {
+    let mut text = String::new();
+    let mut i = start;
+    while i < chars.len() {
+        match chars[i] {
+            '"' => return Ok((text, i + 1)),
+            '\n' | '\r' => break,
+            '\\' => {
+                let escaped = chars.get(i + 1).copied().ok_or_else(|| invalid("falta cerrar la cadena"))?;
+                i += 2;
+                match escaped {
+                    '"' => text.push('"'),
+                    '\\' => text.push('\\'),
+                    '/' => text.push('/'),
+                    'b' => text.push('\u{8}'),
+                    'f' => text.push('\u{c}'),
+                    'n' => text.push('\n'),
+                    'r' => text.push('\r'),
+                    't' => text.push('\t'),
+                    'u' => {
+                        let (code, next) = read_unicode_escape(chars, i)?;
+                        i = next;
+                        // Par sustituto (p. ej. \uD83D\uDE00)
+                        let code = if (0xD800..0xDC00).contains(&code) && chars.get(i) == Some(&'\\') && chars.get(i + 1) == Some(&'u') {
+                            let (low, next) = read_unicode_escape(chars, i + 2)?;
+                            if !(0xDC00..0xE000).contains(&low) {
+                                return Err(invalid("secuencia unicode inválida"));
+                            }
+                            i = next;
+                            0x10000 + ((code - 0xD800) << 10) + (low - 0xDC00)
+                        } else {
+                            code
+                        };
+                        text.push(char::from_u32(code).ok_or_else(|| invalid("secuencia unicode inválida"))?);
+                    },
+                    other => return Err(invalid(&format!("secuencia de escape inválida '\\{}'", other))),
+                }
+            },
+            c => {
+                text.push(c);
+                i += 1;
+            },
+        }
+    }
+    Err(invalid("falta cerrar la cadena"))
+}
+
+/// Lee los 4 dígitos hexadecimales (o `{...}`) de un escape `\u`.
+fn read_unicode_escape(chars: &[char], start: usize) -> DomainResult<(u32, usize)> {
+    let (digits, next): (String, usize) = if chars.get(start) == Some(&'{') {
+        let end = chars[start..].iter().position(|c| *c == '}').map(|p| start + p)
+            .ok_or_else(|| invalid("secuencia unicode inválida"))?;
+        (chars[start + 1..end].iter().collect(), end + 1)
+    } else {
+        let end = (start + 4).min(chars.len());
+        (chars[start..end].iter().collect(), end)
+    };
+    if digits.is_empty() || digits.len() > 6 || !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(invalid("secuencia unicode inválida"));
+    }
+    let code = u32::from_str_radix(&digits, 16).map_err(|_| invalid("secuencia unicode inválida"))?;
+    Ok((code, next))
+}
+
+/// Lee una cadena de bloque (`"""..."""`, ya consumidas las comillas de apertura)
+/// y elimina la sangría común y las líneas en blanco iniciales y finales.
+fn read_block_string(chars: &[char], start: usize) -> DomainResult<(String, usize)> {
+    let mut raw = String::new();
+    let mut i = start;
+    loop {
+        if i >= chars.len() {
+            return Err(invalid("falta cerrar la cadena de bloque"));
+        }
+        if chars[i] == '"' && chars.get(i + 1) == Some(&'"') && chars.get(i + 2) == Some(&'"') {
+            i += 3;
+            break;
+        }
+        if chars[i] == '\\' && chars.get(i + 1) == Some(&'"') && chars.get(i + 2) == Some(&'"') && chars.get(i + 3) == Some(&'"') {
+            raw.push_str("\"\"\"");
+            i += 4;
+            continue;
+        }
+        raw.push(chars[i]);
+        i += 1;
+    }
+
+    let raw = raw.replace("\r\n", "\n").replace('\r', "\n");
+    let lines: Vec<&str> = raw.split('\n').collect();
+    let indent = lines.iter().skip(1)
+        .filter_map(|line| {
+            let width = line.chars().take_while(|c| *c == ' ' || *c == '\t').count();
+            (width < line.chars().count()).then_some(width)
+        })
+        .min()
+        .unwrap_or(0);
+    let mut lines: Vec<String> = lines.iter().enumerate()
+        .map(|(n, line)| if n == 0 { line.to_string() } else { line.chars().skip(indent).collect() })
+        .collect();
+    while lines.first().is_some_and(|l| l.trim_matches([' ', '\t']).is_empty()) {
+        lines.remove(0);
+    }
+    while lines.last().is_some_and(|l| l.trim_matches([' ', '\t']).is_empty()) {
+        lines.pop();
+    }
+    Ok((lines.join("\n"), i))
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    position: usize,
+    /// Anidamiento actual de selecciones, listas, objetos y tipos lista. Se limita
+    /// durante el análisis para que un documento malicioso no agote la pila.
+    depth: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    }
+
+    fn peek_punctuator(&self, c: char) -> bool {
+        self.peek() == Some(&Token::Punctuator(c))
+    }
+
+    /// Consume el signo `c` si es el siguiente token.
+    fn skip_punctuator(&mut self, c: char) -> bool {
+        if self.peek_punctuator(c) {
+            self.position += 1;
+            return true;
+        }
+        false
+    }
+
+    fn expect_punctuator(&mut self, c: char) -> DomainResult<()> {
+        if self.skip_punctuator(c) {
+            return Ok(());
+        }
+        Err(invalid(&format!("se esperaba '{}' y se encontró {}", c, self.describe_next())))
+    }
+
+    fn expect_name(&mut self) -> DomainResult<String> {
+        match self.peek() {
+            Some(Token::Name(name)) => {
+                let name = name.clone();
+                self.position += 1;
+                Ok(name)
+            },
+            _ => Err(invalid(&format!("se esperaba un nombre y se encontró {}", self.describe_next()))),
+        }
+    }
+
+    fn expect_keyword(&mut self, keyword: &str) -> DomainResult<()> {
+        match self.peek() {
+            Some(Token::Name(name)) if name == keyword => {
+                self.position += 1;
+                Ok(())
+            },
+            _ => Err(invalid(&format!("se esperaba '{}' y se encontró {}", keyword, self.describe_next()))),
+        }
+    }
+
+    /// Entra en un nivel de anidamiento; falla si se supera `MAX_QUERY_DEPTH`.
+    fn enter(&mut self) -> DomainResult<()> {
+        if self.depth >= MAX_QUERY_DEPTH {
+            return Err(invalid(&format!("el documento supera la profundidad máxima de {} niveles", MAX_QUERY_DEPTH)));
+        }
+        self.depth += 1;
+        Ok(())
+    }
+
+    fn leave(&mut self) {
+        self.depth -= 1;
+    }
+
+    fn describe_next(&self) -> String {
+        self.peek().map(describe).unwrap_or_else(|| "el final del documento".to_string())
+    }
+
+    fn parse_operation(&mut self) -> DomainResult<OperationDefinition> {
+        // Forma abreviada: `{ ... }` es una query sin nombre
+        if self.peek_punctuator('{') {
+            return Ok(OperationDefinition {
+                operation: OperationType::Query,
+                name: None,
+                variables: Vec::new(),
+                directives: Vec::new(),
+                selection_set: self.parse_selection_set()?,
+            });
+        }
+
+        let operation = match self.expect_name()?.as_str() {
+            "query" => OperationType::Query,
+            "mutation" => OperationType::Mutation,
+            "subscription" => return Err(invalid("las suscripciones no están soportadas")),
+            other => return Err(invalid(&format!("se esperaba 'query', 'mutation' o 'fragment' y se encontró '{}'", other))),
+        };
+        let name = match self.peek() {
+            Some(Token::Name(_)) => Some(self.expect_name()?),
+            _ => None,
+        };
+        let variables = if self.peek_punctuator('(') {
+            self.parse_variable_definitions()?
+        } else {
+            Vec::new()
+        };
+        let directives = self.parse_directives()?;
+        let selection_set = self.parse_selection_set()?;
+        Ok(OperationDefinition { operation, name, variables, directives, selection_set })
+    }
+
+    fn parse_variable_definitions(&mut self) -> DomainResult<Vec<VariableDefinition>> {
+        self.expect_punctuator('(')?;
+        let mut variables: Vec<VariableDefinition> = Vec::new();
+        while !self.skip_punctuator(')') {
+            self.expect_punctuator('$')?;
+            let name = self.expect_name()?;
+            if variables.iter().any(|v| v.name == name) {
+                return Err(invalid(&format!("la variable '${}' está definida más de una vez", name)));
+            }
+            self.expect_punctuator(':')?;
+            let ty = self.parse_type()?;
+            let default_value = if self.skip_punctuator('=') {
+                Some(self.parse_value(true)?)
+            } else {
+                None
+            };
+            // Las directivas de variables se admiten por sintaxis pero no tienen efecto
+            self.parse_directives()?;
+            variables.push(VariableDefinition { name, ty, default_value });
+        }
+        Ok(variables)
+    }
+
+    fn parse_type(&mut self) -> DomainResult<TypeRef> {
+        let ty = if self.skip_punctuator('[') {
+            self.enter()?;
+            let inner = self.parse_type()?;
+            self.expect_punctuator(']')?;
+            self.leave();
+            TypeRef::list(inner)
+        } else {
+            TypeRef::Named(self.expect_name()?)
+        };
+        Ok(if self.skip_punctuator('!') { TypeRef::non_null(ty) } else { ty })
+    }
+
+    fn parse_fragment(&mut self) -> DomainResult<FragmentDefinition> {
+        self.expect_keyword("fragment")?;
+        let name = self.expect_name()?;
+        if name == "on" {
+            return Err(invalid("un fragmento no puede llamarse 'on'"));
+        }
+        self.expect_keyword("on")?;
+        let type_condition = self.expect_name()?;
+        let directives = self.parse_directives()?;
+        let selection_set = self.parse_selection_set()?;
+        Ok(FragmentDefinition { name, type_condition, directives, selection_set })
+    }
+
+    fn parse_selection_set(&mut self) -> DomainResult<Vec<Selection>> {
+        self.expect_punctuator('{')?;
+        self.enter()?;
+        let mut selections = Vec::new();
+        while !self.skip_punctuator('}') {
+            if self.peek().is_none() {
+                return Err(invalid("falta cerrar una selección con '}'"));
+            }
+            selections.push(self.parse_selection()?);
+        }
+        self.leave();
+        if selections.is_empty() {
+            return Err(invalid("una selección no puede estar vacía"));
+        }
+        Ok(selections)
+    }
+
+    fn parse_selection(&mut self) -> DomainResult<Selection> {
+        if self.peek() == Some(&Token::Spread) {
+            self.position += 1;
+            return match self.peek() {
+                Some(Token::Name(name)) if name != "on" => {
+                    let name = self.expect_name()?;
+                    let directives = self.parse_directives()?;
+                    Ok(Selection::FragmentSpread { name, directives })
+                },
+                _ => {
+                    let type_condition = match self.peek() {
+                        Some(Token::Name(name)) if name == "on" => {
+                            self.position += 1;
+                            Some(self.expect_name()?)
+                        },
+                        _ => None,
+                    };
+                    let directives = self.parse_directives()?;
+                    let selection_set = self.parse_selection_set()?;
+                    Ok(Selection::InlineFragment { type_condition, directives, selection_set })
+                },
+            };
+        }
+
+        let first = self.expect_name()?;
+        let (alias, name) = if self.skip_punctuator(':') {
+            (Some(first), self.expect_name()?)
+        } else {
+            (None, first)
+        };
+        let arguments = self.parse_arguments(false)?;
+        let directives = self.parse_directives()?;
+        let selection_set = if self.peek_punctuator('{') {
+            self.parse_selection_set()?
+        } else {
+            Vec::new()
+        };
+        Ok(Selection::Field(Field { alias, name, arguments, directives, selection_set }))
+    }
+
+    fn parse_arguments(&mut self, constant: bool) -> DomainResult<Vec<(String, InputValue)>> {
+        let mut arguments: Vec<(String, InputValue)> = Vec::new();
+        if !self.skip_punctuator('(') {
+            return Ok(arguments);
+        }
+        while !self.skip_punctuator(')') {
+            let name = self.expect_name()?;
+            if arguments.iter().any(|(n, _)| *n == name) {
+                return Err(invalid(&format!("el argumento '{}' está repetido", name)));
+            }
+            self.expect_punctuator(':')?;
+            arguments.push((name, self.parse_value(constant)?));
+        }
+        if arguments.is_empty() {
+            return Err(invalid("la lista de argumentos no puede estar vacía"));
+        }
+        Ok(arguments)
+    }
+
+    fn parse_directives(&mut self) -> DomainResult<Vec<Directive>> {
+        let mut directives = Vec::new();
+        while self.skip_punctuator('@') {
+            let name = self.expect_name()?;
+            let arguments = self.parse_arguments(false)?;
+            directives.push(Directive { name, arguments });
+        }
+        Ok(directives)
+    }
+
+    /// Analiza un valor; con `constant` no se admiten variables (valores por defecto).
+    fn parse_value(&mut self, constant: bool) -> DomainResult<InputValue> {
+        let next = self.describe_next();
+        match self.next() {
+            Some(Token::Punctuator('$')) if !constant => Ok(InputValue::Variable(self.expect_name()?)),
+            Some(Token::Punctuator('$')) => Err(invalid("los valores por defecto no pueden usar variables")),
+            Some(Token::Int(text)) => text.parse::<i64>()
+                .map(InputValue::Int)
+                .map_err(|_| invalid(&format!("el entero {} está fuera de rango", text))),
+            Some(Token::Float(text)) => text.parse::<f64>()
+                .ok()
+                .filter(|f| f.is_finite())
+                .map(InputValue::Float)
+                .ok_or_else(|| invalid(&format!("el número {} está fuera de rango", text))),
+            Some(Token::Str(text)) => Ok(InputValue::String(text)),
+            Some(Token::Name(name)) => Ok(match name.as_str() {
+                "true" => InputValue::Boolean(true),
+                "false" => InputValue::Boolean(false),
+                "null" => InputValue::Null,
+                _ => InputValue::Enum(name),
+            }),
+            Some(Token::Punctuator('[')) => {
+                self.enter()?;
+                let mut items = Vec::new();
+                while !self.skip_punctuator(']') {
+                    if self.peek().is_none() {
+                        return Err(invalid("falta cerrar una lista con ']'"));
+                    }
+                    items.push(self.parse_value(constant)?);
+                }
+                self.leave();
+                Ok(InputValue::List(items))
+            },
+            Some(Token::Punctuator('{')) => {
+                self.enter()?;
+                let mut fields: Vec<(String, InputValue)> = Vec::new();
+                while !self.skip_punctuator('}') {
+                    let name = self.expect_name()?;
+                    if fields.iter().any(|(n, _)| *n == name) {
+                        return Err(invalid(&format!("el campo '{}' está repetido en el objeto", name)));
+                    }
+                    self.expect_punctuator(':')?;
+                    fields.push((name, self.parse_value(constant)?));
+                }
+                self.leave();
+                Ok(InputValue::Object(fields))
+            },
+            _ => Err(invalid(&format!("se esperaba un valor y se encontró {}", next))),
+        }
+    }
+}
+
+fn describe(token: &Token) -> String {
+    match token {
+        Token::Punctuator(c) => format!("'{}'", c),
+        Token::Spread => "'...'".to_string(),
+        Token::Name(name) => format!("'{}'", name),
+        Token::Int(text) | Token::Float(text) => format!("el número {}", text),
+        Token::Str(text) => format!("la cadena \"{}\"", text),
+    }
+}
+
+fn invalid(message: &str) -> DomainError {
+    DomainError::ValidationError(format!("Consulta GraphQL inválida: {}", message))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_operations_fragments_and_values() {
+        let document = parse_document(r#"
+            # Pedidos abiertos
+            query Orders($first: Int = 10, $status: String!) {
+                list: order_list(filter: $status, first: $first) {
+                    items { ...OrderFields customer @include(if: true) { id } }
+                    next_cursor
+                }
+            }
+            fragment OrderFields on Order { id amount tags: _entities }
+            mutation Create { create_order(input: { amount: -1.5e2, note: """
+                Hola
+                  mundo
+            """, lines: [1, 2] }) { id } }
+        "#).unwrap();
+
+        assert_eq!(document.operations.len(), 2);
+        assert_eq!(document.fragments.len(), 1);
+        let query = document.operation(Some("Orders")).unwrap();
+        assert_eq!(query.variables[0].default_value, Some(InputValue::Int(10)));
+        assert_eq!(query.variables[1].ty, TypeRef::non_null(TypeRef::named("String")));
+        let Selection::Field(list) = &query.selection_set[0] else { panic!("se esperaba un campo") };
+        assert_eq!(list.response_key(), "list");
+        assert_eq!(list.arguments[0], ("filter".to_string(), InputValue::Variable("status".to_string())));
+
+        let mutation = &document.operations[1];
+        assert_eq!(mutation.operation, OperationType::Mutation);
+        let Selection::Field(create) = &mutation.selection_set[0] else { panic!("se esperaba un campo") };
+        let InputValue::Object(fields) = &create.arguments[0].1 else { panic!("se esperaba un objeto") };
+        assert_eq!(fields[0].1, InputValue::Float(-150.0));
+        assert_eq!(fields[1].1, InputValue::String("Hola\n  mundo".to_string()));
+        assert_eq!(fields[2].1, InputValue::List(vec![InputValue::Int(1), InputValue::Int(2)]));
+        assert!(document.operation(None).is_err());
+    }
+
+    #[test]
+    fn rejects_invalid_documents() {
+        assert!(parse_document("").is_err());
+        assert!(parse_document("{ order(id: \"1) { id } }").is_err());
+        assert!(parse_document("{ order(id: 01) { id } }").is_err());
+        assert!(parse_document("subscription { order { id } }").is_err());
+        assert!(parse_document("query($id: ID = $other) { order(id: $id) { id } }").is_err());
+        assert!(parse_document("{ a } { b }").is_err());
+        assert!(parse_document("{ order { } }").is_err());
+        assert_eq!(
+            parse_document(r#"{ a(text: "\u00e9\uD83D\uDE00\n") }"#).unwrap().operations[0].selection_set[0],
+            Selection::Field(Field {
+                alias: None,
+                name: "a".to_string(),
+                arguments: vec![("text".to_string(), InputValue::String("é😀\n".to_string()))],
+                directives: Vec::new(),
+                selection_set: Vec::new(),
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_excessive_nesting_without_exhausting_the_stack() {
+        let fields = "{a".repeat(20_000);
+        assert!(parse_document(&fields).is_err());
+        let values = format!("{{ a(x: {}) }}", "[".repeat(40_000));
+        assert!(parse_document(&values).is_err());
+        assert!(parse_document(&format!("query($x: {}Int{}) {{ a }}", "[".repeat(30_000), "]".repeat(30_000))).is_err());
+        assert!(parse_value_literal(&"{a: ".repeat(20_000)).is_err());
+
+        // Dentro del límite se sigue admitiendo el anidamiento
+        let nested = format!("{}b{}", "{a".repeat(MAX_QUERY_DEPTH), "}".repeat(MAX_QUERY_DEPTH));
+        assert!(parse_document(&nested).is_ok());
+        assert!(parse_document(&format!("{}b{}", "{a".repeat(MAX_QUERY_DEPTH + 1), "}".repeat(MAX_QUERY_DEPTH + 1))).is_err());
+    }
+}
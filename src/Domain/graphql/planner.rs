@@ -0,0 +1,601 @@
+// src/Domain/graphql/planner.rs
+//
+// Validación y planificación de una operación contra el esquema: se resuelven
+// fragmentos y directivas (@skip, @include), se combinan los campos con la misma
+// clave de respuesta y los argumentos y variables se convierten a JSON según su
+// tipo. El resultado es un árbol de campos listo para ejecutar.
+
+use serde_json::{Map, Number, Value};
+use uuid::Uuid;
+
+use crate::Domain::errors::{DomainError, DomainResult};
+
+use super::introspection::meta_field;
+use super::parser::{
+    parse_value_literal, Directive, Document, Field, InputValue, OperationType, Selection, TypeRef,
+};
+use super::schema::{FieldDef, FieldResolver, InputValueDef, Schema, TypeDef, TypeKind};
+
+/// Profundidad máxima de las selecciones (la consulta de introspección estándar usa unos 12 niveles).
+pub const MAX_QUERY_DEPTH: usize = 20;
+
+/// Campo a ejecutar, con sus argumentos ya convertidos y su selección planificada.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlannedField {
+    /// Alias o nombre del campo.
+    pub response_key: String,
+    pub name: String,
+    pub ty: TypeRef,
+    pub resolver: FieldResolver,
+    /// Argumentos por nombre; los ausentes sin valor por defecto no aparecen.
+    pub arguments: Map<String, Value>,
+    pub selection: Vec<PlannedField>,
+}
+
+impl PlannedField {
+    pub fn argument(&self, name: &str) -> Option<&Value> {
+        self.arguments.get(name).filter(|v| !v.is_null())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlannedOperation {
+    pub operation: OperationType,
+    pub fields: Vec<PlannedField>,
+}
+
+/// Valida la operación `operation_name` del documento y la convierte en un plan.
+/// `variables`: valores de las variables recibidos junto a la consulta.
+pub fn plan_operation(
+    schema: &Schema,
+    document: &Document,
+    operation_name: Option<&str>,
+    variables: &Map<String, Value>,
+) -> DomainResult<PlannedOperation> {
+    let operation = document.operation(operation_name)?;
+    let root = match operation.operation {
+        OperationType::Query => schema.query_type(),
+        OperationType::Mutation => schema.mutation_type()
+            .ok_or_else(|| invalid("el esquema no admite mutaciones (no hay entidades activas)"))?,
+    };
+
+    let mut planner = Planner {
+        schema,
+        document,
+        defined_variables: operation.variables.iter().map(|v| v.name.clone()).collect(),
+        variables: Map::new(),
+        fragment_stack: Vec::new(),
+    };
+    for definition in &operation.variables {
+        let location = format!("variable '${}'", definition.name);
+        match schema.type_def(definition.ty.base_name()) {
+            Some(t) if t.is_input() => {},
+            _ => return Err(invalid(&format!("{}: '{}' no es un tipo de entrada", location, definition.ty))),
+        }
+        let value = match (variables.get(&definition.name), &definition.default_value) {
+            (Some(value), _) => planner.coerce_json(value, &definition.ty, &location)?,
+            (None, Some(default)) => planner.coerce_literal(default, &definition.ty, &location)?,
+            (None, None) if definition.ty.is_non_null() => {
+                return Err(invalid(&format!("falta el valor de la {} ({})", location, definition.ty)));
+            },
+            (None, None) => continue,
+        };
+        planner.variables.insert(definition.name.clone(), value);
+    }
+
+    let fields = planner.plan_selection_sets(root, &[&operation.selection_set], 1)?;
+    Ok(PlannedOperation { operation: operation.operation, fields })
+}
+
+struct Planner<'a> {
+    schema: &'a Schema,
+    document: &'a Document,
+    defined_variables: Vec<String>,
+    /// Variables con valor (recibido o por defecto), ya convertidas a su tipo declarado.
+    variables: Map<String, Value>,
+    /// Fragmentos en expansión, para detectar ciclos.
+    fragment_stack: Vec<String>,
+}
+
+impl<'a> Planner<'a> {
+    /// Planifica la unión de varias selecciones sobre el mismo tipo objeto
+    /// (las de los campos que comparten clave de respuesta).
+    fn plan_selection_sets(&mut self, parent: &'a TypeDef, sets: &[&'a [Selection]], depth: usize) -> DomainResult<Vec<PlannedField>> {
+        if depth > MAX_QUERY_DEPTH {
+            return Err(invalid(&format!("la consulta supera la profundidad máxima de {} niveles", MAX_QUERY_DEPTH)));
+        }
+        let mut groups: Vec<(String, Vec<&'a Field>)> = Vec::new();
+        for selections in sets {
+            self.collect_fields(parent, selections, &mut groups)?;
+        }
+        groups.into_iter()
+            .map(|(key, fields)| self.plan_field(parent, key, &fields, depth))
+            .collect()
+    }
+
+    /// Recorre la selección expandiendo fragmentos y agrupa los campos incluidos
+    /// por clave de respuesta, en el orden en que aparecen.
+    fn collect_fields(
+        &mut self,
+        parent: &TypeDef,
+        selections: &'a [Selection],
+        groups: &mut Vec<(String, Vec<&'a Field>)>,
+    ) -> DomainResult<()> {
+        for selection in selections {
+            match selection {
+                Selection::Field(field) => {
+                    if !self.is_included(&field.directives)? {
+                        continue;
+                    }
+                    let key = field.response_key();
+                    match groups.iter_mut().find(|(k, _)| k == key) {
+                        Some((_, fields)) => fields.push(field),
+                        None => groups.push((key.to_string(), vec![field])),
+                    }
+                },
+                Selection::FragmentSpread { name, directives } => {
+                    if !self.is_included(directives)? {
+                        continue;
+                    }
+                    let fragment = self.document.fragment(name)
+                        .ok_or_else(|| invalid(&format!("el fragmento '{}' no está definido", name)))?;
+                    if self.fragment_stack.contains(name) {
+                        return Err(invalid(&format!("el fragmento '{}' se incluye a sí mismo", name)));
+                    }
+                    self.check_type_condition(parent, &fragment.type_condition, &format!("el fragmento '{}'", name))?;
+                    self.fragment_stack.push(name.clone());
+                    self.collect_fields(parent, &fragment.selection_set, groups)?;
+                    self.fragment_stack.pop();
+                },
+                Selection::InlineFragment { type_condition, directives, selection_set } => {
+                    if !self.is_included(directives)? {
+                        continue;
+                    }
+                    if let Some(type_condition) = type_condition {
+                        self.check_type_condition(parent, type_condition, "un fragmento en línea")?;
+                    }
+                    self.collect_fields(parent, selection_set, groups)?;
+                },
+            }
+        }
+        Ok(())
+    }
+
+    /// Todos los tipos objeto son concretos (no hay interfaces ni uniones): un
+    /// fragmento solo se puede aplicar sobre su mismo tipo.
+    fn check_type_condition(&self, parent: &TypeDef, type_condition: &str, what: &str) -> DomainResult<()> {
+        if self.schema.type_def(type_condition).is_none() {
+            return Err(invalid(&format!("el tipo '{}' de {} no existe", type_condition, what)));
+        }
+        if type_condition != parent.name {
+            return Err(invalid(&format!("{} es de tipo '{}' y no se puede aplicar sobre '{}'", what, type_condition, parent.name)));
+        }
+        Ok(())
+    }
+
+    /// Evalúa @skip(if:) e @include(if:).
+    fn is_included(&self, directives: &[Directive]) -> DomainResult<bool> {
+        let mut included = true;
+        for directive in directives {
+            let definition = self.schema.directives().iter()
+                .find(|d| d.name == directive.name)
+                .ok_or_else(|| invalid(&format!("la directiva '@{}' no existe", directive.name)))?;
+            let arguments = self.coerce_arguments(&definition.args, &directive.arguments, &format!("@{}", directive.name))?;
+            let condition = arguments.get("if").and_then(Value::as_bool).unwrap_or(false);
+            match directive.name.as_str() {
+                "skip" if condition => included = false,
+                "include" if !condition => included = false,
+                _ => {},
+            }
+        }
+        Ok(included)
+    }
+
+    fn plan_field(&mut self, parent: &'a TypeDef, key: String, fields: &[&'a Field], depth: usize) -> DomainResult<PlannedField> {
+        let first = fields[0];
+        let name = first.name.as_str();
+        if let Some(other) = fields.iter().find(|f| f.name != first.name) {
+            return Err(invalid(&format!(
+                "'{}' selecciona los campos '{}' y '{}'; use alias distintos", key, first.name, other.name
+            )));
+        }
+
+        if name == "__typename" {
+            if fields.iter().any(|f| !f.arguments.is_empty() || !f.selection_set.is_empty()) {
+                return Err(invalid("'__typename' no admite argumentos ni subcampos"));
+            }
+            return Ok(PlannedField {
+                response_key: key,
+                name: name.to_string(),
+                ty: TypeRef::non_null(TypeRef::named("String")),
+                resolver: FieldResolver::TypeName,
+                arguments: Map::new(),
+                selection: Vec::new(),
+            });
+        }
+
+        let meta;
+        let definition: &FieldDef = match parent.field(name) {
+            Some(definition) => definition,
+            None if parent.name == self.schema.query_type().name && meta_field(name).is_some() => {
+                meta = meta_field(name).expect("meta field");
+                &meta
+            },
+            None => return Err(invalid(&format!("el campo '{}' no existe en el tipo '{}'", name, parent.name))),
+        };
+
+        let owner = format!("{}.{}", parent.name, name);
+        let arguments = self.coerce_arguments(&definition.args, &first.arguments, &owner)?;
+        for other in &fields[1..] {
+            if self.coerce_arguments(&definition.args, &other.arguments, &owner)? != arguments {
+                return Err(invalid(&format!("'{}' se selecciona varias veces con argumentos distintos", key)));
+            }
+        }
+
+        let field_type = self.schema.type_def(definition.ty.base_name())
+            .ok_or_else(|| invalid(&format!("el tipo '{}' no existe", definition.ty.base_name())))?;
+        let selection = if field_type.is_leaf() {
+            if fields.iter().any(|f| !f.selection_set.is_empty()) {
+                return Err(invalid(&format!("el campo '{}' es de tipo '{}' y no admite subcampos", owner, definition.ty)));
+            }
+            Vec::new()
+        } else {
+            let sets: Vec<&'a [Selection]> = fields.iter()
+                .map(|f| f.selection_set.as_slice())
+                .filter(|s| !s.is_empty())
+                .collect();
+            if sets.is_empty() {
+                return Err(invalid(&format!("el campo '{}' es de tipo '{}' y necesita una selección de subcampos", owner, definition.ty)));
+            }
+            self.plan_selection_sets(field_type, &sets, depth + 1)?
+        };
+
+        Ok(PlannedField {
+            response_key: key,
+            name: name.to_string(),
+            ty: definition.ty.clone(),
+            resolver: definition.resolver.clone(),
+            arguments,
+            selection,
+        })
+    }
+
+    fn coerce_arguments(&self, definitions: &[InputValueDef], given: &[(String, InputValue)], owner: &str) -> DomainResult<Map<String, Value>> {
+        if let Some((name, _)) = given.iter().find(|(name, _)| !definitions.iter().any(|d| d.name == *name)) {
+            return Err(invalid(&format!("el argumento '{}' no existe en '{}'", name, owner)));
+        }
+        let mut arguments = Map::new();
+        for definition in definitions {
+            let location = format!("argumento '{}' de '{}'", definition.name, owner);
+            let value = given.iter()
+                .find(|(name, _)| *name == definition.name)
+                .map(|(_, value)| value)
+                // Una variable sin valor equivale a un argumento ausente
+                .filter(|value| !matches!(value, InputValue::Variable(v) if self.defined_variables.contains(v) && !self.variables.contains_key(v)));
+            match (value, &definition.default_value) {
+                (Some(value), _) => {
+                    arguments.insert(definition.name.clone(), self.coerce_literal(value, &definition.ty, &location)?);
+                },
+                (None, Some(default)) => {
+                    let default = parse_value_literal(default)?;
+                    arguments.insert(definition.name.clone(), self.coerce_literal(&default, &definition.ty, &location)?);
+                },
+                (None, None) if definition.ty.is_non_null() => {
+                    return Err(invalid(&format!("falta el {} ({})", location, definition.ty)));
+                },
+                (None, None) => {},
+            }
+        }
+        Ok(arguments)
+    }
+
+    /// Convierte un valor escrito en la consulta al tipo `ty`.
+    fn coerce_literal(&self, value: &InputValue, ty: &TypeRef, location: &str) -> DomainResult<Value> {
+        if let InputValue::Variable(name) = value {
+            if !self.defined_variables.contains(name) {
+                return Err(invalid(&format!("la variable '${}' no está definida en la operación", name)));
+            }
+            let value = self.variables.get(name).cloned().unwrap_or(Value::Null);
+            return self.coerce_json(&value, ty, location);
+        }
+
+        match ty {
+            TypeRef::NonNull(inner) => {
+                let coerced = self.coerce_literal(value, inner, location)?;
+                if coerced.is_null() {
+                    return Err(invalid(&format!("{} no puede ser null ({})", location, ty)));
+                }
+                Ok(coerced)
+            },
+            _ if *value == InputValue::Null => Ok(Value::Null),
+            TypeRef::List(inner) => match value {
+                InputValue::List(items) => items.iter()
+                    .map(|item| self.coerce_literal(item, inner, location))
+                    .collect::<DomainResult<Vec<_>>>()
+                    .map(Value::Array),
+                // Un valor suelto equivale a una lista de un elemento
+                other => Ok(Value::Array(vec![self.coerce_literal(other, inner, location)?])),
+            },
+            TypeRef::Named(name) => {
+                let type_def = self.input_type(name, location)?;
+                match (type_def.kind, value) {
+                    (TypeKind::Enum, InputValue::Enum(v)) if type_def.enum_values.contains(v) => Ok(Value::String(v.clone())),
+                    (TypeKind::Enum, _) => Err(invalid(&format!(
+                        "{}: se esperaba un valor de {} ({})", location, name, type_def.enum_values.join(", ")
+                    ))),
+                    (TypeKind::InputObject, InputValue::Object(fields)) => {
+                        let given: Map<String, Value> = fields.iter()
+                            .map(|(field, value)| {
+                                let definition = type_def.input_field(field)
+                                    .ok_or_else(|| invalid(&format!("{}: el campo '{}' no existe en '{}'", location, field, name)))?;
+                                let field_location = format!("campo '{}' de {}", field, location);
+                                Ok((field.clone(), self.coerce_literal(value, &definition.ty, &field_location)?))
+                            })
+                            .collect::<DomainResult<_>>()?;
+                        self.complete_input_object(type_def, given, location)
+                    },
+                    (TypeKind::InputObject, _) => Err(invalid(&format!("{}: se esperaba un objeto {}", location, name))),
+                    (_, InputValue::Enum(v)) => Err(invalid(&format!("{}: '{}' no es un valor de tipo {}", location, v, name))),
+                    (_, literal) => coerce_scalar(name, &self.literal_to_json(literal), location),
+                }
+            },
+        }
+    }
+
+    /// Convierte el valor JSON de una variable al tipo `ty`.
+    fn coerce_json(&self, value: &Value, ty: &TypeRef, location: &str) -> DomainResult<Value> {
+        match ty {
+            TypeRef::NonNull(inner) => {
+                if value.is_null() {
+                    return Err(invalid(&format!("{} no puede ser null ({})", location, ty)));
+                }
+                self.coerce_json(value, inner, location)
+            },
+            _ if value.is_null() => Ok(Value::Null),
+            TypeRef::List(inner) => match value {
+                Value::Array(items) => items.iter()
+                    .map(|item| self.coerce_json(item, inner, location))
+                    .collect::<DomainResult<Vec<_>>>()
+                    .map(Value::Array),
+                other => Ok(Value::Array(vec![self.coerce_json(other, inner, location)?])),
+            },
+            TypeRef::Named(name) => {
+                let type_def = self.input_type(name, location)?;
+                match (type_def.kind, value) {
+                    (TypeKind::Enum, Value::String(v)) if type_def.enum_values.contains(v) => Ok(value.clone()),
+                    (TypeKind::Enum, _) => Err(invalid(&format!(
+                        "{}: se esperaba un valor de {} ({})", location, name, type_def.enum_values.join(", ")
+                    ))),
+                    (TypeKind::InputObject, Value::Object(fields)) => {
+                        let given: Map<String, Value> = fields.iter()
+                            .map(|(field, value)| {
+                                let definition = type_def.input_field(field)
+                                    .ok_or_else(|| invalid(&format!("{}: el campo '{}' no existe en '{}'", location, field, name)))?;
+                                let field_location = format!("campo '{}' de {}", field, location);
+                                Ok((field.clone(), self.coerce_json(value, &definition.ty, &field_location)?))
+                            })
+                            .collect::<DomainResult<_>>()?;
+                        self.complete_input_object(type_def, given, location)
+                    },
+                    (TypeKind::InputObject, _) => Err(invalid(&format!("{}: se esperaba un objeto {}", location, name))),
+                    _ => coerce_scalar(name, value, location),
+                }
+            },
+        }
+    }
+
+    /// Añade a un objeto de entrada los valores por defecto de los campos ausentes
+    /// y comprueba los obligatorios. Los campos se devuelven en el orden del tipo.
+    fn complete_input_object(&self, type_def: &TypeDef, mut given: Map<String, Value>, location: &str) -> DomainResult<Value> {
+        let mut object = Map::new();
+        for definition in &type_def.input_fields {
+            match (given.remove(&definition.name), &definition.default_value) {
+                (Some(value), _) => {
+                    object.insert(definition.name.clone(), value);
+                },
+                (None, Some(default)) => {
+                    let field_location = format!("campo '{}' de {}", definition.name, location);
+                    let value = self.coerce_literal(&parse_value_literal(default)?, &definition.ty, &field_location)?;
+                    object.insert(definition.name.clone(), value);
+                },
+                (None, None) if definition.ty.is_non_null() => {
+                    return Err(invalid(&format!("{}: falta el campo obligatorio '{}' ({})", location, definition.name, definition.ty)));
+                },
+                (None, None) => {},
+            }
+        }
+        Ok(Value::Object(object))
+    }
+
+    fn input_type(&self, name: &str, location: &str) -> DomainResult<&'a TypeDef> {
+        match self.schema.type_def(name) {
+            Some(type_def) if type_def.is_input() => Ok(type_def),
+            _ => Err(invalid(&format!("{}: '{}' no es un tipo de entrada", location, name))),
+        }
+    }
+
+    /// Literal como JSON (para los escalares; `JSON` admite listas y objetos).
+    fn literal_to_json(&self, value: &InputValue) -> Value {
+        match value {
+            InputValue::Variable(name) => self.variables.get(name).cloned().unwrap_or(Value::Null),
+            InputValue::Int(i) => Value::Number((*i).into()),
+            InputValue::Float(f) => Number::from_f64(*f).map(Value::Number).unwrap_or(Value::Null),
+            InputValue::String(s) | InputValue::Enum(s) => Value::String(s.clone()),
+            InputValue::Boolean(b) => Value::Bool(*b),
+            InputValue::Null => Value::Null,
+            InputValue::List(items) => Value::Array(items.iter().map(|i| self.literal_to_json(i)).collect()),
+            InputValue::Object(fields) => Value::Object(
+                fields.iter().map(|(k, v)| (k.clone(), self.literal_to_json(v))).collect(),
+            ),
+        }
+    }
+}
+
+/// Conversión de entrada de los escalares. Las fechas se validan al escribir el
+/// registro (como en la API REST); aquí solo se exige texto.
+fn coerce_scalar(name: &str, value: &Value, location: &str) -> DomainResult<Value> {
+    let integer = || value.as_i64().or_else(|| value.as_f64().filter(|f| f.fract() == 0.0 && f.abs() < 9.0e15).map(|f| f as i64));
+    let coerced = match name {
+        "Int" => integer().filter(|i| i32::try_from(*i).is_ok()).map(|i| Value::Number(i.into())),
+        "BigInt" => integer()
+            .or_else(|| value.as_str().and_then(|s| s.trim().parse::<i64>().ok()))
+            .map(|i| Value::Number(i.into())),
+        "Float" => value.as_f64().and_then(Number::from_f64).map(Value::Number),
+        "Decimal" => match value {
+            Value::Number(_) => Some(value.clone()),
+            Value::String(s) if s.trim().parse::<f64>().is_ok_and(f64::is_finite) => Some(value.clone()),
+            _ => None,
+        },
+        "Boolean" => value.as_bool().map(Value::Bool),
+        "ID" => match value {
+            Value::String(_) => Some(value.clone()),
+            Value::Number(n) if n.is_i64() || n.is_u64() => Some(Value::String(n.to_string())),
+            _ => None,
+        },
+        "UUID" => value.as_str()
+            .and_then(|s| Uuid::parse_str(s.trim()).ok())
+            .map(|id| Value::String(id.to_string())),
+        "String" | "DateTime" | "Date" | "Time" => value.as_str().map(|_| value.clone()),
+        _ => Some(value.clone()),
+    };
+    coerced.ok_or_else(|| invalid(&format!("{}: {} no es un valor de tipo {}", location, value, name)))
+}
+
+fn invalid(message: &str) -> DomainError {
+    DomainError::ValidationError(format!("Consulta GraphQL inválida: {}", message))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::Domain::data_types::{DataTypeKind, DataTypeParams, DataTypeStorage};
+    use crate::Domain::graphql::introspection::resolve_meta_field;
+    use crate::Domain::graphql::parser::parse_document;
+    use crate::Domain::graphql::schema::{GraphQLAttribute, GraphQLEntity};
+    use crate::Domain::records::StorageColumn;
+
+    fn storage(column: StorageColumn, cast: Option<&str>) -> DataTypeStorage {
+        DataTypeStorage::new(column, cast, DataTypeParams::default()).unwrap()
+    }
+
+    fn schema() -> Schema {
+        let text = storage(StorageColumn::String, None);
+        let integer = storage(StorageColumn::Integer, Some("integer"));
+        let reference = storage(StorageColumn::Uuid, None).with_kind(DataTypeKind::Reference).unwrap();
+        let customer_id = Uuid::from_u128(1);
+        let attribute = |name, storage, is_required, reference_entity_id| GraphQLAttribute {
+            name,
+            description: None,
+            storage,
+            is_required,
+            has_default: false,
+            reference_entity_id,
+        };
+        Schema::build(&[
+            GraphQLEntity {
+                id: Uuid::from_u128(2),
+                name: "sales order",
+                description: Some("Pedidos"),
+                attributes: vec![
+                    attribute("code", &text, true, None),
+                    attribute("lines", &integer, false, None),
+                    attribute("customer", &reference, false, Some(customer_id)),
+                    attribute("status", &text, false, None),
+                ],
+            },
+            GraphQLEntity {
+                id: customer_id,
+                name: "customer",
+                description: None,
+                attributes: vec![attribute("name", &text, true, None)],
+            },
+        ])
+    }
+
+    fn plan(schema: &Schema, query: &str, variables: Value) -> DomainResult<PlannedOperation> {
+        let document = parse_document(query)?;
+        plan_operation(schema, &document, None, variables.as_object().unwrap())
+    }
+
+    #[test]
+    fn builds_entity_types_and_root_fields() {
+        let schema = schema();
+        let order = schema.type_def("SalesOrder").unwrap();
+        let names: Vec<&str> = order.fields.iter().map(|f| f.name.as_str()).collect();
+        // `status` coincide con una columna de sistema: el atributo no se expone
+        assert_eq!(names, ["id", "code", "lines", "customer", "created_at", "created_by", "updated_at", "updated_by", "status"]);
+        assert_eq!(order.field("lines").unwrap().ty, TypeRef::named("Int"));
+        assert_eq!(order.field("customer").unwrap().ty, TypeRef::named("Customer"));
+        assert_eq!(
+            schema.type_def("SalesOrderInput").unwrap().input_field("code").unwrap().ty,
+            TypeRef::non_null(TypeRef::named("String"))
+        );
+        assert_eq!(schema.type_def("SalesOrderPatch").unwrap().input_field("code").unwrap().ty, TypeRef::named("String"));
+        assert!(schema.query_type().field("sales_order_list").is_some());
+        assert!(schema.mutation_type().unwrap().field("update_customer").is_some());
+        assert_eq!(schema.entities().len(), 2);
+    }
+
+    #[test]
+    fn plans_fragments_variables_and_defaults() {
+        let schema = schema();
+        let operation = plan(&schema, r#"
+            query($filter: String, $skip: Boolean!) {
+                page: sales_order_list(filter: $filter) {
+                    items { ...Order id customer @skip(if: $skip) { name } }
+                    next_cursor
+                }
+            }
+            fragment Order on SalesOrder { id code }
+        "#, json!({ "filter": "code eq 'A'", "skip": false })).unwrap();
+
+        let page = &operation.fields[0];
+        assert_eq!(page.response_key, "page");
+        assert_eq!(page.arguments, json!({ "filter": "code eq 'A'", "first": 20 }).as_object().cloned().unwrap());
+        let items: Vec<&str> = page.selection[0].selection.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(items, ["id", "code", "customer"]);
+        assert!(matches!(page.selection[0].selection[2].resolver, FieldResolver::Reference { .. }));
+
+        let mutation = plan(&schema, r#"mutation { create_sales_order(input: { code: "A", lines: 3 }) { id } }"#, json!({})).unwrap();
+        assert_eq!(mutation.fields[0].arguments["input"], json!({ "code": "A", "lines": 3 }));
+    }
+
+    #[test]
+    fn rejects_invalid_operations() {
+        let schema = schema();
+        let error = |query: &str, variables: Value| plan(&schema, query, variables).unwrap_err().to_string();
+
+        assert!(error("{ sales_order_list { total } }", json!({})).contains("'total' no existe"));
+        assert!(error("{ sales_order_list }", json!({})).contains("necesita una selección"));
+        assert!(error("{ sales_order(id: \"1\") { code { x } } }", json!({})).contains("no admite subcampos"));
+        assert!(error("{ sales_order { id } }", json!({})).contains("falta el argumento 'id'"));
+        assert!(error("{ sales_order_list(first: \"10\") { next_cursor } }", json!({})).contains("no es un valor de tipo Int"));
+        assert!(error("query($id: ID!) { sales_order(id: $id) { id } }", json!({})).contains("falta el valor"));
+        assert!(error("{ a: sales_order(id: 1) { id } a: customer(id: 1) { id } }", json!({})).contains("alias distintos"));
+        assert!(error("mutation { create_customer(input: {}) { id } }", json!({})).contains("falta el campo obligatorio 'name'"));
+        assert!(error("{ ...F } fragment F on Query { ...F }", json!({})).contains("a sí mismo"));
+        assert!(error("{ sales_order(id: 1) { ... on Customer { id } } }", json!({})).contains("no se puede aplicar"));
+    }
+
+    #[test]
+    fn resolves_introspection() {
+        let schema = schema();
+        let operation = plan(&schema, r#"{
+            __type(name: "SalesOrderInput") {
+                kind name
+                inputFields { name type { kind name ofType { name } } }
+            }
+            __schema { queryType { name } mutationType { name } directives { name } }
+        }"#, json!({})).unwrap();
+
+        let ty = resolve_meta_field(&schema, &operation.fields[0]);
+        assert_eq!(ty["kind"], "INPUT_OBJECT");
+        assert_eq!(ty["inputFields"][0], json!({ "name": "code", "type": { "kind": "NON_NULL", "name": null, "ofType": { "name": "String" } } }));
+        let schema_info = resolve_meta_field(&schema, &operation.fields[1]);
+        assert_eq!(schema_info["queryType"]["name"], "Query");
+        assert_eq!(schema_info["directives"], json!([{ "name": "skip" }, { "name": "include" }]));
+    }
+}
@@ -0,0 +1,581 @@
+// src/Domain/graphql/schema.rs
+//
+// Esquema GraphQL generado a partir de las entidades lógicas activas:
+//
+//   type Order { id: ID!  amount: Decimal  customer: Customer  created_at: DateTime! ... }
+//   type OrderPage { items: [Order!]!  next_cursor: String }
+//   input OrderInput { amount: Decimal! ... }      (alta)
+//   input OrderPatch { amount: Decimal ... }       (actualización parcial)
+//
+//   type Query    { order(id: ID!): Order  order_list(filter, sort, first, after): OrderPage  _entities: [String!]! }
+//   type Mutation { create_order(input: OrderInput!): Order  update_order(id: ID!, input: OrderPatch!): Order
+//                   delete_order(id: ID!): ID }
+//
+// Los nombres de entidades y atributos que no son nombres GraphQL válidos se
+// adaptan (`línea pedido` -> `l_nea_pedido`); si dos nombres coinciden tras
+// adaptarlos, solo se expone el primero (por orden de nombre o de `position`).
+
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+use crate::Domain::data_types::DataTypeStorage;
+use crate::Domain::record_queries::SystemField;
+use crate::Domain::records::StorageColumn;
+
+use super::introspection::introspection_types;
+use super::parser::TypeRef;
+
+/// Tamaño de página por defecto de los listados (`first`).
+pub const DEFAULT_PAGE_SIZE: i64 = 20;
+
+/// Escalares estándar de GraphQL.
+pub const BUILTIN_SCALARS: [&str; 5] = ["String", "Int", "Float", "Boolean", "ID"];
+
+/// Escalares propios: se serializan como en la API REST (texto ISO 8601 para
+/// fechas, UUID en texto, BigInt y Decimal como número o texto, JSON tal cual).
+pub const CUSTOM_SCALARS: [(&str, &str); 7] = [
+    ("BigInt", "64-bit signed integer"),
+    ("Decimal", "Arbitrary-precision decimal number (a string keeps every digit)"),
+    ("DateTime", "ISO 8601 date and time with time zone"),
+    ("Date", "ISO 8601 calendar date (YYYY-MM-DD)"),
+    ("Time", "ISO 8601 time of day (HH:MM:SS)"),
+    ("UUID", "UUID in its canonical text form"),
+    ("JSON", "Any JSON value"),
+];
+
+pub const QUERY_TYPE: &str = "Query";
+pub const MUTATION_TYPE: &str = "Mutation";
+
+/// Casts de Postgres de las columnas enteras que caben en el `Int` de GraphQL (32 bits).
+const INT32_CASTS: [&str; 6] = ["int", "integer", "int4", "smallint", "int2", "serial"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TypeKind {
+    Scalar,
+    Object,
+    InputObject,
+    Enum,
+}
+
+/// Entidad lógica expuesta en el esquema.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EntityRef {
+    pub id: Uuid,
+    /// Nombre de la entidad lógica (el que usan los casos de uso de registros).
+    pub name: String,
+    /// Nombre del tipo objeto de sus registros.
+    pub type_name: String,
+}
+
+/// Cómo se obtiene el valor de un campo.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FieldResolver {
+    /// Campos de los tipos de introspección y campos sin resolución propia.
+    Introspection,
+    /// `__typename`: lo añade el planificador en cualquier tipo objeto.
+    TypeName,
+    /// `Query.<entidad>(id)`
+    FindRecord(EntityRef),
+    /// `Query.<entidad>_list(...)`
+    ListRecords(EntityRef),
+    /// `Query._entities`
+    EntityNames,
+    CreateRecord(EntityRef),
+    UpdateRecord(EntityRef),
+    DeleteRecord(EntityRef),
+    /// Campos de `<Tipo>Page`.
+    PageItems,
+    PageNextCursor,
+    /// Columnas de la tupla (id, fechas, usuarios y estado).
+    System(SystemField),
+    /// Valor de un atributo (por su nombre en la entidad).
+    Attribute(String),
+    /// Registro referenciado por un atributo de tipo referencia.
+    Reference { attribute: String, entity: EntityRef },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct InputValueDef {
+    pub name: String,
+    pub description: Option<String>,
+    pub ty: TypeRef,
+    /// Valor por defecto como literal GraphQL (así lo devuelve la introspección).
+    pub default_value: Option<String>,
+    /// Campos de los tipos de entrada de una entidad: atributo al que corresponde.
+    pub attribute: Option<String>,
+}
+
+impl InputValueDef {
+    pub fn new(name: &str, ty: TypeRef, description: Option<&str>) -> Self {
+        Self {
+            name: name.to_string(),
+            description: description.map(str::to_string),
+            ty,
+            default_value: None,
+            attribute: None,
+        }
+    }
+
+    pub fn with_default(mut self, default_value: &str) -> Self {
+        self.default_value = Some(default_value.to_string());
+        self
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldDef {
+    pub name: String,
+    pub description: Option<String>,
+    pub args: Vec<InputValueDef>,
+    pub ty: TypeRef,
+    pub resolver: FieldResolver,
+}
+
+impl FieldDef {
+    pub fn new(name: &str, ty: TypeRef, resolver: FieldResolver, description: Option<&str>) -> Self {
+        Self {
+            name: name.to_string(),
+            description: description.map(str::to_string),
+            args: Vec::new(),
+            ty,
+            resolver,
+        }
+    }
+
+    pub fn with_args(mut self, args: Vec<InputValueDef>) -> Self {
+        self.args = args;
+        self
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypeDef {
+    pub name: String,
+    pub kind: TypeKind,
+    pub description: Option<String>,
+    pub fields: Vec<FieldDef>,
+    pub input_fields: Vec<InputValueDef>,
+    pub enum_values: Vec<String>,
+}
+
+impl TypeDef {
+    pub fn scalar(name: &str, description: Option<&str>) -> Self {
+        Self::new(name, TypeKind::Scalar, description)
+    }
+
+    pub fn object(name: &str, description: Option<&str>, fields: Vec<FieldDef>) -> Self {
+        Self { fields, ..Self::new(name, TypeKind::Object, description) }
+    }
+
+    pub fn input_object(name: &str, description: Option<&str>, input_fields: Vec<InputValueDef>) -> Self {
+        Self { input_fields, ..Self::new(name, TypeKind::InputObject, description) }
+    }
+
+    pub fn enumeration(name: &str, description: Option<&str>, values: &[&str]) -> Self {
+        Self {
+            enum_values: values.iter().map(|v| v.to_string()).collect(),
+            ..Self::new(name, TypeKind::Enum, description)
+        }
+    }
+
+    fn new(name: &str, kind: TypeKind, description: Option<&str>) -> Self {
+        Self {
+            name: name.to_string(),
+            kind,
+            description: description.map(str::to_string),
+            fields: Vec::new(),
+            input_fields: Vec::new(),
+            enum_values: Vec::new(),
+        }
+    }
+
+    pub fn field(&self, name: &str) -> Option<&FieldDef> {
+        self.fields.iter().find(|f| f.name == name)
+    }
+
+    pub fn input_field(&self, name: &str) -> Option<&InputValueDef> {
+        self.input_fields.iter().find(|f| f.name == name)
+    }
+
+    /// Tipos válidos como argumento o variable.
+    pub fn is_input(&self) -> bool {
+        matches!(self.kind, TypeKind::Scalar | TypeKind::Enum | TypeKind::InputObject)
+    }
+
+    /// Tipos sin subselección (escalares y enumeraciones).
+    pub fn is_leaf(&self) -> bool {
+        matches!(self.kind, TypeKind::Scalar | TypeKind::Enum)
+    }
+}
+
+/// Directiva admitida por el ejecutor (`@skip` e `@include`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct DirectiveDef {
+    pub name: String,
+    pub description: String,
+    pub locations: Vec<&'static str>,
+    pub args: Vec<InputValueDef>,
+}
+
+// Estructuras auxiliares con los datos de los metadatos que definen el esquema
+pub struct GraphQLEntity<'a> {
+    pub id: Uuid,
+    pub name: &'a str,
+    pub description: Option<&'a str>,
+    pub attributes: Vec<GraphQLAttribute<'a>>, // Atributos activos, en orden de `position`
+}
+
+pub struct GraphQLAttribute<'a> {
+    pub name: &'a str,
+    pub description: Option<&'a str>,
+    pub storage: &'a DataTypeStorage,
+    pub is_required: bool,
+    pub has_default: bool,
+    pub reference_entity_id: Option<Uuid>,
+}
+
+/// Esquema ejecutable: tipos indexados por nombre, entidades expuestas y directivas.
+#[derive(Debug, Clone)]
+pub struct Schema {
+    types: Vec<TypeDef>,
+    index: HashMap<String, usize>,
+    entities: Vec<EntityRef>,
+    directives: Vec<DirectiveDef>,
+}
+
+impl Schema {
+    /// Genera el esquema de las entidades indicadas (ya filtradas a las activas).
+    pub fn build(entities: &[GraphQLEntity<'_>]) -> Self {
+        let mut ordered: Vec<&GraphQLEntity<'_>> = entities.iter().collect();
+        ordered.sort_by_key(|e| e.name);
+
+        // 1. Entidades expuestas: nombres de tipo y de campos raíz sin colisiones
+        let mut taken_types: HashSet<String> = BUILTIN_SCALARS.iter()
+            .map(|s| s.to_string())
+            .chain(CUSTOM_SCALARS.iter().map(|(s, _)| s.to_string()))
+            .chain([QUERY_TYPE.to_string(), MUTATION_TYPE.to_string()])
+            .collect();
+        let mut taken_fields: HashSet<String> = HashSet::from(["_entities".to_string()]);
+        let mut exposed: Vec<(&GraphQLEntity<'_>, EntityRef, String)> = Vec::new();
+        for entity in ordered {
+            let (Some(field), Some(type_name)) = (graphql_name(entity.name), pascal_case(entity.name)) else {
+                continue;
+            };
+            let types = entity_type_names(&type_name);
+            let fields = root_field_names(&field);
+            if types.iter().any(|t| taken_types.contains(t)) || fields.iter().any(|f| taken_fields.contains(f)) {
+                continue;
+            }
+            taken_types.extend(types);
+            taken_fields.extend(fields);
+            exposed.push((entity, EntityRef { id: entity.id, name: entity.name.to_string(), type_name }, field));
+        }
+        let by_id: HashMap<Uuid, &EntityRef> = exposed.iter().map(|(_, r, _)| (r.id, r)).collect();
+
+        // 2. Tipos de cada entidad y campos raíz
+        let mut types = Vec::new();
+        let mut query_fields = Vec::new();
+        let mut mutation_fields = Vec::new();
+        for (entity, entity_ref, field) in &exposed {
+            let attributes = exposed_attributes(entity);
+            types.extend(entity_types(entity, entity_ref, &attributes, &by_id));
+            add_root_fields(&mut query_fields, &mut mutation_fields, entity_ref, field, !attributes.is_empty());
+        }
+
+        query_fields.push(FieldDef::new(
+            "_entities",
+            TypeRef::non_null(TypeRef::list(TypeRef::non_null(TypeRef::named("String")))),
+            FieldResolver::EntityNames,
+            Some("Names of the logical entities exposed in this schema"),
+        ));
+
+        let mut all_types = vec![TypeDef::object(QUERY_TYPE, None, query_fields)];
+        // Un tipo objeto necesita al menos un campo: sin entidades no hay mutaciones
+        if !mutation_fields.is_empty() {
+            all_types.push(TypeDef::object(MUTATION_TYPE, None, mutation_fields));
+        }
+        all_types.extend(types);
+        for scalar in BUILTIN_SCALARS {
+            all_types.push(TypeDef::scalar(scalar, None));
+        }
+        for (scalar, description) in CUSTOM_SCALARS {
+            all_types.push(TypeDef::scalar(scalar, Some(description)));
+        }
+        all_types.extend(introspection_types());
+
+        let index = all_types.iter().enumerate().map(|(i, t)| (t.name.clone(), i)).collect();
+        Self {
+            types: all_types,
+            index,
+            entities: exposed.into_iter().map(|(_, r, _)| r).collect(),
+            directives: builtin_directives(),
+        }
+    }
+
+    pub fn type_def(&self, name: &str) -> Option<&TypeDef> {
+        self.index.get(name).map(|i| &self.types[*i])
+    }
+
+    pub fn types(&self) -> &[TypeDef] {
+        &self.types
+    }
+
+    pub fn query_type(&self) -> &TypeDef {
+        &self.types[self.index[QUERY_TYPE]]
+    }
+
+    pub fn mutation_type(&self) -> Option<&TypeDef> {
+        self.type_def(MUTATION_TYPE)
+    }
+
+    pub fn entities(&self) -> &[EntityRef] {
+        &self.entities
+    }
+
+    pub fn directives(&self) -> &[DirectiveDef] {
+        &self.directives
+    }
+}
+
+/// Adapta un nombre a la sintaxis de GraphQL (`[_A-Za-z][_0-9A-Za-z]*`).
+/// Devuelve `None` si queda vacío o empieza por `__` (reservado a la introspección).
+pub fn graphql_name(name: &str) -> Option<String> {
+    let mut adapted: String = name.trim()
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '_' { c } else { '_' })
+        .collect();
+    if adapted.is_empty() || adapted.chars().all(|c| c == '_') {
+        return None;
+    }
+    if adapted.starts_with(|c: char| c.is_ascii_digit()) {
+        adapted.insert(0, '_');
+    }
+    (!adapted.starts_with("__")).then_some(adapted)
+}
+
+/// Nombre de tipo de una entidad: `customer_order` -> `CustomerOrder`.
+fn pascal_case(name: &str) -> Option<String> {
+    let pascal: String = name
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect();
+    graphql_name(&pascal)
+}
+
+fn entity_type_names(type_name: &str) -> [String; 4] {
+    [
+        type_name.to_string(),
+        format!("{}Page", type_name),
+        format!("{}Input", type_name),
+        format!("{}Patch", type_name),
+    ]
+}
+
+fn root_field_names(field: &str) -> [String; 5] {
+    [
+        field.to_string(),
+        format!("{}_list", field),
+        format!("create_{}", field),
+        format!("update_{}", field),
+        format!("delete_{}", field),
+    ]
+}
+
+/// Atributos expuestos con su nombre GraphQL: se omiten los que coinciden con
+/// una columna de sistema o con un atributo anterior tras adaptar el nombre.
+fn exposed_attributes<'e, 'a>(entity: &'e GraphQLEntity<'a>) -> Vec<(String, &'e GraphQLAttribute<'a>)> {
+    let mut taken: HashSet<String> = SystemField::ALL.iter().map(|f| f.name().to_string()).collect();
+    let mut attributes = Vec::new();
+    for attribute in &entity.attributes {
+        if let Some(name) = graphql_name(attribute.name) {
+            if taken.insert(name.clone()) {
+                attributes.push((name, attribute));
+            }
+        }
+    }
+    attributes
+}
+
+/// Tipo de los valores de un atributo (sin referencias).
+fn scalar_type(storage: &DataTypeStorage) -> &'static str {
+    if storage.is_enumeration() {
+        return "String";
+    }
+    match storage.column {
+        StorageColumn::String | StorageColumn::Text | StorageColumn::Binary => "String",
+        StorageColumn::Integer if INT32_CASTS.contains(&storage.pg_cast.as_str()) => "Int",
+        StorageColumn::Integer => "BigInt",
+        StorageColumn::Float => "Float",
+        StorageColumn::Numeric => "Decimal",
+        StorageColumn::Boolean => "Boolean",
+        StorageColumn::DateTime => "DateTime",
+        StorageColumn::Date => "Date",
+        StorageColumn::Time => "Time",
+        StorageColumn::Uuid => "UUID",
+        StorageColumn::Json => "JSON",
+    }
+}
+
+fn entity_types(
+    entity: &GraphQLEntity<'_>,
+    entity_ref: &EntityRef,
+    attributes: &[(String, &GraphQLAttribute<'_>)],
+    by_id: &HashMap<Uuid, &EntityRef>,
+) -> Vec<TypeDef> {
+    let type_name = &entity_ref.type_name;
+    let system = |field: SystemField, ty: TypeRef, description: &str| {
+        FieldDef::new(field.name(), ty, FieldResolver::System(field), Some(description))
+    };
+
+    let mut fields = vec![system(SystemField::Id, TypeRef::non_null(TypeRef::named("ID")), "Record ID")];
+    let mut input_fields = Vec::new();
+    let mut patch_fields = Vec::new();
+    for (name, attribute) in attributes {
+        let description = attribute.description.filter(|d| !d.is_empty());
+        let target = attribute.reference_entity_id
+            .filter(|_| attribute.storage.is_reference())
+            .and_then(|id| by_id.get(&id));
+        let (output, resolver, input) = match target {
+            Some(target) => (
+                TypeRef::named(&target.type_name),
+                FieldResolver::Reference { attribute: attribute.name.to_string(), entity: (*target).clone() },
+                "ID",
+            ),
+            // Referencias a entidades no expuestas: solo el ID
+            None if attribute.storage.is_reference() => {
+                (TypeRef::named("ID"), FieldResolver::Attribute(attribute.name.to_string()), "ID")
+            },
+            None => {
+                let scalar = scalar_type(attribute.storage);
+                (TypeRef::named(scalar), FieldResolver::Attribute(attribute.name.to_string()), scalar)
+            },
+        };
+        fields.push(FieldDef::new(name, output, resolver, description));
+
+        let input_type = if attribute.is_required && !attribute.has_default {
+            TypeRef::non_null(TypeRef::named(input))
+        } else {
+            TypeRef::named(input)
+        };
+        let mut input_field = InputValueDef::new(name, input_type, description);
+        input_field.attribute = Some(attribute.name.to_string());
+        input_fields.push(input_field);
+
+        let mut patch_field = InputValueDef::new(name, TypeRef::named(input), description);
+        patch_field.attribute = Some(attribute.name.to_string());
+        patch_fields.push(patch_field);
+    }
+    fields.push(system(SystemField::CreatedAt, TypeRef::non_null(TypeRef::named("DateTime")), "Creation date"));
+    fields.push(system(SystemField::CreatedBy, TypeRef::named("UUID"), "User who created the record"));
+    fields.push(system(SystemField::UpdatedAt, TypeRef::named("DateTime"), "Last update date"));
+    fields.push(system(SystemField::UpdatedBy, TypeRef::named("UUID"), "User who last updated the record"));
+    fields.push(system(SystemField::Status, TypeRef::non_null(TypeRef::named("Int")), "Record status"));
+
+    let page_fields = vec![
+        FieldDef::new(
+            "items",
+            TypeRef::non_null(TypeRef::list(TypeRef::non_null(TypeRef::named(type_name)))),
+            FieldResolver::PageItems,
+            None,
+        ),
+        FieldDef::new(
+            "next_cursor",
+            TypeRef::named("String"),
+            FieldResolver::PageNextCursor,
+            Some("Cursor for the next page (argument `after`); null on the last page"),
+        ),
+    ];
+
+    let description = entity.description.filter(|d| !d.is_empty()).unwrap_or(entity.name);
+    let mut types = vec![
+        TypeDef::object(type_name, Some(description), fields),
+        TypeDef::object(&format!("{}Page", type_name), Some(&format!("Page of {} records", entity.name)), page_fields),
+    ];
+    // Un tipo de entrada necesita al menos un campo
+    if !input_fields.is_empty() {
+        types.push(TypeDef::input_object(
+            &format!("{}Input", type_name),
+            Some(&format!("Values of a new {} record; omitted attributes take their default value", entity.name)),
+            input_fields,
+        ));
+        types.push(TypeDef::input_object(
+            &format!("{}Patch", type_name),
+            Some(&format!("Values to change in a {} record; null clears a value", entity.name)),
+            patch_fields,
+        ));
+    }
+    types
+}
+
+fn add_root_fields(
+    query_fields: &mut Vec<FieldDef>,
+    mutation_fields: &mut Vec<FieldDef>,
+    entity: &EntityRef,
+    field: &str,
+    has_attributes: bool,
+) {
+    let type_name = &entity.type_name;
+    let id_arg = || InputValueDef::new("id", TypeRef::non_null(TypeRef::named("ID")), Some("Record ID"));
+
+    query_fields.push(
+        FieldDef::new(field, TypeRef::named(type_name), FieldResolver::FindRecord(entity.clone()), Some(&format!("{} record by ID; null if it does not exist", entity.name)))
+            .with_args(vec![id_arg()]),
+    );
+    query_fields.push(
+        FieldDef::new(
+            &format!("{}_list", field),
+            TypeRef::named(&format!("{}Page", type_name)),
+            FieldResolver::ListRecords(entity.clone()),
+            Some(&format!("Page of {} records", entity.name)),
+        )
+        .with_args(vec![
+            InputValueDef::new("filter", TypeRef::named("String"), Some("Filter expression over attribute names, e.g. status eq 'open' and amount gt 100")),
+            InputValueDef::new("sort", TypeRef::named("String"), Some("Comma-separated attribute names; prefix with - for descending order")),
+            InputValueDef::new("first", TypeRef::named("Int"), Some("Page size")).with_default(&DEFAULT_PAGE_SIZE.to_string()),
+            InputValueDef::new("after", TypeRef::named("String"), Some("Cursor returned as next_cursor by the previous page")),
+        ]),
+    );
+
+    let input = |suffix: &str| InputValueDef::new("input", TypeRef::non_null(TypeRef::named(&format!("{}{}", type_name, suffix))), None);
+    // Sin atributos no hay tipos de entrada (ver `entity_types`): solo se puede borrar
+    if has_attributes {
+        mutation_fields.push(
+            FieldDef::new(&format!("create_{}", field), TypeRef::named(type_name), FieldResolver::CreateRecord(entity.clone()), Some(&format!("Create a {} record", entity.name)))
+                .with_args(vec![input("Input")]),
+        );
+        mutation_fields.push(
+            FieldDef::new(&format!("update_{}", field), TypeRef::named(type_name), FieldResolver::UpdateRecord(entity.clone()), Some(&format!("Update some values of a {} record", entity.name)))
+                .with_args(vec![id_arg(), input("Patch")]),
+        );
+    }
+    mutation_fields.push(
+        FieldDef::new(&format!("delete_{}", field), TypeRef::named("ID"), FieldResolver::DeleteRecord(entity.clone()), Some(&format!("Move a {} record to the trash; returns its ID", entity.name)))
+            .with_args(vec![id_arg()]),
+    );
+}
+
+fn builtin_directives() -> Vec<DirectiveDef> {
+    let locations = vec!["FIELD", "FRAGMENT_SPREAD", "INLINE_FRAGMENT"];
+    let condition = |description: &str| vec![InputValueDef::new("if", TypeRef::non_null(TypeRef::named("Boolean")), Some(description))];
+    vec![
+        DirectiveDef {
+            name: "skip".to_string(),
+            description: "Directs the executor to skip this field or fragment when the `if` argument is true.".to_string(),
+            locations: locations.clone(),
+            args: condition("Skipped when true."),
+        },
+        DirectiveDef {
+            name: "include".to_string(),
+            description: "Directs the executor to include this field or fragment only when the `if` argument is true.".to_string(),
+            locations,
+            args: condition("Included when true."),
+        },
+    ]
+}
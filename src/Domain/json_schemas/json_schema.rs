@@ -0,0 +1,215 @@
+// src/Domain/json_schemas/json_schema.rs
+
+use serde_json::{json, Map, Value};
+
+use crate::Domain::attribute_options::AttributeOption;
+use crate::Domain::data_types::DataTypeStorage;
+use crate::Domain::records::StorageColumn;
+
+/// Dialecto de los documentos generados.
+pub const JSON_SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+// Estructura auxiliar con los datos del atributo que describen sus valores
+pub struct SchemaAttribute<'a> {
+    pub name: &'a str,
+    pub description: Option<&'a str>,
+    pub position: i16,
+    pub storage: &'a DataTypeStorage,
+    pub is_required: bool,
+    pub default_value: Option<&'a str>,
+    pub validation_regex: Option<&'a str>,
+    pub options: &'a [AttributeOption], // Enumeraciones: solo las opciones activas admiten valores
+}
+
+/// Documento JSON Schema (draft 2020-12) del cuerpo de un registro de la entidad:
+/// un objeto plano {nombre_atributo: valor} sin propiedades adicionales.
+/// Las propiedades siguen el orden de `position`.
+pub fn entity_json_schema(entity_name: &str, description: Option<&str>, attributes: &[SchemaAttribute<'_>]) -> Value {
+    let mut ordered: Vec<&SchemaAttribute<'_>> = attributes.iter().collect();
+    ordered.sort_by_key(|a| (a.position, a.name));
+
+    let mut properties = Map::new();
+    for attribute in &ordered {
+        properties.insert(attribute.name.to_string(), Value::Object(attribute_schema(attribute)));
+    }
+    let required: Vec<Value> = ordered.iter()
+        .filter(|a| a.is_required)
+        .map(|a| Value::String(a.name.to_string()))
+        .collect();
+
+    let mut schema = Map::new();
+    schema.insert("$schema".to_string(), json!(JSON_SCHEMA_DIALECT));
+    schema.insert("title".to_string(), json!(entity_name));
+    if let Some(description) = description.filter(|d| !d.is_empty()) {
+        schema.insert("description".to_string(), json!(description));
+    }
+    schema.insert("type".to_string(), json!("object"));
+    schema.insert("properties".to_string(), Value::Object(properties));
+    if !required.is_empty() {
+        schema.insert("required".to_string(), Value::Array(required));
+    }
+    schema.insert("additionalProperties".to_string(), json!(false));
+    Value::Object(schema)
+}
+
+/// Esquema de los valores de un atributo. Los atributos opcionales admiten `null`.
+fn attribute_schema(attribute: &SchemaAttribute<'_>) -> Map<String, Value> {
+    let storage = attribute.storage;
+    let params = &storage.params;
+    let mut schema = Map::new();
+
+    if let Some(description) = attribute.description.filter(|d| !d.is_empty()) {
+        schema.insert("description".to_string(), json!(description));
+    }
+
+    // Json admite cualquier valor: no se declara tipo
+    if let Some(json_type) = json_type(storage.column) {
+        let json_type = if attribute.is_required { json!(json_type) } else { json!([json_type, "null"]) };
+        schema.insert("type".to_string(), json_type);
+    }
+    if let Some(format) = json_format(storage.column) {
+        schema.insert("format".to_string(), json!(format));
+    }
+    if storage.column == StorageColumn::Binary {
+        schema.insert("contentEncoding".to_string(), json!("base64"));
+    }
+
+    if let Some(max_length) = params.max_length {
+        schema.insert("maxLength".to_string(), json!(max_length));
+    }
+    if let Some(min) = params.min_value {
+        schema.insert("minimum".to_string(), json!(min));
+    }
+    if let Some(max) = params.max_value {
+        schema.insert("maximum".to_string(), json!(max));
+    }
+
+    if storage.is_enumeration() {
+        let mut options: Vec<&AttributeOption> = attribute.options.iter().filter(|o| o.is_active).collect();
+        options.sort_by_key(|o| o.sort_order);
+        let mut codes: Vec<Value> = options.iter().map(|o| json!(o.code)).collect();
+        if !attribute.is_required {
+            codes.push(Value::Null);
+        }
+        schema.insert("enum".to_string(), Value::Array(codes));
+    }
+
+    // validation_regex debe coincidir con el valor completo; `pattern` de JSON Schema no está anclado
+    if let Some(pattern) = attribute.validation_regex.filter(|p| !p.is_empty()) {
+        schema.insert("pattern".to_string(), json!(format!("^(?:{})$", pattern)));
+    }
+
+    if let Some(default) = attribute.default_value {
+        schema.insert("default".to_string(), default_value(storage.column, default));
+    }
+    schema
+}
+
+fn json_type(column: StorageColumn) -> Option<&'static str> {
+    match column {
+        StorageColumn::String
+        | StorageColumn::Text
+        | StorageColumn::DateTime
+        | StorageColumn::Date
+        | StorageColumn::Time
+        | StorageColumn::Uuid
+        | StorageColumn::Binary => Some("string"),
+        StorageColumn::Integer => Some("integer"),
+        StorageColumn::Float | StorageColumn::Numeric => Some("number"),
+        StorageColumn::Boolean => Some("boolean"),
+        StorageColumn::Json => None,
+    }
+}
+
+fn json_format(column: StorageColumn) -> Option<&'static str> {
+    match column {
+        StorageColumn::DateTime => Some("date-time"),
+        StorageColumn::Date => Some("date"),
+        StorageColumn::Time => Some("time"),
+        StorageColumn::Uuid => Some("uuid"),
+        _ => None,
+    }
+}
+
+/// `default_value` se guarda como texto: se convierte al tipo JSON de la columna.
+/// Si no se puede convertir, se conserva como cadena.
+fn default_value(column: StorageColumn, default: &str) -> Value {
+    let text = || Value::String(default.to_string());
+    match column {
+        StorageColumn::Integer => default.trim().parse::<i64>().map(Value::from).unwrap_or_else(|_| text()),
+        StorageColumn::Float | StorageColumn::Numeric => {
+            serde_json::from_str::<serde_json::Number>(default.trim()).map(Value::Number).unwrap_or_else(|_| text())
+        },
+        StorageColumn::Boolean => match default.to_lowercase().as_str() {
+            "true" | "1" => Value::Bool(true),
+            "false" | "0" => Value::Bool(false),
+            _ => text(),
+        },
+        StorageColumn::Json => serde_json::from_str(default).unwrap_or_else(|_| text()),
+        _ => text(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Domain::data_types::{DataTypeKind, DataTypeParams};
+
+    fn storage(column: StorageColumn, params: DataTypeParams) -> DataTypeStorage {
+        DataTypeStorage::new(column, None, params).unwrap()
+    }
+
+    fn attribute<'a>(name: &'a str, position: i16, storage: &'a DataTypeStorage) -> SchemaAttribute<'a> {
+        SchemaAttribute {
+            name,
+            description: None,
+            position,
+            storage,
+            is_required: false,
+            default_value: None,
+            validation_regex: None,
+            options: &[],
+        }
+    }
+
+    #[test]
+    fn maps_attributes_to_ordered_properties() {
+        let code = storage(StorageColumn::String, DataTypeParams { max_length: Some(20), ..Default::default() });
+        let amount = storage(StorageColumn::Numeric, DataTypeParams { min_value: Some(0.0), ..Default::default() });
+        let active = storage(StorageColumn::Boolean, DataTypeParams::default());
+        let attributes = vec![
+            SchemaAttribute { default_value: Some("1"), ..attribute("active", 3, &active) },
+            SchemaAttribute { is_required: true, validation_regex: Some("[A-Z]+"), ..attribute("code", 1, &code) },
+            SchemaAttribute { default_value: Some("10.50"), ..attribute("amount", 2, &amount) },
+        ];
+
+        let schema = entity_json_schema("invoice", Some("Facturas"), &attributes);
+
+        assert_eq!(schema["$schema"], JSON_SCHEMA_DIALECT);
+        assert_eq!(schema["additionalProperties"], false);
+        assert_eq!(schema["required"], json!(["code"]));
+        let names: Vec<&String> = schema["properties"].as_object().unwrap().keys().collect();
+        assert_eq!(names, ["code", "amount", "active"]);
+        assert_eq!(schema["properties"]["code"], json!({"type": "string", "maxLength": 20, "pattern": "^(?:[A-Z]+)$"}));
+        assert_eq!(schema["properties"]["amount"], json!({"type": ["number", "null"], "minimum": 0.0, "default": 10.50}));
+        assert_eq!(schema["properties"]["active"]["default"], true);
+    }
+
+    #[test]
+    fn enumerations_list_active_option_codes() {
+        let status = storage(StorageColumn::String, DataTypeParams::default()).with_kind(DataTypeKind::Enumeration).unwrap();
+        let option = |code: &str, sort_order, is_active| AttributeOption {
+            code: code.to_string(),
+            label: code.to_uppercase(),
+            sort_order,
+            is_active,
+        };
+        let options = vec![option("closed", 2, true), option("open", 1, true), option("legacy", 3, false)];
+        let attributes = vec![SchemaAttribute { options: &options, ..attribute("status", 1, &status) }];
+
+        let schema = entity_json_schema("ticket", None, &attributes);
+
+        assert_eq!(schema["properties"]["status"]["enum"], json!(["open", "closed", null]));
+        assert!(schema.get("required").is_none());
+    }
+}
@@ -0,0 +1,6 @@
+// src/Domain/json_schemas/mod.rs
+// Documentos JSON Schema (draft 2020-12) generados a partir de los atributos de una entidad.
+
+pub mod json_schema;
+
+pub use json_schema::{SchemaAttribute, entity_json_schema, JSON_SCHEMA_DIALECT};
@@ -2,3 +2,21 @@ pub mod entities;
 pub mod repositories;
 pub mod services;
 pub mod errors;
+pub mod records;
+pub mod views;
+pub mod schema_versions;
+pub mod data_types;
+pub mod attribute_options;
+pub mod attribute_references;
+pub mod record_queries;
+pub mod record_imports;
+pub mod record_exports;
+pub mod schema_bundles;
+pub mod json_schemas;
+pub mod graphql;
+pub mod trash;
+pub mod record_history;
+pub mod concurrency;
+pub mod physical_tables;
+pub mod sql_identifiers;
+pub mod schema_previews;
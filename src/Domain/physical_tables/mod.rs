@@ -0,0 +1,12 @@
+// src/Domain/physical_tables/mod.rs
+// Almacenamiento físico de los registros: una tabla por entidad con una columna
+// tipada por atributo y restricciones derivadas de sus metadatos.
+
+pub mod storage_mode;
+pub mod table_generator;
+
+pub use storage_mode::StorageMode;
+pub use table_generator::{
+    ExistingColumn, ExistingTable, PhysicalColumn, attribute_id_of_column, copy_to_eav_sql, copy_to_physical_sql,
+    delete_eav_values_sql, drop_table_sql, physical_column_name, physical_table_name, physical_type, plan_table_sync,
+};
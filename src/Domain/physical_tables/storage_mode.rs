@@ -0,0 +1,36 @@
+// src/Domain/physical_tables/storage_mode.rs
+
+use serde::{Deserialize, Serialize};
+
+use crate::Domain::errors::{DomainError, DomainResult};
+
+/// Dónde se guardan los valores de los registros de una entidad lógica.
+/// Las tuplas (ID, versión, papelera, historial) siempre están en `tuplas`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StorageMode {
+    /// Una fila de `attribute_values` por atributo con valor
+    #[default]
+    Eav,
+    /// Una tabla propia de la entidad con una columna tipada por atributo
+    Physical,
+}
+
+impl StorageMode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StorageMode::Eav => "eav",
+            StorageMode::Physical => "physical",
+        }
+    }
+
+    pub fn parse(value: &str) -> DomainResult<Self> {
+        match value {
+            "eav" => Ok(StorageMode::Eav),
+            "physical" => Ok(StorageMode::Physical),
+            other => Err(DomainError::ValidationError(format!(
+                "Modo de almacenamiento desconocido: '{}' (se admite 'eav' o 'physical')", other
+            ))),
+        }
+    }
+}
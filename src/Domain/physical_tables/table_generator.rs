@@ -0,0 +1,405 @@
+// src/Domain/physical_tables/table_generator.rs
+
+use std::collections::{BTreeMap, BTreeSet};
+use uuid::Uuid;
+
+use crate::Domain::data_types::DataTypeStorage;
+use crate::Domain::errors::{DomainError, DomainResult};
+use crate::Domain::records::StorageColumn;
+use crate::Domain::sql_identifiers::{quote_literal, uuid_literal};
+
+/// Función de trigger (migración add_physical_storage_mode) que mantiene el documento
+/// de búsqueda de los registros guardados en tablas físicas.
+const SEARCH_TRIGGER_FUNCTION: &str = "physical_record_search_trigger";
+
+/// Nombre de la tabla física de una entidad. Se deriva del ID (no del nombre) para que
+/// renombrar la entidad no obligue a renombrar la tabla; al ser solo minúsculas, dígitos
+/// y `_`, se interpola sin comillas.
+pub fn physical_table_name(entity_id: Uuid) -> String {
+    format!("records_{}", entity_id.simple())
+}
+
+/// Nombre de la columna física de un atributo (estable aunque el atributo cambie de nombre).
+pub fn physical_column_name(attribute_id: Uuid) -> String {
+    format!("a_{}", attribute_id.simple())
+}
+
+/// Atributo al que corresponde una columna de la tabla física (None para `id`).
+pub fn attribute_id_of_column(column_name: &str) -> Option<Uuid> {
+    column_name
+        .strip_prefix("a_")
+        .filter(|hex| hex.len() == 32)
+        .and_then(|hex| Uuid::parse_str(hex).ok())
+}
+
+/// Tipo de la columna física de un atributo: el de su columna de almacenamiento, con la
+/// grafía de `format_type` de Postgres (la misma con la que se describe la tabla existente).
+pub fn physical_type(column: StorageColumn) -> &'static str {
+    match column {
+        StorageColumn::DateTime => "timestamp with time zone",
+        StorageColumn::Time => "time without time zone",
+        other => other.pg_type(),
+    }
+}
+
+/// Columna de la tabla física derivada de un atributo de la entidad.
+#[derive(Debug, Clone)]
+pub struct PhysicalColumn {
+    pub attribute_id: Uuid,
+    /// Nombre del atributo: se guarda como comentario de la columna
+    pub name: String,
+    pub storage: DataTypeStorage,
+    pub is_required: bool,
+    pub unique_group: Option<i16>,
+    /// Códigos de la lista de opciones (activas o no) de las enumeraciones
+    pub option_codes: Vec<String>,
+    /// Los atributos retirados conservan su columna y sus valores, sin restricciones
+    pub active: bool,
+}
+
+impl PhysicalColumn {
+    pub fn column_name(&self) -> String {
+        physical_column_name(self.attribute_id)
+    }
+
+    fn not_null(&self) -> bool {
+        self.active && self.is_required
+    }
+
+    /// Condiciones CHECK derivadas de los parámetros del tipo de dato y de la lista de
+    /// opciones. La expresión regular de validación no se traslada: su sintaxis no
+    /// coincide del todo con la de Postgres y se sigue comprobando al escribir.
+    pub fn check_expressions(&self) -> Vec<String> {
+        if !self.active {
+            return Vec::new();
+        }
+        let column = self.column_name();
+        let params = &self.storage.params;
+        let mut checks = Vec::new();
+        if let Some(max_length) = params.max_length {
+            checks.push(format!("char_length({}) <= {}", column, max_length));
+        }
+        if let Some(min) = params.min_value {
+            checks.push(format!("{} >= {}", column, min));
+        }
+        if let Some(max) = params.max_value {
+            checks.push(format!("{} <= {}", column, max));
+        }
+        if self.storage.is_enumeration() && !self.option_codes.is_empty() {
+            let codes: Vec<String> = self.option_codes.iter().map(|code| quote_literal(code)).collect();
+            checks.push(format!("{} IN ({})", column, codes.join(", ")));
+        }
+        checks
+    }
+}
+
+/// Columna de la tabla física tal como está en la base de datos.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ExistingColumn {
+    pub name: String,
+    /// Tipo según `format_type` (`bigint`, `timestamp with time zone`, ...)
+    pub data_type: String,
+    pub nullable: bool,
+    pub comment: Option<String>,
+}
+
+/// Estado actual de la tabla física: columnas y nombres de sus restricciones.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ExistingTable {
+    pub columns: Vec<ExistingColumn>,
+    pub constraints: Vec<String>,
+}
+
+/// Hash FNV-1a de 32 bits: estable entre ejecuciones y versiones (a diferencia de
+/// `DefaultHasher`), de modo que la misma definición produce el mismo nombre.
+fn fnv1a(text: &str) -> u32 {
+    text.bytes().fold(0x811c_9dc5_u32, |hash, byte| (hash ^ u32::from(byte)).wrapping_mul(0x0100_0193))
+}
+
+/// Las restricciones se nombran por su definición: si la definición cambia, se elimina
+/// la anterior y se crea otra (los índices de UNIQUE exigen nombres únicos en el esquema).
+fn constraint_name(table: &str, kind: &str, definition: &str) -> String {
+    format!("{}_{}_{:08x}", table, kind, fnv1a(definition))
+}
+
+fn is_managed_constraint(table: &str, name: &str) -> bool {
+    ["ck", "uq"].iter().any(|kind| name.starts_with(&format!("{}_{}_", table, kind)))
+}
+
+/// Restricciones CHECK y UNIQUE que corresponden a los atributos (nombre -> definición).
+/// Un grupo de unicidad se traduce en un UNIQUE sobre sus columnas: como en EAV, las
+/// filas con algún valor NULL en la clave no entran en conflicto.
+fn desired_constraints(table: &str, columns: &[PhysicalColumn]) -> BTreeMap<String, String> {
+    let mut constraints = BTreeMap::new();
+    let mut groups: BTreeMap<i16, Vec<String>> = BTreeMap::new();
+    for column in columns.iter().filter(|c| c.active) {
+        for check in column.check_expressions() {
+            let definition = format!("CHECK ({})", check);
+            constraints.insert(constraint_name(table, "ck", &definition), definition);
+        }
+        if let Some(group) = column.unique_group.filter(|g| (1..=10).contains(g)) {
+            groups.entry(group).or_default().push(column.column_name());
+        }
+    }
+    for mut group_columns in groups.into_values() {
+        group_columns.sort();
+        let definition = format!("UNIQUE ({})", group_columns.join(", "));
+        constraints.insert(constraint_name(table, "uq", &definition), definition);
+    }
+    constraints
+}
+
+/// Sentencias que llevan la tabla física de la entidad (`existing`, None si no existe)
+/// al estado que describen sus atributos. Una tabla al día no genera sentencias.
+/// Falla si un atributo cambió de columna de almacenamiento: convertir esos valores
+/// exige pasar la entidad a EAV.
+pub fn plan_table_sync(
+    entity_id: Uuid,
+    existing: Option<&ExistingTable>,
+    columns: &[PhysicalColumn],
+) -> DomainResult<Vec<String>> {
+    let table = physical_table_name(entity_id);
+    let mut statements = Vec::new();
+    let empty = ExistingTable::default();
+    let current = match existing {
+        Some(existing) => existing,
+        None => {
+            statements.push(format!(
+                "CREATE TABLE {} (id UUID PRIMARY KEY REFERENCES tuplas(id) ON DELETE CASCADE)", table
+            ));
+            statements.push(format!(
+                "CREATE TRIGGER {0}_search AFTER INSERT OR UPDATE OR DELETE ON {0} FOR EACH ROW EXECUTE FUNCTION {1}()",
+                table, SEARCH_TRIGGER_FUNCTION
+            ));
+            &empty
+        },
+    };
+    let constraints = desired_constraints(&table, columns);
+
+    // 1. Restricciones que ya no corresponden a los atributos
+    for name in current.constraints.iter().filter(|n| is_managed_constraint(&table, n) && !constraints.contains_key(*n)) {
+        statements.push(format!("ALTER TABLE {} DROP CONSTRAINT IF EXISTS {}", table, name));
+    }
+
+    // 2. Columnas nuevas, obligatoriedad y comentario con el nombre del atributo
+    for column in columns {
+        column.storage.validate()?;
+        let name = column.column_name();
+        let data_type = physical_type(column.storage.column);
+        let existing_column = current.columns.iter().find(|c| c.name == name);
+        match existing_column {
+            None => {
+                statements.push(format!("ALTER TABLE {} ADD COLUMN {} {}", table, name, data_type));
+                if column.not_null() {
+                    statements.push(format!("ALTER TABLE {} ALTER COLUMN {} SET NOT NULL", table, name));
+                }
+            },
+            Some(existing) if existing.data_type != data_type => {
+                return Err(DomainError::InvalidState(format!(
+                    "La columna física de '{}' es de tipo {} y el atributo necesita {}: pase la entidad a \
+                     almacenamiento EAV para cambiar el tipo de almacenamiento",
+                    column.name, existing.data_type, data_type
+                )));
+            },
+            Some(existing) => {
+                if existing.nullable && column.not_null() {
+                    statements.push(format!("ALTER TABLE {} ALTER COLUMN {} SET NOT NULL", table, name));
+                } else if !existing.nullable && !column.not_null() {
+                    statements.push(format!("ALTER TABLE {} ALTER COLUMN {} DROP NOT NULL", table, name));
+                }
+            },
+        }
+        if existing_column.and_then(|c| c.comment.as_deref()) != Some(column.name.as_str()) {
+            statements.push(format!("COMMENT ON COLUMN {}.{} IS {}", table, name, quote_literal(&column.name)));
+        }
+    }
+
+    // 3. Columnas de atributos que ya no existen
+    let kept: BTreeSet<String> = columns.iter().map(|c| c.column_name()).collect();
+    for existing in current.columns.iter().filter(|c| attribute_id_of_column(&c.name).is_some() && !kept.contains(&c.name)) {
+        statements.push(format!("ALTER TABLE {} DROP COLUMN IF EXISTS {}", table, existing.name));
+    }
+
+    // 4. Restricciones nuevas (con las columnas ya creadas)
+    for (name, definition) in &constraints {
+        if !current.constraints.contains(name) {
+            statements.push(format!("ALTER TABLE {} ADD CONSTRAINT {} {}", table, name, definition));
+        }
+    }
+
+    Ok(statements)
+}
+
+/// Copia los valores de `attribute_values` a la tabla física: una fila por tupla de la
+/// entidad (también las de la papelera, que pueden restaurarse) con una columna por atributo.
+pub fn copy_to_physical_sql(entity_id: Uuid, columns: &[PhysicalColumn]) -> String {
+    let mut names = vec!["id".to_string()];
+    let mut values = vec!["tu.id".to_string()];
+    for column in columns {
+        names.push(column.column_name());
+        // array_agg admite todas las columnas de almacenamiento (MAX no admite boolean ni uuid)
+        values.push(format!(
+            "(array_agg(av.{}) FILTER (WHERE av.attribute_id = {}))[1]",
+            column.storage.column.column_name(), uuid_literal(column.attribute_id)
+        ));
+    }
+    format!(
+        "INSERT INTO {} ({})\nSELECT {}\nFROM tuplas tu\nLEFT JOIN attribute_values av ON av.instance_id = tu.id\n\
+         WHERE tu.entity_id = {}\nGROUP BY tu.id",
+        physical_table_name(entity_id),
+        names.join(", "),
+        values.join(",\n    "),
+        uuid_literal(entity_id)
+    )
+}
+
+/// Copia los valores de la tabla física a `attribute_values`: una sentencia por atributo.
+pub fn copy_to_eav_sql(entity_id: Uuid, columns: &[PhysicalColumn]) -> Vec<String> {
+    let table = physical_table_name(entity_id);
+    columns
+        .iter()
+        .map(|column| {
+            let name = column.column_name();
+            format!(
+                "INSERT INTO attribute_values (instance_id, attribute_id, {}) SELECT id, {}, {} FROM {} WHERE {} IS NOT NULL",
+                column.storage.column.column_name(), uuid_literal(column.attribute_id), name, table, name
+            )
+        })
+        .collect()
+}
+
+/// Elimina los valores EAV de los registros de la entidad.
+pub fn delete_eav_values_sql(entity_id: Uuid) -> String {
+    format!(
+        "DELETE FROM attribute_values av USING tuplas tu WHERE av.instance_id = tu.id AND tu.entity_id = {}",
+        uuid_literal(entity_id)
+    )
+}
+
+/// Elimina la tabla física de la entidad (si existe) junto con las vistas que la leen:
+/// quien la elimina debe volver a crear las vistas de las entidades afectadas.
+pub fn drop_table_sql(entity_id: Uuid) -> String {
+    format!("DROP TABLE IF EXISTS {} CASCADE", physical_table_name(entity_id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Domain::data_types::{DataTypeKind, DataTypeParams};
+
+    fn column(name: &str, storage_column: StorageColumn) -> PhysicalColumn {
+        PhysicalColumn {
+            attribute_id: Uuid::new_v4(),
+            name: name.to_string(),
+            storage: DataTypeStorage::new(storage_column, None, DataTypeParams::default()).unwrap(),
+            is_required: false,
+            unique_group: None,
+            option_codes: Vec::new(),
+            active: true,
+        }
+    }
+
+    /// Estado de la tabla tras aplicar el plan completo de `columns`.
+    fn synced(entity_id: Uuid, columns: &[PhysicalColumn]) -> ExistingTable {
+        let table = physical_table_name(entity_id);
+        ExistingTable {
+            columns: columns.iter().map(|c| ExistingColumn {
+                name: c.column_name(),
+                data_type: physical_type(c.storage.column).to_string(),
+                nullable: !c.not_null(),
+                comment: Some(c.name.clone()),
+            }).collect(),
+            constraints: desired_constraints(&table, columns).into_keys().collect(),
+        }
+    }
+
+    #[test]
+    fn column_names_round_trip_to_attribute_ids() {
+        let attribute_id = Uuid::new_v4();
+        let name = physical_column_name(attribute_id);
+        assert_eq!(name.len(), 34);
+        assert_eq!(attribute_id_of_column(&name), Some(attribute_id));
+        assert_eq!(attribute_id_of_column("id"), None);
+        assert!(physical_table_name(Uuid::new_v4()).len() < 63);
+    }
+
+    #[test]
+    fn new_table_gets_typed_columns_and_constraints() {
+        let entity_id = Uuid::new_v4();
+        let mut code = column("code", StorageColumn::String);
+        code.is_required = true;
+        code.unique_group = Some(1);
+        code.storage.params.max_length = Some(20);
+        let mut status = column("status", StorageColumn::String);
+        status.storage = status.storage.clone().with_kind(DataTypeKind::Enumeration).unwrap();
+        status.option_codes = vec!["open".to_string(), "it's".to_string()];
+        let created = column("created", StorageColumn::DateTime);
+
+        let plan = plan_table_sync(entity_id, None, &[code.clone(), status.clone(), created.clone()]).unwrap();
+        let sql = plan.join(";\n");
+        let table = physical_table_name(entity_id);
+
+        assert!(plan[0].starts_with(&format!("CREATE TABLE {} (id UUID PRIMARY KEY REFERENCES tuplas(id)", table)));
+        assert!(sql.contains(&format!("ADD COLUMN {} text", code.column_name())));
+        assert!(sql.contains(&format!("ADD COLUMN {} timestamp with time zone", created.column_name())));
+        assert!(sql.contains(&format!("ALTER COLUMN {} SET NOT NULL", code.column_name())));
+        assert!(!sql.contains(&format!("ALTER COLUMN {} SET NOT NULL", status.column_name())));
+        assert!(sql.contains(&format!("CHECK (char_length({}) <= 20)", code.column_name())));
+        assert!(sql.contains(&format!("CHECK ({} IN ('open', 'it''s'))", status.column_name())));
+        assert!(sql.contains(&format!("UNIQUE ({})", code.column_name())));
+        assert!(sql.contains(&format!("COMMENT ON COLUMN {}.{} IS 'code'", table, code.column_name())));
+    }
+
+    #[test]
+    fn up_to_date_table_needs_no_changes() {
+        let entity_id = Uuid::new_v4();
+        let mut price = column("price", StorageColumn::Numeric);
+        price.storage.params.min_value = Some(0.0);
+        price.unique_group = Some(2);
+        let mut sku = column("sku", StorageColumn::String);
+        sku.unique_group = Some(2);
+        let columns = vec![price, sku];
+
+        let existing = synced(entity_id, &columns);
+        assert_eq!(plan_table_sync(entity_id, Some(&existing), &columns).unwrap(), Vec::<String>::new());
+    }
+
+    #[test]
+    fn follows_attribute_changes() {
+        let entity_id = Uuid::new_v4();
+        let mut qty = column("qty", StorageColumn::Integer);
+        qty.is_required = true;
+        qty.storage.params.max_value = Some(10.0);
+        let removed = column("removed", StorageColumn::Text);
+        let existing = synced(entity_id, &[qty.clone(), removed.clone()]);
+
+        // Se retira qty: conserva la columna, pierde NOT NULL y el CHECK; removed ya no existe
+        qty.active = false;
+        let plan = plan_table_sync(entity_id, Some(&existing), &[qty.clone()]).unwrap();
+        let sql = plan.join(";\n");
+        assert!(sql.contains(&format!("ALTER COLUMN {} DROP NOT NULL", qty.column_name())));
+        assert!(sql.contains("DROP CONSTRAINT IF EXISTS"));
+        assert!(sql.contains(&format!("DROP COLUMN IF EXISTS {}", removed.column_name())));
+        assert!(!sql.contains(&format!("DROP COLUMN IF EXISTS {}", qty.column_name())));
+
+        // Otra columna de almacenamiento exige pasar por EAV
+        let mut retyped = qty.clone();
+        retyped.storage = DataTypeStorage::new(StorageColumn::Text, None, DataTypeParams::default()).unwrap();
+        assert!(plan_table_sync(entity_id, Some(&existing), &[retyped]).is_err());
+    }
+
+    #[test]
+    fn copies_values_between_layouts() {
+        let entity_id = Uuid::new_v4();
+        let qty = column("qty", StorageColumn::Integer);
+
+        let to_physical = copy_to_physical_sql(entity_id, std::slice::from_ref(&qty));
+        assert!(to_physical.contains(&format!("(array_agg(av.integer_value) FILTER (WHERE av.attribute_id = '{}'))[1]", qty.attribute_id)));
+        assert!(to_physical.contains(&format!("WHERE tu.entity_id = '{}'", entity_id)));
+
+        let to_eav = copy_to_eav_sql(entity_id, std::slice::from_ref(&qty));
+        assert_eq!(to_eav.len(), 1);
+        assert!(to_eav[0].starts_with("INSERT INTO attribute_values (instance_id, attribute_id, integer_value)"));
+        assert!(to_eav[0].ends_with(&format!("WHERE {} IS NOT NULL", qty.column_name())));
+    }
+}
@@ -0,0 +1,7 @@
+// src/Domain/record_exports/mod.rs
+// Exportación de registros: formato de salida (CSV, NDJSON, JSON) de cada
+// valor según el tipo de su columna de almacenamiento.
+
+pub mod writer;
+
+pub use writer::{ExportColumn, ExportFormat, ExportValueKind, ExportWriter};
@@ -0,0 +1,241 @@
+// src/Domain/record_exports/writer.rs
+//
+// Escritura incremental de registros exportados. Los valores llegan como el
+// texto que produce Postgres para cada columna (sin pérdida de precisión en
+// numéricos ni de zona horaria en fechas) y se escriben según su tipo:
+//
+// - CSV: texto tal cual; se entrecomilla si contiene separador, comillas o saltos de línea.
+// - NDJSON / JSON: números y booleanos sin comillas, documentos JSON incrustados
+//   y el resto como cadenas. Las claves respetan el orden de las columnas.
+
+use serde_json::Value;
+
+use crate::Domain::errors::{DomainError, DomainResult};
+use crate::Domain::records::StorageColumn;
+
+/// Formato del archivo exportado.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Csv,
+    /// Un objeto JSON por línea.
+    Ndjson,
+    /// Un único array JSON.
+    Json,
+}
+
+impl ExportFormat {
+    pub fn from_name(name: &str) -> DomainResult<Self> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "csv" => Ok(ExportFormat::Csv),
+            "ndjson" | "jsonl" => Ok(ExportFormat::Ndjson),
+            "json" => Ok(ExportFormat::Json),
+            other => Err(DomainError::ValidationError(format!(
+                "formato de exportación '{}' no válido (admitidos: csv, ndjson, json)", other
+            ))),
+        }
+    }
+
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "text/csv; charset=utf-8",
+            ExportFormat::Ndjson => "application/x-ndjson",
+            ExportFormat::Json => "application/json",
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "csv",
+            ExportFormat::Ndjson => "ndjson",
+            ExportFormat::Json => "json",
+        }
+    }
+}
+
+/// Cómo se escribe un valor en JSON.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportValueKind {
+    Text,
+    Number,
+    Boolean,
+    Json,
+}
+
+impl From<StorageColumn> for ExportValueKind {
+    fn from(column: StorageColumn) -> Self {
+        match column {
+            StorageColumn::Integer | StorageColumn::Float | StorageColumn::Numeric => ExportValueKind::Number,
+            StorageColumn::Boolean => ExportValueKind::Boolean,
+            StorageColumn::Json => ExportValueKind::Json,
+            _ => ExportValueKind::Text,
+        }
+    }
+}
+
+/// Columna del archivo exportado.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExportColumn {
+    pub name: String,
+    pub kind: ExportValueKind,
+}
+
+impl ExportColumn {
+    pub fn new(name: &str, kind: ExportValueKind) -> Self {
+        Self { name: name.to_string(), kind }
+    }
+}
+
+/// Escribe la cabecera, las filas y el cierre del archivo. Cada fila trae un
+/// valor (o `None`) por columna, en el orden de `columns`.
+#[derive(Debug, Clone)]
+pub struct ExportWriter {
+    format: ExportFormat,
+    columns: Vec<ExportColumn>,
+    delimiter: char,
+    rows: usize,
+}
+
+impl ExportWriter {
+    pub fn new(format: ExportFormat, columns: Vec<ExportColumn>) -> Self {
+        Self { format, columns, delimiter: ',', rows: 0 }
+    }
+
+    pub fn columns(&self) -> &[ExportColumn] {
+        &self.columns
+    }
+
+    /// Filas escritas hasta el momento.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Inicio del archivo: nombres de columna en CSV, apertura del array en JSON.
+    pub fn header(&self) -> String {
+        match self.format {
+            ExportFormat::Csv => {
+                let names: Vec<String> = self.columns.iter().map(|c| self.csv_cell(&c.name)).collect();
+                format!("{}\r\n", names.join(&self.delimiter.to_string()))
+            },
+            ExportFormat::Ndjson => String::new(),
+            ExportFormat::Json => "[".to_string(),
+        }
+    }
+
+    pub fn row(&mut self, values: &[Option<String>]) -> String {
+        let first = self.rows == 0;
+        self.rows += 1;
+        match self.format {
+            ExportFormat::Csv => {
+                let cells: Vec<String> = values.iter().map(|v| v.as_deref().map(|v| self.csv_cell(v)).unwrap_or_default()).collect();
+                format!("{}\r\n", cells.join(&self.delimiter.to_string()))
+            },
+            ExportFormat::Ndjson => format!("{}\n", self.json_object(values)),
+            ExportFormat::Json => format!("{}\n{}", if first { "" } else { "," }, self.json_object(values)),
+        }
+    }
+
+    /// Fin del archivo: cierre del array en JSON.
+    pub fn footer(&self) -> String {
+        match self.format {
+            ExportFormat::Json => "\n]\n".to_string(),
+            _ => String::new(),
+        }
+    }
+
+    fn csv_cell(&self, value: &str) -> String {
+        let needs_quotes = value.contains(self.delimiter)
+            || value.contains(['"', '\n', '\r'])
+            || value.starts_with(' ')
+            || value.ends_with(' ');
+        if needs_quotes {
+            format!("\"{}\"", value.replace('"', "\"\""))
+        } else {
+            value.to_string()
+        }
+    }
+
+    fn json_object(&self, values: &[Option<String>]) -> String {
+        let members: Vec<String> = self.columns
+            .iter()
+            .zip(values)
+            .map(|(column, value)| format!("{}:{}", json_string(&column.name), json_value(column.kind, value.as_deref())))
+            .collect();
+        format!("{{{}}}", members.join(","))
+    }
+}
+
+fn json_string(text: &str) -> String {
+    Value::String(text.to_string()).to_string()
+}
+
+/// Valor JSON a partir del texto de Postgres. Los números se copian tal cual para
+/// no perder precisión; NaN e Infinity (que JSON no admite) se escriben como texto.
+fn json_value(kind: ExportValueKind, value: Option<&str>) -> String {
+    let value = match value {
+        Some(value) => value,
+        None => return "null".to_string(),
+    };
+    match kind {
+        ExportValueKind::Number if serde_json::from_str::<Value>(value).map(|v| v.is_number()).unwrap_or(false) => value.to_string(),
+        ExportValueKind::Boolean if value == "true" || value == "false" => value.to_string(),
+        ExportValueKind::Json if serde_json::from_str::<Value>(value).is_ok() => value.to_string(),
+        _ => json_string(value),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn columns() -> Vec<ExportColumn> {
+        vec![
+            ExportColumn::new("name", ExportValueKind::Text),
+            ExportColumn::new("amount", ExportValueKind::Number),
+            ExportColumn::new("active", ExportValueKind::Boolean),
+            ExportColumn::new("meta", ExportValueKind::Json),
+        ]
+    }
+
+    fn row() -> Vec<Option<String>> {
+        vec![
+            Some("Smith, \"J\"".to_string()),
+            Some("12345678901234567890.123456789".to_string()),
+            Some("true".to_string()),
+            Some("{\"a\": [1, 2]}".to_string()),
+        ]
+    }
+
+    #[test]
+    fn test_csv_quotes_only_when_needed() {
+        let mut writer = ExportWriter::new(ExportFormat::Csv, columns());
+        assert_eq!(writer.header(), "name,amount,active,meta\r\n");
+        assert_eq!(
+            writer.row(&row()),
+            "\"Smith, \"\"J\"\"\",12345678901234567890.123456789,true,\"{\"\"a\"\": [1, 2]}\"\r\n"
+        );
+        assert_eq!(writer.row(&[None, Some("1".into()), None, None]), ",1,,\r\n");
+        assert_eq!(writer.rows(), 2);
+    }
+
+    #[test]
+    fn test_json_keeps_types_order_and_precision() {
+        let mut writer = ExportWriter::new(ExportFormat::Ndjson, columns());
+        assert_eq!(
+            writer.row(&row()),
+            "{\"name\":\"Smith, \\\"J\\\"\",\"amount\":12345678901234567890.123456789,\"active\":true,\"meta\":{\"a\": [1, 2]}}\n"
+        );
+        let nan = vec![None, Some("NaN".to_string()), Some("t".to_string()), None];
+        assert_eq!(writer.row(&nan), "{\"name\":null,\"amount\":\"NaN\",\"active\":\"t\",\"meta\":null}\n");
+
+        let mut writer = ExportWriter::new(ExportFormat::Json, vec![ExportColumn::new("n", ExportValueKind::Number)]);
+        let mut output = writer.header();
+        output.push_str(&writer.row(&[Some("1".into())]));
+        output.push_str(&writer.row(&[Some("2.5".into())]));
+        output.push_str(&writer.footer());
+        assert_eq!(serde_json::from_str::<Value>(&output).unwrap(), serde_json::json!([{"n": 1}, {"n": 2.5}]));
+
+        let empty = ExportWriter::new(ExportFormat::Json, columns());
+        assert_eq!(serde_json::from_str::<Value>(&format!("{}{}", empty.header(), empty.footer())).unwrap(), serde_json::json!([]));
+        assert!(ExportFormat::from_name("xml").is_err());
+    }
+}
@@ -0,0 +1,123 @@
+// src/Domain/record_history/as_of.rs
+
+use std::collections::HashMap;
+use serde_json::Value;
+use uuid::Uuid;
+
+use super::change::RecordChangeKind;
+
+/// Entrada del historial necesaria para deshacer un cambio. `attribute_id` es
+/// `None` en los eventos de la tupla (creación, eliminación, restauración, purga).
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecordChange {
+    pub attribute_id: Option<Uuid>,
+    pub kind: RecordChangeKind,
+    pub old_value: Option<Value>,
+    pub new_value: Option<Value>,
+}
+
+/// Estado lógico de un registro: si existe fuera de la papelera y sus valores.
+/// Un registro de la papelera (o purgado) no existe y no tiene valores: al moverlo
+/// a la papelera se registra la pérdida de cada valor.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RecordState {
+    pub exists: bool,
+    pub values: HashMap<Uuid, Value>,
+}
+
+/// Reconstruye el estado anterior de un registro deshaciendo, sobre el estado actual,
+/// los cambios posteriores a la fecha buscada. `newest_first` debe estar ordenado del
+/// más reciente al más antiguo.
+pub fn rewind<'a>(mut state: RecordState, newest_first: impl IntoIterator<Item = &'a RecordChange>) -> RecordState {
+    for change in newest_first {
+        match change.attribute_id {
+            Some(attribute_id) => match &change.old_value {
+                Some(value) if !value.is_null() => {
+                    state.values.insert(attribute_id, value.clone());
+                },
+                _ => {
+                    state.values.remove(&attribute_id);
+                },
+            },
+            None => match change.kind {
+                // Antes de crearse, restaurarse o purgarse no existía fuera de la papelera
+                RecordChangeKind::Create | RecordChangeKind::Restore | RecordChangeKind::Purge => state.exists = false,
+                RecordChangeKind::Delete => state.exists = true,
+                RecordChangeKind::Update => {},
+            },
+        }
+    }
+    state
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn tuple(kind: RecordChangeKind) -> RecordChange {
+        RecordChange { attribute_id: None, kind, old_value: None, new_value: None }
+    }
+
+    fn attribute(id: Uuid, kind: RecordChangeKind, old_value: Option<Value>, new_value: Option<Value>) -> RecordChange {
+        RecordChange { attribute_id: Some(id), kind, old_value, new_value }
+    }
+
+    #[test]
+    fn undoes_updates_newest_first() {
+        let name = Uuid::from_u128(1);
+        let current = RecordState { exists: true, values: HashMap::from([(name, json!("c"))]) };
+        let changes = vec![
+            attribute(name, RecordChangeKind::Update, Some(json!("b")), Some(json!("c"))),
+            attribute(name, RecordChangeKind::Update, Some(json!("a")), Some(json!("b"))),
+        ];
+
+        assert_eq!(rewind(current.clone(), &changes[..1]).values[&name], json!("b"));
+        assert_eq!(rewind(current, &changes).values[&name], json!("a"));
+    }
+
+    #[test]
+    fn record_did_not_exist_before_its_creation() {
+        let name = Uuid::from_u128(1);
+        let current = RecordState { exists: true, values: HashMap::from([(name, json!("a"))]) };
+        let changes = vec![
+            tuple(RecordChangeKind::Create),
+            attribute(name, RecordChangeKind::Create, None, Some(json!("a"))),
+        ];
+
+        let state = rewind(current, &changes);
+        assert!(!state.exists);
+        assert!(state.values.is_empty());
+    }
+
+    #[test]
+    fn purged_record_is_rebuilt_from_its_deletion() {
+        let (name, age) = (Uuid::from_u128(1), Uuid::from_u128(2));
+        // Purgado: ya no queda nada del registro
+        let changes = vec![
+            tuple(RecordChangeKind::Purge),
+            tuple(RecordChangeKind::Delete),
+            attribute(name, RecordChangeKind::Delete, Some(json!("a")), None),
+            attribute(age, RecordChangeKind::Delete, Some(json!(30)), None),
+        ];
+
+        let in_trash = rewind(RecordState::default(), &changes[..1]);
+        assert!(!in_trash.exists);
+
+        let live = rewind(RecordState::default(), &changes);
+        assert!(live.exists);
+        assert_eq!(live.values, HashMap::from([(name, json!("a")), (age, json!(30))]));
+    }
+
+    #[test]
+    fn restored_record_was_in_the_trash_before() {
+        let name = Uuid::from_u128(1);
+        let current = RecordState { exists: true, values: HashMap::from([(name, json!("a"))]) };
+        let changes = vec![
+            tuple(RecordChangeKind::Restore),
+            attribute(name, RecordChangeKind::Restore, None, Some(json!("a"))),
+        ];
+
+        assert_eq!(rewind(current, &changes), RecordState::default());
+    }
+}
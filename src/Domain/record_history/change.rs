@@ -0,0 +1,131 @@
+// src/Domain/record_history/change.rs
+
+use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use uuid::Uuid;
+
+/// Tipo de cambio registrado en el historial de un registro.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RecordChangeKind {
+    Create,
+    Update,
+    /// Movido a la papelera.
+    Delete,
+    /// Sacado de la papelera.
+    Restore,
+    /// Eliminado definitivamente.
+    Purge,
+}
+
+impl RecordChangeKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RecordChangeKind::Create => "create",
+            RecordChangeKind::Update => "update",
+            RecordChangeKind::Delete => "delete",
+            RecordChangeKind::Restore => "restore",
+            RecordChangeKind::Purge => "purge",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "create" => Some(RecordChangeKind::Create),
+            "update" => Some(RecordChangeKind::Update),
+            "delete" => Some(RecordChangeKind::Delete),
+            "restore" => Some(RecordChangeKind::Restore),
+            "purge" => Some(RecordChangeKind::Purge),
+            _ => None,
+        }
+    }
+}
+
+/// Autor de un cambio de registros y petición que lo originó; se guardan en el
+/// historial de los registros modificados.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ChangeContext {
+    /// `None` en los cambios del sistema (p. ej. la purga programada de la papelera).
+    pub actor: Option<Uuid>,
+    /// Identificador de la petición que originó el cambio (X-Request-Id).
+    pub request_id: Option<String>,
+}
+
+impl ChangeContext {
+    pub fn new(actor: Uuid, request_id: Option<String>) -> Self {
+        Self { actor: Some(actor), request_id }
+    }
+
+    /// Cambio sin usuario ni petición (tareas programadas).
+    pub fn system() -> Self {
+        Self::default()
+    }
+}
+
+/// Cambio del valor de un atributo. `None` = el atributo no tenía (o deja de tener) valor.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AttributeChange {
+    pub attribute_id: Uuid,
+    pub old_value: Option<Value>,
+    pub new_value: Option<Value>,
+}
+
+/// Atributos cuyo valor difiere entre `before` y `after`, ordenados por ID de atributo.
+/// Los valores `null` se tratan como ausentes.
+pub fn diff_values(before: &HashMap<Uuid, Value>, after: &HashMap<Uuid, Value>) -> Vec<AttributeChange> {
+    let present = |values: &HashMap<Uuid, Value>, id: &Uuid| values.get(id).filter(|v| !v.is_null()).cloned();
+
+    let mut ids: Vec<&Uuid> = before.keys().chain(after.keys()).collect();
+    ids.sort();
+    ids.dedup();
+
+    ids.into_iter()
+        .filter_map(|id| {
+            let old_value = present(before, id);
+            let new_value = present(after, id);
+            (old_value != new_value).then_some(AttributeChange { attribute_id: *id, old_value, new_value })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn kind_names_round_trip() {
+        for kind in [
+            RecordChangeKind::Create,
+            RecordChangeKind::Update,
+            RecordChangeKind::Delete,
+            RecordChangeKind::Restore,
+            RecordChangeKind::Purge,
+        ] {
+            assert_eq!(RecordChangeKind::from_name(kind.as_str()), Some(kind));
+        }
+        assert_eq!(RecordChangeKind::from_name("rename"), None);
+    }
+
+    #[test]
+    fn diff_reports_added_changed_and_removed_values() {
+        let (a, b, c, d) = (Uuid::from_u128(1), Uuid::from_u128(2), Uuid::from_u128(3), Uuid::from_u128(4));
+        let before = HashMap::from([(a, json!("x")), (b, json!(1)), (c, json!(true))]);
+        let after = HashMap::from([(a, json!("x")), (b, json!(2)), (d, json!([1]))]);
+
+        let changes = diff_values(&before, &after);
+        assert_eq!(changes, vec![
+            AttributeChange { attribute_id: b, old_value: Some(json!(1)), new_value: Some(json!(2)) },
+            AttributeChange { attribute_id: c, old_value: Some(json!(true)), new_value: None },
+            AttributeChange { attribute_id: d, old_value: None, new_value: Some(json!([1])) },
+        ]);
+    }
+
+    #[test]
+    fn null_values_count_as_absent() {
+        let a = Uuid::from_u128(1);
+        let before = HashMap::from([(a, Value::Null)]);
+        assert!(diff_values(&before, &HashMap::new()).is_empty());
+    }
+}
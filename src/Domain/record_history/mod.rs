@@ -0,0 +1,9 @@
+// src/Domain/record_history/mod.rs
+// Historial de cambios de los registros a nivel de atributo y reconstrucción del
+// estado de un registro en una fecha ("as-of").
+
+pub mod change;
+pub mod as_of;
+
+pub use change::{diff_values, AttributeChange, ChangeContext, RecordChangeKind};
+pub use as_of::{rewind, RecordChange, RecordState};
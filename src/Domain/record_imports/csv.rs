@@ -0,0 +1,130 @@
+// src/Domain/record_imports/csv.rs
+//
+// Lector de CSV (RFC 4180): campos entre comillas dobles con "" como escape,
+// saltos de línea dentro de las comillas y finales de línea LF o CRLF.
+
+use crate::Domain::errors::{DomainError, DomainResult};
+
+/// Fila del CSV con el número de línea (1 = primera línea) en que empieza.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CsvRecord {
+    pub line: usize,
+    pub fields: Vec<String>,
+}
+
+/// Separadores de campo admitidos.
+pub const CSV_DELIMITERS: [char; 3] = [',', ';', '\t'];
+
+/// Divide el contenido en filas y campos. Las líneas vacías se ignoran.
+pub fn parse_csv(content: &str, delimiter: char) -> DomainResult<Vec<CsvRecord>> {
+    if !CSV_DELIMITERS.contains(&delimiter) {
+        return Err(DomainError::ValidationError(format!(
+            "separador CSV no válido: {:?} (admitidos: ',', ';' y tabulador)", delimiter
+        )));
+    }
+
+    let content = content.strip_prefix('\u{feff}').unwrap_or(content);
+    let mut records = Vec::new();
+    let mut fields: Vec<String> = Vec::new();
+    let mut field = String::new();
+    let mut line = 1;
+    let mut record_line = 1;
+    // El campo actual empezó entre comillas / las comillas siguen abiertas
+    let mut quoted = false;
+    let mut in_quotes = false;
+    let mut chars = content.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    field.push('"');
+                },
+                '"' => in_quotes = false,
+                '\n' => {
+                    line += 1;
+                    field.push(c);
+                },
+                _ => field.push(c),
+            }
+            continue;
+        }
+
+        match c {
+            '"' if field.is_empty() && !quoted => {
+                quoted = true;
+                in_quotes = true;
+            },
+            '"' => {
+                return Err(DomainError::ValidationError(format!(
+                    "CSV no válido en la línea {}: comilla inesperada en un campo", line
+                )));
+            },
+            c if c == delimiter => {
+                fields.push(std::mem::take(&mut field));
+                quoted = false;
+            },
+            '\r' if chars.peek() == Some(&'\n') => {},
+            '\n' => {
+                fields.push(std::mem::take(&mut field));
+                push_record(&mut records, record_line, std::mem::take(&mut fields));
+                quoted = false;
+                line += 1;
+                record_line = line;
+            },
+            _ if quoted => {
+                return Err(DomainError::ValidationError(format!(
+                    "CSV no válido en la línea {}: texto después de la comilla de cierre", line
+                )));
+            },
+            _ => field.push(c),
+        }
+    }
+
+    if in_quotes {
+        return Err(DomainError::ValidationError(format!(
+            "CSV no válido: la comilla abierta en la línea {} no se cierra", record_line
+        )));
+    }
+    if !field.is_empty() || quoted || !fields.is_empty() {
+        fields.push(field);
+        push_record(&mut records, record_line, fields);
+    }
+
+    Ok(records)
+}
+
+fn push_record(records: &mut Vec<CsvRecord>, line: usize, fields: Vec<String>) {
+    // Una línea vacía produce un único campo vacío sin comillas
+    if fields.len() == 1 && fields[0].is_empty() {
+        return;
+    }
+    records.push(CsvRecord { line, fields });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_csv_quotes_and_line_numbers() {
+        let content = "\u{feff}name,notes\r\n\"Smith, J.\",\"says \"\"hi\"\"\"\n\nbob,\"two\nlines\"\nann,\n";
+        let records = parse_csv(content, ',').unwrap();
+        assert_eq!(records.len(), 4);
+        assert_eq!(records[0], CsvRecord { line: 1, fields: vec!["name".into(), "notes".into()] });
+        assert_eq!(records[1].fields, vec!["Smith, J.", "says \"hi\""]);
+        assert_eq!(records[1].line, 2);
+        assert_eq!(records[2], CsvRecord { line: 4, fields: vec!["bob".into(), "two\nlines".into()] });
+        assert_eq!(records[3], CsvRecord { line: 6, fields: vec!["ann".into(), "".into()] });
+    }
+
+    #[test]
+    fn test_parse_csv_rejects_malformed_input() {
+        assert!(parse_csv("a,\"b\nc", ',').is_err());
+        assert!(parse_csv("a,\"b\"c", ',').is_err());
+        assert!(parse_csv("a,b\"c", ',').is_err());
+        assert!(parse_csv("a|b", '|').is_err());
+        assert_eq!(parse_csv("a;b", ';').unwrap()[0].fields, vec!["a", "b"]);
+    }
+}
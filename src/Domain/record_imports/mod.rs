@@ -0,0 +1,9 @@
+// src/Domain/record_imports/mod.rs
+// Importación masiva de registros: lectura de archivos CSV y NDJSON y mapeo
+// de sus columnas a los atributos de la entidad.
+
+pub mod csv;
+pub mod source;
+
+pub use csv::{parse_csv, CsvRecord, CSV_DELIMITERS};
+pub use source::{read_rows, ColumnMapping, ImportErrorPolicy, ImportFormat, ImportIssue, ImportReader, ImportRow, MAX_IMPORT_ROWS};
@@ -0,0 +1,385 @@
+// src/Domain/record_imports/source.rs
+//
+// Lectura de un archivo de importación (CSV o NDJSON) como filas de valores
+// indexados por nombre de atributo, aplicando el mapeo de columnas.
+
+use std::collections::{HashMap, HashSet};
+use serde_json::{Map, Value};
+
+use crate::Domain::errors::{DomainError, DomainResult};
+
+use super::csv::{parse_csv, CSV_DELIMITERS};
+
+/// Número máximo de filas de datos de un archivo de importación.
+pub const MAX_IMPORT_ROWS: usize = 100_000;
+
+/// Formato del archivo de importación.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportFormat {
+    /// Primera fila con los nombres de columna; los valores se leen como texto.
+    Csv,
+    /// Un objeto JSON por línea.
+    Ndjson,
+}
+
+impl ImportFormat {
+    pub fn from_name(name: &str) -> DomainResult<Self> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "csv" => Ok(ImportFormat::Csv),
+            "ndjson" | "jsonl" => Ok(ImportFormat::Ndjson),
+            other => Err(DomainError::ValidationError(format!(
+                "formato de importación '{}' no válido (admitidos: csv, ndjson)", other
+            ))),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ImportFormat::Csv => "csv",
+            ImportFormat::Ndjson => "ndjson",
+        }
+    }
+}
+
+/// Qué hacer con las filas que no superan la validación.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportErrorPolicy {
+    /// Si alguna fila es inválida no se importa ninguna.
+    AllOrNothing,
+    /// Se importan las filas válidas y se informan las inválidas.
+    SkipInvalid,
+}
+
+impl ImportErrorPolicy {
+    pub fn from_name(name: &str) -> DomainResult<Self> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "all_or_nothing" => Ok(ImportErrorPolicy::AllOrNothing),
+            "skip_invalid" => Ok(ImportErrorPolicy::SkipInvalid),
+            other => Err(DomainError::ValidationError(format!(
+                "on_error '{}' no válido (admitidos: all_or_nothing, skip_invalid)", other
+            ))),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ImportErrorPolicy::AllOrNothing => "all_or_nothing",
+            ImportErrorPolicy::SkipInvalid => "skip_invalid",
+        }
+    }
+}
+
+/// Mapeo de columnas del archivo a nombres de atributo. Las columnas sin mapeo
+/// usan su propio nombre; las mapeadas a "" se ignoran.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ColumnMapping {
+    columns: HashMap<String, String>,
+}
+
+impl ColumnMapping {
+    /// Valida que dos columnas no se asignen al mismo atributo.
+    pub fn new(columns: HashMap<String, String>) -> DomainResult<Self> {
+        let mut targets = HashSet::new();
+        for (column, attribute) in &columns {
+            let attribute = attribute.trim();
+            if !attribute.is_empty() && !targets.insert(attribute.to_string()) {
+                return Err(DomainError::ValidationError(format!(
+                    "mapeo no válido: varias columnas se asignan al atributo '{}' (entre ellas '{}')", attribute, column
+                )));
+            }
+        }
+        let columns = columns.into_iter().map(|(column, attribute)| (column, attribute.trim().to_string())).collect();
+        Ok(Self { columns })
+    }
+
+    /// Atributo de una columna del archivo (`None` si se ignora).
+    pub fn attribute_for<'a>(&'a self, column: &'a str) -> Option<&'a str> {
+        match self.columns.get(column) {
+            Some(attribute) if attribute.is_empty() => None,
+            Some(attribute) => Some(attribute),
+            None => Some(column),
+        }
+    }
+
+    /// Columna del archivo de la que procede un atributo (para el informe de errores).
+    pub fn column_for(&self, attribute: &str) -> String {
+        self.columns
+            .iter()
+            .find(|(_, target)| target.as_str() == attribute)
+            .map(|(column, _)| column.clone())
+            .unwrap_or_else(|| attribute.to_string())
+    }
+}
+
+/// Fila de datos del archivo con su número de línea.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImportRow {
+    pub line: usize,
+    pub values: Map<String, Value>,
+}
+
+/// Problema de una fila detectado al leer el archivo (antes de validar sus valores).
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImportIssue {
+    pub line: usize,
+    pub column: Option<String>,
+    pub reason: String,
+}
+
+/// Lee las filas del archivo. Los errores de una fila (JSON no válido, número de
+/// campos incorrecto) se devuelven como `ImportIssue`; los que impiden leer el
+/// archivo (cabecera, comillas sin cerrar, demasiadas filas) como error.
+/// En CSV, las celdas vacías se tratan como ausentes.
+pub fn read_rows(
+    format: ImportFormat,
+    content: &str,
+    delimiter: char,
+    mapping: &ColumnMapping,
+) -> DomainResult<(Vec<ImportRow>, Vec<ImportIssue>)> {
+    let mut reader = ImportReader::new(format, delimiter, mapping.clone())?;
+    for text in content.lines() {
+        reader.push_line(text)?;
+    }
+    reader.finish()
+}
+
+/// Lector incremental del archivo de importación: recibe el contenido línea a línea
+/// (sin el salto de línea), de modo que el archivo no tiene que estar entero en memoria,
+/// y falla en cuanto se supera `MAX_IMPORT_ROWS`. Mismas reglas que `read_rows`.
+#[derive(Debug)]
+pub struct ImportReader {
+    format: ImportFormat,
+    delimiter: char,
+    mapping: ColumnMapping,
+    /// Número de la última línea recibida.
+    line: usize,
+    /// CSV: fila con un campo entre comillas aún abierto y línea en la que empieza.
+    pending: String,
+    pending_line: usize,
+    /// CSV: atributo de cada columna de la cabecera (None hasta leerla).
+    header: Option<Vec<Option<String>>>,
+    rows: Vec<ImportRow>,
+    issues: Vec<ImportIssue>,
+}
+
+impl ImportReader {
+    pub fn new(format: ImportFormat, delimiter: char, mapping: ColumnMapping) -> DomainResult<Self> {
+        if format == ImportFormat::Csv && !CSV_DELIMITERS.contains(&delimiter) {
+            return Err(DomainError::ValidationError(format!(
+                "separador CSV no válido: {:?} (admitidos: ',', ';' y tabulador)", delimiter
+            )));
+        }
+        Ok(Self {
+            format,
+            delimiter,
+            mapping,
+            line: 0,
+            pending: String::new(),
+            pending_line: 0,
+            header: None,
+            rows: Vec::new(),
+            issues: Vec::new(),
+        })
+    }
+
+    /// Procesa la siguiente línea del archivo (se admite el final CRLF).
+    pub fn push_line(&mut self, text: &str) -> DomainResult<()> {
+        self.line += 1;
+        let text = text.strip_suffix('\r').unwrap_or(text);
+        let text = match self.line {
+            1 => text.strip_prefix('\u{feff}').unwrap_or(text),
+            _ => text,
+        };
+        match self.format {
+            ImportFormat::Csv => self.push_csv_line(text)?,
+            ImportFormat::Ndjson => self.push_ndjson_line(text),
+        }
+        if self.rows.len() + self.issues.len() > MAX_IMPORT_ROWS {
+            return Err(DomainError::ValidationError(format!(
+                "el archivo supera el máximo de {} filas por importación", MAX_IMPORT_ROWS
+            )));
+        }
+        Ok(())
+    }
+
+    /// Termina la lectura y devuelve las filas y los problemas encontrados.
+    pub fn finish(self) -> DomainResult<(Vec<ImportRow>, Vec<ImportIssue>)> {
+        if self.format == ImportFormat::Csv {
+            if !self.pending.is_empty() {
+                return Err(DomainError::ValidationError(format!(
+                    "CSV no válido: la comilla abierta en la línea {} no se cierra", self.pending_line
+                )));
+            }
+            if self.header.is_none() {
+                return Err(DomainError::ValidationError("el archivo CSV está vacío (falta la cabecera)".to_string()));
+            }
+        }
+        Ok((self.rows, self.issues))
+    }
+
+    fn push_csv_line(&mut self, text: &str) -> DomainResult<()> {
+        if self.pending.is_empty() {
+            self.pending_line = self.line;
+        } else {
+            self.pending.push('\n');
+        }
+        self.pending.push_str(text);
+        // Con un número impar de comillas, un campo sigue abierto en la línea siguiente
+        if self.pending.matches('"').count() % 2 == 1 {
+            return Ok(());
+        }
+
+        let text = std::mem::take(&mut self.pending);
+        for record in parse_csv(&text, self.delimiter)? {
+            let line = self.pending_line + record.line - 1;
+            match &self.header {
+                None => self.header = Some(self.header_attributes(&record.fields)?),
+                Some(attributes) if record.fields.len() != attributes.len() => self.issues.push(ImportIssue {
+                    line,
+                    column: None,
+                    reason: format!("se esperaban {} campos y la fila tiene {}", attributes.len(), record.fields.len()),
+                }),
+                Some(attributes) => {
+                    let values = attributes
+                        .iter()
+                        .zip(record.fields)
+                        .filter(|(_, value)| !value.is_empty())
+                        .filter_map(|(attribute, value)| attribute.clone().map(|a| (a, Value::String(value))))
+                        .collect();
+                    self.rows.push(ImportRow { line, values });
+                },
+            }
+        }
+        Ok(())
+    }
+
+    /// Atributo de cada columna de la cabecera CSV.
+    fn header_attributes(&self, fields: &[String]) -> DomainResult<Vec<Option<String>>> {
+        let mut attributes = Vec::with_capacity(fields.len());
+        let mut seen = HashSet::new();
+        for column in fields {
+            let column = column.trim();
+            if column.is_empty() {
+                return Err(DomainError::ValidationError("la cabecera CSV contiene una columna sin nombre".to_string()));
+            }
+            let attribute = self.mapping.attribute_for(column).map(str::to_string);
+            if let Some(attribute) = &attribute {
+                if !seen.insert(attribute.clone()) {
+                    return Err(DomainError::ValidationError(format!(
+                        "la cabecera CSV asigna varias columnas al atributo '{}'", attribute
+                    )));
+                }
+            }
+            attributes.push(attribute);
+        }
+        Ok(attributes)
+    }
+
+    fn push_ndjson_line(&mut self, text: &str) {
+        let line = self.line;
+        if text.trim().is_empty() {
+            return;
+        }
+        let object = match serde_json::from_str::<Value>(text) {
+            Ok(Value::Object(object)) => object,
+            Ok(_) => {
+                self.issues.push(ImportIssue { line, column: None, reason: "la línea no es un objeto JSON".to_string() });
+                return;
+            },
+            Err(e) => {
+                self.issues.push(ImportIssue { line, column: None, reason: format!("JSON no válido: {}", e) });
+                return;
+            },
+        };
+
+        let mut values = Map::new();
+        let mut duplicated = None;
+        for (key, value) in object {
+            if let Some(attribute) = self.mapping.attribute_for(&key).map(str::to_string) {
+                if values.insert(attribute.clone(), value).is_some() {
+                    duplicated = Some((key, attribute));
+                }
+            }
+        }
+        match duplicated {
+            Some((key, attribute)) => self.issues.push(ImportIssue {
+                line,
+                column: Some(key),
+                reason: format!("varias claves se asignan al atributo '{}'", attribute),
+            }),
+            None => self.rows.push(ImportRow { line, values }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn mapping(pairs: &[(&str, &str)]) -> ColumnMapping {
+        ColumnMapping::new(pairs.iter().map(|(c, a)| (c.to_string(), a.to_string())).collect()).unwrap()
+    }
+
+    #[test]
+    fn test_read_csv_rows_applies_mapping() {
+        let content = "Full Name,age,internal\nAnn,31,x\nBob,,y\nbroken\n";
+        let mapping = mapping(&[("Full Name", "name"), ("internal", "")]);
+        let (rows, issues) = read_rows(ImportFormat::Csv, content, ',', &mapping).unwrap();
+
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].line, 2);
+        assert_eq!(Value::Object(rows[0].values.clone()), json!({"name": "Ann", "age": "31"}));
+        assert_eq!(Value::Object(rows[1].values.clone()), json!({"name": "Bob"}));
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].line, 4);
+        assert_eq!(mapping.column_for("name"), "Full Name");
+        assert_eq!(mapping.column_for("age"), "age");
+
+        assert!(read_rows(ImportFormat::Csv, "", ',', &mapping).is_err());
+        assert!(read_rows(ImportFormat::Csv, "a,b,a\n1,2,3", ',', &ColumnMapping::default()).is_err());
+    }
+
+    #[test]
+    fn test_read_ndjson_rows_reports_bad_lines() {
+        let content = "{\"name\": \"Ann\", \"age\": 31}\n\n[1, 2]\n{\"name\": \n{\"nombre\": \"Bob\", \"name\": \"B\"}\n";
+        let mapping = mapping(&[("nombre", "name")]);
+        let (rows, issues) = read_rows(ImportFormat::Ndjson, content, ',', &mapping).unwrap();
+
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].values.get("age"), Some(&json!(31)));
+        let lines: Vec<usize> = issues.iter().map(|i| i.line).collect();
+        assert_eq!(lines, vec![3, 4, 5]);
+    }
+
+    #[test]
+    fn test_import_reader_joins_quoted_lines_and_caps_rows() {
+        let mut reader = ImportReader::new(ImportFormat::Csv, ',', ColumnMapping::default()).unwrap();
+        for text in ["\u{feff}name,notes\r", "bob,\"two", "lines\"", "", "ann,"] {
+            reader.push_line(text).unwrap();
+        }
+        let (rows, issues) = reader.finish().unwrap();
+        assert!(issues.is_empty());
+        assert_eq!(rows.iter().map(|r| r.line).collect::<Vec<_>>(), vec![2, 5]);
+        assert_eq!(Value::Object(rows[0].values.clone()), json!({"name": "bob", "notes": "two\nlines"}));
+
+        let mut reader = ImportReader::new(ImportFormat::Csv, ',', ColumnMapping::default()).unwrap();
+        reader.push_line("name").unwrap();
+        reader.push_line("\"open").unwrap();
+        assert!(reader.finish().is_err());
+
+        let mut reader = ImportReader::new(ImportFormat::Ndjson, ',', ColumnMapping::default()).unwrap();
+        let capped = (0..=MAX_IMPORT_ROWS).map(|_| reader.push_line("{}")).find(Result::is_err);
+        assert!(capped.is_some());
+    }
+
+    #[test]
+    fn test_mapping_and_options() {
+        let duplicated = [("a", "x"), ("b", "x")].iter().map(|(c, a)| (c.to_string(), a.to_string())).collect();
+        assert!(ColumnMapping::new(duplicated).is_err());
+        assert_eq!(ImportFormat::from_name("JSONL").unwrap(), ImportFormat::Ndjson);
+        assert!(ImportFormat::from_name("xlsx").is_err());
+        assert_eq!(ImportErrorPolicy::from_name("skip_invalid").unwrap(), ImportErrorPolicy::SkipInvalid);
+        assert!(ImportErrorPolicy::from_name("ignore").is_err());
+    }
+}
@@ -0,0 +1,71 @@
+// src/Domain/record_queries/cursor.rs
+//
+// Cursor opaco de paginación por clave (keyset): guarda los valores de
+// ordenación y el id del último registro devuelto. Se codifica como JSON
+// en base64 url-safe para que el cliente lo reenvíe sin interpretarlo.
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::Domain::errors::{DomainError, DomainResult};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordCursor {
+    /// Firma de la ordenación con la que se generó (ver `sort_signature`).
+    #[serde(rename = "s")]
+    pub sort: String,
+    /// Valores de las claves de ordenación del último registro, como texto de Postgres.
+    #[serde(rename = "v")]
+    pub values: Vec<Option<String>>,
+    /// Id del último registro (desempate final).
+    #[serde(rename = "i")]
+    pub id: Uuid,
+}
+
+impl RecordCursor {
+    pub fn encode(&self) -> String {
+        // La serialización de un struct con tipos simples no puede fallar
+        let json = serde_json::to_vec(self).unwrap_or_default();
+        URL_SAFE_NO_PAD.encode(json)
+    }
+
+    /// Decodifica el cursor y comprueba que corresponda a la ordenación actual.
+    pub fn decode(token: &str, sort_signature: &str, key_count: usize) -> DomainResult<Self> {
+        let invalid = || DomainError::ValidationError("el cursor 'after' no es válido".to_string());
+
+        let bytes = URL_SAFE_NO_PAD.decode(token.trim()).map_err(|_| invalid())?;
+        let cursor: RecordCursor = serde_json::from_slice(&bytes).map_err(|_| invalid())?;
+        if cursor.values.len() != key_count {
+            return Err(invalid());
+        }
+        if cursor.sort != sort_signature {
+            return Err(DomainError::ValidationError(
+                "el cursor 'after' se generó con otra ordenación; vuelva a la primera página".to_string(),
+            ));
+        }
+        Ok(cursor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cursor_round_trip_and_sort_check() {
+        let cursor = RecordCursor {
+            sort: "-amount,created_at".to_string(),
+            values: vec![None, Some("2024-01-01 10:00:00+00".to_string())],
+            id: Uuid::new_v4(),
+        };
+        let token = cursor.encode();
+        assert!(token.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_'));
+
+        assert_eq!(RecordCursor::decode(&token, "-amount,created_at", 2).unwrap(), cursor);
+        assert!(RecordCursor::decode(&token, "created_at", 2).is_err());
+        assert!(RecordCursor::decode(&token, "-amount,created_at", 1).is_err());
+        assert!(RecordCursor::decode("no-es-un-cursor", "created_at", 1).is_err());
+    }
+}
@@ -0,0 +1,419 @@
+// src/Domain/record_queries/filter.rs
+//
+// Sintaxis de filtro del listado de registros, sobre nombres de atributo:
+//
+//   status eq 'open' and (amount gt 100 or priority in ('high', 'urgent'))
+//   not name startswith 'tmp' and closed_at is null
+//
+// Operadores: eq, ne, lt, le, gt, ge, in, contains, startswith, is null, is not null.
+// Literales: 'texto' (comilla escapada como ''), números, true y false.
+// Los nombres con espacios u otros símbolos se escriben entre comillas dobles.
+
+use serde_json::{Number, Value};
+
+use crate::Domain::errors::{DomainError, DomainResult};
+
+/// Número máximo de condiciones de un filtro.
+pub const MAX_FILTER_CONDITIONS: usize = 50;
+
+/// Anidamiento máximo de paréntesis y `not` de un filtro.
+pub const MAX_FILTER_DEPTH: usize = 20;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FilterOperator {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    In,
+    Contains,
+    StartsWith,
+    IsNull,
+    IsNotNull,
+}
+
+impl FilterOperator {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FilterOperator::Eq => "eq",
+            FilterOperator::Ne => "ne",
+            FilterOperator::Lt => "lt",
+            FilterOperator::Le => "le",
+            FilterOperator::Gt => "gt",
+            FilterOperator::Ge => "ge",
+            FilterOperator::In => "in",
+            FilterOperator::Contains => "contains",
+            FilterOperator::StartsWith => "startswith",
+            FilterOperator::IsNull => "is null",
+            FilterOperator::IsNotNull => "is not null",
+        }
+    }
+
+    fn from_keyword(keyword: &str) -> Option<Self> {
+        match keyword {
+            "eq" => Some(FilterOperator::Eq),
+            "ne" => Some(FilterOperator::Ne),
+            "lt" => Some(FilterOperator::Lt),
+            "le" => Some(FilterOperator::Le),
+            "gt" => Some(FilterOperator::Gt),
+            "ge" => Some(FilterOperator::Ge),
+            "in" => Some(FilterOperator::In),
+            "contains" => Some(FilterOperator::Contains),
+            "startswith" => Some(FilterOperator::StartsWith),
+            _ => None,
+        }
+    }
+}
+
+/// Condición sobre un campo, con sus operandos tal como se escribieron
+/// (ninguno en `is null`, uno o varios en `in`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct FilterCondition {
+    pub field: String,
+    pub operator: FilterOperator,
+    pub operands: Vec<Value>,
+}
+
+/// Expresión de filtro sin resolver (los campos son nombres).
+#[derive(Debug, Clone, PartialEq)]
+pub enum FilterExpr {
+    And(Vec<FilterExpr>),
+    Or(Vec<FilterExpr>),
+    Not(Box<FilterExpr>),
+    Condition(FilterCondition),
+}
+
+impl FilterExpr {
+    fn count_conditions(&self) -> usize {
+        match self {
+            FilterExpr::And(items) | FilterExpr::Or(items) => items.iter().map(FilterExpr::count_conditions).sum(),
+            FilterExpr::Not(inner) => inner.count_conditions(),
+            FilterExpr::Condition(_) => 1,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Word(String),
+    Quoted(String),
+    Literal(Value),
+    OpenParen,
+    CloseParen,
+    Comma,
+}
+
+/// Analiza la expresión de filtro. `not` tiene más prioridad que `and`, y `and` más que `or`.
+pub fn parse_filter(input: &str) -> DomainResult<FilterExpr> {
+    let tokens = tokenize(input)?;
+    if tokens.is_empty() {
+        return Err(invalid("el filtro está vacío"));
+    }
+    let mut parser = Parser { tokens, position: 0, depth: 0 };
+    let expr = parser.parse_or()?;
+    if let Some(token) = parser.peek() {
+        return Err(invalid(&format!("se esperaba 'and' u 'or' y se encontró {}", describe(token))));
+    }
+    if expr.count_conditions() > MAX_FILTER_CONDITIONS {
+        return Err(invalid(&format!("el filtro admite como máximo {} condiciones", MAX_FILTER_CONDITIONS)));
+    }
+    Ok(expr)
+}
+
+fn tokenize(input: &str) -> DomainResult<Vec<Token>> {
+    let chars: Vec<char> = input.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        match c {
+            c if c.is_whitespace() => i += 1,
+            '(' => { tokens.push(Token::OpenParen); i += 1; },
+            ')' => { tokens.push(Token::CloseParen); i += 1; },
+            ',' => { tokens.push(Token::Comma); i += 1; },
+            '\'' | '"' => {
+                let (text, next) = read_quoted(&chars, i)?;
+                tokens.push(if c == '\'' { Token::Literal(Value::String(text)) } else { Token::Quoted(text) });
+                i = next;
+            },
+            c if c.is_ascii_digit() || c == '-' || c == '+' || c == '.' => {
+                let start = i;
+                i += 1;
+                while i < chars.len() && (chars[i].is_ascii_alphanumeric() || matches!(chars[i], '.' | '-' | '+')) {
+                    i += 1;
+                }
+                let text: String = chars[start..i].iter().collect();
+                tokens.push(Token::Literal(parse_number(&text)?));
+            },
+            c if c.is_alphabetic() || c == '_' => {
+                let start = i;
+                while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                    i += 1;
+                }
+                let word: String = chars[start..i].iter().collect();
+                match word.to_lowercase().as_str() {
+                    "true" => tokens.push(Token::Literal(Value::Bool(true))),
+                    "false" => tokens.push(Token::Literal(Value::Bool(false))),
+                    _ => tokens.push(Token::Word(word)),
+                }
+            },
+            other => return Err(invalid(&format!("carácter inesperado '{}'", other))),
+        }
+    }
+    Ok(tokens)
+}
+
+/// Lee un texto entre comillas (simples o dobles); la comilla se escapa duplicándola.
+fn read_quoted(chars: &[char], start: usize) -> DomainResult<(String, usize)> {
+    let quote = chars[start];
+    let mut text = String::new();
+    let mut i = start + 1;
+    while i < chars.len() {
+        if chars[i] == quote {
+            if chars.get(i + 1) == Some(&quote) {
+                text.push(quote);
+                i += 2;
+                continue;
+            }
+            return Ok((text, i + 1));
+        }
+        text.push(chars[i]);
+        i += 1;
+    }
+    Err(invalid("falta cerrar las comillas"))
+}
+
+fn parse_number(text: &str) -> DomainResult<Value> {
+    if let Ok(integer) = text.parse::<i64>() {
+        return Ok(Value::Number(integer.into()));
+    }
+    // Los decimales se conservan como texto para no perder precisión
+    // (la conversión al tipo del atributo la hace `to_storage_text`)
+    match text.parse::<f64>().ok().and_then(Number::from_f64) {
+        Some(_) => Ok(Value::String(text.to_string())),
+        None => Err(invalid(&format!("'{}' no es un número válido", text))),
+    }
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    position: usize,
+    /// Anidamiento actual; se limita durante el análisis para no agotar la pila.
+    depth: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    }
+
+    fn peek_keyword(&self, keyword: &str) -> bool {
+        matches!(self.peek(), Some(Token::Word(w)) if w.eq_ignore_ascii_case(keyword))
+    }
+
+    fn expect_keyword(&mut self, keyword: &str) -> DomainResult<()> {
+        if self.peek_keyword(keyword) {
+            self.position += 1;
+            return Ok(());
+        }
+        Err(invalid(&format!("se esperaba '{}' y se encontró {}", keyword, self.describe_next())))
+    }
+
+    fn describe_next(&self) -> String {
+        self.peek().map(describe).unwrap_or_else(|| "el final del filtro".to_string())
+    }
+
+    fn parse_or(&mut self) -> DomainResult<FilterExpr> {
+        let mut items = vec![self.parse_and()?];
+        while self.peek_keyword("or") {
+            self.position += 1;
+            items.push(self.parse_and()?);
+        }
+        Ok(if items.len() == 1 { items.remove(0) } else { FilterExpr::Or(items) })
+    }
+
+    fn parse_and(&mut self) -> DomainResult<FilterExpr> {
+        let mut items = vec![self.parse_unary()?];
+        while self.peek_keyword("and") {
+            self.position += 1;
+            items.push(self.parse_unary()?);
+        }
+        Ok(if items.len() == 1 { items.remove(0) } else { FilterExpr::And(items) })
+    }
+
+    fn parse_unary(&mut self) -> DomainResult<FilterExpr> {
+        if self.peek_keyword("not") {
+            self.position += 1;
+            self.enter()?;
+            let expr = FilterExpr::Not(Box::new(self.parse_unary()?));
+            self.depth -= 1;
+            return Ok(expr);
+        }
+        if self.peek() == Some(&Token::OpenParen) {
+            self.position += 1;
+            self.enter()?;
+            let expr = self.parse_or()?;
+            if self.next() != Some(Token::CloseParen) {
+                return Err(invalid("falta cerrar un paréntesis"));
+            }
+            self.depth -= 1;
+            return Ok(expr);
+        }
+        self.parse_condition()
+    }
+
+    fn enter(&mut self) -> DomainResult<()> {
+        if self.depth >= MAX_FILTER_DEPTH {
+            return Err(invalid(&format!("el filtro admite como máximo {} niveles de anidamiento", MAX_FILTER_DEPTH)));
+        }
+        self.depth += 1;
+        Ok(())
+    }
+
+    fn parse_condition(&mut self) -> DomainResult<FilterExpr> {
+        let field = match self.next() {
+            Some(Token::Word(name)) | Some(Token::Quoted(name)) => name,
+            other => {
+                return Err(invalid(&format!(
+                    "se esperaba un nombre de atributo y se encontró {}",
+                    other.as_ref().map(describe).unwrap_or_else(|| "el final del filtro".to_string())
+                )));
+            },
+        };
+
+        let keyword = match self.next() {
+            Some(Token::Word(word)) => word.to_lowercase(),
+            other => {
+                return Err(invalid(&format!(
+                    "se esperaba un operador después de '{}' y se encontró {}",
+                    field,
+                    other.as_ref().map(describe).unwrap_or_else(|| "el final del filtro".to_string())
+                )));
+            },
+        };
+
+        if keyword == "is" {
+            let operator = if self.peek_keyword("not") {
+                self.position += 1;
+                FilterOperator::IsNotNull
+            } else {
+                FilterOperator::IsNull
+            };
+            self.expect_keyword("null")?;
+            return Ok(FilterExpr::Condition(FilterCondition { field, operator, operands: Vec::new() }));
+        }
+
+        let operator = FilterOperator::from_keyword(&keyword)
+            .ok_or_else(|| invalid(&format!("operador desconocido '{}' (admitidos: eq, ne, lt, le, gt, ge, in, contains, startswith, is null)", keyword)))?;
+
+        let operands = if operator == FilterOperator::In {
+            if self.next() != Some(Token::OpenParen) {
+                return Err(invalid(&format!("'{} in' necesita una lista entre paréntesis", field)));
+            }
+            let mut operands = vec![self.parse_literal(&field)?];
+            loop {
+                match self.next() {
+                    Some(Token::Comma) => operands.push(self.parse_literal(&field)?),
+                    Some(Token::CloseParen) => break,
+                    _ => return Err(invalid(&format!("la lista de '{} in' no está bien cerrada", field))),
+                }
+            }
+            operands
+        } else {
+            vec![self.parse_literal(&field)?]
+        };
+
+        Ok(FilterExpr::Condition(FilterCondition { field, operator, operands }))
+    }
+
+    fn parse_literal(&mut self, field: &str) -> DomainResult<Value> {
+        match self.next() {
+            Some(Token::Literal(value)) => Ok(value),
+            Some(Token::Word(word)) if word.eq_ignore_ascii_case("null") => {
+                Err(invalid(&format!("para comparar '{}' con null use 'is null' o 'is not null'", field)))
+            },
+            other => Err(invalid(&format!(
+                "se esperaba un valor para '{}' y se encontró {}",
+                field,
+                other.as_ref().map(describe).unwrap_or_else(|| "el final del filtro".to_string())
+            ))),
+        }
+    }
+}
+
+fn describe(token: &Token) -> String {
+    match token {
+        Token::Word(word) => format!("'{}'", word),
+        Token::Quoted(name) => format!("\"{}\"", name),
+        Token::Literal(value) => value.to_string(),
+        Token::OpenParen => "'('".to_string(),
+        Token::CloseParen => "')'".to_string(),
+        Token::Comma => "','".to_string(),
+    }
+}
+
+fn invalid(message: &str) -> DomainError {
+    DomainError::ValidationError(format!("filtro no válido: {}", message))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn condition(field: &str, operator: FilterOperator, operands: Vec<Value>) -> FilterExpr {
+        FilterExpr::Condition(FilterCondition { field: field.to_string(), operator, operands })
+    }
+
+    #[test]
+    fn test_parse_filter_respects_precedence() {
+        let expr = parse_filter("status eq 'open' and amount gt 100 or not closed_at is null").unwrap();
+        assert_eq!(expr, FilterExpr::Or(vec![
+            FilterExpr::And(vec![
+                condition("status", FilterOperator::Eq, vec![json!("open")]),
+                condition("amount", FilterOperator::Gt, vec![json!(100)]),
+            ]),
+            FilterExpr::Not(Box::new(condition("closed_at", FilterOperator::IsNull, vec![]))),
+        ]));
+    }
+
+    #[test]
+    fn test_parse_filter_reads_lists_quotes_and_decimals() {
+        let expr = parse_filter("\"due date\" is not null and name in ('O''Brien', 'Smith') and price le 10.50").unwrap();
+        assert_eq!(expr, FilterExpr::And(vec![
+            condition("due date", FilterOperator::IsNotNull, vec![]),
+            condition("name", FilterOperator::In, vec![json!("O'Brien"), json!("Smith")]),
+            condition("price", FilterOperator::Le, vec![json!("10.50")]),
+        ]));
+    }
+
+    #[test]
+    fn test_parse_filter_rejects_malformed_expressions() {
+        assert!(parse_filter("").is_err());
+        assert!(parse_filter("status equals 'open'").is_err());
+        assert!(parse_filter("status eq 'open").is_err());
+        assert!(parse_filter("(status eq 'open'").is_err());
+        assert!(parse_filter("status eq null").is_err());
+        assert!(parse_filter("status eq 'open' amount gt 1").is_err());
+        assert!(parse_filter("amount gt 1e999").is_err());
+    }
+
+    #[test]
+    fn test_parse_filter_limits_nesting() {
+        let parens = format!("{}a eq 1{}", "(".repeat(7000), ")".repeat(7000));
+        assert!(parse_filter(&parens).is_err());
+        assert!(parse_filter(&format!("{}a eq 1", "not ".repeat(7000))).is_err());
+
+        let nested = format!("{}a eq 1{}", "(".repeat(MAX_FILTER_DEPTH), ")".repeat(MAX_FILTER_DEPTH));
+        assert!(parse_filter(&nested).is_ok());
+        assert!(parse_filter(&format!("not {}", nested)).is_err());
+    }
+}
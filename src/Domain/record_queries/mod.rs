@@ -0,0 +1,16 @@
+// src/Domain/record_queries/mod.rs
+// Consultas sobre registros: sintaxis de filtro, ordenación, búsqueda de texto
+// y cursores de paginación.
+
+pub mod cursor;
+pub mod filter;
+pub mod query;
+pub mod search;
+
+pub use cursor::RecordCursor;
+pub use filter::{parse_filter, FilterExpr, FilterOperator};
+pub use query::{
+    parse_sort, resolve_filter, sort_signature, QueryAttribute, QueryField, RecordQuery, ResolvedCondition,
+    ResolvedFilter, SortDirection, SortKey, SystemField,
+};
+pub use search::{validate_search_language, RecordSearch, DEFAULT_SEARCH_LANGUAGE, SEARCH_LANGUAGES};
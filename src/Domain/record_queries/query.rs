@@ -0,0 +1,296 @@
+// src/Domain/record_queries/query.rs
+//
+// Resolución de filtros y ordenación contra los atributos de la entidad:
+// los nombres se convierten en campos tipados y los operandos se validan
+// y normalizan con la columna de almacenamiento de cada atributo.
+
+use uuid::Uuid;
+
+use crate::Domain::errors::{DomainError, DomainResult};
+use crate::Domain::records::{to_storage_text, StorageColumn};
+
+use super::cursor::RecordCursor;
+use super::filter::{FilterCondition, FilterExpr, FilterOperator};
+
+/// Número máximo de claves de ordenación.
+pub const MAX_SORT_KEYS: usize = 5;
+
+/// Columnas propias de `tuplas` que se pueden filtrar y ordenar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SystemField {
+    Id,
+    CreatedAt,
+    UpdatedAt,
+    CreatedBy,
+    UpdatedBy,
+    Status,
+}
+
+impl SystemField {
+    pub const ALL: [SystemField; 6] = [
+        SystemField::Id,
+        SystemField::CreatedAt,
+        SystemField::UpdatedAt,
+        SystemField::CreatedBy,
+        SystemField::UpdatedBy,
+        SystemField::Status,
+    ];
+
+    /// Nombre del campo en la sintaxis de consulta (coincide con la columna de `tuplas`).
+    pub fn name(&self) -> &'static str {
+        match self {
+            SystemField::Id => "id",
+            SystemField::CreatedAt => "created_at",
+            SystemField::UpdatedAt => "updated_at",
+            SystemField::CreatedBy => "created_by",
+            SystemField::UpdatedBy => "updated_by",
+            SystemField::Status => "status",
+        }
+    }
+
+    /// Columna tipada equivalente, usada para validar operandos y castear parámetros.
+    pub fn storage(&self) -> StorageColumn {
+        match self {
+            SystemField::Id | SystemField::CreatedBy | SystemField::UpdatedBy => StorageColumn::Uuid,
+            SystemField::CreatedAt | SystemField::UpdatedAt => StorageColumn::DateTime,
+            SystemField::Status => StorageColumn::Integer,
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        SystemField::ALL.into_iter().find(|f| f.name() == name)
+    }
+}
+
+/// Atributo de la entidad disponible para consultas.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueryAttribute {
+    pub id: Uuid,
+    pub name: String,
+    pub column: StorageColumn,
+}
+
+/// Campo resuelto: una columna de `tuplas` o un atributo almacenado en `attribute_values`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum QueryField {
+    System(SystemField),
+    Attribute { attribute_id: Uuid, column: StorageColumn },
+}
+
+impl QueryField {
+    pub fn storage(&self) -> StorageColumn {
+        match self {
+            QueryField::System(field) => field.storage(),
+            QueryField::Attribute { column, .. } => *column,
+        }
+    }
+}
+
+/// Condición resuelta; los operandos ya están en su representación de texto de almacenamiento.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResolvedCondition {
+    pub field: QueryField,
+    pub operator: FilterOperator,
+    pub values: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ResolvedFilter {
+    And(Vec<ResolvedFilter>),
+    Or(Vec<ResolvedFilter>),
+    Not(Box<ResolvedFilter>),
+    Condition(ResolvedCondition),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortDirection {
+    Asc,
+    Desc,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SortKey {
+    pub name: String,
+    pub field: QueryField,
+    pub direction: SortDirection,
+}
+
+/// Consulta completa de una página de registros.
+/// El orden siempre se desempata por `id`, que es lo que hace estable el cursor.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecordQuery {
+    pub filter: Option<ResolvedFilter>,
+    pub sort: Vec<SortKey>,
+    pub limit: i64,
+    pub after: Option<RecordCursor>,
+}
+
+fn resolve_field(name: &str, attributes: &[QueryAttribute]) -> DomainResult<QueryField> {
+    // Un atributo con el mismo nombre que una columna de sistema tiene prioridad
+    if let Some(attribute) = attributes.iter().find(|a| a.name == name) {
+        return Ok(QueryField::Attribute { attribute_id: attribute.id, column: attribute.column });
+    }
+    SystemField::from_name(name)
+        .map(QueryField::System)
+        .ok_or_else(|| DomainError::ValidationError(format!("el atributo '{}' no existe en la entidad", name)))
+}
+
+/// Resuelve el filtro analizado contra los atributos de la entidad.
+pub fn resolve_filter(expr: &FilterExpr, attributes: &[QueryAttribute]) -> DomainResult<ResolvedFilter> {
+    let resolve_all = |items: &[FilterExpr]| {
+        items.iter().map(|item| resolve_filter(item, attributes)).collect::<DomainResult<Vec<_>>>()
+    };
+    Ok(match expr {
+        FilterExpr::And(items) => ResolvedFilter::And(resolve_all(items)?),
+        FilterExpr::Or(items) => ResolvedFilter::Or(resolve_all(items)?),
+        FilterExpr::Not(inner) => ResolvedFilter::Not(Box::new(resolve_filter(inner, attributes)?)),
+        FilterExpr::Condition(condition) => ResolvedFilter::Condition(resolve_condition(condition, attributes)?),
+    })
+}
+
+fn resolve_condition(condition: &FilterCondition, attributes: &[QueryAttribute]) -> DomainResult<ResolvedCondition> {
+    let field = resolve_field(&condition.field, attributes)?;
+    let column = field.storage();
+    let operator = condition.operator;
+
+    let supported = match operator {
+        FilterOperator::IsNull | FilterOperator::IsNotNull => true,
+        FilterOperator::Contains | FilterOperator::StartsWith => {
+            matches!(column, StorageColumn::String | StorageColumn::Text)
+        },
+        FilterOperator::Eq | FilterOperator::Ne | FilterOperator::In => {
+            !matches!(column, StorageColumn::Json | StorageColumn::Binary)
+        },
+        FilterOperator::Lt | FilterOperator::Le | FilterOperator::Gt | FilterOperator::Ge => {
+            !matches!(column, StorageColumn::Boolean | StorageColumn::Json | StorageColumn::Binary)
+        },
+    };
+    if !supported {
+        return Err(DomainError::ValidationError(format!(
+            "el operador '{}' no se puede usar con '{}' (columna {})",
+            operator.as_str(), condition.field, column.column_name()
+        )));
+    }
+
+    let mut values = Vec::with_capacity(condition.operands.len());
+    for operand in &condition.operands {
+        let text = to_storage_text(column, operand).map_err(|e| match e {
+            DomainError::ValidationError(message) => {
+                DomainError::ValidationError(format!("valor no válido para '{}': {}", condition.field, message))
+            },
+            other => other,
+        })?;
+        // Los literales nunca son null (el analizador no lo permite)
+        values.extend(text);
+    }
+
+    Ok(ResolvedCondition { field, operator, values })
+}
+
+/// Analiza la ordenación (`-created_at,name`; el prefijo `-` indica descendente).
+/// Sin ordenación explícita se ordena por fecha de creación.
+pub fn parse_sort(input: Option<&str>, attributes: &[QueryAttribute]) -> DomainResult<Vec<SortKey>> {
+    let input = input.map(str::trim).filter(|s| !s.is_empty()).unwrap_or(SystemField::CreatedAt.name());
+
+    let mut keys: Vec<SortKey> = Vec::new();
+    for part in input.split(',') {
+        let part = part.trim();
+        let (direction, name) = match part.strip_prefix('-') {
+            Some(name) => (SortDirection::Desc, name.trim()),
+            None => (SortDirection::Asc, part.strip_prefix('+').unwrap_or(part).trim()),
+        };
+        if name.is_empty() {
+            return Err(DomainError::ValidationError("ordenación no válida: hay una clave vacía".to_string()));
+        }
+        if keys.iter().any(|k| k.name == name) {
+            return Err(DomainError::ValidationError(format!("ordenación no válida: '{}' aparece más de una vez", name)));
+        }
+        let field = resolve_field(name, attributes)?;
+        if matches!(field.storage(), StorageColumn::Json | StorageColumn::Binary) {
+            return Err(DomainError::ValidationError(format!(
+                "no se puede ordenar por '{}' (columna {})", name, field.storage().column_name()
+            )));
+        }
+        keys.push(SortKey { name: name.to_string(), field, direction });
+    }
+
+    if keys.len() > MAX_SORT_KEYS {
+        return Err(DomainError::ValidationError(format!("la ordenación admite como máximo {} claves", MAX_SORT_KEYS)));
+    }
+    Ok(keys)
+}
+
+/// Forma canónica de la ordenación; el cursor la guarda para rechazarlo si la ordenación cambia.
+pub fn sort_signature(keys: &[SortKey]) -> String {
+    keys.iter()
+        .map(|k| match k.direction {
+            SortDirection::Asc => k.name.clone(),
+            SortDirection::Desc => format!("-{}", k.name),
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Domain::record_queries::filter::parse_filter;
+
+    fn attributes() -> Vec<QueryAttribute> {
+        vec![
+            QueryAttribute { id: Uuid::new_v4(), name: "status".to_string(), column: StorageColumn::String },
+            QueryAttribute { id: Uuid::new_v4(), name: "amount".to_string(), column: StorageColumn::Integer },
+            QueryAttribute { id: Uuid::new_v4(), name: "paid".to_string(), column: StorageColumn::Boolean },
+        ]
+    }
+
+    #[test]
+    fn test_resolve_filter_uses_attribute_storage() {
+        let attributes = attributes();
+        let expr = parse_filter("status eq 'open' and amount in (100, '200') and created_at is null").unwrap();
+        let ResolvedFilter::And(items) = resolve_filter(&expr, &attributes).unwrap() else { panic!("se esperaba and") };
+
+        // `status` es un atributo, aunque coincida con una columna de sistema
+        assert_eq!(items[0], ResolvedFilter::Condition(ResolvedCondition {
+            field: QueryField::Attribute { attribute_id: attributes[0].id, column: StorageColumn::String },
+            operator: FilterOperator::Eq,
+            values: vec!["open".to_string()],
+        }));
+        assert_eq!(items[1], ResolvedFilter::Condition(ResolvedCondition {
+            field: QueryField::Attribute { attribute_id: attributes[1].id, column: StorageColumn::Integer },
+            operator: FilterOperator::In,
+            values: vec!["100".to_string(), "200".to_string()],
+        }));
+        assert_eq!(items[2], ResolvedFilter::Condition(ResolvedCondition {
+            field: QueryField::System(SystemField::CreatedAt),
+            operator: FilterOperator::IsNull,
+            values: vec![],
+        }));
+    }
+
+    #[test]
+    fn test_resolve_filter_rejects_unknown_fields_and_mismatches() {
+        let attributes = attributes();
+        for filter in ["missing eq 1", "amount eq 'abc'", "amount contains '1'", "paid gt true", "id eq 'x'"] {
+            let expr = parse_filter(filter).unwrap();
+            assert!(
+                matches!(resolve_filter(&expr, &attributes), Err(DomainError::ValidationError(_))),
+                "{} debería rechazarse", filter
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_sort_and_signature() {
+        let attributes = attributes();
+        let keys = parse_sort(Some("-amount, created_at"), &attributes).unwrap();
+        assert_eq!(keys[0].direction, SortDirection::Desc);
+        assert_eq!(keys[1].field, QueryField::System(SystemField::CreatedAt));
+        assert_eq!(sort_signature(&keys), "-amount,created_at");
+
+        assert_eq!(sort_signature(&parse_sort(None, &attributes).unwrap()), "created_at");
+        assert!(parse_sort(Some("amount,-amount"), &attributes).is_err());
+        assert!(parse_sort(Some("missing"), &attributes).is_err());
+        assert!(parse_sort(Some("amount,,paid"), &attributes).is_err());
+    }
+}
@@ -0,0 +1,97 @@
+// src/Domain/record_queries/search.rs
+//
+// Búsqueda de texto sobre los atributos string/text marcados como `is_searchable`.
+// El texto se interpreta con `websearch_to_tsquery` de Postgres: palabras sueltas,
+// "frases entre comillas", `or` y `-exclusión`.
+
+use crate::Domain::errors::{DomainError, DomainResult};
+
+use super::cursor::RecordCursor;
+
+/// Longitud máxima del texto de búsqueda.
+pub const MAX_SEARCH_LENGTH: usize = 500;
+
+/// Configuración de búsqueda por defecto de las entidades (sin stemming ni stopwords).
+pub const DEFAULT_SEARCH_LANGUAGE: &str = "simple";
+
+/// Configuraciones de búsqueda de texto incluidas en Postgres.
+pub const SEARCH_LANGUAGES: [&str; 29] = [
+    "simple", "arabic", "armenian", "basque", "catalan", "danish", "dutch", "english", "finnish",
+    "french", "german", "greek", "hindi", "hungarian", "indonesian", "irish", "italian", "lithuanian",
+    "nepali", "norwegian", "portuguese", "romanian", "russian", "serbian", "spanish", "swedish",
+    "tamil", "turkish", "yiddish",
+];
+
+/// Comprueba que la configuración de búsqueda sea una de las de Postgres.
+pub fn validate_search_language(language: &str) -> DomainResult<()> {
+    if SEARCH_LANGUAGES.contains(&language) {
+        return Ok(());
+    }
+    Err(DomainError::ValidationError(format!(
+        "search_language '{}' no es válido (admitidos: {})", language, SEARCH_LANGUAGES.join(", ")
+    )))
+}
+
+/// Búsqueda de una página de registros, ordenados por relevancia y después por `id`.
+/// En el cursor, `values` guarda la relevancia del último registro devuelto.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecordSearch {
+    pub text: String,
+    pub limit: i64,
+    pub after: Option<RecordCursor>,
+}
+
+impl RecordSearch {
+    /// Valida el texto y el cursor (que debe corresponder a la misma búsqueda).
+    pub fn new(text: &str, limit: i64, after: Option<&str>) -> DomainResult<Self> {
+        let text = text.trim();
+        if text.is_empty() {
+            return Err(DomainError::ValidationError("el texto de búsqueda 'q' no puede estar vacío".to_string()));
+        }
+        if text.chars().count() > MAX_SEARCH_LENGTH {
+            return Err(DomainError::ValidationError(format!(
+                "el texto de búsqueda admite como máximo {} caracteres", MAX_SEARCH_LENGTH
+            )));
+        }
+        let after = match after.filter(|a| !a.is_empty()) {
+            Some(token) => Some(RecordCursor::decode(token, &Self::signature(text), 1)?),
+            None => None,
+        };
+        Ok(Self { text: text.to_string(), limit, after })
+    }
+
+    /// Firma que el cursor guarda para no mezclar páginas de búsquedas distintas.
+    pub fn signature(text: &str) -> String {
+        format!("search:{}", text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    #[test]
+    fn test_validate_search_language() {
+        assert!(validate_search_language("spanish").is_ok());
+        assert!(validate_search_language(DEFAULT_SEARCH_LANGUAGE).is_ok());
+        assert!(validate_search_language("klingon").is_err());
+        assert!(validate_search_language("english; DROP TABLE tuplas").is_err());
+    }
+
+    #[test]
+    fn test_record_search_validates_text_and_cursor() {
+        assert!(RecordSearch::new("   ", 20, None).is_err());
+        assert!(RecordSearch::new(&"a".repeat(MAX_SEARCH_LENGTH + 1), 20, None).is_err());
+
+        let cursor = RecordCursor {
+            sort: RecordSearch::signature("invoice"),
+            values: vec![Some("0.1".to_string())],
+            id: Uuid::new_v4(),
+        };
+        let search = RecordSearch::new(" invoice ", 20, Some(&cursor.encode())).unwrap();
+        assert_eq!(search.text, "invoice");
+        assert_eq!(search.after, Some(cursor.clone()));
+        assert!(RecordSearch::new("receipt", 20, Some(&cursor.encode())).is_err());
+    }
+}
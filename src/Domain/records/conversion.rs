@@ -0,0 +1,215 @@
+// src/Domain/records/conversion.rs
+//
+// Conversión en la base de datos de los valores almacenados de un atributo cuando cambia
+// su tipo de dato. Las expresiones siguen las reglas de `to_storage_text` y de
+// `DataTypeStorage::check_value` para que un cambio de tipo acepte los mismos valores
+// que una escritura por la API, sin leer los valores en la aplicación.
+
+use crate::Domain::attribute_options::AttributeOption;
+use crate::Domain::data_types::DataTypeStorage;
+use crate::Domain::sql_identifiers::quote_literal;
+use super::storage::StorageColumn;
+
+/// Entero en texto (se admiten espacios alrededor, como en `str::trim`).
+const INTEGER_TEXT: &str = r"^\s*[+-]?[0-9]+\s*$";
+/// Número de coma flotante en texto, incluidos `inf` y `NaN` (sin distinguir mayúsculas).
+const FLOAT_TEXT: &str = r"^\s*[+-]?(([0-9]+\.?[0-9]*|\.[0-9]+)(e[+-]?[0-9]+)?|inf|infinity|nan)\s*$";
+/// Literal decimal simple (ver `is_decimal_literal`).
+const DECIMAL_TEXT: &str = r"^\s*[+-]?[0-9]+(\.[0-9]+)?\s*$";
+const RFC3339_TEXT: &str =
+    r"^[0-9]{4}-[0-9]{2}-[0-9]{2}[Tt ][0-9]{2}:[0-9]{2}:[0-9]{2}(\.[0-9]+)?([Zz]|[+-][0-9]{2}:[0-9]{2})$";
+const DATE_TEXT: &str = r"^[0-9]{4}-[0-9]{1,2}-[0-9]{1,2}$";
+const TIME_TEXT: &str = r"^[0-9]{1,2}:[0-9]{1,2}:[0-9]{1,2}(\.[0-9]+)?$";
+const BASE64_TEXT: &str = r"^([A-Za-z0-9+/]{4})*([A-Za-z0-9+/]{2}==|[A-Za-z0-9+/]{3}=)?$";
+
+/// Expresiones SQL de la conversión de una columna a un tipo de dato.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValueConversionSql {
+    /// Valor convertido, con el tipo de la columna de destino (NULL si no se puede convertir).
+    pub value: String,
+    /// Condición que cumplen los valores no nulos que no se pueden convertir o que no
+    /// cumplen los parámetros del tipo o, en las enumeraciones, las opciones activas.
+    pub failed: String,
+}
+
+/// Valor de `source` (una columna de tipo `column`) en la representación JSON de la API
+/// (binary en base64), que es la que reciben las reglas de conversión.
+pub fn value_json_expression(column: StorageColumn, source: &str) -> String {
+    match column {
+        StorageColumn::Json => source.to_string(),
+        StorageColumn::Binary => format!("to_jsonb(encode({}, 'base64'))", source),
+        _ => format!("to_jsonb({})", source),
+    }
+}
+
+/// Conversión de los valores de `source` (una columna de tipo `from`) al tipo `to`.
+/// Requiere la función `value_casts_to` de las migraciones. Si la columna no cambia,
+/// el valor se conserva y solo se comprueban los parámetros y las opciones.
+pub fn value_conversion_sql(
+    from: StorageColumn,
+    source: &str,
+    to: &DataTypeStorage,
+    options: &[AttributeOption],
+) -> ValueConversionSql {
+    let json = value_json_expression(from, source);
+    let value = if from == to.column { source.to_string() } else { converted_value(&json, to.column) };
+
+    let mut failures = Vec::new();
+    if from != to.column {
+        failures.push(format!("{} IS NULL", value));
+    }
+    failures.extend(param_failures(to, &value));
+    if to.is_enumeration() {
+        let codes: Vec<String> = options.iter().filter(|o| o.is_active).map(|o| quote_literal(&o.code)).collect();
+        failures.push(format!("{}::text <> ALL(ARRAY[{}]::text[])", value, codes.join(", ")));
+    }
+
+    let mut failed = format!("{} IS NOT NULL", source);
+    // Un `null` JSON equivale a un valor ausente: no se convierte pero tampoco falla
+    if from == StorageColumn::Json {
+        failed.push_str(&format!(" AND jsonb_typeof({}) <> 'null'", source));
+    }
+    let failed = match failures.is_empty() {
+        true => "FALSE".to_string(),
+        false => format!("({} AND ({}))", failed, failures.join(" OR ")),
+    };
+    ValueConversionSql { value, failed }
+}
+
+/// Valor JSON `json` convertido a `column` con las reglas de `to_storage_text`, o NULL.
+fn converted_value(json: &str, column: StorageColumn) -> String {
+    let text = format!("({} #>> '{{}}')", json);
+    let kind = format!("jsonb_typeof({})", json);
+    let matches = |pattern: &str| format!("{} ~ '{}'", text, pattern);
+    let casts_to = |pg_type: &str| format!("value_casts_to({}, '{}')", text, pg_type);
+
+    match column {
+        StorageColumn::String | StorageColumn::Text => {
+            format!("CASE WHEN {} IN ('string', 'number', 'boolean') THEN {} END", kind, text)
+        },
+        StorageColumn::Integer => format!(
+            "CASE WHEN ({k} = 'number' AND {t} ~ '^-?[0-9]+$' OR {k} = 'string' AND {m}) AND {c} THEN {t}::bigint END",
+            k = kind, t = text, m = matches(INTEGER_TEXT), c = casts_to("bigint"),
+        ),
+        StorageColumn::Float => format!(
+            "CASE WHEN ({k} = 'number' OR {k} = 'string' AND {t} ~* '{p}') AND {c} THEN {t}::double precision END",
+            k = kind, t = text, p = FLOAT_TEXT, c = casts_to("double precision"),
+        ),
+        StorageColumn::Numeric => format!(
+            "CASE WHEN {k} = 'number' OR {k} = 'string' AND {m} THEN {t}::numeric END",
+            k = kind, t = text, m = matches(DECIMAL_TEXT),
+        ),
+        StorageColumn::Boolean => format!(
+            "CASE {k} WHEN 'boolean' THEN {t}::boolean WHEN 'string' THEN \
+             CASE lower({t}) WHEN 'true' THEN TRUE WHEN '1' THEN TRUE WHEN 'false' THEN FALSE WHEN '0' THEN FALSE END END",
+            k = kind, t = text,
+        ),
+        StorageColumn::DateTime => format!(
+            "CASE WHEN {k} = 'string' AND {m} AND {c} THEN {t}::timestamptz END",
+            k = kind, t = text, m = matches(RFC3339_TEXT), c = casts_to("timestamptz"),
+        ),
+        StorageColumn::Date => format!(
+            "CASE WHEN {k} = 'string' AND {m} AND {c} THEN {t}::date END",
+            k = kind, t = text, m = matches(DATE_TEXT), c = casts_to("date"),
+        ),
+        StorageColumn::Time => format!(
+            "CASE WHEN {k} = 'string' AND {m} AND {c} THEN {t}::time END",
+            k = kind, t = text, m = matches(TIME_TEXT), c = casts_to("time"),
+        ),
+        StorageColumn::Uuid => format!(
+            "CASE WHEN {k} = 'string' AND {c} THEN {t}::uuid END",
+            k = kind, t = text, c = casts_to("uuid"),
+        ),
+        StorageColumn::Json => json.to_string(),
+        StorageColumn::Binary => format!(
+            "CASE WHEN {k} = 'string' AND {t} <> '' AND {m} THEN decode({t}, 'base64') END",
+            k = kind, t = text, m = matches(BASE64_TEXT),
+        ),
+    }
+}
+
+/// Condiciones que incumplen los parámetros del tipo, como en `DataTypeStorage::check_value`.
+fn param_failures(to: &DataTypeStorage, value: &str) -> Vec<String> {
+    let params = &to.params;
+    let mut failures = Vec::new();
+
+    if let Some(max_length) = params.max_length {
+        failures.push(format!("char_length({}) > {}", value, max_length));
+    }
+    if to.column == StorageColumn::Numeric {
+        let scale = params.numeric_scale.unwrap_or(0);
+        if params.numeric_scale.is_some() {
+            failures.push(format!("length(rtrim(split_part({}::text, '.', 2), '0')) > {}", value, scale));
+        }
+        if let Some(precision) = params.numeric_precision {
+            failures.push(format!(
+                "length(ltrim(split_part(abs({})::text, '.', 1), '0')) > {}", value, precision - scale
+            ));
+        }
+    }
+    // Los enteros y decimales se comparan como numeric, sin pasar por double precision;
+    // `f64` se formatea siempre sin exponente, así que el límite es un literal numeric válido
+    let number = match to.column {
+        StorageColumn::Float => value.to_string(),
+        _ => format!("{}::numeric", value),
+    };
+    if let Some(min) = params.min_value {
+        failures.push(format!("{} < {}", number, min));
+    }
+    if let Some(max) = params.max_value {
+        failures.push(format!("{} > {}", number, max));
+    }
+    failures
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Domain::data_types::{DataTypeKind, DataTypeParams};
+
+    fn storage(column: StorageColumn, params: DataTypeParams) -> DataTypeStorage {
+        DataTypeStorage::new(column, None, params).unwrap()
+    }
+
+    #[test]
+    fn test_same_column_without_checks_never_fails() {
+        let sql = value_conversion_sql(StorageColumn::Text, "text_value", &storage(StorageColumn::Text, Default::default()), &[]);
+        assert_eq!(sql.value, "text_value");
+        assert_eq!(sql.failed, "FALSE");
+    }
+
+    #[test]
+    fn test_column_change_fails_when_value_does_not_convert() {
+        let sql = value_conversion_sql(
+            StorageColumn::String, "string_value", &storage(StorageColumn::Integer, Default::default()), &[],
+        );
+        assert!(sql.value.starts_with("CASE WHEN (jsonb_typeof(to_jsonb(string_value)) = 'number'"));
+        assert!(sql.value.contains("value_casts_to((to_jsonb(string_value) #>> '{}'), 'bigint')"));
+        assert!(sql.value.ends_with("THEN (to_jsonb(string_value) #>> '{}')::bigint END"));
+        assert_eq!(sql.failed, format!("(string_value IS NOT NULL AND ({} IS NULL))", sql.value));
+    }
+
+    #[test]
+    fn test_params_and_options_are_checked_on_the_converted_value() {
+        let params = DataTypeParams { numeric_precision: Some(5), numeric_scale: Some(2), min_value: Some(-1.5), ..Default::default() };
+        let sql = value_conversion_sql(StorageColumn::Numeric, "numeric_value", &storage(StorageColumn::Numeric, params), &[]);
+        assert!(sql.failed.contains("length(rtrim(split_part(numeric_value::text, '.', 2), '0')) > 2"));
+        assert!(sql.failed.contains("length(ltrim(split_part(abs(numeric_value)::text, '.', 1), '0')) > 3"));
+        assert!(sql.failed.contains("numeric_value::numeric < -1.5"));
+
+        let enumeration = storage(StorageColumn::String, Default::default()).with_kind(DataTypeKind::Enumeration).unwrap();
+        let options = [
+            AttributeOption { code: "o'k".to_string(), label: "Ok".to_string(), sort_order: 0, is_active: true },
+            AttributeOption { code: "old".to_string(), label: "Old".to_string(), sort_order: 1, is_active: false },
+        ];
+        let sql = value_conversion_sql(StorageColumn::String, "string_value", &enumeration, &options);
+        assert_eq!(sql.failed, "(string_value IS NOT NULL AND (string_value::text <> ALL(ARRAY['o''k']::text[])))");
+    }
+
+    #[test]
+    fn test_json_null_is_not_a_failure() {
+        let sql = value_conversion_sql(StorageColumn::Json, "json_value", &storage(StorageColumn::Text, Default::default()), &[]);
+        assert!(sql.failed.starts_with("(json_value IS NOT NULL AND jsonb_typeof(json_value) <> 'null' AND ("));
+        assert_eq!(value_json_expression(StorageColumn::Binary, "b"), "to_jsonb(encode(b, 'base64'))");
+    }
+}
@@ -0,0 +1,8 @@
+// src/Domain/records/mod.rs
+// Registros (tuplas) de una entidad lógica y el almacenamiento tipado de sus valores.
+
+pub mod storage;
+pub mod conversion;
+
+pub use storage::{StorageColumn, to_storage_text};
+pub use conversion::{ValueConversionSql, value_conversion_sql, value_json_expression};
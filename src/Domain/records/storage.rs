@@ -0,0 +1,267 @@
+// src/Domain/records/storage.rs
+
+use chrono::{DateTime, NaiveDate, NaiveTime};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::Domain::errors::DomainError;
+
+/// Columna tipada de `attribute_values` donde se almacena el valor de un atributo.
+/// Se serializa con el nombre de la columna (`string_value`, `integer_value`, ...),
+/// que es como la declara el registro de tipos de datos (`data_types.storage_column`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum StorageColumn {
+    #[serde(rename = "string_value")]
+    String,
+    #[serde(rename = "text_value")]
+    Text,
+    #[serde(rename = "integer_value")]
+    Integer,
+    #[serde(rename = "float_value")]
+    Float,
+    #[serde(rename = "numeric_value")]
+    Numeric,
+    #[serde(rename = "boolean_value")]
+    Boolean,
+    #[serde(rename = "datetime_value")]
+    DateTime,
+    #[serde(rename = "date_value")]
+    Date,
+    #[serde(rename = "time_value")]
+    Time,
+    #[serde(rename = "uuid_value")]
+    Uuid,
+    #[serde(rename = "json_value")]
+    Json,
+    #[serde(rename = "binary_value")]
+    Binary,
+}
+
+impl StorageColumn {
+    /// Todas las columnas de valor, en el orden en que aparecen en la tabla.
+    pub const ALL: [StorageColumn; 12] = [
+        StorageColumn::String,
+        StorageColumn::Text,
+        StorageColumn::Integer,
+        StorageColumn::Float,
+        StorageColumn::Numeric,
+        StorageColumn::Boolean,
+        StorageColumn::DateTime,
+        StorageColumn::Date,
+        StorageColumn::Time,
+        StorageColumn::Uuid,
+        StorageColumn::Json,
+        StorageColumn::Binary,
+    ];
+
+    /// Resuelve la columna a partir de su nombre en `attribute_values`
+    /// (valor de `data_types.storage_column`).
+    pub fn from_column_name(column_name: &str) -> Option<Self> {
+        StorageColumn::ALL.into_iter().find(|c| c.column_name() == column_name)
+    }
+
+    /// Nombre de la columna en `attribute_values`.
+    pub fn column_name(&self) -> &'static str {
+        match self {
+            StorageColumn::String => "string_value",
+            StorageColumn::Text => "text_value",
+            StorageColumn::Integer => "integer_value",
+            StorageColumn::Float => "float_value",
+            StorageColumn::Numeric => "numeric_value",
+            StorageColumn::Boolean => "boolean_value",
+            StorageColumn::DateTime => "datetime_value",
+            StorageColumn::Date => "date_value",
+            StorageColumn::Time => "time_value",
+            StorageColumn::Uuid => "uuid_value",
+            StorageColumn::Json => "json_value",
+            StorageColumn::Binary => "binary_value",
+        }
+    }
+
+    /// Tipo de Postgres de la columna; es el cast por defecto de los tipos de datos que la usan.
+    pub fn pg_type(&self) -> &'static str {
+        match self {
+            StorageColumn::String | StorageColumn::Text => "text",
+            StorageColumn::Integer => "bigint",
+            StorageColumn::Float => "double precision",
+            StorageColumn::Numeric => "numeric",
+            StorageColumn::Boolean => "boolean",
+            StorageColumn::DateTime => "timestamptz",
+            StorageColumn::Date => "date",
+            StorageColumn::Time => "time",
+            StorageColumn::Uuid => "uuid",
+            StorageColumn::Json => "jsonb",
+            StorageColumn::Binary => "bytea",
+        }
+    }
+
+    /// Tipos de Postgres a los que se puede convertir el valor de la columna en una vista.
+    pub fn compatible_casts(&self) -> &'static [&'static str] {
+        match self {
+            StorageColumn::String | StorageColumn::Text => {
+                &["text", "varchar", "character varying", "char", "character", "citext"]
+            },
+            StorageColumn::Integer => &["bigint", "integer", "int", "smallint", "int2", "int4", "int8", "numeric"],
+            StorageColumn::Float => &["double precision", "real", "float4", "float8", "numeric"],
+            StorageColumn::Numeric => &["numeric", "decimal"],
+            StorageColumn::Boolean => &["boolean", "bool"],
+            StorageColumn::DateTime => &["timestamptz", "timestamp", "timestamp with time zone", "timestamp without time zone", "date"],
+            StorageColumn::Date => &["date"],
+            StorageColumn::Time => &["time", "time without time zone"],
+            StorageColumn::Uuid => &["uuid", "text"],
+            StorageColumn::Json => &["jsonb", "json"],
+            StorageColumn::Binary => &["bytea"],
+        }
+    }
+
+    /// Expresión SQL que convierte un parámetro de texto (p. ej. `$3`) al tipo de la columna.
+    pub fn cast_expression(&self, param: &str) -> String {
+        match self {
+            StorageColumn::String | StorageColumn::Text => param.to_string(),
+            StorageColumn::Integer => format!("{}::bigint", param),
+            StorageColumn::Float => format!("{}::double precision", param),
+            StorageColumn::Numeric => format!("{}::numeric", param),
+            StorageColumn::Boolean => format!("{}::boolean", param),
+            StorageColumn::DateTime => format!("{}::timestamptz", param),
+            StorageColumn::Date => format!("{}::date", param),
+            StorageColumn::Time => format!("{}::time", param),
+            StorageColumn::Uuid => format!("{}::uuid", param),
+            StorageColumn::Json => format!("{}::jsonb", param),
+            StorageColumn::Binary => format!("decode({}, 'base64')", param),
+        }
+    }
+}
+
+/// Valida un valor JSON recibido por la API contra la columna de destino y
+/// lo convierte a la representación de texto que se enlaza en el INSERT/UPDATE.
+/// `null` se traduce en `None` (valor ausente).
+pub fn to_storage_text(column: StorageColumn, value: &Value) -> Result<Option<String>, DomainError> {
+    if value.is_null() {
+        return Ok(None);
+    }
+    let invalid = |expected: &str| {
+        DomainError::ValidationError(format!("se esperaba un valor {} y se recibió {}", expected, value))
+    };
+
+    let text = match column {
+        StorageColumn::String | StorageColumn::Text => match value {
+            Value::String(s) => s.clone(),
+            Value::Number(n) => n.to_string(),
+            Value::Bool(b) => b.to_string(),
+            _ => return Err(invalid("de texto")),
+        },
+        StorageColumn::Integer => match value {
+            Value::Number(n) if n.is_i64() => n.to_string(),
+            Value::String(s) => s.trim().parse::<i64>().map_err(|_| invalid("entero"))?.to_string(),
+            _ => return Err(invalid("entero")),
+        },
+        StorageColumn::Float => match value {
+            Value::Number(n) => n.as_f64().ok_or_else(|| invalid("numérico"))?.to_string(),
+            Value::String(s) => s.trim().parse::<f64>().map_err(|_| invalid("numérico"))?.to_string(),
+            _ => return Err(invalid("numérico")),
+        },
+        StorageColumn::Numeric => match value {
+            Value::Number(n) => n.to_string(),
+            // Se acepta como texto para no perder precisión en decimales grandes
+            Value::String(s) if is_decimal_literal(s.trim()) => s.trim().to_string(),
+            _ => return Err(invalid("decimal")),
+        },
+        StorageColumn::Boolean => match value {
+            Value::Bool(b) => b.to_string(),
+            Value::String(s) => match s.to_lowercase().as_str() {
+                "true" | "1" => "true".to_string(),
+                "false" | "0" => "false".to_string(),
+                _ => return Err(invalid("booleano")),
+            },
+            _ => return Err(invalid("booleano")),
+        },
+        StorageColumn::DateTime => {
+            let s = value.as_str().ok_or_else(|| invalid("de fecha y hora (RFC 3339)"))?;
+            DateTime::parse_from_rfc3339(s).map_err(|_| invalid("de fecha y hora (RFC 3339)"))?;
+            s.to_string()
+        },
+        StorageColumn::Date => {
+            let s = value.as_str().ok_or_else(|| invalid("de fecha (YYYY-MM-DD)"))?;
+            NaiveDate::parse_from_str(s, "%Y-%m-%d").map_err(|_| invalid("de fecha (YYYY-MM-DD)"))?;
+            s.to_string()
+        },
+        StorageColumn::Time => {
+            let s = value.as_str().ok_or_else(|| invalid("de hora (HH:MM:SS)"))?;
+            NaiveTime::parse_from_str(s, "%H:%M:%S%.f").map_err(|_| invalid("de hora (HH:MM:SS)"))?;
+            s.to_string()
+        },
+        StorageColumn::Uuid => {
+            let s = value.as_str().ok_or_else(|| invalid("UUID"))?;
+            Uuid::parse_str(s).map_err(|_| invalid("UUID"))?.to_string()
+        },
+        StorageColumn::Json => value.to_string(),
+        StorageColumn::Binary => match value {
+            // Contenido binario codificado en base64
+            Value::String(s) if is_base64(s) => s.clone(),
+            _ => return Err(invalid("binario (base64)")),
+        },
+    };
+    Ok(Some(text))
+}
+
+fn is_decimal_literal(s: &str) -> bool {
+    let digits = s.strip_prefix('-').or_else(|| s.strip_prefix('+')).unwrap_or(s);
+    let mut parts = digits.splitn(2, '.');
+    let int_part = parts.next().unwrap_or("");
+    let frac_part = parts.next();
+    let int_ok = !int_part.is_empty() && int_part.chars().all(|c| c.is_ascii_digit());
+    let frac_ok = frac_part.map(|f| !f.is_empty() && f.chars().all(|c| c.is_ascii_digit())).unwrap_or(true);
+    int_ok && frac_ok
+}
+
+fn is_base64(s: &str) -> bool {
+    let data = s.trim_end_matches('=');
+    // El relleno solo completa el último grupo: como mucho dos '=' y nunca un grupo entero
+    !data.is_empty()
+        && s.len().is_multiple_of(4)
+        && s.len() - data.len() <= 2
+        && data.chars().all(|c| c.is_ascii_alphanumeric() || c == '+' || c == '/')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_from_column_name_round_trips() {
+        for column in StorageColumn::ALL {
+            assert_eq!(StorageColumn::from_column_name(column.column_name()), Some(column));
+        }
+        assert_eq!(StorageColumn::from_column_name("string"), None);
+    }
+
+    #[test]
+    fn test_to_storage_text_converts_valid_values() {
+        assert_eq!(to_storage_text(StorageColumn::Integer, &json!(42)).unwrap(), Some("42".to_string()));
+        assert_eq!(to_storage_text(StorageColumn::Numeric, &json!("1000.50")).unwrap(), Some("1000.50".to_string()));
+        assert_eq!(to_storage_text(StorageColumn::Boolean, &json!("1")).unwrap(), Some("true".to_string()));
+        assert_eq!(to_storage_text(StorageColumn::Json, &json!({"a": 1})).unwrap(), Some("{\"a\":1}".to_string()));
+        assert_eq!(to_storage_text(StorageColumn::String, &Value::Null).unwrap(), None);
+    }
+
+    #[test]
+    fn test_to_storage_text_rejects_type_mismatch() {
+        assert!(to_storage_text(StorageColumn::Numeric, &json!("1000J")).is_err());
+        assert!(to_storage_text(StorageColumn::Integer, &json!(1.5)).is_err());
+        assert!(to_storage_text(StorageColumn::Date, &json!("2024-13-01")).is_err());
+        assert!(to_storage_text(StorageColumn::Uuid, &json!("no-es-uuid")).is_err());
+    }
+
+    #[test]
+    fn test_to_storage_text_rejects_padding_only_base64() {
+        assert_eq!(to_storage_text(StorageColumn::Binary, &json!("AB==")).unwrap(), Some("AB==".to_string()));
+        for value in ["====", "A===", "AB=C", ""] {
+            assert!(matches!(
+                to_storage_text(StorageColumn::Binary, &json!(value)),
+                Err(DomainError::ValidationError(_))
+            ), "{:?}", value);
+        }
+    }
+}
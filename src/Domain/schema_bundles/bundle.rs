@@ -0,0 +1,331 @@
+// src/Domain/schema_bundles/bundle.rs
+//
+// Bundle portable de definiciones: entidades lógicas, sus atributos y los tipos
+// de datos de los que dependen. No contiene IDs: todo se identifica por nombre,
+// de modo que el mismo bundle se puede aplicar en cualquier entorno.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+use crate::Domain::attribute_options::{validate_options, AttributeOption};
+use crate::Domain::attribute_references::ReferenceDeleteAction;
+use crate::Domain::data_types::{DataTypeKind, DataTypeParams, DataTypeStorage};
+use crate::Domain::errors::{DomainError, DomainResult};
+use crate::Domain::records::StorageColumn;
+
+/// Versión del formato que se genera y la única que se admite al importar.
+pub const SCHEMA_BUNDLE_VERSION: u32 = 1;
+
+const DEFAULT_SEARCH_LANGUAGE: &str = "simple";
+
+/// Formato de serialización del bundle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BundleFormat {
+    Json,
+    Yaml,
+}
+
+impl BundleFormat {
+    pub fn from_name(name: &str) -> DomainResult<Self> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "json" => Ok(BundleFormat::Json),
+            "yaml" | "yml" => Ok(BundleFormat::Yaml),
+            other => Err(DomainError::ValidationError(format!(
+                "formato de bundle '{}' no válido (admitidos: json, yaml)", other
+            ))),
+        }
+    }
+
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            BundleFormat::Json => "application/json",
+            BundleFormat::Yaml => "application/yaml",
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            BundleFormat::Json => "json",
+            BundleFormat::Yaml => "yaml",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SchemaBundle {
+    pub version: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exported_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub data_types: Vec<BundleDataType>,
+    #[serde(default)]
+    pub entities: Vec<BundleEntity>,
+}
+
+/// Tipo de dato del bundle. Los parámetros van al mismo nivel que el resto de campos.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BundleDataType {
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    pub storage_column: StorageColumn,
+    pub pg_cast: String,
+    #[serde(default)]
+    pub kind: DataTypeKind,
+    #[serde(flatten)]
+    pub params: DataTypeParams,
+}
+
+/// Entidad del bundle. Sin `description` se conserva la del destino.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BundleEntity {
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default = "default_search_language")]
+    pub search_language: String,
+    #[serde(default)]
+    pub assign_view: bool,
+    #[serde(default)]
+    pub attributes: Vec<BundleAttribute>,
+}
+
+/// Atributo del bundle. `retired` indica un atributo retirado (status 0): la
+/// exportación no los incluye, pero un bundle puede pedir que se retire uno.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BundleAttribute {
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    pub data_type: String,
+    pub position: i16,
+    #[serde(default)]
+    pub is_required: bool,
+    #[serde(default)]
+    pub is_unique: Option<i16>,
+    #[serde(default)]
+    pub default_value: Option<String>,
+    #[serde(default)]
+    pub validation_regex: Option<String>,
+    #[serde(default)]
+    pub show_option_label: bool,
+    #[serde(default)]
+    pub is_searchable: bool,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub options: Vec<AttributeOption>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reference: Option<BundleReference>,
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub retired: bool,
+}
+
+#[cfg(test)]
+impl BundleAttribute {
+    /// Atributo activo, opcional, sin descripción ni restricciones.
+    pub(crate) fn for_tests(name: &str, data_type: &str, position: i16) -> Self {
+        BundleAttribute {
+            name: name.to_string(),
+            description: None,
+            data_type: data_type.to_string(),
+            position,
+            is_required: false,
+            is_unique: None,
+            default_value: None,
+            validation_regex: None,
+            show_option_label: false,
+            is_searchable: false,
+            options: Vec::new(),
+            reference: None,
+            retired: false,
+        }
+    }
+}
+
+/// Referencia a otra entidad, por nombre (y el atributo que se muestra, también por nombre).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BundleReference {
+    pub entity: String,
+    #[serde(default)]
+    pub on_delete: ReferenceDeleteAction,
+    #[serde(default)]
+    pub display_attribute: Option<String>,
+}
+
+fn default_search_language() -> String {
+    DEFAULT_SEARCH_LANGUAGE.to_string()
+}
+
+fn is_false(value: &bool) -> bool {
+    !*value
+}
+
+impl SchemaBundle {
+    pub fn new(data_types: Vec<BundleDataType>, entities: Vec<BundleEntity>) -> Self {
+        Self { version: SCHEMA_BUNDLE_VERSION, exported_at: None, data_types, entities }
+    }
+
+    /// Lee y valida un bundle.
+    pub fn parse(content: &str, format: BundleFormat) -> DomainResult<Self> {
+        let bundle: SchemaBundle = match format {
+            BundleFormat::Json => serde_json::from_str(content)
+                .map_err(|e| DomainError::ValidationError(format!("bundle JSON no válido: {}", e)))?,
+            BundleFormat::Yaml => serde_yaml::from_str(content)
+                .map_err(|e| DomainError::ValidationError(format!("bundle YAML no válido: {}", e)))?,
+        };
+        bundle.validate()?;
+        Ok(bundle)
+    }
+
+    pub fn render(&self, format: BundleFormat) -> DomainResult<String> {
+        match format {
+            BundleFormat::Json => serde_json::to_string_pretty(self)
+                .map(|json| format!("{}\n", json))
+                .map_err(|e| DomainError::ValidationError(format!("no se pudo serializar el bundle: {}", e))),
+            BundleFormat::Yaml => serde_yaml::to_string(self)
+                .map_err(|e| DomainError::ValidationError(format!("no se pudo serializar el bundle: {}", e))),
+        }
+    }
+
+    /// Comprueba la versión del formato, que los nombres no se repitan y que las
+    /// definiciones de tipos de datos y opciones sean válidas.
+    pub fn validate(&self) -> DomainResult<()> {
+        if self.version != SCHEMA_BUNDLE_VERSION {
+            return Err(invalid(format!(
+                "versión de bundle {} no admitida (se admite la {})", self.version, SCHEMA_BUNDLE_VERSION
+            )));
+        }
+
+        let mut data_types = HashSet::new();
+        for data_type in &self.data_types {
+            if data_type.name.trim().is_empty() {
+                return Err(invalid("el nombre de un tipo de dato no puede estar vacío".to_string()));
+            }
+            if !data_types.insert(data_type.name.as_str()) {
+                return Err(invalid(format!("el tipo de dato '{}' aparece más de una vez", data_type.name)));
+            }
+            data_type.storage().map_err(|e| invalid(format!("tipo de dato '{}': {}", data_type.name, e)))?;
+        }
+
+        let mut entities = HashSet::new();
+        for entity in &self.entities {
+            if entity.name.trim().is_empty() {
+                return Err(invalid("el nombre de una entidad no puede estar vacío".to_string()));
+            }
+            if !entities.insert(entity.name.as_str()) {
+                return Err(invalid(format!("la entidad '{}' aparece más de una vez", entity.name)));
+            }
+            let mut names = HashSet::new();
+            let mut positions = HashSet::new();
+            for attribute in &entity.attributes {
+                if !names.insert(attribute.name.as_str()) {
+                    return Err(invalid(format!(
+                        "el atributo '{}' aparece más de una vez en la entidad '{}'", attribute.name, entity.name
+                    )));
+                }
+                if !attribute.retired && !positions.insert(attribute.position) {
+                    return Err(invalid(format!(
+                        "la posición {} se repite en la entidad '{}'", attribute.position, entity.name
+                    )));
+                }
+                validate_options(&attribute.options)
+                    .map_err(|e| invalid(format!("atributo '{}.{}': {}", entity.name, attribute.name, e)))?;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn data_type(&self, name: &str) -> Option<&BundleDataType> {
+        self.data_types.iter().find(|d| d.name == name)
+    }
+
+    pub fn entity(&self, name: &str) -> Option<&BundleEntity> {
+        self.entities.iter().find(|e| e.name == name)
+    }
+}
+
+impl BundleDataType {
+    /// Definición de almacenamiento validada (el cast se normaliza).
+    pub fn storage(&self) -> DomainResult<DataTypeStorage> {
+        DataTypeStorage::new(self.storage_column, Some(&self.pg_cast), self.params.clone())?.with_kind(self.kind)
+    }
+}
+
+impl BundleEntity {
+    /// Lee una entidad suelta, sin validarla (la vista previa informa de sus problemas).
+    pub fn parse(content: &str, format: BundleFormat) -> DomainResult<Self> {
+        match format {
+            BundleFormat::Json => serde_json::from_str(content)
+                .map_err(|e| DomainError::ValidationError(format!("entidad JSON no válida: {}", e))),
+            BundleFormat::Yaml => serde_yaml::from_str(content)
+                .map_err(|e| DomainError::ValidationError(format!("entidad YAML no válida: {}", e))),
+        }
+    }
+
+    pub fn attribute(&self, name: &str) -> Option<&BundleAttribute> {
+        self.attributes.iter().find(|a| a.name == name)
+    }
+}
+
+fn invalid(message: String) -> DomainError {
+    DomainError::ValidationError(message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const YAML: &str = r#"
+version: 1
+data_types:
+  - name: money
+    storage_column: numeric_value
+    pg_cast: numeric(12,2)
+    numeric_precision: 12
+    numeric_scale: 2
+entities:
+  - name: invoice
+    assign_view: true
+    attributes:
+      - name: total
+        data_type: money
+        position: 0
+        is_required: true
+      - name: status
+        data_type: status_code
+        position: 1
+        options:
+          - { code: open, label: Open, sort_order: 0, is_active: true }
+"#;
+
+    #[test]
+    fn test_parse_yaml_and_round_trip_json() {
+        let bundle = SchemaBundle::parse(YAML, BundleFormat::Yaml).unwrap();
+        assert_eq!(bundle.data_types[0].params.numeric_scale, Some(2));
+        let invoice = bundle.entity("invoice").unwrap();
+        assert_eq!(invoice.search_language, "simple");
+        assert!(invoice.attribute("total").unwrap().is_required);
+        assert_eq!(invoice.attribute("status").unwrap().options.len(), 1);
+
+        let json = bundle.render(BundleFormat::Json).unwrap();
+        assert_eq!(SchemaBundle::parse(&json, BundleFormat::Json).unwrap(), bundle);
+        let yaml = bundle.render(BundleFormat::Yaml).unwrap();
+        assert_eq!(SchemaBundle::parse(&yaml, BundleFormat::Yaml).unwrap(), bundle);
+    }
+
+    #[test]
+    fn test_validate_rejects_unknown_version_and_duplicates() {
+        let newer = YAML.replace("version: 1", "version: 2");
+        assert!(SchemaBundle::parse(&newer, BundleFormat::Yaml).is_err());
+
+        let mut bundle = SchemaBundle::parse(YAML, BundleFormat::Yaml).unwrap();
+        bundle.entities[0].attributes[1].position = 0;
+        assert!(bundle.validate().is_err());
+        bundle.entities[0].attributes[1].retired = true;
+        assert!(bundle.validate().is_ok());
+
+        bundle.entities.push(bundle.entities[0].clone());
+        assert!(bundle.validate().is_err());
+        assert!(BundleFormat::from_name("toml").is_err());
+    }
+}
@@ -0,0 +1,12 @@
+// src/Domain/schema_bundles/mod.rs
+// Bundles portables de definiciones (entidades, atributos y tipos de datos) para
+// promover modelos entre entornos, y el plan de cambios al importarlos.
+
+pub mod bundle;
+pub mod plan;
+
+pub use bundle::{
+    SchemaBundle, BundleFormat, BundleDataType, BundleEntity, BundleAttribute, BundleReference,
+    SCHEMA_BUNDLE_VERSION,
+};
+pub use plan::{BundlePlan, PlanStep, PlanTarget, PlanAction, plan_bundle};
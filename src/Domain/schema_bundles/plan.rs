@@ -0,0 +1,340 @@
+// src/Domain/schema_bundles/plan.rs
+//
+// Plan de importación de un bundle: compara la definición actual del destino con
+// la del bundle (por nombre) y enumera los tipos de datos, entidades y atributos
+// que se añaden, cambian o eliminan. Las diferencias que no se pueden aplicar
+// automáticamente se informan como conflictos.
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::Domain::schema_versions::FieldChange;
+use super::bundle::{BundleAttribute, BundleDataType, BundleEntity, SchemaBundle};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PlanTarget {
+    DataType,
+    Entity,
+    Attribute,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PlanAction {
+    Add,
+    Change,
+    /// Atributo del destino que no está en el bundle. Solo se retira si se pide
+    /// expresamente (los valores almacenados se conservan).
+    Remove,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlanStep {
+    pub target: PlanTarget,
+    pub action: PlanAction,
+    /// Entidad a la que pertenece el atributo.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub entity: Option<String>,
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub changes: Vec<FieldChange>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BundlePlan {
+    pub steps: Vec<PlanStep>,
+    pub conflicts: Vec<String>,
+}
+
+impl BundlePlan {
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    pub fn count(&self, action: PlanAction) -> usize {
+        self.steps.iter().filter(|s| s.action == action).count()
+    }
+}
+
+/// Compara `current` (definición del destino) con `desired` (el bundle).
+/// `current` debe incluir las entidades del bundle que ya existen y las que
+/// estas referencian; las entidades del destino que no están en el bundle no
+/// se consideran eliminadas. Los tipos de datos tampoco se eliminan nunca.
+pub fn plan_bundle(current: &SchemaBundle, desired: &SchemaBundle) -> BundlePlan {
+    let mut plan = BundlePlan::default();
+
+    for data_type in &desired.data_types {
+        match current.data_type(&data_type.name) {
+            None => plan.steps.push(step(PlanTarget::DataType, PlanAction::Add, None, &data_type.name, Vec::new())),
+            Some(before) => {
+                let changes = field_changes(data_type_fields(before), data_type_fields(data_type));
+                if !changes.is_empty() {
+                    plan.steps.push(step(PlanTarget::DataType, PlanAction::Change, None, &data_type.name, changes));
+                }
+            },
+        }
+    }
+
+    for entity in &desired.entities {
+        let before = current.entity(&entity.name);
+        match before {
+            None => plan.steps.push(step(PlanTarget::Entity, PlanAction::Add, None, &entity.name, Vec::new())),
+            Some(before) => {
+                let changes = field_changes(entity_fields(before, entity), entity_fields(entity, entity));
+                if !changes.is_empty() {
+                    plan.steps.push(step(PlanTarget::Entity, PlanAction::Change, None, &entity.name, changes));
+                }
+            },
+        }
+        plan_attributes(current, desired, before, entity, &mut plan);
+    }
+    plan
+}
+
+fn plan_attributes(
+    current: &SchemaBundle,
+    desired: &SchemaBundle,
+    before: Option<&BundleEntity>,
+    entity: &BundleEntity,
+    plan: &mut BundlePlan,
+) {
+    let mut attributes: Vec<&BundleAttribute> = entity.attributes.iter().collect();
+    attributes.sort_by_key(|a| (a.position, a.name.clone()));
+
+    for attribute in attributes {
+        let existing = before.and_then(|e| e.attribute(&attribute.name));
+        if attribute.retired && existing.is_none_or(|a| a.retired) {
+            continue;
+        }
+        if !attribute.retired {
+            check_dependencies(current, desired, entity, attribute, plan);
+        }
+        match existing {
+            None => plan.steps.push(step(PlanTarget::Attribute, PlanAction::Add, Some(&entity.name), &attribute.name, Vec::new())),
+            Some(existing) => {
+                let changes = field_changes(attribute_fields(existing), attribute_fields(attribute));
+                if changes.is_empty() {
+                    continue;
+                }
+                if changes.iter().any(|c| c.field == "reference") {
+                    check_reference_change(entity, existing, attribute, plan);
+                }
+                plan.steps.push(step(PlanTarget::Attribute, PlanAction::Change, Some(&entity.name), &attribute.name, changes));
+            },
+        }
+    }
+
+    if let Some(before) = before {
+        for attribute in before.attributes.iter().filter(|a| !a.retired && entity.attribute(&a.name).is_none()) {
+            plan.steps.push(step(PlanTarget::Attribute, PlanAction::Remove, Some(&entity.name), &attribute.name, Vec::new()));
+        }
+    }
+}
+
+/// El tipo de dato y la entidad referenciada deben estar en el bundle o en el destino.
+fn check_dependencies(
+    current: &SchemaBundle,
+    desired: &SchemaBundle,
+    entity: &BundleEntity,
+    attribute: &BundleAttribute,
+    plan: &mut BundlePlan,
+) {
+    if desired.data_type(&attribute.data_type).is_none() && current.data_type(&attribute.data_type).is_none() {
+        plan.conflicts.push(format!(
+            "atributo '{}.{}': el tipo de dato '{}' no existe en el destino ni en el bundle",
+            entity.name, attribute.name, attribute.data_type
+        ));
+    }
+    if let Some(reference) = &attribute.reference {
+        match desired.entity(&reference.entity).or_else(|| current.entity(&reference.entity)) {
+            None => plan.conflicts.push(format!(
+                "atributo '{}.{}': la entidad referenciada '{}' no existe en el destino ni en el bundle",
+                entity.name, attribute.name, reference.entity
+            )),
+            Some(target) => {
+                if let Some(display) = &reference.display_attribute {
+                    if target.attribute(display).is_none() {
+                        plan.conflicts.push(format!(
+                            "atributo '{}.{}': la entidad '{}' no tiene el atributo '{}'",
+                            entity.name, attribute.name, reference.entity, display
+                        ));
+                    }
+                }
+            },
+        }
+    }
+}
+
+/// De una referencia existente solo se pueden cambiar la acción al eliminar y el
+/// atributo que se muestra.
+fn check_reference_change(entity: &BundleEntity, before: &BundleAttribute, after: &BundleAttribute, plan: &mut BundlePlan) {
+    match (&before.reference, &after.reference) {
+        (Some(old), Some(new)) if old.entity != new.entity => plan.conflicts.push(format!(
+            "atributo '{}.{}': la entidad referenciada no se puede cambiar ('{}' -> '{}')",
+            entity.name, after.name, old.entity, new.entity
+        )),
+        (Some(_), None) | (None, Some(_)) => plan.conflicts.push(format!(
+            "atributo '{}.{}': un atributo existente no puede pasar a ser una referencia ni dejar de serlo",
+            entity.name, after.name
+        )),
+        _ => {},
+    }
+}
+
+fn step(target: PlanTarget, action: PlanAction, entity: Option<&str>, name: &str, changes: Vec<FieldChange>) -> PlanStep {
+    PlanStep { target, action, entity: entity.map(str::to_string), name: name.to_string(), changes }
+}
+
+fn field_changes<const N: usize>(before: [(&'static str, Value); N], after: [(&'static str, Value); N]) -> Vec<FieldChange> {
+    before.into_iter()
+        .zip(after)
+        .filter(|((_, old), (_, new))| old != new)
+        .map(|((field, old), (_, new))| FieldChange { field: field.to_string(), from: old, to: new })
+        .collect()
+}
+
+fn data_type_fields(data_type: &BundleDataType) -> [(&'static str, Value); 9] {
+    // El cast se compara normalizado ("NUMERIC(12, 2)" == "numeric(12,2)")
+    let pg_cast = data_type.storage().map(|s| s.pg_cast).unwrap_or_else(|_| data_type.pg_cast.clone());
+    [
+        ("description", json!(data_type.description)),
+        ("storage_column", json!(data_type.storage_column)),
+        ("pg_cast", json!(pg_cast)),
+        ("kind", json!(data_type.kind)),
+        ("max_length", json!(data_type.params.max_length)),
+        ("numeric_precision", json!(data_type.params.numeric_precision)),
+        ("numeric_scale", json!(data_type.params.numeric_scale)),
+        ("min_value", json!(data_type.params.min_value)),
+        ("max_value", json!(data_type.params.max_value)),
+    ]
+}
+
+/// Campos de `entity`. La descripción solo se compara si el bundle (`desired`) la indica.
+fn entity_fields(entity: &BundleEntity, desired: &BundleEntity) -> [(&'static str, Value); 3] {
+    let description = if desired.description.is_some() { json!(entity.description) } else { Value::Null };
+    [
+        ("description", description),
+        ("search_language", json!(entity.search_language)),
+        ("assign_view", json!(entity.assign_view)),
+    ]
+}
+
+fn attribute_fields(attribute: &BundleAttribute) -> [(&'static str, Value); 12] {
+    [
+        ("description", json!(attribute.description)),
+        ("data_type", json!(attribute.data_type)),
+        ("position", json!(attribute.position)),
+        ("is_required", json!(attribute.is_required)),
+        ("is_unique", json!(attribute.is_unique)),
+        ("default_value", json!(attribute.default_value)),
+        ("validation_regex", json!(attribute.validation_regex)),
+        ("show_option_label", json!(attribute.show_option_label)),
+        ("is_searchable", json!(attribute.is_searchable)),
+        ("options", json!(attribute.options)),
+        ("reference", json!(attribute.reference)),
+        ("retired", json!(attribute.retired)),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Domain::attribute_references::ReferenceDeleteAction;
+    use crate::Domain::data_types::{DataTypeKind, DataTypeParams};
+    use crate::Domain::records::StorageColumn;
+    use super::super::bundle::BundleReference;
+
+    fn entity(name: &str, attributes: Vec<BundleAttribute>) -> BundleEntity {
+        BundleEntity {
+            name: name.to_string(),
+            description: None,
+            search_language: "simple".to_string(),
+            assign_view: false,
+            attributes,
+        }
+    }
+
+    fn data_type(name: &str, pg_cast: &str) -> BundleDataType {
+        BundleDataType {
+            name: name.to_string(),
+            description: None,
+            storage_column: StorageColumn::String,
+            pg_cast: pg_cast.to_string(),
+            kind: DataTypeKind::Scalar,
+            params: DataTypeParams::default(),
+        }
+    }
+
+    fn actions(plan: &BundlePlan) -> Vec<(PlanTarget, PlanAction, String)> {
+        plan.steps.iter().map(|s| (s.target, s.action, s.name.clone())).collect()
+    }
+
+    #[test]
+    fn test_plan_lists_adds_changes_and_removals_by_name() {
+        let current = SchemaBundle::new(
+            vec![data_type("string", "text"), data_type("code", "varchar(10)")],
+            vec![entity("customer", vec![BundleAttribute::for_tests("name", "string", 0), BundleAttribute::for_tests("fax", "string", 1)])],
+        );
+        let mut name = BundleAttribute::for_tests("name", "string", 0);
+        name.is_required = true;
+        let mut code = data_type("code", "VARCHAR(20)");
+        code.params.max_length = None;
+        let desired = SchemaBundle::new(
+            vec![code, data_type("email", "text")],
+            vec![
+                entity("customer", vec![name, BundleAttribute::for_tests("email", "email", 1)]),
+                entity("supplier", vec![BundleAttribute::for_tests("name", "string", 0)]),
+            ],
+        );
+
+        let plan = plan_bundle(&current, &desired);
+        assert!(plan.conflicts.is_empty());
+        assert_eq!(actions(&plan), vec![
+            (PlanTarget::DataType, PlanAction::Change, "code".to_string()),
+            (PlanTarget::DataType, PlanAction::Add, "email".to_string()),
+            (PlanTarget::Attribute, PlanAction::Change, "name".to_string()),
+            (PlanTarget::Attribute, PlanAction::Add, "email".to_string()),
+            (PlanTarget::Attribute, PlanAction::Remove, "fax".to_string()),
+            (PlanTarget::Entity, PlanAction::Add, "supplier".to_string()),
+            (PlanTarget::Attribute, PlanAction::Add, "name".to_string()),
+        ]);
+        assert_eq!(plan.steps[0].changes[0].field, "pg_cast");
+        assert_eq!(plan.steps[2].changes[0].field, "is_required");
+        assert_eq!(plan.count(PlanAction::Add), 4);
+
+        // Aplicar el bundle dos veces no produce cambios
+        assert!(plan_bundle(&desired, &desired).is_empty());
+    }
+
+    #[test]
+    fn test_plan_reports_unresolved_dependencies_and_reference_changes() {
+        let mut owner = BundleAttribute::for_tests("owner", "ref", 0);
+        owner.reference = Some(BundleReference {
+            entity: "user".to_string(),
+            on_delete: ReferenceDeleteAction::Restrict,
+            display_attribute: None,
+        });
+        let current = SchemaBundle::new(
+            vec![data_type("ref", "uuid")],
+            vec![entity("account", vec![owner.clone()]), entity("user", vec![]), entity("team", vec![])],
+        );
+
+        let mut moved = owner.clone();
+        moved.reference = Some(BundleReference {
+            entity: "team".to_string(),
+            on_delete: ReferenceDeleteAction::Restrict,
+            display_attribute: Some("label".to_string()),
+        });
+        let desired = SchemaBundle::new(
+            Vec::new(),
+            vec![entity("account", vec![moved, BundleAttribute::for_tests("size", "missing", 1)])],
+        );
+
+        let plan = plan_bundle(&current, &desired);
+        assert_eq!(plan.conflicts.len(), 3, "{:?}", plan.conflicts);
+        assert!(plan.conflicts[0].contains("'label'"));
+        assert!(plan.conflicts[1].contains("'user' -> 'team'"));
+        assert!(plan.conflicts[2].contains("'missing'"));
+    }
+}
@@ -0,0 +1,8 @@
+// src/Domain/schema_previews/mod.rs
+// Vista previa de la creación o el cambio de una entidad (en su forma de bundle): reglas
+// que hay que comprobar contra los registros existentes, avisos y definiciones que no
+// se podrían guardar. No modifica nada.
+
+pub mod preview;
+
+pub use preview::{ImpactRule, RuleCheck, RuleImpact, definition_conflicts, preview_warnings, rule_checks};
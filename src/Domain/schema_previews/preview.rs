@@ -0,0 +1,288 @@
+// src/Domain/schema_previews/preview.rs
+//
+// Compara la definición actual de una entidad con la deseada (ambas en su forma de
+// bundle, por nombre) para estimar el impacto del cambio antes de aplicarlo. Los
+// atributos que no están en la definición deseada se consideran retirados.
+
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+
+use crate::Domain::attribute_options::validate_options;
+use crate::Domain::errors::DomainError;
+use crate::Domain::schema_bundles::{BundleAttribute, BundleEntity};
+use crate::Domain::sql_identifiers::{validate_attribute_name, validate_entity_name};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ImpactRule {
+    Required,
+    Regex,
+    Unique,
+}
+
+/// Regla nueva o más estricta que los registros existentes podrían incumplir.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RuleCheck {
+    pub rule: ImpactRule,
+    /// El atributo o, en un grupo de unicidad, los atributos de la clave (por posición).
+    pub attributes: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group: Option<i16>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pattern: Option<String>,
+}
+
+/// Registros que incumplirían una regla (sin los de la papelera). En la unicidad se
+/// cuentan todos los registros que comparten clave.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RuleImpact {
+    #[serde(flatten)]
+    pub check: RuleCheck,
+    pub violations: i64,
+}
+
+/// Reglas de `desired` que hay que comprobar contra los registros de la entidad actual
+/// (`current`, con sus atributos retirados; None si la entidad se crearía). Solo se
+/// incluyen las que cambian y pueden fallar: un atributo nuevo no tiene valores (salvo
+/// el valor por defecto, con el que se rellena y que ya se valida al añadirlo).
+pub fn rule_checks(current: Option<&BundleEntity>, desired: &BundleEntity) -> Vec<RuleCheck> {
+    let mut checks = Vec::new();
+    let mut groups: BTreeMap<i16, Vec<&BundleAttribute>> = BTreeMap::new();
+
+    for attribute in active_attributes(desired) {
+        let stored = current.and_then(|c| c.attribute(&attribute.name));
+        // Un atributo retirado conserva sus valores pero no aplica sus reglas
+        let enforced = stored.filter(|s| !s.retired);
+
+        if attribute.is_required
+            && !enforced.is_some_and(|s| s.is_required)
+            && (stored.is_some() || attribute.default_value.is_none())
+        {
+            checks.push(single(ImpactRule::Required, attribute, None));
+        }
+        if let Some(regex) = pattern(attribute) {
+            if stored.is_some() && enforced.and_then(pattern) != Some(regex) {
+                checks.push(single(ImpactRule::Regex, attribute, Some(regex.to_string())));
+            }
+        }
+        if let Some(group) = unique_group(attribute) {
+            groups.entry(group).or_default().push(attribute);
+        }
+    }
+
+    for (group, members) in groups {
+        // NULL no participa en la unicidad: con un atributo nuevo la clave está incompleta
+        if members.iter().any(|m| current.and_then(|c| c.attribute(&m.name)).is_none()) {
+            continue;
+        }
+        let names: Vec<String> = members.iter().map(|m| m.name.clone()).collect();
+        let before: Vec<String> = current
+            .map(|c| active_attributes(c).into_iter().filter(|a| unique_group(a) == Some(group)).map(|a| a.name.clone()).collect())
+            .unwrap_or_default();
+        if sorted(&before) != sorted(&names) {
+            checks.push(RuleCheck { rule: ImpactRule::Unique, attributes: names, group: Some(group), pattern: None });
+        }
+    }
+    checks
+}
+
+/// Avisos que no impiden el cambio: posiciones repetidas, descripciones ausentes y
+/// atributos nuevos que rellenarían con el mismo valor una clave de unicidad.
+pub fn preview_warnings(current: Option<&BundleEntity>, desired: &BundleEntity) -> Vec<String> {
+    let mut warnings = Vec::new();
+    let attributes = active_attributes(desired);
+
+    let mut positions: BTreeMap<i16, Vec<&str>> = BTreeMap::new();
+    for attribute in &attributes {
+        positions.entry(attribute.position).or_default().push(&attribute.name);
+    }
+    for (position, names) in positions.iter().filter(|(_, names)| names.len() > 1) {
+        warnings.push(format!(
+            "entidad '{}': la posición {} se repite en los atributos {}", desired.name, position, names.join(", ")
+        ));
+    }
+
+    // Sin descripción en la definición se conserva la actual
+    let description = desired.description.as_deref().or_else(|| current.and_then(|c| c.description.as_deref()));
+    if is_blank(description) {
+        warnings.push(format!("entidad '{}': no tiene descripción", desired.name));
+    }
+
+    for attribute in &attributes {
+        if is_blank(attribute.description.as_deref()) {
+            warnings.push(format!("atributo '{}.{}': no tiene descripción", desired.name, attribute.name));
+        }
+        let is_new = current.and_then(|c| c.attribute(&attribute.name)).is_none();
+        if let (true, Some(group), Some(default)) = (is_new && current.is_some(), unique_group(attribute), &attribute.default_value) {
+            warnings.push(format!(
+                "atributo '{}.{}': es nuevo y pertenece al grupo de unicidad {}; todos los registros existentes recibirán el valor por defecto '{}'",
+                desired.name, attribute.name, group, default
+            ));
+        }
+    }
+    warnings
+}
+
+/// Errores de la definición deseada que harían fallar el cambio: nombres que no son
+/// identificadores SQL válidos (solo los nuevos: los guardados se conservan), atributos
+/// repetidos y listas de opciones no válidas.
+pub fn definition_conflicts(current: Option<&BundleEntity>, desired: &BundleEntity) -> Vec<String> {
+    let mut conflicts = Vec::new();
+    if current.is_none() {
+        if let Err(e) = validate_entity_name(&desired.name) {
+            conflicts.push(format!("entidad '{}': {}", desired.name, message(e)));
+        }
+    }
+
+    let mut names = HashSet::new();
+    for attribute in &desired.attributes {
+        if !names.insert(attribute.name.as_str()) {
+            conflicts.push(format!("atributo '{}.{}': aparece más de una vez", desired.name, attribute.name));
+            continue;
+        }
+        if current.and_then(|c| c.attribute(&attribute.name)).is_none() {
+            if let Err(e) = validate_attribute_name(&attribute.name) {
+                conflicts.push(format!("atributo '{}.{}': {}", desired.name, attribute.name, message(e)));
+            }
+        }
+        if let Err(e) = validate_options(&attribute.options) {
+            conflicts.push(format!("atributo '{}.{}': {}", desired.name, attribute.name, message(e)));
+        }
+    }
+    conflicts
+}
+
+/// Atributos activos de la entidad, por posición y nombre.
+fn active_attributes(entity: &BundleEntity) -> Vec<&BundleAttribute> {
+    let mut attributes: Vec<&BundleAttribute> = entity.attributes.iter().filter(|a| !a.retired).collect();
+    attributes.sort_by_key(|a| (a.position, a.name.clone()));
+    attributes
+}
+
+fn single(rule: ImpactRule, attribute: &BundleAttribute, pattern: Option<String>) -> RuleCheck {
+    RuleCheck { rule, attributes: vec![attribute.name.clone()], group: None, pattern }
+}
+
+fn pattern(attribute: &BundleAttribute) -> Option<&str> {
+    attribute.validation_regex.as_deref().filter(|p| !p.is_empty())
+}
+
+/// is_unique NULL o 0 = sin restricción; 1-10 = grupo de unicidad.
+fn unique_group(attribute: &BundleAttribute) -> Option<i16> {
+    attribute.is_unique.filter(|g| (1..=10).contains(g))
+}
+
+fn sorted(names: &[String]) -> Vec<&str> {
+    let mut names: Vec<&str> = names.iter().map(String::as_str).collect();
+    names.sort_unstable();
+    names
+}
+
+fn is_blank(text: Option<&str>) -> bool {
+    text.is_none_or(|t| t.trim().is_empty())
+}
+
+fn message(error: DomainError) -> String {
+    match error {
+        DomainError::ValidationError(message) => message,
+        other => other.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Atributo de texto con descripción (sin ella se genera un aviso).
+    fn attribute(name: &str, position: i16) -> BundleAttribute {
+        let mut attribute = BundleAttribute::for_tests(name, "text", position);
+        attribute.description = Some(format!("{} del pedido", name));
+        attribute
+    }
+
+    fn entity(attributes: Vec<BundleAttribute>) -> BundleEntity {
+        BundleEntity {
+            name: "order".to_string(),
+            description: Some("Pedidos".to_string()),
+            search_language: "simple".to_string(),
+            assign_view: true,
+            attributes,
+        }
+    }
+
+    #[test]
+    fn only_tightened_rules_on_stored_values_are_checked() {
+        let mut retired = attribute("legacy", 3);
+        retired.retired = true;
+        let current = entity(vec![attribute("code", 0), attribute("customer", 1), retired]);
+
+        let mut code = attribute("code", 0);
+        code.is_required = true;
+        code.validation_regex = Some("[A-Z]+".to_string());
+        code.is_unique = Some(1);
+        let mut customer = attribute("customer", 1);
+        customer.is_unique = Some(1);
+        let mut with_default = attribute("channel", 2);
+        with_default.is_required = true;
+        with_default.default_value = Some("web".to_string());
+        let mut without_default = attribute("region", 4);
+        without_default.is_required = true;
+        without_default.validation_regex = Some("[a-z]+".to_string());
+        let mut legacy = attribute("legacy", 3);
+        legacy.is_required = true;
+        let desired = entity(vec![code, customer, with_default, legacy, without_default]);
+
+        let checks = rule_checks(Some(&current), &desired);
+        let summary: Vec<(ImpactRule, Vec<&str>)> = checks
+            .iter()
+            .map(|c| (c.rule, c.attributes.iter().map(String::as_str).collect()))
+            .collect();
+        assert_eq!(summary, vec![
+            (ImpactRule::Required, vec!["code"]),
+            (ImpactRule::Regex, vec!["code"]),
+            (ImpactRule::Required, vec!["legacy"]),
+            (ImpactRule::Required, vec!["region"]),
+            (ImpactRule::Unique, vec!["code", "customer"]),
+        ]);
+        assert_eq!(checks[1].pattern.as_deref(), Some("[A-Z]+"));
+        assert_eq!(checks[4].group, Some(1));
+
+        // Sin cambios en las reglas no hay nada que comprobar
+        assert!(rule_checks(Some(&desired), &desired).is_empty());
+    }
+
+    #[test]
+    fn warnings_report_positions_descriptions_and_backfilled_keys() {
+        let current = entity(vec![attribute("code", 0)]);
+        let mut undocumented = attribute("total", 0);
+        undocumented.description = None;
+        let mut keyed = attribute("channel", 1);
+        keyed.is_unique = Some(2);
+        keyed.default_value = Some("web".to_string());
+        let mut desired = entity(vec![attribute("code", 0), undocumented, keyed]);
+        desired.description = None;
+
+        let warnings = preview_warnings(Some(&current), &desired);
+        assert_eq!(warnings.len(), 3, "{:?}", warnings);
+        assert!(warnings[0].contains("posición 0") && warnings[0].contains("code, total"));
+        assert!(warnings[1].contains("'order.total'"));
+        assert!(warnings[2].contains("grupo de unicidad 2"));
+
+        // Una entidad nueva sin descripción también se avisa
+        assert!(preview_warnings(None, &desired).iter().any(|w| w == "entidad 'order': no tiene descripción"));
+    }
+
+    #[test]
+    fn conflicts_cover_new_names_and_duplicates() {
+        let mut desired = entity(vec![attribute("code", 0), attribute("status", 1), attribute("code", 2)]);
+        desired.name = "select".to_string();
+        let conflicts = definition_conflicts(None, &desired);
+        assert_eq!(conflicts.len(), 3, "{:?}", conflicts);
+
+        // Un nombre ya guardado se conserva aunque no cumpla las reglas actuales
+        let mut current = entity(vec![attribute("status", 1)]);
+        current.name = "select".to_string();
+        desired.attributes.pop();
+        assert!(definition_conflicts(Some(&current), &desired).is_empty());
+    }
+}
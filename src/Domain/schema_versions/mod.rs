@@ -0,0 +1,9 @@
+// src/Domain/schema_versions/mod.rs
+// Versiones (instantáneas inmutables) de la definición de una entidad lógica.
+
+pub mod snapshot;
+
+pub use snapshot::{
+    SchemaSnapshot, AttributeSnapshot, SchemaDiff, AttributeDiff, AttributeChangeKind, FieldChange,
+    diff_snapshots,
+};
@@ -0,0 +1,216 @@
+// src/Domain/schema_versions/snapshot.rs
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+use crate::Domain::attribute_options::AttributeOption;
+use crate::Domain::attribute_references::AttributeReference;
+
+/// Definición de un atributo en el momento de la instantánea.
+/// Las instantáneas anteriores a las listas de opciones no incluyen
+/// `show_option_label` ni `options`, las anteriores a las referencias, `reference`,
+/// y las anteriores a la búsqueda de texto, `is_searchable`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AttributeSnapshot {
+    pub attribute_id: Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    pub data_type_id: Uuid,
+    pub data_type_name: String,
+    pub position: i16,
+    pub is_required: bool,
+    pub is_unique: Option<i16>,
+    pub default_value: Option<String>,
+    pub validation_regex: Option<String>,
+    #[serde(default)]
+    pub show_option_label: bool,
+    #[serde(default)]
+    pub options: Vec<AttributeOption>,
+    #[serde(default)]
+    pub reference: Option<AttributeReference>,
+    #[serde(default)]
+    pub is_searchable: bool,
+    pub status: i16,
+}
+
+/// Definición completa de una entidad (incluye los atributos retirados, con status 0).
+/// Es el contenido de `entity_schema_versions.definition`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SchemaSnapshot {
+    pub entity_name: String,
+    pub attributes: Vec<AttributeSnapshot>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AttributeChangeKind {
+    Added,
+    Removed,
+    Modified,
+}
+
+/// Valor de un campo antes y después del cambio.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FieldChange {
+    pub field: String,
+    pub from: Value,
+    pub to: Value,
+}
+
+/// Diferencias de un atributo entre dos versiones (se identifica por su ID,
+/// de modo que un renombrado aparece como modificación del campo `name`).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AttributeDiff {
+    pub attribute_id: Uuid,
+    pub name: String,
+    pub change: AttributeChangeKind,
+    pub fields: Vec<FieldChange>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SchemaDiff {
+    pub entity_name: Option<FieldChange>,
+    pub attributes: Vec<AttributeDiff>,
+}
+
+impl AttributeSnapshot {
+    /// Campos comparables del atributo, en el orden en que se informan.
+    fn fields(&self) -> [(&'static str, Value); 13] {
+        [
+            ("name", json!(self.name)),
+            ("description", json!(self.description)),
+            ("data_type", json!(self.data_type_name)),
+            ("position", json!(self.position)),
+            ("is_required", json!(self.is_required)),
+            ("is_unique", json!(self.is_unique)),
+            ("default_value", json!(self.default_value)),
+            ("validation_regex", json!(self.validation_regex)),
+            ("show_option_label", json!(self.show_option_label)),
+            ("options", json!(self.options)),
+            ("reference", json!(self.reference)),
+            ("is_searchable", json!(self.is_searchable)),
+            ("status", json!(self.status)),
+        ]
+    }
+}
+
+/// Compara dos definiciones atributo por atributo. Los atributos se devuelven
+/// en el orden de posición de la versión de destino (los eliminados al final).
+pub fn diff_snapshots(from: &SchemaSnapshot, to: &SchemaSnapshot) -> SchemaDiff {
+    let entity_name = (from.entity_name != to.entity_name).then(|| FieldChange {
+        field: "entity_name".to_string(),
+        from: json!(from.entity_name),
+        to: json!(to.entity_name),
+    });
+
+    let mut target: Vec<&AttributeSnapshot> = to.attributes.iter().collect();
+    target.sort_by_key(|a| (a.position, a.name.clone()));
+
+    let mut attributes = Vec::new();
+    for after in target {
+        match from.attributes.iter().find(|a| a.attribute_id == after.attribute_id) {
+            None => attributes.push(AttributeDiff {
+                attribute_id: after.attribute_id,
+                name: after.name.clone(),
+                change: AttributeChangeKind::Added,
+                fields: after.fields().into_iter()
+                    .map(|(field, value)| FieldChange { field: field.to_string(), from: Value::Null, to: value })
+                    .collect(),
+            }),
+            Some(before) => {
+                let fields: Vec<FieldChange> = before.fields().into_iter()
+                    .zip(after.fields())
+                    .filter(|((_, old), (_, new))| old != new)
+                    .map(|((field, old), (_, new))| FieldChange { field: field.to_string(), from: old, to: new })
+                    .collect();
+                if !fields.is_empty() {
+                    attributes.push(AttributeDiff {
+                        attribute_id: after.attribute_id,
+                        name: after.name.clone(),
+                        change: AttributeChangeKind::Modified,
+                        fields,
+                    });
+                }
+            }
+        }
+    }
+
+    for before in &from.attributes {
+        if !to.attributes.iter().any(|a| a.attribute_id == before.attribute_id) {
+            attributes.push(AttributeDiff {
+                attribute_id: before.attribute_id,
+                name: before.name.clone(),
+                change: AttributeChangeKind::Removed,
+                fields: before.fields().into_iter()
+                    .map(|(field, value)| FieldChange { field: field.to_string(), from: value, to: Value::Null })
+                    .collect(),
+            });
+        }
+    }
+
+    SchemaDiff { entity_name, attributes }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attribute(name: &str, data_type: &str, position: i16) -> AttributeSnapshot {
+        AttributeSnapshot {
+            attribute_id: Uuid::new_v4(),
+            name: name.to_string(),
+            description: None,
+            data_type_id: Uuid::new_v4(),
+            data_type_name: data_type.to_string(),
+            position,
+            is_required: false,
+            is_unique: None,
+            default_value: None,
+            validation_regex: None,
+            show_option_label: false,
+            options: Vec::new(),
+            reference: None,
+            is_searchable: false,
+            status: 1,
+        }
+    }
+
+    #[test]
+    fn test_diff_reports_added_removed_and_modified_attributes() {
+        let code = attribute("code", "string", 0);
+        let notes = attribute("notes", "text", 1);
+        let from = SchemaSnapshot { entity_name: "product".to_string(), attributes: vec![code.clone(), notes.clone()] };
+
+        let mut renamed = code.clone();
+        renamed.name = "sku".to_string();
+        renamed.is_required = true;
+        let price = attribute("price", "numeric", 2);
+        let to = SchemaSnapshot { entity_name: "product".to_string(), attributes: vec![renamed, price.clone()] };
+
+        let diff = diff_snapshots(&from, &to);
+        assert_eq!(diff.entity_name, None);
+        assert_eq!(diff.attributes.len(), 3);
+
+        assert_eq!(diff.attributes[0].attribute_id, code.attribute_id);
+        assert_eq!(diff.attributes[0].change, AttributeChangeKind::Modified);
+        let changed: Vec<&str> = diff.attributes[0].fields.iter().map(|f| f.field.as_str()).collect();
+        assert_eq!(changed, vec!["name", "is_required"]);
+
+        assert_eq!(diff.attributes[1].attribute_id, price.attribute_id);
+        assert_eq!(diff.attributes[1].change, AttributeChangeKind::Added);
+        assert_eq!(diff.attributes[2].attribute_id, notes.attribute_id);
+        assert_eq!(diff.attributes[2].change, AttributeChangeKind::Removed);
+    }
+
+    #[test]
+    fn test_diff_of_identical_snapshots_is_empty() {
+        let snapshot = SchemaSnapshot {
+            entity_name: "customer".to_string(),
+            attributes: vec![attribute("name", "string", 0)],
+        };
+        let diff = diff_snapshots(&snapshot, &snapshot.clone());
+        assert!(diff.entity_name.is_none());
+        assert!(diff.attributes.is_empty());
+    }
+}
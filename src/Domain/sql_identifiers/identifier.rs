@@ -0,0 +1,154 @@
+// src/Domain/sql_identifiers/identifier.rs
+
+use uuid::Uuid;
+
+use crate::Domain::errors::{DomainError, DomainResult};
+
+/// Longitud máxima de un identificador en Postgres (NAMEDATALEN - 1). Los más largos
+/// se truncan sin error, y dos nombres distintos pueden acabar en el mismo objeto.
+pub const MAX_IDENTIFIER_BYTES: usize = 63;
+
+/// Prefijo de la vista de una entidad (`view_<entidad>`).
+pub const VIEW_NAME_PREFIX: &str = "view_";
+/// Sufijo del índice único de una vista materializada (`view_<entidad>_id_key`).
+pub const VIEW_INDEX_SUFFIX: &str = "_id_key";
+/// Sufijo de la columna con la etiqueta de la opción de una enumeración.
+pub const OPTION_LABEL_SUFFIX: &str = "_label";
+/// Sufijo de la columna con el atributo a mostrar de una referencia.
+pub const REFERENCE_DISPLAY_SUFFIX: &str = "_display";
+
+/// Nombre más largo de una entidad: su vista y el índice de la vista materializada
+/// deben caber en un identificador.
+pub const MAX_ENTITY_NAME_BYTES: usize = MAX_IDENTIFIER_BYTES - VIEW_NAME_PREFIX.len() - VIEW_INDEX_SUFFIX.len();
+/// Nombre más largo de un atributo: sus columnas derivadas deben caber en un identificador.
+pub const MAX_ATTRIBUTE_NAME_BYTES: usize = MAX_IDENTIFIER_BYTES - REFERENCE_DISPLAY_SUFFIX.len();
+
+/// Columnas de sistema de la vista de una entidad: ningún atributo puede llamarse así.
+pub const VIEW_SYSTEM_COLUMNS: &[&str] = &["id", "created_by", "created_at", "updated_by", "updated_at", "status"];
+
+/// Palabras reservadas de Postgres (categorías "reserved" y "reserved (can be function or type)").
+const RESERVED_WORDS: &[&str] = &[
+    "all", "analyse", "analyze", "and", "any", "array", "as", "asc", "asymmetric", "authorization", "binary",
+    "both", "case", "cast", "check", "collate", "collation", "column", "concurrently", "constraint", "create",
+    "cross", "current_catalog", "current_date", "current_role", "current_schema", "current_time",
+    "current_timestamp", "current_user", "default", "deferrable", "desc", "distinct", "do", "else", "end",
+    "except", "false", "fetch", "for", "foreign", "freeze", "from", "full", "grant", "group", "having", "ilike",
+    "in", "initially", "inner", "intersect", "into", "is", "isnull", "join", "lateral", "leading", "left", "like",
+    "limit", "localtime", "localtimestamp", "natural", "not", "notnull", "null", "offset", "on", "only", "or",
+    "order", "outer", "overlaps", "placing", "primary", "references", "returning", "right", "select",
+    "session_user", "similar", "some", "symmetric", "system_user", "table", "tablesample", "then", "to",
+    "trailing", "true", "union", "unique", "user", "using", "variadic", "verbose", "when", "where", "window",
+    "with",
+];
+
+/// Comprueba el formato de un nombre que acaba en un identificador SQL: letras ASCII,
+/// dígitos y `_`, sin empezar por dígito, hasta `max_bytes` y sin ser palabra reservada.
+fn validate_name(kind: &str, name: &str, max_bytes: usize) -> DomainResult<()> {
+    let invalid = |reason: String| DomainError::ValidationError(format!("El nombre de {} '{}' no es válido: {}", kind, name, reason));
+
+    let mut chars = name.chars();
+    match chars.next() {
+        None => return Err(invalid("está vacío".to_string())),
+        Some(first) if !(first.is_ascii_alphabetic() || first == '_') => {
+            return Err(invalid("debe empezar por una letra o '_'".to_string()));
+        },
+        Some(_) => {},
+    }
+    if let Some(other) = chars.find(|c| !(c.is_ascii_alphanumeric() || *c == '_')) {
+        return Err(invalid(format!("contiene '{}' (se admiten letras sin acentos, dígitos y '_')", other)));
+    }
+    if name.len() > max_bytes {
+        return Err(invalid(format!("tiene {} caracteres (máximo {})", name.len(), max_bytes)));
+    }
+    if RESERVED_WORDS.iter().any(|word| word.eq_ignore_ascii_case(name)) {
+        return Err(invalid("es una palabra reservada de SQL".to_string()));
+    }
+    Ok(())
+}
+
+/// Valida el nombre de una entidad lógica antes de guardarlo.
+pub fn validate_entity_name(name: &str) -> DomainResult<()> {
+    validate_name("entidad", name, MAX_ENTITY_NAME_BYTES)
+}
+
+/// Valida el nombre de un atributo antes de guardarlo; tampoco puede coincidir con una
+/// columna de sistema de la vista.
+pub fn validate_attribute_name(name: &str) -> DomainResult<()> {
+    validate_name("atributo", name, MAX_ATTRIBUTE_NAME_BYTES)?;
+    ensure_not_system_column(name)
+}
+
+/// Rechaza una columna de la vista con el nombre de una columna de sistema.
+pub fn ensure_not_system_column(column: &str) -> DomainResult<()> {
+    if VIEW_SYSTEM_COLUMNS.iter().any(|system| system.eq_ignore_ascii_case(column)) {
+        return Err(DomainError::ValidationError(format!(
+            "'{}' es una columna de sistema de la vista (reservadas: {})", column, VIEW_SYSTEM_COLUMNS.join(", ")
+        )));
+    }
+    Ok(())
+}
+
+/// Comprueba que un identificador generado (p. ej. a partir de un nombre guardado antes de
+/// estas reglas) cabe sin que Postgres lo trunque.
+pub fn ensure_identifier_fits(identifier: &str) -> DomainResult<()> {
+    if identifier.is_empty() || identifier.len() > MAX_IDENTIFIER_BYTES {
+        return Err(DomainError::ValidationError(format!(
+            "El identificador '{}' tiene {} bytes (entre 1 y {})", identifier, identifier.len(), MAX_IDENTIFIER_BYTES
+        )));
+    }
+    Ok(())
+}
+
+/// Identificador SQL entre comillas dobles (las comillas internas se duplican).
+pub fn quote_identifier(identifier: &str) -> String {
+    format!("\"{}\"", identifier.replace('"', "\"\""))
+}
+
+/// Literal de texto SQL (comillas simples duplicadas).
+pub fn quote_literal(text: &str) -> String {
+    format!("'{}'", text.replace('\'', "''"))
+}
+
+/// Literal SQL de un UUID (siempre en su forma canónica con guiones).
+pub fn uuid_literal(id: Uuid) -> String {
+    format!("'{}'", id.hyphenated())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_plain_names_and_rejects_the_rest() {
+        assert!(validate_entity_name("product").is_ok());
+        assert!(validate_entity_name("_Order_2024").is_ok());
+        assert!(validate_entity_name("").is_err());
+        assert!(validate_entity_name("2fast").is_err());
+        assert!(validate_entity_name("my product").is_err());
+        assert!(validate_entity_name("x\"; DROP TABLE tuplas; --").is_err());
+        assert!(validate_entity_name("producción").is_err());
+        assert!(validate_entity_name("Select").is_err());
+        assert!(validate_entity_name(&"e".repeat(MAX_ENTITY_NAME_BYTES)).is_ok());
+        assert!(validate_entity_name(&"e".repeat(MAX_ENTITY_NAME_BYTES + 1)).is_err());
+    }
+
+    #[test]
+    fn attribute_names_cannot_shadow_system_columns() {
+        assert!(validate_attribute_name("price").is_ok());
+        assert!(validate_attribute_name("status").is_err());
+        assert!(validate_attribute_name("Created_At").is_err());
+        assert!(validate_attribute_name("user").is_err());
+        let longest = "a".repeat(MAX_ATTRIBUTE_NAME_BYTES);
+        assert!(validate_attribute_name(&longest).is_ok());
+        assert!(ensure_identifier_fits(&format!("{}{}", longest, REFERENCE_DISPLAY_SUFFIX)).is_ok());
+        assert!(validate_attribute_name(&format!("{}a", longest)).is_err());
+    }
+
+    #[test]
+    fn quoting_escapes_embedded_quotes() {
+        assert_eq!(quote_identifier("view_a\"b"), "\"view_a\"\"b\"");
+        assert_eq!(quote_literal("O'Brien"), "'O''Brien'");
+        let id = Uuid::new_v4();
+        assert_eq!(uuid_literal(id), format!("'{}'", id));
+    }
+}
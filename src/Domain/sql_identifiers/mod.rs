@@ -0,0 +1,12 @@
+// src/Domain/sql_identifiers/mod.rs
+// Identificadores y literales del SQL dinámico (vistas, tablas físicas): validación de
+// los nombres de entidades y atributos y entrecomillado de todo lo que se interpola.
+
+pub mod identifier;
+
+pub use identifier::{
+    MAX_ATTRIBUTE_NAME_BYTES, MAX_ENTITY_NAME_BYTES, MAX_IDENTIFIER_BYTES, OPTION_LABEL_SUFFIX,
+    REFERENCE_DISPLAY_SUFFIX, VIEW_INDEX_SUFFIX, VIEW_NAME_PREFIX, VIEW_SYSTEM_COLUMNS, ensure_identifier_fits,
+    ensure_not_system_column, quote_identifier, quote_literal, uuid_literal, validate_attribute_name,
+    validate_entity_name,
+};
@@ -0,0 +1,7 @@
+// src/Domain/trash/mod.rs
+// Papelera: los registros, entidades lógicas y usuarios eliminados se conservan
+// (deleted_at / deleted_by) hasta que se restauran o se purgan.
+
+pub mod retention;
+
+pub use retention::{purge_cutoff, TrashKind, MAX_RETENTION_DAYS};
@@ -0,0 +1,61 @@
+// src/Domain/trash/retention.rs
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::Domain::errors::{DomainError, DomainResult};
+
+/// Antigüedad máxima que se puede indicar al purgar (unos diez años).
+pub const MAX_RETENTION_DAYS: i64 = 3650;
+
+/// Tipo de elemento de la papelera.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TrashKind {
+    Record,
+    LogicalEntity,
+    User,
+}
+
+impl TrashKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TrashKind::Record => "record",
+            TrashKind::LogicalEntity => "logical_entity",
+            TrashKind::User => "user",
+        }
+    }
+}
+
+/// Fecha límite de una purga por antigüedad: se purgan los elementos eliminados
+/// antes de `now - older_than_days`. Con 0 se purga toda la papelera.
+pub fn purge_cutoff(now: DateTime<Utc>, older_than_days: i64) -> DomainResult<DateTime<Utc>> {
+    if !(0..=MAX_RETENTION_DAYS).contains(&older_than_days) {
+        return Err(DomainError::ValidationError(format!(
+            "La antigüedad de la purga debe estar entre 0 y {} días (recibido: {})",
+            MAX_RETENTION_DAYS, older_than_days
+        )));
+    }
+    Ok(now - Duration::days(older_than_days))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn cutoff_is_relative_to_now() {
+        let now = Utc.with_ymd_and_hms(2024, 3, 31, 12, 0, 0).unwrap();
+        assert_eq!(purge_cutoff(now, 30).unwrap(), Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap());
+        assert_eq!(purge_cutoff(now, 0).unwrap(), now);
+    }
+
+    #[test]
+    fn rejects_out_of_range_ages() {
+        let now = Utc::now();
+        assert!(purge_cutoff(now, -1).is_err());
+        assert!(purge_cutoff(now, MAX_RETENTION_DAYS + 1).is_err());
+        assert!(purge_cutoff(now, MAX_RETENTION_DAYS).is_ok());
+    }
+}
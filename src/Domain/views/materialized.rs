@@ -0,0 +1,210 @@
+// src/Domain/views/materialized.rs
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::Domain::errors::{DomainError, DomainResult};
+use crate::Domain::views::view_generator::ViewPivotStrategy;
+
+/// Intervalo máximo del refresco programado (una semana).
+pub const MAX_REFRESH_INTERVAL_SECS: i32 = 7 * 24 * 3600;
+
+/// Tiempo tras el que un refresco que sigue "en curso" se considera abandonado
+/// (p. ej. el proceso se detuvo a mitad) y se puede volver a lanzar.
+pub const STALE_REFRESH_MINUTES: i64 = 30;
+
+/// Opciones de la vista de una entidad lógica. Por defecto, vista normal con pivote automático.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EntityViewSettings {
+    pub materialized: bool,
+    /// Refresco programado cada N segundos (solo vistas materializadas; None = sin programar)
+    pub refresh_interval_secs: Option<i32>,
+    /// Estrategia de pivote de los valores (None = automática según el número de atributos)
+    pub pivot_strategy: Option<ViewPivotStrategy>,
+}
+
+impl EntityViewSettings {
+    pub fn validate(&self) -> DomainResult<()> {
+        if let Some(interval) = self.refresh_interval_secs {
+            if !self.materialized {
+                return Err(DomainError::ValidationError(
+                    "El refresco programado solo se admite en vistas materializadas".to_string(),
+                ));
+            }
+            if !(1..=MAX_REFRESH_INTERVAL_SECS).contains(&interval) {
+                return Err(DomainError::ValidationError(format!(
+                    "El intervalo de refresco debe estar entre 1 y {} segundos (recibido: {})",
+                    MAX_REFRESH_INTERVAL_SECS, interval
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Estado del refresco de una vista materializada.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ViewRefreshStatus {
+    #[default]
+    Never,
+    Refreshing,
+    Ready,
+    Failed,
+}
+
+impl ViewRefreshStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ViewRefreshStatus::Never => "never",
+            ViewRefreshStatus::Refreshing => "refreshing",
+            ViewRefreshStatus::Ready => "ready",
+            ViewRefreshStatus::Failed => "failed",
+        }
+    }
+
+    pub fn parse(value: &str) -> DomainResult<Self> {
+        match value {
+            "never" => Ok(ViewRefreshStatus::Never),
+            "refreshing" => Ok(ViewRefreshStatus::Refreshing),
+            "ready" => Ok(ViewRefreshStatus::Ready),
+            "failed" => Ok(ViewRefreshStatus::Failed),
+            other => Err(DomainError::InvalidState(format!("Estado de refresco desconocido: '{}'", other))),
+        }
+    }
+}
+
+/// Último refresco de una vista materializada.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ViewRefreshState {
+    pub status: ViewRefreshStatus,
+    pub last_refresh_started_at: Option<DateTime<Utc>>,
+    pub last_refreshed_at: Option<DateTime<Utc>>,
+    pub last_refresh_duration_ms: Option<i64>,
+    pub last_refresh_error: Option<String>,
+}
+
+/// Motivo de un refresco.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RefreshTrigger {
+    /// Solicitado por la API
+    Manual,
+    /// Ha pasado el intervalo programado
+    Schedule,
+    /// Hubo escrituras de registros y ha pasado el margen sin nuevas escrituras
+    Writes,
+}
+
+/// Vista materializada candidata a refrescarse, con la última escritura de registros
+/// de su entidad.
+#[derive(Debug, Clone)]
+pub struct MaterializedViewCandidate {
+    pub entity_id: Uuid,
+    pub view_name: String,
+    pub settings: EntityViewSettings,
+    pub state: ViewRefreshState,
+    pub last_write_at: Option<DateTime<Utc>>,
+}
+
+/// Decide si una vista materializada debe refrescarse ahora y por qué.
+/// Las escrituras posteriores al inicio del último refresco no están en la vista; se
+/// refresca cuando llevan `debounce` sin repetirse (None = no se refresca por escrituras).
+/// El intervalo programado se cuenta desde el inicio del último refresco.
+pub fn refresh_due(
+    settings: &EntityViewSettings,
+    state: &ViewRefreshState,
+    last_write_at: Option<DateTime<Utc>>,
+    debounce: Option<Duration>,
+    now: DateTime<Utc>,
+) -> Option<RefreshTrigger> {
+    if !settings.materialized {
+        return None;
+    }
+
+    let last_start = state.last_refresh_started_at;
+    if state.status == ViewRefreshStatus::Refreshing
+        && last_start.is_some_and(|start| now - start < Duration::minutes(STALE_REFRESH_MINUTES))
+    {
+        return None;
+    }
+
+    if let (Some(written_at), Some(debounce)) = (last_write_at, debounce) {
+        let pending = last_start.is_none_or(|start| written_at > start);
+        if pending && now - written_at >= debounce {
+            return Some(RefreshTrigger::Writes);
+        }
+    }
+
+    let interval = settings.refresh_interval_secs?;
+    match last_start {
+        Some(start) if now - start < Duration::seconds(i64::from(interval)) => None,
+        _ => Some(RefreshTrigger::Schedule),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(minute: u32, second: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 5, 1, 10, minute, second).unwrap()
+    }
+
+    fn refreshed_at(start: DateTime<Utc>) -> ViewRefreshState {
+        ViewRefreshState {
+            status: ViewRefreshStatus::Ready,
+            last_refresh_started_at: Some(start),
+            last_refreshed_at: Some(start),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn writes_wait_for_the_debounce_window() {
+        let settings = EntityViewSettings { materialized: true, refresh_interval_secs: None, ..Default::default() };
+        let state = refreshed_at(at(0, 0));
+        let debounce = Some(Duration::seconds(60));
+
+        assert_eq!(refresh_due(&settings, &state, Some(at(5, 0)), debounce, at(5, 30)), None);
+        assert_eq!(refresh_due(&settings, &state, Some(at(5, 0)), debounce, at(6, 0)), Some(RefreshTrigger::Writes));
+        // Escrituras ya incluidas en el último refresco
+        assert_eq!(refresh_due(&settings, &refreshed_at(at(5, 10)), Some(at(5, 0)), debounce, at(9, 0)), None);
+        // Sin margen configurado las escrituras no provocan refrescos
+        assert_eq!(refresh_due(&settings, &state, Some(at(5, 0)), None, at(9, 0)), None);
+    }
+
+    #[test]
+    fn schedule_counts_from_the_last_refresh() {
+        let settings = EntityViewSettings { materialized: true, refresh_interval_secs: Some(600), ..Default::default() };
+        let state = refreshed_at(at(0, 0));
+        let debounce = Some(Duration::seconds(60));
+
+        assert_eq!(refresh_due(&settings, &state, None, debounce, at(9, 59)), None);
+        assert_eq!(refresh_due(&settings, &state, None, debounce, at(10, 0)), Some(RefreshTrigger::Schedule));
+        assert_eq!(refresh_due(&settings, &ViewRefreshState::default(), None, debounce, at(0, 0)), Some(RefreshTrigger::Schedule));
+    }
+
+    #[test]
+    fn skips_plain_views_and_refreshes_in_progress() {
+        let debounce = Some(Duration::seconds(0));
+        let plain = EntityViewSettings::default();
+        assert_eq!(refresh_due(&plain, &ViewRefreshState::default(), Some(at(0, 0)), debounce, at(30, 0)), None);
+
+        let settings = EntityViewSettings { materialized: true, refresh_interval_secs: Some(1), ..Default::default() };
+        let in_progress = ViewRefreshState { status: ViewRefreshStatus::Refreshing, ..refreshed_at(at(0, 0)) };
+        assert_eq!(refresh_due(&settings, &in_progress, Some(at(1, 0)), debounce, at(10, 0)), None);
+        // Un refresco abandonado no bloquea los siguientes
+        assert_eq!(refresh_due(&settings, &in_progress, Some(at(1, 0)), debounce, at(40, 0)), Some(RefreshTrigger::Writes));
+    }
+
+    #[test]
+    fn validates_settings() {
+        assert!(EntityViewSettings { materialized: false, refresh_interval_secs: Some(60), ..Default::default() }.validate().is_err());
+        assert!(EntityViewSettings { materialized: true, refresh_interval_secs: Some(0), ..Default::default() }.validate().is_err());
+        assert!(EntityViewSettings { materialized: true, refresh_interval_secs: Some(60), ..Default::default() }.validate().is_ok());
+        assert!(EntityViewSettings::default().validate().is_ok());
+    }
+}
@@ -0,0 +1,14 @@
+// src/Domain/views/mod.rs
+// Vistas SQL por entidad lógica (una columna por atributo), normales o materializadas.
+
+pub mod materialized;
+pub mod repository;
+pub mod view_generator;
+
+pub use materialized::{
+    refresh_due, EntityViewSettings, MaterializedViewCandidate, RefreshTrigger, ViewRefreshState, ViewRefreshStatus,
+};
+pub use repository::ViewRepository;
+pub use view_generator::{
+    AttributeInfo, ReferenceDisplay, ViewPivotStrategy, generate_view_sql, materialized_view_index_sql, view_name_for,
+};
@@ -1,7 +1,37 @@
 use async_trait::async_trait;
-use crate::Domain::error::DomainError;
+use uuid::Uuid;
+use crate::Domain::errors::DomainError;
+use crate::Domain::views::materialized::{EntityViewSettings, MaterializedViewCandidate, ViewRefreshState};
 
 #[async_trait]
 pub trait ViewRepository: Send + Sync {
+    /// Crea la vista o la reemplaza por completo (las columnas pueden cambiar de nombre u orden).
+    /// Si existía como vista materializada, se elimina antes.
     async fn create_or_replace_view(&self, view_name: &str, view_sql: &str) -> Result<(), DomainError>;
+
+    /// Crea la vista materializada (poblada) y su índice único, sustituyendo la vista
+    /// existente del mismo nombre sea del tipo que sea.
+    async fn create_materialized_view(&self, view_name: &str, view_sql: &str, index_sql: &str) -> Result<(), DomainError>;
+
+    /// Refresca la vista materializada sin bloquear las lecturas (`CONCURRENTLY`).
+    async fn refresh_materialized_view(&self, view_name: &str) -> Result<(), DomainError>;
+
+    /// Elimina la vista (normal o materializada) si existe.
+    async fn drop_view(&self, view_name: &str) -> Result<(), DomainError>;
+
+    /// Opciones de la vista de la entidad y estado del último refresco
+    /// (valores por defecto si nunca se configuraron).
+    async fn find_view_settings(&self, entity_id: Uuid) -> Result<(EntityViewSettings, ViewRefreshState), DomainError>;
+
+    /// Guarda las opciones de la vista. Al cambiar de tipo de vista se reinicia el estado del refresco.
+    async fn save_view_settings(&self, entity_id: Uuid, settings: &EntityViewSettings) -> Result<(), DomainError>;
+
+    /// Marca el inicio de un refresco.
+    async fn mark_refresh_started(&self, entity_id: Uuid) -> Result<(), DomainError>;
+
+    /// Marca el fin del refresco en curso: correcto o con el error producido.
+    async fn mark_refresh_finished(&self, entity_id: Uuid, error: Option<&str>) -> Result<(), DomainError>;
+
+    /// Vistas materializadas de entidades activas, con la última escritura de sus registros.
+    async fn find_materialized_views(&self) -> Result<Vec<MaterializedViewCandidate>, DomainError>;
 }
@@ -0,0 +1,342 @@
+use crate::Domain::errors::DomainError;
+use crate::Domain::data_types::DataTypeStorage;
+use crate::Domain::physical_tables::{StorageMode, physical_column_name, physical_table_name};
+use crate::Domain::sql_identifiers::{
+    OPTION_LABEL_SUFFIX, REFERENCE_DISPLAY_SUFFIX, VIEW_INDEX_SUFFIX, VIEW_NAME_PREFIX, ensure_identifier_fits,
+    ensure_not_system_column, quote_identifier, uuid_literal,
+};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+// Estructura auxiliar para pasar datos necesarios
+pub struct AttributeInfo<'a> {
+   pub attribute_id: Uuid,
+   pub name: &'a str,
+   pub position: i16,
+   pub storage: &'a DataTypeStorage, // Columna y cast según el registro de tipos de datos
+   pub show_option_label: bool, // Enumeraciones: añade la columna "<nombre>_label"
+   pub reference_display: Option<ReferenceDisplay<'a>>, // Referencias: añade la columna "<nombre>_display"
+}
+
+/// Atributo de la entidad referenciada que se muestra junto al ID de la referencia.
+pub struct ReferenceDisplay<'a> {
+   pub attribute_id: Uuid,
+   pub storage: &'a DataTypeStorage,
+   pub entity_id: Uuid, // Entidad referenciada y dónde guarda sus valores
+   pub entity_storage: StorageMode,
+}
+
+/// Nombre de la columna con la etiqueta de la opción de un atributo de tipo enumeración.
+pub fn option_label_column(attribute_name: &str) -> String {
+    format!("{}{}", attribute_name, OPTION_LABEL_SUFFIX)
+}
+
+/// Nombre de la columna con el atributo a mostrar del registro referenciado.
+pub fn reference_display_column(attribute_name: &str) -> String {
+    format!("{}{}", attribute_name, REFERENCE_DISPLAY_SUFFIX)
+}
+
+/// Nombre de la vista asociada a una entidad lógica.
+pub fn view_name_for(entity_name: &str) -> String {
+    format!("{}{}", VIEW_NAME_PREFIX, entity_name)
+}
+
+/// Nombre del índice único sobre el ID de la tupla de una vista materializada
+/// (necesario para `REFRESH MATERIALIZED VIEW CONCURRENTLY`).
+pub fn materialized_view_index_name(entity_name: &str) -> String {
+    format!("{}{}", view_name_for(entity_name), VIEW_INDEX_SUFFIX)
+}
+
+/// DDL del índice único de la vista materializada de una entidad.
+pub fn materialized_view_index_sql(entity_name: &str) -> String {
+    format!(
+        "CREATE UNIQUE INDEX {} ON {} (id);",
+        quote_identifier(&materialized_view_index_name(entity_name)),
+        quote_identifier(&view_name_for(entity_name))
+    )
+}
+
+/// A partir de este número de atributos activos, la estrategia automática pivota con
+/// una sola agregación: los planes con un JOIN por atributo se degradan a partir de ahí.
+pub const AUTO_AGGREGATE_PIVOT_MIN_ATTRIBUTES: usize = 30;
+
+/// Forma de pasar los valores de `attribute_values` (una fila por atributo) a columnas.
+/// Ambas estrategias producen las mismas columnas con los mismos tipos.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ViewPivotStrategy {
+    /// Un `LEFT JOIN attribute_values` por atributo
+    Joins,
+    /// Una sola lectura de `attribute_values` agrupada por registro, con un agregado
+    /// `FILTER (WHERE attribute_id = ...)` por atributo
+    Aggregate,
+}
+
+impl ViewPivotStrategy {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ViewPivotStrategy::Joins => "joins",
+            ViewPivotStrategy::Aggregate => "aggregate",
+        }
+    }
+
+    pub fn parse(value: &str) -> Result<Self, DomainError> {
+        match value {
+            "joins" => Ok(ViewPivotStrategy::Joins),
+            "aggregate" => Ok(ViewPivotStrategy::Aggregate),
+            other => Err(DomainError::ValidationError(format!(
+                "Estrategia de pivote desconocida: '{}' (se admite 'joins' o 'aggregate')", other
+            ))),
+        }
+    }
+
+    /// Estrategia a usar: la configurada en la entidad o, si no hay (automática),
+    /// la que corresponde al número de atributos activos.
+    pub fn resolve(configured: Option<ViewPivotStrategy>, attribute_count: usize) -> ViewPivotStrategy {
+        configured.unwrap_or(if attribute_count >= AUTO_AGGREGATE_PIVOT_MIN_ATTRIBUTES {
+            ViewPivotStrategy::Aggregate
+        } else {
+            ViewPivotStrategy::Joins
+        })
+    }
+}
+
+/// Genera el DDL de la vista de la entidad. Con `materialized` se genera una
+/// `MATERIALIZED VIEW` (que se crea poblada); su índice único se crea aparte con
+/// `materialized_view_index_sql`. Si la entidad guarda sus valores en una tabla
+/// física (`storage`), las columnas se leen de ella y `pivot` no se usa.
+/// Los nombres se entrecomillan; falla si un nombre no cabe en un identificador o si
+/// una columna de atributo coincide con una columna de sistema.
+pub fn generate_view_sql(
+    entity_id: Uuid,
+    entity_name: &str,
+    attributes_info: &[AttributeInfo<'_>], // Atributos con su tipo de dato asociado
+    materialized: bool,
+    pivot: ViewPivotStrategy,
+    storage: StorageMode,
+) -> Result<String, DomainError> {
+    // Una entidad sin atributos genera una vista solo con las columnas de sistema,
+    // para que la vista exista siempre que la entidad la tenga asignada.
+    let view_name = view_name_for(entity_name);
+    ensure_identifier_fits(&view_name)?;
+    if materialized {
+        ensure_identifier_fits(&materialized_view_index_name(entity_name))?;
+    }
+
+    // --- Construcción de la parte SELECT ---
+    let mut select_clauses: Vec<String> = vec![
+        "t.id".to_string(), // ID de la tupla/instancia
+        // Seleccionar usernames si es posible
+        "COALESCE(creator.username, t.created_by::text) AS created_by".to_string(),
+        "t.created_at".to_string(),
+        "COALESCE(updater.username, t.updated_by::text) AS updated_by".to_string(),
+        "t.updated_at".to_string(),
+        "t.status".to_string(), // Status de la tupla
+    ];
+
+    // --- Construcción de la parte FROM y JOINs ---
+    let mut join_clauses: Vec<String> = vec![
+        "FROM tuplas t".to_string(),
+        "LEFT JOIN users creator ON t.created_by = creator.id".to_string(),
+        "LEFT JOIN users updater ON t.updated_by = updater.id".to_string(),
+    ];
+
+    // Ordenar atributos por posición para el orden de las columnas en la vista
+    let mut sorted_attributes: Vec<&AttributeInfo<'_>> = attributes_info.iter().collect();
+    sorted_attributes.sort_by_key(|a| (a.position, a.name));
+
+    // Estrategia por agregación: columnas de la subconsulta que pivota los valores
+    let mut pivot_columns: Vec<String> = Vec::new();
+    // Joins de etiquetas y atributos a mostrar: van tras la subconsulta de pivote
+    let mut lookup_joins: Vec<String> = Vec::new();
+    if storage == StorageMode::Physical {
+        join_clauses.push(format!("LEFT JOIN {} p ON p.id = t.id", physical_table_name(entity_id)));
+    }
+
+    for (index, attr_info) in sorted_attributes.iter().enumerate() {
+        let attribute_name = attr_info.name;
+        let attribute_id = attr_info.attribute_id;
+
+        ensure_identifier_fits(attribute_name)?;
+        ensure_not_system_column(attribute_name)?;
+
+        // Columna de valor en attribute_values y cast declarados en el registro de tipos
+        // (la misma columna que usan las escrituras de registros)
+        attr_info.storage.validate()?;
+        let storage_column = attr_info.storage.column.column_name();
+
+        // Valor almacenado (sin cast) del atributo en el registro
+        let raw_value = match (storage, pivot) {
+            (StorageMode::Physical, _) => format!("p.{}", physical_column_name(attribute_id)),
+            (StorageMode::Eav, ViewPivotStrategy::Joins) => {
+                let alias = format!("av_{}", index); // Alias único para cada join a attribute_values
+                join_clauses.push(format!(
+                    "LEFT JOIN attribute_values {} ON t.id = {}.instance_id AND {}.attribute_id = {}",
+                    alias, alias, alias, uuid_literal(attribute_id)
+                ));
+                format!("{}.{}", alias, storage_column)
+            },
+            (StorageMode::Eav, ViewPivotStrategy::Aggregate) => {
+                // array_agg admite todas las columnas de almacenamiento (MAX no admite boolean ni uuid)
+                let pivot_column = format!("v_{}", index);
+                pivot_columns.push(format!(
+                    "(array_agg(av.{}) FILTER (WHERE av.attribute_id = {}))[1] AS {}",
+                    storage_column, uuid_literal(attribute_id), pivot_column
+                ));
+                format!("pv.{}", pivot_column)
+            },
+        };
+
+        // Añadir SELECT para este atributo, con el alias entrecomillado
+        select_clauses.push(format!(
+            "{} AS {}",
+            attr_info.storage.cast_expression(&raw_value), quote_identifier(attribute_name)
+        ));
+
+        // Enumeraciones: etiqueta de la opción además del código almacenado
+        if attr_info.show_option_label && attr_info.storage.is_enumeration() {
+            let label_column = option_label_column(attribute_name);
+            ensure_identifier_fits(&label_column)?;
+            if sorted_attributes.iter().any(|a| a.name == label_column) {
+                return Err(DomainError::ValidationError(format!(
+                    "La columna de etiqueta '{}' coincide con el nombre de otro atributo", label_column
+                )));
+            }
+            let option_alias = format!("ao_{}", index);
+            lookup_joins.push(format!(
+                "LEFT JOIN attribute_options {} ON {}.attribute_id = {} AND {}.code = {}",
+                option_alias, option_alias, uuid_literal(attribute_id), option_alias, raw_value
+            ));
+            select_clauses.push(format!("{}.label AS {}", option_alias, quote_identifier(&label_column)));
+        }
+
+        // Referencias: valor del atributo a mostrar del registro referenciado
+        if let Some(display) = attr_info.reference_display.as_ref().filter(|_| attr_info.storage.is_reference()) {
+            let display_column = reference_display_column(attribute_name);
+            ensure_identifier_fits(&display_column)?;
+            if sorted_attributes.iter().any(|a| a.name == display_column) {
+                return Err(DomainError::ValidationError(format!(
+                    "La columna '{}' coincide con el nombre de otro atributo", display_column
+                )));
+            }
+            display.storage.validate()?;
+            let display_alias = format!("rd_{}", index);
+            let display_value = match display.entity_storage {
+                StorageMode::Eav => {
+                    lookup_joins.push(format!(
+                        "LEFT JOIN attribute_values {} ON {}.instance_id = {} AND {}.attribute_id = {}",
+                        display_alias, display_alias, raw_value, display_alias, uuid_literal(display.attribute_id)
+                    ));
+                    display.storage.view_expression(&display_alias)
+                },
+                StorageMode::Physical => {
+                    lookup_joins.push(format!(
+                        "LEFT JOIN {} {} ON {}.id = {}",
+                        physical_table_name(display.entity_id), display_alias, display_alias, raw_value
+                    ));
+                    display.storage.cast_expression(&format!("{}.{}", display_alias, physical_column_name(display.attribute_id)))
+                },
+            };
+            select_clauses.push(format!("{} AS {}", display_value, quote_identifier(&display_column)));
+        }
+    }
+
+    // Una sola lectura de attribute_values, limitada a los registros vivos de la entidad
+    if !pivot_columns.is_empty() {
+        join_clauses.push(format!(
+            "LEFT JOIN (\n    SELECT av.instance_id,\n        {}\n    FROM attribute_values av\n    \
+             JOIN tuplas pt ON pt.id = av.instance_id AND pt.entity_id = {} AND pt.deleted_at IS NULL\n    \
+             GROUP BY av.instance_id\n  ) pv ON pv.instance_id = t.id",
+            pivot_columns.join(",\n        "),
+            uuid_literal(entity_id)
+        ));
+    }
+    join_clauses.extend(lookup_joins);
+
+    // --- Ensamblar la consulta completa ---
+    let select_sql = select_clauses.join(",\n    ");
+    let join_sql = join_clauses.join("\n  ");
+    let where_sql = format!("WHERE t.entity_id = {} AND t.deleted_at IS NULL", uuid_literal(entity_id)); // Filtrar por entity_id; sin registros de la papelera
+
+    // Usar CREATE OR REPLACE VIEW para idempotencia; las vistas materializadas no
+    // admiten OR REPLACE (el repositorio elimina antes la vista existente)
+    let create_sql = if materialized { "CREATE MATERIALIZED VIEW" } else { "CREATE OR REPLACE VIEW" };
+    let final_sql = format!(
+        "{} {} AS\nSELECT\n    {}\n  {}\n  {};",
+        create_sql,
+        quote_identifier(&view_name),
+        select_sql,
+        join_sql,
+        where_sql
+    );
+
+    Ok(final_sql)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Domain::data_types::{DataTypeKind, DataTypeParams};
+    use crate::Domain::records::StorageColumn;
+
+    /// Columnas de la vista tal como se declaran en el SELECT.
+    fn select_list(sql: &str) -> Vec<String> {
+        let select = &sql[sql.find("SELECT\n").unwrap() + 7..sql.find("\n  FROM tuplas").unwrap()];
+        select.split(",\n").map(|c| c.trim().to_string()).collect()
+    }
+
+    #[test]
+    fn pivot_strategies_produce_the_same_columns() {
+        let params = DataTypeParams { numeric_precision: Some(12), numeric_scale: Some(2), ..Default::default() };
+        let price = DataTypeStorage::new(StorageColumn::Numeric, None, params).unwrap();
+        let stage = DataTypeStorage::new(StorageColumn::String, None, DataTypeParams::default()).unwrap()
+            .with_kind(DataTypeKind::Enumeration).unwrap();
+        let attributes = vec![
+            AttributeInfo { attribute_id: Uuid::new_v4(), name: "stage", position: 2, storage: &stage, show_option_label: true, reference_display: None },
+            AttributeInfo { attribute_id: Uuid::new_v4(), name: "price", position: 1, storage: &price, show_option_label: false, reference_display: None },
+        ];
+        let entity_id = Uuid::new_v4();
+
+        let joins = generate_view_sql(entity_id, "product", &attributes, false, ViewPivotStrategy::Joins, StorageMode::Eav).unwrap();
+        let aggregate = generate_view_sql(entity_id, "product", &attributes, false, ViewPivotStrategy::Aggregate, StorageMode::Eav).unwrap();
+        let physical = generate_view_sql(entity_id, "product", &attributes, false, ViewPivotStrategy::Joins, StorageMode::Physical).unwrap();
+
+        let column_names = |sql: &str| -> Vec<String> {
+            select_list(sql).iter().map(|c| c.rsplit(" AS ").next().unwrap().to_string()).collect()
+        };
+        assert_eq!(column_names(&joins), column_names(&aggregate));
+        assert_eq!(column_names(&joins), column_names(&physical));
+        assert!(select_list(&joins).contains(&"av_0.numeric_value::numeric(12,2) AS \"price\"".to_string()));
+        assert!(select_list(&aggregate).contains(&"pv.v_0::numeric(12,2) AS \"price\"".to_string()));
+
+        assert_eq!(aggregate.matches("attribute_values").count(), 1);
+        assert!(aggregate.contains("FILTER (WHERE av.attribute_id = "));
+        assert!(aggregate.contains("ao_1.code = pv.v_1"));
+
+        assert!(!physical.contains("attribute_values"));
+        assert!(physical.contains(&format!("LEFT JOIN {} p ON p.id = t.id", physical_table_name(entity_id))));
+        assert!(select_list(&physical).contains(&format!("p.{}::numeric(12,2) AS \"price\"", physical_column_name(attributes[1].attribute_id))));
+    }
+
+    #[test]
+    fn names_are_quoted_and_system_columns_rejected() {
+        let text = DataTypeStorage::new(StorageColumn::String, None, DataTypeParams::default()).unwrap();
+        let entity_id = Uuid::new_v4();
+        let quoted = [AttributeInfo { attribute_id: Uuid::new_v4(), name: "a\"b", position: 1, storage: &text, show_option_label: false, reference_display: None }];
+        let sql = generate_view_sql(entity_id, "x\"y", &quoted, false, ViewPivotStrategy::Joins, StorageMode::Eav).unwrap();
+        assert!(sql.starts_with("CREATE OR REPLACE VIEW \"view_x\"\"y\" AS"));
+        assert!(sql.contains("AS \"a\"\"b\""));
+        assert!(sql.contains(&format!("WHERE t.entity_id = '{}'", entity_id)));
+
+        let shadowing = [AttributeInfo { attribute_id: Uuid::new_v4(), name: "status", position: 1, storage: &text, show_option_label: false, reference_display: None }];
+        assert!(generate_view_sql(entity_id, "product", &shadowing, false, ViewPivotStrategy::Joins, StorageMode::Eav).is_err());
+        assert!(generate_view_sql(entity_id, &"e".repeat(60), &[], false, ViewPivotStrategy::Joins, StorageMode::Eav).is_err());
+    }
+
+    #[test]
+    fn automatic_strategy_depends_on_attribute_count() {
+        assert_eq!(ViewPivotStrategy::resolve(None, AUTO_AGGREGATE_PIVOT_MIN_ATTRIBUTES - 1), ViewPivotStrategy::Joins);
+        assert_eq!(ViewPivotStrategy::resolve(None, AUTO_AGGREGATE_PIVOT_MIN_ATTRIBUTES), ViewPivotStrategy::Aggregate);
+        assert_eq!(ViewPivotStrategy::resolve(Some(ViewPivotStrategy::Joins), 100), ViewPivotStrategy::Joins);
+    }
+}
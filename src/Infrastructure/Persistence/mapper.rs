@@ -15,6 +15,7 @@ pub fn user_to_model(user: &User) -> UserModel {
         updated_by: user.updated_by,
         updated_at: user.updated_at.map(|dt| dt.naive_utc()),
         status: user.status,
+        version: user.version,
     }
 }
 
@@ -31,5 +32,6 @@ pub fn model_to_user(model: &UserModel) -> User {
         updated_by: model.updated_by,
         updated_at: model.updated_at.map(|ndt| DateTime::<Utc>::from_naive_utc_and_offset(ndt, Utc)),
         status: model.status,
+        version: model.version,
     }
 }
\ No newline at end of file
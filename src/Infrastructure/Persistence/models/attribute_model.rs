@@ -0,0 +1,27 @@
+use diesel::prelude::*;
+use uuid::Uuid;
+
+use crate::Infrastructure::Persistence::schema::attributes;
+
+#[derive(AsChangeset, Debug)]
+#[diesel(table_name = attributes)]
+pub struct UpdateAttributeChangeset<'a> {
+    // Option<> para actualizaciones parciales: None no modifica la columna.
+    // Option<Option<>> en columnas anulables: Some(None) las deja en NULL.
+    pub name: Option<&'a str>,
+    pub description: Option<Option<&'a str>>,
+    pub data_type_id: Option<Uuid>,
+    pub is_required: Option<bool>,
+    pub position: Option<i16>,
+    pub is_unique: Option<Option<i16>>,
+    pub default_value: Option<Option<&'a str>>,
+    pub validation_regex: Option<Option<&'a str>>,
+    pub show_option_label: Option<bool>,
+    pub is_searchable: Option<bool>,
+    pub reference_entity_id: Option<Option<Uuid>>,
+    pub reference_on_delete: Option<Option<&'a str>>,
+    pub reference_display_attribute_id: Option<Option<Uuid>>,
+    pub status: Option<i16>,
+    pub updated_by: Option<Uuid>,
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>, // Timestamptz
+}
@@ -0,0 +1,24 @@
+use diesel::prelude::*;
+use uuid::Uuid;
+
+use crate::Infrastructure::Persistence::schema::data_types;
+
+#[derive(AsChangeset, Debug)]
+#[diesel(table_name = data_types)]
+pub struct UpdateDataTypeChangeset<'a> {
+    // Option<> para actualizaciones parciales: None no modifica la columna.
+    // Option<Option<>> en columnas anulables: Some(None) las deja en NULL.
+    pub name: Option<&'a str>,
+    pub description: Option<Option<&'a str>>,
+    pub storage_column: Option<&'a str>,
+    pub pg_cast: Option<&'a str>,
+    pub max_length: Option<Option<i32>>,
+    pub numeric_precision: Option<Option<i16>>,
+    pub numeric_scale: Option<Option<i16>>,
+    pub min_value: Option<Option<f64>>,
+    pub max_value: Option<Option<f64>>,
+    pub kind: Option<&'a str>,
+    pub status: Option<i16>,
+    pub updated_by: Option<Uuid>,
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>, // Timestamptz
+}
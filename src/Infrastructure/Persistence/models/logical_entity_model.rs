@@ -43,4 +43,16 @@ impl Default for LogicalEntityModel {
             status: 1, // 1 un estado default apropiado
         }
     }
-}
\ No newline at end of file
+}
+
+#[derive(AsChangeset, Debug)]
+#[diesel(table_name = logical_entities)]
+pub struct UpdateLogicalEntityChangeset<'a> {
+    // Option<> para actualizaciones parciales: None no modifica la columna
+    pub name: Option<&'a str>,
+    pub description: Option<&'a str>,
+    pub search_language: Option<&'a str>,
+    pub status: Option<i16>,
+    pub updated_by: Option<Uuid>,
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>, // Timestamptz
+}
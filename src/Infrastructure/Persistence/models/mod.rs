@@ -1,8 +1,13 @@
 pub mod user_model;
 //pub mod entity;
 pub mod logical_entity_model; // Declarar el módulo como público
+pub mod attribute_model;
+pub mod data_type_model;
 
 pub use user_model::UserModel;
 pub use user_model::UpdateUserChangeset;
 //pub use entity::EntityModel;
 pub use logical_entity_model::LogicalEntityModel; // Reexportar el struct
+pub use logical_entity_model::UpdateLogicalEntityChangeset;
+pub use attribute_model::UpdateAttributeChangeset;
+pub use data_type_model::UpdateDataTypeChangeset;
@@ -43,6 +43,11 @@ pub struct UserModel {
 
     #[diesel(column_name = status)]
     pub status: i16, // i16 coincide con Int2
+
+    // La BD asigna la versión inicial (DEFAULT 1)
+    #[diesel(column_name = version)]
+    #[diesel(skip_insertion)]
+    pub version: i32,
 }
 
 #[derive(AsChangeset, Debug)] // Solo AsChangeset (y Debug opcional)
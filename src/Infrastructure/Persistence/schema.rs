@@ -13,6 +13,9 @@ diesel::table! {
         updated_by -> Nullable<Uuid>,
         updated_at -> Nullable<Timestamp>, // O Timestamptz
         status -> Int2,
+        deleted_at -> Nullable<Timestamptz>, // Papelera: fecha de eliminación
+        deleted_by -> Nullable<Uuid>,
+        version -> Int4, // Control de concurrencia optimista (ETag)
     }
 }
 
@@ -22,20 +25,37 @@ diesel::table! {
         name -> Text,
         description -> Nullable<Text>,
         assign_view -> Nullable<Text>,
+        search_language -> Text, // Configuración de búsqueda de texto de Postgres (regconfig)
+        storage_mode -> Text, // 'eav' o 'physical' (tabla records_<id>)
         created_by -> Nullable<Uuid>, // Asume referencia a users.id
         created_at -> Timestamptz,
         updated_by -> Nullable<Uuid>, // Asume referencia a users.id
         updated_at -> Nullable<Timestamptz>,
         status -> Int2,
+        deleted_at -> Nullable<Timestamptz>, // Papelera: fecha de eliminación
+        deleted_by -> Nullable<Uuid>, // FK a users
     }
 }
 
 diesel::table! {
-    // Definición placeholder para data_types, ajusta si es diferente
+    // Registro de tipos de datos
     data_types (id) {
         id -> Uuid,
-        name -> Text, // Asumiendo que tiene al menos un nombre
-        // ... otras columnas de data_types ...
+        name -> Text,
+        description -> Nullable<Text>,
+        storage_column -> Text, // Columna de attribute_values (string_value, integer_value, ...)
+        pg_cast -> Text,        // Cast de Postgres aplicado en las vistas
+        max_length -> Nullable<Int4>,
+        numeric_precision -> Nullable<Int2>,
+        numeric_scale -> Nullable<Int2>,
+        min_value -> Nullable<Float8>,
+        max_value -> Nullable<Float8>,
+        kind -> Text,           // 'scalar', 'enumeration' o 'reference'
+        created_by -> Nullable<Uuid>,
+        created_at -> Timestamptz,
+        updated_by -> Nullable<Uuid>,
+        updated_at -> Nullable<Timestamptz>,
+        status -> Int2,
     }
 }
 
@@ -51,6 +71,11 @@ diesel::table! {
         is_unique -> Nullable<Int2>, // SMALLINT -> Int2
         default_value -> Nullable<Text>,
         validation_regex -> Nullable<Text>,
+        show_option_label -> Bool,
+        is_searchable -> Bool, // Incluido en la búsqueda de texto (solo string/text)
+        reference_entity_id -> Nullable<Uuid>, // FK a logical_entities (atributos de tipo referencia)
+        reference_on_delete -> Nullable<Text>, // 'restrict', 'cascade' o 'set_null'
+        reference_display_attribute_id -> Nullable<Uuid>, // FK a attributes de la entidad referenciada
         created_by -> Nullable<Uuid>, // FK a users
         created_at -> Timestamptz,
         updated_by -> Nullable<Uuid>, // FK a users
@@ -59,6 +84,85 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    // Opciones de los atributos de tipo enumeración
+    attribute_options (id) {
+        id -> Uuid,
+        attribute_id -> Uuid, // FK a attributes
+        code -> Text,
+        label -> Text,
+        sort_order -> Int4,
+        is_active -> Bool,
+        created_at -> Timestamptz,
+        updated_at -> Nullable<Timestamptz>,
+    }
+}
+
+diesel::table! {
+    entity_schema_versions (id) {
+        id -> Uuid,
+        entity_id -> Uuid, // FK a logical_entities
+        version -> Int4,
+        definition -> Jsonb,
+        change_summary -> Nullable<Text>,
+        created_by -> Nullable<Uuid>, // FK a users
+        created_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    tuplas (id) {
+        id -> Uuid,
+        entity_id -> Uuid, // FK a logical_entities
+        created_by -> Nullable<Uuid>, // FK a users
+        created_at -> Timestamptz,
+        updated_by -> Nullable<Uuid>, // FK a users
+        updated_at -> Nullable<Timestamptz>,
+        status -> Int2,
+        deleted_at -> Nullable<Timestamptz>, // Papelera: fecha de eliminación
+        deleted_by -> Nullable<Uuid>, // FK a users
+        version -> Int4, // Control de concurrencia optimista (ETag)
+    }
+}
+
+diesel::table! {
+    attribute_values (id) {
+        id -> Uuid,
+        instance_id -> Uuid, // FK a tuplas
+        attribute_id -> Uuid, // FK a attributes
+        string_value -> Nullable<Text>,
+        text_value -> Nullable<Text>,
+        integer_value -> Nullable<Int8>,
+        float_value -> Nullable<Float8>,
+        numeric_value -> Nullable<Numeric>,
+        boolean_value -> Nullable<Bool>,
+        datetime_value -> Nullable<Timestamptz>,
+        date_value -> Nullable<Date>,
+        time_value -> Nullable<Time>,
+        uuid_value -> Nullable<Uuid>,
+        json_value -> Nullable<Jsonb>,
+        binary_value -> Nullable<Bytea>,
+        created_at -> Timestamptz,
+        updated_at -> Nullable<Timestamptz>,
+    }
+}
+
+diesel::table! {
+    record_history (id) {
+        id -> Int8,
+        entity_id -> Uuid, // Sin FK: se conserva tras la purga
+        tuple_id -> Uuid,
+        attribute_id -> Nullable<Uuid>,
+        attribute_name -> Nullable<Text>,
+        change_kind -> Text,
+        old_value -> Nullable<Jsonb>,
+        new_value -> Nullable<Jsonb>,
+        changed_by -> Nullable<Uuid>,
+        changed_at -> Timestamptz,
+        request_id -> Nullable<Text>,
+    }
+}
+
 // --- Definiciones de Joins ---
 // Diesel infiere joins simples basados en convenciones o claves foráneas.
 // Para joins más complejos o ambiguos (como múltiples FK a la misma tabla),
@@ -75,6 +179,15 @@ diesel::joinable!(attributes -> data_types (data_type_id));
 diesel::joinable!(attributes -> users (created_by));
 //diesel::joinable!(attributes -> users (updated_by)); // Necesitarás alias en la consulta si usas ambos joins a users
 
+diesel::joinable!(attribute_options -> attributes (attribute_id));
+
+diesel::joinable!(entity_schema_versions -> logical_entities (entity_id));
+
+// Joins para tuplas y attribute_values
+diesel::joinable!(tuplas -> logical_entities (entity_id));
+diesel::joinable!(attribute_values -> tuplas (instance_id));
+diesel::joinable!(attribute_values -> attributes (attribute_id));
+
 
 // --- Permitir tablas en la misma query ---
 // Esto le dice a Diesel que estas tablas pueden aparecer juntas en una consulta.
@@ -83,6 +196,11 @@ diesel::allow_tables_to_appear_in_same_query!(
     logical_entities,
     data_types,
     attributes,
+    attribute_options,
+    entity_schema_versions,
+    tuplas,
+    attribute_values,
+    record_history,
 );
 
 
@@ -32,6 +32,7 @@ impl SqlxMapper<User> for UserMapper {
             created_at: row.try_get("created_at")?,
             updated_by: row.try_get("updated_by")?,
             updated_at: row.try_get("updated_at")?,
+            version: row.try_get("version")?,
         };
         
         Ok(user)
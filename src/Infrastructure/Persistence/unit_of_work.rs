@@ -4,6 +4,7 @@ use async_trait::async_trait;
 use diesel_async::{
     pooled_connection::AsyncDieselConnectionManager,
     AsyncPgConnection,
+    AsyncConnection,
     RunQueryDsl,
     scoped_futures::ScopedFutureExt,
     pooled_connection::bb8::Pool as AsyncDieselPool,
@@ -11,12 +12,11 @@ use diesel_async::{
 // --- SQLx Imports ---
 use sqlx::{PgPool as SqlxPool};
 // --- Standard Imports ---
-use std::future::Future;
 use std::sync::Arc;
 use log::{error, debug};
 
 // --- Importar Traits de Ports ---
-use crate::Application::ports::unit_of_work::{UnitOfWork, RepositoryRegistry};
+use crate::Application::ports::unit_of_work::{UnitOfWork, UnitOfWorkTask, RepositoryRegistry};
 use crate::Application::ports::driven::repositories::{
     // User Repositories
     UserQueryRepository, UserCommandRepository, // UserRepositoryPort eliminado (no usado)
@@ -24,6 +24,12 @@ use crate::Application::ports::driven::repositories::{
     LogicalEntityCommandRepository, LogicalEntityQueryRepository,
     // Attribute & DataType Repositories
     AttributeCommandRepository, DataTypeQueryRepository, // <--- Asegurarse que estén importados
+    AttributeQueryRepository, DataTypeCommandRepository,
+    AttributeOptionCommandRepository,
+    // Record Repositories
+    RecordCommandRepository,
+    // Schema Version Repositories
+    SchemaVersionCommandRepository,
 };
 
 // --- Importar Implementaciones de Repositorios ---
@@ -35,6 +41,12 @@ use crate::Infrastructure::repositories::{
     LogicalEntityCommandRepositoryImpl, LogicalEntityQueryRepositoryImpl,
     // Attribute & DataType Repositories
     AttributeCommandRepositoryImpl, DataTypeQueryRepositoryImpl, // <--- Asegurarse que estén importados
+    AttributeQueryRepositoryImpl, DataTypeCommandRepositoryImpl,
+    AttributeOptionCommandRepositoryImpl,
+    // Record Repositories
+    RecordCommandRepositoryImpl,
+    // Schema Version Repositories
+    SchemaVersionCommandRepositoryImpl,
 };
 
 // --- Implementación del Registro (Contextual a la Transacción Async) ---
@@ -43,6 +55,7 @@ struct TransactionalRepositoryRegistry<'conn> {
     user_query_repo: Arc<UserQueryRepositorySqlx>,
     le_query_repo: Arc<LogicalEntityQueryRepositoryImpl>,
     dt_query_repo: Arc<DataTypeQueryRepositoryImpl>, // <--- Añadido para DataType
+    attr_query_repo: Arc<AttributeQueryRepositoryImpl>,
 }
 
 impl<'conn> TransactionalRepositoryRegistry<'conn> {
@@ -51,12 +64,14 @@ impl<'conn> TransactionalRepositoryRegistry<'conn> {
         user_query_repo: Arc<UserQueryRepositorySqlx>,
         le_query_repo: Arc<LogicalEntityQueryRepositoryImpl>,
         dt_query_repo: Arc<DataTypeQueryRepositoryImpl>, // <--- Añadido
+        attr_query_repo: Arc<AttributeQueryRepositoryImpl>,
     ) -> Self {
         Self {
             diesel_tx_conn,
             user_query_repo,
             le_query_repo,
             dt_query_repo, // <--- Añadido
+            attr_query_repo,
         }
     }
 
@@ -68,29 +83,49 @@ impl<'conn> TransactionalRepositoryRegistry<'conn> {
 // Implementa el trait del Port RepositoryRegistry
 impl<'conn> RepositoryRegistry for TransactionalRepositoryRegistry<'conn> {
 
-    fn user_command_repository(&self) -> &dyn UserCommandRepository {
+    fn user_command_repository(&self) -> &'static dyn UserCommandRepository {
         &UserCommandRepositoryImpl
     }
 
-    fn user_query_repository(&self) -> &dyn UserQueryRepository {
-        self.user_query_repo.as_ref()
+    fn user_query_repository(&self) -> Arc<dyn UserQueryRepository> {
+        self.user_query_repo.clone()
     }
 
     // --- Logical Entity Repos ---
-    fn logical_entity_command_repository(&self) -> &dyn LogicalEntityCommandRepository {
+    fn logical_entity_command_repository(&self) -> &'static dyn LogicalEntityCommandRepository {
          &LogicalEntityCommandRepositoryImpl
     }
-    fn logical_entity_query_repository(&self) -> &dyn LogicalEntityQueryRepository {
-        self.le_query_repo.as_ref()
+    fn logical_entity_query_repository(&self) -> Arc<dyn LogicalEntityQueryRepository> {
+        self.le_query_repo.clone()
     }
 
     // --- Attribute & DataType Repos ---
-    fn attribute_command_repository(&self) -> &dyn AttributeCommandRepository { // <--- COMPLETADO
+    fn attribute_command_repository(&self) -> &'static dyn AttributeCommandRepository { // <--- COMPLETADO
         &AttributeCommandRepositoryImpl
     }
-    fn data_type_query_repository(&self) -> &dyn DataTypeQueryRepository { // <--- COMPLETADO
-        self.dt_query_repo.as_ref()
+    fn data_type_query_repository(&self) -> Arc<dyn DataTypeQueryRepository> { // <--- COMPLETADO
+        self.dt_query_repo.clone()
     }
+    fn data_type_command_repository(&self) -> &'static dyn DataTypeCommandRepository {
+        &DataTypeCommandRepositoryImpl
+    }
+    fn attribute_query_repository(&self) -> Arc<dyn AttributeQueryRepository> {
+        self.attr_query_repo.clone()
+    }
+    fn attribute_option_command_repository(&self) -> &'static dyn AttributeOptionCommandRepository {
+        &AttributeOptionCommandRepositoryImpl
+    }
+
+    // --- Record Repos ---
+    fn record_command_repository(&self) -> &'static dyn RecordCommandRepository {
+        &RecordCommandRepositoryImpl
+    }
+
+    // --- Schema Version Repos ---
+    fn schema_version_command_repository(&self) -> &'static dyn SchemaVersionCommandRepository {
+        &SchemaVersionCommandRepositoryImpl
+    }
+
     fn get_diesel_async_conn(&mut self) -> &mut AsyncPgConnection { // <-- AÑADIDO
         self.conn()
     }
@@ -104,6 +139,7 @@ pub struct DieselAsyncUnitOfWork {
     user_query_repo: Arc<UserQueryRepositorySqlx>,
     le_query_repo: Arc<LogicalEntityQueryRepositoryImpl>,
     dt_query_repo: Arc<DataTypeQueryRepositoryImpl>, // <--- Añadido
+    attr_query_repo: Arc<AttributeQueryRepositoryImpl>,
 }
 
 impl DieselAsyncUnitOfWork {
@@ -115,50 +151,55 @@ impl DieselAsyncUnitOfWork {
         let user_query_repo = Arc::new(UserQueryRepositorySqlx::with_pool(sqlx_pool.clone()));
         let le_query_repo = Arc::new(LogicalEntityQueryRepositoryImpl::new(sqlx_pool.clone()));
         let dt_query_repo = Arc::new(DataTypeQueryRepositoryImpl::new(sqlx_pool.clone())); // <--- Añadido
+        let attr_query_repo = Arc::new(AttributeQueryRepositoryImpl::with_pool(sqlx_pool.clone()));
         Self {
             diesel_async_pool,
             sqlx_pool,
             user_query_repo,
             le_query_repo,
             dt_query_repo,
+            attr_query_repo,
         }
     }
 }
 
 #[async_trait]
 impl UnitOfWork for DieselAsyncUnitOfWork {
-    async fn execute<F, Fut, R>(&self, work: F) -> Result<R>
-    where
-        F: FnOnce(&dyn RepositoryRegistry) -> Fut + Send,
-        Fut: Future<Output = Result<R>> + Send,
-        R: Send,
-    {
+    async fn run<'a>(&'a self, task: UnitOfWorkTask<'a>) -> Result<()> {
         debug!("Iniciando Unidad de Trabajo Asíncrona (Diesel Async)");
-        let mut conn = self.diesel_async_pool.get().await
+        let mut pooled_conn = self.diesel_async_pool.get().await
             .context("Failed to get async Diesel connection from pool")?;
+        let conn: &mut AsyncPgConnection = &mut pooled_conn;
+
+        // Diesel solo admite su propio tipo de error para el rollback: el error de la
+        // clausura se guarda aquí y se devuelve intacto al terminar la transacción.
+        let mut work_error: Option<anyhow::Error> = None;
+        let work_error_slot = &mut work_error;
 
-        let result = conn.transaction::<_, R, diesel::result::Error>(|tx_conn| {
+        let result = conn.transaction::<(), diesel::result::Error, _>(|tx_conn| {
             async move {
                 debug!("Dentro de la transacción Diesel Async");
                 // Crear el registro contextual, pasando la conexión y los repos de consulta
-                let registry = TransactionalRepositoryRegistry::new(
+                let mut registry = TransactionalRepositoryRegistry::new(
                     tx_conn,
                     self.user_query_repo.clone(),
                     self.le_query_repo.clone(),
                     self.dt_query_repo.clone(), // <--- Añadido
+                    self.attr_query_repo.clone(),
                 );
 
                 // Ejecutar la clausura del caso de uso
-                let operation_result = work(&registry).await;
+                let operation_result = task(&mut registry).await;
 
                 // Mapear resultado para commit/rollback
                 match operation_result {
-                    Ok(value) => {
+                    Ok(()) => {
                         debug!("Operación UoW exitosa, preparando commit");
-                        Ok(value) // COMMIT
+                        Ok(()) // COMMIT
                     },
                     Err(app_err) => {
                         error!("Error dentro de la operación UoW, iniciando rollback: {:?}", app_err);
+                        *work_error_slot = Some(app_err);
                         Err(diesel::result::Error::RollbackTransaction) // ROLLBACK
                     }
                 }
@@ -167,13 +208,50 @@ impl UnitOfWork for DieselAsyncUnitOfWork {
         }).await;
 
         debug!("Unidad de Trabajo Asíncrona completada");
+        transaction_outcome(result, work_error)
+    }
+}
 
-        // Mapear error final
-        result.map_err(|diesel_err| {
-            error!("Error en la transacción Diesel: {:?}", diesel_err);
-            anyhow!("Transaction failed: {}", diesel_err)
-        })
+/// Resultado final de la UoW. Si la clausura falló se devuelve su error sin envolver,
+/// para que los casos de uso recuperen su `ApplicationError` con `downcast`.
+fn transaction_outcome<R>(result: std::result::Result<R, diesel::result::Error>, work_error: Option<anyhow::Error>) -> Result<R> {
+    if let Some(work_error) = work_error {
+        return Err(work_error);
     }
+    result.map_err(|diesel_err| {
+        error!("Error en la transacción Diesel: {:?}", diesel_err);
+        anyhow!("Transaction failed: {}", diesel_err)
+    })
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Application::errors::application_error::{ApplicationError, FieldError};
+    use crate::Application::use_cases::records::record_values::map_uow_error;
 
+    #[test]
+    fn closure_error_comes_back_unchanged() {
+        let errors = vec![FieldError::new("email", "unique", "duplicated")];
+        let work_error = anyhow!(ApplicationError::FieldValidationErrors(errors));
+
+        let outcome = transaction_outcome::<()>(Err(diesel::result::Error::RollbackTransaction), Some(work_error));
+
+        match map_uow_error(outcome.unwrap_err(), "test") {
+            ApplicationError::FieldValidationErrors(errors) => assert_eq!(errors[0].code, "unique"),
+            other => panic!("unexpected error: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn database_error_is_an_infrastructure_error() {
+        let outcome = transaction_outcome::<()>(Err(diesel::result::Error::BrokenTransactionManager), None);
+
+        assert!(matches!(map_uow_error(outcome.unwrap_err(), "test"), ApplicationError::InfrastructureError(_)));
+    }
+
+    #[test]
+    fn committed_value_is_returned() {
+        assert_eq!(transaction_outcome(Ok(7), None).unwrap(), 7);
+    }
+}
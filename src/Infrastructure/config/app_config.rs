@@ -31,6 +31,19 @@ pub struct AppConfig {
     // Configuración de características
     pub enable_swagger: bool,
     pub enable_metrics: bool,
+
+    // Documentación de la API (solo con enable_swagger)
+    pub openapi_path: String,
+    pub swagger_ui_path: Option<String>,
+
+    // Retención de la papelera: días tras los que se purga automáticamente (None = sin purga programada)
+    pub trash_retention_days: Option<u32>,
+    pub trash_purge_interval_secs: u64,
+
+    // Refresco de vistas materializadas: cada cuánto se revisan y margen sin escrituras
+    // de registros antes de refrescar (0 desactiva el refresco tras escrituras)
+    pub view_refresh_check_interval_secs: u64,
+    pub view_refresh_debounce_secs: Option<u64>,
 }
 
 impl AppConfig {
@@ -109,6 +122,32 @@ impl AppConfig {
             .unwrap_or_else(|_| "true".to_string())
             .parse::<bool>()
             .unwrap_or(true);
+
+        // Documento OpenAPI y página de Swagger UI (SWAGGER_UI_PATH vacío la desactiva)
+        let openapi_path = env::var("OPENAPI_PATH").unwrap_or_else(|_| "/api/openapi.json".to_string());
+        let swagger_ui_path = env::var("SWAGGER_UI_PATH")
+            .unwrap_or_else(|_| "/api/docs".to_string());
+        let swagger_ui_path = Some(swagger_ui_path.trim().to_string()).filter(|p| !p.is_empty());
+
+        // Purga programada de la papelera (TRASH_RETENTION_DAYS vacío o ausente la desactiva)
+        let trash_retention_days = env::var("TRASH_RETENTION_DAYS")
+            .ok()
+            .and_then(|days| days.trim().parse::<u32>().ok());
+        let trash_purge_interval_secs = env::var("TRASH_PURGE_INTERVAL_SECS")
+            .unwrap_or_else(|_| "3600".to_string())
+            .parse::<u64>()
+            .unwrap_or(3600);
+
+        // Refresco automático de vistas materializadas
+        let view_refresh_check_interval_secs = env::var("VIEW_REFRESH_CHECK_INTERVAL_SECS")
+            .unwrap_or_else(|_| "30".to_string())
+            .parse::<u64>()
+            .unwrap_or(30);
+        let view_refresh_debounce_secs = env::var("VIEW_REFRESH_DEBOUNCE_SECS")
+            .unwrap_or_else(|_| "60".to_string())
+            .parse::<u64>()
+            .unwrap_or(60);
+        let view_refresh_debounce_secs = Some(view_refresh_debounce_secs).filter(|secs| *secs > 0);
         
         Self {
             environment,
@@ -122,6 +161,12 @@ impl AppConfig {
             log_level,
             enable_swagger,
             enable_metrics,
+            openapi_path,
+            swagger_ui_path,
+            trash_retention_days,
+            trash_purge_interval_secs,
+            view_refresh_check_interval_secs,
+            view_refresh_debounce_secs,
         }
     }
     
@@ -0,0 +1,5 @@
+pub mod trash_purge_job;
+pub mod view_refresh_job;
+
+pub use trash_purge_job::TrashPurgeJob;
+pub use view_refresh_job::ViewRefreshJob;
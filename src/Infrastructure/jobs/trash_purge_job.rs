@@ -0,0 +1,55 @@
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::interval;
+use log::{info, warn, error};
+
+use crate::Application::use_cases::trash::PurgeTrashUseCase;
+use crate::Domain::record_history::ChangeContext;
+
+/// Purga periódica de la papelera: elimina definitivamente lo que lleva en ella
+/// más de `retention_days` días.
+pub struct TrashPurgeJob {
+    purge_trash_use_case: Arc<dyn PurgeTrashUseCase>,
+    retention_days: u32,
+    run_interval: Duration,
+}
+
+impl TrashPurgeJob {
+    pub fn new(purge_trash_use_case: Arc<dyn PurgeTrashUseCase>, retention_days: u32, run_interval_seconds: u64) -> Self {
+        Self {
+            purge_trash_use_case,
+            retention_days,
+            run_interval: Duration::from_secs(run_interval_seconds.max(1)),
+        }
+    }
+
+    // Iniciar la purga en segundo plano
+    pub fn start(&self) {
+        let purge_trash_use_case = self.purge_trash_use_case.clone();
+        let retention_days = self.retention_days;
+        let run_interval = self.run_interval;
+
+        info!("Purga programada de la papelera: retención de {} días, cada {:?}", retention_days, run_interval);
+
+        tokio::spawn(async move {
+            let mut interval_timer = interval(run_interval);
+
+            loop {
+                interval_timer.tick().await;
+
+                match purge_trash_use_case.execute(i64::from(retention_days), ChangeContext::system()).await {
+                    Ok(report) => {
+                        if !report.skipped.is_empty() {
+                            warn!("Purga programada: {} elementos de la papelera no se pudieron purgar", report.skipped.len());
+                        }
+                        info!(
+                            "Purga programada completada: {} registros, {} entidades lógicas, {} usuarios",
+                            report.records, report.logical_entities, report.users
+                        );
+                    },
+                    Err(e) => error!("Error en la purga programada de la papelera: {:?}", e),
+                }
+            }
+        });
+    }
+}
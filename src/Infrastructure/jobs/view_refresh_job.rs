@@ -0,0 +1,58 @@
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::interval;
+use log::{info, warn, error};
+
+use crate::Application::use_cases::logical_entities::RefreshMaterializedViewUseCase;
+
+/// Refresco automático de las vistas materializadas: cada `check_interval` revisa
+/// cuáles tocan por su intervalo programado o por escrituras de registros que ya
+/// llevan `debounce` sin repetirse.
+pub struct ViewRefreshJob {
+    refresh_use_case: Arc<dyn RefreshMaterializedViewUseCase>,
+    check_interval: Duration,
+    debounce: Option<Duration>,
+}
+
+impl ViewRefreshJob {
+    pub fn new(
+        refresh_use_case: Arc<dyn RefreshMaterializedViewUseCase>,
+        check_interval_seconds: u64,
+        debounce_seconds: Option<u64>,
+    ) -> Self {
+        Self {
+            refresh_use_case,
+            check_interval: Duration::from_secs(check_interval_seconds.max(1)),
+            debounce: debounce_seconds.map(Duration::from_secs),
+        }
+    }
+
+    // Iniciar la revisión en segundo plano
+    pub fn start(&self) {
+        let refresh_use_case = self.refresh_use_case.clone();
+        let check_interval = self.check_interval;
+        let debounce = self.debounce.and_then(|d| chrono::Duration::from_std(d).ok());
+
+        info!("Refresco de vistas materializadas: revisión cada {:?}, margen tras escrituras {:?}", check_interval, self.debounce);
+
+        tokio::spawn(async move {
+            let mut interval_timer = interval(check_interval);
+
+            loop {
+                interval_timer.tick().await;
+
+                match refresh_use_case.execute_due(debounce).await {
+                    Ok(report) => {
+                        if !report.failed.is_empty() {
+                            warn!("Refresco automático: {} vistas materializadas no se pudieron refrescar", report.failed.len());
+                        }
+                        if !report.refreshed.is_empty() {
+                            info!("Refresco automático completado: {} vistas materializadas", report.refreshed.len());
+                        }
+                    },
+                    Err(e) => error!("Error en el refresco automático de vistas materializadas: {:?}", e),
+                }
+            }
+        });
+    }
+}
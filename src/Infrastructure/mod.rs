@@ -5,4 +5,5 @@ pub mod Services;    // Corregir capitalización
 pub mod config;
 
 
-pub mod monitoring;
\ No newline at end of file
+pub mod monitoring;
+pub mod jobs;
\ No newline at end of file
@@ -9,9 +9,11 @@ use anyhow::Context; // Para contexto de errores
 use log::debug; // Para logging
 
 // Importar el trait del Port
-use crate::Application::ports::driven::repositories::AttributeCommandRepository;
+use crate::Application::ports::driven::repositories::{AttributeCommandRepository, AttributeChanges};
+use crate::Domain::attribute_references::AttributeReference;
 // Importar el schema de la tabla attributes
 use crate::Infrastructure::Persistence::schema::attributes;
+use crate::Infrastructure::Persistence::models::UpdateAttributeChangeset;
 // Importar (o definir) el modelo Diesel para attributes si es necesario para Insertable
 // use crate::Infrastructure::Persistence::models::AttributeModel;
 
@@ -41,6 +43,9 @@ impl AttributeCommandRepository for AttributeCommandRepositoryImpl {
         is_unique: Option<i16>,
         default_value: Option<&str>,
         validation_regex: Option<&str>,
+        show_option_label: bool,
+        is_searchable: bool,
+        reference: Option<&AttributeReference>,
         created_by: Uuid,
     ) -> Result<Uuid, Box<dyn Error + Send + Sync>> {
         debug!("Creando atributo (Diesel Async): name='{}', entity_id='{}'", name, entity_id);
@@ -58,6 +63,11 @@ impl AttributeCommandRepository for AttributeCommandRepositoryImpl {
             attributes::is_unique.eq(is_unique),
             attributes::default_value.eq(default_value),
             attributes::validation_regex.eq(validation_regex),
+            attributes::show_option_label.eq(show_option_label),
+            attributes::is_searchable.eq(is_searchable),
+            attributes::reference_entity_id.eq(reference.map(|r| r.entity_id)),
+            attributes::reference_on_delete.eq(reference.map(|r| r.on_delete.as_str())),
+            attributes::reference_display_attribute_id.eq(reference.and_then(|r| r.display_attribute_id)),
             attributes::created_by.eq(Some(created_by)),
             // attributes::status.eq(1), // Establecer estado inicial si es necesario
         );
@@ -73,6 +83,49 @@ impl AttributeCommandRepository for AttributeCommandRepositoryImpl {
         debug!("Atributo '{}' creado con ID: {}", name, inserted_id);
         Ok(inserted_id)
     }
+
+    /// Actualiza parcialmente un atributo de la entidad usando Diesel Async.
+    async fn update(
+        &self,
+        conn: &mut AsyncPgConnection,
+        entity_id: Uuid,
+        id: Uuid,
+        changes: &AttributeChanges,
+        updated_by: Uuid,
+    ) -> Result<usize, Box<dyn Error + Send + Sync>> {
+        debug!("Actualizando atributo (Diesel Async): id='{}', entity_id='{}'", id, entity_id);
+
+        let changeset = UpdateAttributeChangeset {
+            name: changes.name.as_deref(),
+            description: changes.description.as_ref().map(|d| d.as_deref()),
+            data_type_id: changes.data_type_id,
+            is_required: changes.is_required,
+            position: changes.position,
+            is_unique: changes.is_unique,
+            default_value: changes.default_value.as_ref().map(|d| d.as_deref()),
+            validation_regex: changes.validation_regex.as_ref().map(|r| r.as_deref()),
+            show_option_label: changes.show_option_label,
+            is_searchable: changes.is_searchable,
+            reference_entity_id: changes.reference.as_ref().map(|r| r.as_ref().map(|r| r.entity_id)),
+            reference_on_delete: changes.reference.as_ref().map(|r| r.as_ref().map(|r| r.on_delete.as_str())),
+            reference_display_attribute_id: changes.reference.as_ref().map(|r| r.as_ref().and_then(|r| r.display_attribute_id)),
+            status: changes.status,
+            updated_by: Some(updated_by),
+            updated_at: Some(chrono::Utc::now()),
+        };
+
+        let affected_rows = diesel::update(
+                attributes::table
+                    .filter(attributes::id.eq(id))
+                    .filter(attributes::entity_id.eq(entity_id))
+            )
+            .set(&changeset)
+            .execute(conn)
+            .await
+            .context(format!("Failed to update attribute {} using Diesel Async", id))?;
+
+        Ok(affected_rows)
+    }
 }
 
 // --- Modelo Diesel (Opcional, si no usas tuplas para insertar) ---
@@ -0,0 +1,135 @@
+// src/Infrastructure/repositories/attribute_option_command_repository_impl.rs
+
+use async_trait::async_trait;
+use diesel::prelude::*;
+use diesel::sql_types;
+use diesel::upsert::excluded;
+use diesel_async::{AsyncPgConnection, RunQueryDsl};
+use std::error::Error;
+use uuid::Uuid;
+use anyhow::Context;
+use log::debug;
+
+use crate::Application::ports::driven::repositories::AttributeOptionCommandRepository;
+use crate::Domain::attribute_options::AttributeOption;
+use crate::Domain::records::StorageColumn;
+use crate::Domain::physical_tables::{StorageMode, physical_column_name, physical_table_name};
+use crate::Infrastructure::Persistence::schema::{attribute_options, attributes, logical_entities};
+
+/// Fila de `count_values_by_code`.
+#[derive(QueryableByName)]
+struct CodeCountRow {
+    #[diesel(sql_type = sql_types::Text)]
+    code: String,
+    #[diesel(sql_type = sql_types::BigInt)]
+    total: i64,
+}
+
+// Implementación del repositorio de comandos para las opciones de atributos (ZST)
+#[derive(Clone, Copy)]
+pub struct AttributeOptionCommandRepositoryImpl;
+
+impl AttributeOptionCommandRepositoryImpl {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+#[async_trait]
+impl AttributeOptionCommandRepository for AttributeOptionCommandRepositoryImpl {
+    /// Sustituye la lista de opciones usando Diesel Async.
+    async fn replace_all(
+        &self,
+        conn: &mut AsyncPgConnection,
+        attribute_id: Uuid,
+        options: &[AttributeOption],
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        debug!("Sustituyendo opciones (Diesel Async): attribute_id='{}', opciones={}", attribute_id, options.len());
+
+        let codes: Vec<&str> = options.iter().map(|o| o.code.as_str()).collect();
+        let removed = diesel::delete(
+                attribute_options::table
+                    .filter(attribute_options::attribute_id.eq(attribute_id))
+                    .filter(attribute_options::code.ne_all(&codes))
+            )
+            .execute(conn)
+            .await
+            .context(format!("Failed to delete options of attribute {}", attribute_id))?;
+        debug!("{} opciones eliminadas del atributo {}", removed, attribute_id);
+
+        if options.is_empty() {
+            return Ok(());
+        }
+
+        let rows: Vec<_> = options.iter()
+            .map(|o| (
+                attribute_options::attribute_id.eq(attribute_id),
+                attribute_options::code.eq(o.code.as_str()),
+                attribute_options::label.eq(o.label.as_str()),
+                attribute_options::sort_order.eq(o.sort_order),
+                attribute_options::is_active.eq(o.is_active),
+            ))
+            .collect();
+
+        diesel::insert_into(attribute_options::table)
+            .values(rows)
+            .on_conflict((attribute_options::attribute_id, attribute_options::code))
+            .do_update()
+            .set((
+                attribute_options::label.eq(excluded(attribute_options::label)),
+                attribute_options::sort_order.eq(excluded(attribute_options::sort_order)),
+                attribute_options::is_active.eq(excluded(attribute_options::is_active)),
+                attribute_options::updated_at.eq(Some(chrono::Utc::now())),
+            ))
+            .execute(conn)
+            .await
+            .context(format!("Failed to upsert options of attribute {}", attribute_id))?;
+
+        Ok(())
+    }
+
+    async fn count_values_by_code(
+        &self,
+        conn: &mut AsyncPgConnection,
+        attribute_id: Uuid,
+        column: StorageColumn,
+    ) -> Result<Vec<(String, i64)>, Box<dyn Error + Send + Sync>> {
+        let (entity_id, storage_mode) = attributes::table
+            .inner_join(logical_entities::table)
+            .filter(attributes::id.eq(attribute_id))
+            .select((attributes::entity_id, logical_entities::storage_mode))
+            .first::<(Uuid, String)>(conn)
+            .await
+            .context(format!("Failed to load storage mode of attribute {}", attribute_id))?;
+
+        let rows = if StorageMode::parse(&storage_mode)? == StorageMode::Physical {
+            // Columna derivada del ID del atributo en la tabla física de la entidad
+            let sql = format!(
+                "SELECT {col} AS code, COUNT(*) AS total FROM {table} \
+                 WHERE {col} IS NOT NULL \
+                 GROUP BY {col} ORDER BY {col}",
+                col = physical_column_name(attribute_id),
+                table = physical_table_name(entity_id),
+            );
+            diesel::sql_query(sql)
+                .load::<CodeCountRow>(conn)
+                .await
+                .context(format!("Failed to count stored codes of attribute {}", attribute_id))?
+        } else {
+            // El nombre de la columna viene del enum StorageColumn, nunca de la entrada del usuario
+            let sql = format!(
+                "SELECT {col} AS code, COUNT(*) AS total FROM attribute_values \
+                 WHERE attribute_id = $1 AND {col} IS NOT NULL \
+                 GROUP BY {col} ORDER BY {col}",
+                col = column.column_name()
+            );
+            diesel::sql_query(sql)
+                .bind::<sql_types::Uuid, _>(attribute_id)
+                .load::<CodeCountRow>(conn)
+                .await
+                .context(format!("Failed to count stored codes of attribute {}", attribute_id))?
+        };
+
+        Ok(rows.into_iter().map(|row| (row.code, row.total)).collect())
+    }
+}
@@ -0,0 +1,140 @@
+use async_trait::async_trait;
+use sqlx::{Pool, Postgres, Row};
+use sqlx::postgres::PgRow;
+use std::sync::Arc;
+use uuid::Uuid;
+use std::error::Error;
+use std::collections::HashMap;
+
+use crate::Application::ports::driven::repositories::{AttributeQueryRepository, AttributeDto};
+use crate::Domain::attribute_options::AttributeOption;
+use crate::Domain::attribute_references::{AttributeReference, ReferenceDeleteAction};
+use super::data_type_query_repository_impl::{DATA_TYPE_STORAGE_COLUMNS, storage_from_row};
+
+#[derive(Clone)]
+pub struct AttributeQueryRepositoryImpl {
+    pool: Arc<Pool<Postgres>>,
+}
+
+impl AttributeQueryRepositoryImpl {
+    /// Constructor Preferido: Recibe el pool (Inyección de Dependencias).
+    pub fn with_pool(pool: Arc<Pool<Postgres>>) -> Self {
+        Self { pool }
+    }
+}
+
+impl AttributeQueryRepositoryImpl {
+    /// Atributos cuya columna `filter_column` es uno de `ids`, con su tipo de dato, su
+    /// referencia y sus opciones. `filter_column` es siempre una constante del módulo.
+    async fn fetch_attributes(&self, filter_column: &str, ids: &[Uuid]) -> Result<Vec<AttributeDto>, Box<dyn Error + Send + Sync>> {
+        let sql = format!(r#"
+            SELECT
+                a.id, a.entity_id, a.name, a.description, a.data_type_id,
+                dt.name AS data_type_name, {storage}, a.is_required, a.position, a.is_unique,
+                a.default_value, a.validation_regex, a.show_option_label, a.is_searchable,
+                a.reference_entity_id, a.reference_on_delete, a.reference_display_attribute_id,
+                a.created_by, a.created_at, a.updated_by, a.updated_at, a.status
+            FROM attributes a
+            JOIN data_types dt ON dt.id = a.data_type_id
+            WHERE {filter} = ANY($1)
+            ORDER BY a.position, a.name
+        "#, storage = DATA_TYPE_STORAGE_COLUMNS, filter = filter_column);
+
+        let rows = sqlx::query(&sql)
+            .bind(ids)
+            .fetch_all(&*self.pool)
+            .await
+            .map_err(|e| Box::new(e) as Box<dyn Error + Send + Sync>)?;
+
+        let mut attribute_ids = Vec::with_capacity(rows.len());
+        for row in &rows {
+            attribute_ids.push(row.try_get::<Uuid, _>("id")?);
+        }
+
+        // Opciones de los atributos de tipo enumeración, en su orden de presentación
+        let option_rows = sqlx::query(r#"
+            SELECT o.attribute_id, o.code, o.label, o.sort_order, o.is_active
+            FROM attribute_options o
+            WHERE o.attribute_id = ANY($1)
+            ORDER BY o.sort_order, o.code
+        "#)
+            .bind(&attribute_ids)
+            .fetch_all(&*self.pool)
+            .await
+            .map_err(|e| Box::new(e) as Box<dyn Error + Send + Sync>)?;
+
+        let mut options: HashMap<Uuid, Vec<AttributeOption>> = HashMap::new();
+        for row in option_rows {
+            options.entry(row.try_get("attribute_id")?).or_default().push(AttributeOption {
+                code: row.try_get("code")?,
+                label: row.try_get("label")?,
+                sort_order: row.try_get("sort_order")?,
+                is_active: row.try_get("is_active")?,
+            });
+        }
+
+        let mut attributes = Vec::with_capacity(rows.len());
+        for row in rows {
+            let id: Uuid = row.try_get("id")?;
+            attributes.push(AttributeDto {
+                id,
+                entity_id: row.try_get("entity_id")?,
+                name: row.try_get("name")?,
+                description: row.try_get("description")?,
+                data_type_id: row.try_get("data_type_id")?,
+                data_type_name: row.try_get("data_type_name")?,
+                storage: storage_from_row(&row)?,
+                is_required: row.try_get("is_required")?,
+                position: row.try_get("position")?,
+                is_unique: row.try_get("is_unique")?,
+                default_value: row.try_get("default_value")?,
+                validation_regex: row.try_get("validation_regex")?,
+                show_option_label: row.try_get("show_option_label")?,
+                is_searchable: row.try_get("is_searchable")?,
+                options: options.remove(&id).unwrap_or_default(),
+                reference: reference_from_row(&row)?,
+                created_by: row.try_get("created_by")?,
+                created_at: row.try_get("created_at")?,
+                updated_by: row.try_get("updated_by")?,
+                updated_at: row.try_get("updated_at")?,
+                status: row.try_get("status")?,
+            });
+        }
+        Ok(attributes)
+    }
+}
+
+/// Configuración de referencia del atributo (si es de tipo referencia).
+fn reference_from_row(row: &PgRow) -> Result<Option<AttributeReference>, Box<dyn Error + Send + Sync>> {
+    let entity_id: Option<Uuid> = row.try_get("reference_entity_id")?;
+    let Some(entity_id) = entity_id else {
+        return Ok(None);
+    };
+    let on_delete: Option<String> = row.try_get("reference_on_delete")?;
+    let on_delete = on_delete.as_deref().unwrap_or_default();
+    let on_delete = ReferenceDeleteAction::from_name(on_delete)
+        .ok_or_else(|| format!("Acción on_delete desconocida: '{}'", on_delete))?;
+    Ok(Some(AttributeReference {
+        entity_id,
+        on_delete,
+        display_attribute_id: row.try_get("reference_display_attribute_id")?,
+    }))
+}
+
+#[async_trait]
+impl AttributeQueryRepository for AttributeQueryRepositoryImpl {
+    async fn find_by_entity_id(&self, entity_id: Uuid) -> Result<Vec<AttributeDto>, Box<dyn Error + Send + Sync>> {
+        self.fetch_attributes("a.entity_id", &[entity_id]).await
+    }
+
+    async fn find_by_entity_ids(&self, entity_ids: &[Uuid]) -> Result<Vec<AttributeDto>, Box<dyn Error + Send + Sync>> {
+        if entity_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        self.fetch_attributes("a.entity_id", entity_ids).await
+    }
+
+    async fn find_referencing(&self, entity_id: Uuid) -> Result<Vec<AttributeDto>, Box<dyn Error + Send + Sync>> {
+        self.fetch_attributes("a.reference_entity_id", &[entity_id]).await
+    }
+}
@@ -0,0 +1,118 @@
+// src/Infrastructure/repositories/data_type_command_repository_impl.rs
+
+use async_trait::async_trait;
+use diesel::prelude::*;
+use diesel_async::{AsyncPgConnection, RunQueryDsl};
+use std::error::Error;
+use uuid::Uuid;
+use anyhow::Context;
+use log::debug;
+
+use crate::Application::ports::driven::repositories::{DataTypeCommandRepository, DataTypeChanges};
+use crate::Domain::data_types::DataTypeStorage;
+use crate::Infrastructure::Persistence::schema::data_types;
+use crate::Infrastructure::Persistence::models::UpdateDataTypeChangeset;
+
+// Implementación del repositorio de comandos para el registro de tipos de datos (ZST)
+#[derive(Clone, Copy)]
+pub struct DataTypeCommandRepositoryImpl;
+
+impl DataTypeCommandRepositoryImpl {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+#[async_trait]
+impl DataTypeCommandRepository for DataTypeCommandRepositoryImpl {
+    /// Crea un tipo de dato usando Diesel Async.
+    async fn create(
+        &self,
+        conn: &mut AsyncPgConnection,
+        name: &str,
+        description: Option<&str>,
+        storage: &DataTypeStorage,
+        created_by: Uuid,
+    ) -> Result<Uuid, Box<dyn Error + Send + Sync>> {
+        debug!("Creando tipo de dato (Diesel Async): name='{}'", name);
+
+        let params = &storage.params;
+        let data_type_data = (
+            data_types::name.eq(name),
+            data_types::description.eq(description),
+            data_types::storage_column.eq(storage.column.column_name()),
+            data_types::pg_cast.eq(storage.pg_cast.as_str()),
+            data_types::max_length.eq(params.max_length),
+            data_types::numeric_precision.eq(params.numeric_precision),
+            data_types::numeric_scale.eq(params.numeric_scale),
+            data_types::min_value.eq(params.min_value),
+            data_types::max_value.eq(params.max_value),
+            data_types::kind.eq(storage.kind.as_str()),
+            data_types::created_by.eq(Some(created_by)),
+        );
+
+        let inserted_id = diesel::insert_into(data_types::table)
+            .values(data_type_data)
+            .returning(data_types::id)
+            .get_result::<Uuid>(conn)
+            .await
+            .context(format!("Failed to insert data type '{}' using Diesel Async", name))?;
+
+        debug!("Tipo de dato '{}' creado con ID: {}", name, inserted_id);
+        Ok(inserted_id)
+    }
+
+    /// Actualiza parcialmente un tipo de dato usando Diesel Async.
+    async fn update(
+        &self,
+        conn: &mut AsyncPgConnection,
+        id: Uuid,
+        changes: &DataTypeChanges,
+        updated_by: Uuid,
+    ) -> Result<usize, Box<dyn Error + Send + Sync>> {
+        debug!("Actualizando tipo de dato (Diesel Async): id='{}'", id);
+
+        let storage = changes.storage.as_ref();
+        let params = storage.map(|s| &s.params);
+        let changeset = UpdateDataTypeChangeset {
+            name: changes.name.as_deref(),
+            description: changes.description.as_ref().map(|d| d.as_deref()),
+            storage_column: storage.map(|s| s.column.column_name()),
+            pg_cast: storage.map(|s| s.pg_cast.as_str()),
+            max_length: params.map(|p| p.max_length),
+            numeric_precision: params.map(|p| p.numeric_precision),
+            numeric_scale: params.map(|p| p.numeric_scale),
+            min_value: params.map(|p| p.min_value),
+            max_value: params.map(|p| p.max_value),
+            kind: storage.map(|s| s.kind.as_str()),
+            status: changes.status,
+            updated_by: Some(updated_by),
+            updated_at: Some(chrono::Utc::now()),
+        };
+
+        let affected_rows = diesel::update(data_types::table.filter(data_types::id.eq(id)))
+            .set(&changeset)
+            .execute(conn)
+            .await
+            .context(format!("Failed to update data type {} using Diesel Async", id))?;
+
+        Ok(affected_rows)
+    }
+
+    /// Elimina un tipo de dato usando Diesel Async. Los atributos lo referencian
+    /// con ON DELETE RESTRICT: el caso de uso comprueba antes que no esté en uso.
+    async fn delete(
+        &self,
+        conn: &mut AsyncPgConnection,
+        id: Uuid,
+    ) -> Result<usize, Box<dyn Error + Send + Sync>> {
+        debug!("Eliminando tipo de dato (Diesel Async): id='{}'", id);
+
+        let affected_rows = diesel::delete(data_types::table.filter(data_types::id.eq(id)))
+            .execute(conn)
+            .await
+            .context(format!("Failed to delete data type {} using Diesel Async", id))?;
+
+        Ok(affected_rows)
+    }
+}
@@ -1,11 +1,60 @@
 use async_trait::async_trait;
 use sqlx::{Pool, Postgres, Row};
+use sqlx::postgres::PgRow;
 use std::sync::Arc;
 use uuid::Uuid;
-use anyhow::{Result, Context}; // Añadir Context
-use std::error::Error; // Mantener si se usa en firmas de trait
+use std::error::Error;
 
 use crate::Application::ports::driven::repositories::DataTypeQueryRepository;
+use crate::Domain::data_types::{DataType, DataTypeStorage, DataTypeParams, DataTypeKind};
+use crate::Domain::records::StorageColumn;
+
+/// Columnas de `data_types` (alias `dt`) que describen el almacenamiento de un tipo.
+/// Compartidas con las consultas de atributos, que las leen junto al atributo.
+pub(crate) const DATA_TYPE_STORAGE_COLUMNS: &str = "dt.storage_column, dt.pg_cast, dt.max_length, \
+    dt.numeric_precision, dt.numeric_scale, dt.min_value, dt.max_value, dt.kind";
+
+const SELECT_DATA_TYPE: &str = "SELECT dt.id, dt.name, dt.description, \
+    dt.storage_column, dt.pg_cast, dt.max_length, dt.numeric_precision, dt.numeric_scale, dt.min_value, dt.max_value, dt.kind, \
+    dt.created_by, dt.created_at, dt.updated_by, dt.updated_at, dt.status \
+    FROM data_types dt";
+
+/// Construye la definición de almacenamiento a partir de las columnas de `DATA_TYPE_STORAGE_COLUMNS`.
+pub(crate) fn storage_from_row(row: &PgRow) -> Result<DataTypeStorage, Box<dyn Error + Send + Sync>> {
+    let column_name: String = row.try_get("storage_column")?;
+    let column = StorageColumn::from_column_name(&column_name)
+        .ok_or_else(|| format!("Columna de almacenamiento desconocida en data_types: '{}'", column_name))?;
+    let kind_name: String = row.try_get("kind")?;
+    let kind = DataTypeKind::from_name(&kind_name)
+        .ok_or_else(|| format!("Naturaleza de tipo desconocida en data_types: '{}'", kind_name))?;
+
+    Ok(DataTypeStorage {
+        column,
+        pg_cast: row.try_get("pg_cast")?,
+        params: DataTypeParams {
+            max_length: row.try_get("max_length")?,
+            numeric_precision: row.try_get("numeric_precision")?,
+            numeric_scale: row.try_get("numeric_scale")?,
+            min_value: row.try_get("min_value")?,
+            max_value: row.try_get("max_value")?,
+        },
+        kind,
+    })
+}
+
+fn data_type_from_row(row: &PgRow) -> Result<DataType, Box<dyn Error + Send + Sync>> {
+    Ok(DataType {
+        id: row.try_get("id")?,
+        name: row.try_get("name")?,
+        description: row.try_get("description")?,
+        storage: storage_from_row(row)?,
+        created_by: row.try_get("created_by")?,
+        created_at: row.try_get("created_at")?,
+        updated_by: row.try_get("updated_by")?,
+        updated_at: row.try_get("updated_at")?,
+        status: row.try_get("status")?,
+    })
+}
 
 #[derive(Clone)] // Añadir Clone si se necesita
 pub struct DataTypeQueryRepositoryImpl {
@@ -31,4 +80,47 @@ impl DataTypeQueryRepository for DataTypeQueryRepositoryImpl {
         // Mapear el Option<Row> a Option<Uuid>
         Ok(result.map(|row| row.get("id")))
     }
+
+    async fn find_all(&self, include_inactive: bool) -> Result<Vec<DataType>, Box<dyn Error + Send + Sync>> {
+        let sql = format!("{} WHERE ($1 OR dt.status = 1) ORDER BY LOWER(dt.name)", SELECT_DATA_TYPE);
+        let rows = sqlx::query(&sql)
+            .bind(include_inactive)
+            .fetch_all(&*self.pool)
+            .await
+            .map_err(|e| Box::new(e) as Box<dyn Error + Send + Sync>)?;
+
+        rows.iter().map(data_type_from_row).collect()
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<DataType>, Box<dyn Error + Send + Sync>> {
+        let sql = format!("{} WHERE dt.id = $1", SELECT_DATA_TYPE);
+        let row = sqlx::query(&sql)
+            .bind(id)
+            .fetch_optional(&*self.pool)
+            .await
+            .map_err(|e| Box::new(e) as Box<dyn Error + Send + Sync>)?;
+
+        row.as_ref().map(data_type_from_row).transpose()
+    }
+
+    async fn find_by_name(&self, name: &str) -> Result<Option<DataType>, Box<dyn Error + Send + Sync>> {
+        let sql = format!("{} WHERE LOWER(dt.name) = LOWER($1)", SELECT_DATA_TYPE);
+        let row = sqlx::query(&sql)
+            .bind(name)
+            .fetch_optional(&*self.pool)
+            .await
+            .map_err(|e| Box::new(e) as Box<dyn Error + Send + Sync>)?;
+
+        row.as_ref().map(data_type_from_row).transpose()
+    }
+
+    async fn find_entity_ids_using(&self, id: Uuid) -> Result<Vec<Uuid>, Box<dyn Error + Send + Sync>> {
+        let rows = sqlx::query("SELECT DISTINCT entity_id FROM attributes WHERE data_type_id = $1")
+            .bind(id)
+            .fetch_all(&*self.pool)
+            .await
+            .map_err(|e| Box::new(e) as Box<dyn Error + Send + Sync>)?;
+
+        Ok(rows.iter().map(|row| row.get("entity_id")).collect())
+    }
 }
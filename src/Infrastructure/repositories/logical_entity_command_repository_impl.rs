@@ -9,6 +9,7 @@ use anyhow::Context; // Para añadir contexto a errores
 use crate::Application::ports::driven::repositories::LogicalEntityCommandRepository;
 // Importar el modelo Diesel y el schema
 use crate::Infrastructure::Persistence::models::LogicalEntityModel; // Asume que existe y tiene los derives necesarios
+use crate::Infrastructure::Persistence::models::UpdateLogicalEntityChangeset;
 use crate::Infrastructure::Persistence::schema::logical_entities;
 
 // La implementación puede ser un struct vacío (Zero-Sized Type) si no tiene estado propio.
@@ -56,7 +57,119 @@ impl LogicalEntityCommandRepository for LogicalEntityCommandRepositoryImpl {
         Ok(inserted_id)
     }
 
-    // Implementar update, delete de forma similar usando conn.execute() o .get_result() async
+    async fn update(
+        &self,
+        conn: &mut AsyncPgConnection,
+        id: Uuid,
+        name: Option<&str>,
+        description: Option<&str>,
+        search_language: Option<&str>,
+        status: Option<i16>,
+        updated_by: Uuid,
+    ) -> Result<usize, Box<dyn Error + Send + Sync>> {
+        let changeset = UpdateLogicalEntityChangeset {
+            name,
+            description,
+            search_language,
+            status,
+            updated_by: Some(updated_by),
+            updated_at: Some(chrono::Utc::now()),
+        };
+
+        let affected_rows = diesel::update(logical_entities::table.find(id))
+            .set(&changeset)
+            .execute(conn)
+            .await
+            .context(format!("Failed to update logical entity {} using Diesel Async", id))?;
+
+        Ok(affected_rows)
+    }
+
+    async fn set_assign_view(
+        &self,
+        conn: &mut AsyncPgConnection,
+        id: Uuid,
+        view_name: Option<&str>,
+    ) -> Result<usize, Box<dyn Error + Send + Sync>> {
+        let affected_rows = diesel::update(logical_entities::table.find(id))
+            .set(logical_entities::assign_view.eq(view_name))
+            .execute(conn)
+            .await
+            .context(format!("Failed to set assign_view of logical entity {} using Diesel Async", id))?;
+
+        Ok(affected_rows)
+    }
+
+    async fn create_view(
+        &self,
+        conn: &mut AsyncPgConnection,
+        id: Uuid,
+        view_name: &str,
+        view_sql: &str,
+    ) -> Result<usize, Box<dyn Error + Send + Sync>> {
+        diesel::sql_query(view_sql)
+            .execute(conn)
+            .await
+            .context(format!("Failed to create view {} of logical entity {} using Diesel Async", view_name, id))?;
+
+        self.set_assign_view(conn, id, Some(view_name)).await
+    }
+
+    async fn soft_delete(
+        &self,
+        conn: &mut AsyncPgConnection,
+        id: Uuid,
+        deleted_by: Uuid,
+    ) -> Result<usize, Box<dyn Error + Send + Sync>> {
+        let affected_rows = diesel::update(
+                logical_entities::table.find(id).filter(logical_entities::deleted_at.is_null())
+            )
+            .set((
+                logical_entities::deleted_at.eq(diesel::dsl::now),
+                logical_entities::deleted_by.eq(Some(deleted_by)),
+            ))
+            .execute(conn)
+            .await
+            .context(format!("Failed to move logical entity {} to trash using Diesel Async", id))?;
+
+        Ok(affected_rows)
+    }
+
+    async fn restore(
+        &self,
+        conn: &mut AsyncPgConnection,
+        id: Uuid,
+    ) -> Result<usize, Box<dyn Error + Send + Sync>> {
+        let affected_rows = diesel::update(
+                logical_entities::table.find(id).filter(logical_entities::deleted_at.is_not_null())
+            )
+            .set((
+                logical_entities::deleted_at.eq(None::<chrono::DateTime<chrono::Utc>>),
+                logical_entities::deleted_by.eq(None::<Uuid>),
+            ))
+            .execute(conn)
+            .await
+            .context(format!("Failed to restore logical entity {} using Diesel Async", id))?;
+
+        Ok(affected_rows)
+    }
+
+    async fn delete(
+        &self,
+        conn: &mut AsyncPgConnection,
+        id: Uuid,
+    ) -> Result<usize, Box<dyn Error + Send + Sync>> {
+        // Solo entidades de la papelera; los atributos y las tuplas se eliminan
+        // en cascada (FK ON DELETE CASCADE)
+        let affected_rows = diesel::delete(
+                logical_entities::table.find(id).filter(logical_entities::deleted_at.is_not_null())
+            )
+            .execute(conn)
+            .await
+            .context(format!("Failed to delete logical entity {} using Diesel Async", id))?;
+
+        Ok(affected_rows)
+    }
 }
 
 // --- Modelo Diesel (Ejemplo, debería estar en Infrastructure/Persistence/models/) ---
@@ -1,11 +1,36 @@
 use async_trait::async_trait;
 use sqlx::{Pool, Postgres, Row};
+use sqlx::postgres::PgRow;
 use std::sync::Arc;
+use uuid::Uuid;
 use anyhow::{Result, Context}; // Añadir Context
 use std::error::Error; // Mantener si se usa en firmas de trait
+use chrono::{DateTime, Utc};
 
-use crate::Application::ports::driven::repositories::{LogicalEntityQueryRepository, LogicalEntityDto};
+use crate::Application::ports::driven::repositories::{
+    LogicalEntityQueryRepository, LogicalEntityDto, DeletedLogicalEntityDto,
+};
+use crate::Domain::physical_tables::StorageMode;
 
+const SELECT_LOGICAL_ENTITY: &str = r#"
+    SELECT id, name, description, assign_view, search_language, storage_mode, created_by, created_at,
+           updated_by, updated_at, status, deleted_at, deleted_by
+    FROM logical_entities
+"#;
+
+// Huella de los metadatos: md5 de las columnas que determinan la forma de los
+// registros. No depende de `updated_at`, que no todas las modificaciones actualizan.
+const METADATA_FINGERPRINT: &str = r#"
+    SELECT md5(concat_ws('|',
+        (SELECT string_agg(concat_ws(',', id, name, description, status, deleted_at), ';' ORDER BY id)
+         FROM logical_entities),
+        (SELECT string_agg(concat_ws(',', id, entity_id, data_type_id, name, description, is_required,
+                                     position, default_value, reference_entity_id, status), ';' ORDER BY id)
+         FROM attributes),
+        (SELECT string_agg(concat_ws(',', id, storage_column, pg_cast, kind, status), ';' ORDER BY id)
+         FROM data_types)
+    ))
+"#;
 
 #[derive(Clone)] // Añadir Clone si se necesita
 pub struct LogicalEntityQueryRepositoryImpl {
@@ -17,12 +42,43 @@ impl LogicalEntityQueryRepositoryImpl {
     pub fn with_pool(pool: Arc<Pool<Postgres>>) -> Self { // Renombrado/Asegurado
         Self { pool }
     }
+
+    /// Mapeo manual de una fila de `logical_entities` al DTO.
+    fn map_row(row: &PgRow) -> Result<LogicalEntityDto, sqlx::Error> {
+        let storage_mode: String = row.try_get("storage_mode")?;
+        let storage_mode = StorageMode::parse(&storage_mode).map_err(|e| sqlx::Error::ColumnDecode {
+            index: "storage_mode".to_string(),
+            source: e.to_string().into(),
+        })?;
+        Ok(LogicalEntityDto {
+            id: row.try_get("id")?,
+            name: row.try_get("name")?,
+            description: row.try_get("description")?,
+            assign_view: row.try_get("assign_view")?,
+            search_language: row.try_get("search_language")?,
+            storage_mode,
+            created_by: row.try_get("created_by")?,
+            created_at: row.try_get("created_at")?,
+            updated_by: row.try_get("updated_by")?,
+            updated_at: row.try_get("updated_at")?,
+            status: row.try_get("status")?,
+        })
+    }
+
+    /// Mapeo de una fila de la papelera.
+    fn map_deleted_row(row: &PgRow) -> Result<DeletedLogicalEntityDto, sqlx::Error> {
+        Ok(DeletedLogicalEntityDto {
+            entity: Self::map_row(row)?,
+            deleted_at: row.try_get("deleted_at")?,
+            deleted_by: row.try_get("deleted_by")?,
+        })
+    }
 }
 
 #[async_trait]
 impl LogicalEntityQueryRepository for LogicalEntityQueryRepositoryImpl {
     async fn exists_by_name(&self, name: &str) -> Result<bool, Box<dyn Error + Send + Sync>> {
-        let result = sqlx::query("SELECT EXISTS(SELECT 1 FROM logical_entities WHERE name = $1 AND status = 1)")
+        let result = sqlx::query("SELECT EXISTS(SELECT 1 FROM logical_entities WHERE name = $1 AND deleted_at IS NULL)")
             .bind(name)
             .fetch_one(&*self.pool) // Usar &* para obtener &Pool<Postgres> de Arc
             .await
@@ -31,7 +87,115 @@ impl LogicalEntityQueryRepository for LogicalEntityQueryRepositoryImpl {
         Ok(result.try_get(0).unwrap_or(false))
     }
 
-    // Implementar otros métodos del trait si existen...
-    // async fn find_by_id(...) -> Result<Option<LogicalEntityDto>, Box<dyn Error + Send + Sync>> { ... }
-    // async fn find_all(...) -> Result<Vec<LogicalEntityDto>, Box<dyn Error + Send + Sync>> { ... }
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<LogicalEntityDto>, Box<dyn Error + Send + Sync>> {
+        let sql = format!("{} WHERE id = $1 AND deleted_at IS NULL", SELECT_LOGICAL_ENTITY);
+        let row = sqlx::query(&sql)
+            .bind(id)
+            .fetch_optional(&*self.pool)
+            .await
+            .map_err(|e| Box::new(e) as Box<dyn Error + Send + Sync>)?;
+
+        match row {
+            Some(row) => Ok(Some(Self::map_row(&row)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn find_by_name(&self, name: &str) -> Result<Option<LogicalEntityDto>, Box<dyn Error + Send + Sync>> {
+        let sql = format!("{} WHERE name = $1 AND deleted_at IS NULL", SELECT_LOGICAL_ENTITY);
+        let row = sqlx::query(&sql)
+            .bind(name)
+            .fetch_optional(&*self.pool)
+            .await
+            .map_err(|e| Box::new(e) as Box<dyn Error + Send + Sync>)?;
+
+        match row {
+            Some(row) => Ok(Some(Self::map_row(&row)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn find_all(&self, limit: i64, offset: i64) -> Result<Vec<LogicalEntityDto>, Box<dyn Error + Send + Sync>> {
+        let sql = format!("{} WHERE deleted_at IS NULL ORDER BY name LIMIT $1 OFFSET $2", SELECT_LOGICAL_ENTITY);
+        let rows = sqlx::query(&sql)
+            .bind(limit)
+            .bind(offset)
+            .fetch_all(&*self.pool)
+            .await
+            .map_err(|e| Box::new(e) as Box<dyn Error + Send + Sync>)?;
+
+        let mut entities = Vec::with_capacity(rows.len());
+        for row in rows {
+            entities.push(Self::map_row(&row)?);
+        }
+        Ok(entities)
+    }
+
+    async fn count_all(&self) -> Result<i64, Box<dyn Error + Send + Sync>> {
+        let total: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM logical_entities WHERE deleted_at IS NULL")
+            .fetch_one(&*self.pool)
+            .await
+            .map_err(|e| Box::new(e) as Box<dyn Error + Send + Sync>)?;
+        Ok(total)
+    }
+
+    async fn metadata_fingerprint(&self) -> Result<String, Box<dyn Error + Send + Sync>> {
+        let fingerprint: String = sqlx::query_scalar(METADATA_FINGERPRINT)
+            .fetch_one(&*self.pool)
+            .await
+            .map_err(|e| Box::new(e) as Box<dyn Error + Send + Sync>)?;
+        Ok(fingerprint)
+    }
+
+    async fn find_deleted(&self, limit: i64, offset: i64) -> Result<Vec<DeletedLogicalEntityDto>, Box<dyn Error + Send + Sync>> {
+        let sql = format!(
+            "{} WHERE deleted_at IS NOT NULL ORDER BY deleted_at DESC, name LIMIT $1 OFFSET $2",
+            SELECT_LOGICAL_ENTITY
+        );
+        let rows = sqlx::query(&sql)
+            .bind(limit)
+            .bind(offset)
+            .fetch_all(&*self.pool)
+            .await
+            .map_err(|e| Box::new(e) as Box<dyn Error + Send + Sync>)?;
+
+        let mut entities = Vec::with_capacity(rows.len());
+        for row in rows {
+            entities.push(Self::map_deleted_row(&row)?);
+        }
+        Ok(entities)
+    }
+
+    async fn count_deleted(&self) -> Result<i64, Box<dyn Error + Send + Sync>> {
+        let total: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM logical_entities WHERE deleted_at IS NOT NULL")
+            .fetch_one(&*self.pool)
+            .await
+            .map_err(|e| Box::new(e) as Box<dyn Error + Send + Sync>)?;
+        Ok(total)
+    }
+
+    async fn find_deleted_by_id(&self, id: Uuid) -> Result<Option<DeletedLogicalEntityDto>, Box<dyn Error + Send + Sync>> {
+        let sql = format!("{} WHERE id = $1 AND deleted_at IS NOT NULL", SELECT_LOGICAL_ENTITY);
+        let row = sqlx::query(&sql)
+            .bind(id)
+            .fetch_optional(&*self.pool)
+            .await
+            .map_err(|e| Box::new(e) as Box<dyn Error + Send + Sync>)?;
+
+        match row {
+            Some(row) => Ok(Some(Self::map_deleted_row(&row)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn find_deleted_before(&self, deleted_before: DateTime<Utc>) -> Result<Vec<Uuid>, Box<dyn Error + Send + Sync>> {
+        let ids: Vec<Uuid> = sqlx::query_scalar(
+                "SELECT id FROM logical_entities WHERE deleted_at IS NOT NULL AND deleted_at < $1 ORDER BY deleted_at, id"
+            )
+            .bind(deleted_before)
+            .fetch_all(&*self.pool)
+            .await
+            .map_err(|e| Box::new(e) as Box<dyn Error + Send + Sync>)?;
+        Ok(ids)
+    }
 }
@@ -3,20 +3,38 @@ pub mod sqlx_repository_base;
 pub mod user_query_repository_sqlx;
 pub mod sqlx_repository_cached;
 pub mod sqlx_batch_repository;
+pub mod sqlx_view_repository;
 
 // Declarar módulos para Logical Entity, Attribute, DataType
 pub mod logical_entity_command_repository_impl;
 pub mod logical_entity_query_repository_impl;
 pub mod attribute_command_repository_impl;
+pub mod attribute_query_repository_impl;
+pub mod attribute_option_command_repository_impl;
+pub mod data_type_command_repository_impl;
 pub mod data_type_query_repository_impl;
+pub mod record_command_repository_impl;
+pub mod record_query_repository_impl;
+pub mod record_storage;
+pub mod schema_version_command_repository_impl;
+pub mod schema_version_query_repository_impl;
 
 
 pub use user_command_repository_impl::UserCommandRepositoryImpl;
 pub use user_query_repository_sqlx::UserQueryRepositorySqlx;
 pub use sqlx_repository_base::SqlxRepositoryBase;
+pub use sqlx_view_repository::SqlxViewRepository;
+pub use sqlx_batch_repository::BatchRepository;
 
 // Exportar las implementaciones correspondientes
 pub use logical_entity_command_repository_impl::LogicalEntityCommandRepositoryImpl;
 pub use logical_entity_query_repository_impl::LogicalEntityQueryRepositoryImpl;
 pub use attribute_command_repository_impl::AttributeCommandRepositoryImpl;
-pub use data_type_query_repository_impl::DataTypeQueryRepositoryImpl;
\ No newline at end of file
+pub use attribute_query_repository_impl::AttributeQueryRepositoryImpl;
+pub use attribute_option_command_repository_impl::AttributeOptionCommandRepositoryImpl;
+pub use data_type_command_repository_impl::DataTypeCommandRepositoryImpl;
+pub use data_type_query_repository_impl::DataTypeQueryRepositoryImpl;
+pub use record_command_repository_impl::RecordCommandRepositoryImpl;
+pub use record_query_repository_impl::RecordQueryRepositoryImpl;
+pub use schema_version_command_repository_impl::SchemaVersionCommandRepositoryImpl;
+pub use schema_version_query_repository_impl::SchemaVersionQueryRepositoryImpl;
@@ -0,0 +1,1169 @@
+use async_trait::async_trait;
+use diesel::prelude::*;
+use diesel::pg::Pg;
+use diesel::sql_types;
+use diesel_async::{AsyncPgConnection, RunQueryDsl};
+use std::error::Error;
+use uuid::Uuid;
+use anyhow::Context;
+use chrono::{DateTime, Utc};
+
+use crate::Application::ports::driven::repositories::{
+    RecordCommandRepository, AttributeValueMatch, AttributeValueWrite, StoredAttributeValue, ValueConversionCheck,
+    ReferencingTuple, TupleAttributeValue, NewRecordHistoryDto,
+};
+use crate::Domain::attribute_options::AttributeOption;
+use crate::Domain::records::{StorageColumn, value_conversion_sql, value_json_expression};
+use crate::Domain::errors::DomainError;
+use crate::Domain::sql_identifiers::uuid_literal;
+use crate::Domain::data_types::{DataTypeKind, DataTypeParams, DataTypeStorage};
+use crate::Domain::physical_tables::{
+    StorageMode, PhysicalColumn, ExistingColumn, ExistingTable, plan_table_sync, copy_to_physical_sql, copy_to_eav_sql,
+    delete_eav_values_sql, drop_table_sql, physical_column_name, physical_table_name, physical_type,
+};
+use crate::Infrastructure::Persistence::schema::{tuplas, attributes, attribute_values, logical_entities};
+use super::record_storage::{storage_lock_key, physical_values_from, PHYSICAL_VALUE_JSON};
+
+/// Valor de una fila de attribute_values como JSON, con la misma representación que
+/// devuelve la API de registros (binary en base64). Compartido con las inserciones en lote.
+pub(crate) const VALUE_JSON_EXPRESSION: &str = "COALESCE( \
+    to_jsonb(string_value), to_jsonb(text_value), to_jsonb(integer_value), \
+    to_jsonb(float_value), to_jsonb(numeric_value), to_jsonb(boolean_value), \
+    to_jsonb(datetime_value), to_jsonb(date_value), to_jsonb(time_value), \
+    to_jsonb(uuid_value), json_value, to_jsonb(encode(binary_value, 'base64')) \
+ )";
+
+/// Fila de resultado de las consultas `SELECT EXISTS(...) AS found`.
+#[derive(QueryableByName)]
+struct ExistsRow {
+    #[diesel(sql_type = sql_types::Bool)]
+    found: bool,
+}
+
+/// Fila de la muestra de `check_value_conversion`: el valor se serializa como texto JSON
+/// (Diesel no tiene soporte de serde_json habilitado).
+#[derive(QueryableByName)]
+struct StoredValueRow {
+    #[diesel(sql_type = sql_types::Uuid)]
+    instance_id: Uuid,
+    #[diesel(sql_type = sql_types::Nullable<sql_types::Text>)]
+    value_json: Option<String>,
+}
+
+/// Fila de los recuentos de `check_value_conversion`.
+#[derive(QueryableByName)]
+struct ConversionCountRow {
+    #[diesel(sql_type = sql_types::BigInt)]
+    convertible: i64,
+    #[diesel(sql_type = sql_types::BigInt)]
+    failed: i64,
+}
+
+/// Fila de `find_values_by_tuple`.
+#[derive(QueryableByName)]
+struct TupleValueRow {
+    #[diesel(sql_type = sql_types::Uuid)]
+    attribute_id: Uuid,
+    #[diesel(sql_type = sql_types::Text)]
+    attribute_name: String,
+    #[diesel(sql_type = sql_types::Nullable<sql_types::Text>)]
+    value_json: Option<String>,
+}
+
+/// Fila de `find_tuples_referencing` en las tablas físicas.
+#[derive(QueryableByName)]
+struct ReferencingRow {
+    #[diesel(sql_type = sql_types::Uuid)]
+    id: Uuid,
+    #[diesel(sql_type = sql_types::Nullable<sql_types::Timestamptz>)]
+    deleted_at: Option<DateTime<Utc>>,
+}
+
+/// Fila de las consultas de una sola columna de texto.
+#[derive(QueryableByName)]
+struct TextRow {
+    #[diesel(sql_type = sql_types::Text)]
+    value: String,
+}
+
+/// Atributo de la entidad con su tipo de dato, para derivar la columna física.
+#[derive(QueryableByName)]
+struct PhysicalColumnRow {
+    #[diesel(sql_type = sql_types::Uuid)]
+    id: Uuid,
+    #[diesel(sql_type = sql_types::Text)]
+    name: String,
+    #[diesel(sql_type = sql_types::Text)]
+    storage_column: String,
+    #[diesel(sql_type = sql_types::Text)]
+    pg_cast: String,
+    #[diesel(sql_type = sql_types::Nullable<sql_types::Int4>)]
+    max_length: Option<i32>,
+    #[diesel(sql_type = sql_types::Nullable<sql_types::Int2>)]
+    numeric_precision: Option<i16>,
+    #[diesel(sql_type = sql_types::Nullable<sql_types::Int2>)]
+    numeric_scale: Option<i16>,
+    #[diesel(sql_type = sql_types::Nullable<sql_types::Float8>)]
+    min_value: Option<f64>,
+    #[diesel(sql_type = sql_types::Nullable<sql_types::Float8>)]
+    max_value: Option<f64>,
+    #[diesel(sql_type = sql_types::Text)]
+    kind: String,
+    #[diesel(sql_type = sql_types::Bool)]
+    is_required: bool,
+    #[diesel(sql_type = sql_types::Nullable<sql_types::Int2>)]
+    is_unique: Option<i16>,
+    #[diesel(sql_type = sql_types::Int2)]
+    status: i16,
+    #[diesel(sql_type = sql_types::Array<sql_types::Text>)]
+    option_codes: Vec<String>,
+}
+
+/// Columna existente de una tabla física (pg_attribute).
+#[derive(QueryableByName)]
+struct ExistingColumnRow {
+    #[diesel(sql_type = sql_types::Text)]
+    name: String,
+    #[diesel(sql_type = sql_types::Text)]
+    data_type: String,
+    #[diesel(sql_type = sql_types::Bool)]
+    nullable: bool,
+    #[diesel(sql_type = sql_types::Nullable<sql_types::Text>)]
+    comment: Option<String>,
+}
+
+/// Las entidades con tabla física no cambian la columna de un atributo (ver
+/// `ensure_storage_column_change_allowed`): sus valores solo se comprueban.
+fn ensure_same_physical_column(
+    attribute_id: Uuid,
+    from_column: StorageColumn,
+    to: &DataTypeStorage,
+) -> Result<(), DomainError> {
+    if from_column == to.column {
+        return Ok(());
+    }
+    Err(DomainError::InvalidState(format!(
+        "El atributo {} está en una tabla física y no puede pasar de {} a {}",
+        attribute_id, from_column.column_name(), to.column.column_name()
+    )))
+}
+
+#[derive(Clone, Copy)]
+pub struct RecordCommandRepositoryImpl;
+
+impl RecordCommandRepositoryImpl {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Bloquea (en modo compartido) el modo de almacenamiento de la entidad hasta el fin
+    /// de la transacción y lo devuelve. El cambio de modo toma el mismo bloqueo en
+    /// exclusiva, así que las escrituras esperan a que termine y leen el modo nuevo.
+    async fn lock_storage_mode(
+        &self,
+        conn: &mut AsyncPgConnection,
+        entity_id: Uuid,
+    ) -> Result<StorageMode, Box<dyn Error + Send + Sync>> {
+        // Sentencias separadas: la lectura del modo debe ver lo confirmado tras la espera
+        diesel::sql_query("SELECT pg_advisory_xact_lock_shared(hashtextextended($1, 0))")
+            .bind::<sql_types::Text, _>(storage_lock_key(entity_id))
+            .execute(conn)
+            .await
+            .context(format!("Failed to lock storage mode of entity {}", entity_id))?;
+        self.find_storage_mode(conn, entity_id).await
+    }
+
+    async fn find_storage_mode(
+        &self,
+        conn: &mut AsyncPgConnection,
+        entity_id: Uuid,
+    ) -> Result<StorageMode, Box<dyn Error + Send + Sync>> {
+        let mode = logical_entities::table
+            .find(entity_id)
+            .select(logical_entities::storage_mode)
+            .first::<String>(conn)
+            .await
+            .optional()
+            .context(format!("Failed to load storage mode of entity {}", entity_id))?;
+
+        match mode {
+            Some(mode) => Ok(StorageMode::parse(&mode)?),
+            None => Ok(StorageMode::Eav),
+        }
+    }
+
+    /// Entidad de una tupla y su modo de almacenamiento (bloqueado como en `lock_storage_mode`).
+    async fn lock_tuple_storage(
+        &self,
+        conn: &mut AsyncPgConnection,
+        instance_id: Uuid,
+    ) -> Result<Option<(Uuid, StorageMode)>, Box<dyn Error + Send + Sync>> {
+        let entity_id = tuplas::table
+            .find(instance_id)
+            .select(tuplas::entity_id)
+            .first::<Uuid>(conn)
+            .await
+            .optional()
+            .context(format!("Failed to load entity of tuple {}", instance_id))?;
+
+        match entity_id {
+            Some(entity_id) => Ok(Some((entity_id, self.lock_storage_mode(conn, entity_id).await?))),
+            None => Ok(None),
+        }
+    }
+
+    /// Entidad de un atributo y su modo de almacenamiento (bloqueado como en `lock_storage_mode`).
+    async fn lock_attribute_storage(
+        &self,
+        conn: &mut AsyncPgConnection,
+        attribute_id: Uuid,
+    ) -> Result<(Uuid, StorageMode), Box<dyn Error + Send + Sync>> {
+        let entity_id = attributes::table
+            .find(attribute_id)
+            .select(attributes::entity_id)
+            .first::<Uuid>(conn)
+            .await
+            .context(format!("Failed to load entity of attribute {}", attribute_id))?;
+
+        Ok((entity_id, self.lock_storage_mode(conn, entity_id).await?))
+    }
+
+    async fn upsert_eav_value(
+        &self,
+        conn: &mut AsyncPgConnection,
+        instance_id: Uuid,
+        attribute_id: Uuid,
+        column: StorageColumn,
+        value: &str,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        // El nombre de columna proviene de StorageColumn (no de la entrada del usuario);
+        // el valor se enlaza como texto y Postgres lo convierte al tipo de la columna.
+        let column_name = column.column_name();
+        let sql = format!(
+            "INSERT INTO attribute_values (instance_id, attribute_id, {col}) \
+             VALUES ($1, $2, {expr}) \
+             ON CONFLICT (instance_id, attribute_id) \
+             DO UPDATE SET {col} = EXCLUDED.{col}, updated_at = NOW()",
+            col = column_name,
+            expr = column.cast_expression("$3"),
+        );
+
+        diesel::sql_query(sql)
+            .bind::<sql_types::Uuid, _>(instance_id)
+            .bind::<sql_types::Uuid, _>(attribute_id)
+            .bind::<sql_types::Text, _>(value)
+            .execute(conn)
+            .await
+            .context(format!("Failed to store value of attribute {} for tuple {}", attribute_id, instance_id))?;
+
+        Ok(())
+    }
+
+    async fn delete_eav_value(
+        &self,
+        conn: &mut AsyncPgConnection,
+        instance_id: Uuid,
+        attribute_id: Uuid,
+    ) -> Result<usize, Box<dyn Error + Send + Sync>> {
+        let affected_rows = diesel::delete(
+                attribute_values::table
+                    .filter(attribute_values::instance_id.eq(instance_id))
+                    .filter(attribute_values::attribute_id.eq(attribute_id))
+            )
+            .execute(conn)
+            .await
+            .context(format!("Failed to delete value of attribute {} for tuple {}", attribute_id, instance_id))?;
+
+        Ok(affected_rows)
+    }
+
+    /// Escribe los valores en la fila de la tupla en la tabla física: actualiza la fila
+    /// y, si aún no existe, la inserta con los valores no nulos. Devuelve las filas afectadas.
+    async fn write_physical_row(
+        &self,
+        conn: &mut AsyncPgConnection,
+        entity_id: Uuid,
+        instance_id: Uuid,
+        writes: &[AttributeValueWrite],
+    ) -> Result<usize, Box<dyn Error + Send + Sync>> {
+        if writes.is_empty() {
+            return Ok(0);
+        }
+        let table = physical_table_name(entity_id);
+
+        // Los nombres de columna se derivan de los IDs de atributo y los valores se
+        // enlazan como texto ($2, $3, ... solo los no nulos).
+        let mut assignments = Vec::with_capacity(writes.len());
+        let mut inserted_columns = vec!["id".to_string()];
+        let mut inserted_values = vec!["$1".to_string()];
+        let mut params: Vec<&str> = Vec::new();
+        for write in writes {
+            let column = physical_column_name(write.attribute_id);
+            match &write.value {
+                Some(value) => {
+                    params.push(value);
+                    let expression = write.column.cast_expression(&format!("${}", params.len() + 1));
+                    assignments.push(format!("{} = {}", column, expression));
+                    inserted_columns.push(column);
+                    inserted_values.push(expression);
+                },
+                None => assignments.push(format!("{} = NULL", column)),
+            }
+        }
+
+        let bind_all = |sql: String| {
+            let mut query = diesel::sql_query(sql)
+                .into_boxed::<Pg>()
+                .bind::<sql_types::Uuid, _>(instance_id);
+            for value in &params {
+                query = query.bind::<sql_types::Text, _>(value.to_string());
+            }
+            query
+        };
+
+        let updated = bind_all(format!("UPDATE {} SET {} WHERE id = $1", table, assignments.join(", ")))
+            .execute(conn)
+            .await
+            .context(format!("Failed to update physical row of tuple {}", instance_id))?;
+        if updated > 0 || params.is_empty() {
+            return Ok(updated);
+        }
+
+        let inserted = bind_all(format!(
+                "INSERT INTO {} ({}) VALUES ({})", table, inserted_columns.join(", "), inserted_values.join(", ")
+            ))
+            .execute(conn)
+            .await
+            .context(format!("Failed to insert physical row of tuple {}", instance_id))?;
+        Ok(inserted)
+    }
+
+    /// Columnas físicas de la entidad según sus atributos (activos y retirados),
+    /// leídas dentro de la transacción.
+    async fn load_physical_columns(
+        &self,
+        conn: &mut AsyncPgConnection,
+        entity_id: Uuid,
+    ) -> Result<Vec<PhysicalColumn>, Box<dyn Error + Send + Sync>> {
+        let rows = diesel::sql_query(
+                "SELECT a.id, a.name, dt.storage_column, dt.pg_cast, dt.max_length, dt.numeric_precision, \
+                        dt.numeric_scale, dt.min_value, dt.max_value, dt.kind, a.is_required, a.is_unique, a.status, \
+                        ARRAY(SELECT o.code FROM attribute_options o WHERE o.attribute_id = a.id ORDER BY o.code) \
+                            AS option_codes \
+                 FROM attributes a JOIN data_types dt ON dt.id = a.data_type_id \
+                 WHERE a.entity_id = $1 \
+                 ORDER BY a.position, a.id"
+            )
+            .bind::<sql_types::Uuid, _>(entity_id)
+            .load::<PhysicalColumnRow>(conn)
+            .await
+            .context(format!("Failed to load attributes of entity {}", entity_id))?;
+
+        let mut columns = Vec::with_capacity(rows.len());
+        for row in rows {
+            let column = StorageColumn::from_column_name(&row.storage_column)
+                .ok_or_else(|| format!("Columna de almacenamiento desconocida en data_types: '{}'", row.storage_column))?;
+            let kind = DataTypeKind::from_name(&row.kind)
+                .ok_or_else(|| format!("Naturaleza de tipo desconocida en data_types: '{}'", row.kind))?;
+            columns.push(PhysicalColumn {
+                attribute_id: row.id,
+                name: row.name,
+                storage: DataTypeStorage {
+                    column,
+                    pg_cast: row.pg_cast,
+                    params: DataTypeParams {
+                        max_length: row.max_length,
+                        numeric_precision: row.numeric_precision,
+                        numeric_scale: row.numeric_scale,
+                        min_value: row.min_value,
+                        max_value: row.max_value,
+                    },
+                    kind,
+                },
+                is_required: row.is_required,
+                unique_group: row.is_unique,
+                option_codes: row.option_codes,
+                active: row.status == 1,
+            });
+        }
+        Ok(columns)
+    }
+
+    /// Estado actual de la tabla física de la entidad (None si no existe).
+    async fn describe_physical_table(
+        &self,
+        conn: &mut AsyncPgConnection,
+        entity_id: Uuid,
+    ) -> Result<Option<ExistingTable>, Box<dyn Error + Send + Sync>> {
+        let table = physical_table_name(entity_id);
+        let found = diesel::sql_query("SELECT to_regclass($1) IS NOT NULL AS found")
+            .bind::<sql_types::Text, _>(table.as_str())
+            .get_result::<ExistsRow>(conn)
+            .await
+            .context(format!("Failed to look up physical table {}", table))?;
+        if !found.found {
+            return Ok(None);
+        }
+
+        let columns = diesel::sql_query(
+                "SELECT a.attname::text AS name, format_type(a.atttypid, a.atttypmod) AS data_type, \
+                        NOT a.attnotnull AS nullable, col_description(a.attrelid, a.attnum) AS comment \
+                 FROM pg_attribute a \
+                 WHERE a.attrelid = to_regclass($1) AND a.attnum > 0 AND NOT a.attisdropped \
+                 ORDER BY a.attnum"
+            )
+            .bind::<sql_types::Text, _>(table.as_str())
+            .load::<ExistingColumnRow>(conn)
+            .await
+            .context(format!("Failed to describe physical table {}", table))?;
+        let constraints = diesel::sql_query(
+                "SELECT conname::text AS value FROM pg_constraint WHERE conrelid = to_regclass($1) ORDER BY conname"
+            )
+            .bind::<sql_types::Text, _>(table.as_str())
+            .load::<TextRow>(conn)
+            .await
+            .context(format!("Failed to load constraints of physical table {}", table))?;
+
+        Ok(Some(ExistingTable {
+            columns: columns.into_iter().map(|c| ExistingColumn {
+                name: c.name,
+                data_type: c.data_type,
+                nullable: c.nullable,
+                comment: c.comment,
+            }).collect(),
+            constraints: constraints.into_iter().map(|c| c.value).collect(),
+        }))
+    }
+
+    /// Aplica el plan de sincronización de la tabla física. Devuelve el número de sentencias.
+    async fn apply_table_sync(
+        &self,
+        conn: &mut AsyncPgConnection,
+        entity_id: Uuid,
+    ) -> Result<(usize, Vec<PhysicalColumn>), Box<dyn Error + Send + Sync>> {
+        let columns = self.load_physical_columns(conn, entity_id).await?;
+        let existing = self.describe_physical_table(conn, entity_id).await?;
+        let statements = plan_table_sync(entity_id, existing.as_ref(), &columns)?;
+        for statement in &statements {
+            // Los registros existentes pueden no cumplir una restricción nueva (NOT NULL, UNIQUE, CHECK)
+            diesel::sql_query(statement.as_str())
+                .execute(conn)
+                .await
+                .map_err(|e| DomainError::InvalidState(format!(
+                    "Los registros existentes no admiten el cambio en la tabla física ({}): {}", statement, e
+                )))?;
+        }
+        Ok((statements.len(), columns))
+    }
+
+    /// `backfill_value` en la tabla física. La columna se crea si el atributo es nuevo
+    /// (la sincronización posterior añade sus restricciones). Devuelve las filas escritas.
+    async fn backfill_physical_value(
+        &self,
+        conn: &mut AsyncPgConnection,
+        entity_id: Uuid,
+        attribute_id: Uuid,
+        column: StorageColumn,
+        value: &str,
+    ) -> Result<usize, Box<dyn Error + Send + Sync>> {
+        let table = physical_table_name(entity_id);
+        let name = physical_column_name(attribute_id);
+        diesel::sql_query(format!("ALTER TABLE {} ADD COLUMN IF NOT EXISTS {} {}", table, name, physical_type(column)))
+            .execute(conn)
+            .await
+            .context(format!("Failed to add column of attribute {} to {}", attribute_id, table))?;
+
+        let updated = diesel::sql_query(format!(
+                "UPDATE {} SET {col} = {expr} WHERE {col} IS NULL",
+                table, col = name, expr = column.cast_expression("$1"),
+            ))
+            .bind::<sql_types::Text, _>(value)
+            .execute(conn)
+            .await
+            .context(format!("Failed to backfill attribute {} in entity {}", attribute_id, entity_id))?;
+        // Tuplas sin fila (sin ningún valor todavía)
+        let inserted = diesel::sql_query(format!(
+                "INSERT INTO {table} (id, {col}) SELECT tu.id, {expr} FROM tuplas tu \
+                 WHERE tu.entity_id = $2 AND NOT EXISTS (SELECT 1 FROM {table} p WHERE p.id = tu.id)",
+                table = table, col = name, expr = column.cast_expression("$1"),
+            ))
+            .bind::<sql_types::Text, _>(value)
+            .bind::<sql_types::Uuid, _>(entity_id)
+            .execute(conn)
+            .await
+            .context(format!("Failed to backfill attribute {} in entity {}", attribute_id, entity_id))?;
+
+        Ok(updated + inserted)
+    }
+}
+
+#[async_trait]
+impl RecordCommandRepository for RecordCommandRepositoryImpl {
+    async fn create_tuple(
+        &self,
+        conn: &mut AsyncPgConnection,
+        entity_id: Uuid,
+        created_by: Option<Uuid>,
+    ) -> Result<Uuid, Box<dyn Error + Send + Sync>> {
+        let inserted_id = diesel::insert_into(tuplas::table)
+            .values((
+                tuplas::entity_id.eq(entity_id),
+                tuplas::created_by.eq(created_by),
+            ))
+            .returning(tuplas::id)
+            .get_result::<Uuid>(conn)
+            .await
+            .context(format!("Failed to insert tuple for entity {} using Diesel Async", entity_id))?;
+
+        Ok(inserted_id)
+    }
+
+    async fn touch_tuple(
+        &self,
+        conn: &mut AsyncPgConnection,
+        entity_id: Uuid,
+        id: Uuid,
+        updated_by: Option<Uuid>,
+    ) -> Result<usize, Box<dyn Error + Send + Sync>> {
+        let affected_rows = diesel::update(
+                tuplas::table
+                    .filter(tuplas::id.eq(id))
+                    .filter(tuplas::entity_id.eq(entity_id))
+                    .filter(tuplas::deleted_at.is_null())
+            )
+            .set((
+                tuplas::updated_by.eq(updated_by),
+                tuplas::updated_at.eq(Some(chrono::Utc::now())),
+                tuplas::version.eq(tuplas::version + 1),
+            ))
+            .execute(conn)
+            .await
+            .context(format!("Failed to update tuple {} using Diesel Async", id))?;
+
+        Ok(affected_rows)
+    }
+
+    async fn lock_tuple_version(
+        &self,
+        conn: &mut AsyncPgConnection,
+        entity_id: Uuid,
+        id: Uuid,
+    ) -> Result<Option<i32>, Box<dyn Error + Send + Sync>> {
+        let version = tuplas::table
+            .filter(tuplas::id.eq(id))
+            .filter(tuplas::entity_id.eq(entity_id))
+            .filter(tuplas::deleted_at.is_null())
+            .select(tuplas::version)
+            .for_update()
+            .first::<i32>(conn)
+            .await
+            .optional()
+            .context(format!("Failed to lock tuple {} of entity {}", id, entity_id))?;
+
+        Ok(version)
+    }
+
+    async fn soft_delete_tuple(
+        &self,
+        conn: &mut AsyncPgConnection,
+        entity_id: Uuid,
+        id: Uuid,
+        deleted_by: Option<Uuid>,
+    ) -> Result<usize, Box<dyn Error + Send + Sync>> {
+        // NOW() es la hora de inicio de la transacción: las tuplas eliminadas en
+        // cascada comparten deleted_at y se restauran juntas.
+        let affected_rows = diesel::update(
+                tuplas::table
+                    .filter(tuplas::id.eq(id))
+                    .filter(tuplas::entity_id.eq(entity_id))
+                    .filter(tuplas::deleted_at.is_null())
+            )
+            .set((
+                tuplas::deleted_at.eq(diesel::dsl::now),
+                tuplas::deleted_by.eq(deleted_by),
+                tuplas::version.eq(tuplas::version + 1),
+            ))
+            .execute(conn)
+            .await
+            .context(format!("Failed to move tuple {} to trash using Diesel Async", id))?;
+
+        Ok(affected_rows)
+    }
+
+    async fn lock_deleted_tuple(
+        &self,
+        conn: &mut AsyncPgConnection,
+        entity_id: Uuid,
+        id: Uuid,
+    ) -> Result<Option<DateTime<Utc>>, Box<dyn Error + Send + Sync>> {
+        let deleted_at = tuplas::table
+            .filter(tuplas::id.eq(id))
+            .filter(tuplas::entity_id.eq(entity_id))
+            .filter(tuplas::deleted_at.is_not_null())
+            .select(tuplas::deleted_at)
+            .for_update()
+            .first::<Option<DateTime<Utc>>>(conn)
+            .await
+            .optional()
+            .context(format!("Failed to lock deleted tuple {} of entity {}", id, entity_id))?;
+
+        Ok(deleted_at.flatten())
+    }
+
+    async fn restore_tuple(
+        &self,
+        conn: &mut AsyncPgConnection,
+        entity_id: Uuid,
+        id: Uuid,
+        deleted_at: DateTime<Utc>,
+    ) -> Result<usize, Box<dyn Error + Send + Sync>> {
+        let affected_rows = diesel::update(
+                tuplas::table
+                    .filter(tuplas::id.eq(id))
+                    .filter(tuplas::entity_id.eq(entity_id))
+                    .filter(tuplas::deleted_at.eq(deleted_at))
+            )
+            .set((
+                tuplas::deleted_at.eq(None::<DateTime<Utc>>),
+                tuplas::deleted_by.eq(None::<Uuid>),
+                tuplas::version.eq(tuplas::version + 1),
+            ))
+            .execute(conn)
+            .await
+            .context(format!("Failed to restore tuple {} using Diesel Async", id))?;
+
+        Ok(affected_rows)
+    }
+
+    async fn delete_tuple(
+        &self,
+        conn: &mut AsyncPgConnection,
+        entity_id: Uuid,
+        id: Uuid,
+    ) -> Result<usize, Box<dyn Error + Send + Sync>> {
+        // Los valores se eliminan en cascada (FK ON DELETE CASCADE)
+        let affected_rows = diesel::delete(
+                tuplas::table.filter(tuplas::id.eq(id)).filter(tuplas::entity_id.eq(entity_id))
+            )
+            .execute(conn)
+            .await
+            .context(format!("Failed to delete tuple {} using Diesel Async", id))?;
+
+        Ok(affected_rows)
+    }
+
+    async fn lock_tuple_if_exists(
+        &self,
+        conn: &mut AsyncPgConnection,
+        entity_id: Uuid,
+        id: Uuid,
+    ) -> Result<bool, Box<dyn Error + Send + Sync>> {
+        let found = tuplas::table
+            .filter(tuplas::id.eq(id))
+            .filter(tuplas::entity_id.eq(entity_id))
+            .filter(tuplas::deleted_at.is_null())
+            .select(tuplas::id)
+            .for_key_share()
+            .first::<Uuid>(conn)
+            .await
+            .optional()
+            .context(format!("Failed to lock tuple {} of entity {}", id, entity_id))?;
+
+        Ok(found.is_some())
+    }
+
+    async fn find_tuples_referencing(
+        &self,
+        conn: &mut AsyncPgConnection,
+        attribute_id: Uuid,
+        referenced_id: Uuid,
+    ) -> Result<Vec<ReferencingTuple>, Box<dyn Error + Send + Sync>> {
+        let (entity_id, mode) = self.lock_attribute_storage(conn, attribute_id).await?;
+        if mode == StorageMode::Physical {
+            let sql = format!(
+                "SELECT p.id, t.deleted_at FROM {} p JOIN tuplas t ON t.id = p.id \
+                 WHERE p.{} = $1 ORDER BY p.id FOR UPDATE OF p",
+                physical_table_name(entity_id), physical_column_name(attribute_id),
+            );
+            let rows = diesel::sql_query(sql)
+                .bind::<sql_types::Uuid, _>(referenced_id)
+                .load::<ReferencingRow>(conn)
+                .await
+                .context(format!("Failed to find tuples referencing {} through attribute {}", referenced_id, attribute_id))?;
+            return Ok(rows.into_iter().map(|row| ReferencingTuple { id: row.id, deleted_at: row.deleted_at }).collect());
+        }
+
+        let rows = attribute_values::table
+            .inner_join(tuplas::table)
+            .filter(attribute_values::attribute_id.eq(attribute_id))
+            .filter(attribute_values::uuid_value.eq(referenced_id))
+            .select((attribute_values::instance_id, tuplas::deleted_at))
+            .order(attribute_values::instance_id)
+            .for_update()
+            .load::<(Uuid, Option<DateTime<Utc>>)>(conn)
+            .await
+            .context(format!("Failed to find tuples referencing {} through attribute {}", referenced_id, attribute_id))?;
+
+        Ok(rows.into_iter().map(|(id, deleted_at)| ReferencingTuple { id, deleted_at }).collect())
+    }
+
+    async fn upsert_value(
+        &self,
+        conn: &mut AsyncPgConnection,
+        instance_id: Uuid,
+        attribute_id: Uuid,
+        column: StorageColumn,
+        value: &str,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        if let Some((entity_id, StorageMode::Physical)) = self.lock_tuple_storage(conn, instance_id).await? {
+            let write = AttributeValueWrite { attribute_id, column, value: Some(value.to_string()) };
+            self.write_physical_row(conn, entity_id, instance_id, std::slice::from_ref(&write)).await?;
+            return Ok(());
+        }
+
+        self.upsert_eav_value(conn, instance_id, attribute_id, column, value).await
+    }
+
+    async fn delete_value(
+        &self,
+        conn: &mut AsyncPgConnection,
+        instance_id: Uuid,
+        attribute_id: Uuid,
+    ) -> Result<usize, Box<dyn Error + Send + Sync>> {
+        if let Some((entity_id, StorageMode::Physical)) = self.lock_tuple_storage(conn, instance_id).await? {
+            let sql = format!(
+                "UPDATE {} SET {col} = NULL WHERE id = $1 AND {col} IS NOT NULL",
+                physical_table_name(entity_id), col = physical_column_name(attribute_id),
+            );
+            let affected_rows = diesel::sql_query(sql)
+                .bind::<sql_types::Uuid, _>(instance_id)
+                .execute(conn)
+                .await
+                .context(format!("Failed to delete value of attribute {} for tuple {}", attribute_id, instance_id))?;
+            return Ok(affected_rows);
+        }
+
+        self.delete_eav_value(conn, instance_id, attribute_id).await
+    }
+
+    async fn write_values(
+        &self,
+        conn: &mut AsyncPgConnection,
+        entity_id: Uuid,
+        instance_id: Uuid,
+        writes: &[AttributeValueWrite],
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        if self.lock_storage_mode(conn, entity_id).await? == StorageMode::Physical {
+            self.write_physical_row(conn, entity_id, instance_id, writes).await?;
+            return Ok(());
+        }
+
+        for write in writes {
+            match &write.value {
+                Some(value) => self.upsert_eav_value(conn, instance_id, write.attribute_id, write.column, value).await?,
+                None => {
+                    self.delete_eav_value(conn, instance_id, write.attribute_id).await?;
+                },
+            }
+        }
+        Ok(())
+    }
+
+    async fn lock_uniqueness_group(
+        &self,
+        conn: &mut AsyncPgConnection,
+        entity_id: Uuid,
+        group: i16,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        // Advisory lock transaccional: se libera automáticamente en COMMIT/ROLLBACK
+        diesel::sql_query("SELECT pg_advisory_xact_lock(hashtextextended($1, 0))")
+            .bind::<sql_types::Text, _>(format!("unique:{}:{}", entity_id, group))
+            .execute(conn)
+            .await
+            .context(format!("Failed to lock uniqueness group {} of entity {}", group, entity_id))?;
+
+        Ok(())
+    }
+
+    async fn exists_with_values(
+        &self,
+        conn: &mut AsyncPgConnection,
+        entity_id: Uuid,
+        exclude_id: Option<Uuid>,
+        matches: &[AttributeValueMatch],
+    ) -> Result<bool, Box<dyn Error + Send + Sync>> {
+        if self.lock_storage_mode(conn, entity_id).await? == StorageMode::Physical {
+            // Una condición por columna de la clave en la fila de la tabla física
+            let mut sql = format!("SELECT EXISTS (SELECT 1 FROM {} p WHERE p.id <> $1", physical_table_name(entity_id));
+            for (index, m) in matches.iter().enumerate() {
+                sql.push_str(&format!(
+                    " AND p.{} = {}",
+                    physical_column_name(m.attribute_id),
+                    m.column.cast_expression(&format!("${}", 2 + index)),
+                ));
+            }
+            sql.push_str(") AS found");
+
+            let mut query = diesel::sql_query(sql)
+                .into_boxed::<Pg>()
+                .bind::<sql_types::Uuid, _>(exclude_id.unwrap_or_else(Uuid::nil));
+            for m in matches {
+                query = query.bind::<sql_types::Text, _>(m.value.clone());
+            }
+            let row = query
+                .get_result::<ExistsRow>(conn)
+                .await
+                .context(format!("Failed to check duplicated values in entity {}", entity_id))?;
+            return Ok(row.found);
+        }
+
+        // Una condición EXISTS por atributo de la clave; los nombres de columna
+        // provienen de StorageColumn y los valores se enlazan como parámetros.
+        let mut sql = String::from(
+            "SELECT EXISTS (SELECT 1 FROM tuplas t WHERE t.entity_id = $1 AND t.id <> $2"
+        );
+        for (index, m) in matches.iter().enumerate() {
+            let attribute_param = format!("${}", 3 + index * 2);
+            let value_param = format!("${}", 4 + index * 2);
+            sql.push_str(&format!(
+                " AND EXISTS (SELECT 1 FROM attribute_values av WHERE av.instance_id = t.id \
+                 AND av.attribute_id = {} AND av.{} = {})",
+                attribute_param,
+                m.column.column_name(),
+                m.column.cast_expression(&value_param),
+            ));
+        }
+        sql.push_str(") AS found");
+
+        let mut query = diesel::sql_query(sql)
+            .into_boxed::<Pg>()
+            .bind::<sql_types::Uuid, _>(entity_id)
+            .bind::<sql_types::Uuid, _>(exclude_id.unwrap_or_else(Uuid::nil));
+        for m in matches {
+            query = query
+                .bind::<sql_types::Uuid, _>(m.attribute_id)
+                .bind::<sql_types::Text, _>(m.value.clone());
+        }
+
+        let row = query
+            .get_result::<ExistsRow>(conn)
+            .await
+            .context(format!("Failed to check duplicated values in entity {}", entity_id))?;
+
+        Ok(row.found)
+    }
+
+    async fn check_value_conversion(
+        &self,
+        conn: &mut AsyncPgConnection,
+        attribute_id: Uuid,
+        from_column: StorageColumn,
+        to: &DataTypeStorage,
+        options: &[AttributeOption],
+        sample_size: usize,
+    ) -> Result<ValueConversionCheck, Box<dyn Error + Send + Sync>> {
+        let (entity_id, mode) = self.lock_attribute_storage(conn, attribute_id).await?;
+        let (count_sql, sample_sql) = match mode {
+            StorageMode::Eav => {
+                let column = from_column.column_name();
+                let conversion = value_conversion_sql(from_column, column, to, options);
+                (
+                    format!(
+                        "SELECT count(*) FILTER (WHERE NOT v.failed) AS convertible, count(*) FILTER (WHERE v.failed) AS failed \
+                         FROM (SELECT {} AS failed FROM attribute_values \
+                               WHERE attribute_id = {} AND {} IS NOT NULL FOR UPDATE) v",
+                        conversion.failed, uuid_literal(attribute_id), column,
+                    ),
+                    format!(
+                        "SELECT instance_id, {}::text AS value_json FROM attribute_values \
+                         WHERE attribute_id = {} AND {} ORDER BY instance_id LIMIT $1",
+                        VALUE_JSON_EXPRESSION, uuid_literal(attribute_id), conversion.failed,
+                    ),
+                )
+            },
+            StorageMode::Physical => {
+                ensure_same_physical_column(attribute_id, from_column, to)?;
+                let column = format!("p.{}", physical_column_name(attribute_id));
+                let conversion = value_conversion_sql(from_column, &column, to, options);
+                let table = physical_table_name(entity_id);
+                (
+                    format!(
+                        "SELECT count(*) FILTER (WHERE NOT v.failed) AS convertible, count(*) FILTER (WHERE v.failed) AS failed \
+                         FROM (SELECT {} AS failed FROM {} p WHERE {} IS NOT NULL FOR UPDATE) v",
+                        conversion.failed, table, column,
+                    ),
+                    format!(
+                        "SELECT p.id AS instance_id, ({})::text AS value_json FROM {} p \
+                         WHERE {} ORDER BY p.id LIMIT $1",
+                        value_json_expression(from_column, &column), table, conversion.failed,
+                    ),
+                )
+            },
+        };
+
+        let counts = diesel::sql_query(count_sql)
+            .get_result::<ConversionCountRow>(conn)
+            .await
+            .context(format!("Failed to check conversion of attribute {}", attribute_id))?;
+        let mut check = ValueConversionCheck {
+            convertible: counts.convertible as usize,
+            failed: counts.failed as usize,
+            failed_sample: Vec::new(),
+        };
+        if check.failed == 0 || sample_size == 0 {
+            return Ok(check);
+        }
+
+        let rows = diesel::sql_query(sample_sql)
+            .bind::<sql_types::BigInt, _>(sample_size as i64)
+            .load::<StoredValueRow>(conn)
+            .await
+            .context(format!("Failed to load values of attribute {} that do not convert", attribute_id))?;
+        for row in rows {
+            let Some(json) = row.value_json else { continue };
+            let value = serde_json::from_str(&json)
+                .context(format!("Invalid stored value of attribute {} in tuple {}", attribute_id, row.instance_id))?;
+            check.failed_sample.push(StoredAttributeValue { instance_id: row.instance_id, value });
+        }
+        Ok(check)
+    }
+
+    async fn convert_values(
+        &self,
+        conn: &mut AsyncPgConnection,
+        attribute_id: Uuid,
+        from_column: StorageColumn,
+        to: &DataTypeStorage,
+        options: &[AttributeOption],
+    ) -> Result<(usize, usize), Box<dyn Error + Send + Sync>> {
+        let (entity_id, mode) = self.lock_attribute_storage(conn, attribute_id).await?;
+        if mode == StorageMode::Physical {
+            ensure_same_physical_column(attribute_id, from_column, to)?;
+            let column = physical_column_name(attribute_id);
+            let conversion = value_conversion_sql(from_column, &format!("p.{}", column), to, options);
+            let sql = format!(
+                "UPDATE {} p SET {} = NULL WHERE {}",
+                physical_table_name(entity_id), column, conversion.failed,
+            );
+            let discarded = diesel::sql_query(sql)
+                .execute(conn)
+                .await
+                .context(format!("Failed to discard values of attribute {}", attribute_id))?;
+            return Ok((0, discarded));
+        }
+
+        let from = from_column.column_name();
+        let conversion = value_conversion_sql(from_column, from, to, options);
+        let discarded = diesel::sql_query(format!(
+            "DELETE FROM attribute_values WHERE attribute_id = $1 AND {}", conversion.failed,
+        ))
+            .bind::<sql_types::Uuid, _>(attribute_id)
+            .execute(conn)
+            .await
+            .context(format!("Failed to discard values of attribute {}", attribute_id))?;
+        if from_column == to.column {
+            return Ok((0, discarded));
+        }
+
+        // Las expresiones del SET leen la fila anterior: el valor se mueve de columna
+        let converted = diesel::sql_query(format!(
+            "UPDATE attribute_values SET {to} = {value}, {from} = NULL, updated_at = NOW() \
+             WHERE attribute_id = $1 AND {from} IS NOT NULL",
+            to = to.column.column_name(), value = conversion.value, from = from,
+        ))
+            .bind::<sql_types::Uuid, _>(attribute_id)
+            .execute(conn)
+            .await
+            .context(format!("Failed to convert values of attribute {}", attribute_id))?;
+        Ok((converted, discarded))
+    }
+
+    async fn backfill_value(
+        &self,
+        conn: &mut AsyncPgConnection,
+        entity_id: Uuid,
+        attribute_id: Uuid,
+        column: StorageColumn,
+        value: &str,
+    ) -> Result<usize, Box<dyn Error + Send + Sync>> {
+        if self.lock_storage_mode(conn, entity_id).await? == StorageMode::Physical {
+            return self.backfill_physical_value(conn, entity_id, attribute_id, column, value).await;
+        }
+
+        let sql = format!(
+            "INSERT INTO attribute_values (instance_id, attribute_id, {col}) \
+             SELECT t.id, $2, {expr} FROM tuplas t WHERE t.entity_id = $1 \
+             ON CONFLICT (instance_id, attribute_id) DO NOTHING",
+            col = column.column_name(),
+            expr = column.cast_expression("$3"),
+        );
+
+        let inserted = diesel::sql_query(sql)
+            .bind::<sql_types::Uuid, _>(entity_id)
+            .bind::<sql_types::Uuid, _>(attribute_id)
+            .bind::<sql_types::Text, _>(value)
+            .execute(conn)
+            .await
+            .context(format!("Failed to backfill attribute {} in entity {}", attribute_id, entity_id))?;
+
+        Ok(inserted)
+    }
+
+    async fn find_values_by_tuple(
+        &self,
+        conn: &mut AsyncPgConnection,
+        instance_id: Uuid,
+    ) -> Result<Vec<TupleAttributeValue>, Box<dyn Error + Send + Sync>> {
+        let sql = match self.lock_tuple_storage(conn, instance_id).await? {
+            Some((entity_id, StorageMode::Physical)) => format!(
+                "SELECT a.id AS attribute_id, a.name AS attribute_name, ({})::text AS value_json \
+                 FROM {} \
+                 WHERE p.id = $1 AND kv.value <> 'null'::jsonb \
+                 ORDER BY a.id FOR UPDATE OF p",
+                PHYSICAL_VALUE_JSON, physical_values_from(&physical_table_name(entity_id), &uuid_literal(entity_id)),
+            ),
+            _ => format!(
+                "SELECT av.attribute_id, a.name AS attribute_name, {}::text AS value_json \
+                 FROM attribute_values av JOIN attributes a ON a.id = av.attribute_id \
+                 WHERE av.instance_id = $1 \
+                 ORDER BY av.attribute_id FOR UPDATE OF av",
+                VALUE_JSON_EXPRESSION,
+            ),
+        };
+        let rows = diesel::sql_query(sql)
+            .bind::<sql_types::Uuid, _>(instance_id)
+            .load::<TupleValueRow>(conn)
+            .await
+            .context(format!("Failed to load stored values of tuple {}", instance_id))?;
+
+        let mut values = Vec::with_capacity(rows.len());
+        for row in rows {
+            let Some(json) = row.value_json else { continue };
+            let value = serde_json::from_str(&json)
+                .context(format!("Invalid stored value of attribute {} in tuple {}", row.attribute_id, instance_id))?;
+            values.push(TupleAttributeValue { attribute_id: row.attribute_id, attribute_name: row.attribute_name, value });
+        }
+        Ok(values)
+    }
+
+    async fn sync_physical_table(
+        &self,
+        conn: &mut AsyncPgConnection,
+        entity_id: Uuid,
+    ) -> Result<usize, Box<dyn Error + Send + Sync>> {
+        if self.lock_storage_mode(conn, entity_id).await? != StorageMode::Physical {
+            return Ok(0);
+        }
+        let (applied, _) = self.apply_table_sync(conn, entity_id).await?;
+        Ok(applied)
+    }
+
+    async fn change_storage_mode(
+        &self,
+        conn: &mut AsyncPgConnection,
+        entity_id: Uuid,
+        mode: StorageMode,
+    ) -> Result<usize, Box<dyn Error + Send + Sync>> {
+        // En exclusiva: las escrituras de registros de la entidad esperan al COMMIT
+        diesel::sql_query("SELECT pg_advisory_xact_lock(hashtextextended($1, 0))")
+            .bind::<sql_types::Text, _>(storage_lock_key(entity_id))
+            .execute(conn)
+            .await
+            .context(format!("Failed to lock storage mode of entity {}", entity_id))?;
+        if self.find_storage_mode(conn, entity_id).await? == mode {
+            return Ok(0);
+        }
+
+        let mut copied = 0;
+        match mode {
+            StorageMode::Physical => {
+                let (_, columns) = self.apply_table_sync(conn, entity_id).await?;
+                // Las restricciones ya existen: los registros que no las cumplen impiden el cambio
+                copied = diesel::sql_query(copy_to_physical_sql(entity_id, &columns))
+                    .execute(conn)
+                    .await
+                    .map_err(|e| DomainError::InvalidState(format!(
+                        "Los registros de la entidad no cumplen las restricciones de la tabla física: {}", e
+                    )))?;
+                diesel::sql_query(delete_eav_values_sql(entity_id))
+                    .execute(conn)
+                    .await
+                    .context(format!("Failed to delete EAV values of entity {}", entity_id))?;
+            },
+            StorageMode::Eav => {
+                let columns = self.load_physical_columns(conn, entity_id).await?;
+                for statement in copy_to_eav_sql(entity_id, &columns) {
+                    copied += diesel::sql_query(statement)
+                        .execute(conn)
+                        .await
+                        .context(format!("Failed to copy values of entity {} to attribute_values", entity_id))?;
+                }
+                diesel::sql_query(drop_table_sql(entity_id))
+                    .execute(conn)
+                    .await
+                    .context(format!("Failed to drop physical table of entity {}", entity_id))?;
+            },
+        }
+
+        diesel::update(logical_entities::table.find(entity_id))
+            .set(logical_entities::storage_mode.eq(mode.as_str()))
+            .execute(conn)
+            .await
+            .context(format!("Failed to update storage mode of entity {}", entity_id))?;
+
+        Ok(copied)
+    }
+
+    async fn drop_physical_table(
+        &self,
+        conn: &mut AsyncPgConnection,
+        entity_id: Uuid,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        diesel::sql_query(drop_table_sql(entity_id))
+            .execute(conn)
+            .await
+            .context(format!("Failed to drop physical table of entity {}", entity_id))?;
+
+        Ok(())
+    }
+
+    async fn insert_history(
+        &self,
+        conn: &mut AsyncPgConnection,
+        entries: &[NewRecordHistoryDto],
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        if entries.is_empty() {
+            return Ok(());
+        }
+
+        // Una sola sentencia por lote; WITH ORDINALITY conserva el orden de las entradas
+        // en el id. Los valores viajan como texto JSON (sin soporte serde_json en Diesel).
+        let json = |value: &Option<serde_json::Value>| value.as_ref().map(|v| v.to_string());
+        diesel::sql_query(
+                "INSERT INTO record_history \
+                    (entity_id, tuple_id, attribute_id, attribute_name, change_kind, \
+                     old_value, new_value, changed_by, request_id) \
+                 SELECT e.entity_id, e.tuple_id, e.attribute_id, e.attribute_name, e.change_kind, \
+                        e.old_value::jsonb, e.new_value::jsonb, e.changed_by, e.request_id \
+                 FROM unnest($1::uuid[], $2::uuid[], $3::uuid[], $4::text[], $5::text[], \
+                             $6::text[], $7::text[], $8::uuid[], $9::text[]) WITH ORDINALITY \
+                      AS e(entity_id, tuple_id, attribute_id, attribute_name, change_kind, \
+                           old_value, new_value, changed_by, request_id, position) \
+                 ORDER BY e.position"
+            )
+            .bind::<sql_types::Array<sql_types::Uuid>, _>(entries.iter().map(|e| e.entity_id).collect::<Vec<_>>())
+            .bind::<sql_types::Array<sql_types::Uuid>, _>(entries.iter().map(|e| e.tuple_id).collect::<Vec<_>>())
+            .bind::<sql_types::Array<sql_types::Nullable<sql_types::Uuid>>, _>(entries.iter().map(|e| e.attribute_id).collect::<Vec<_>>())
+            .bind::<sql_types::Array<sql_types::Nullable<sql_types::Text>>, _>(entries.iter().map(|e| e.attribute_name.clone()).collect::<Vec<_>>())
+            .bind::<sql_types::Array<sql_types::Text>, _>(entries.iter().map(|e| e.change_kind.as_str()).collect::<Vec<_>>())
+            .bind::<sql_types::Array<sql_types::Nullable<sql_types::Text>>, _>(entries.iter().map(|e| json(&e.old_value)).collect::<Vec<_>>())
+            .bind::<sql_types::Array<sql_types::Nullable<sql_types::Text>>, _>(entries.iter().map(|e| json(&e.new_value)).collect::<Vec<_>>())
+            .bind::<sql_types::Array<sql_types::Nullable<sql_types::Uuid>>, _>(entries.iter().map(|e| e.changed_by).collect::<Vec<_>>())
+            .bind::<sql_types::Array<sql_types::Nullable<sql_types::Text>>, _>(entries.iter().map(|e| e.request_id.clone()).collect::<Vec<_>>())
+            .execute(conn)
+            .await
+            .context(format!("Failed to write {} record history entries", entries.len()))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,869 @@
+use async_trait::async_trait;
+use futures::stream::{self, BoxStream, StreamExt};
+use sqlx::{Pool, Postgres, Row, Transaction};
+use sqlx::postgres::PgRow;
+use serde_json::Value;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::sync::Arc;
+use uuid::Uuid;
+use std::error::Error;
+
+use crate::Application::ports::driven::repositories::{
+    RecordQueryRepository, RecordDto, KeyedRecordDto, DeletedRecordDto, RecordSearchHitDto, ExportRecordDto,
+    RecordHistoryEntryDto,
+};
+use crate::Domain::record_queries::{
+    FilterOperator, QueryField, RecordQuery, RecordSearch, ResolvedCondition, ResolvedFilter, SortDirection, SortKey,
+};
+use crate::Domain::records::StorageColumn;
+use crate::Domain::record_history::RecordChangeKind;
+use crate::Domain::physical_tables::{StorageMode, physical_column_name};
+use crate::Domain::sql_identifiers::uuid_literal;
+use super::record_storage::{RecordSource, physical_values_from, PHYSICAL_VALUE_JSON};
+
+// Los valores se agregan en un objeto JSON {nombre_atributo: valor} (RECORD_VALUES,
+// según el modo de almacenamiento de la entidad).
+const SELECT_RECORD: &str = r#"
+    SELECT
+        t.id, t.entity_id, t.created_by, t.created_at, t.updated_by, t.updated_at, t.status,
+        t.version, t.deleted_at, t.deleted_by,
+        RECORD_VALUES AS record_values
+    FROM tuplas t
+"#;
+
+// EAV: se toma la columna tipada que no sea NULL (binary se devuelve en base64).
+// Los valores de atributos retirados (status = 0) no se devuelven.
+const EAV_RECORD_VALUES: &str = r#"COALESCE((
+            SELECT jsonb_object_agg(a.name, COALESCE(
+                to_jsonb(av.string_value), to_jsonb(av.text_value), to_jsonb(av.integer_value),
+                to_jsonb(av.float_value), to_jsonb(av.numeric_value), to_jsonb(av.boolean_value),
+                to_jsonb(av.datetime_value), to_jsonb(av.date_value), to_jsonb(av.time_value),
+                to_jsonb(av.uuid_value), av.json_value, to_jsonb(encode(av.binary_value, 'base64'))
+            ))
+            FROM attribute_values av
+            JOIN attributes a ON a.id = av.attribute_id
+            WHERE av.instance_id = t.id AND a.status = 1
+        ), '{}'::jsonb)"#;
+
+// Exportación: los valores se agregan como el texto de su columna, para escribirlos
+// sin pérdida de precisión (numéricos) y con la zona horaria (fechas en ISO 8601).
+const SELECT_EXPORT_RECORD: &str = r#"
+    SELECT
+        t.id, t.created_by, t.created_at, t.updated_by, t.updated_at,
+        RECORD_VALUES AS record_values
+    FROM tuplas t
+"#;
+
+const EAV_EXPORT_VALUES: &str = r#"COALESCE((
+            SELECT jsonb_object_agg(a.name, COALESCE(
+                av.string_value, av.text_value, av.integer_value::text, av.float_value::text,
+                av.numeric_value::text, av.boolean_value::text, to_jsonb(av.datetime_value) #>> '{}',
+                av.date_value::text, av.time_value::text, av.uuid_value::text, av.json_value::text,
+                encode(av.binary_value, 'base64')
+            ))
+            FROM attribute_values av
+            JOIN attributes a ON a.id = av.attribute_id
+            WHERE av.instance_id = t.id AND a.status = 1
+        ), '{}'::jsonb)"#;
+
+// Tabla física: to_jsonb ya da el texto ISO 8601 de las fechas; bytea se serializa
+// en hexadecimal y se devuelve en base64.
+const PHYSICAL_EXPORT_VALUE: &str = "CASE dt.storage_column \
+    WHEN 'binary_value' THEN encode(decode(substr(kv.value #>> '{}', 3), 'hex'), 'base64') \
+    WHEN 'json_value' THEN kv.value::text \
+    ELSE kv.value #>> '{}' END";
+
+/// Nombre del cursor de servidor de la exportación (uno por transacción).
+const EXPORT_CURSOR: &str = "record_export";
+
+// Búsqueda de texto sobre `record_search_documents` con la configuración de la entidad
+// (sin los registros de la papelera).
+// Los resultados se ordenan por relevancia (ts_rank_cd) y por id; el cursor guarda
+// la relevancia y el id del último resultado. `highlights` incluye un fragmento por
+// cada atributo buscable cuyo valor coincide con la búsqueda.
+const SEARCH_RECORDS: &str = r#"
+    WITH q AS (
+        SELECT le.search_language::regconfig AS cfg,
+               websearch_to_tsquery(le.search_language::regconfig, $2) AS query
+        FROM logical_entities le
+        WHERE le.id = $1
+    ),
+    hits AS (
+        SELECT d.instance_id AS id, ts_rank_cd(d.document, q.query) AS rank
+        FROM record_search_documents d
+        JOIN tuplas t ON t.id = d.instance_id AND t.deleted_at IS NULL
+        CROSS JOIN q
+        WHERE d.entity_id = $1 AND d.document @@ q.query
+    ),
+    page AS (
+        SELECT hits.* FROM hits
+        WHERE $3::real IS NULL OR hits.rank < $3::real OR (hits.rank = $3::real AND hits.id > $4)
+        ORDER BY hits.rank DESC, hits.id
+        LIMIT $5
+    )
+    SELECT r.*, page.rank, HIGHLIGHTS AS highlights
+    FROM page
+    CROSS JOIN q
+    JOIN LATERAL (SELECT_RECORD WHERE t.id = page.id) r ON TRUE
+    ORDER BY page.rank DESC, page.id
+"#;
+
+const EAV_HIGHLIGHTS: &str = r#"COALESCE((
+            SELECT jsonb_object_agg(a.name, ts_headline(
+                q.cfg, COALESCE(av.string_value, av.text_value), q.query,
+                'StartSel=<mark>, StopSel=</mark>, MaxFragments=2, MaxWords=20, MinWords=5'
+            ))
+            FROM attribute_values av
+            JOIN attributes a ON a.id = av.attribute_id
+            WHERE av.instance_id = page.id AND a.is_searchable AND a.status = 1
+              AND to_tsvector(q.cfg, COALESCE(av.string_value, av.text_value, '')) @@ q.query
+        ), '{}'::jsonb)"#;
+
+/// Objeto JSON con los valores de la tupla `t`, en la representación de la API.
+fn record_values_sql(source: &RecordSource) -> String {
+    match source.mode {
+        StorageMode::Eav => EAV_RECORD_VALUES.to_string(),
+        StorageMode::Physical => format!(
+            "COALESCE((SELECT jsonb_object_agg(a.name, {}) FROM {} \
+             WHERE p.id = t.id AND a.status = 1 AND kv.value <> 'null'::jsonb), '{{}}'::jsonb)",
+            PHYSICAL_VALUE_JSON, physical_values_from(&source.table(), "t.entity_id"),
+        ),
+    }
+}
+
+fn select_record(source: &RecordSource) -> String {
+    SELECT_RECORD.replace("RECORD_VALUES", &record_values_sql(source))
+}
+
+fn select_export_record(source: &RecordSource) -> String {
+    let values = match source.mode {
+        StorageMode::Eav => EAV_EXPORT_VALUES.to_string(),
+        StorageMode::Physical => format!(
+            "COALESCE((SELECT jsonb_object_agg(a.name, {}) FROM {} \
+             WHERE p.id = t.id AND a.status = 1 AND kv.value <> 'null'::jsonb), '{{}}'::jsonb)",
+            PHYSICAL_EXPORT_VALUE, physical_values_from(&source.table(), "t.entity_id"),
+        ),
+    };
+    SELECT_EXPORT_RECORD.replace("RECORD_VALUES", &values)
+}
+
+/// Fragmentos de la búsqueda por atributo buscable (solo columnas string/text).
+fn highlights_sql(source: &RecordSource) -> String {
+    match source.mode {
+        StorageMode::Eav => EAV_HIGHLIGHTS.to_string(),
+        StorageMode::Physical => format!(
+            "COALESCE((SELECT jsonb_object_agg(a.name, ts_headline( \
+                 q.cfg, kv.value #>> '{{}}', q.query, \
+                 'StartSel=<mark>, StopSel=</mark>, MaxFragments=2, MaxWords=20, MinWords=5')) \
+             FROM {} \
+             WHERE p.id = page.id AND a.is_searchable AND a.status = 1 \
+               AND dt.storage_column IN ('string_value', 'text_value') AND kv.value <> 'null'::jsonb \
+               AND to_tsvector(q.cfg, kv.value #>> '{{}}') @@ q.query), '{{}}'::jsonb)",
+            physical_values_from(&source.table(), "$1"),
+        ),
+    }
+}
+
+/// Valor almacenado de un atributo en la tupla `t` (NULL si no tiene).
+fn stored_value_sql(source: &RecordSource, attribute_id: Uuid, column: StorageColumn) -> String {
+    match source.mode {
+        StorageMode::Physical => format!(
+            "(SELECT sv.{} FROM {} sv WHERE sv.id = t.id)",
+            physical_column_name(attribute_id), source.table(),
+        ),
+        StorageMode::Eav => format!(
+            "(SELECT sv.{} FROM attribute_values sv WHERE sv.instance_id = t.id AND sv.attribute_id = {} LIMIT 1)",
+            column.column_name(), uuid_literal(attribute_id),
+        ),
+    }
+}
+
+const SELECT_HISTORY: &str = r#"
+SELECT id, tuple_id, attribute_id, attribute_name, change_kind, old_value, new_value,
+       changed_by, changed_at, request_id
+FROM record_history
+"#;
+
+#[derive(Clone)]
+pub struct RecordQueryRepositoryImpl {
+    pool: Arc<Pool<Postgres>>,
+}
+
+impl RecordQueryRepositoryImpl {
+    /// Constructor Preferido: Recibe el pool (Inyección de Dependencias).
+    pub fn with_pool(pool: Arc<Pool<Postgres>>) -> Self {
+        Self { pool }
+    }
+
+    async fn source(&self, entity_id: Uuid) -> Result<RecordSource, Box<dyn Error + Send + Sync>> {
+        RecordSource::load(&*self.pool, entity_id)
+            .await
+            .map_err(|e| Box::new(e) as Box<dyn Error + Send + Sync>)
+    }
+
+    /// Mapeo manual de una fila de `tuplas` (con sus valores agregados) al DTO.
+    fn map_row(row: &PgRow) -> Result<RecordDto, sqlx::Error> {
+        let values = match row.try_get::<Value, _>("record_values")? {
+            Value::Object(map) => map,
+            _ => Default::default(),
+        };
+        Ok(RecordDto {
+            id: row.try_get("id")?,
+            entity_id: row.try_get("entity_id")?,
+            values,
+            created_by: row.try_get("created_by")?,
+            created_at: row.try_get("created_at")?,
+            updated_by: row.try_get("updated_by")?,
+            updated_at: row.try_get("updated_at")?,
+            status: row.try_get("status")?,
+            version: row.try_get("version")?,
+        })
+    }
+
+    /// Mapeo de una fila de la papelera (SELECT_RECORD de una tupla eliminada).
+    fn map_deleted_row(row: &PgRow) -> Result<DeletedRecordDto, sqlx::Error> {
+        Ok(DeletedRecordDto {
+            record: Self::map_row(row)?,
+            deleted_at: row.try_get("deleted_at")?,
+            deleted_by: row.try_get("deleted_by")?,
+        })
+    }
+
+    /// Mapeo de una fila de `record_history`.
+    fn map_history_row(row: &PgRow) -> Result<RecordHistoryEntryDto, sqlx::Error> {
+        let change_kind: String = row.try_get("change_kind")?;
+        let change_kind = RecordChangeKind::from_name(&change_kind).ok_or_else(|| sqlx::Error::ColumnDecode {
+            index: "change_kind".to_string(),
+            source: format!("Unknown change kind '{}'", change_kind).into(),
+        })?;
+        Ok(RecordHistoryEntryDto {
+            id: row.try_get("id")?,
+            tuple_id: row.try_get("tuple_id")?,
+            attribute_id: row.try_get("attribute_id")?,
+            attribute_name: row.try_get("attribute_name")?,
+            change_kind,
+            old_value: row.try_get("old_value")?,
+            new_value: row.try_get("new_value")?,
+            changed_by: row.try_get("changed_by")?,
+            changed_at: row.try_get("changed_at")?,
+            request_id: row.try_get("request_id")?,
+        })
+    }
+
+    /// Mapeo de una fila de la exportación (valores como texto).
+    fn map_export_row(row: &PgRow) -> Result<ExportRecordDto, sqlx::Error> {
+        let values = match row.try_get::<Value, _>("record_values")? {
+            Value::Object(map) => map
+                .into_iter()
+                .filter_map(|(name, value)| match value {
+                    Value::String(text) => Some((name, text)),
+                    _ => None,
+                })
+                .collect(),
+            _ => HashMap::new(),
+        };
+        Ok(ExportRecordDto {
+            id: row.try_get("id")?,
+            values,
+            created_by: row.try_get("created_by")?,
+            created_at: row.try_get("created_at")?,
+            updated_by: row.try_get("updated_by")?,
+            updated_at: row.try_get("updated_at")?,
+        })
+    }
+}
+
+#[async_trait]
+impl RecordQueryRepository for RecordQueryRepositoryImpl {
+    async fn find_by_id(&self, entity_id: Uuid, id: Uuid) -> Result<Option<RecordDto>, Box<dyn Error + Send + Sync>> {
+        let source = self.source(entity_id).await?;
+        let sql = format!("{} WHERE t.entity_id = $1 AND t.id = $2 AND t.deleted_at IS NULL", select_record(&source));
+        let row = sqlx::query(&sql)
+            .bind(entity_id)
+            .bind(id)
+            .fetch_optional(&*self.pool)
+            .await
+            .map_err(|e| Box::new(e) as Box<dyn Error + Send + Sync>)?;
+
+        match row {
+            Some(row) => Ok(Some(Self::map_row(&row)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn find_by_ids(&self, entity_id: Uuid, ids: &[Uuid]) -> Result<Vec<RecordDto>, Box<dyn Error + Send + Sync>> {
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        let source = self.source(entity_id).await?;
+        let sql = format!("{} WHERE t.entity_id = $1 AND t.id = ANY($2) AND t.deleted_at IS NULL", select_record(&source));
+        let rows = sqlx::query(&sql)
+            .bind(entity_id)
+            .bind(ids)
+            .fetch_all(&*self.pool)
+            .await
+            .map_err(|e| Box::new(e) as Box<dyn Error + Send + Sync>)?;
+
+        let mut records = Vec::with_capacity(rows.len());
+        for row in rows {
+            records.push(Self::map_row(&row)?);
+        }
+        Ok(records)
+    }
+
+    async fn find_page(&self, entity_id: Uuid, query: &RecordQuery) -> Result<Vec<KeyedRecordDto>, Box<dyn Error + Send + Sync>> {
+        let source = self.source(entity_id).await?;
+        let (sql, params) = PageSqlBuilder::build(source, query);
+        let mut statement = sqlx::query(&sql);
+        for param in params {
+            statement = match param {
+                SqlParam::Uuid(value) => statement.bind(value),
+                SqlParam::Text(value) => statement.bind(value),
+            };
+        }
+        let rows = statement
+            .fetch_all(&*self.pool)
+            .await
+            .map_err(|e| Box::new(e) as Box<dyn Error + Send + Sync>)?;
+
+        let mut records = Vec::with_capacity(rows.len());
+        for row in rows {
+            let mut sort_values = Vec::with_capacity(query.sort.len());
+            for index in 0..query.sort.len() {
+                sort_values.push(row.try_get::<Option<String>, _>(sort_alias(index).as_str())?);
+            }
+            records.push(KeyedRecordDto { record: Self::map_row(&row)?, sort_values });
+        }
+        Ok(records)
+    }
+
+    async fn search(&self, entity_id: Uuid, search: &RecordSearch) -> Result<Vec<RecordSearchHitDto>, Box<dyn Error + Send + Sync>> {
+        let source = self.source(entity_id).await?;
+        let sql = SEARCH_RECORDS
+            .replace("HIGHLIGHTS", &highlights_sql(&source))
+            .replace("SELECT_RECORD", &select_record(&source));
+        let (after_rank, after_id) = match &search.after {
+            Some(cursor) => (cursor.values.first().cloned().flatten(), Some(cursor.id)),
+            None => (None, None),
+        };
+        let rows = sqlx::query(&sql)
+            .bind(entity_id)
+            .bind(&search.text)
+            .bind(after_rank)
+            .bind(after_id)
+            .bind(search.limit)
+            .fetch_all(&*self.pool)
+            .await
+            .map_err(|e| Box::new(e) as Box<dyn Error + Send + Sync>)?;
+
+        let mut hits = Vec::with_capacity(rows.len());
+        for row in rows {
+            let highlights = match row.try_get::<Value, _>("highlights")? {
+                Value::Object(map) => map,
+                _ => Default::default(),
+            };
+            hits.push(RecordSearchHitDto {
+                record: Self::map_row(&row)?,
+                rank: row.try_get("rank")?,
+                highlights,
+            });
+        }
+        Ok(hits)
+    }
+
+    fn export(
+        &self,
+        entity_id: Uuid,
+        filter: Option<&ResolvedFilter>,
+        sort: &[SortKey],
+        batch_size: usize,
+    ) -> BoxStream<'static, Result<Vec<ExportRecordDto>, Box<dyn Error + Send + Sync>>> {
+        let pool = self.pool.clone();
+        let fetch = format!("FETCH FORWARD {} FROM {}", batch_size.max(1), EXPORT_CURSOR);
+
+        // Estado: la transacción que mantiene abierto el cursor (None hasta el primer lote)
+        // y, hasta entonces, el filtro y la ordenación con los que se declara
+        let request = (filter.cloned(), sort.to_vec());
+        let start: (Option<Transaction<'static, Postgres>>, Option<(Option<ResolvedFilter>, Vec<SortKey>)>) = (None, Some(request));
+        stream::try_unfold(start, move |(tx, request)| {
+            let pool = pool.clone();
+            let fetch = fetch.clone();
+            async move {
+                let mut tx = match tx {
+                    Some(tx) => tx,
+                    None => {
+                        // Instantánea coherente durante toda la exportación
+                        let mut tx = pool.begin().await?;
+                        sqlx::query("SET TRANSACTION ISOLATION LEVEL REPEATABLE READ, READ ONLY")
+                            .execute(&mut *tx)
+                            .await?;
+                        let (filter, sort) = request.unwrap_or_default();
+                        let source = RecordSource::load(&mut *tx, entity_id).await?;
+                        let (sql, params) = PageSqlBuilder::export(source, filter.as_ref(), &sort);
+                        let declare = format!("DECLARE {} NO SCROLL CURSOR FOR {}", EXPORT_CURSOR, sql);
+                        let mut statement = sqlx::query(&declare);
+                        for param in params {
+                            statement = match param {
+                                SqlParam::Uuid(value) => statement.bind(value),
+                                SqlParam::Text(value) => statement.bind(value),
+                            };
+                        }
+                        statement.execute(&mut *tx).await?;
+                        tx
+                    },
+                };
+
+                let rows = sqlx::query(&fetch).fetch_all(&mut *tx).await?;
+                if rows.is_empty() {
+                    tx.commit().await?;
+                    return Ok(None);
+                }
+                let mut records = Vec::with_capacity(rows.len());
+                for row in &rows {
+                    records.push(Self::map_export_row(row)?);
+                }
+                Ok::<_, Box<dyn Error + Send + Sync>>(Some((records, (Some(tx), None))))
+            }
+        })
+        .boxed()
+    }
+
+    async fn count_by_entity(&self, entity_id: Uuid) -> Result<i64, Box<dyn Error + Send + Sync>> {
+        let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM tuplas WHERE entity_id = $1 AND deleted_at IS NULL")
+            .bind(entity_id)
+            .fetch_one(&*self.pool)
+            .await
+            .map_err(|e| Box::new(e) as Box<dyn Error + Send + Sync>)?;
+        Ok(count)
+    }
+
+    async fn count_missing_values(&self, entity_id: Uuid, attribute_id: Uuid, column: StorageColumn) -> Result<i64, Box<dyn Error + Send + Sync>> {
+        let source = self.source(entity_id).await?;
+        let sql = format!(
+            "SELECT COUNT(*) FROM tuplas t WHERE t.entity_id = $1 AND t.deleted_at IS NULL AND {} IS NULL",
+            stored_value_sql(&source, attribute_id, column)
+        );
+        let count: i64 = sqlx::query_scalar(&sql)
+            .bind(entity_id)
+            .fetch_one(&*self.pool)
+            .await
+            .map_err(|e| Box::new(e) as Box<dyn Error + Send + Sync>)?;
+        Ok(count)
+    }
+
+    async fn count_pattern_mismatches(
+        &self,
+        entity_id: Uuid,
+        attribute_id: Uuid,
+        column: StorageColumn,
+        pattern: &str,
+    ) -> Result<Option<i64>, Box<dyn Error + Send + Sync>> {
+        let source = self.source(entity_id).await?;
+        let sql = format!(
+            "SELECT COUNT(*) FROM (SELECT {}::text AS value FROM tuplas t WHERE t.entity_id = $1 AND t.deleted_at IS NULL) v \
+             WHERE v.value IS NOT NULL AND v.value !~ ('^(?:' || $2 || ')$')",
+            stored_value_sql(&source, attribute_id, column)
+        );
+        match sqlx::query_scalar::<_, i64>(&sql).bind(entity_id).bind(pattern).fetch_one(&*self.pool).await {
+            Ok(count) => Ok(Some(count)),
+            // invalid_regular_expression: la sintaxis de Postgres no coincide del todo con la de `regex`
+            Err(sqlx::Error::Database(e)) if e.code().as_deref() == Some("2201B") => Ok(None),
+            Err(e) => Err(Box::new(e)),
+        }
+    }
+
+    async fn count_duplicated_values(&self, entity_id: Uuid, key: &[(Uuid, StorageColumn)]) -> Result<i64, Box<dyn Error + Send + Sync>> {
+        if key.is_empty() {
+            return Ok(0);
+        }
+        let source = self.source(entity_id).await?;
+        let columns: Vec<String> = key
+            .iter()
+            .enumerate()
+            .map(|(index, (attribute_id, column))| format!("{} AS k{}", stored_value_sql(&source, *attribute_id, *column), index))
+            .collect();
+        let aliases: Vec<String> = (0..key.len()).map(|index| format!("k{}", index)).collect();
+        let complete: Vec<String> = aliases.iter().map(|alias| format!("{} IS NOT NULL", alias)).collect();
+        let sql = format!(
+            "SELECT COALESCE(SUM(d.n), 0)::bigint FROM ( \
+                 SELECT COUNT(*) AS n FROM (SELECT {} FROM tuplas t WHERE t.entity_id = $1 AND t.deleted_at IS NULL) v \
+                 WHERE {} GROUP BY {} HAVING COUNT(*) > 1 \
+             ) d",
+            columns.join(", "), complete.join(" AND "), aliases.join(", ")
+        );
+        let count: i64 = sqlx::query_scalar(&sql)
+            .bind(entity_id)
+            .fetch_one(&*self.pool)
+            .await
+            .map_err(|e| Box::new(e) as Box<dyn Error + Send + Sync>)?;
+        Ok(count)
+    }
+
+    async fn find_deleted(&self, entity_id: Uuid, limit: i64, offset: i64) -> Result<Vec<DeletedRecordDto>, Box<dyn Error + Send + Sync>> {
+        let source = self.source(entity_id).await?;
+        let sql = format!(
+            "{} WHERE t.entity_id = $1 AND t.deleted_at IS NOT NULL ORDER BY t.deleted_at DESC, t.id LIMIT $2 OFFSET $3",
+            select_record(&source)
+        );
+        let rows = sqlx::query(&sql)
+            .bind(entity_id)
+            .bind(limit)
+            .bind(offset)
+            .fetch_all(&*self.pool)
+            .await
+            .map_err(|e| Box::new(e) as Box<dyn Error + Send + Sync>)?;
+
+        let mut records = Vec::with_capacity(rows.len());
+        for row in rows {
+            records.push(Self::map_deleted_row(&row)?);
+        }
+        Ok(records)
+    }
+
+    async fn count_deleted(&self, entity_id: Uuid) -> Result<i64, Box<dyn Error + Send + Sync>> {
+        let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM tuplas WHERE entity_id = $1 AND deleted_at IS NOT NULL")
+            .bind(entity_id)
+            .fetch_one(&*self.pool)
+            .await
+            .map_err(|e| Box::new(e) as Box<dyn Error + Send + Sync>)?;
+        Ok(count)
+    }
+
+    async fn find_deleted_by_id(&self, entity_id: Uuid, id: Uuid) -> Result<Option<DeletedRecordDto>, Box<dyn Error + Send + Sync>> {
+        let source = self.source(entity_id).await?;
+        let sql = format!("{} WHERE t.entity_id = $1 AND t.id = $2 AND t.deleted_at IS NOT NULL", select_record(&source));
+        let row = sqlx::query(&sql)
+            .bind(entity_id)
+            .bind(id)
+            .fetch_optional(&*self.pool)
+            .await
+            .map_err(|e| Box::new(e) as Box<dyn Error + Send + Sync>)?;
+
+        match row {
+            Some(row) => Ok(Some(Self::map_deleted_row(&row)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn find_deleted_before(&self, deleted_before: DateTime<Utc>) -> Result<Vec<(Uuid, Uuid)>, Box<dyn Error + Send + Sync>> {
+        let rows = sqlx::query(
+                "SELECT entity_id, id FROM tuplas WHERE deleted_at IS NOT NULL AND deleted_at < $1 ORDER BY deleted_at, id"
+            )
+            .bind(deleted_before)
+            .fetch_all(&*self.pool)
+            .await
+            .map_err(|e| Box::new(e) as Box<dyn Error + Send + Sync>)?;
+
+        let mut keys = Vec::with_capacity(rows.len());
+        for row in rows {
+            keys.push((row.try_get("entity_id")?, row.try_get("id")?));
+        }
+        Ok(keys)
+    }
+
+    async fn find_history(
+        &self,
+        entity_id: Uuid,
+        tuple_id: Uuid,
+        limit: i64,
+        before_id: Option<i64>
+    ) -> Result<Vec<RecordHistoryEntryDto>, Box<dyn Error + Send + Sync>> {
+        let sql = format!(
+            "{} WHERE tuple_id = $1 AND entity_id = $2 AND ($3::bigint IS NULL OR id < $3) ORDER BY id DESC LIMIT $4",
+            SELECT_HISTORY
+        );
+        let rows = sqlx::query(&sql)
+            .bind(tuple_id)
+            .bind(entity_id)
+            .bind(before_id)
+            .bind(limit)
+            .fetch_all(&*self.pool)
+            .await
+            .map_err(|e| Box::new(e) as Box<dyn Error + Send + Sync>)?;
+
+        let mut entries = Vec::with_capacity(rows.len());
+        for row in rows {
+            entries.push(Self::map_history_row(&row)?);
+        }
+        Ok(entries)
+    }
+
+    async fn find_history_since(
+        &self,
+        entity_id: Uuid,
+        tuple_id: Uuid,
+        since: DateTime<Utc>
+    ) -> Result<Vec<RecordHistoryEntryDto>, Box<dyn Error + Send + Sync>> {
+        let sql = format!(
+            "{} WHERE tuple_id = $1 AND entity_id = $2 AND changed_at > $3 ORDER BY id DESC",
+            SELECT_HISTORY
+        );
+        let rows = sqlx::query(&sql)
+            .bind(tuple_id)
+            .bind(entity_id)
+            .bind(since)
+            .fetch_all(&*self.pool)
+            .await
+            .map_err(|e| Box::new(e) as Box<dyn Error + Send + Sync>)?;
+
+        let mut entries = Vec::with_capacity(rows.len());
+        for row in rows {
+            entries.push(Self::map_history_row(&row)?);
+        }
+        Ok(entries)
+    }
+}
+
+/// Parámetro enlazado a la consulta de una página.
+enum SqlParam {
+    Uuid(Uuid),
+    Text(String),
+}
+
+fn sort_alias(index: usize) -> String {
+    format!("sort_{}", index)
+}
+
+/// Traduce una `RecordQuery` a SQL parametrizado. Los valores del filtro y del
+/// cursor siempre viajan como parámetros; en el texto solo se interpolan nombres
+/// de columna conocidos (de `StorageColumn`/`SystemField`) y el límite numérico.
+///
+/// Los registros se ordenan por las claves pedidas (NULL al final) y por `id`;
+/// el cursor se aplica como comparación lexicográfica sobre esas mismas claves.
+struct PageSqlBuilder {
+    source: RecordSource,
+    params: Vec<SqlParam>,
+}
+
+impl PageSqlBuilder {
+    fn build(source: RecordSource, query: &RecordQuery) -> (String, Vec<SqlParam>) {
+        let mut builder = PageSqlBuilder { source, params: Vec::new() };
+        let entity_param = builder.push(SqlParam::Uuid(source.entity_id));
+
+        let mut sort_columns = Vec::with_capacity(query.sort.len());
+        for (index, key) in query.sort.iter().enumerate() {
+            let expression = builder.sort_expression(&key.field);
+            sort_columns.push(format!("{} AS {}", expression, sort_alias(index)));
+        }
+        let filter = match &query.filter {
+            Some(filter) => builder.filter(filter),
+            None => "TRUE".to_string(),
+        };
+        let keyset = match &query.after {
+            Some(cursor) => builder.keyset(query, &cursor.values, cursor.id),
+            None => "TRUE".to_string(),
+        };
+
+        let order_by = |prefix: &str| {
+            let mut terms: Vec<String> = query.sort.iter().enumerate()
+                .map(|(index, key)| {
+                    let direction = match key.direction {
+                        SortDirection::Asc => "ASC",
+                        SortDirection::Desc => "DESC",
+                    };
+                    format!("{}.{} {} NULLS LAST", prefix, sort_alias(index), direction)
+                })
+                .collect();
+            terms.push(format!("{}.id", prefix));
+            terms.join(", ")
+        };
+        let sort_select: String = (0..query.sort.len())
+            .map(|index| format!(", page.{0}::text AS {0}", sort_alias(index)))
+            .collect();
+        let sort_inner: String = sort_columns.iter().map(|c| format!(", {}", c)).collect();
+
+        let sql = format!(
+            r#"
+            WITH page AS (
+                SELECT k.* FROM (
+                    SELECT t.id{sort_inner}
+                    FROM tuplas t
+                    WHERE t.entity_id = {entity_param} AND t.deleted_at IS NULL AND ({filter})
+                ) k
+                WHERE {keyset}
+                ORDER BY {inner_order}
+                LIMIT {limit}
+            )
+            SELECT r.*{sort_select}
+            FROM page
+            JOIN LATERAL ({select_record} WHERE t.id = page.id) r ON TRUE
+            ORDER BY {outer_order}
+            "#,
+            sort_inner = sort_inner,
+            entity_param = entity_param,
+            filter = filter,
+            keyset = keyset,
+            inner_order = order_by("k"),
+            limit = query.limit.max(0),
+            sort_select = sort_select,
+            select_record = select_record(&source),
+            outer_order = order_by("page"),
+        );
+        (sql, builder.params)
+    }
+
+    /// Consulta de la exportación: todos los registros que cumplen el filtro,
+    /// ordenados por las claves pedidas (NULL al final) y por `id`.
+    fn export(source: RecordSource, filter: Option<&ResolvedFilter>, sort: &[SortKey]) -> (String, Vec<SqlParam>) {
+        let mut builder = PageSqlBuilder { source, params: Vec::new() };
+        let entity_param = builder.push(SqlParam::Uuid(source.entity_id));
+        let filter = match filter {
+            Some(filter) => builder.filter(filter),
+            None => "TRUE".to_string(),
+        };
+        let mut order_by: Vec<String> = sort.iter()
+            .map(|key| {
+                let direction = match key.direction {
+                    SortDirection::Asc => "ASC",
+                    SortDirection::Desc => "DESC",
+                };
+                format!("{} {} NULLS LAST", builder.sort_expression(&key.field), direction)
+            })
+            .collect();
+        order_by.push("t.id".to_string());
+
+        let sql = format!(
+            "{} WHERE t.entity_id = {} AND t.deleted_at IS NULL AND ({}) ORDER BY {}",
+            select_export_record(&source), entity_param, filter, order_by.join(", "),
+        );
+        (sql, builder.params)
+    }
+
+    fn push(&mut self, param: SqlParam) -> String {
+        self.params.push(param);
+        format!("${}", self.params.len())
+    }
+
+    /// Parámetro de texto convertido al tipo de la columna.
+    fn value(&mut self, column: StorageColumn, text: &str) -> String {
+        let param = self.push(SqlParam::Text(text.to_string()));
+        column.cast_expression(&param)
+    }
+
+    fn sort_expression(&mut self, field: &QueryField) -> String {
+        match field {
+            QueryField::System(system) => format!("t.{}", system.name()),
+            QueryField::Attribute { attribute_id, .. } if self.source.mode == StorageMode::Physical => format!(
+                "(SELECT sv.{} FROM {} sv WHERE sv.id = t.id)",
+                physical_column_name(*attribute_id), self.source.table(),
+            ),
+            QueryField::Attribute { attribute_id, column } => {
+                let attribute_param = self.push(SqlParam::Uuid(*attribute_id));
+                format!(
+                    "(SELECT sv.{col} FROM attribute_values sv WHERE sv.instance_id = t.id AND sv.attribute_id = {attr} LIMIT 1)",
+                    col = column.column_name(),
+                    attr = attribute_param,
+                )
+            },
+        }
+    }
+
+    fn filter(&mut self, filter: &ResolvedFilter) -> String {
+        match filter {
+            ResolvedFilter::And(items) => self.join(items, " AND "),
+            ResolvedFilter::Or(items) => self.join(items, " OR "),
+            ResolvedFilter::Not(inner) => format!("NOT ({})", self.filter(inner)),
+            ResolvedFilter::Condition(condition) => self.condition(condition),
+        }
+    }
+
+    fn join(&mut self, items: &[ResolvedFilter], separator: &str) -> String {
+        let parts: Vec<String> = items.iter().map(|item| self.filter(item)).collect();
+        format!("({})", parts.join(separator))
+    }
+
+    /// Predicado sobre una expresión de columna; `ne` se trata aparte porque
+    /// un valor ausente también cuenta como distinto.
+    fn predicate(&mut self, expression: &str, condition: &ResolvedCondition) -> String {
+        let column = condition.field.storage();
+        match condition.operator {
+            FilterOperator::Eq | FilterOperator::Ne => format!("{} = {}", expression, self.value(column, &condition.values[0])),
+            FilterOperator::Lt => format!("{} < {}", expression, self.value(column, &condition.values[0])),
+            FilterOperator::Le => format!("{} <= {}", expression, self.value(column, &condition.values[0])),
+            FilterOperator::Gt => format!("{} > {}", expression, self.value(column, &condition.values[0])),
+            FilterOperator::Ge => format!("{} >= {}", expression, self.value(column, &condition.values[0])),
+            FilterOperator::In => {
+                let values: Vec<String> = condition.values.iter().map(|v| self.value(column, v)).collect();
+                format!("{} IN ({})", expression, values.join(", "))
+            },
+            FilterOperator::Contains => {
+                let pattern = self.push(SqlParam::Text(format!("%{}%", escape_like(&condition.values[0]))));
+                format!("{} LIKE {} ESCAPE '\\'", expression, pattern)
+            },
+            FilterOperator::StartsWith => {
+                let pattern = self.push(SqlParam::Text(format!("{}%", escape_like(&condition.values[0]))));
+                format!("{} LIKE {} ESCAPE '\\'", expression, pattern)
+            },
+            FilterOperator::IsNull | FilterOperator::IsNotNull => format!("{} IS NOT NULL", expression),
+        }
+    }
+
+    fn condition(&mut self, condition: &ResolvedCondition) -> String {
+        // Los operadores "negativos" se expresan como la negación de su positivo,
+        // de modo que las tuplas sin valor almacenado también los cumplan.
+        let negated = matches!(condition.operator, FilterOperator::Ne | FilterOperator::IsNull);
+        let positive = match condition.field {
+            QueryField::System(system) => {
+                let expression = format!("t.{}", system.name());
+                let predicate = self.predicate(&expression, condition);
+                if negated {
+                    return format!("NOT COALESCE({}, FALSE)", predicate);
+                }
+                predicate
+            },
+            QueryField::Attribute { attribute_id, .. } if self.source.mode == StorageMode::Physical => {
+                let expression = format!("fv.{}", physical_column_name(attribute_id));
+                let predicate = self.predicate(&expression, condition);
+                format!("EXISTS (SELECT 1 FROM {} fv WHERE fv.id = t.id AND {})", self.source.table(), predicate)
+            },
+            QueryField::Attribute { attribute_id, column } => {
+                let attribute_param = self.push(SqlParam::Uuid(attribute_id));
+                let expression = format!("fv.{}", column.column_name());
+                let predicate = self.predicate(&expression, condition);
+                format!(
+                    "EXISTS (SELECT 1 FROM attribute_values fv WHERE fv.instance_id = t.id AND fv.attribute_id = {} AND {})",
+                    attribute_param, predicate
+                )
+            },
+        };
+        if negated { format!("NOT {}", positive) } else { positive }
+    }
+
+    /// Registros posteriores al cursor: (k0, k1, ..., id) > (c0, c1, ..., cid)
+    /// en el orden de la consulta, con los NULL detrás de cualquier valor.
+    fn keyset(&mut self, query: &RecordQuery, values: &[Option<String>], cursor_id: Uuid) -> String {
+        let mut equal_prefix: Vec<String> = Vec::new();
+        let mut alternatives: Vec<String> = Vec::new();
+
+        for (index, (key, value)) in query.sort.iter().zip(values).enumerate() {
+            let column = format!("k.{}", sort_alias(index));
+            match value {
+                Some(text) => {
+                    let cursor_value = self.value(key.field.storage(), text);
+                    let operator = match key.direction {
+                        SortDirection::Asc => ">",
+                        SortDirection::Desc => "<",
+                    };
+                    let mut terms = equal_prefix.clone();
+                    terms.push(format!("({0} {1} {2} OR {0} IS NULL)", column, operator, cursor_value));
+                    alternatives.push(format!("({})", terms.join(" AND ")));
+                    equal_prefix.push(format!("{} = {}", column, cursor_value));
+                },
+                // Nada va detrás de un NULL en esta clave: solo cuentan los empates
+                None => equal_prefix.push(format!("{} IS NULL", column)),
+            }
+        }
+
+        let id_param = self.push(SqlParam::Uuid(cursor_id));
+        let mut terms = equal_prefix;
+        terms.push(format!("k.id > {}", id_param));
+        alternatives.push(format!("({})", terms.join(" AND ")));
+        format!("({})", alternatives.join(" OR "))
+    }
+}
+
+/// Escapa los comodines de LIKE para buscar el texto literal.
+fn escape_like(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('%', "\\%").replace('_', "\\_")
+}
@@ -0,0 +1,62 @@
+// src/Infrastructure/repositories/record_storage.rs
+// Fragmentos SQL comunes a los repositorios de registros para leer los valores de las
+// entidades que los guardan en su tabla física (modo de almacenamiento `physical`).
+
+use uuid::Uuid;
+
+use crate::Domain::physical_tables::{StorageMode, physical_table_name};
+
+/// Clave del advisory lock del modo de almacenamiento de una entidad: las escrituras de
+/// registros lo toman en modo compartido y el cambio de modo en exclusiva.
+pub(crate) fn storage_lock_key(entity_id: Uuid) -> String {
+    format!("storage:{}", entity_id)
+}
+
+/// Entidad cuyos registros se leen y dónde guarda sus valores.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct RecordSource {
+    pub entity_id: Uuid,
+    pub mode: StorageMode,
+}
+
+impl RecordSource {
+    /// Lee el modo de almacenamiento de la entidad (EAV si la entidad no existe).
+    pub(crate) async fn load<'e, E>(executor: E, entity_id: Uuid) -> Result<Self, sqlx::Error>
+    where
+        E: sqlx::PgExecutor<'e>,
+    {
+        let mode: Option<String> = sqlx::query_scalar("SELECT storage_mode FROM logical_entities WHERE id = $1")
+            .bind(entity_id)
+            .fetch_optional(executor)
+            .await?;
+        let mode = match mode {
+            Some(mode) => StorageMode::parse(&mode).map_err(|e| sqlx::Error::ColumnDecode {
+                index: "storage_mode".to_string(),
+                source: e.to_string().into(),
+            })?,
+            None => StorageMode::Eav,
+        };
+        Ok(Self { entity_id, mode })
+    }
+
+    pub(crate) fn table(&self) -> String {
+        physical_table_name(self.entity_id)
+    }
+}
+
+/// Valores de la fila `p` de la tabla física como pares `kv` (clave, valor JSON), unidos a
+/// su atributo `a` y su tipo de dato `dt`. `entity_expression` es la entidad de la tabla.
+/// Las columnas que no corresponden a un atributo (`id`) quedan fuera.
+pub(crate) fn physical_values_from(table: &str, entity_expression: &str) -> String {
+    format!(
+        "{} p CROSS JOIN LATERAL jsonb_each(to_jsonb(p)) kv \
+         JOIN attributes a ON a.entity_id = {} AND kv.key = 'a_' || replace(a.id::text, '-', '') \
+         JOIN data_types dt ON dt.id = a.data_type_id",
+        table, entity_expression
+    )
+}
+
+/// Valor `kv.value` de `physical_values_from` en la representación de la API: to_jsonb
+/// serializa bytea en hexadecimal (`\x...`) y la API lo devuelve en base64.
+pub(crate) const PHYSICAL_VALUE_JSON: &str = "CASE WHEN dt.storage_column = 'binary_value' \
+    THEN to_jsonb(encode(decode(substr(kv.value #>> '{}', 3), 'hex'), 'base64')) ELSE kv.value END";
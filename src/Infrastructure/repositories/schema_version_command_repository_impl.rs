@@ -0,0 +1,107 @@
+use async_trait::async_trait;
+use diesel::prelude::*;
+use diesel::sql_types;
+use diesel_async::{AsyncPgConnection, RunQueryDsl};
+use std::error::Error;
+use uuid::Uuid;
+use anyhow::Context;
+use log::debug;
+
+use crate::Application::ports::driven::repositories::SchemaVersionCommandRepository;
+
+// La definición se construye en SQL dentro de la transacción, con la misma forma
+// que Domain::schema_versions::SchemaSnapshot (Diesel no tiene soporte de serde_json).
+const INSERT_SNAPSHOT: &str = r#"
+    INSERT INTO entity_schema_versions (entity_id, version, definition, change_summary, created_by)
+    SELECT
+        le.id,
+        COALESCE((SELECT MAX(v.version) FROM entity_schema_versions v WHERE v.entity_id = le.id), 0) + 1,
+        jsonb_build_object(
+            'entity_name', le.name,
+            'attributes', COALESCE((
+                SELECT jsonb_agg(jsonb_build_object(
+                    'attribute_id', a.id,
+                    'name', a.name,
+                    'description', a.description,
+                    'data_type_id', a.data_type_id,
+                    'data_type_name', dt.name,
+                    'position', a.position,
+                    'is_required', a.is_required,
+                    'is_unique', a.is_unique,
+                    'default_value', a.default_value,
+                    'validation_regex', a.validation_regex,
+                    'show_option_label', a.show_option_label,
+                    'is_searchable', a.is_searchable,
+                    'options', COALESCE((
+                        SELECT jsonb_agg(jsonb_build_object(
+                            'code', o.code,
+                            'label', o.label,
+                            'sort_order', o.sort_order,
+                            'is_active', o.is_active
+                        ) ORDER BY o.sort_order, o.code)
+                        FROM attribute_options o
+                        WHERE o.attribute_id = a.id
+                    ), '[]'::jsonb),
+                    'reference', CASE WHEN a.reference_entity_id IS NULL THEN NULL ELSE jsonb_build_object(
+                        'entity_id', a.reference_entity_id,
+                        'on_delete', a.reference_on_delete,
+                        'display_attribute_id', a.reference_display_attribute_id
+                    ) END,
+                    'status', a.status
+                ) ORDER BY a.position, a.name)
+                FROM attributes a
+                JOIN data_types dt ON dt.id = a.data_type_id
+                WHERE a.entity_id = le.id
+            ), '[]'::jsonb)
+        ),
+        $2,
+        $3
+    FROM logical_entities le
+    WHERE le.id = $1
+    RETURNING version
+"#;
+
+/// Fila de resultado de INSERT_SNAPSHOT.
+#[derive(QueryableByName)]
+struct VersionRow {
+    #[diesel(sql_type = sql_types::Int4)]
+    version: i32,
+}
+
+#[derive(Clone, Copy)]
+pub struct SchemaVersionCommandRepositoryImpl;
+
+impl SchemaVersionCommandRepositoryImpl {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+#[async_trait]
+impl SchemaVersionCommandRepository for SchemaVersionCommandRepositoryImpl {
+    async fn create_snapshot(
+        &self,
+        conn: &mut AsyncPgConnection,
+        entity_id: Uuid,
+        change_summary: Option<&str>,
+        created_by: Uuid,
+    ) -> Result<i32, Box<dyn Error + Send + Sync>> {
+        // Serializa la numeración de versiones de la entidad hasta el fin de la transacción
+        diesel::sql_query("SELECT pg_advisory_xact_lock(hashtextextended($1, 0))")
+            .bind::<sql_types::Text, _>(format!("schema_version:{}", entity_id))
+            .execute(conn)
+            .await
+            .context(format!("Failed to lock schema versions of entity {}", entity_id))?;
+
+        let row = diesel::sql_query(INSERT_SNAPSHOT)
+            .bind::<sql_types::Uuid, _>(entity_id)
+            .bind::<sql_types::Nullable<sql_types::Text>, _>(change_summary)
+            .bind::<sql_types::Uuid, _>(created_by)
+            .get_result::<VersionRow>(conn)
+            .await
+            .context(format!("Failed to store schema version of entity {}", entity_id))?;
+
+        debug!("Versión {} de la definición de la entidad {} registrada", row.version, entity_id);
+        Ok(row.version)
+    }
+}
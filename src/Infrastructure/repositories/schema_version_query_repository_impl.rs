@@ -0,0 +1,82 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{Pool, Postgres, Row};
+use sqlx::postgres::PgRow;
+use serde_json::Value;
+use std::sync::Arc;
+use uuid::Uuid;
+use std::error::Error;
+
+use crate::Application::ports::driven::repositories::{SchemaVersionQueryRepository, SchemaVersionDto};
+
+const SELECT_VERSION: &str = r#"
+    SELECT id, entity_id, version, definition, change_summary, created_by, created_at
+    FROM entity_schema_versions
+"#;
+
+#[derive(Clone)]
+pub struct SchemaVersionQueryRepositoryImpl {
+    pool: Arc<Pool<Postgres>>,
+}
+
+impl SchemaVersionQueryRepositoryImpl {
+    /// Constructor Preferido: Recibe el pool (Inyección de Dependencias).
+    pub fn with_pool(pool: Arc<Pool<Postgres>>) -> Self {
+        Self { pool }
+    }
+
+    /// Mapeo manual de una fila de `entity_schema_versions` al DTO.
+    fn map_row(row: &PgRow) -> Result<SchemaVersionDto, Box<dyn Error + Send + Sync>> {
+        let definition: Value = row.try_get("definition")?;
+        Ok(SchemaVersionDto {
+            id: row.try_get("id")?,
+            entity_id: row.try_get("entity_id")?,
+            version: row.try_get("version")?,
+            definition: serde_json::from_value(definition)?,
+            change_summary: row.try_get("change_summary")?,
+            created_by: row.try_get("created_by")?,
+            created_at: row.try_get("created_at")?,
+        })
+    }
+}
+
+#[async_trait]
+impl SchemaVersionQueryRepository for SchemaVersionQueryRepositoryImpl {
+    async fn find_by_entity_id(&self, entity_id: Uuid) -> Result<Vec<SchemaVersionDto>, Box<dyn Error + Send + Sync>> {
+        let sql = format!("{} WHERE entity_id = $1 ORDER BY version DESC", SELECT_VERSION);
+        let rows = sqlx::query(&sql)
+            .bind(entity_id)
+            .fetch_all(&*self.pool)
+            .await
+            .map_err(|e| Box::new(e) as Box<dyn Error + Send + Sync>)?;
+
+        rows.iter().map(Self::map_row).collect()
+    }
+
+    async fn find_version(&self, entity_id: Uuid, version: i32) -> Result<Option<SchemaVersionDto>, Box<dyn Error + Send + Sync>> {
+        let sql = format!("{} WHERE entity_id = $1 AND version = $2", SELECT_VERSION);
+        let row = sqlx::query(&sql)
+            .bind(entity_id)
+            .bind(version)
+            .fetch_optional(&*self.pool)
+            .await
+            .map_err(|e| Box::new(e) as Box<dyn Error + Send + Sync>)?;
+
+        row.as_ref().map(Self::map_row).transpose()
+    }
+
+    async fn find_as_of(&self, entity_id: Uuid, at: DateTime<Utc>) -> Result<Option<SchemaVersionDto>, Box<dyn Error + Send + Sync>> {
+        let sql = format!(
+            "{} WHERE entity_id = $1 AND created_at <= $2 ORDER BY version DESC LIMIT 1",
+            SELECT_VERSION
+        );
+        let row = sqlx::query(&sql)
+            .bind(entity_id)
+            .bind(at)
+            .fetch_optional(&*self.pool)
+            .await
+            .map_err(|e| Box::new(e) as Box<dyn Error + Send + Sync>)?;
+
+        row.as_ref().map(Self::map_row).transpose()
+    }
+}
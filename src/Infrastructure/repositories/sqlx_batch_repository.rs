@@ -1,13 +1,33 @@
-use anyhow::{Result, anyhow};
-//use futures::{stream, StreamExt};
-use sqlx::{Pool, Postgres};
+use async_trait::async_trait;
+use sqlx::{PgConnection, Pool, Postgres, Row};
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::error::Error;
 use std::sync::Arc;
-//use uuid::Uuid;
+use uuid::Uuid;
+use log::debug;
 
-use crate::Domain::entities::user::User; // Asumiendo que es para User
+use crate::Application::ports::driven::repositories::{
+    RecordBatchRepository,
+    NewRecordDto,
+    UniqueKeyValuesDto,
+    ReferencedTuplesDto,
+    BatchInsertOutcome,
+};
+use crate::Domain::records::StorageColumn;
+use crate::Domain::record_history::{ChangeContext, RecordChangeKind};
+use crate::Domain::physical_tables::{StorageMode, physical_column_name};
 use crate::Infrastructure::repositories::sqlx_repository_base::SqlxRepositoryBase;
+use crate::Infrastructure::repositories::record_command_repository_impl::VALUE_JSON_EXPRESSION;
+use crate::Infrastructure::repositories::record_storage::{
+    RecordSource, storage_lock_key, physical_values_from, PHYSICAL_VALUE_JSON,
+};
 
-/// Repositorio para operaciones en lote
+/// Registros (o claves) por sentencia cuando no se indica otro tamaño.
+pub const DEFAULT_BATCH_SIZE: usize = 1_000;
+
+/// Repositorio para operaciones en lote sobre registros (tuplas y sus valores).
+/// Cada sentencia procesa hasta `batch_size` registros pasando los datos como
+/// arrays de Postgres (UNNEST), de modo que el número de parámetros es fijo.
 pub struct BatchRepository {
     base: SqlxRepositoryBase,
     batch_size: usize,
@@ -16,14 +36,366 @@ pub struct BatchRepository {
 impl BatchRepository {
 
     /// Constructor Preferido: Recibe el pool (Inyección de Dependencias).
-    pub fn with_pool(pool: Arc<Pool<Postgres>>, entity_name: &str, batch_size: usize) -> Self {
+    pub fn with_pool(pool: Arc<Pool<Postgres>>, batch_size: usize) -> Self {
         Self {
-            base: SqlxRepositoryBase::with_pool(pool, entity_name),
-            batch_size,
+            base: SqlxRepositoryBase::with_pool(pool, "record"),
+            batch_size: batch_size.max(1),
+        }
+    }
+
+    /// Posiciones de las claves ya usadas por algún registro de la entidad.
+    /// Las claves se agrupan por grupo de unicidad (mismos atributos y columnas)
+    /// y se comprueban con una consulta por lote.
+    async fn existing_keys(
+        &self,
+        conn: &mut PgConnection,
+        source: RecordSource,
+        keys: &[UniqueKeyValuesDto],
+    ) -> Result<HashSet<usize>, Box<dyn Error + Send + Sync>> {
+        let entity_id = source.entity_id;
+        let mut buckets: BTreeMap<(i16, Vec<Uuid>), Vec<usize>> = BTreeMap::new();
+        for (index, key) in keys.iter().enumerate() {
+            let attribute_ids = key.matches.iter().map(|m| m.attribute_id).collect();
+            buckets.entry((key.group, attribute_ids)).or_default().push(index);
+        }
+
+        let mut found = HashSet::new();
+        for indexes in buckets.values() {
+            let template = &keys[indexes[0]].matches;
+            // k(idx, v0, v1, ...): una columna de texto por atributo de la clave
+            let value_columns: Vec<String> = (0..template.len()).map(|i| format!("v{}", i)).collect();
+            let mut sql = format!(
+                "SELECT k.idx FROM UNNEST($2::int[]{}) AS k(idx, {}) \
+                 WHERE EXISTS (SELECT 1 FROM tuplas t WHERE t.entity_id = $1",
+                (0..template.len()).map(|i| format!(", ${}::text[]", 3 + i)).collect::<String>(),
+                value_columns.join(", "),
+            );
+            match source.mode {
+                StorageMode::Eav => {
+                    for (i, m) in template.iter().enumerate() {
+                        // Los nombres de columna provienen de StorageColumn; los ID de atributo se enlazan
+                        sql.push_str(&format!(
+                            " AND EXISTS (SELECT 1 FROM attribute_values av WHERE av.instance_id = t.id \
+                             AND av.attribute_id = ${} AND av.{} = {})",
+                            3 + template.len() + i,
+                            m.column.column_name(),
+                            m.column.cast_expression(&format!("k.{}", value_columns[i])),
+                        ));
+                    }
+                },
+                StorageMode::Physical => {
+                    // Una condición por columna en la fila de la tabla física
+                    sql.push_str(&format!(" AND EXISTS (SELECT 1 FROM {} p WHERE p.id = t.id", source.table()));
+                    for (i, m) in template.iter().enumerate() {
+                        sql.push_str(&format!(
+                            " AND p.{} = {}",
+                            physical_column_name(m.attribute_id),
+                            m.column.cast_expression(&format!("k.{}", value_columns[i])),
+                        ));
+                    }
+                    sql.push(')');
+                },
+            }
+            sql.push(')');
+
+            for chunk in indexes.chunks(self.batch_size) {
+                let mut statement = sqlx::query(&sql)
+                    .bind(entity_id)
+                    .bind(chunk.iter().map(|&index| index as i32).collect::<Vec<i32>>());
+                for i in 0..template.len() {
+                    let values: Vec<String> = chunk.iter().map(|&index| keys[index].matches[i].value.clone()).collect();
+                    statement = statement.bind(values);
+                }
+                if source.mode == StorageMode::Eav {
+                    for m in template {
+                        statement = statement.bind(m.attribute_id);
+                    }
+                }
+                let rows = statement
+                    .fetch_all(&mut *conn)
+                    .await
+                    .map_err(|e| Box::new(e) as Box<dyn Error + Send + Sync>)?;
+                for row in rows {
+                    let index: i32 = row.try_get("idx").map_err(|e| Box::new(e) as Box<dyn Error + Send + Sync>)?;
+                    found.insert(index as usize);
+                }
+            }
+        }
+        Ok(found)
+    }
+
+    /// IDs de `ids` que existen en la entidad (fuera de la papelera). Con `lock`, las tuplas quedan
+    /// bloqueadas (FOR KEY SHARE) hasta el fin de la transacción.
+    async fn existing_tuples(
+        &self,
+        conn: &mut PgConnection,
+        entity_id: Uuid,
+        ids: &[Uuid],
+        lock: bool,
+    ) -> Result<HashSet<Uuid>, Box<dyn Error + Send + Sync>> {
+        let sql = format!(
+            "SELECT id FROM tuplas WHERE entity_id = $1 AND id = ANY($2) AND deleted_at IS NULL{}",
+            if lock { " FOR KEY SHARE" } else { "" },
+        );
+        let mut found = HashSet::new();
+        for chunk in ids.chunks(self.batch_size) {
+            let chunk_ids: Vec<Uuid> = chunk.to_vec();
+            let rows = sqlx::query(&sql)
+                .bind(entity_id)
+                .bind(chunk_ids)
+                .fetch_all(&mut *conn)
+                .await
+                .map_err(|e| Box::new(e) as Box<dyn Error + Send + Sync>)?;
+            for row in rows {
+                found.insert(row.try_get::<Uuid, _>("id").map_err(|e| Box::new(e) as Box<dyn Error + Send + Sync>)?);
+            }
+        }
+        Ok(found)
+    }
+
+    /// Inserta un lote de registros: una sentencia para las tuplas, una por
+    /// columna de almacenamiento para sus valores (una sola en las tablas físicas)
+    /// y dos para el historial.
+    async fn insert_chunk(
+        &self,
+        conn: &mut PgConnection,
+        source: RecordSource,
+        records: &[NewRecordDto],
+        context: &ChangeContext,
+    ) -> Result<Vec<Uuid>, Box<dyn Error + Send + Sync>> {
+        let entity_id = source.entity_id;
+        let ids: Vec<Uuid> = records.iter().map(|_| Uuid::new_v4()).collect();
+        sqlx::query("INSERT INTO tuplas (id, entity_id, created_by) SELECT UNNEST($1::uuid[]), $2, $3")
+            .bind(ids.clone())
+            .bind(entity_id)
+            .bind(context.actor)
+            .execute(&mut *conn)
+            .await
+            .map_err(|e| Box::new(e) as Box<dyn Error + Send + Sync>)?;
+
+        if source.mode == StorageMode::Physical {
+            self.insert_physical_rows(conn, source, &ids, records).await?;
+            self.insert_history(conn, source, &ids, context).await?;
+            return Ok(ids);
         }
+
+        // columna -> (instance_id, attribute_id, valor)
+        let mut columns: BTreeMap<&'static str, (StorageColumn, Vec<Uuid>, Vec<Uuid>, Vec<String>)> = BTreeMap::new();
+        for (id, record) in ids.iter().zip(records) {
+            for value in &record.values {
+                let entry = columns
+                    .entry(value.column.column_name())
+                    .or_insert_with(|| (value.column, Vec::new(), Vec::new(), Vec::new()));
+                entry.1.push(*id);
+                entry.2.push(value.attribute_id);
+                entry.3.push(value.value.clone());
+            }
+        }
+
+        for (column_name, (column, instance_ids, attribute_ids, values)) in columns {
+            // El valor se enlaza como texto y Postgres lo convierte al tipo de la columna
+            let sql = format!(
+                "INSERT INTO attribute_values (instance_id, attribute_id, {col}) \
+                 SELECT v.instance_id, v.attribute_id, {expr} \
+                 FROM UNNEST($1::uuid[], $2::uuid[], $3::text[]) AS v(instance_id, attribute_id, value)",
+                col = column_name,
+                expr = column.cast_expression("v.value"),
+            );
+            sqlx::query(&sql)
+                .bind(instance_ids)
+                .bind(attribute_ids)
+                .bind(values)
+                .execute(&mut *conn)
+                .await
+                .map_err(|e| Box::new(e) as Box<dyn Error + Send + Sync>)?;
+        }
+
+        self.insert_history(conn, source, &ids, context).await?;
+        Ok(ids)
     }
 
-    pub async fn bulk_insert_users(&self, users: Vec<User>) -> Result<Vec<User>> { /* ... */ }
-    async fn insert_user_batch(&self, users: Vec<User>) -> Result<Vec<User>> { /* ... */ }
-    pub async fn bulk_update_users(&self, users: Vec<User>, concurrency: usize) -> Result<Vec<User>> { /* ... */ }
+    /// Inserta las filas de la tabla física: un array de texto por atributo con el
+    /// valor de cada registro (NULL si no tiene).
+    async fn insert_physical_rows(
+        &self,
+        conn: &mut PgConnection,
+        source: RecordSource,
+        ids: &[Uuid],
+        records: &[NewRecordDto],
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        // atributo -> (columna, valor de cada registro)
+        let mut attributes: BTreeMap<Uuid, (StorageColumn, Vec<Option<String>>)> = BTreeMap::new();
+        for (position, record) in records.iter().enumerate() {
+            for value in &record.values {
+                let entry = attributes
+                    .entry(value.attribute_id)
+                    .or_insert_with(|| (value.column, vec![None; records.len()]));
+                entry.1[position] = Some(value.value.clone());
+            }
+        }
+
+        let value_columns: Vec<String> = (0..attributes.len()).map(|i| format!("c{}", i)).collect();
+        let sql = format!(
+            "INSERT INTO {} (id{}) SELECT v.id{} FROM UNNEST($1::uuid[]{}) AS v(id{})",
+            source.table(),
+            attributes.keys().map(|id| format!(", {}", physical_column_name(*id))).collect::<String>(),
+            attributes.values().zip(&value_columns)
+                .map(|((column, _), name)| format!(", {}", column.cast_expression(&format!("v.{}", name))))
+                .collect::<String>(),
+            (0..attributes.len()).map(|i| format!(", ${}::text[]", 2 + i)).collect::<String>(),
+            value_columns.iter().map(|name| format!(", {}", name)).collect::<String>(),
+        );
+        let mut statement = sqlx::query(&sql).bind(ids.to_vec());
+        for (_, values) in attributes.into_values() {
+            statement = statement.bind(values);
+        }
+        statement
+            .execute(&mut *conn)
+            .await
+            .map_err(|e| Box::new(e) as Box<dyn Error + Send + Sync>)?;
+        Ok(())
+    }
+
+    /// Registra en el historial la creación de las tuplas: el evento de cada tupla y
+    /// sus valores iniciales, leídos de attribute_values (o de la tabla física) con la
+    /// representación de la API.
+    async fn insert_history(
+        &self,
+        conn: &mut PgConnection,
+        source: RecordSource,
+        ids: &[Uuid],
+        context: &ChangeContext,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let entity_id = source.entity_id;
+        let kind = RecordChangeKind::Create.as_str();
+        sqlx::query(
+                "INSERT INTO record_history (entity_id, tuple_id, change_kind, changed_by, request_id) \
+                 SELECT $2, t.id, $3, $4, $5 FROM UNNEST($1::uuid[]) WITH ORDINALITY AS t(id, position) \
+                 ORDER BY t.position"
+            )
+            .bind(ids)
+            .bind(entity_id)
+            .bind(kind)
+            .bind(context.actor)
+            .bind(context.request_id.as_deref())
+            .execute(&mut *conn)
+            .await
+            .map_err(|e| Box::new(e) as Box<dyn Error + Send + Sync>)?;
+
+        let sql = match source.mode {
+            StorageMode::Eav => format!(
+                "INSERT INTO record_history \
+                    (entity_id, tuple_id, attribute_id, attribute_name, change_kind, new_value, changed_by, request_id) \
+                 SELECT $2, av.instance_id, av.attribute_id, a.name, $3, {}, $4, $5 \
+                 FROM attribute_values av JOIN attributes a ON a.id = av.attribute_id \
+                 WHERE av.instance_id = ANY($1::uuid[]) \
+                 ORDER BY av.instance_id, av.attribute_id",
+                VALUE_JSON_EXPRESSION,
+            ),
+            StorageMode::Physical => format!(
+                "INSERT INTO record_history \
+                    (entity_id, tuple_id, attribute_id, attribute_name, change_kind, new_value, changed_by, request_id) \
+                 SELECT $2, p.id, a.id, a.name, $3, {}, $4, $5 \
+                 FROM {} \
+                 WHERE p.id = ANY($1::uuid[]) AND kv.value <> 'null'::jsonb \
+                 ORDER BY p.id, a.id",
+                PHYSICAL_VALUE_JSON, physical_values_from(&source.table(), "$2"),
+            ),
+        };
+        sqlx::query(&sql)
+            .bind(ids)
+            .bind(entity_id)
+            .bind(kind)
+            .bind(context.actor)
+            .bind(context.request_id.as_deref())
+            .execute(&mut *conn)
+            .await
+            .map_err(|e| Box::new(e) as Box<dyn Error + Send + Sync>)?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl RecordBatchRepository for BatchRepository {
+    async fn find_existing_keys(
+        &self,
+        entity_id: Uuid,
+        keys: &[UniqueKeyValuesDto],
+    ) -> Result<HashSet<usize>, Box<dyn Error + Send + Sync>> {
+        let mut conn = self.base.pool().acquire().await.map_err(|e| Box::new(e) as Box<dyn Error + Send + Sync>)?;
+        let source = RecordSource::load(&mut *conn, entity_id)
+            .await
+            .map_err(|e| Box::new(e) as Box<dyn Error + Send + Sync>)?;
+        self.existing_keys(&mut conn, source, keys).await
+    }
+
+    async fn find_existing_tuples(
+        &self,
+        entity_id: Uuid,
+        ids: &[Uuid],
+    ) -> Result<HashSet<Uuid>, Box<dyn Error + Send + Sync>> {
+        let mut conn = self.base.pool().acquire().await.map_err(|e| Box::new(e) as Box<dyn Error + Send + Sync>)?;
+        self.existing_tuples(&mut conn, entity_id, ids, false).await
+    }
+
+    async fn insert_records(
+        &self,
+        entity_id: Uuid,
+        records: &[NewRecordDto],
+        unique_keys: &[UniqueKeyValuesDto],
+        references: &[ReferencedTuplesDto],
+        context: &ChangeContext,
+    ) -> Result<BatchInsertOutcome, Box<dyn Error + Send + Sync>> {
+        let mut tx = self.base.begin_transaction().await?;
+
+        // 0. Modo de almacenamiento de la entidad (compartido: un cambio de modo espera al COMMIT)
+        sqlx::query("SELECT pg_advisory_xact_lock_shared(hashtextextended($1, 0))")
+            .bind(storage_lock_key(entity_id))
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| Box::new(e) as Box<dyn Error + Send + Sync>)?;
+        let source = RecordSource::load(&mut *tx, entity_id)
+            .await
+            .map_err(|e| Box::new(e) as Box<dyn Error + Send + Sync>)?;
+
+        // 1. Bloquear los grupos de unicidad (mismo advisory lock que las escrituras individuales)
+        let groups: BTreeSet<i16> = unique_keys.iter().map(|k| k.group).collect();
+        for group in groups {
+            sqlx::query("SELECT pg_advisory_xact_lock(hashtextextended($1, 0))")
+                .bind(format!("unique:{}:{}", entity_id, group))
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| Box::new(e) as Box<dyn Error + Send + Sync>)?;
+        }
+
+        // 2. Bloquear las tuplas referenciadas y comprobar que siguen existiendo
+        for reference in references {
+            let ids: Vec<Uuid> = reference.ids.iter().copied().collect::<BTreeSet<Uuid>>().into_iter().collect();
+            let found = self.existing_tuples(&mut *tx, reference.entity_id, &ids, true).await?;
+            if let Some(missing) = ids.iter().find(|id| !found.contains(id)) {
+                tx.rollback().await.map_err(|e| Box::new(e) as Box<dyn Error + Send + Sync>)?;
+                return Ok(BatchInsertOutcome::Conflict(format!(
+                    "El registro referenciado {} se eliminó durante la importación", missing
+                )));
+            }
+        }
+
+        // 3. Volver a comprobar la unicidad con los grupos ya bloqueados
+        if !self.existing_keys(&mut *tx, source, unique_keys).await?.is_empty() {
+            tx.rollback().await.map_err(|e| Box::new(e) as Box<dyn Error + Send + Sync>)?;
+            return Ok(BatchInsertOutcome::Conflict(
+                "Otro proceso creó registros con valores únicos del archivo durante la importación".to_string(),
+            ));
+        }
+
+        // 4. Insertar por lotes
+        let mut ids = Vec::with_capacity(records.len());
+        for chunk in records.chunks(self.batch_size) {
+            ids.extend(self.insert_chunk(&mut *tx, source, chunk, context).await?);
+            debug!("Lote de {} registros insertado en la entidad {}", chunk.len(), entity_id);
+        }
+
+        tx.commit().await.map_err(|e| Box::new(e) as Box<dyn Error + Send + Sync>)?;
+        Ok(BatchInsertOutcome::Inserted(ids))
+    }
 }
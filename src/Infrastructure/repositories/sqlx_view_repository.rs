@@ -0,0 +1,258 @@
+use async_trait::async_trait;
+use sqlx::postgres::PgRow;
+use sqlx::{PgConnection, PgPool, Row};
+use uuid::Uuid;
+use crate::Domain::errors::DomainError;
+use crate::Domain::views::repository::ViewRepository;
+use crate::Domain::views::materialized::{
+    EntityViewSettings, MaterializedViewCandidate, ViewRefreshState, ViewRefreshStatus,
+};
+use crate::Domain::views::view_generator::ViewPivotStrategy;
+use crate::Domain::sql_identifiers::quote_identifier;
+
+const SELECT_VIEW_SETTINGS: &str = r#"
+    SELECT materialized, refresh_interval_secs, pivot_strategy, refresh_status, last_refresh_started_at,
+           last_refreshed_at, last_refresh_duration_ms, last_refresh_error
+    FROM entity_views
+"#;
+
+fn db_error(action: &str, view_name: &str, e: sqlx::Error) -> DomainError {
+    DomainError::GenericDomainError(format!("Failed to {} '{}': {}", action, view_name, e))
+}
+
+/// Elimina la vista existente con ese nombre, sea normal o materializada.
+async fn drop_existing_view(conn: &mut PgConnection, view_name: &str) -> Result<(), DomainError> {
+    let relkind: Option<String> = sqlx::query_scalar(
+        "SELECT c.relkind::text FROM pg_class c JOIN pg_namespace n ON n.oid = c.relnamespace \
+         WHERE c.relname = $1 AND n.nspname = current_schema()",
+    )
+    .bind(view_name)
+    .fetch_optional(&mut *conn)
+    .await
+    .map_err(|e| db_error("look up view", view_name, e))?;
+
+    let drop_sql = match relkind.as_deref() {
+        Some("m") => format!("DROP MATERIALIZED VIEW IF EXISTS {}", quote_identifier(view_name)),
+        Some("v") => format!("DROP VIEW IF EXISTS {}", quote_identifier(view_name)),
+        _ => return Ok(()),
+    };
+    sqlx::query(&drop_sql)
+        .execute(&mut *conn)
+        .await
+        .map_err(|e| db_error("drop view", view_name, e))?;
+    Ok(())
+}
+
+fn map_refresh_state(row: &PgRow) -> Result<ViewRefreshState, DomainError> {
+    let map_err = |e: sqlx::Error| DomainError::GenericDomainError(format!("Failed to read view refresh state: {}", e));
+    let status: String = row.try_get("refresh_status").map_err(map_err)?;
+    Ok(ViewRefreshState {
+        status: ViewRefreshStatus::parse(&status)?,
+        last_refresh_started_at: row.try_get("last_refresh_started_at").map_err(map_err)?,
+        last_refreshed_at: row.try_get("last_refreshed_at").map_err(map_err)?,
+        last_refresh_duration_ms: row.try_get("last_refresh_duration_ms").map_err(map_err)?,
+        last_refresh_error: row.try_get("last_refresh_error").map_err(map_err)?,
+    })
+}
+
+fn map_settings(row: &PgRow) -> Result<EntityViewSettings, DomainError> {
+    let map_err = |e: sqlx::Error| DomainError::GenericDomainError(format!("Failed to read view settings: {}", e));
+    let pivot_strategy: Option<String> = row.try_get("pivot_strategy").map_err(map_err)?;
+    Ok(EntityViewSettings {
+        materialized: row.try_get("materialized").map_err(map_err)?,
+        refresh_interval_secs: row.try_get("refresh_interval_secs").map_err(map_err)?,
+        pivot_strategy: pivot_strategy.as_deref().map(ViewPivotStrategy::parse).transpose()?,
+    })
+}
+
+#[derive(Clone)]
+pub struct SqlxViewRepository {
+    pool: PgPool,
+}
+
+impl SqlxViewRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    // Método directo si no se usa el trait
+    pub async fn execute_create_or_replace_view(&self, view_sql: &str) -> Result<(), DomainError> {
+        sqlx::query(view_sql)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| {
+                eprintln!("Failed to create/replace view: {}", e);
+                DomainError::GenericDomainError(format!("Failed to execute DDL for view: {}", e))
+            })?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ViewRepository for SqlxViewRepository {
+    async fn create_or_replace_view(&self, view_name: &str, view_sql: &str) -> Result<(), DomainError> {
+        // CREATE OR REPLACE VIEW no admite quitar, renombrar ni reordenar columnas,
+        // así que se elimina y se vuelve a crear dentro de una misma transacción.
+        let mut tx = self.pool.begin().await
+            .map_err(|e| DomainError::GenericDomainError(format!("Failed to begin view transaction: {}", e)))?;
+
+        drop_existing_view(&mut *tx, view_name).await?;
+
+        sqlx::query(view_sql)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| db_error("execute DDL for view", view_name, e))?;
+
+        tx.commit().await
+            .map_err(|e| db_error("commit view", view_name, e))?;
+        Ok(())
+    }
+
+    async fn create_materialized_view(&self, view_name: &str, view_sql: &str, index_sql: &str) -> Result<(), DomainError> {
+        let mut tx = self.pool.begin().await
+            .map_err(|e| DomainError::GenericDomainError(format!("Failed to begin view transaction: {}", e)))?;
+
+        drop_existing_view(&mut *tx, view_name).await?;
+
+        sqlx::query(view_sql)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| db_error("execute DDL for materialized view", view_name, e))?;
+
+        // El índice único permite REFRESH ... CONCURRENTLY
+        sqlx::query(index_sql)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| db_error("create unique index for materialized view", view_name, e))?;
+
+        tx.commit().await
+            .map_err(|e| db_error("commit materialized view", view_name, e))?;
+        Ok(())
+    }
+
+    async fn refresh_materialized_view(&self, view_name: &str) -> Result<(), DomainError> {
+        sqlx::query(&format!("REFRESH MATERIALIZED VIEW CONCURRENTLY {}", quote_identifier(view_name)))
+            .execute(&self.pool)
+            .await
+            .map_err(|e| db_error("refresh materialized view", view_name, e))?;
+        Ok(())
+    }
+
+    async fn drop_view(&self, view_name: &str) -> Result<(), DomainError> {
+        let mut conn = self.pool.acquire().await
+            .map_err(|e| db_error("acquire connection to drop view", view_name, e))?;
+        drop_existing_view(&mut *conn, view_name).await
+    }
+
+    async fn find_view_settings(&self, entity_id: Uuid) -> Result<(EntityViewSettings, ViewRefreshState), DomainError> {
+        let sql = format!("{} WHERE entity_id = $1", SELECT_VIEW_SETTINGS);
+        let row = sqlx::query(&sql)
+            .bind(entity_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| DomainError::GenericDomainError(format!("Failed to read view settings: {}", e)))?;
+
+        match row {
+            Some(row) => Ok((map_settings(&row)?, map_refresh_state(&row)?)),
+            None => Ok((EntityViewSettings::default(), ViewRefreshState::default())),
+        }
+    }
+
+    async fn save_view_settings(&self, entity_id: Uuid, settings: &EntityViewSettings) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            INSERT INTO entity_views (entity_id, materialized, refresh_interval_secs, pivot_strategy)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (entity_id) DO UPDATE SET
+                materialized = EXCLUDED.materialized,
+                refresh_interval_secs = EXCLUDED.refresh_interval_secs,
+                pivot_strategy = EXCLUDED.pivot_strategy,
+                refresh_status = CASE WHEN entity_views.materialized = EXCLUDED.materialized
+                    THEN entity_views.refresh_status ELSE 'never' END,
+                last_refresh_started_at = CASE WHEN entity_views.materialized = EXCLUDED.materialized
+                    THEN entity_views.last_refresh_started_at END,
+                last_refreshed_at = CASE WHEN entity_views.materialized = EXCLUDED.materialized
+                    THEN entity_views.last_refreshed_at END,
+                last_refresh_duration_ms = CASE WHEN entity_views.materialized = EXCLUDED.materialized
+                    THEN entity_views.last_refresh_duration_ms END,
+                last_refresh_error = CASE WHEN entity_views.materialized = EXCLUDED.materialized
+                    THEN entity_views.last_refresh_error END,
+                updated_at = NOW()
+            "#,
+        )
+        .bind(entity_id)
+        .bind(settings.materialized)
+        .bind(settings.refresh_interval_secs)
+        .bind(settings.pivot_strategy.map(|p| p.as_str()))
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::GenericDomainError(format!("Failed to save view settings of entity {}: {}", entity_id, e)))?;
+        Ok(())
+    }
+
+    async fn mark_refresh_started(&self, entity_id: Uuid) -> Result<(), DomainError> {
+        sqlx::query(
+            "UPDATE entity_views SET refresh_status = 'refreshing', last_refresh_started_at = NOW() WHERE entity_id = $1",
+        )
+        .bind(entity_id)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::GenericDomainError(format!("Failed to mark view refresh of entity {}: {}", entity_id, e)))?;
+        Ok(())
+    }
+
+    async fn mark_refresh_finished(&self, entity_id: Uuid, error: Option<&str>) -> Result<(), DomainError> {
+        // Con error se conserva la fecha del último refresco correcto
+        sqlx::query(
+            r#"
+            UPDATE entity_views SET
+                refresh_status = CASE WHEN $2::text IS NULL THEN 'ready' ELSE 'failed' END,
+                last_refreshed_at = CASE WHEN $2::text IS NULL THEN NOW() ELSE last_refreshed_at END,
+                last_refresh_duration_ms = (EXTRACT(EPOCH FROM NOW() - last_refresh_started_at) * 1000)::BIGINT,
+                last_refresh_error = $2
+            WHERE entity_id = $1
+            "#,
+        )
+        .bind(entity_id)
+        .bind(error)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::GenericDomainError(format!("Failed to record view refresh of entity {}: {}", entity_id, e)))?;
+        Ok(())
+    }
+
+    async fn find_materialized_views(&self) -> Result<Vec<MaterializedViewCandidate>, DomainError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT ev.entity_id, le.assign_view, ev.materialized, ev.refresh_interval_secs, ev.pivot_strategy, ev.refresh_status,
+                   ev.last_refresh_started_at, ev.last_refreshed_at, ev.last_refresh_duration_ms,
+                   ev.last_refresh_error, last_write.changed_at AS last_write_at
+            FROM entity_views ev
+            JOIN logical_entities le ON le.id = ev.entity_id
+            LEFT JOIN LATERAL (
+                SELECT rh.changed_at FROM record_history rh
+                WHERE rh.entity_id = ev.entity_id
+                ORDER BY rh.id DESC LIMIT 1
+            ) last_write ON TRUE
+            WHERE ev.materialized AND le.assign_view IS NOT NULL AND le.deleted_at IS NULL
+            ORDER BY le.name
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::GenericDomainError(format!("Failed to list materialized views: {}", e)))?;
+
+        rows.iter()
+            .map(|row| {
+                let map_err = |e: sqlx::Error| DomainError::GenericDomainError(format!("Failed to read materialized view: {}", e));
+                Ok(MaterializedViewCandidate {
+                    entity_id: row.try_get("entity_id").map_err(map_err)?,
+                    view_name: row.try_get("assign_view").map_err(map_err)?,
+                    settings: map_settings(row)?,
+                    state: map_refresh_state(row)?,
+                    last_write_at: row.try_get("last_write_at").map_err(map_err)?,
+                })
+            })
+            .collect()
+    }
+}
@@ -57,25 +57,41 @@ impl UserCommandRepository for UserCommandRepositoryImpl {
             status: Some(user.status),
         };
 
-        let affected_rows = diesel::update(users::table.find(user.id))
-            .set(&changeset)
-            .execute(conn) // Usar la conexión async pasada
+        let new_version: Option<i32> = diesel::update(users::table.find(user.id).filter(users::deleted_at.is_null()))
+            .set((&changeset, users::version.eq(users::version + 1)))
+            .returning(users::version)
+            .get_result(conn) // Usar la conexión async pasada
             .await // Usar .await para la ejecución async
+            .optional()
             .context(format!("Failed to update user {} using Diesel Async", user.id))?; // Añadir contexto
 
-        if affected_rows == 0 {
-            return Err(anyhow!("Usuario con ID {} no encontrado para actualizar", user.id));
-        }
-        Ok(user)
+        let version = new_version
+            .ok_or_else(|| anyhow!("Usuario con ID {} no encontrado para actualizar", user.id))?;
+        Ok(User { version, ..user })
     }
 
-    // --- AJUSTADO: Acepta 'conn: &mut AsyncPgConnection' ---
-    async fn delete(&self, conn: &mut AsyncPgConnection, id: Uuid) -> Result<()> {
-        // --- ELIMINADO ---
-        // let mut conn = self.get_connection().await?;
-        // -----------------
+    async fn find_version_for_update(&self, conn: &mut AsyncPgConnection, id: Uuid) -> Result<Option<i32>> {
+        let version = users::table
+            .filter(users::id.eq(id))
+            .filter(users::deleted_at.is_null())
+            .select(users::version)
+            .for_update()
+            .first::<i32>(conn)
+            .await
+            .optional()
+            .context(format!("Failed to read version of user {} using Diesel Async", id))?;
+
+        Ok(version)
+    }
 
-        let affected = diesel::delete(users::table.filter(users::id.eq(id)))
+    // Eliminación lógica: el usuario pasa a la papelera
+    async fn delete(&self, conn: &mut AsyncPgConnection, id: Uuid, deleted_by: Uuid) -> Result<()> {
+        let affected = diesel::update(users::table.filter(users::id.eq(id)).filter(users::deleted_at.is_null()))
+            .set((
+                users::deleted_at.eq(diesel::dsl::now),
+                users::deleted_by.eq(Some(deleted_by)),
+                users::version.eq(users::version + 1),
+            ))
             .execute(conn) // Usar la conexión async pasada
             .await // Usar .await para la ejecución async
             .context(format!("Failed to delete user {} using Diesel Async", id))?; // Añadir contexto
@@ -87,4 +103,28 @@ impl UserCommandRepository for UserCommandRepositoryImpl {
 
         Ok(())
     }
+
+    async fn restore(&self, conn: &mut AsyncPgConnection, id: Uuid) -> Result<usize> {
+        let affected = diesel::update(users::table.filter(users::id.eq(id)).filter(users::deleted_at.is_not_null()))
+            .set((
+                users::deleted_at.eq(None::<chrono::DateTime<Utc>>),
+                users::deleted_by.eq(None::<Uuid>),
+                users::version.eq(users::version + 1),
+            ))
+            .execute(conn)
+            .await
+            .context(format!("Failed to restore user {} using Diesel Async", id))?;
+
+        Ok(affected)
+    }
+
+    async fn purge(&self, conn: &mut AsyncPgConnection, id: Uuid) -> Result<usize> {
+        // Las referencias de auditoría (created_by, deleted_by...) quedan en NULL (ON DELETE SET NULL)
+        let affected = diesel::delete(users::table.filter(users::id.eq(id)).filter(users::deleted_at.is_not_null()))
+            .execute(conn)
+            .await
+            .context(format!("Failed to purge user {} using Diesel Async", id))?;
+
+        Ok(affected)
+    }
 }
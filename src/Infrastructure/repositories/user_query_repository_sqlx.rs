@@ -5,7 +5,7 @@ use std::sync::Arc;
 use uuid::Uuid;
 use chrono::{DateTime, Utc}; // <-- *** AÑADIDO IMPORT ***
 
-use crate::Application::ports::driven::repositories::UserQueryRepository;
+use crate::Application::ports::driven::repositories::{UserQueryRepository, DeletedUserDto};
 use crate::Domain::entities::user::User; // La struct User ya usa DateTime<Utc>
 use crate::Infrastructure::repositories::sqlx_repository_base::SqlxRepositoryBase;
 
@@ -35,9 +35,9 @@ impl UserQueryRepository for UserQueryRepositorySqlx {
             SELECT
                 id, username, first_name, last_name, email,
                 password_hash as password, status, created_by, created_at,
-                updated_by, updated_at
+                updated_by, updated_at, version
             FROM users
-            WHERE id = $1 AND status = 1
+            WHERE id = $1 AND status = 1 AND deleted_at IS NULL
         "#;
 
         let result = sqlx::query(sql)
@@ -60,6 +60,7 @@ impl UserQueryRepository for UserQueryRepositorySqlx {
                     created_at: row.try_get("created_at")?, // Requiere feature chrono
                     updated_by: row.try_get("updated_by")?,
                     updated_at: row.try_get("updated_at")?, // Requiere feature chrono
+                    version: row.try_get("version")?,
                 };
                 Ok(Some(user))
             },
@@ -70,7 +71,14 @@ impl UserQueryRepository for UserQueryRepositorySqlx {
 
     /// Busca un usuario por su email.
     async fn find_by_email(&self, email: &str) -> Result<Option<User>> {
-        let sql = r#" SELECT ... FROM users WHERE email = $1 AND status = 1 "#; // Query como antes
+        let sql = r#"
+            SELECT
+                id, username, first_name, last_name, email,
+                password_hash as password, status, created_by, created_at,
+                updated_by, updated_at, version
+            FROM users
+            WHERE email = $1 AND status = 1 AND deleted_at IS NULL
+        "#;
 
         let result = sqlx::query(sql)
             .bind(email)
@@ -84,6 +92,7 @@ impl UserQueryRepository for UserQueryRepositorySqlx {
                     last_name: row.try_get("last_name")?, email: row.try_get("email")?, password: row.try_get("password")?,
                     status: row.try_get("status")?, created_by: row.try_get("created_by")?, created_at: row.try_get("created_at")?,
                     updated_by: row.try_get("updated_by")?, updated_at: row.try_get("updated_at")?,
+                    version: row.try_get("version")?,
                  };
                 Ok(Some(user))
             },
@@ -94,7 +103,14 @@ impl UserQueryRepository for UserQueryRepositorySqlx {
 
     /// Busca un usuario por su nombre de usuario.
     async fn find_by_username(&self, username: &str) -> Result<Option<User>> {
-        let sql = r#" SELECT ... FROM users WHERE username = $1 AND status = 1 "#; // Query como antes
+        let sql = r#"
+            SELECT
+                id, username, first_name, last_name, email,
+                password_hash as password, status, created_by, created_at,
+                updated_by, updated_at, version
+            FROM users
+            WHERE username = $1 AND status = 1 AND deleted_at IS NULL
+        "#;
 
         let result = sqlx::query(sql)
             .bind(username)
@@ -108,6 +124,7 @@ impl UserQueryRepository for UserQueryRepositorySqlx {
                     last_name: row.try_get("last_name")?, email: row.try_get("email")?, password: row.try_get("password")?,
                     status: row.try_get("status")?, created_by: row.try_get("created_by")?, created_at: row.try_get("created_at")?,
                     updated_by: row.try_get("updated_by")?, updated_at: row.try_get("updated_at")?,
+                    version: row.try_get("version")?,
                  };
                 Ok(Some(user))
             },
@@ -118,7 +135,15 @@ impl UserQueryRepository for UserQueryRepositorySqlx {
 
     /// Obtiene todos los usuarios.
     async fn find_all(&self) -> Result<Vec<User>> {
-        let sql = r#" SELECT ... FROM users WHERE status = 1 ORDER BY username "#; // Query como antes
+        let sql = r#"
+            SELECT
+                id, username, first_name, last_name, email,
+                password_hash as password, status, created_by, created_at,
+                updated_by, updated_at, version
+            FROM users
+            WHERE status = 1 AND deleted_at IS NULL
+            ORDER BY username
+        "#;
 
         let result = sqlx::query(sql)
             .fetch_all(self.base.pool())
@@ -133,6 +158,7 @@ impl UserQueryRepository for UserQueryRepositorySqlx {
                         last_name: row.try_get("last_name")?, email: row.try_get("email")?, password: row.try_get("password")?,
                         status: row.try_get("status")?, created_by: row.try_get("created_by")?, created_at: row.try_get("created_at")?,
                         updated_by: row.try_get("updated_by")?, updated_at: row.try_get("updated_at")?,
+                        version: row.try_get("version")?,
                      };
                     users.push(user);
                 }
@@ -141,4 +167,55 @@ impl UserQueryRepository for UserQueryRepositorySqlx {
             Err(e) => Err(anyhow!("Error en find_all: {}", e)),
         }
     }
+
+    /// Usuarios de la papelera, del más reciente al más antiguo.
+    async fn find_deleted(&self, limit: i64, offset: i64) -> Result<Vec<DeletedUserDto>> {
+        let sql = r#"
+            SELECT id, username, email, deleted_at, deleted_by
+            FROM users
+            WHERE deleted_at IS NOT NULL
+            ORDER BY deleted_at DESC, username
+            LIMIT $1 OFFSET $2
+        "#;
+
+        let rows = sqlx::query(sql)
+            .bind(limit)
+            .bind(offset)
+            .fetch_all(self.base.pool())
+            .await
+            .map_err(|e| anyhow!("Error en find_deleted: {}", e))?;
+
+        let mut users = Vec::with_capacity(rows.len());
+        for row in rows {
+            users.push(DeletedUserDto {
+                id: row.try_get("id")?,
+                username: row.try_get("username")?,
+                email: row.try_get("email")?,
+                deleted_at: row.try_get("deleted_at")?,
+                deleted_by: row.try_get("deleted_by")?,
+            });
+        }
+        Ok(users)
+    }
+
+    /// Cuenta los usuarios de la papelera.
+    async fn count_deleted(&self) -> Result<i64> {
+        let total: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM users WHERE deleted_at IS NOT NULL")
+            .fetch_one(self.base.pool())
+            .await
+            .map_err(|e| anyhow!("Error en count_deleted: {}", e))?;
+        Ok(total)
+    }
+
+    /// IDs de los usuarios eliminados antes de `deleted_before`.
+    async fn find_deleted_before(&self, deleted_before: DateTime<Utc>) -> Result<Vec<Uuid>> {
+        let ids: Vec<Uuid> = sqlx::query_scalar(
+                "SELECT id FROM users WHERE deleted_at IS NOT NULL AND deleted_at < $1 ORDER BY deleted_at, id"
+            )
+            .bind(deleted_before)
+            .fetch_all(self.base.pool())
+            .await
+            .map_err(|e| anyhow!("Error en find_deleted_before: {}", e))?;
+        Ok(ids)
+    }
 }
@@ -2,7 +2,7 @@ use actix_web::http::StatusCode;
 use actix_web::HttpResponse;
 use log::error;
 use crate::Application::errors::application_error::ApplicationError;
-use crate::Presentation::api::responses::{ApiResponse, ApiError};
+use crate::Presentation::api::responses::{ApiResponse, ApiError, ApiFieldError};
 
 /// Adaptador para convertir errores de aplicación a respuestas HTTP
 /// 
@@ -26,9 +26,20 @@ impl ErrorAdapter {
             ApplicationError::ValidationError(msg) => {
                 HttpResponse::BadRequest().json(ApiResponse::<()>::error(ApiError::bad_request(&msg)))
             },
+            ApplicationError::FieldValidationErrors(errors) => {
+                let fields = errors.into_iter()
+                    .map(|e| ApiFieldError { field: e.field, code: e.code, message: e.message })
+                    .collect();
+                HttpResponse::BadRequest().json(ApiResponse::<()>::error(
+                    ApiError::bad_request("Uno o más campos no son válidos").with_fields(fields)
+                ))
+            },
             ApplicationError::Conflict(msg) => {
                 HttpResponse::Conflict().json(ApiResponse::<()>::error(ApiError::new(StatusCode::CONFLICT, &msg)))
             },
+            ApplicationError::PreconditionFailed(msg) => {
+                HttpResponse::PreconditionFailed().json(ApiResponse::<()>::error(ApiError::new(StatusCode::PRECONDITION_FAILED, &msg)))
+            },
             ApplicationError::AuthenticationError(msg) => {
                 HttpResponse::Unauthorized().json(ApiResponse::<()>::error(ApiError::new(StatusCode::UNAUTHORIZED, &msg)))
             },
@@ -60,4 +71,29 @@ impl ErrorAdapter {
             ApiError::internal_server_error(&format!("Error interno: {}", err))
         ))
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stale_version_is_precondition_failed() {
+        let response = ErrorAdapter::map_application_error(ApplicationError::PreconditionFailed("versión 3".to_string()));
+
+        assert_eq!(response.status(), StatusCode::PRECONDITION_FAILED);
+    }
+
+    #[test]
+    fn restricted_delete_is_conflict() {
+        let response = ErrorAdapter::map_application_error(ApplicationError::Conflict("restrict".to_string()));
+
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+    }
+
+    #[test]
+    fn unit_of_work_errors_keep_their_status() {
+        let error = anyhow::anyhow!(ApplicationError::PreconditionFailed("versión 3".to_string()));
+
+        assert_eq!(ErrorAdapter::map_anyhow_error(error).status(), StatusCode::PRECONDITION_FAILED);
+    }
+}
@@ -0,0 +1,270 @@
+use actix_web::{web, HttpResponse, post, put, patch, Error};
+use std::sync::Arc;
+use uuid::Uuid;
+use log::{info, error};
+
+use crate::Container::app_state::AppState;
+use crate::Application::use_cases::attributes::{
+    AddAttributeUseCase,
+    UpdateAttributeUseCase,
+    ReorderAttributesUseCase,
+    ChangeAttributeDataTypeUseCase,
+    ReplaceAttributeOptionsUseCase,
+};
+use crate::Application::dtos::attribute_dto::{
+    AddAttributeDto, UpdateAttributeDto, AttributePositionDto, ChangeAttributeDataTypeDto,
+    ReplaceAttributeOptionsDto, ReferenceDefinitionDto,
+};
+use crate::Domain::attribute_options::AttributeOption;
+use crate::Presentation::api::validators::validate_json;
+use crate::Presentation::api::responses::ApiResponse;
+use crate::Presentation::api::models::request::{
+    AddAttributeRequest, UpdateAttributeRequest, ReorderAttributesRequest, ChangeAttributeDataTypeRequest,
+    AttributeOptionRequest, ReplaceAttributeOptionsRequest,
+};
+use crate::Presentation::api::models::response::{
+    AttributeResponse, AttributeListResponse, DataTypeChangeResponse,
+};
+use crate::Presentation::api::adapters::ErrorAdapter;
+use super::logical_entity_controller::placeholder_user_id;
+
+// Controlador para la evolución del esquema (atributos) de una entidad lógica existente
+pub struct AttributeController {
+    pub add_attribute_use_case: Arc<dyn AddAttributeUseCase>,
+    pub update_attribute_use_case: Arc<dyn UpdateAttributeUseCase>,
+    pub reorder_attributes_use_case: Arc<dyn ReorderAttributesUseCase>,
+    pub change_attribute_data_type_use_case: Arc<dyn ChangeAttributeDataTypeUseCase>,
+    pub replace_attribute_options_use_case: Arc<dyn ReplaceAttributeOptionsUseCase>,
+}
+
+impl AttributeController {
+    pub fn new(
+        add_attribute_use_case: Arc<dyn AddAttributeUseCase>,
+        update_attribute_use_case: Arc<dyn UpdateAttributeUseCase>,
+        reorder_attributes_use_case: Arc<dyn ReorderAttributesUseCase>,
+        change_attribute_data_type_use_case: Arc<dyn ChangeAttributeDataTypeUseCase>,
+        replace_attribute_options_use_case: Arc<dyn ReplaceAttributeOptionsUseCase>,
+    ) -> Self {
+        Self {
+            add_attribute_use_case,
+            update_attribute_use_case,
+            reorder_attributes_use_case,
+            change_attribute_data_type_use_case,
+            replace_attribute_options_use_case,
+        }
+    }
+}
+
+/// Opciones de la petición; sin `sort_order` se usa la posición en la lista.
+fn options_from_request(options: Vec<AttributeOptionRequest>) -> Vec<AttributeOption> {
+    options.into_iter()
+        .enumerate()
+        .map(|(index, o)| AttributeOption {
+            code: o.code,
+            label: o.label,
+            sort_order: o.sort_order.unwrap_or(index as i32),
+            is_active: o.is_active,
+        })
+        .collect()
+}
+
+// Handler para la ruta POST /api/logical-entities/{entity_id}/attributes
+#[post("")]
+async fn add_attribute(
+    app_state: web::Data<AppState>,
+    entity_id: web::Path<Uuid>,
+    req_payload: web::Json<AddAttributeRequest>,
+) -> Result<HttpResponse, Error> {
+    validate_json(&req_payload)?;
+
+    let entity_id = entity_id.into_inner();
+    info!("Añadiendo atributo '{}' a la entidad lógica {}", req_payload.name, entity_id);
+
+    let req = req_payload.into_inner();
+    let dto = AddAttributeDto {
+        name: req.name,
+        description: req.description,
+        data_type_name: req.data_type,
+        position: req.position,
+        is_required: req.is_required,
+        is_unique: req.is_unique,
+        default_value: req.default_value,
+        validation_regex: req.validation_regex,
+        show_option_label: req.show_option_label,
+        is_searchable: req.is_searchable,
+        options: options_from_request(req.options),
+        reference: req.reference.map(|r| ReferenceDefinitionDto {
+            entity_id: r.entity_id,
+            on_delete: r.on_delete,
+            display_attribute: r.display_attribute,
+        }),
+        created_by: placeholder_user_id(), // <--- ¡USA EL user_id REAL AQUÍ!
+    };
+
+    match app_state.attribute_controller_data.add_attribute_use_case.execute(entity_id, dto).await {
+        Ok(attribute) => {
+            info!("Atributo {} añadido a la entidad {}", attribute.id, entity_id);
+            let response_body = AttributeResponse::from(attribute);
+            Ok(HttpResponse::Created().json(ApiResponse::success(Some(response_body), Some("Attribute added successfully."))))
+        },
+        Err(app_error) => {
+            error!("Error al añadir atributo a la entidad {}: {:?}", entity_id, app_error);
+            Ok(ErrorAdapter::map_application_error(app_error))
+        },
+    }
+}
+
+// Handler para la ruta PUT /api/logical-entities/{entity_id}/attributes/order
+#[put("/order")]
+async fn reorder_attributes(
+    app_state: web::Data<AppState>,
+    entity_id: web::Path<Uuid>,
+    req_payload: web::Json<ReorderAttributesRequest>,
+) -> Result<HttpResponse, Error> {
+    validate_json(&req_payload)?;
+
+    let entity_id = entity_id.into_inner();
+    info!("Reordenando atributos de la entidad lógica {}", entity_id);
+
+    let positions = req_payload.positions.iter()
+        .map(|p| AttributePositionDto { attribute_id: p.attribute_id, position: p.position })
+        .collect();
+
+    match app_state.attribute_controller_data.reorder_attributes_use_case.execute(entity_id, positions, placeholder_user_id()).await {
+        Ok(attributes) => {
+            let response_body = AttributeListResponse {
+                entity_id,
+                attributes: attributes.into_iter().map(AttributeResponse::from).collect(),
+            };
+            Ok(HttpResponse::Ok().json(ApiResponse::success(Some(response_body), Some("Attributes reordered successfully."))))
+        },
+        Err(app_error) => {
+            error!("Error al reordenar atributos de la entidad {}: {:?}", entity_id, app_error);
+            Ok(ErrorAdapter::map_application_error(app_error))
+        },
+    }
+}
+
+// Handler para la ruta PATCH /api/logical-entities/{entity_id}/attributes/{attribute_id}
+// Renombra, reconfigura o retira (status = 0) un atributo.
+#[patch("/{attribute_id}")]
+async fn update_attribute(
+    app_state: web::Data<AppState>,
+    path: web::Path<(Uuid, Uuid)>,
+    req_payload: web::Json<UpdateAttributeRequest>,
+) -> Result<HttpResponse, Error> {
+    validate_json(&req_payload)?;
+
+    let (entity_id, attribute_id) = path.into_inner();
+    info!("Actualizando atributo {} de la entidad lógica {}", attribute_id, entity_id);
+
+    let req = req_payload.into_inner();
+    let dto = UpdateAttributeDto {
+        name: req.name,
+        description: req.description,
+        is_required: req.is_required,
+        is_unique: req.is_unique,
+        default_value: req.default_value,
+        validation_regex: req.validation_regex,
+        show_option_label: req.show_option_label,
+        is_searchable: req.is_searchable,
+        reference_on_delete: req.reference_on_delete,
+        reference_display_attribute: req.reference_display_attribute,
+        status: req.status,
+        updated_by: placeholder_user_id(), // <--- ¡USA EL user_id REAL AQUÍ!
+    };
+
+    match app_state.attribute_controller_data.update_attribute_use_case.execute(entity_id, attribute_id, dto).await {
+        Ok(attribute) => {
+            let response_body = AttributeResponse::from(attribute);
+            Ok(HttpResponse::Ok().json(ApiResponse::success(Some(response_body), Some("Attribute updated successfully."))))
+        },
+        Err(app_error) => {
+            error!("Error al actualizar el atributo {} de la entidad {}: {:?}", attribute_id, entity_id, app_error);
+            Ok(ErrorAdapter::map_application_error(app_error))
+        },
+    }
+}
+
+// Handler para la ruta PUT /api/logical-entities/{entity_id}/attributes/{attribute_id}/data-type
+// Devuelve el informe de conversión; `applied` indica si el cambio se aplicó.
+#[put("/{attribute_id}/data-type")]
+async fn change_attribute_data_type(
+    app_state: web::Data<AppState>,
+    path: web::Path<(Uuid, Uuid)>,
+    req_payload: web::Json<ChangeAttributeDataTypeRequest>,
+) -> Result<HttpResponse, Error> {
+    validate_json(&req_payload)?;
+
+    let (entity_id, attribute_id) = path.into_inner();
+    info!("Cambiando tipo de dato del atributo {} a '{}'", attribute_id, req_payload.data_type);
+
+    let req = req_payload.into_inner();
+    let dto = ChangeAttributeDataTypeDto {
+        data_type_name: req.data_type,
+        options: options_from_request(req.options),
+        dry_run: req.dry_run,
+        discard_failures: req.discard_failures,
+        updated_by: placeholder_user_id(), // <--- ¡USA EL user_id REAL AQUÍ!
+    };
+
+    match app_state.attribute_controller_data.change_attribute_data_type_use_case.execute(entity_id, attribute_id, dto).await {
+        Ok(report) => {
+            let message = if report.applied {
+                "Data type changed successfully."
+            } else if report.failed_count == 0 {
+                "Dry run: no changes applied."
+            } else {
+                "Some values cannot be converted: no changes applied."
+            };
+            let response_body = DataTypeChangeResponse::from(report);
+            Ok(HttpResponse::Ok().json(ApiResponse::success(Some(response_body), Some(message))))
+        },
+        Err(app_error) => {
+            error!("Error al cambiar el tipo de dato del atributo {}: {:?}", attribute_id, app_error);
+            Ok(ErrorAdapter::map_application_error(app_error))
+        },
+    }
+}
+
+// Handler para la ruta PUT /api/logical-entities/{entity_id}/attributes/{attribute_id}/options
+// Sustituye la lista de opciones de un atributo de tipo enumeración.
+#[put("/{attribute_id}/options")]
+async fn replace_attribute_options(
+    app_state: web::Data<AppState>,
+    path: web::Path<(Uuid, Uuid)>,
+    req_payload: web::Json<ReplaceAttributeOptionsRequest>,
+) -> Result<HttpResponse, Error> {
+    validate_json(&req_payload)?;
+
+    let (entity_id, attribute_id) = path.into_inner();
+    info!("Sustituyendo las opciones del atributo {} de la entidad lógica {}", attribute_id, entity_id);
+
+    let dto = ReplaceAttributeOptionsDto {
+        options: options_from_request(req_payload.into_inner().options),
+        updated_by: placeholder_user_id(), // <--- ¡USA EL user_id REAL AQUÍ!
+    };
+
+    match app_state.attribute_controller_data.replace_attribute_options_use_case.execute(entity_id, attribute_id, dto).await {
+        Ok(attribute) => {
+            let response_body = AttributeResponse::from(attribute);
+            Ok(HttpResponse::Ok().json(ApiResponse::success(Some(response_body), Some("Attribute options updated successfully."))))
+        },
+        Err(app_error) => {
+            error!("Error al sustituir las opciones del atributo {}: {:?}", attribute_id, app_error);
+            Ok(ErrorAdapter::map_application_error(app_error))
+        },
+    }
+}
+
+// Configuración de las rutas para este controlador
+pub fn config(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("") // El prefijo (/api/logical-entities/{entity_id}/attributes) se define en routes.rs
+            .service(add_attribute)
+            .service(reorder_attributes)
+            .service(update_attribute)
+            .service(change_attribute_data_type)
+            .service(replace_attribute_options)
+    );
+}
@@ -0,0 +1,203 @@
+use actix_web::{web, HttpResponse, get, post, patch, delete, Error};
+use std::sync::Arc;
+use uuid::Uuid;
+use log::{info, error};
+
+use crate::Container::app_state::AppState;
+use crate::Application::use_cases::data_types::{
+    ListDataTypesUseCase,
+    FindDataTypeUseCase,
+    CreateDataTypeUseCase,
+    UpdateDataTypeUseCase,
+    DeleteDataTypeUseCase,
+};
+use crate::Application::dtos::data_type_dto::{CreateDataTypeDto, UpdateDataTypeDto};
+use crate::Domain::data_types::DataTypeParams;
+use crate::Presentation::api::validators::validate_json;
+use crate::Presentation::api::responses::ApiResponse;
+use crate::Presentation::api::models::request::{ListDataTypesQuery, CreateDataTypeRequest, UpdateDataTypeRequest};
+use crate::Presentation::api::models::response::{DataTypeResponse, DataTypeListResponse};
+use crate::Presentation::api::adapters::ErrorAdapter;
+use super::logical_entity_controller::placeholder_user_id;
+
+// Controlador para el registro de tipos de datos
+pub struct DataTypeController {
+    pub list_data_types_use_case: Arc<dyn ListDataTypesUseCase>,
+    pub find_data_type_use_case: Arc<dyn FindDataTypeUseCase>,
+    pub create_data_type_use_case: Arc<dyn CreateDataTypeUseCase>,
+    pub update_data_type_use_case: Arc<dyn UpdateDataTypeUseCase>,
+    pub delete_data_type_use_case: Arc<dyn DeleteDataTypeUseCase>,
+}
+
+impl DataTypeController {
+    pub fn new(
+        list_data_types_use_case: Arc<dyn ListDataTypesUseCase>,
+        find_data_type_use_case: Arc<dyn FindDataTypeUseCase>,
+        create_data_type_use_case: Arc<dyn CreateDataTypeUseCase>,
+        update_data_type_use_case: Arc<dyn UpdateDataTypeUseCase>,
+        delete_data_type_use_case: Arc<dyn DeleteDataTypeUseCase>,
+    ) -> Self {
+        Self {
+            list_data_types_use_case,
+            find_data_type_use_case,
+            create_data_type_use_case,
+            update_data_type_use_case,
+            delete_data_type_use_case,
+        }
+    }
+}
+
+// Handler para la ruta GET /api/data-types
+#[get("")]
+async fn list_data_types(
+    app_state: web::Data<AppState>,
+    query: web::Query<ListDataTypesQuery>,
+) -> Result<HttpResponse, Error> {
+    info!("Listando tipos de datos (include_inactive={})", query.include_inactive);
+
+    match app_state.data_type_controller_data.list_data_types_use_case.execute(query.include_inactive).await {
+        Ok(data_types) => {
+            let response_body = DataTypeListResponse {
+                data_types: data_types.into_iter().map(DataTypeResponse::from).collect(),
+            };
+            Ok(HttpResponse::Ok().json(ApiResponse::success(Some(response_body), None)))
+        },
+        Err(app_error) => {
+            error!("Error al listar tipos de datos: {:?}", app_error);
+            Ok(ErrorAdapter::map_application_error(app_error))
+        },
+    }
+}
+
+// Handler para la ruta GET /api/data-types/{id}
+#[get("/{id}")]
+async fn get_data_type(
+    app_state: web::Data<AppState>,
+    id: web::Path<Uuid>,
+) -> Result<HttpResponse, Error> {
+    let id = id.into_inner();
+    info!("Buscando tipo de dato con ID: {}", id);
+
+    match app_state.data_type_controller_data.find_data_type_use_case.execute(id).await {
+        Ok(data_type) => {
+            let response_body = DataTypeResponse::from(data_type);
+            Ok(HttpResponse::Ok().json(ApiResponse::success(Some(response_body), None)))
+        },
+        Err(app_error) => {
+            error!("Error al buscar tipo de dato {}: {:?}", id, app_error);
+            Ok(ErrorAdapter::map_application_error(app_error))
+        },
+    }
+}
+
+// Handler para la ruta POST /api/data-types
+#[post("")]
+async fn create_data_type(
+    app_state: web::Data<AppState>,
+    req_payload: web::Json<CreateDataTypeRequest>,
+) -> Result<HttpResponse, Error> {
+    validate_json(&req_payload)?;
+    info!("Registrando tipo de dato '{}'", req_payload.name);
+
+    let req = req_payload.into_inner();
+    let dto = CreateDataTypeDto {
+        name: req.name,
+        description: req.description,
+        storage_column: req.storage_column,
+        pg_cast: req.pg_cast,
+        params: DataTypeParams {
+            max_length: req.max_length,
+            numeric_precision: req.numeric_precision,
+            numeric_scale: req.numeric_scale,
+            min_value: req.min_value,
+            max_value: req.max_value,
+        },
+        kind: req.kind,
+        created_by: placeholder_user_id(), // <--- ¡USA EL user_id REAL AQUÍ!
+    };
+
+    match app_state.data_type_controller_data.create_data_type_use_case.execute(dto).await {
+        Ok(data_type) => {
+            info!("Tipo de dato '{}' registrado con ID {}", data_type.name, data_type.id);
+            let response_body = DataTypeResponse::from(data_type);
+            Ok(HttpResponse::Created().json(ApiResponse::success(Some(response_body), Some("Data type created successfully."))))
+        },
+        Err(app_error) => {
+            error!("Error al registrar tipo de dato: {:?}", app_error);
+            Ok(ErrorAdapter::map_application_error(app_error))
+        },
+    }
+}
+
+// Handler para la ruta PATCH /api/data-types/{id}
+#[patch("/{id}")]
+async fn update_data_type(
+    app_state: web::Data<AppState>,
+    id: web::Path<Uuid>,
+    req_payload: web::Json<UpdateDataTypeRequest>,
+) -> Result<HttpResponse, Error> {
+    validate_json(&req_payload)?;
+
+    let id = id.into_inner();
+    info!("Actualizando tipo de dato {}", id);
+
+    let req = req_payload.into_inner();
+    let dto = UpdateDataTypeDto {
+        name: req.name,
+        description: req.description,
+        storage_column: req.storage_column,
+        pg_cast: req.pg_cast,
+        max_length: req.max_length,
+        numeric_precision: req.numeric_precision,
+        numeric_scale: req.numeric_scale,
+        min_value: req.min_value,
+        max_value: req.max_value,
+        kind: req.kind,
+        status: req.status,
+        updated_by: placeholder_user_id(), // <--- ¡USA EL user_id REAL AQUÍ!
+    };
+
+    match app_state.data_type_controller_data.update_data_type_use_case.execute(id, dto).await {
+        Ok(data_type) => {
+            let response_body = DataTypeResponse::from(data_type);
+            Ok(HttpResponse::Ok().json(ApiResponse::success(Some(response_body), Some("Data type updated successfully."))))
+        },
+        Err(app_error) => {
+            error!("Error al actualizar tipo de dato {}: {:?}", id, app_error);
+            Ok(ErrorAdapter::map_application_error(app_error))
+        },
+    }
+}
+
+// Handler para la ruta DELETE /api/data-types/{id}
+#[delete("/{id}")]
+async fn delete_data_type(
+    app_state: web::Data<AppState>,
+    id: web::Path<Uuid>,
+) -> Result<HttpResponse, Error> {
+    let id = id.into_inner();
+    info!("Eliminando tipo de dato con ID: {}", id);
+
+    match app_state.data_type_controller_data.delete_data_type_use_case.execute(id).await {
+        Ok(()) => {
+            info!("Tipo de dato eliminado con éxito: ID={}", id);
+            Ok(HttpResponse::Ok().json(ApiResponse::<()>::success(None, Some("Data type deleted successfully."))))
+        },
+        Err(app_error) => {
+            error!("Error al eliminar tipo de dato {}: {:?}", id, app_error);
+            Ok(ErrorAdapter::map_application_error(app_error))
+        },
+    }
+}
+
+// Configuración de las rutas para este controlador
+pub fn config(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("") // El prefijo (/api/data-types) se define en routes.rs
+            .service(list_data_types)
+            .service(create_data_type)
+            .service(get_data_type)
+            .service(update_data_type)
+            .service(delete_data_type)
+    );
+}
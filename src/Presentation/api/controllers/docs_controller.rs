@@ -0,0 +1,48 @@
+use actix_web::{web, HttpResponse, Error};
+use std::sync::Arc;
+use log::{info, error};
+
+use crate::Container::app_state::AppState;
+use crate::Application::use_cases::logical_entities::ListEntityJsonSchemasUseCase;
+use crate::Presentation::api::openapi::{build_openapi_document, swagger_ui_page};
+use crate::Presentation::api::adapters::ErrorAdapter;
+
+// Controlador de la documentación de la API (OpenAPI y Swagger UI)
+pub struct DocsController {
+    pub list_entity_json_schemas_use_case: Arc<dyn ListEntityJsonSchemasUseCase>,
+}
+
+impl DocsController {
+    pub fn new(list_entity_json_schemas_use_case: Arc<dyn ListEntityJsonSchemasUseCase>) -> Self {
+        Self { list_entity_json_schemas_use_case }
+    }
+}
+
+// Handler para la ruta del documento OpenAPI (OPENAPI_PATH, por defecto /api/openapi.json)
+// El documento se genera en cada petición para reflejar las entidades activas.
+async fn openapi_document(app_state: web::Data<AppState>) -> Result<HttpResponse, Error> {
+    match app_state.docs_controller_data.list_entity_json_schemas_use_case.execute().await {
+        Ok(entities) => {
+            info!("Generando documento OpenAPI con {} entidades", entities.len());
+            Ok(HttpResponse::Ok().json(build_openapi_document(&entities)))
+        },
+        Err(app_error) => {
+            error!("Error al generar el documento OpenAPI: {:?}", app_error);
+            Ok(ErrorAdapter::map_application_error(app_error))
+        },
+    }
+}
+
+// Configuración de las rutas: las rutas son configurables (AppConfig.openapi_path y
+// AppConfig.swagger_ui_path), por lo que no se usan las macros de ruta.
+pub fn config(cfg: &mut web::ServiceConfig, openapi_path: &str, swagger_ui_path: Option<&str>) {
+    cfg.service(web::resource(openapi_path).route(web::get().to(openapi_document)));
+
+    if let Some(swagger_ui_path) = swagger_ui_path {
+        let page = swagger_ui_page(openapi_path);
+        cfg.service(web::resource(swagger_ui_path).route(web::get().to(move || {
+            let page = page.clone();
+            async move { HttpResponse::Ok().content_type("text/html; charset=utf-8").body(page) }
+        })));
+    }
+}
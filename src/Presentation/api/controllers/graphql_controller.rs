@@ -0,0 +1,57 @@
+use actix_web::{web, HttpRequest, HttpResponse, post, Error};
+use std::sync::Arc;
+use log::{info, error};
+
+use crate::Container::app_state::AppState;
+use crate::Application::use_cases::graphql::ExecuteGraphQLUseCase;
+use crate::Application::dtos::graphql_dto::GraphQLRequestDto;
+use crate::Presentation::api::validators::validate_json;
+use crate::Presentation::api::models::request::GraphQLRequest;
+use crate::Presentation::api::adapters::ErrorAdapter;
+use super::record_controller::change_context;
+
+// Controlador del endpoint GraphQL (esquema generado a partir de las entidades lógicas)
+pub struct GraphQLController {
+    pub execute_graphql_use_case: Arc<dyn ExecuteGraphQLUseCase>,
+}
+
+impl GraphQLController {
+    pub fn new(execute_graphql_use_case: Arc<dyn ExecuteGraphQLUseCase>) -> Self {
+        Self { execute_graphql_use_case }
+    }
+}
+
+// Handler para la ruta POST /graphql
+// La respuesta sigue el formato GraphQL ({ data, errors }), no el envoltorio ApiResponse:
+// los errores de la consulta y de cada campo van en `errors` con estado 200.
+#[post("")]
+async fn execute_graphql(
+    app_state: web::Data<AppState>,
+    http_request: HttpRequest,
+    req_payload: web::Json<GraphQLRequest>,
+) -> Result<HttpResponse, Error> {
+    validate_json(&req_payload)?;
+
+    let req = req_payload.into_inner();
+    info!("Recibida operación GraphQL: operation={:?}, {} bytes", req.operation_name, req.query.len());
+    let request = GraphQLRequestDto {
+        query: req.query,
+        operation_name: req.operation_name,
+        variables: req.variables.unwrap_or_default(),
+    };
+    match app_state.graphql_controller_data.execute_graphql_use_case.execute(request, change_context(&http_request)).await {
+        Ok(response) => Ok(HttpResponse::Ok().json(response)),
+        Err(app_error) => {
+            error!("Error al ejecutar la operación GraphQL: {:?}", app_error);
+            Ok(ErrorAdapter::map_application_error(app_error))
+        },
+    }
+}
+
+// Configuración de las rutas para este controlador
+pub fn config(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("") // El prefijo (/graphql) se define en routes.rs
+            .service(execute_graphql)
+    );
+}
@@ -1,4 +1,4 @@
-use actix_web::{web, HttpResponse, post, Error};
+use actix_web::{web, HttpResponse, post, get, put, delete, Error};
 use std::sync::Arc;
 use uuid::Uuid;
 use log::{info, error};
@@ -8,11 +8,34 @@ use crate::Application::use_cases::logical_entities::{ // Usar los nombres corre
     CreateEntityWithAttributesUseCase, // Nombre correcto del trait
     CreateEntityWithAttributesCommand, // Nombre correcto del comando
     CreateEntityError, // Asumiendo que este es el nombre correcto del error exportado
+    FindLogicalEntityByIdUseCase,
+    FindLogicalEntityByNameUseCase,
+    ListLogicalEntitiesUseCase,
+    UpdateLogicalEntityUseCase,
+    DeleteLogicalEntityUseCase,
+    RefreshEntityViewUseCase,
+    EntityViewStatusUseCase,
+    UpdateEntityViewSettingsUseCase,
+    RefreshMaterializedViewUseCase,
+    EntityJsonSchemaUseCase,
+    ListDeletedLogicalEntitiesUseCase,
+    RestoreLogicalEntityUseCase,
+    PurgeLogicalEntityUseCase,
+    ChangeStorageModeUseCase,
 };
-use crate::Presentation::api::validators::validate_json;
+use crate::Application::dtos::logical_entity_dto::UpdateLogicalEntityDto;
+use crate::Presentation::api::validators::{validate_json, validate_request};
 use crate::Presentation::api::responses::ApiResponse;
-use crate::Presentation::api::models::request::CreateEntityWithAttributesRequest;
-use crate::Presentation::api::models::response::CreateLogicalEntityResponse; // O LogicalEntityResponse si devuelves más datos
+use crate::Presentation::api::models::request::{
+    CreateEntityWithAttributesRequest, UpdateLogicalEntityRequest, ListLogicalEntitiesQuery, ListTrashQuery,
+    UpdateEntityViewSettingsRequest, ChangeStorageModeRequest,
+};
+use crate::Presentation::api::models::response::{
+    CreateLogicalEntityResponse, LogicalEntityDetailsResponse, LogicalEntityPageResponse,
+    EntityViewResponse, EntityViewStatusResponse, DeletedLogicalEntityResponse, TrashPageResponse,
+    StorageModeResponse,
+};
+use crate::Domain::views::EntityViewSettings;
 use crate::Presentation::api::adapters::ErrorAdapter;
 // Probablemente necesites importar el trait CommandHandler si lo usas genéricamente
 // use crate::Application::use_cases::common::CommandHandler;
@@ -21,17 +44,69 @@ use crate::Presentation::api::adapters::ErrorAdapter;
 pub struct LogicalEntityController {
     // Asume que tienes un trait y una implementación para el caso de uso
     pub create_logical_entity_use_case: Arc<dyn CreateEntityWithAttributesUseCase>, // Usar el trait correcto
-    // Añade otros casos de uso (find, update, delete) aquí cuando los necesites
+    pub find_logical_entity_by_id_use_case: Arc<dyn FindLogicalEntityByIdUseCase>,
+    pub find_logical_entity_by_name_use_case: Arc<dyn FindLogicalEntityByNameUseCase>,
+    pub list_logical_entities_use_case: Arc<dyn ListLogicalEntitiesUseCase>,
+    pub update_logical_entity_use_case: Arc<dyn UpdateLogicalEntityUseCase>,
+    pub delete_logical_entity_use_case: Arc<dyn DeleteLogicalEntityUseCase>,
+    pub refresh_entity_view_use_case: Arc<dyn RefreshEntityViewUseCase>,
+    pub entity_view_status_use_case: Arc<dyn EntityViewStatusUseCase>,
+    pub update_entity_view_settings_use_case: Arc<dyn UpdateEntityViewSettingsUseCase>,
+    pub refresh_materialized_view_use_case: Arc<dyn RefreshMaterializedViewUseCase>,
+    pub entity_json_schema_use_case: Arc<dyn EntityJsonSchemaUseCase>,
+    pub list_deleted_logical_entities_use_case: Arc<dyn ListDeletedLogicalEntitiesUseCase>,
+    pub restore_logical_entity_use_case: Arc<dyn RestoreLogicalEntityUseCase>,
+    pub purge_logical_entity_use_case: Arc<dyn PurgeLogicalEntityUseCase>,
+    pub change_storage_mode_use_case: Arc<dyn ChangeStorageModeUseCase>,
 }
 
 impl LogicalEntityController {
-    pub fn new(create_logical_entity_use_case: Arc<dyn CreateEntityWithAttributesUseCase>) -> Self { // Usar el trait correcto
+    pub fn new(
+        create_logical_entity_use_case: Arc<dyn CreateEntityWithAttributesUseCase>, // Usar el trait correcto
+        find_logical_entity_by_id_use_case: Arc<dyn FindLogicalEntityByIdUseCase>,
+        find_logical_entity_by_name_use_case: Arc<dyn FindLogicalEntityByNameUseCase>,
+        list_logical_entities_use_case: Arc<dyn ListLogicalEntitiesUseCase>,
+        update_logical_entity_use_case: Arc<dyn UpdateLogicalEntityUseCase>,
+        delete_logical_entity_use_case: Arc<dyn DeleteLogicalEntityUseCase>,
+        refresh_entity_view_use_case: Arc<dyn RefreshEntityViewUseCase>,
+        entity_view_status_use_case: Arc<dyn EntityViewStatusUseCase>,
+        update_entity_view_settings_use_case: Arc<dyn UpdateEntityViewSettingsUseCase>,
+        refresh_materialized_view_use_case: Arc<dyn RefreshMaterializedViewUseCase>,
+        entity_json_schema_use_case: Arc<dyn EntityJsonSchemaUseCase>,
+        list_deleted_logical_entities_use_case: Arc<dyn ListDeletedLogicalEntitiesUseCase>,
+        restore_logical_entity_use_case: Arc<dyn RestoreLogicalEntityUseCase>,
+        purge_logical_entity_use_case: Arc<dyn PurgeLogicalEntityUseCase>,
+        change_storage_mode_use_case: Arc<dyn ChangeStorageModeUseCase>,
+    ) -> Self {
         Self {
             create_logical_entity_use_case,
+            find_logical_entity_by_id_use_case,
+            find_logical_entity_by_name_use_case,
+            list_logical_entities_use_case,
+            update_logical_entity_use_case,
+            delete_logical_entity_use_case,
+            refresh_entity_view_use_case,
+            entity_view_status_use_case,
+            update_entity_view_settings_use_case,
+            refresh_materialized_view_use_case,
+            entity_json_schema_use_case,
+            list_deleted_logical_entities_use_case,
+            restore_logical_entity_use_case,
+            purge_logical_entity_use_case,
+            change_storage_mode_use_case,
         }
     }
 }
 
+/// Tamaño de página por defecto para GET /api/logical-entities
+const DEFAULT_PAGE_SIZE: i64 = 20;
+
+// --- Placeholder para obtener User ID ---
+// ¡¡¡REEMPLAZAR CON LA EXTRACCIÓN REAL DEL USUARIO AUTENTICADO (AuthMiddleware)!!!
+pub(crate) fn placeholder_user_id() -> Uuid {
+    Uuid::parse_str("00000000-0000-0000-0000-000000000001").unwrap()
+}
+
 #[post("")]
 async fn create_logical_entity(
     app_state: web::Data<AppState>,
@@ -53,7 +128,7 @@ async fn create_logical_entity(
     //         actix_web::error::ErrorInternalServerError("Authentication context missing")
     //     })?;
     // Por ahora, usamos un placeholder como en tu ejemplo original, pero ¡esto es incorrecto!
-    let user_id_placeholder = Uuid::parse_str("00000000-0000-0000-0000-000000000001").unwrap();
+    let user_id_placeholder = placeholder_user_id();
     // --- Fin Placeholder ---
 
     info!("Creando nueva entidad lógica: {}", req_payload.name);
@@ -62,7 +137,8 @@ async fn create_logical_entity(
     let command = CreateEntityWithAttributesCommand { // Usar el struct de comando correcto
         entity_name: req_payload.name.clone(), // El comando espera 'entity_name'
         attributes: req_payload.attributes.clone(), // El comando espera 'attributes'
-        // description y assign_view no están en CreateEntityWithAttributesCommand
+        // description no está en CreateEntityWithAttributesCommand
+        assign_view: req_payload.assign_view,
         created_by_user_id: user_id_placeholder, // <--- ¡USA EL user_id REAL AQUÍ!
     };
 
@@ -80,6 +156,343 @@ async fn create_logical_entity(
         Err(app_error) => {
             // Asegúrate que ErrorAdapter maneje los errores de CreateLogicalEntityError
             error!("Error al crear entidad lógica: {:?}", app_error);
+            Ok(ErrorAdapter::map_application_error(app_error.into()))
+        },
+    }
+}
+
+// Handler para la ruta GET /api/logical-entities?page=1&page_size=20
+#[get("")]
+async fn list_logical_entities(
+    app_state: web::Data<AppState>,
+    query: web::Query<ListLogicalEntitiesQuery>,
+) -> Result<HttpResponse, Error> {
+    validate_request(&query.0)?;
+
+    let page = query.page.unwrap_or(1);
+    let page_size = query.page_size.unwrap_or(DEFAULT_PAGE_SIZE);
+    info!("Listando entidades lógicas: page={}, page_size={}", page, page_size);
+
+    match app_state.logical_entity_controller_data.list_logical_entities_use_case.execute(page, page_size).await {
+        Ok(page_dto) => {
+            info!("Se encontraron {} entidades lógicas (total={})", page_dto.items.len(), page_dto.total);
+            let response_body = LogicalEntityPageResponse::from(page_dto);
+            Ok(HttpResponse::Ok().json(ApiResponse::success(Some(response_body), None)))
+        },
+        Err(app_error) => {
+            error!("Error al listar entidades lógicas: {:?}", app_error);
+            Ok(ErrorAdapter::map_application_error(app_error))
+        },
+    }
+}
+
+// Handler para la ruta GET /api/logical-entities/{id}
+#[get("/{id}")]
+async fn find_logical_entity_by_id(
+    app_state: web::Data<AppState>,
+    id: web::Path<Uuid>,
+) -> Result<HttpResponse, Error> {
+    let entity_id = id.into_inner();
+    info!("Buscando entidad lógica por ID: {}", entity_id);
+
+    match app_state.logical_entity_controller_data.find_logical_entity_by_id_use_case.execute(entity_id).await {
+        Ok(details) => {
+            let response_body = LogicalEntityDetailsResponse::from(details);
+            Ok(HttpResponse::Ok().json(ApiResponse::success(Some(response_body), None)))
+        },
+        Err(app_error) => {
+            error!("Error al buscar entidad lógica {}: {:?}", entity_id, app_error);
+            Ok(ErrorAdapter::map_application_error(app_error))
+        },
+    }
+}
+
+// Handler para la ruta GET /api/logical-entities/by-name/{name}
+#[get("/by-name/{name}")]
+async fn find_logical_entity_by_name(
+    app_state: web::Data<AppState>,
+    name: web::Path<String>,
+) -> Result<HttpResponse, Error> {
+    let entity_name = name.into_inner();
+    info!("Buscando entidad lógica por nombre: {}", entity_name);
+
+    match app_state.logical_entity_controller_data.find_logical_entity_by_name_use_case.execute(&entity_name).await {
+        Ok(details) => {
+            let response_body = LogicalEntityDetailsResponse::from(details);
+            Ok(HttpResponse::Ok().json(ApiResponse::success(Some(response_body), None)))
+        },
+        Err(app_error) => {
+            error!("Error al buscar entidad lógica '{}': {:?}", entity_name, app_error);
+            Ok(ErrorAdapter::map_application_error(app_error))
+        },
+    }
+}
+
+// Handler para la ruta PUT /api/logical-entities/{id}
+#[put("/{id}")]
+async fn update_logical_entity(
+    app_state: web::Data<AppState>,
+    id: web::Path<Uuid>,
+    req_payload: web::Json<UpdateLogicalEntityRequest>,
+) -> Result<HttpResponse, Error> {
+    validate_json(&req_payload)?;
+
+    let entity_id = id.into_inner();
+    info!("Actualizando entidad lógica con ID: {}", entity_id);
+
+    let update_dto = UpdateLogicalEntityDto {
+        name: req_payload.name.clone(),
+        description: req_payload.description.clone(),
+        assign_view: req_payload.assign_view,
+        search_language: req_payload.search_language.clone(),
+        status: req_payload.status,
+        updated_by: placeholder_user_id(), // <--- ¡USA EL user_id REAL AQUÍ!
+    };
+
+    match app_state.logical_entity_controller_data.update_logical_entity_use_case.execute(entity_id, update_dto).await {
+        Ok(details) => {
+            info!("Entidad lógica actualizada con éxito: ID={}", entity_id);
+            let response_body = LogicalEntityDetailsResponse::from(details);
+            Ok(HttpResponse::Ok().json(ApiResponse::success(Some(response_body), Some("Logical entity updated successfully."))))
+        },
+        Err(app_error) => {
+            error!("Error al actualizar entidad lógica {}: {:?}", entity_id, app_error);
+            Ok(ErrorAdapter::map_application_error(app_error))
+        },
+    }
+}
+
+// Handler para la ruta DELETE /api/logical-entities/{id}
+#[delete("/{id}")]
+async fn delete_logical_entity(
+    app_state: web::Data<AppState>,
+    id: web::Path<Uuid>,
+) -> Result<HttpResponse, Error> {
+    let entity_id = id.into_inner();
+    info!("Moviendo a la papelera la entidad lógica con ID: {}", entity_id);
+
+    match app_state.logical_entity_controller_data.delete_logical_entity_use_case
+        .execute(entity_id, placeholder_user_id()) // <--- ¡USA EL user_id REAL AQUÍ!
+        .await
+    {
+        Ok(()) => {
+            info!("Entidad lógica movida a la papelera: ID={}", entity_id);
+            Ok(HttpResponse::Ok().json(ApiResponse::<()>::success(None, Some("Logical entity moved to the trash."))))
+        },
+        Err(app_error) => {
+            error!("Error al eliminar entidad lógica {}: {:?}", entity_id, app_error);
+            Ok(ErrorAdapter::map_application_error(app_error))
+        },
+    }
+}
+
+// Handler para la ruta GET /api/logical-entities/trash?page=1&page_size=20
+#[get("/trash")]
+async fn list_deleted_logical_entities(
+    app_state: web::Data<AppState>,
+    query: web::Query<ListTrashQuery>,
+) -> Result<HttpResponse, Error> {
+    validate_request(&query.0)?;
+
+    let page = query.page.unwrap_or(1);
+    let page_size = query.page_size.unwrap_or(DEFAULT_PAGE_SIZE);
+    info!("Listando la papelera de entidades lógicas: page={}, page_size={}", page, page_size);
+
+    match app_state.logical_entity_controller_data.list_deleted_logical_entities_use_case.execute(page, page_size).await {
+        Ok(page_dto) => {
+            info!("La papelera tiene {} entidades lógicas", page_dto.total);
+            let response_body: TrashPageResponse<DeletedLogicalEntityResponse> = TrashPageResponse::from(page_dto);
+            Ok(HttpResponse::Ok().json(ApiResponse::success(Some(response_body), None)))
+        },
+        Err(app_error) => {
+            error!("Error al listar la papelera de entidades lógicas: {:?}", app_error);
+            Ok(ErrorAdapter::map_application_error(app_error))
+        },
+    }
+}
+
+// Handler para la ruta POST /api/logical-entities/trash/{id}/restore
+#[post("/trash/{id}/restore")]
+async fn restore_logical_entity(
+    app_state: web::Data<AppState>,
+    id: web::Path<Uuid>,
+) -> Result<HttpResponse, Error> {
+    let entity_id = id.into_inner();
+    info!("Restaurando entidad lógica con ID: {}", entity_id);
+
+    match app_state.logical_entity_controller_data.restore_logical_entity_use_case.execute(entity_id).await {
+        Ok(details) => {
+            info!("Entidad lógica restaurada con éxito: ID={}", entity_id);
+            let response_body = LogicalEntityDetailsResponse::from(details);
+            Ok(HttpResponse::Ok().json(ApiResponse::success(Some(response_body), Some("Logical entity restored successfully."))))
+        },
+        Err(app_error) => {
+            error!("Error al restaurar entidad lógica {}: {:?}", entity_id, app_error);
+            Ok(ErrorAdapter::map_application_error(app_error))
+        },
+    }
+}
+
+// Handler para la ruta DELETE /api/logical-entities/trash/{id}
+// Elimina definitivamente la entidad con sus atributos y registros.
+#[delete("/trash/{id}")]
+async fn purge_logical_entity(
+    app_state: web::Data<AppState>,
+    id: web::Path<Uuid>,
+) -> Result<HttpResponse, Error> {
+    let entity_id = id.into_inner();
+    info!("Purgando entidad lógica con ID: {}", entity_id);
+
+    match app_state.logical_entity_controller_data.purge_logical_entity_use_case.execute(entity_id).await {
+        Ok(()) => {
+            info!("Entidad lógica purgada con éxito: ID={}", entity_id);
+            Ok(HttpResponse::Ok().json(ApiResponse::<()>::success(None, Some("Logical entity permanently deleted."))))
+        },
+        Err(app_error) => {
+            error!("Error al purgar entidad lógica {}: {:?}", entity_id, app_error);
+            Ok(ErrorAdapter::map_application_error(app_error))
+        },
+    }
+}
+
+// Handler para la ruta POST /api/logical-entities/{id}/view
+// Regenera la vista de la entidad a partir de sus atributos actuales.
+#[post("/{id}/view")]
+async fn refresh_entity_view(
+    app_state: web::Data<AppState>,
+    id: web::Path<Uuid>,
+) -> Result<HttpResponse, Error> {
+    let entity_id = id.into_inner();
+    info!("Regenerando vista de la entidad lógica: {}", entity_id);
+
+    match app_state.logical_entity_controller_data.refresh_entity_view_use_case.execute(entity_id).await {
+        Ok(view_name) => {
+            info!("Vista '{}' regenerada para la entidad {}", view_name, entity_id);
+            let response_body = EntityViewResponse { entity_id, view_name };
+            Ok(HttpResponse::Ok().json(ApiResponse::success(Some(response_body), Some("View refreshed successfully."))))
+        },
+        Err(app_error) => {
+            error!("Error al regenerar la vista de la entidad {}: {:?}", entity_id, app_error);
+            Ok(ErrorAdapter::map_application_error(app_error))
+        },
+    }
+}
+
+// Handler para la ruta GET /api/logical-entities/{id}/view
+// Opciones de la vista y estado del último refresco (vistas materializadas).
+#[get("/{id}/view")]
+async fn get_entity_view_status(
+    app_state: web::Data<AppState>,
+    id: web::Path<Uuid>,
+) -> Result<HttpResponse, Error> {
+    let entity_id = id.into_inner();
+    info!("Consultando la vista de la entidad lógica: {}", entity_id);
+
+    match app_state.logical_entity_controller_data.entity_view_status_use_case.execute(entity_id).await {
+        Ok(status) => {
+            let response_body = EntityViewStatusResponse::from(status);
+            Ok(HttpResponse::Ok().json(ApiResponse::success(Some(response_body), None)))
+        },
+        Err(app_error) => {
+            error!("Error al consultar la vista de la entidad {}: {:?}", entity_id, app_error);
+            Ok(ErrorAdapter::map_application_error(app_error))
+        },
+    }
+}
+
+// Handler para la ruta PUT /api/logical-entities/{id}/view/settings
+// Vista normal o materializada y estrategia de pivote; cambiarlas vuelve a crear la vista.
+#[put("/{id}/view/settings")]
+async fn update_entity_view_settings(
+    app_state: web::Data<AppState>,
+    id: web::Path<Uuid>,
+    req_payload: web::Json<UpdateEntityViewSettingsRequest>,
+) -> Result<HttpResponse, Error> {
+    validate_json(&req_payload)?;
+
+    let entity_id = id.into_inner();
+    let settings = EntityViewSettings {
+        materialized: req_payload.materialized,
+        refresh_interval_secs: req_payload.refresh_interval_secs,
+        pivot_strategy: req_payload.pivot_strategy,
+    };
+    info!("Actualizando las opciones de la vista de la entidad {}: {:?}", entity_id, settings);
+
+    match app_state.logical_entity_controller_data.update_entity_view_settings_use_case.execute(entity_id, settings).await {
+        Ok(status) => {
+            let response_body = EntityViewStatusResponse::from(status);
+            Ok(HttpResponse::Ok().json(ApiResponse::success(Some(response_body), Some("View settings updated successfully."))))
+        },
+        Err(app_error) => {
+            error!("Error al actualizar las opciones de la vista de la entidad {}: {:?}", entity_id, app_error);
+            Ok(ErrorAdapter::map_application_error(app_error))
+        },
+    }
+}
+
+// Handler para la ruta POST /api/logical-entities/{id}/view/refresh
+// Refresca los datos de la vista materializada (REFRESH ... CONCURRENTLY).
+#[post("/{id}/view/refresh")]
+async fn refresh_materialized_view(
+    app_state: web::Data<AppState>,
+    id: web::Path<Uuid>,
+) -> Result<HttpResponse, Error> {
+    let entity_id = id.into_inner();
+    info!("Refrescando la vista materializada de la entidad lógica: {}", entity_id);
+
+    match app_state.logical_entity_controller_data.refresh_materialized_view_use_case.execute(entity_id).await {
+        Ok(status) => {
+            let response_body = EntityViewStatusResponse::from(status);
+            Ok(HttpResponse::Ok().json(ApiResponse::success(Some(response_body), Some("Materialized view refreshed successfully."))))
+        },
+        Err(app_error) => {
+            error!("Error al refrescar la vista materializada de la entidad {}: {:?}", entity_id, app_error);
+            Ok(ErrorAdapter::map_application_error(app_error))
+        },
+    }
+}
+
+// Handler para la ruta PUT /api/logical-entities/{id}/storage
+// Mueve los registros entre almacenamiento EAV y la tabla física de la entidad.
+#[put("/{id}/storage")]
+async fn change_storage_mode(
+    app_state: web::Data<AppState>,
+    id: web::Path<Uuid>,
+    req_payload: web::Json<ChangeStorageModeRequest>,
+) -> Result<HttpResponse, Error> {
+    validate_json(&req_payload)?;
+
+    let entity_id = id.into_inner();
+    let mode = req_payload.storage_mode;
+    info!("Cambiando el modo de almacenamiento de la entidad {} a '{}'", entity_id, mode.as_str());
+
+    match app_state.logical_entity_controller_data.change_storage_mode_use_case.execute(entity_id, mode).await {
+        Ok(change) => {
+            let response_body = StorageModeResponse::from(change);
+            Ok(HttpResponse::Ok().json(ApiResponse::success(Some(response_body), Some("Storage mode updated successfully."))))
+        },
+        Err(app_error) => {
+            error!("Error al cambiar el modo de almacenamiento de la entidad {}: {:?}", entity_id, app_error);
+            Ok(ErrorAdapter::map_application_error(app_error))
+        },
+    }
+}
+
+// Handler para la ruta GET /api/logical-entities/{id}/json-schema
+// Devuelve el documento JSON Schema (draft 2020-12) de los registros de la entidad.
+#[get("/{id}/json-schema")]
+async fn get_entity_json_schema(
+    app_state: web::Data<AppState>,
+    id: web::Path<Uuid>,
+) -> Result<HttpResponse, Error> {
+    let entity_id = id.into_inner();
+    info!("Generando JSON Schema de la entidad lógica: {}", entity_id);
+
+    match app_state.logical_entity_controller_data.entity_json_schema_use_case.execute(entity_id).await {
+        // El documento se devuelve tal cual (sin ApiResponse) para que lo consuman los validadores
+        Ok(schema) => Ok(HttpResponse::Ok().content_type("application/schema+json").json(schema)),
+        Err(app_error) => {
+            error!("Error al generar el JSON Schema de la entidad {}: {:?}", entity_id, app_error);
             Ok(ErrorAdapter::map_application_error(app_error))
         },
     }
@@ -90,6 +503,19 @@ pub fn config(cfg: &mut web::ServiceConfig) {
     cfg.service(
         web::scope("") // El prefijo se define en routes.rs
             .service(create_logical_entity)
-            // Añade aquí los servicios para find, update, delete cuando los implementes
+            .service(list_logical_entities)
+            .service(find_logical_entity_by_name)
+            .service(list_deleted_logical_entities) // Antes de /{id}: "trash" no es un {id}
+            .service(restore_logical_entity)
+            .service(purge_logical_entity)
+            .service(find_logical_entity_by_id)
+            .service(update_logical_entity)
+            .service(delete_logical_entity)
+            .service(refresh_entity_view)
+            .service(get_entity_view_status)
+            .service(update_entity_view_settings)
+            .service(refresh_materialized_view)
+            .service(change_storage_mode)
+            .service(get_entity_json_schema)
     );
 }
@@ -2,9 +2,25 @@ pub mod user_controller;
 pub mod auth_controller;
 pub mod health_controller;
 pub mod logical_entity_controller;
+pub mod record_controller;
+pub mod attribute_controller;
+pub mod schema_version_controller;
+pub mod data_type_controller;
+pub mod schema_bundle_controller;
+pub mod docs_controller;
+pub mod graphql_controller;
+pub mod trash_controller;
 
 
 pub use user_controller::UserController;
 pub use auth_controller::AuthController;
 pub use health_controller::HealthController;
 pub use logical_entity_controller::LogicalEntityController; // <--- AÑADIR
+pub use record_controller::RecordController;
+pub use attribute_controller::AttributeController;
+pub use schema_version_controller::SchemaVersionController;
+pub use data_type_controller::DataTypeController;
+pub use schema_bundle_controller::SchemaBundleController;
+pub use docs_controller::DocsController;
+pub use graphql_controller::GraphQLController;
+pub use trash_controller::TrashController;
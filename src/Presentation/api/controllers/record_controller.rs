@@ -0,0 +1,590 @@
+use actix_web::{web, HttpRequest, HttpResponse, post, get, put, patch, delete, Error};
+use futures::stream::{self, StreamExt};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::sync::Arc;
+use uuid::Uuid;
+use log::{info, debug, error};
+
+use crate::Container::app_state::AppState;
+use crate::Application::use_cases::records::{
+    CreateRecordUseCase,
+    FindRecordUseCase,
+    ListRecordsUseCase,
+    ListRecordsParams,
+    SearchRecordsUseCase,
+    ImportRecordsUseCase,
+    ExportRecordsUseCase,
+    UpdateRecordUseCase,
+    DeleteRecordUseCase,
+    ListDeletedRecordsUseCase,
+    RestoreRecordUseCase,
+    PurgeRecordUseCase,
+    RecordHistoryUseCase,
+    FindRecordAsOfUseCase,
+};
+use crate::Application::dtos::record_dto::{ImportRecordsDto, RecordUpdateMode};
+use crate::Application::errors::application_error::ApplicationError;
+use crate::Domain::record_exports::{ExportFormat, ExportWriter};
+use crate::Domain::record_imports::{ColumnMapping, ImportErrorPolicy, ImportFormat, ImportReader};
+use crate::Domain::record_history::ChangeContext;
+use crate::Presentation::api::validators::validate_request;
+use crate::Presentation::api::responses::ApiResponse;
+use crate::Presentation::api::responses::etag::{etag_header, if_match, not_modified};
+use crate::Presentation::api::models::request::{
+    ListRecordsQuery, SearchRecordsQuery, ImportRecordsQuery, ExportRecordsQuery, ListTrashQuery,
+    RecordHistoryQuery, RecordAsOfQuery,
+};
+use crate::Presentation::api::models::response::{
+    RecordResponse, RecordPageResponse, RecordSearchPageResponse, ImportReportResponse,
+    DeletedRecordResponse, TrashPageResponse, RecordHistoryPageResponse, RecordAsOfResponse,
+};
+use crate::Presentation::api::adapters::ErrorAdapter;
+use crate::Presentation::api::middleware::request_logger::request_id;
+use super::logical_entity_controller::placeholder_user_id;
+
+// Controlador para los registros (tuplas) de las entidades lógicas
+pub struct RecordController {
+    pub create_record_use_case: Arc<dyn CreateRecordUseCase>,
+    pub find_record_use_case: Arc<dyn FindRecordUseCase>,
+    pub list_records_use_case: Arc<dyn ListRecordsUseCase>,
+    pub search_records_use_case: Arc<dyn SearchRecordsUseCase>,
+    pub import_records_use_case: Arc<dyn ImportRecordsUseCase>,
+    pub export_records_use_case: Arc<dyn ExportRecordsUseCase>,
+    pub update_record_use_case: Arc<dyn UpdateRecordUseCase>,
+    pub delete_record_use_case: Arc<dyn DeleteRecordUseCase>,
+    pub list_deleted_records_use_case: Arc<dyn ListDeletedRecordsUseCase>,
+    pub restore_record_use_case: Arc<dyn RestoreRecordUseCase>,
+    pub purge_record_use_case: Arc<dyn PurgeRecordUseCase>,
+    pub record_history_use_case: Arc<dyn RecordHistoryUseCase>,
+    pub find_record_as_of_use_case: Arc<dyn FindRecordAsOfUseCase>,
+}
+
+impl RecordController {
+    pub fn new(
+        create_record_use_case: Arc<dyn CreateRecordUseCase>,
+        find_record_use_case: Arc<dyn FindRecordUseCase>,
+        list_records_use_case: Arc<dyn ListRecordsUseCase>,
+        search_records_use_case: Arc<dyn SearchRecordsUseCase>,
+        import_records_use_case: Arc<dyn ImportRecordsUseCase>,
+        export_records_use_case: Arc<dyn ExportRecordsUseCase>,
+        update_record_use_case: Arc<dyn UpdateRecordUseCase>,
+        delete_record_use_case: Arc<dyn DeleteRecordUseCase>,
+        list_deleted_records_use_case: Arc<dyn ListDeletedRecordsUseCase>,
+        restore_record_use_case: Arc<dyn RestoreRecordUseCase>,
+        purge_record_use_case: Arc<dyn PurgeRecordUseCase>,
+        record_history_use_case: Arc<dyn RecordHistoryUseCase>,
+        find_record_as_of_use_case: Arc<dyn FindRecordAsOfUseCase>,
+    ) -> Self {
+        Self {
+            create_record_use_case,
+            find_record_use_case,
+            list_records_use_case,
+            search_records_use_case,
+            import_records_use_case,
+            export_records_use_case,
+            update_record_use_case,
+            delete_record_use_case,
+            list_deleted_records_use_case,
+            restore_record_use_case,
+            purge_record_use_case,
+            record_history_use_case,
+            find_record_as_of_use_case,
+        }
+    }
+}
+
+/// Autor y petición de un cambio de registros, para el historial.
+pub(crate) fn change_context(req: &HttpRequest) -> ChangeContext {
+    ChangeContext::new(placeholder_user_id(), request_id(req)) // <--- ¡USA EL user_id REAL AQUÍ!
+}
+
+/// Tamaño de página por defecto para GET /api/entities/{entity_name}/records
+const DEFAULT_PAGE_SIZE: i64 = 20;
+
+/// Entradas por página por defecto para GET /api/entities/{entity_name}/records/{id}/history
+const DEFAULT_HISTORY_LIMIT: i64 = 50;
+
+/// Tamaño máximo del archivo de POST /api/entities/{entity_name}/records/import
+/// (el cuerpo se lee por partes, así que no se aplica el límite de web::PayloadConfig)
+const MAX_IMPORT_PAYLOAD: usize = 64 * 1024 * 1024;
+
+// Handler para la ruta POST /api/entities/{entity_name}/records
+#[post("/{entity_name}/records")]
+async fn create_record(
+    app_state: web::Data<AppState>,
+    req: HttpRequest,
+    entity_name: web::Path<String>,
+    req_payload: web::Json<Map<String, Value>>,
+) -> Result<HttpResponse, Error> {
+    let entity_name = entity_name.into_inner();
+    info!("Creando registro en la entidad '{}'", entity_name);
+
+    match app_state.record_controller_data.create_record_use_case
+        .execute(&entity_name, req_payload.into_inner(), change_context(&req))
+        .await
+    {
+        Ok(record) => {
+            info!("Registro creado con éxito: ID={}", record.id);
+            let etag = etag_header(record.version);
+            let response_body = RecordResponse::from(record);
+            Ok(HttpResponse::Created().insert_header(etag).json(ApiResponse::success(Some(response_body), Some("Record created successfully."))))
+        },
+        Err(app_error) => {
+            error!("Error al crear registro en '{}': {:?}", entity_name, app_error);
+            Ok(ErrorAdapter::map_application_error(app_error))
+        },
+    }
+}
+
+// Handler para la ruta GET /api/entities/{entity_name}/records?filter=...&sort=-created_at&limit=20&after=<cursor>
+#[get("/{entity_name}/records")]
+async fn list_records(
+    app_state: web::Data<AppState>,
+    entity_name: web::Path<String>,
+    query: web::Query<ListRecordsQuery>,
+) -> Result<HttpResponse, Error> {
+    validate_request(&query.0)?;
+
+    let entity_name = entity_name.into_inner();
+    let query = query.into_inner();
+    let params = ListRecordsParams {
+        filter: query.filter,
+        sort: query.sort,
+        limit: query.limit.unwrap_or(DEFAULT_PAGE_SIZE),
+        after: query.after,
+    };
+    info!("Listando registros de '{}': filter={:?}, sort={:?}, limit={}", entity_name, params.filter, params.sort, params.limit);
+
+    match app_state.record_controller_data.list_records_use_case.execute(&entity_name, params).await {
+        Ok(page_dto) => {
+            info!("Se encontraron {} registros en '{}' (hay más: {})", page_dto.items.len(), entity_name, page_dto.next_cursor.is_some());
+            let response_body = RecordPageResponse::from(page_dto);
+            Ok(HttpResponse::Ok().json(ApiResponse::success(Some(response_body), None)))
+        },
+        Err(app_error) => {
+            error!("Error al listar registros de '{}': {:?}", entity_name, app_error);
+            Ok(ErrorAdapter::map_application_error(app_error))
+        },
+    }
+}
+
+// Handler para la ruta GET /api/entities/{entity_name}/records/search?q=...&limit=20&after=<cursor>
+#[get("/{entity_name}/records/search")]
+async fn search_records(
+    app_state: web::Data<AppState>,
+    entity_name: web::Path<String>,
+    query: web::Query<SearchRecordsQuery>,
+) -> Result<HttpResponse, Error> {
+    validate_request(&query.0)?;
+
+    let entity_name = entity_name.into_inner();
+    let limit = query.limit.unwrap_or(DEFAULT_PAGE_SIZE);
+    info!("Buscando registros de '{}': q='{}', limit={}", entity_name, query.q, limit);
+
+    match app_state.record_controller_data.search_records_use_case
+        .execute(&entity_name, &query.q, limit, query.after.as_deref())
+        .await
+    {
+        Ok(page_dto) => {
+            info!("La búsqueda en '{}' devolvió {} registros (hay más: {})", entity_name, page_dto.items.len(), page_dto.next_cursor.is_some());
+            let response_body = RecordSearchPageResponse::from(page_dto);
+            Ok(HttpResponse::Ok().json(ApiResponse::success(Some(response_body), None)))
+        },
+        Err(app_error) => {
+            error!("Error al buscar registros en '{}': {:?}", entity_name, app_error);
+            Ok(ErrorAdapter::map_application_error(app_error))
+        },
+    }
+}
+
+// Handler para la ruta POST /api/entities/{entity_name}/records/import?format=csv&dry_run=true&on_error=skip_invalid
+// El cuerpo es el contenido del archivo; la respuesta es el informe de errores por fila.
+#[post("/{entity_name}/records/import")]
+async fn import_records(
+    app_state: web::Data<AppState>,
+    req: HttpRequest,
+    entity_name: web::Path<String>,
+    query: web::Query<ImportRecordsQuery>,
+    body: web::Payload,
+) -> Result<HttpResponse, Error> {
+    validate_request(&query.0)?;
+
+    let entity_name = entity_name.into_inner();
+    let query = query.into_inner();
+    info!("Importando registros en '{}': format={}, dry_run={:?}, on_error={:?}", entity_name, query.format, query.dry_run, query.on_error);
+
+    let import = match read_import(query, body, change_context(&req)).await {
+        Ok(import) => import,
+        Err(app_error) => return Ok(ErrorAdapter::map_application_error(app_error)),
+    };
+
+    match app_state.record_controller_data.import_records_use_case.execute(&entity_name, import).await {
+        Ok(report) => {
+            info!("Importación en '{}': {} filas, {} importadas, {} con errores", entity_name, report.total_rows, report.imported, report.invalid_rows);
+            let message = if report.applied {
+                "Records imported successfully."
+            } else if report.dry_run {
+                "Dry run completed; no records were imported."
+            } else {
+                "No records were imported."
+            };
+            let response_body = ImportReportResponse::from(report);
+            Ok(HttpResponse::Ok().json(ApiResponse::success(Some(response_body), Some(message))))
+        },
+        Err(app_error) => {
+            error!("Error al importar registros en '{}': {:?}", entity_name, app_error);
+            Ok(ErrorAdapter::map_application_error(app_error))
+        },
+    }
+}
+
+/// Traduce los parámetros de la importación al DTO del caso de uso. El cuerpo (UTF-8)
+/// se lee línea a línea a medida que llega, sin cargar el archivo entero: la lectura se
+/// corta al superar MAX_IMPORT_PAYLOAD bytes o el máximo de filas (MAX_IMPORT_ROWS).
+async fn read_import(query: ImportRecordsQuery, mut body: web::Payload, context: ChangeContext) -> Result<ImportRecordsDto, ApplicationError> {
+    let invalid = |message: String| ApplicationError::ValidationError(message);
+    let format = ImportFormat::from_name(&query.format).map_err(|e| invalid(e.to_string()))?;
+    let on_error = match query.on_error.as_deref() {
+        Some(name) => ImportErrorPolicy::from_name(name).map_err(|e| invalid(e.to_string()))?,
+        None => ImportErrorPolicy::AllOrNothing,
+    };
+    let mapping = match query.mapping.as_deref() {
+        Some(text) => serde_json::from_str::<HashMap<String, String>>(text)
+            .map_err(|e| invalid(format!("mapping must be a JSON object of column -> attribute: {}", e)))?,
+        None => HashMap::new(),
+    };
+    let mapping = ColumnMapping::new(mapping).map_err(|e| invalid(e.to_string()))?;
+    let delimiter = query.delimiter.and_then(|d| d.chars().next()).unwrap_or(',');
+    let mut reader = ImportReader::new(format, delimiter, mapping.clone()).map_err(|e| invalid(e.to_string()))?;
+
+    let mut size = 0;
+    let mut buffer: Vec<u8> = Vec::new();
+    while let Some(chunk) = body.next().await {
+        let chunk = chunk.map_err(|e| invalid(format!("The import file could not be read: {}", e)))?;
+        size += chunk.len();
+        if size > MAX_IMPORT_PAYLOAD {
+            return Err(invalid(format!("The import file exceeds the maximum size of {} bytes", MAX_IMPORT_PAYLOAD)));
+        }
+        buffer.extend_from_slice(&chunk);
+        // Se procesan las líneas completas; el resto espera al siguiente fragmento
+        let mut start = 0;
+        while let Some(end) = buffer[start..].iter().position(|b| *b == b'\n') {
+            push_import_line(&mut reader, &buffer[start..start + end])?;
+            start += end + 1;
+        }
+        buffer.drain(..start);
+    }
+    if !buffer.is_empty() {
+        push_import_line(&mut reader, &buffer)?;
+    }
+    let (rows, issues) = reader.finish().map_err(|e| invalid(e.to_string()))?;
+    debug!("Archivo de importación leído: {} bytes, {} filas", size, rows.len() + issues.len());
+
+    Ok(ImportRecordsDto {
+        format,
+        rows,
+        issues,
+        mapping,
+        dry_run: query.dry_run.unwrap_or(false),
+        on_error,
+        context,
+    })
+}
+
+/// Pasa una línea del cuerpo (sin el salto de línea) al lector de la importación.
+fn push_import_line(reader: &mut ImportReader, line: &[u8]) -> Result<(), ApplicationError> {
+    let text = std::str::from_utf8(line)
+        .map_err(|_| ApplicationError::ValidationError("The import file must be UTF-8 encoded".to_string()))?;
+    reader.push_line(text).map_err(|e| ApplicationError::ValidationError(e.to_string()))
+}
+
+// Handler para la ruta GET /api/entities/{entity_name}/records/export?format=csv|ndjson|json&filter=...&sort=...
+// La respuesta se envía por partes a medida que se leen los lotes del cursor.
+#[get("/{entity_name}/records/export")]
+async fn export_records(
+    app_state: web::Data<AppState>,
+    entity_name: web::Path<String>,
+    query: web::Query<ExportRecordsQuery>,
+) -> Result<HttpResponse, Error> {
+    validate_request(&query.0)?;
+
+    let entity_name = entity_name.into_inner();
+    let query = query.into_inner();
+    let format = match ExportFormat::from_name(query.format.as_deref().unwrap_or("csv")) {
+        Ok(format) => format,
+        Err(e) => return Ok(ErrorAdapter::map_application_error(ApplicationError::ValidationError(e.to_string()))),
+    };
+    info!("Exportando registros de '{}': format={:?}, filter={:?}, sort={:?}", entity_name, format, query.filter, query.sort);
+
+    match app_state.record_controller_data.export_records_use_case.execute(&entity_name, query.filter, query.sort).await {
+        Ok(export) => {
+            let mut writer = ExportWriter::new(format, export.columns);
+            let header = web::Bytes::from(writer.header());
+            let footer = web::Bytes::from(writer.footer());
+            let rows = export.rows.map(move |batch| {
+                batch.map(|rows| {
+                    let chunk: String = rows.iter().map(|row| writer.row(row)).collect();
+                    web::Bytes::from(chunk)
+                })
+            });
+            let body = stream::once(async move { Ok::<_, ApplicationError>(header) })
+                .chain(rows)
+                .chain(stream::once(async move { Ok(footer) }));
+
+            Ok(HttpResponse::Ok()
+                .content_type(format.content_type())
+                .insert_header(("Content-Disposition", format!("attachment; filename=\"{}.{}\"", entity_name, format.extension())))
+                .streaming(body))
+        },
+        Err(app_error) => {
+            error!("Error al exportar registros de '{}': {:?}", entity_name, app_error);
+            Ok(ErrorAdapter::map_application_error(app_error))
+        },
+    }
+}
+
+
+// Handler para la ruta GET /api/entities/{entity_name}/records/{id}
+#[get("/{entity_name}/records/{id}")]
+async fn find_record(
+    app_state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, Uuid)>,
+) -> Result<HttpResponse, Error> {
+    let (entity_name, record_id) = path.into_inner();
+    info!("Buscando registro {} en '{}'", record_id, entity_name);
+
+    match app_state.record_controller_data.find_record_use_case.execute(&entity_name, record_id).await {
+        Ok(record) => {
+            if let Some(response) = not_modified(&req, record.version) {
+                return Ok(response);
+            }
+            let etag = etag_header(record.version);
+            let response_body = RecordResponse::from(record);
+            Ok(HttpResponse::Ok().insert_header(etag).json(ApiResponse::success(Some(response_body), None)))
+        },
+        Err(app_error) => {
+            error!("Error al buscar registro {} en '{}': {:?}", record_id, entity_name, app_error);
+            Ok(ErrorAdapter::map_application_error(app_error))
+        },
+    }
+}
+
+/// Lógica común de PUT (reemplazo) y PATCH (fusión).
+async fn update_record(
+    app_state: web::Data<AppState>,
+    req: HttpRequest,
+    entity_name: String,
+    record_id: Uuid,
+    values: Map<String, Value>,
+    mode: RecordUpdateMode,
+) -> Result<HttpResponse, Error> {
+    info!("Actualizando registro {} en '{}' ({:?})", record_id, entity_name, mode);
+
+    match app_state.record_controller_data.update_record_use_case
+        .execute(&entity_name, record_id, values, mode, change_context(&req), if_match(&req))
+        .await
+    {
+        Ok(record) => {
+            info!("Registro actualizado con éxito: ID={}", record_id);
+            let etag = etag_header(record.version);
+            let response_body = RecordResponse::from(record);
+            Ok(HttpResponse::Ok().insert_header(etag).json(ApiResponse::success(Some(response_body), Some("Record updated successfully."))))
+        },
+        Err(app_error) => {
+            error!("Error al actualizar registro {} en '{}': {:?}", record_id, entity_name, app_error);
+            Ok(ErrorAdapter::map_application_error(app_error))
+        },
+    }
+}
+
+// Handler para la ruta PUT /api/entities/{entity_name}/records/{id}
+#[put("/{entity_name}/records/{id}")]
+async fn replace_record(
+    app_state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, Uuid)>,
+    req_payload: web::Json<Map<String, Value>>,
+) -> Result<HttpResponse, Error> {
+    let (entity_name, record_id) = path.into_inner();
+    update_record(app_state, req, entity_name, record_id, req_payload.into_inner(), RecordUpdateMode::Replace).await
+}
+
+// Handler para la ruta PATCH /api/entities/{entity_name}/records/{id}
+#[patch("/{entity_name}/records/{id}")]
+async fn patch_record(
+    app_state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, Uuid)>,
+    req_payload: web::Json<Map<String, Value>>,
+) -> Result<HttpResponse, Error> {
+    let (entity_name, record_id) = path.into_inner();
+    update_record(app_state, req, entity_name, record_id, req_payload.into_inner(), RecordUpdateMode::Merge).await
+}
+
+// Handler para la ruta DELETE /api/entities/{entity_name}/records/{id}
+#[delete("/{entity_name}/records/{id}")]
+async fn delete_record(
+    app_state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, Uuid)>,
+) -> Result<HttpResponse, Error> {
+    let (entity_name, record_id) = path.into_inner();
+    info!("Moviendo a la papelera el registro {} de '{}'", record_id, entity_name);
+
+    match app_state.record_controller_data.delete_record_use_case
+        .execute(&entity_name, record_id, change_context(&req), if_match(&req))
+        .await
+    {
+        Ok(()) => {
+            info!("Registro movido a la papelera: ID={}", record_id);
+            Ok(HttpResponse::Ok().json(ApiResponse::<()>::success(None, Some("Record moved to the trash."))))
+        },
+        Err(app_error) => {
+            error!("Error al eliminar registro {} de '{}': {:?}", record_id, entity_name, app_error);
+            Ok(ErrorAdapter::map_application_error(app_error))
+        },
+    }
+}
+
+// Handler para la ruta GET /api/entities/{entity_name}/records/trash?page=1&page_size=20
+#[get("/{entity_name}/records/trash")]
+async fn list_deleted_records(
+    app_state: web::Data<AppState>,
+    entity_name: web::Path<String>,
+    query: web::Query<ListTrashQuery>,
+) -> Result<HttpResponse, Error> {
+    validate_request(&query.0)?;
+
+    let entity_name = entity_name.into_inner();
+    let page = query.page.unwrap_or(1);
+    let page_size = query.page_size.unwrap_or(DEFAULT_PAGE_SIZE);
+    info!("Listando la papelera de '{}': page={}, page_size={}", entity_name, page, page_size);
+
+    match app_state.record_controller_data.list_deleted_records_use_case.execute(&entity_name, page, page_size).await {
+        Ok(page_dto) => {
+            info!("La papelera de '{}' tiene {} registros", entity_name, page_dto.total);
+            let response_body: TrashPageResponse<DeletedRecordResponse> = TrashPageResponse::from(page_dto);
+            Ok(HttpResponse::Ok().json(ApiResponse::success(Some(response_body), None)))
+        },
+        Err(app_error) => {
+            error!("Error al listar la papelera de '{}': {:?}", entity_name, app_error);
+            Ok(ErrorAdapter::map_application_error(app_error))
+        },
+    }
+}
+
+// Handler para la ruta POST /api/entities/{entity_name}/records/trash/{id}/restore
+#[post("/{entity_name}/records/trash/{id}/restore")]
+async fn restore_record(
+    app_state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, Uuid)>,
+) -> Result<HttpResponse, Error> {
+    let (entity_name, record_id) = path.into_inner();
+    info!("Restaurando registro {} de '{}'", record_id, entity_name);
+
+    match app_state.record_controller_data.restore_record_use_case.execute(&entity_name, record_id, change_context(&req)).await {
+        Ok(record) => {
+            info!("Registro restaurado con éxito: ID={}", record_id);
+            let etag = etag_header(record.version);
+            let response_body = RecordResponse::from(record);
+            Ok(HttpResponse::Ok().insert_header(etag).json(ApiResponse::success(Some(response_body), Some("Record restored successfully."))))
+        },
+        Err(app_error) => {
+            error!("Error al restaurar registro {} de '{}': {:?}", record_id, entity_name, app_error);
+            Ok(ErrorAdapter::map_application_error(app_error))
+        },
+    }
+}
+
+// Handler para la ruta DELETE /api/entities/{entity_name}/records/trash/{id}
+#[delete("/{entity_name}/records/trash/{id}")]
+async fn purge_record(
+    app_state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, Uuid)>,
+) -> Result<HttpResponse, Error> {
+    let (entity_name, record_id) = path.into_inner();
+    info!("Purgando registro {} de '{}'", record_id, entity_name);
+
+    match app_state.record_controller_data.purge_record_use_case.execute(&entity_name, record_id, change_context(&req)).await {
+        Ok(purged) => {
+            info!("Registro {} purgado ({} registros en total)", record_id, purged);
+            Ok(HttpResponse::Ok().json(ApiResponse::<()>::success(None, Some("Record permanently deleted."))))
+        },
+        Err(app_error) => {
+            error!("Error al purgar registro {} de '{}': {:?}", record_id, entity_name, app_error);
+            Ok(ErrorAdapter::map_application_error(app_error))
+        },
+    }
+}
+
+// Handler para la ruta GET /api/entities/{entity_name}/records/{id}/history?limit=50&before=<cursor>
+#[get("/{entity_name}/records/{id}/history")]
+async fn record_history(
+    app_state: web::Data<AppState>,
+    path: web::Path<(String, Uuid)>,
+    query: web::Query<RecordHistoryQuery>,
+) -> Result<HttpResponse, Error> {
+    validate_request(&query.0)?;
+
+    let (entity_name, record_id) = path.into_inner();
+    let limit = query.limit.unwrap_or(DEFAULT_HISTORY_LIMIT);
+    info!("Consultando el historial del registro {} de '{}': limit={}, before={:?}", record_id, entity_name, limit, query.before);
+
+    match app_state.record_controller_data.record_history_use_case.execute(&entity_name, record_id, limit, query.before).await {
+        Ok(page) => {
+            let response_body = RecordHistoryPageResponse::from(page);
+            Ok(HttpResponse::Ok().json(ApiResponse::success(Some(response_body), None)))
+        },
+        Err(app_error) => {
+            error!("Error al consultar el historial del registro {} de '{}': {:?}", record_id, entity_name, app_error);
+            Ok(ErrorAdapter::map_application_error(app_error))
+        },
+    }
+}
+
+// Handler para la ruta GET /api/entities/{entity_name}/records/{id}/as-of?at=2024-03-01T00:00:00Z
+#[get("/{entity_name}/records/{id}/as-of")]
+async fn find_record_as_of(
+    app_state: web::Data<AppState>,
+    path: web::Path<(String, Uuid)>,
+    query: web::Query<RecordAsOfQuery>,
+) -> Result<HttpResponse, Error> {
+    let (entity_name, record_id) = path.into_inner();
+    info!("Reconstruyendo el registro {} de '{}' en {}", record_id, entity_name, query.at);
+
+    match app_state.record_controller_data.find_record_as_of_use_case.execute(&entity_name, record_id, query.at).await {
+        Ok(record) => {
+            let response_body = RecordAsOfResponse::from(record);
+            Ok(HttpResponse::Ok().json(ApiResponse::success(Some(response_body), None)))
+        },
+        Err(app_error) => {
+            error!("Error al reconstruir el registro {} de '{}' en {}: {:?}", record_id, entity_name, query.at, app_error);
+            Ok(ErrorAdapter::map_application_error(app_error))
+        },
+    }
+}
+
+// Configuración de las rutas para este controlador
+pub fn config(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("") // El prefijo se define en routes.rs
+            .service(create_record)
+            .service(list_records)
+            .service(search_records) // Antes de find_record: "search" no es un {id}
+            .service(import_records)
+            .service(export_records) // Antes de find_record: "export" no es un {id}
+            .service(list_deleted_records) // Antes de find_record: "trash" no es un {id}
+            .service(restore_record)
+            .service(purge_record)
+            .service(record_history)
+            .service(find_record_as_of)
+            .service(find_record)
+            .service(replace_record)
+            .service(patch_record)
+            .service(delete_record)
+    );
+}
@@ -0,0 +1,194 @@
+use actix_web::{web, HttpRequest, HttpResponse, get, post, Error};
+use std::sync::Arc;
+use log::{info, error};
+
+use crate::Container::app_state::AppState;
+use crate::Application::use_cases::schema_bundles::{ExportSchemaBundleUseCase, ImportSchemaBundleUseCase, PreviewEntitySchemaUseCase};
+use crate::Application::dtos::schema_bundle_dto::ImportSchemaBundleDto;
+use crate::Application::errors::application_error::ApplicationError;
+use crate::Domain::schema_bundles::{BundleEntity, BundleFormat, SchemaBundle};
+use crate::Presentation::api::validators::validate_request;
+use crate::Presentation::api::responses::ApiResponse;
+use crate::Presentation::api::models::request::{ExportSchemaBundleQuery, ImportSchemaBundleQuery, PreviewEntitySchemaQuery};
+use crate::Presentation::api::models::response::{EntitySchemaPreviewResponse, SchemaBundleImportResponse};
+use crate::Presentation::api::adapters::ErrorAdapter;
+use super::logical_entity_controller::placeholder_user_id;
+
+/// Tamaño máximo del bundle recibido en /import.
+const MAX_BUNDLE_PAYLOAD: usize = 8 * 1024 * 1024;
+
+// Controlador para los bundles de definiciones (promoción de modelos entre entornos)
+pub struct SchemaBundleController {
+    pub export_schema_bundle_use_case: Arc<dyn ExportSchemaBundleUseCase>,
+    pub import_schema_bundle_use_case: Arc<dyn ImportSchemaBundleUseCase>,
+    pub preview_entity_schema_use_case: Arc<dyn PreviewEntitySchemaUseCase>,
+}
+
+impl SchemaBundleController {
+    pub fn new(
+        export_schema_bundle_use_case: Arc<dyn ExportSchemaBundleUseCase>,
+        import_schema_bundle_use_case: Arc<dyn ImportSchemaBundleUseCase>,
+        preview_entity_schema_use_case: Arc<dyn PreviewEntitySchemaUseCase>,
+    ) -> Self {
+        Self { export_schema_bundle_use_case, import_schema_bundle_use_case, preview_entity_schema_use_case }
+    }
+}
+
+// Handler para la ruta GET /api/schema-bundles/export?entities=customer,invoice&format=json|yaml
+// La respuesta es el bundle como archivo adjunto.
+#[get("/export")]
+async fn export_schema_bundle(
+    app_state: web::Data<AppState>,
+    query: web::Query<ExportSchemaBundleQuery>,
+) -> Result<HttpResponse, Error> {
+    validate_request(&query.0)?;
+
+    let query = query.into_inner();
+    let format = match BundleFormat::from_name(query.format.as_deref().unwrap_or("json")) {
+        Ok(format) => format,
+        Err(e) => return Ok(ErrorAdapter::map_application_error(ApplicationError::ValidationError(e.to_string()))),
+    };
+    let entity_names: Vec<String> = query.entities
+        .as_deref()
+        .map(|names| names.split(',').map(|n| n.trim().to_string()).filter(|n| !n.is_empty()).collect())
+        .unwrap_or_default();
+    info!("Exportando bundle de definiciones: entidades={:?}, format={:?}", entity_names, format);
+
+    let content = match app_state.schema_bundle_controller_data.export_schema_bundle_use_case.execute(entity_names).await {
+        Ok(bundle) => bundle.render(format).map_err(|e| ApplicationError::UnexpectedError(e.to_string())),
+        Err(app_error) => Err(app_error),
+    };
+    match content {
+        Ok(content) => Ok(HttpResponse::Ok()
+            .content_type(format.content_type())
+            .insert_header(("Content-Disposition", format!("attachment; filename=\"schema-bundle.{}\"", format.extension())))
+            .body(content)),
+        Err(app_error) => {
+            error!("Error al exportar el bundle de definiciones: {:?}", app_error);
+            Ok(ErrorAdapter::map_application_error(app_error))
+        },
+    }
+}
+
+// Handler para la ruta POST /api/schema-bundles/import?format=yaml&dry_run=true&prune=false
+// El cuerpo es el bundle; la respuesta es el plan de cambios y si se aplicó.
+#[post("/import")]
+async fn import_schema_bundle(
+    app_state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<ImportSchemaBundleQuery>,
+    body: web::Bytes,
+) -> Result<HttpResponse, Error> {
+    validate_request(&query.0)?;
+
+    let query = query.into_inner();
+    info!("Importando bundle de definiciones: format={:?}, dry_run={:?}, prune={:?}, {} bytes", query.format, query.dry_run, query.prune, body.len());
+
+    let bundle = match parse_bundle(query.format.as_deref(), &req, &body) {
+        Ok(bundle) => bundle,
+        Err(app_error) => return Ok(ErrorAdapter::map_application_error(app_error)),
+    };
+    let import = ImportSchemaBundleDto {
+        bundle,
+        dry_run: query.dry_run.unwrap_or(false),
+        prune: query.prune.unwrap_or(false),
+        updated_by: placeholder_user_id(), // <--- ¡USA EL user_id REAL AQUÍ!
+    };
+
+    match app_state.schema_bundle_controller_data.import_schema_bundle_use_case.execute(import).await {
+        Ok(report) => {
+            let message = if report.applied {
+                "Schema bundle applied successfully."
+            } else if !report.plan.conflicts.is_empty() {
+                "The schema bundle has conflicts and was not applied."
+            } else if report.dry_run {
+                "Dry run completed; no changes were applied."
+            } else {
+                "The schema is already up to date."
+            };
+            let response_body = SchemaBundleImportResponse::from(report);
+            Ok(HttpResponse::Ok().json(ApiResponse::success(Some(response_body), Some(message))))
+        },
+        Err(app_error) => {
+            error!("Error al importar el bundle de definiciones: {:?}", app_error);
+            Ok(ErrorAdapter::map_application_error(app_error))
+        },
+    }
+}
+
+// Handler para la ruta POST /api/schema-bundles/preview?format=yaml
+// El cuerpo es una entidad en el formato del bundle; la respuesta es lo que ocurriría
+// al crearla o cambiarla (plan, SQL de la vista, impacto en los registros y avisos).
+#[post("/preview")]
+async fn preview_entity_schema(
+    app_state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<PreviewEntitySchemaQuery>,
+    body: web::Bytes,
+) -> Result<HttpResponse, Error> {
+    validate_request(&query.0)?;
+
+    let query = query.into_inner();
+    info!("Vista previa de una entidad: format={:?}, {} bytes", query.format, body.len());
+
+    let entity = match parse_entity(query.format.as_deref(), &req, &body) {
+        Ok(entity) => entity,
+        Err(app_error) => return Ok(ErrorAdapter::map_application_error(app_error)),
+    };
+
+    match app_state.schema_bundle_controller_data.preview_entity_schema_use_case.execute(entity).await {
+        Ok(preview) => {
+            let message = if preview.plan.conflicts.is_empty() {
+                "Preview computed; no changes were applied."
+            } else {
+                "The entity definition has conflicts and could not be applied as is."
+            };
+            let response_body = EntitySchemaPreviewResponse::from(preview);
+            Ok(HttpResponse::Ok().json(ApiResponse::success(Some(response_body), Some(message))))
+        },
+        Err(app_error) => {
+            error!("Error al calcular la vista previa de la entidad: {:?}", app_error);
+            Ok(ErrorAdapter::map_application_error(app_error))
+        },
+    }
+}
+
+/// Lee el bundle del cuerpo.
+fn parse_bundle(format: Option<&str>, req: &HttpRequest, body: &[u8]) -> Result<SchemaBundle, ApplicationError> {
+    let format = body_format(format, req)?;
+    let content = body_text(body, "The schema bundle")?;
+    SchemaBundle::parse(content, format).map_err(|e| ApplicationError::ValidationError(e.to_string()))
+}
+
+/// Lee del cuerpo la entidad de la vista previa.
+fn parse_entity(format: Option<&str>, req: &HttpRequest, body: &[u8]) -> Result<BundleEntity, ApplicationError> {
+    let format = body_format(format, req)?;
+    let content = body_text(body, "The entity definition")?;
+    BundleEntity::parse(content, format).map_err(|e| ApplicationError::ValidationError(e.to_string()))
+}
+
+/// Formato del cuerpo. Sin `format`, un Content-Type YAML indica YAML y cualquier otro, JSON.
+fn body_format(format: Option<&str>, req: &HttpRequest) -> Result<BundleFormat, ApplicationError> {
+    match format {
+        Some(name) => BundleFormat::from_name(name).map_err(|e| ApplicationError::ValidationError(e.to_string())),
+        None => {
+            let content_type = req.headers().get("Content-Type").and_then(|v| v.to_str().ok()).unwrap_or_default();
+            Ok(if content_type.contains("yaml") { BundleFormat::Yaml } else { BundleFormat::Json })
+        },
+    }
+}
+
+fn body_text<'a>(body: &'a [u8], what: &str) -> Result<&'a str, ApplicationError> {
+    std::str::from_utf8(body).map_err(|_| ApplicationError::ValidationError(format!("{} must be UTF-8 encoded", what)))
+}
+
+// Configuración de las rutas para este controlador
+pub fn config(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("") // El prefijo (/api/schema-bundles) se define en routes.rs
+            .app_data(web::PayloadConfig::new(MAX_BUNDLE_PAYLOAD))
+            .service(export_schema_bundle)
+            .service(import_schema_bundle)
+            .service(preview_entity_schema)
+    );
+}
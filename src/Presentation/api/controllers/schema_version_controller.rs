@@ -0,0 +1,177 @@
+use actix_web::{web, HttpResponse, post, get, Error};
+use std::sync::Arc;
+use uuid::Uuid;
+use log::{info, error};
+
+use crate::Container::app_state::AppState;
+use crate::Application::use_cases::schema_versions::{
+    ListSchemaVersionsUseCase,
+    FindSchemaVersionUseCase,
+    FindSchemaVersionAsOfUseCase,
+    DiffSchemaVersionsUseCase,
+    RollbackSchemaVersionUseCase,
+};
+use crate::Presentation::api::validators::validate_request;
+use crate::Presentation::api::responses::ApiResponse;
+use crate::Presentation::api::models::request::{SchemaVersionDiffQuery, SchemaVersionAsOfQuery};
+use crate::Presentation::api::models::response::{
+    SchemaVersionSummaryResponse, SchemaVersionResponse, SchemaVersionListResponse, SchemaVersionDiffResponse,
+};
+use crate::Presentation::api::adapters::ErrorAdapter;
+use super::logical_entity_controller::placeholder_user_id;
+
+// Controlador para las versiones de la definición de una entidad lógica
+pub struct SchemaVersionController {
+    pub list_schema_versions_use_case: Arc<dyn ListSchemaVersionsUseCase>,
+    pub find_schema_version_use_case: Arc<dyn FindSchemaVersionUseCase>,
+    pub find_schema_version_as_of_use_case: Arc<dyn FindSchemaVersionAsOfUseCase>,
+    pub diff_schema_versions_use_case: Arc<dyn DiffSchemaVersionsUseCase>,
+    pub rollback_schema_version_use_case: Arc<dyn RollbackSchemaVersionUseCase>,
+}
+
+impl SchemaVersionController {
+    pub fn new(
+        list_schema_versions_use_case: Arc<dyn ListSchemaVersionsUseCase>,
+        find_schema_version_use_case: Arc<dyn FindSchemaVersionUseCase>,
+        find_schema_version_as_of_use_case: Arc<dyn FindSchemaVersionAsOfUseCase>,
+        diff_schema_versions_use_case: Arc<dyn DiffSchemaVersionsUseCase>,
+        rollback_schema_version_use_case: Arc<dyn RollbackSchemaVersionUseCase>,
+    ) -> Self {
+        Self {
+            list_schema_versions_use_case,
+            find_schema_version_use_case,
+            find_schema_version_as_of_use_case,
+            diff_schema_versions_use_case,
+            rollback_schema_version_use_case,
+        }
+    }
+}
+
+// Handler para la ruta GET /api/logical-entities/{entity_id}/versions
+#[get("")]
+async fn list_schema_versions(
+    app_state: web::Data<AppState>,
+    entity_id: web::Path<Uuid>,
+) -> Result<HttpResponse, Error> {
+    let entity_id = entity_id.into_inner();
+    info!("Listando versiones de la entidad lógica {}", entity_id);
+
+    match app_state.schema_version_controller_data.list_schema_versions_use_case.execute(entity_id).await {
+        Ok(versions) => {
+            let response_body = SchemaVersionListResponse {
+                entity_id,
+                versions: versions.into_iter().map(SchemaVersionSummaryResponse::from).collect(),
+            };
+            Ok(HttpResponse::Ok().json(ApiResponse::success(Some(response_body), None)))
+        },
+        Err(app_error) => {
+            error!("Error al listar versiones de la entidad {}: {:?}", entity_id, app_error);
+            Ok(ErrorAdapter::map_application_error(app_error))
+        },
+    }
+}
+
+// Handler para la ruta GET /api/logical-entities/{entity_id}/versions/as-of?at=2024-01-31T00:00:00Z
+#[get("/as-of")]
+async fn find_schema_version_as_of(
+    app_state: web::Data<AppState>,
+    entity_id: web::Path<Uuid>,
+    query: web::Query<SchemaVersionAsOfQuery>,
+) -> Result<HttpResponse, Error> {
+    let entity_id = entity_id.into_inner();
+    info!("Buscando la versión de la entidad lógica {} vigente en {}", entity_id, query.at);
+
+    match app_state.schema_version_controller_data.find_schema_version_as_of_use_case.execute(entity_id, query.at).await {
+        Ok(version) => {
+            let response_body = SchemaVersionResponse::from(version);
+            Ok(HttpResponse::Ok().json(ApiResponse::success(Some(response_body), None)))
+        },
+        Err(app_error) => {
+            error!("Error al buscar la versión vigente de la entidad {}: {:?}", entity_id, app_error);
+            Ok(ErrorAdapter::map_application_error(app_error))
+        },
+    }
+}
+
+// Handler para la ruta GET /api/logical-entities/{entity_id}/versions/diff?from=1&to=3
+#[get("/diff")]
+async fn diff_schema_versions(
+    app_state: web::Data<AppState>,
+    entity_id: web::Path<Uuid>,
+    query: web::Query<SchemaVersionDiffQuery>,
+) -> Result<HttpResponse, Error> {
+    validate_request(&query.0)?;
+
+    let entity_id = entity_id.into_inner();
+    info!("Comparando versiones {} y {} de la entidad lógica {}", query.from, query.to, entity_id);
+
+    match app_state.schema_version_controller_data.diff_schema_versions_use_case.execute(entity_id, query.from, query.to).await {
+        Ok(diff) => {
+            let response_body = SchemaVersionDiffResponse::from(diff);
+            Ok(HttpResponse::Ok().json(ApiResponse::success(Some(response_body), None)))
+        },
+        Err(app_error) => {
+            error!("Error al comparar versiones de la entidad {}: {:?}", entity_id, app_error);
+            Ok(ErrorAdapter::map_application_error(app_error))
+        },
+    }
+}
+
+// Handler para la ruta GET /api/logical-entities/{entity_id}/versions/{version}
+#[get("/{version}")]
+async fn find_schema_version(
+    app_state: web::Data<AppState>,
+    path: web::Path<(Uuid, i32)>,
+) -> Result<HttpResponse, Error> {
+    let (entity_id, version) = path.into_inner();
+    info!("Buscando versión {} de la entidad lógica {}", version, entity_id);
+
+    match app_state.schema_version_controller_data.find_schema_version_use_case.execute(entity_id, version).await {
+        Ok(version) => {
+            let response_body = SchemaVersionResponse::from(version);
+            Ok(HttpResponse::Ok().json(ApiResponse::success(Some(response_body), None)))
+        },
+        Err(app_error) => {
+            error!("Error al buscar la versión {} de la entidad {}: {:?}", version, entity_id, app_error);
+            Ok(ErrorAdapter::map_application_error(app_error))
+        },
+    }
+}
+
+// Handler para la ruta POST /api/logical-entities/{entity_id}/versions/{version}/rollback
+#[post("/{version}/rollback")]
+async fn rollback_schema_version(
+    app_state: web::Data<AppState>,
+    path: web::Path<(Uuid, i32)>,
+) -> Result<HttpResponse, Error> {
+    let (entity_id, version) = path.into_inner();
+    info!("Restaurando la entidad lógica {} a la versión {}", entity_id, version);
+
+    match app_state.schema_version_controller_data.rollback_schema_version_use_case
+        .execute(entity_id, version, placeholder_user_id()) // <--- ¡USA EL user_id REAL AQUÍ!
+        .await
+    {
+        Ok(new_version) => {
+            info!("Entidad {} restaurada a la versión {} (nueva versión {})", entity_id, version, new_version.version);
+            let response_body = SchemaVersionResponse::from(new_version);
+            Ok(HttpResponse::Ok().json(ApiResponse::success(Some(response_body), Some("Schema version restored successfully."))))
+        },
+        Err(app_error) => {
+            error!("Error al restaurar la versión {} de la entidad {}: {:?}", version, entity_id, app_error);
+            Ok(ErrorAdapter::map_application_error(app_error))
+        },
+    }
+}
+
+// Configuración de las rutas para este controlador
+pub fn config(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("") // El prefijo (/api/logical-entities/{entity_id}/versions) se define en routes.rs
+            .service(list_schema_versions)
+            // Rutas fijas antes de /{version}
+            .service(find_schema_version_as_of)
+            .service(diff_schema_versions)
+            .service(find_schema_version)
+            .service(rollback_schema_version)
+    );
+}
@@ -0,0 +1,60 @@
+use actix_web::{web, HttpRequest, HttpResponse, delete, Error};
+use std::sync::Arc;
+use log::{info, error};
+
+use crate::Container::app_state::AppState;
+use crate::Application::use_cases::trash::PurgeTrashUseCase;
+use crate::Presentation::api::validators::validate_request;
+use crate::Presentation::api::responses::ApiResponse;
+use crate::Presentation::api::models::request::PurgeTrashQuery;
+use crate::Presentation::api::models::response::PurgeReportResponse;
+use crate::Presentation::api::adapters::ErrorAdapter;
+use super::record_controller::change_context;
+
+// Controlador para la papelera global (registros, entidades lógicas y usuarios)
+pub struct TrashController {
+    pub purge_trash_use_case: Arc<dyn PurgeTrashUseCase>,
+}
+
+impl TrashController {
+    pub fn new(purge_trash_use_case: Arc<dyn PurgeTrashUseCase>) -> Self {
+        Self { purge_trash_use_case }
+    }
+}
+
+// Handler para la ruta DELETE /api/trash?older_than_days=30
+// Sin `older_than_days` se vacía toda la papelera.
+#[delete("")]
+async fn purge_trash(
+    app_state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<PurgeTrashQuery>,
+) -> Result<HttpResponse, Error> {
+    validate_request(&query.0)?;
+
+    let older_than_days = query.older_than_days.unwrap_or(0);
+    info!("Purgando la papelera: older_than_days={}", older_than_days);
+
+    match app_state.trash_controller_data.purge_trash_use_case.execute(older_than_days, change_context(&req)).await {
+        Ok(report) => {
+            info!(
+                "Papelera purgada: {} registros, {} entidades lógicas, {} usuarios ({} omitidos)",
+                report.records, report.logical_entities, report.users, report.skipped.len()
+            );
+            let response_body = PurgeReportResponse::from(report);
+            Ok(HttpResponse::Ok().json(ApiResponse::success(Some(response_body), Some("Trash purged successfully."))))
+        },
+        Err(app_error) => {
+            error!("Error al purgar la papelera: {:?}", app_error);
+            Ok(ErrorAdapter::map_application_error(app_error))
+        },
+    }
+}
+
+// Configuración de las rutas para este controlador
+pub fn config(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("") // El prefijo se define en routes.rs
+            .service(purge_trash)
+    );
+}
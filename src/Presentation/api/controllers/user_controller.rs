@@ -1,4 +1,4 @@
-use actix_web::{web, HttpResponse, post, get, put, delete, Error};
+use actix_web::{web, HttpRequest, HttpResponse, post, get, put, delete, Error};
 use crate::Container::app_state::AppState; // Importar AppState
 use crate::Application::use_cases::{
     CreateUserUseCase, 
@@ -8,16 +8,19 @@ use crate::Application::use_cases::{
     UpdateUserUseCase, 
     DeleteUserUseCase
 };
+use crate::Application::use_cases::user::{ListDeletedUsersUseCase, RestoreUserUseCase, PurgeUserUseCase};
 use crate::Application::dtos::create_user_dto::CreateUserDto;
 use crate::Application::dtos::update_user_dto::UpdateUserDto;
 use std::sync::Arc;
 use uuid::Uuid;
 use log::{info, error};
 use crate::Presentation::api::adapters::ErrorAdapter;
-use crate::Presentation::api::validators::validate_json;
+use crate::Presentation::api::validators::{validate_json, validate_request};
 use crate::Presentation::api::responses::ApiResponse;
-use crate::Presentation::api::models::request::{CreateUserRequest, UpdateUserRequest};
-use crate::Presentation::api::models::response::UserResponse;
+use crate::Presentation::api::responses::etag::{etag_header, if_match, not_modified};
+use crate::Presentation::api::models::request::{CreateUserRequest, UpdateUserRequest, ListTrashQuery};
+use crate::Presentation::api::models::response::{UserResponse, DeletedUserResponse, TrashPageResponse};
+use super::logical_entity_controller::placeholder_user_id;
 
 // Controlador para usuarios
 pub struct UserController {
@@ -27,6 +30,9 @@ pub struct UserController {
     pub find_all_users_use_case: Arc<dyn FindAllUsersUseCase>,
     pub update_user_use_case: Arc<dyn UpdateUserUseCase>,
     pub delete_user_use_case: Arc<dyn DeleteUserUseCase>,
+    pub list_deleted_users_use_case: Arc<dyn ListDeletedUsersUseCase>,
+    pub restore_user_use_case: Arc<dyn RestoreUserUseCase>,
+    pub purge_user_use_case: Arc<dyn PurgeUserUseCase>,
 }
 
 impl UserController {
@@ -37,6 +43,9 @@ impl UserController {
         find_all_users_use_case: Arc<dyn FindAllUsersUseCase>,
         update_user_use_case: Arc<dyn UpdateUserUseCase>,
         delete_user_use_case: Arc<dyn DeleteUserUseCase>,
+        list_deleted_users_use_case: Arc<dyn ListDeletedUsersUseCase>,
+        restore_user_use_case: Arc<dyn RestoreUserUseCase>,
+        purge_user_use_case: Arc<dyn PurgeUserUseCase>,
     ) -> Self {
         UserController {
             create_user_use_case,
@@ -45,6 +54,9 @@ impl UserController {
             find_all_users_use_case,
             update_user_use_case,
             delete_user_use_case,
+            list_deleted_users_use_case,
+            restore_user_use_case,
+            purge_user_use_case,
         }
     }
 }
@@ -87,9 +99,12 @@ async fn create_user(
                 updated_by: user_dto.updated_by,
                 updated_at: user_dto.updated_at,
                 status: user_dto.status,
+                version: user_dto.version,
             };
             
-            Ok(HttpResponse::Created().json(ApiResponse::success(Some(user_response), None)))
+            Ok(HttpResponse::Created()
+                .insert_header(etag_header(user_response.version))
+                .json(ApiResponse::success(Some(user_response), None)))
         },
         Err(app_error) => {
             error!("Error al crear usuario: {:?}", app_error);
@@ -102,6 +117,7 @@ async fn create_user(
 #[get("/{id}")]
 async fn find_user_by_id(
     app_state: web::Data<AppState>, // Cambiar a AppState
+    req: HttpRequest,
     id: web::Path<Uuid>,
 ) -> Result<HttpResponse, Error> {
     let user_id = id.into_inner();
@@ -111,6 +127,9 @@ async fn find_user_by_id(
     match app_state.user_controller_data.find_user_by_id_use_case.execute(user_id).await {
         Ok(user_dto) => {
             info!("Usuario encontrado: ID={}", user_dto.id);
+            if let Some(response) = not_modified(&req, user_dto.version) {
+                return Ok(response);
+            }
             
             // Mapeo explícito de UserResponseDto a UserResponse
             let user_response = UserResponse {
@@ -124,9 +143,12 @@ async fn find_user_by_id(
                 updated_by: user_dto.updated_by,
                 updated_at: user_dto.updated_at,
                 status: user_dto.status,
+                version: user_dto.version,
             };
             
-            Ok(HttpResponse::Ok().json(ApiResponse::success(Some(user_response), None)))
+            Ok(HttpResponse::Ok()
+                .insert_header(etag_header(user_response.version))
+                .json(ApiResponse::success(Some(user_response), None)))
         },
         Err(app_error) => {
             error!("Error al buscar usuario por ID {}: {:?}", user_id, app_error);
@@ -161,6 +183,7 @@ async fn find_user_by_username(
                 updated_by: user_dto.updated_by,
                 updated_at: user_dto.updated_at,
                 status: user_dto.status,
+                version: user_dto.version,
             };
             
             Ok(HttpResponse::Ok().json(ApiResponse::success(Some(user_response), None)))
@@ -198,6 +221,7 @@ async fn find_all_users(
                     updated_by: user_dto.updated_by,
                     updated_at: user_dto.updated_at,
                     status: user_dto.status,
+                version: user_dto.version,
                 })
                 .collect();
             
@@ -214,6 +238,7 @@ async fn find_all_users(
 #[put("/{id}")]
 async fn update_user(
     app_state: web::Data<AppState>, // Cambiar a AppState
+    req: HttpRequest,
     id: web::Path<Uuid>,
     user_req: web::Json<UpdateUserRequest>,
 ) -> Result<HttpResponse, Error> {
@@ -233,7 +258,7 @@ async fn update_user(
     
     // Ejecutar caso de uso
     // Acceder al controlador específico desde AppState
-    match app_state.user_controller_data.update_user_use_case.execute(user_id, update_dto, None).await {
+    match app_state.user_controller_data.update_user_use_case.execute(user_id, update_dto, None, if_match(&req)).await {
         Ok(user_dto) => {
             info!("Usuario actualizado con éxito: ID={}", user_dto.id);
             
@@ -249,9 +274,12 @@ async fn update_user(
                 updated_by: user_dto.updated_by,
                 updated_at: user_dto.updated_at,
                 status: user_dto.status,
+                version: user_dto.version,
             };
             
-            Ok(HttpResponse::Ok().json(ApiResponse::success(Some(user_response), None)))
+            Ok(HttpResponse::Ok()
+                .insert_header(etag_header(user_response.version))
+                .json(ApiResponse::success(Some(user_response), None)))
         },
         Err(app_error) => {
             error!("Error al actualizar usuario {}: {:?}", user_id, app_error);
@@ -264,19 +292,20 @@ async fn update_user(
 #[delete("/{id}")]
 async fn delete_user(
     app_state: web::Data<AppState>, // Cambiar a AppState
+    req: HttpRequest,
     id: web::Path<Uuid>,
 ) -> Result<HttpResponse, Error> {
     let user_id = id.into_inner();
-    info!("Eliminando usuario con ID: {}", user_id);
+    info!("Moviendo a la papelera el usuario con ID: {}", user_id);
     
     // Acceder al controlador específico desde AppState
-    match app_state.user_controller_data.delete_user_use_case.execute(user_id).await {
+    match app_state.user_controller_data.delete_user_use_case.execute(user_id, placeholder_user_id(), if_match(&req)).await { // <--- ¡USA EL user_id REAL AQUÍ!
         Ok(()) => {
-            info!("Usuario eliminado con éxito: ID={}", user_id);
+            info!("Usuario movido a la papelera: ID={}", user_id);
             
             // Para mantener el patrón consistente, devolvemos un ApiResponse
             // con success: true pero data: None (porque el delete no devuelve datos)
-            Ok(HttpResponse::Ok().json(ApiResponse::<()>::success(None, Some("Usuario eliminado con éxito"))))
+            Ok(HttpResponse::Ok().json(ApiResponse::<()>::success(None, Some("Usuario movido a la papelera"))))
         },
         Err(app_error) => {
             error!("Error al eliminar usuario {}: {:?}", user_id, app_error);
@@ -285,12 +314,100 @@ async fn delete_user(
     }
 }
 
+// Handler para la ruta GET /api/users/trash?page=1&page_size=20
+#[get("/trash")]
+async fn list_deleted_users(
+    app_state: web::Data<AppState>,
+    query: web::Query<ListTrashQuery>,
+) -> Result<HttpResponse, Error> {
+    validate_request(&query.0)?;
+
+    let page = query.page.unwrap_or(1);
+    let page_size = query.page_size.unwrap_or(20);
+    info!("Listando la papelera de usuarios: page={}, page_size={}", page, page_size);
+
+    match app_state.user_controller_data.list_deleted_users_use_case.execute(page, page_size).await {
+        Ok(page_dto) => {
+            info!("La papelera tiene {} usuarios", page_dto.total);
+            let response_body: TrashPageResponse<DeletedUserResponse> = TrashPageResponse::from(page_dto);
+            Ok(HttpResponse::Ok().json(ApiResponse::success(Some(response_body), None)))
+        },
+        Err(app_error) => {
+            error!("Error al listar la papelera de usuarios: {:?}", app_error);
+            Ok(ErrorAdapter::map_application_error(app_error))
+        },
+    }
+}
+
+// Handler para la ruta POST /api/users/trash/{id}/restore
+#[post("/trash/{id}/restore")]
+async fn restore_user(
+    app_state: web::Data<AppState>,
+    id: web::Path<Uuid>,
+) -> Result<HttpResponse, Error> {
+    let user_id = id.into_inner();
+    info!("Restaurando usuario con ID: {}", user_id);
+
+    match app_state.user_controller_data.restore_user_use_case.execute(user_id).await {
+        Ok(user_dto) => {
+            info!("Usuario restaurado con éxito: ID={}", user_dto.id);
+
+            // Mapeo explícito de UserResponseDto a UserResponse
+            let user_response = UserResponse {
+                id: user_dto.id,
+                username: user_dto.username,
+                first_name: user_dto.first_name,
+                last_name: user_dto.last_name,
+                email: user_dto.email,
+                created_by: user_dto.created_by,
+                created_at: user_dto.created_at,
+                updated_by: user_dto.updated_by,
+                updated_at: user_dto.updated_at,
+                status: user_dto.status,
+                version: user_dto.version,
+            };
+
+            Ok(HttpResponse::Ok()
+                .insert_header(etag_header(user_response.version))
+                .json(ApiResponse::success(Some(user_response), Some("Usuario restaurado con éxito"))))
+        },
+        Err(app_error) => {
+            error!("Error al restaurar usuario {}: {:?}", user_id, app_error);
+            Ok(ErrorAdapter::map_application_error(app_error))
+        },
+    }
+}
+
+// Handler para la ruta DELETE /api/users/trash/{id}
+#[delete("/trash/{id}")]
+async fn purge_user(
+    app_state: web::Data<AppState>,
+    id: web::Path<Uuid>,
+) -> Result<HttpResponse, Error> {
+    let user_id = id.into_inner();
+    info!("Purgando usuario con ID: {}", user_id);
+
+    match app_state.user_controller_data.purge_user_use_case.execute(user_id).await {
+        Ok(()) => {
+            info!("Usuario purgado con éxito: ID={}", user_id);
+            Ok(HttpResponse::Ok().json(ApiResponse::<()>::success(None, Some("Usuario eliminado definitivamente"))))
+        },
+        Err(app_error) => {
+            error!("Error al purgar usuario {}: {:?}", user_id, app_error);
+            Ok(ErrorAdapter::map_application_error(app_error))
+        },
+    }
+}
+
 // Configuración de las rutas
 pub fn config(cfg: &mut web::ServiceConfig) {
     cfg.service(
         web::scope("")
             .service(create_user)
             .service(find_all_users)
+            .service(list_deleted_users) // Antes de /{id}: "trash" no es un {id}
+            .service(restore_user)
+            .service(purge_user)
             .service(find_user_by_id)
             .service(update_user)
             .service(delete_user)
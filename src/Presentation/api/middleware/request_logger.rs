@@ -1,14 +1,44 @@
 // Este middleware registra todas las peticiones entrantes y las respuestas salientes.
 // Registra el método, la ruta, las cabeceras, la información de conexión, el código de estado y el tiempo transcurrido.
+// También asigna a cada petición un identificador (cabecera X-Request-Id: el recibido o
+// uno nuevo), que se devuelve en la respuesta y se guarda en el historial de registros.
 use actix_web::{
     dev::{forward_ready, Service, ServiceRequest, ServiceResponse, Transform},
-    Error,
+    http::header::{HeaderName, HeaderValue},
+    Error, HttpMessage, HttpRequest,
 };
 use futures::future::{ok, LocalBoxFuture, Ready};
 use log::{info, debug};
 // use std::future::Future;
 //use std::pin::Pin;
 use std::time::Instant;
+use uuid::Uuid;
+
+/// Cabecera con el identificador de la petición.
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// Longitud máxima aceptada del X-Request-Id recibido.
+const MAX_REQUEST_ID_LENGTH: usize = 128;
+
+/// Identificador de la petición, guardado en las extensiones de la petición.
+#[derive(Debug, Clone)]
+pub struct RequestId(pub String);
+
+/// Identificador de la petición asignado por `RequestLoggerMiddleware`.
+pub fn request_id(req: &HttpRequest) -> Option<String> {
+    req.extensions().get::<RequestId>().map(|id| id.0.clone())
+}
+
+/// X-Request-Id recibido si es válido (ASCII visible, longitud acotada); si no, uno nuevo.
+fn incoming_or_new_request_id(req: &ServiceRequest) -> String {
+    req.headers()
+        .get(REQUEST_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .map(str::trim)
+        .filter(|id| !id.is_empty() && id.len() <= MAX_REQUEST_ID_LENGTH && id.chars().all(|c| c.is_ascii_graphic()))
+        .map(str::to_owned)
+        .unwrap_or_else(|| Uuid::new_v4().to_string())
+}
 
 // Estructura para el middleware
 pub struct RequestLoggerMiddleware;
@@ -52,15 +82,20 @@ where
         let headers = req.headers().clone();
         let connection_info = req.connection_info().clone();
         let start_time = Instant::now();
+        let request_id = incoming_or_new_request_id(&req);
+        req.extensions_mut().insert(RequestId(request_id.clone()));
 
-        debug!("Request: {} {} - Headers: {:?} - Connection Info: {:?}", method, path, headers, connection_info);
+        debug!("Request [{}]: {} {} - Headers: {:?} - Connection Info: {:?}", request_id, method, path, headers, connection_info);
 
         let fut = self.service.call(req);
 
         Box::pin(async move {
-            let res = fut.await?;
+            let mut res = fut.await?;
             let elapsed_time = start_time.elapsed();
-            info!("Response: {} {} - {} - Time: {:?}", method, path, res.status(), elapsed_time);
+            info!("Response [{}]: {} {} - {} - Time: {:?}", request_id, method, path, res.status(), elapsed_time);
+            if let Ok(value) = HeaderValue::from_str(&request_id) {
+                res.headers_mut().insert(HeaderName::from_static(REQUEST_ID_HEADER), value);
+            }
             Ok(res)
         })
     }
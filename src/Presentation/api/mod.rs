@@ -6,4 +6,5 @@ pub mod routes;
 pub mod validators;
 pub mod responses;
 pub mod adapters;
-pub mod models;
\ No newline at end of file
+pub mod models;
+pub mod openapi;
\ No newline at end of file
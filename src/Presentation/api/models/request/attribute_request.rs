@@ -0,0 +1,142 @@
+use serde::{Deserialize, Deserializer, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::Domain::attribute_references::ReferenceDeleteAction;
+
+// --- Alta de atributo (POST /api/logical-entities/{id}/attributes) ---
+#[derive(Deserialize, Validate, Debug, Clone)]
+pub struct AddAttributeRequest {
+    #[validate(length(min = 1, max = 100, message = "Attribute name must be between 1 and 100 characters"))]
+    pub name: String,
+    pub description: Option<String>,
+    #[validate(length(min = 1, message = "data_type cannot be empty"))]
+    pub data_type: String,
+    #[validate(range(min = 0, max = 100, message = "Position must be between 0 and 100"))]
+    #[serde(default)]
+    pub position: i16,
+    #[serde(default)]
+    pub is_required: bool,
+    #[validate(range(min = 0, max = 10, message = "is_unique must be between 0 and 10"))]
+    pub is_unique: Option<i16>,
+    pub default_value: Option<String>,
+    pub validation_regex: Option<String>,
+    /// Enumeraciones: añade a la vista la columna "<nombre>_label".
+    #[serde(default)]
+    pub show_option_label: bool,
+    /// Atributos string/text: los incluye en la búsqueda de texto de la entidad.
+    #[serde(default)]
+    pub is_searchable: bool,
+    /// Enumeraciones: lista de opciones inicial.
+    #[validate]
+    #[serde(default)]
+    pub options: Vec<AttributeOptionRequest>,
+    /// Referencias: entidad referenciada (obligatorio en los tipos de referencia).
+    #[validate]
+    pub reference: Option<AttributeReferenceRequest>,
+}
+
+// --- Configuración de un atributo de tipo referencia ---
+// `on_delete`: restrict (por defecto), cascade o set_null.
+// `display_attribute`: atributo de la entidad referenciada que la vista muestra como "<nombre>_display".
+#[derive(Deserialize, Serialize, Validate, Debug, Clone)]
+pub struct AttributeReferenceRequest {
+    pub entity_id: Uuid,
+    #[serde(default)]
+    pub on_delete: ReferenceDeleteAction,
+    #[validate(length(min = 1, max = 100, message = "display_attribute must be between 1 and 100 characters"))]
+    pub display_attribute: Option<String>,
+}
+
+// --- Opción de un atributo de tipo enumeración ---
+// Sin `sort_order` se usa la posición en la lista.
+#[derive(Deserialize, Validate, Debug, Clone)]
+pub struct AttributeOptionRequest {
+    #[validate(length(min = 1, max = 100, message = "Option code must be between 1 and 100 characters"))]
+    pub code: String,
+    #[validate(length(min = 1, max = 200, message = "Option label must be between 1 and 200 characters"))]
+    pub label: String,
+    pub sort_order: Option<i32>,
+    #[serde(default = "default_true")]
+    pub is_active: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+// --- Lista de opciones (PUT /api/logical-entities/{id}/attributes/{attribute_id}/options) ---
+// Sustituye la lista completa: las opciones que no aparecen se eliminan.
+#[derive(Deserialize, Validate, Debug, Clone)]
+pub struct ReplaceAttributeOptionsRequest {
+    #[validate]
+    pub options: Vec<AttributeOptionRequest>,
+}
+
+// --- Cambios sobre un atributo (PATCH /api/logical-entities/{id}/attributes/{attribute_id}) ---
+// En los campos anulables, ausente = no se modifica y null = se elimina el valor.
+#[derive(Deserialize, Validate, Debug, Clone)]
+pub struct UpdateAttributeRequest {
+    #[validate(length(min = 1, max = 100, message = "Attribute name must be between 1 and 100 characters"))]
+    pub name: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_nullable")]
+    pub description: Option<Option<String>>,
+    pub is_required: Option<bool>,
+    #[serde(default, deserialize_with = "deserialize_nullable")]
+    pub is_unique: Option<Option<i16>>,
+    #[serde(default, deserialize_with = "deserialize_nullable")]
+    pub default_value: Option<Option<String>>,
+    #[serde(default, deserialize_with = "deserialize_nullable")]
+    pub validation_regex: Option<Option<String>>,
+    pub show_option_label: Option<bool>,
+    pub is_searchable: Option<bool>,
+    /// Referencias: restrict, cascade o set_null.
+    pub reference_on_delete: Option<ReferenceDeleteAction>,
+    /// Referencias: atributo a mostrar de la entidad referenciada (null = ninguno).
+    #[serde(default, deserialize_with = "deserialize_nullable")]
+    pub reference_display_attribute: Option<Option<String>>,
+    /// 1 activo, 0 retirado.
+    #[validate(range(min = 0, max = 1, message = "status must be 0 or 1"))]
+    pub status: Option<i16>,
+}
+
+// --- Reordenación (PUT /api/logical-entities/{id}/attributes/order) ---
+#[derive(Deserialize, Serialize, Validate, Debug, Clone)]
+pub struct AttributePositionRequest {
+    pub attribute_id: Uuid,
+    #[validate(range(min = 0, max = 100, message = "Position must be between 0 and 100"))]
+    pub position: i16,
+}
+
+#[derive(Deserialize, Validate, Debug, Clone)]
+pub struct ReorderAttributesRequest {
+    #[validate]
+    #[validate(length(min = 1, message = "At least one position must be provided"))]
+    pub positions: Vec<AttributePositionRequest>,
+}
+
+// --- Cambio de tipo (PUT /api/logical-entities/{id}/attributes/{attribute_id}/data-type) ---
+#[derive(Deserialize, Validate, Debug, Clone)]
+pub struct ChangeAttributeDataTypeRequest {
+    #[validate(length(min = 1, message = "data_type cannot be empty"))]
+    pub data_type: String,
+    /// Solo informa del resultado de la conversión.
+    #[serde(default)]
+    pub dry_run: bool,
+    /// Elimina los valores no convertibles en lugar de abortar el cambio.
+    #[serde(default)]
+    pub discard_failures: bool,
+    /// Lista de opciones si el nuevo tipo es una enumeración.
+    #[validate]
+    #[serde(default)]
+    pub options: Vec<AttributeOptionRequest>,
+}
+
+/// Distingue un campo ausente (None) de un campo con null (Some(None)).
+pub(super) fn deserialize_nullable<'de, D, T>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
+}
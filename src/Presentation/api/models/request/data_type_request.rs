@@ -0,0 +1,64 @@
+use serde::Deserialize;
+use validator::Validate;
+
+use crate::Domain::data_types::DataTypeKind;
+use crate::Domain::records::StorageColumn;
+use super::attribute_request::deserialize_nullable;
+
+// --- Listado (GET /api/data-types?include_inactive=true) ---
+#[derive(Deserialize, Debug, Clone, Default)]
+pub struct ListDataTypesQuery {
+    #[serde(default)]
+    pub include_inactive: bool,
+}
+
+// --- Alta de tipo de dato (POST /api/data-types) ---
+// `storage_column` es la columna de attribute_values: string_value, integer_value, ...
+// `kind`: scalar (por defecto), enumeration (valores restringidos a las opciones del atributo)
+// o reference (IDs de registros de otra entidad, en uuid_value).
+#[derive(Deserialize, Validate, Debug, Clone)]
+pub struct CreateDataTypeRequest {
+    #[validate(length(min = 1, max = 100, message = "Data type name must be between 1 and 100 characters"))]
+    pub name: String,
+    pub description: Option<String>,
+    pub storage_column: StorageColumn,
+    #[validate(length(min = 1, max = 100, message = "pg_cast must be between 1 and 100 characters"))]
+    pub pg_cast: Option<String>,
+    #[validate(range(min = 1, message = "max_length must be greater than 0"))]
+    pub max_length: Option<i32>,
+    #[validate(range(min = 1, max = 1000, message = "numeric_precision must be between 1 and 1000"))]
+    pub numeric_precision: Option<i16>,
+    #[validate(range(min = 0, max = 1000, message = "numeric_scale must be between 0 and 1000"))]
+    pub numeric_scale: Option<i16>,
+    pub min_value: Option<f64>,
+    pub max_value: Option<f64>,
+    #[serde(default)]
+    pub kind: DataTypeKind,
+}
+
+// --- Cambios sobre un tipo de dato (PATCH /api/data-types/{id}) ---
+// En los campos anulables, ausente = no se modifica y null = se elimina el valor.
+#[derive(Deserialize, Validate, Debug, Clone)]
+pub struct UpdateDataTypeRequest {
+    #[validate(length(min = 1, max = 100, message = "Data type name must be between 1 and 100 characters"))]
+    pub name: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_nullable")]
+    pub description: Option<Option<String>>,
+    pub storage_column: Option<StorageColumn>,
+    #[validate(length(min = 1, max = 100, message = "pg_cast must be between 1 and 100 characters"))]
+    pub pg_cast: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_nullable")]
+    pub max_length: Option<Option<i32>>,
+    #[serde(default, deserialize_with = "deserialize_nullable")]
+    pub numeric_precision: Option<Option<i16>>,
+    #[serde(default, deserialize_with = "deserialize_nullable")]
+    pub numeric_scale: Option<Option<i16>>,
+    #[serde(default, deserialize_with = "deserialize_nullable")]
+    pub min_value: Option<Option<f64>>,
+    #[serde(default, deserialize_with = "deserialize_nullable")]
+    pub max_value: Option<Option<f64>>,
+    pub kind: Option<DataTypeKind>,
+    /// 1 activo, 0 inactivo.
+    #[validate(range(min = 0, max = 1, message = "status must be 0 or 1"))]
+    pub status: Option<i16>,
+}
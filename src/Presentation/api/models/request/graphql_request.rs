@@ -0,0 +1,14 @@
+use serde::Deserialize;
+use serde_json::{Map, Value};
+use validator::Validate;
+
+// --- Operación GraphQL (POST /graphql) ---
+// Cuerpo estándar de GraphQL sobre HTTP: { "query", "operationName", "variables" }.
+#[derive(Deserialize, Validate, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GraphQLRequest {
+    #[validate(length(min = 1, message = "query must not be empty"))]
+    pub query: String,
+    pub operation_name: Option<String>,
+    pub variables: Option<Map<String, Value>>,
+}
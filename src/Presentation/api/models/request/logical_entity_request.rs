@@ -1,10 +1,15 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use validator::Validate;
 use serde::{de, Deserializer}; // Necesario para helpers
 use std::str::FromStr; // Necesario para helpers
 
+use super::attribute_request::AttributeReferenceRequest;
+use crate::Domain::physical_tables::StorageMode;
+use crate::Domain::views::ViewPivotStrategy;
+
 // Estructura para definir un atributo en el request
-#[derive(Deserialize, Validate, Debug, Clone)]
+// Serialize: validator lo exige para la validación de longitud de la lista que lo contiene
+#[derive(Deserialize, Serialize, Validate, Debug, Clone)]
 pub struct AttributeDefinitionRequest {
     #[validate(length(min = 1, max = 100, message = "Attribute name must be between 1 and 100 characters"))]
     pub name: String,
@@ -26,6 +31,14 @@ pub struct AttributeDefinitionRequest {
 
     pub default_value: Option<String>,
     pub validation_regex: Option<String>,
+
+    /// Incluye el atributo (string/text) en la búsqueda de texto de la entidad.
+    #[serde(default, deserialize_with = "deserialize_string_to_bool")]
+    pub is_searchable: bool,
+
+    /// Solo en atributos de tipo referencia.
+    #[validate]
+    pub reference: Option<AttributeReferenceRequest>,
 }
 
 // Estructura principal del request (sin cambios aquí)
@@ -38,6 +51,10 @@ pub struct AttributeDefinitionRequest {
     #[validate]
     #[validate(length(min = 1, message = "At least one attribute must be provided"))]
     pub attributes: Vec<AttributeDefinitionRequest>,
+
+    /// Genera la vista de la entidad (view_<nombre>) y la registra en assign_view.
+    #[serde(rename = "AssignView", default)]
+    pub assign_view: bool,
 }
 
 // --- Funciones Helper para Deserialización Flexible (sin cambios aquí) ---
@@ -94,3 +111,46 @@ where
 }
 
 
+
+// --- Actualización de la entidad lógica (PUT /api/logical-entities/{id}) ---
+#[derive(Deserialize, Validate, Debug, Clone)]
+pub struct UpdateLogicalEntityRequest {
+    #[validate(length(min = 1, max = 100, message = "Entity name must be between 1 and 100 characters"))]
+    pub name: Option<String>,
+    pub description: Option<String>,
+    /// true crea/regenera la vista de la entidad, false la elimina.
+    pub assign_view: Option<bool>,
+    /// Configuración de búsqueda de texto de Postgres (simple, english, spanish...).
+    #[validate(length(min = 1, max = 63, message = "search_language must be between 1 and 63 characters"))]
+    pub search_language: Option<String>,
+    #[validate(range(min = 0, max = 1, message = "status must be 0 or 1"))]
+    pub status: Option<i16>,
+}
+
+// --- Parámetros de paginación (GET /api/logical-entities) ---
+#[derive(Deserialize, Validate, Debug, Clone)]
+pub struct ListLogicalEntitiesQuery {
+    #[validate(range(min = 1, message = "page must be greater than or equal to 1"))]
+    pub page: Option<i64>,
+    #[validate(range(min = 1, max = 100, message = "page_size must be between 1 and 100"))]
+    pub page_size: Option<i64>,
+}
+
+// --- Opciones de la vista (PUT /api/logical-entities/{id}/view/settings) ---
+#[derive(Deserialize, Validate, Debug, Clone)]
+pub struct UpdateEntityViewSettingsRequest {
+    /// true genera una vista materializada en lugar de una vista normal.
+    pub materialized: bool,
+    /// Refresco programado cada N segundos (solo vistas materializadas).
+    #[validate(range(min = 1, max = 604800, message = "refresh_interval_secs must be between 1 and 604800"))]
+    pub refresh_interval_secs: Option<i32>,
+    /// joins o aggregate; sin valor, automática según el número de atributos.
+    pub pivot_strategy: Option<ViewPivotStrategy>,
+}
+
+// --- Modo de almacenamiento (PUT /api/logical-entities/{id}/storage) ---
+#[derive(Deserialize, Validate, Debug, Clone)]
+pub struct ChangeStorageModeRequest {
+    /// eav o physical
+    pub storage_mode: StorageMode,
+}
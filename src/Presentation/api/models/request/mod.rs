@@ -2,8 +2,30 @@ pub mod create_user_request;
 pub mod update_user_request;
 pub mod login_request;
 pub mod logical_entity_request;
+pub mod record_request;
+pub mod attribute_request;
+pub mod schema_version_request;
+pub mod data_type_request;
+pub mod schema_bundle_request;
+pub mod graphql_request;
+pub mod trash_request;
+pub mod record_history_request;
 
 pub use create_user_request::CreateUserRequest;
 pub use update_user_request::UpdateUserRequest;
 pub use login_request::LoginRequest;
-pub use logical_entity_request::CreateEntityWithAttributesRequest;
+pub use logical_entity_request::{
+    CreateEntityWithAttributesRequest, UpdateLogicalEntityRequest, ListLogicalEntitiesQuery, UpdateEntityViewSettingsRequest,
+    ChangeStorageModeRequest,
+};
+pub use record_request::{ListRecordsQuery, SearchRecordsQuery, ImportRecordsQuery, ExportRecordsQuery};
+pub use attribute_request::{
+    AddAttributeRequest, UpdateAttributeRequest, ReorderAttributesRequest, ChangeAttributeDataTypeRequest,
+    AttributeOptionRequest, ReplaceAttributeOptionsRequest, AttributeReferenceRequest,
+};
+pub use schema_version_request::{SchemaVersionDiffQuery, SchemaVersionAsOfQuery};
+pub use data_type_request::{ListDataTypesQuery, CreateDataTypeRequest, UpdateDataTypeRequest};
+pub use schema_bundle_request::{ExportSchemaBundleQuery, ImportSchemaBundleQuery, PreviewEntitySchemaQuery};
+pub use graphql_request::GraphQLRequest;
+pub use trash_request::{ListTrashQuery, PurgeTrashQuery};
+pub use record_history_request::{RecordHistoryQuery, RecordAsOfQuery};
@@ -0,0 +1,20 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use validator::Validate;
+
+// --- Historial de un registro (GET /api/entities/{entity_name}/records/{id}/history?limit=50&before=<cursor>) ---
+// `before` es el `next_before` de la página anterior.
+#[derive(Deserialize, Validate, Debug, Clone)]
+pub struct RecordHistoryQuery {
+    #[validate(range(min = 1, max = 100, message = "limit must be between 1 and 100"))]
+    pub limit: Option<i64>,
+    #[validate(range(min = 1, message = "before must be a positive history entry id"))]
+    pub before: Option<i64>,
+}
+
+// --- Registro en una fecha (GET /api/entities/{entity_name}/records/{id}/as-of?at=...) ---
+#[derive(Deserialize, Debug, Clone)]
+pub struct RecordAsOfQuery {
+    /// Fecha y hora en RFC 3339.
+    pub at: DateTime<Utc>,
+}
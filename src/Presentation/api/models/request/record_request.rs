@@ -0,0 +1,57 @@
+use serde::Deserialize;
+use validator::Validate;
+
+// El cuerpo de POST/PUT/PATCH es un objeto JSON plano {nombre_atributo: valor},
+// por lo que se recibe directamente como serde_json::Map en el controlador.
+
+// --- Parámetros del listado (GET /api/entities/{entity_name}/records) ---
+// ?filter=status eq 'open' and amount gt 100&sort=-created_at&limit=50&after=<cursor>
+#[derive(Deserialize, Validate, Debug, Clone)]
+pub struct ListRecordsQuery {
+    #[validate(length(max = 4000, message = "filter must be at most 4000 characters"))]
+    pub filter: Option<String>,
+    #[validate(length(max = 500, message = "sort must be at most 500 characters"))]
+    pub sort: Option<String>,
+    #[validate(range(min = 1, max = 100, message = "limit must be between 1 and 100"))]
+    pub limit: Option<i64>,
+    pub after: Option<String>,
+}
+
+// --- Búsqueda de texto (GET /api/entities/{entity_name}/records/search) ---
+#[derive(Deserialize, Validate, Debug, Clone)]
+pub struct SearchRecordsQuery {
+    #[validate(length(min = 1, max = 500, message = "q must be between 1 and 500 characters"))]
+    pub q: String,
+    #[validate(range(min = 1, max = 100, message = "limit must be between 1 and 100"))]
+    pub limit: Option<i64>,
+    pub after: Option<String>,
+}
+
+// --- Importación masiva (POST /api/entities/{entity_name}/records/import) ---
+// El cuerpo es el contenido del archivo (CSV o NDJSON en UTF-8).
+// ?format=csv&delimiter=;&dry_run=true&on_error=skip_invalid&mapping={"Full Name":"name"}
+// `mapping` es un objeto JSON columna -> atributo ("" ignora la columna).
+#[derive(Deserialize, Validate, Debug, Clone)]
+pub struct ImportRecordsQuery {
+    #[validate(length(min = 1, max = 10, message = "format must be between 1 and 10 characters"))]
+    pub format: String,
+    #[validate(length(equal = 1, message = "delimiter must be a single character"))]
+    pub delimiter: Option<String>,
+    pub dry_run: Option<bool>,
+    #[validate(length(min = 1, max = 20, message = "on_error must be between 1 and 20 characters"))]
+    pub on_error: Option<String>,
+    #[validate(length(max = 10000, message = "mapping must be at most 10000 characters"))]
+    pub mapping: Option<String>,
+}
+
+// --- Exportación (GET /api/entities/{entity_name}/records/export) ---
+// ?format=csv|ndjson|json&filter=...&sort=... (filtro y orden como en el listado)
+#[derive(Deserialize, Validate, Debug, Clone)]
+pub struct ExportRecordsQuery {
+    #[validate(length(min = 1, max = 10, message = "format must be between 1 and 10 characters"))]
+    pub format: Option<String>,
+    #[validate(length(max = 4000, message = "filter must be at most 4000 characters"))]
+    pub filter: Option<String>,
+    #[validate(length(max = 500, message = "sort must be at most 500 characters"))]
+    pub sort: Option<String>,
+}
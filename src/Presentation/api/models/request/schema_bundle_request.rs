@@ -0,0 +1,30 @@
+use serde::Deserialize;
+use validator::Validate;
+
+// --- Exportación (GET /api/schema-bundles/export?entities=customer,invoice&format=yaml) ---
+// Sin `entities` se exportan todas las entidades.
+#[derive(Deserialize, Validate, Debug, Clone)]
+pub struct ExportSchemaBundleQuery {
+    #[validate(length(max = 4000, message = "entities must be at most 4000 characters"))]
+    pub entities: Option<String>,
+    #[validate(length(min = 1, max = 10, message = "format must be between 1 and 10 characters"))]
+    pub format: Option<String>,
+}
+
+// --- Importación (POST /api/schema-bundles/import?format=yaml&dry_run=true&prune=false) ---
+// El cuerpo es el bundle (JSON o YAML). Sin `format` se deduce del Content-Type.
+#[derive(Deserialize, Validate, Debug, Clone)]
+pub struct ImportSchemaBundleQuery {
+    #[validate(length(min = 1, max = 10, message = "format must be between 1 and 10 characters"))]
+    pub format: Option<String>,
+    pub dry_run: Option<bool>,
+    pub prune: Option<bool>,
+}
+
+// --- Vista previa de una entidad (POST /api/schema-bundles/preview?format=yaml) ---
+// El cuerpo es una entidad del bundle (JSON o YAML). Sin `format` se deduce del Content-Type.
+#[derive(Deserialize, Validate, Debug, Clone)]
+pub struct PreviewEntitySchemaQuery {
+    #[validate(length(min = 1, max = 10, message = "format must be between 1 and 10 characters"))]
+    pub format: Option<String>,
+}
@@ -0,0 +1,19 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use validator::Validate;
+
+// --- Comparación de versiones (GET /api/logical-entities/{id}/versions/diff?from=1&to=3) ---
+#[derive(Deserialize, Validate, Debug, Clone)]
+pub struct SchemaVersionDiffQuery {
+    #[validate(range(min = 1, message = "from must be greater than or equal to 1"))]
+    pub from: i32,
+    #[validate(range(min = 1, message = "to must be greater than or equal to 1"))]
+    pub to: i32,
+}
+
+// --- Versión vigente en una fecha (GET /api/logical-entities/{id}/versions/as-of?at=...) ---
+#[derive(Deserialize, Debug, Clone)]
+pub struct SchemaVersionAsOfQuery {
+    /// Fecha y hora en RFC 3339.
+    pub at: DateTime<Utc>,
+}
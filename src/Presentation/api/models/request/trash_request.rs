@@ -0,0 +1,19 @@
+use serde::Deserialize;
+use validator::Validate;
+
+// --- Listado de la papelera (GET .../trash?page=1&page_size=20) ---
+#[derive(Deserialize, Validate, Debug, Clone)]
+pub struct ListTrashQuery {
+    #[validate(range(min = 1, message = "page must be greater than or equal to 1"))]
+    pub page: Option<i64>,
+    #[validate(range(min = 1, max = 100, message = "page_size must be between 1 and 100"))]
+    pub page_size: Option<i64>,
+}
+
+// --- Purga por antigüedad (DELETE /api/trash?older_than_days=30) ---
+// Sin `older_than_days` se purga toda la papelera.
+#[derive(Deserialize, Validate, Debug, Clone)]
+pub struct PurgeTrashQuery {
+    #[validate(range(min = 0, max = 3650, message = "older_than_days must be between 0 and 3650"))]
+    pub older_than_days: Option<i64>,
+}
@@ -0,0 +1,56 @@
+use serde::Serialize;
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::Application::dtos::attribute_dto::{ConversionFailureDto, DataTypeChangeReportDto};
+use super::logical_entity_response::AttributeResponse;
+
+/// Atributos de una entidad ordenados por posición.
+#[derive(Serialize, Debug)]
+pub struct AttributeListResponse {
+    pub entity_id: Uuid,
+    pub attributes: Vec<AttributeResponse>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct ConversionFailureResponse {
+    pub record_id: Uuid,
+    pub value: Value,
+    pub error: String,
+}
+
+/// Informe del cambio de tipo de dato de un atributo. `failed` es una muestra de los
+/// valores que no se pueden convertir; `failed_count` es el total.
+#[derive(Serialize, Debug)]
+pub struct DataTypeChangeResponse {
+    pub attribute_id: Uuid,
+    pub from_data_type: String,
+    pub to_data_type: String,
+    pub applied: bool,
+    pub converted: usize,
+    pub discarded: usize,
+    pub failed_count: usize,
+    pub failed: Vec<ConversionFailureResponse>,
+}
+
+// --- Mapeos explícitos DTO -> Response ---
+impl From<ConversionFailureDto> for ConversionFailureResponse {
+    fn from(dto: ConversionFailureDto) -> Self {
+        Self { record_id: dto.record_id, value: dto.value, error: dto.error }
+    }
+}
+
+impl From<DataTypeChangeReportDto> for DataTypeChangeResponse {
+    fn from(dto: DataTypeChangeReportDto) -> Self {
+        Self {
+            attribute_id: dto.attribute_id,
+            from_data_type: dto.from_data_type,
+            to_data_type: dto.to_data_type,
+            applied: dto.applied,
+            converted: dto.converted,
+            discarded: dto.discarded,
+            failed_count: dto.failed_count,
+            failed: dto.failed.into_iter().map(ConversionFailureResponse::from).collect(),
+        }
+    }
+}
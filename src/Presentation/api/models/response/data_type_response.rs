@@ -0,0 +1,57 @@
+use serde::Serialize;
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+
+use crate::Domain::data_types::{DataType, DataTypeKind};
+use crate::Domain::records::StorageColumn;
+
+/// Tipo de dato del registro con su definición de almacenamiento.
+#[derive(Serialize, Debug)]
+pub struct DataTypeResponse {
+    pub id: Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    pub storage_column: StorageColumn,
+    pub pg_cast: String,
+    pub max_length: Option<i32>,
+    pub numeric_precision: Option<i16>,
+    pub numeric_scale: Option<i16>,
+    pub min_value: Option<f64>,
+    pub max_value: Option<f64>,
+    pub kind: DataTypeKind,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_by: Option<Uuid>,
+    pub updated_at: Option<DateTime<Utc>>,
+    pub status: i16,
+}
+
+#[derive(Serialize, Debug)]
+pub struct DataTypeListResponse {
+    pub data_types: Vec<DataTypeResponse>,
+}
+
+// --- Mapeo explícito Domain -> Response ---
+impl From<DataType> for DataTypeResponse {
+    fn from(data_type: DataType) -> Self {
+        let storage = data_type.storage;
+        Self {
+            id: data_type.id,
+            name: data_type.name,
+            description: data_type.description,
+            storage_column: storage.column,
+            pg_cast: storage.pg_cast,
+            max_length: storage.params.max_length,
+            numeric_precision: storage.params.numeric_precision,
+            numeric_scale: storage.params.numeric_scale,
+            min_value: storage.params.min_value,
+            max_value: storage.params.max_value,
+            kind: storage.kind,
+            created_by: data_type.created_by,
+            created_at: data_type.created_at,
+            updated_by: data_type.updated_by,
+            updated_at: data_type.updated_at,
+            status: data_type.status,
+        }
+    }
+}
@@ -2,12 +2,22 @@ use serde::Serialize;
 use uuid::Uuid;
 use chrono::{DateTime, Utc}; // O los tipos que devuelva tu caso de uso/DTO
 
+use crate::Application::dtos::logical_entity_dto::{LogicalEntityDetailsDto, LogicalEntityPageDto};
+use crate::Application::ports::driven::repositories::{LogicalEntityDto, AttributeDto};
+use crate::Application::use_cases::logical_entities::{EntityViewStatusDto, StorageModeChangeDto};
+use crate::Domain::physical_tables::StorageMode;
+use crate::Domain::views::{ViewPivotStrategy, ViewRefreshStatus};
+use crate::Domain::attribute_options::AttributeOption;
+use crate::Domain::attribute_references::AttributeReference;
+
 #[derive(Serialize, Debug)]
 pub struct LogicalEntityResponse {
     pub id: Uuid,
     pub name: String,
     pub description: Option<String>,
     pub assign_view: Option<String>,
+    pub search_language: String,
+    pub storage_mode: StorageMode,
     pub created_by: Option<Uuid>, // Asumiendo que el caso de uso devuelve esto
     pub created_at: DateTime<Utc>, // Asumiendo que el caso de uso devuelve esto
     pub updated_by: Option<Uuid>, // Asumiendo que el caso de uso devuelve esto
@@ -20,3 +30,161 @@ pub struct LogicalEntityResponse {
 pub struct CreateLogicalEntityResponse {
     pub id: Uuid,
 }
+
+#[derive(Serialize, Debug)]
+pub struct AttributeResponse {
+    pub id: Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    pub data_type_id: Uuid,
+    pub data_type_name: String,
+    pub position: i16,
+    pub is_required: bool,
+    pub is_unique: Option<i16>,
+    pub default_value: Option<String>,
+    pub validation_regex: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub options: Vec<AttributeOption>,
+    pub show_option_label: bool,
+    pub is_searchable: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reference: Option<AttributeReference>,
+    pub status: i16,
+}
+
+/// Entidad lógica con sus atributos.
+#[derive(Serialize, Debug)]
+pub struct LogicalEntityDetailsResponse {
+    #[serde(flatten)]
+    pub entity: LogicalEntityResponse,
+    pub attributes: Vec<AttributeResponse>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct LogicalEntityPageResponse {
+    pub items: Vec<LogicalEntityDetailsResponse>,
+    pub page: i64,
+    pub page_size: i64,
+    pub total: i64,
+}
+
+/// Vista regenerada de una entidad lógica.
+#[derive(Serialize, Debug)]
+pub struct EntityViewResponse {
+    pub entity_id: Uuid,
+    pub view_name: String,
+}
+
+/// Opciones de la vista de una entidad lógica y estado de su último refresco
+/// (solo vistas materializadas).
+#[derive(Serialize, Debug)]
+pub struct EntityViewStatusResponse {
+    pub entity_id: Uuid,
+    pub view_name: Option<String>,
+    pub materialized: bool,
+    pub refresh_interval_secs: Option<i32>,
+    /// None = automática según el número de atributos
+    pub pivot_strategy: Option<ViewPivotStrategy>,
+    pub refresh_status: ViewRefreshStatus,
+    pub last_refresh_started_at: Option<DateTime<Utc>>,
+    pub last_refreshed_at: Option<DateTime<Utc>>,
+    pub last_refresh_duration_ms: Option<i64>,
+    pub last_refresh_error: Option<String>,
+}
+
+/// Modo de almacenamiento de una entidad lógica tras cambiarlo.
+#[derive(Serialize, Debug)]
+pub struct StorageModeResponse {
+    pub entity_id: Uuid,
+    pub storage_mode: StorageMode,
+    /// Registros o valores copiados (0 si la entidad ya estaba en ese modo)
+    pub migrated: usize,
+}
+
+// --- Mapeos explícitos DTO -> Response ---
+impl From<LogicalEntityDto> for LogicalEntityResponse {
+    fn from(dto: LogicalEntityDto) -> Self {
+        Self {
+            id: dto.id,
+            name: dto.name,
+            description: dto.description,
+            assign_view: dto.assign_view,
+            search_language: dto.search_language,
+            storage_mode: dto.storage_mode,
+            created_by: dto.created_by,
+            created_at: dto.created_at,
+            updated_by: dto.updated_by,
+            updated_at: dto.updated_at,
+            status: dto.status,
+        }
+    }
+}
+
+impl From<AttributeDto> for AttributeResponse {
+    fn from(dto: AttributeDto) -> Self {
+        Self {
+            id: dto.id,
+            name: dto.name,
+            description: dto.description,
+            data_type_id: dto.data_type_id,
+            data_type_name: dto.data_type_name,
+            position: dto.position,
+            is_required: dto.is_required,
+            is_unique: dto.is_unique,
+            default_value: dto.default_value,
+            validation_regex: dto.validation_regex,
+            options: dto.options,
+            show_option_label: dto.show_option_label,
+            is_searchable: dto.is_searchable,
+            reference: dto.reference,
+            status: dto.status,
+        }
+    }
+}
+
+impl From<LogicalEntityDetailsDto> for LogicalEntityDetailsResponse {
+    fn from(dto: LogicalEntityDetailsDto) -> Self {
+        Self {
+            entity: dto.entity.into(),
+            attributes: dto.attributes.into_iter().map(AttributeResponse::from).collect(),
+        }
+    }
+}
+
+impl From<LogicalEntityPageDto> for LogicalEntityPageResponse {
+    fn from(dto: LogicalEntityPageDto) -> Self {
+        Self {
+            items: dto.items.into_iter().map(LogicalEntityDetailsResponse::from).collect(),
+            page: dto.page,
+            page_size: dto.page_size,
+            total: dto.total,
+        }
+    }
+}
+
+impl From<EntityViewStatusDto> for EntityViewStatusResponse {
+    fn from(dto: EntityViewStatusDto) -> Self {
+        Self {
+            entity_id: dto.entity_id,
+            view_name: dto.view_name,
+            materialized: dto.settings.materialized,
+            refresh_interval_secs: dto.settings.refresh_interval_secs,
+            pivot_strategy: dto.settings.pivot_strategy,
+            refresh_status: dto.state.status,
+            last_refresh_started_at: dto.state.last_refresh_started_at,
+            last_refreshed_at: dto.state.last_refreshed_at,
+            last_refresh_duration_ms: dto.state.last_refresh_duration_ms,
+            last_refresh_error: dto.state.last_refresh_error,
+        }
+    }
+}
+
+impl From<StorageModeChangeDto> for StorageModeResponse {
+    fn from(dto: StorageModeChangeDto) -> Self {
+        Self {
+            entity_id: dto.entity_id,
+            storage_mode: dto.storage_mode,
+            migrated: dto.migrated,
+        }
+    }
+}
@@ -1,7 +1,32 @@
 mod user_response;
 mod token_response;
 pub mod logical_entity_response;
+pub mod record_response;
+pub mod attribute_response;
+pub mod schema_version_response;
+pub mod data_type_response;
+pub mod schema_bundle_response;
+pub mod trash_response;
+pub mod record_history_response;
 
 pub use user_response::UserResponse;
 pub use token_response::TokenResponse;
-pub use logical_entity_response::{LogicalEntityResponse, CreateLogicalEntityResponse}; // <--- AÑADIR (elige una o ambas según necesites)
+pub use logical_entity_response::{
+    LogicalEntityResponse, CreateLogicalEntityResponse,
+    AttributeResponse, LogicalEntityDetailsResponse, LogicalEntityPageResponse,
+    EntityViewResponse, EntityViewStatusResponse, StorageModeResponse,
+};
+pub use record_response::{
+    RecordResponse, RecordPageResponse, RecordSearchHitResponse, RecordSearchPageResponse, ImportReportResponse,
+    ImportRowErrorResponse,
+};
+pub use attribute_response::{AttributeListResponse, DataTypeChangeResponse, ConversionFailureResponse};
+pub use schema_version_response::{
+    SchemaVersionSummaryResponse, SchemaVersionResponse, SchemaVersionListResponse, SchemaVersionDiffResponse,
+};
+pub use data_type_response::{DataTypeResponse, DataTypeListResponse};
+pub use schema_bundle_response::{EntitySchemaPreviewResponse, SchemaBundleImportResponse};
+pub use trash_response::{
+    DeletedRecordResponse, DeletedLogicalEntityResponse, DeletedUserResponse, TrashPageResponse, PurgeReportResponse,
+};
+pub use record_history_response::{RecordHistoryEntryResponse, RecordHistoryPageResponse, RecordAsOfResponse};
@@ -0,0 +1,69 @@
+use serde::Serialize;
+use serde_json::{Map, Value};
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+
+use crate::Application::dtos::record_history_dto::{RecordHistoryPageDto, RecordAsOfDto};
+use crate::Application::ports::driven::repositories::RecordHistoryEntryDto;
+use crate::Domain::record_history::RecordChangeKind;
+
+/// Entrada del historial: un evento del registro (`attribute` a null) o el cambio
+/// de valor de un atributo (null = sin valor).
+#[derive(Serialize, Debug)]
+pub struct RecordHistoryEntryResponse {
+    pub id: i64,
+    pub change: RecordChangeKind,
+    pub attribute_id: Option<Uuid>,
+    pub attribute: Option<String>,
+    pub old_value: Option<Value>,
+    pub new_value: Option<Value>,
+    pub changed_by: Option<Uuid>,
+    pub changed_at: DateTime<Utc>,
+    pub request_id: Option<String>,
+}
+
+impl From<RecordHistoryEntryDto> for RecordHistoryEntryResponse {
+    fn from(dto: RecordHistoryEntryDto) -> Self {
+        Self {
+            id: dto.id,
+            change: dto.change_kind,
+            attribute_id: dto.attribute_id,
+            attribute: dto.attribute_name,
+            old_value: dto.old_value,
+            new_value: dto.new_value,
+            changed_by: dto.changed_by,
+            changed_at: dto.changed_at,
+            request_id: dto.request_id,
+        }
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct RecordHistoryPageResponse {
+    pub items: Vec<RecordHistoryEntryResponse>,
+    pub next_before: Option<i64>,
+}
+
+impl From<RecordHistoryPageDto> for RecordHistoryPageResponse {
+    fn from(dto: RecordHistoryPageDto) -> Self {
+        Self {
+            items: dto.entries.into_iter().map(RecordHistoryEntryResponse::from).collect(),
+            next_before: dto.next_before,
+        }
+    }
+}
+
+/// Registro reconstruido en una fecha: `id`, `as_of` y un campo por atributo.
+#[derive(Serialize, Debug)]
+pub struct RecordAsOfResponse {
+    pub id: Uuid,
+    pub as_of: DateTime<Utc>,
+    #[serde(flatten)]
+    pub values: Map<String, Value>,
+}
+
+impl From<RecordAsOfDto> for RecordAsOfResponse {
+    fn from(dto: RecordAsOfDto) -> Self {
+        Self { id: dto.id, as_of: dto.as_of, values: dto.values }
+    }
+}
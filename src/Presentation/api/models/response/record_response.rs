@@ -0,0 +1,135 @@
+use serde::Serialize;
+use serde_json::{Map, Value};
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+
+use crate::Application::dtos::record_dto::{RecordPageDto, RecordSearchPageDto, ImportReportDto, ImportRowErrorDto};
+use crate::Application::ports::driven::repositories::{RecordDto, RecordSearchHitDto};
+
+/// Registro como objeto JSON plano: columnas de sistema + un campo por atributo.
+#[derive(Serialize, Debug)]
+pub struct RecordResponse {
+    pub id: Uuid,
+    #[serde(flatten)]
+    pub values: Map<String, Value>,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_by: Option<Uuid>,
+    pub updated_at: Option<DateTime<Utc>>,
+    pub status: i16,
+}
+
+#[derive(Serialize, Debug)]
+pub struct RecordPageResponse {
+    pub items: Vec<RecordResponse>,
+    pub limit: i64,
+    pub next_cursor: Option<String>,
+}
+
+/// Resultado de búsqueda: el registro, su relevancia y los fragmentos resaltados por atributo.
+#[derive(Serialize, Debug)]
+pub struct RecordSearchHitResponse {
+    pub record: RecordResponse,
+    pub rank: f32,
+    pub highlights: Map<String, Value>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct RecordSearchPageResponse {
+    pub items: Vec<RecordSearchHitResponse>,
+    pub limit: i64,
+    pub next_cursor: Option<String>,
+}
+
+/// Error de una fila del archivo importado.
+#[derive(Serialize, Debug)]
+pub struct ImportRowErrorResponse {
+    pub line: usize,
+    pub column: Option<String>,
+    pub code: String,
+    pub reason: String,
+}
+
+/// Informe de la importación masiva de registros.
+#[derive(Serialize, Debug)]
+pub struct ImportReportResponse {
+    pub total_rows: usize,
+    pub valid_rows: usize,
+    pub invalid_rows: usize,
+    pub imported: usize,
+    pub dry_run: bool,
+    pub on_error: String,
+    pub applied: bool,
+    pub errors: Vec<ImportRowErrorResponse>,
+}
+
+// --- Mapeos explícitos DTO -> Response ---
+impl From<RecordDto> for RecordResponse {
+    fn from(dto: RecordDto) -> Self {
+        Self {
+            id: dto.id,
+            values: dto.values,
+            created_by: dto.created_by,
+            created_at: dto.created_at,
+            updated_by: dto.updated_by,
+            updated_at: dto.updated_at,
+            status: dto.status,
+        }
+    }
+}
+
+impl From<RecordPageDto> for RecordPageResponse {
+    fn from(dto: RecordPageDto) -> Self {
+        Self {
+            items: dto.items.into_iter().map(RecordResponse::from).collect(),
+            limit: dto.limit,
+            next_cursor: dto.next_cursor,
+        }
+    }
+}
+
+impl From<RecordSearchHitDto> for RecordSearchHitResponse {
+    fn from(dto: RecordSearchHitDto) -> Self {
+        Self {
+            record: RecordResponse::from(dto.record),
+            rank: dto.rank,
+            highlights: dto.highlights,
+        }
+    }
+}
+
+impl From<RecordSearchPageDto> for RecordSearchPageResponse {
+    fn from(dto: RecordSearchPageDto) -> Self {
+        Self {
+            items: dto.items.into_iter().map(RecordSearchHitResponse::from).collect(),
+            limit: dto.limit,
+            next_cursor: dto.next_cursor,
+        }
+    }
+}
+
+impl From<ImportRowErrorDto> for ImportRowErrorResponse {
+    fn from(dto: ImportRowErrorDto) -> Self {
+        Self {
+            line: dto.line,
+            column: dto.column,
+            code: dto.code,
+            reason: dto.reason,
+        }
+    }
+}
+
+impl From<ImportReportDto> for ImportReportResponse {
+    fn from(dto: ImportReportDto) -> Self {
+        Self {
+            total_rows: dto.total_rows,
+            valid_rows: dto.valid_rows,
+            invalid_rows: dto.invalid_rows,
+            imported: dto.imported,
+            dry_run: dto.dry_run,
+            on_error: dto.on_error,
+            applied: dto.applied,
+            errors: dto.errors.into_iter().map(ImportRowErrorResponse::from).collect(),
+        }
+    }
+}
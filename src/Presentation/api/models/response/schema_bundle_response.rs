@@ -0,0 +1,71 @@
+use serde::Serialize;
+
+use uuid::Uuid;
+
+use crate::Application::dtos::schema_bundle_dto::{EntitySchemaPreviewDto, SchemaBundleImportReportDto};
+use crate::Domain::schema_bundles::{PlanAction, PlanStep};
+use crate::Domain::schema_previews::RuleImpact;
+
+/// Informe de la importación de un bundle: plan de cambios y si se aplicó.
+#[derive(Serialize, Debug)]
+pub struct SchemaBundleImportResponse {
+    pub dry_run: bool,
+    pub prune: bool,
+    pub applied: bool,
+    pub adds: usize,
+    pub changes: usize,
+    pub removals: usize,
+    pub steps: Vec<PlanStep>,
+    pub conflicts: Vec<String>,
+}
+
+// --- Mapeo explícito DTO -> Response ---
+impl From<SchemaBundleImportReportDto> for SchemaBundleImportResponse {
+    fn from(dto: SchemaBundleImportReportDto) -> Self {
+        Self {
+            dry_run: dto.dry_run,
+            prune: dto.prune,
+            applied: dto.applied,
+            adds: dto.plan.count(PlanAction::Add),
+            changes: dto.plan.count(PlanAction::Change),
+            removals: dto.plan.count(PlanAction::Remove),
+            steps: dto.plan.steps,
+            conflicts: dto.plan.conflicts,
+        }
+    }
+}
+
+/// Vista previa de la creación o el cambio de una entidad (no se aplica nada).
+#[derive(Serialize, Debug)]
+pub struct EntitySchemaPreviewResponse {
+    pub entity: String,
+    /// None si la entidad se crearía
+    pub entity_id: Option<Uuid>,
+    pub adds: usize,
+    pub changes: usize,
+    pub removals: usize,
+    pub steps: Vec<PlanStep>,
+    pub conflicts: Vec<String>,
+    pub view_name: Option<String>,
+    pub view_sql: Option<String>,
+    pub rule_impacts: Vec<RuleImpact>,
+    pub warnings: Vec<String>,
+}
+
+impl From<EntitySchemaPreviewDto> for EntitySchemaPreviewResponse {
+    fn from(dto: EntitySchemaPreviewDto) -> Self {
+        Self {
+            entity: dto.entity,
+            entity_id: dto.entity_id,
+            adds: dto.plan.count(PlanAction::Add),
+            changes: dto.plan.count(PlanAction::Change),
+            removals: dto.plan.count(PlanAction::Remove),
+            steps: dto.plan.steps,
+            conflicts: dto.plan.conflicts,
+            view_name: dto.view_name,
+            view_sql: dto.view_sql,
+            rule_impacts: dto.rule_impacts,
+            warnings: dto.warnings,
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use serde::Serialize;
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+
+use crate::Application::dtos::schema_version_dto::SchemaVersionDiffDto;
+use crate::Application::ports::driven::repositories::SchemaVersionDto;
+use crate::Domain::schema_versions::{AttributeSnapshot, AttributeDiff, FieldChange};
+
+/// Resumen de una versión (listado).
+#[derive(Serialize, Debug)]
+pub struct SchemaVersionSummaryResponse {
+    pub version: i32,
+    pub entity_name: String,
+    pub attribute_count: usize,
+    pub change_summary: Option<String>,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Versión completa con la definición de cada atributo.
+#[derive(Serialize, Debug)]
+pub struct SchemaVersionResponse {
+    pub entity_id: Uuid,
+    pub version: i32,
+    pub entity_name: String,
+    pub attributes: Vec<AttributeSnapshot>,
+    pub change_summary: Option<String>,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct SchemaVersionListResponse {
+    pub entity_id: Uuid,
+    pub versions: Vec<SchemaVersionSummaryResponse>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct SchemaVersionDiffResponse {
+    pub entity_id: Uuid,
+    pub from_version: i32,
+    pub to_version: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entity_name: Option<FieldChange>,
+    pub attributes: Vec<AttributeDiff>,
+}
+
+// --- Mapeos explícitos DTO -> Response ---
+impl From<SchemaVersionDto> for SchemaVersionSummaryResponse {
+    fn from(dto: SchemaVersionDto) -> Self {
+        Self {
+            version: dto.version,
+            entity_name: dto.definition.entity_name,
+            attribute_count: dto.definition.attributes.len(),
+            change_summary: dto.change_summary,
+            created_by: dto.created_by,
+            created_at: dto.created_at,
+        }
+    }
+}
+
+impl From<SchemaVersionDto> for SchemaVersionResponse {
+    fn from(dto: SchemaVersionDto) -> Self {
+        Self {
+            entity_id: dto.entity_id,
+            version: dto.version,
+            entity_name: dto.definition.entity_name,
+            attributes: dto.definition.attributes,
+            change_summary: dto.change_summary,
+            created_by: dto.created_by,
+            created_at: dto.created_at,
+        }
+    }
+}
+
+impl From<SchemaVersionDiffDto> for SchemaVersionDiffResponse {
+    fn from(dto: SchemaVersionDiffDto) -> Self {
+        Self {
+            entity_id: dto.entity_id,
+            from_version: dto.from_version,
+            to_version: dto.to_version,
+            entity_name: dto.entity_name,
+            attributes: dto.attributes,
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use serde::Serialize;
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+
+use crate::Application::dtos::trash_dto::{TrashPageDto, PurgeReportDto, PurgeSkipDto};
+use crate::Application::ports::driven::repositories::{DeletedRecordDto, DeletedLogicalEntityDto, DeletedUserDto};
+use super::logical_entity_response::LogicalEntityResponse;
+use super::record_response::RecordResponse;
+
+/// Registro de la papelera: el registro plano más quién y cuándo lo eliminó.
+#[derive(Serialize, Debug)]
+pub struct DeletedRecordResponse {
+    #[serde(flatten)]
+    pub record: RecordResponse,
+    pub deleted_at: DateTime<Utc>,
+    pub deleted_by: Option<Uuid>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct DeletedLogicalEntityResponse {
+    #[serde(flatten)]
+    pub entity: LogicalEntityResponse,
+    pub deleted_at: DateTime<Utc>,
+    pub deleted_by: Option<Uuid>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct DeletedUserResponse {
+    pub id: Uuid,
+    pub username: String,
+    pub email: String,
+    pub deleted_at: DateTime<Utc>,
+    pub deleted_by: Option<Uuid>,
+}
+
+/// Página de la papelera (paginación por número de página).
+#[derive(Serialize, Debug)]
+pub struct TrashPageResponse<T> {
+    pub items: Vec<T>,
+    pub page: i64,
+    pub page_size: i64,
+    pub total: i64,
+}
+
+/// Informe de la purga de la papelera.
+#[derive(Serialize, Debug)]
+pub struct PurgeReportResponse {
+    pub deleted_before: DateTime<Utc>,
+    pub records: usize,
+    pub logical_entities: usize,
+    pub users: usize,
+    pub skipped: Vec<PurgeSkipDto>,
+}
+
+// --- Mapeos explícitos DTO -> Response ---
+impl From<DeletedRecordDto> for DeletedRecordResponse {
+    fn from(dto: DeletedRecordDto) -> Self {
+        Self {
+            record: RecordResponse::from(dto.record),
+            deleted_at: dto.deleted_at,
+            deleted_by: dto.deleted_by,
+        }
+    }
+}
+
+impl From<DeletedLogicalEntityDto> for DeletedLogicalEntityResponse {
+    fn from(dto: DeletedLogicalEntityDto) -> Self {
+        Self {
+            entity: LogicalEntityResponse::from(dto.entity),
+            deleted_at: dto.deleted_at,
+            deleted_by: dto.deleted_by,
+        }
+    }
+}
+
+impl From<DeletedUserDto> for DeletedUserResponse {
+    fn from(dto: DeletedUserDto) -> Self {
+        Self {
+            id: dto.id,
+            username: dto.username,
+            email: dto.email,
+            deleted_at: dto.deleted_at,
+            deleted_by: dto.deleted_by,
+        }
+    }
+}
+
+impl<D, T: From<D>> From<TrashPageDto<D>> for TrashPageResponse<T> {
+    fn from(dto: TrashPageDto<D>) -> Self {
+        Self {
+            items: dto.items.into_iter().map(T::from).collect(),
+            page: dto.page,
+            page_size: dto.page_size,
+            total: dto.total,
+        }
+    }
+}
+
+impl From<PurgeReportDto> for PurgeReportResponse {
+    fn from(dto: PurgeReportDto) -> Self {
+        Self {
+            deleted_before: dto.deleted_before,
+            records: dto.records,
+            logical_entities: dto.logical_entities,
+            users: dto.users,
+            skipped: dto.skipped,
+        }
+    }
+}
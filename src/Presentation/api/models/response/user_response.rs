@@ -18,4 +18,6 @@ pub struct UserResponse {
     pub updated_by: Option<Uuid>,
     pub updated_at: Option<DateTime<Utc>>,
     pub status: i32,
+    /// Versión del usuario (la misma que la cabecera ETag).
+    pub version: i32,
 }
\ No newline at end of file
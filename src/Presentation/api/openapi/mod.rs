@@ -0,0 +1,306 @@
+// src/Presentation/api/openapi/mod.rs
+// Documento OpenAPI 3.1 de la API: rutas fijas (usuarios, autenticación, salud y
+// entidades lógicas) y rutas de registros generadas a partir de cada entidad activa.
+
+mod record_paths;
+mod static_paths;
+
+use serde_json::{json, Map, Value};
+
+use crate::Application::dtos::logical_entity_dto::EntityJsonSchemaDto;
+
+/// Versión de la especificación del documento generado.
+pub const OPENAPI_VERSION: &str = "3.1.0";
+
+/// Página de Swagger UI; `{{OPENAPI_URL}}` se sustituye por la ruta del documento.
+const SWAGGER_UI_PAGE: &str = include_str!("swagger_ui.html");
+
+/// Versión exacta de swagger-ui-dist que carga la página. Fijada para que el CDN no
+/// pueda servir otra distinta; al cambiarla hay que revisar la página.
+const SWAGGER_UI_VERSION: &str = "5.17.14";
+
+/// Construye el documento. `entities`: JSON Schema de los registros de cada entidad
+/// activa (ver `ListEntityJsonSchemasUseCase`).
+pub fn build_openapi_document(entities: &[EntityJsonSchemaDto]) -> Value {
+    let mut paths = Map::new();
+    let mut schemas = Map::new();
+    let mut tags = Vec::new();
+
+    static_paths::add_static_paths(&mut paths, &mut schemas, &mut tags);
+    for entity in entities {
+        record_paths::add_record_paths(&mut paths, &mut schemas, &mut tags, entity);
+    }
+
+    json!({
+        "openapi": OPENAPI_VERSION,
+        "jsonSchemaDialect": crate::Domain::json_schemas::JSON_SCHEMA_DIALECT,
+        "info": {
+            "title": "anyb API",
+            "version": env!("CARGO_PKG_VERSION"),
+            "description": "Metadata service for logical entities. Record endpoints are generated from the active logical entities.",
+        },
+        "tags": tags,
+        "paths": paths,
+        "components": { "schemas": schemas },
+    })
+}
+
+/// Página HTML de Swagger UI que carga el documento de `openapi_url`.
+pub fn swagger_ui_page(openapi_url: &str) -> String {
+    SWAGGER_UI_PAGE
+        .replace("{{SWAGGER_UI_VERSION}}", SWAGGER_UI_VERSION)
+        .replace("{{OPENAPI_URL}}", openapi_url)
+}
+
+// --- Helpers compartidos ---
+
+fn schema_ref(name: &str) -> Value {
+    json!({ "$ref": format!("#/components/schemas/{}", name) })
+}
+
+fn tag(name: &str, description: &str) -> Value {
+    json!({ "name": name, "description": description })
+}
+
+/// Respuesta correcta envuelta en ApiResponse (`{success, data, error}`).
+/// Sin `data` (p. ej. en los borrados), `data` es null.
+fn data_response(description: &str, data: Option<Value>) -> Value {
+    json!({
+        "description": description,
+        "content": {
+            "application/json": {
+                "schema": {
+                    "type": "object",
+                    "properties": {
+                        "success": { "const": true },
+                        "data": data.unwrap_or_else(|| json!({ "type": "null" })),
+                        "error": { "type": "null" },
+                    },
+                    "required": ["success", "data"],
+                },
+            },
+        },
+    })
+}
+
+/// Respuesta de error (ver ErrorAdapter).
+fn error_response(description: &str) -> Value {
+    json!({
+        "description": description,
+        "content": { "application/json": { "schema": schema_ref("ErrorResponse") } },
+    })
+}
+
+/// Añade a la respuesta la cabecera ETag (versión del recurso).
+fn with_etag(mut response: Value) -> Value {
+    response["headers"] = json!({
+        "ETag": { "description": "Version of the resource, for If-Match / If-None-Match", "schema": { "type": "string" } },
+    });
+    response
+}
+
+/// 304 de un GET con If-None-Match.
+fn not_modified_response() -> Value {
+    with_etag(json!({ "description": "Not modified: If-None-Match matches the current ETag" }))
+}
+
+/// 412 de una escritura con If-Match.
+fn precondition_failed_response() -> Value {
+    error_response("If-Match does not match the current ETag: the resource was modified")
+}
+
+fn if_match_param() -> Value {
+    json!({
+        "name": "If-Match", "in": "header", "required": false,
+        "description": "ETag of the version being modified; the request fails with 412 if it is no longer current",
+        "schema": { "type": "string" },
+    })
+}
+
+fn if_none_match_param() -> Value {
+    json!({
+        "name": "If-None-Match", "in": "header", "required": false,
+        "description": "ETag of the cached version; the response is 304 if it is still current",
+        "schema": { "type": "string" },
+    })
+}
+
+fn json_body(schema: Value, required: bool) -> Value {
+    json!({
+        "required": required,
+        "content": { "application/json": { "schema": schema } },
+    })
+}
+
+fn path_param(name: &str, schema: Value, description: &str) -> Value {
+    json!({ "name": name, "in": "path", "required": true, "description": description, "schema": schema })
+}
+
+fn query_param(name: &str, schema: Value, description: &str) -> Value {
+    json!({ "name": name, "in": "query", "required": false, "description": description, "schema": schema })
+}
+
+/// Operación con sus respuestas; las respuestas de error se añaden a las indicadas.
+fn operation(tag: &str, operation_id: &str, summary: &str, responses: Vec<(&str, Value)>) -> Map<String, Value> {
+    let mut operation = Map::new();
+    operation.insert("tags".to_string(), json!([tag]));
+    operation.insert("operationId".to_string(), json!(operation_id));
+    operation.insert("summary".to_string(), json!(summary));
+    let responses: Map<String, Value> = responses.into_iter().map(|(status, r)| (status.to_string(), r)).collect();
+    operation.insert("responses".to_string(), Value::Object(responses));
+    operation
+}
+
+fn with_parameters(mut operation: Map<String, Value>, parameters: Vec<Value>) -> Map<String, Value> {
+    operation.insert("parameters".to_string(), Value::Array(parameters));
+    operation
+}
+
+fn with_body(mut operation: Map<String, Value>, body: Value) -> Map<String, Value> {
+    operation.insert("requestBody".to_string(), body);
+    operation
+}
+
+/// Elemento de la papelera: el recurso más quién y cuándo lo eliminó.
+fn trashed(item: Value) -> Value {
+    json!({
+        "allOf": [
+            item,
+            {
+                "type": "object",
+                "properties": {
+                    "deleted_at": { "type": "string", "format": "date-time" },
+                    "deleted_by": { "type": ["string", "null"], "format": "uuid" },
+                },
+                "required": ["deleted_at"],
+            },
+        ],
+    })
+}
+
+/// Página de la papelera (paginación por número de página).
+fn trash_page(item: Value) -> Value {
+    json!({
+        "type": "object",
+        "properties": {
+            "items": { "type": "array", "items": item },
+            "page": { "type": "integer" },
+            "page_size": { "type": "integer" },
+            "total": { "type": "integer" },
+        },
+        "required": ["items", "page", "page_size", "total"],
+    })
+}
+
+fn trash_page_parameters() -> Vec<Value> {
+    vec![
+        query_param("page", json!({ "type": "integer", "minimum": 1, "default": 1 }), "Page number"),
+        query_param("page_size", json!({ "type": "integer", "minimum": 1, "maximum": 100, "default": 20 }), "Page size"),
+    ]
+}
+
+/// Añade la operación `method` a la ruta `path`.
+fn add_operation(paths: &mut Map<String, Value>, path: &str, method: &str, operation: Map<String, Value>) {
+    let item = paths.entry(path.to_string()).or_insert_with(|| json!({}));
+    if let Value::Object(item) = item {
+        item.insert(method.to_string(), Value::Object(operation));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    fn entity(name: &str) -> EntityJsonSchemaDto {
+        EntityJsonSchemaDto {
+            entity_id: Uuid::new_v4(),
+            entity_name: name.to_string(),
+            schema: json!({
+                "$schema": crate::Domain::json_schemas::JSON_SCHEMA_DIALECT,
+                "type": "object",
+                "description": "Pedidos de clientes",
+                "properties": {
+                    "code": { "type": "string", "maxLength": 20 },
+                    "amount": { "type": ["number", "null"], "default": 0 },
+                },
+                "required": ["code"],
+            }),
+        }
+    }
+
+    /// Todas las referencias `$ref` del valor.
+    fn collect_refs<'a>(value: &'a Value, refs: &mut Vec<&'a str>) {
+        match value {
+            Value::Object(map) => {
+                if let Some(Value::String(reference)) = map.get("$ref") {
+                    refs.push(reference);
+                }
+                map.values().for_each(|v| collect_refs(v, refs));
+            },
+            Value::Array(items) => items.iter().for_each(|v| collect_refs(v, refs)),
+            _ => {},
+        }
+    }
+
+    #[test]
+    fn document_includes_static_and_record_paths() {
+        let document = build_openapi_document(&[entity("customer_order")]);
+        assert_eq!(document["openapi"], OPENAPI_VERSION);
+
+        let paths = document["paths"].as_object().unwrap();
+        for path in ["/api/auth/login", "/api/users/{id}", "/api/logical-entities/{id}", "/graphql"] {
+            assert!(paths.contains_key(path), "falta la ruta {}", path);
+        }
+        let records = &paths["/api/entities/customer_order/records"];
+        assert!(records.get("get").is_some() && records.get("post").is_some());
+        assert!(paths.contains_key("/api/entities/customer_order/records/{id}"));
+        assert!(document["tags"].as_array().unwrap().iter().any(|t| t["name"] == "Records: customer_order"));
+
+        let schemas = &document["components"]["schemas"];
+        assert_eq!(schemas["CustomerOrderRecordInput"]["required"], json!(["code"]));
+        assert!(schemas["CustomerOrderRecordInput"].get("$schema").is_none());
+        let patch = &schemas["CustomerOrderRecordPatch"];
+        assert!(patch.get("required").is_none());
+        assert!(patch["properties"]["amount"].get("default").is_none());
+        let record = &schemas["CustomerOrderRecord"]["properties"];
+        assert!(record.get("id").is_some() && record.get("code").is_some() && record.get("created_at").is_some());
+        let as_of = &schemas["CustomerOrderRecordAsOf"]["properties"];
+        assert!(as_of.get("as_of").is_some() && as_of.get("amount").is_some());
+
+        // Cada referencia apunta a un componente definido
+        let mut refs = Vec::new();
+        collect_refs(&document, &mut refs);
+        assert!(!refs.is_empty());
+        for reference in refs {
+            let name = reference.strip_prefix("#/components/schemas/").unwrap_or_else(|| panic!("referencia {}", reference));
+            assert!(schemas.get(name).is_some(), "el componente {} no existe", name);
+        }
+    }
+
+    #[test]
+    fn operation_ids_are_unique_across_entities() {
+        let document = build_openapi_document(&[entity("customer_order"), entity("invoice")]);
+        let mut ids: Vec<&str> = document["paths"]
+            .as_object()
+            .unwrap()
+            .values()
+            .flat_map(|item| item.as_object().unwrap().values())
+            .filter_map(|operation| operation["operationId"].as_str())
+            .collect();
+        let total = ids.len();
+        ids.sort_unstable();
+        ids.dedup();
+        assert_eq!(ids.len(), total);
+        assert!(ids.contains(&"listCustomerOrder") && ids.contains(&"listInvoice"));
+    }
+
+    #[test]
+    fn swagger_ui_page_pins_its_assets() {
+        let page = swagger_ui_page("/api/openapi.json");
+        assert!(page.contains(r#"url: "/api/openapi.json""#));
+        assert!(!page.contains("{{"));
+        assert_eq!(page.matches(&format!("swagger-ui-dist@{}/", SWAGGER_UI_VERSION)).count(), 2);
+        assert_eq!(page.matches(r#"crossorigin="anonymous""#).count(), 2);
+    }
+}
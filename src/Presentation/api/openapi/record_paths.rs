@@ -0,0 +1,343 @@
+// src/Presentation/api/openapi/record_paths.rs
+// Rutas de registros (/api/entities/{entidad}/records) generadas para cada entidad
+// activa a partir del JSON Schema de sus atributos.
+
+use serde_json::{json, Map, Value};
+
+use crate::Application::dtos::logical_entity_dto::EntityJsonSchemaDto;
+use super::{
+    add_operation, data_response, error_response, if_match_param, if_none_match_param, json_body, not_modified_response,
+    operation, path_param, precondition_failed_response, query_param, schema_ref, tag, trash_page, trash_page_parameters,
+    trashed, with_body, with_etag, with_parameters,
+};
+
+/// Columnas de sistema que acompañan a los valores de cada registro.
+fn system_properties() -> [(&'static str, Value); 5] {
+    [
+        ("created_by", json!({ "type": ["string", "null"], "format": "uuid" })),
+        ("created_at", json!({ "type": "string", "format": "date-time" })),
+        ("updated_by", json!({ "type": ["string", "null"], "format": "uuid" })),
+        ("updated_at", json!({ "type": ["string", "null"], "format": "date-time" })),
+        ("status", json!({ "type": "integer" })),
+    ]
+}
+
+/// Nombre de los componentes de la entidad: `customer_order` -> `CustomerOrder`.
+/// Los caracteres no admitidos en las claves de `components` se descartan.
+fn component_prefix(entity_name: &str) -> String {
+    entity_name
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+pub(super) fn add_record_paths(
+    paths: &mut Map<String, Value>,
+    schemas: &mut Map<String, Value>,
+    tags: &mut Vec<Value>,
+    entity: &EntityJsonSchemaDto,
+) {
+    let name = entity.entity_name.as_str();
+    let prefix = format!("{}Record", component_prefix(name));
+    let tag_name = format!("Records: {}", name);
+    let description = entity.schema.get("description").and_then(Value::as_str).unwrap_or(name);
+    tags.push(tag(&tag_name, description));
+
+    add_record_schemas(schemas, &prefix, &entity.schema);
+    let schema = |suffix: &str| schema_ref(&format!("{}{}", prefix, suffix));
+    let op_id = |action: &str| format!("{}{}", action, component_prefix(name));
+
+    let collection = format!("/api/entities/{}/records", name);
+    let item = format!("{}/{{id}}", collection);
+    let id = || path_param("id", json!({ "type": "string", "format": "uuid" }), "Record ID");
+    let limit = || query_param("limit", json!({ "type": "integer", "minimum": 1, "maximum": 100, "default": 20 }), "Page size");
+    let after = || query_param("after", json!({ "type": "string" }), "Cursor returned as next_cursor by the previous page");
+    let filter = || query_param("filter", json!({ "type": "string", "maxLength": 4000 }), "Filter expression, e.g. status eq 'open' and amount gt 100");
+    let sort = || query_param("sort", json!({ "type": "string", "maxLength": 500 }), "Comma-separated attributes; prefix with - for descending order");
+
+    let create = operation(&tag_name, &op_id("create"), &format!("Create a {} record", name), vec![
+        ("201", with_etag(data_response("Created record", Some(schema(""))))),
+        ("400", error_response("Invalid values")),
+        ("404", error_response("Entity not found")),
+        ("409", error_response("A unique constraint was violated")),
+    ]);
+    add_operation(paths, &collection, "post", with_body(create, json_body(schema("Input"), true)));
+
+    let list = operation(&tag_name, &op_id("list"), &format!("List {} records", name), vec![
+        ("200", data_response("Page of records", Some(schema("Page")))),
+        ("400", error_response("Invalid filter, sort or cursor")),
+        ("404", error_response("Entity not found")),
+    ]);
+    add_operation(paths, &collection, "get", with_parameters(list, vec![filter(), sort(), limit(), after()]));
+
+    let search = operation(&tag_name, &op_id("search"), &format!("Full-text search over {} records", name), vec![
+        ("200", data_response("Page of search hits", Some(schema("SearchPage")))),
+        ("400", error_response("Invalid query or cursor")),
+        ("404", error_response("Entity not found")),
+    ]);
+    let q = json!({ "name": "q", "in": "query", "required": true, "description": "Search text", "schema": { "type": "string", "minLength": 1, "maxLength": 500 } });
+    add_operation(paths, &format!("{}/search", collection), "get", with_parameters(search, vec![q, limit(), after()]));
+
+    let import = operation(&tag_name, &op_id("import"), &format!("Bulk import {} records from CSV or NDJSON", name), vec![
+        ("200", data_response("Import report", Some(schema_ref("ImportReport")))),
+        ("400", error_response("Invalid file or parameters")),
+        ("404", error_response("Entity not found")),
+    ]);
+    let import = with_parameters(import, vec![
+        json!({ "name": "format", "in": "query", "required": true, "schema": { "enum": ["csv", "ndjson"] } }),
+        query_param("delimiter", json!({ "type": "string", "minLength": 1, "maxLength": 1, "default": "," }), "CSV delimiter"),
+        query_param("dry_run", json!({ "type": "boolean", "default": false }), "Validate without importing"),
+        query_param("on_error", json!({ "enum": ["all_or_nothing", "skip_invalid"], "default": "all_or_nothing" }), "What to do with invalid rows"),
+        query_param("mapping", json!({ "type": "string" }), "JSON object of column -> attribute (\"\" ignores the column)"),
+    ]);
+    let file = json!({
+        "required": true,
+        "content": {
+            "text/csv": { "schema": { "type": "string" } },
+            "application/x-ndjson": { "schema": { "type": "string" } },
+        },
+    });
+    add_operation(paths, &format!("{}/import", collection), "post", with_body(import, file));
+
+    let export = operation(&tag_name, &op_id("export"), &format!("Export {} records", name), vec![
+        ("200", json!({
+            "description": "Exported records (streamed as an attachment)",
+            "content": {
+                "text/csv": { "schema": { "type": "string" } },
+                "application/x-ndjson": { "schema": { "type": "string" } },
+                "application/json": { "schema": { "type": "array", "items": schema("") } },
+            },
+        })),
+        ("400", error_response("Invalid format, filter or sort")),
+        ("404", error_response("Entity not found")),
+    ]);
+    let format = query_param("format", json!({ "enum": ["csv", "ndjson", "json"], "default": "csv" }), "Output format");
+    add_operation(paths, &format!("{}/export", collection), "get", with_parameters(export, vec![format, filter(), sort()]));
+
+    let find = operation(&tag_name, &op_id("find"), &format!("Get a {} record", name), vec![
+        ("200", with_etag(data_response("Record", Some(schema(""))))),
+        ("304", not_modified_response()),
+        ("404", error_response("Entity or record not found")),
+    ]);
+    add_operation(paths, &item, "get", with_parameters(find, vec![id(), if_none_match_param()]));
+
+    let replace = operation(&tag_name, &op_id("replace"), &format!("Replace the values of a {} record", name), vec![
+        ("200", with_etag(data_response("Updated record", Some(schema(""))))),
+        ("400", error_response("Invalid values")),
+        ("404", error_response("Entity or record not found")),
+        ("409", error_response("A unique constraint was violated or the record was modified concurrently")),
+        ("412", precondition_failed_response()),
+    ]);
+    add_operation(paths, &item, "put", with_body(with_parameters(replace, vec![id(), if_match_param()]), json_body(schema("Input"), true)));
+
+    let patch = operation(&tag_name, &op_id("patch"), &format!("Update some values of a {} record", name), vec![
+        ("200", with_etag(data_response("Updated record", Some(schema(""))))),
+        ("400", error_response("Invalid values")),
+        ("404", error_response("Entity or record not found")),
+        ("409", error_response("A unique constraint was violated or the record was modified concurrently")),
+        ("412", precondition_failed_response()),
+    ]);
+    add_operation(paths, &item, "patch", with_body(with_parameters(patch, vec![id(), if_match_param()]), json_body(schema("Patch"), true)));
+
+    let delete = operation(&tag_name, &op_id("delete"), &format!("Move a {} record to the trash", name), vec![
+        ("200", data_response("Record moved to the trash", None)),
+        ("404", error_response("Entity or record not found")),
+        ("409", error_response("The record is still referenced")),
+        ("412", precondition_failed_response()),
+    ]);
+    add_operation(paths, &item, "delete", with_parameters(delete, vec![id(), if_match_param()]));
+
+    // --- Historial ---
+    let history = operation(&tag_name, &op_id("history"), &format!("List the changes of a {} record, newest first", name), vec![
+        ("200", data_response("Page of history entries", Some(schema_ref("RecordHistoryPage")))),
+        ("400", error_response("Invalid pagination parameters")),
+        ("404", error_response("Entity or record not found")),
+    ]);
+    let history = with_parameters(history, vec![
+        id(),
+        query_param("limit", json!({ "type": "integer", "minimum": 1, "maximum": 100, "default": 50 }), "Page size"),
+        query_param("before", json!({ "type": "integer", "minimum": 1 }), "Cursor returned as next_before by the previous page"),
+    ]);
+    add_operation(paths, &format!("{}/history", item), "get", history);
+
+    let as_of = operation(&tag_name, &op_id("findAsOf"), &format!("Get the values a {} record had at a point in time", name), vec![
+        ("200", data_response("Record values at the requested time", Some(schema("AsOf")))),
+        ("400", error_response("Invalid timestamp")),
+        ("404", error_response("Entity not found or record not live at that time")),
+    ]);
+    let at = json!({ "name": "at", "in": "query", "required": true, "description": "RFC 3339 timestamp", "schema": { "type": "string", "format": "date-time" } });
+    add_operation(paths, &format!("{}/as-of", item), "get", with_parameters(as_of, vec![id(), at]));
+
+    // --- Papelera ---
+    let trash = format!("{}/trash", collection);
+    let trash_item = format!("{}/{{id}}", trash);
+
+    let list_trash = operation(&tag_name, &op_id("listDeleted"), &format!("List {} records in the trash", name), vec![
+        ("200", data_response("Page of deleted records", Some(trash_page(trashed(schema("")))))),
+        ("400", error_response("Invalid pagination parameters")),
+        ("404", error_response("Entity not found")),
+    ]);
+    add_operation(paths, &trash, "get", with_parameters(list_trash, trash_page_parameters()));
+
+    let restore = operation(&tag_name, &op_id("restore"), &format!("Restore a {} record from the trash", name), vec![
+        ("200", with_etag(data_response("Restored record", Some(schema(""))))),
+        ("404", error_response("Entity not found or record not in the trash")),
+        ("409", error_response("A unique constraint or a reference is no longer satisfied")),
+    ]);
+    add_operation(paths, &format!("{}/restore", trash_item), "post", with_parameters(restore, vec![id()]));
+
+    let purge = operation(&tag_name, &op_id("purge"), &format!("Permanently delete a {} record from the trash", name), vec![
+        ("200", data_response("Record permanently deleted", None)),
+        ("404", error_response("Entity not found or record not in the trash")),
+        ("409", error_response("The record is still referenced")),
+    ]);
+    add_operation(paths, &trash_item, "delete", with_parameters(purge, vec![id()]));
+}
+
+/// Componentes de la entidad a partir de su JSON Schema:
+/// - `<Prefijo>Input`: cuerpo de alta y reemplazo (el JSON Schema tal cual).
+/// - `<Prefijo>Patch`: cuerpo de actualización parcial (ningún atributo obligatorio).
+/// - `<Prefijo>`, `<Prefijo>Page`, `<Prefijo>SearchPage`: registros devueltos.
+/// - `<Prefijo>AsOf`: valores del registro en una fecha.
+fn add_record_schemas(schemas: &mut Map<String, Value>, prefix: &str, entity_schema: &Value) {
+    // El dialecto se declara en `jsonSchemaDialect` del documento
+    let input = without(entity_schema.as_object().cloned().unwrap_or_default(), "$schema");
+
+    let mut patch = without(input.clone(), "required");
+    if let Some(Value::Object(properties)) = patch.get_mut("properties") {
+        for property in properties.values_mut() {
+            if let Value::Object(schema) = property {
+                *schema = without(std::mem::take(schema), "default");
+            }
+        }
+    }
+
+    let mut properties = Map::new();
+    properties.insert("id".to_string(), json!({ "type": "string", "format": "uuid" }));
+    if let Some(Value::Object(attributes)) = patch.get("properties") {
+        properties.extend(attributes.clone());
+    }
+    for (name, schema) in system_properties() {
+        properties.insert(name.to_string(), schema);
+    }
+    let record = json!({
+        "type": "object",
+        "properties": properties,
+        "required": ["id", "created_at", "status"],
+    });
+
+    let page = |item: Value| json!({
+        "type": "object",
+        "properties": {
+            "items": { "type": "array", "items": item },
+            "limit": { "type": "integer" },
+            "next_cursor": { "type": ["string", "null"] },
+        },
+        "required": ["items", "limit"],
+    });
+    let hit = json!({
+        "type": "object",
+        "properties": {
+            "record": schema_ref(prefix),
+            "rank": { "type": "number" },
+            "highlights": { "type": "object", "additionalProperties": { "type": "string" } },
+        },
+        "required": ["record", "rank"],
+    });
+
+    let mut as_of = Map::new();
+    as_of.insert("id".to_string(), json!({ "type": "string", "format": "uuid" }));
+    as_of.insert("as_of".to_string(), json!({ "type": "string", "format": "date-time" }));
+    if let Some(Value::Object(attributes)) = patch.get("properties") {
+        as_of.extend(attributes.clone());
+    }
+
+    schemas.insert(format!("{}Input", prefix), Value::Object(input));
+    schemas.insert(format!("{}Patch", prefix), Value::Object(patch));
+    schemas.insert(prefix.to_string(), record);
+    schemas.insert(format!("{}Page", prefix), page(schema_ref(prefix)));
+    schemas.insert(format!("{}SearchPage", prefix), page(hit));
+
+    schemas.insert(format!("{}AsOf", prefix), json!({
+        "type": "object",
+        "properties": as_of,
+        "required": ["id", "as_of"],
+    }));
+
+    if !schemas.contains_key("ImportReport") {
+        schemas.insert("ImportReport".to_string(), import_report_schema());
+    }
+    if !schemas.contains_key("RecordHistoryPage") {
+        schemas.insert("RecordHistoryPage".to_string(), history_page_schema());
+    }
+}
+
+/// Copia del objeto sin `key`, conservando el orden de las demás claves.
+fn without(map: Map<String, Value>, key: &str) -> Map<String, Value> {
+    map.into_iter().filter(|(k, _)| k != key).collect()
+}
+
+fn import_report_schema() -> Value {
+    json!({
+        "type": "object",
+        "properties": {
+            "total_rows": { "type": "integer" },
+            "valid_rows": { "type": "integer" },
+            "invalid_rows": { "type": "integer" },
+            "imported": { "type": "integer" },
+            "dry_run": { "type": "boolean" },
+            "on_error": { "type": "string" },
+            "applied": { "type": "boolean" },
+            "errors": {
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "properties": {
+                        "line": { "type": "integer" },
+                        "column": { "type": ["string", "null"] },
+                        "code": { "type": "string" },
+                        "reason": { "type": "string" },
+                    },
+                    "required": ["line", "code", "reason"],
+                },
+            },
+        },
+        "required": ["total_rows", "valid_rows", "invalid_rows", "imported", "dry_run", "on_error", "applied", "errors"],
+    })
+}
+
+fn history_page_schema() -> Value {
+    let value = json!({ "description": "Value in the record representation; null when the attribute had no value" });
+    json!({
+        "type": "object",
+        "properties": {
+            "items": {
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "properties": {
+                        "id": { "type": "integer" },
+                        "change": { "enum": ["create", "update", "delete", "restore", "purge"] },
+                        "attribute_id": { "type": ["string", "null"], "format": "uuid", "description": "null for record-level events" },
+                        "attribute": { "type": ["string", "null"] },
+                        "old_value": value,
+                        "new_value": value,
+                        "changed_by": { "type": ["string", "null"], "format": "uuid" },
+                        "changed_at": { "type": "string", "format": "date-time" },
+                        "request_id": { "type": ["string", "null"], "description": "X-Request-Id of the request that made the change" },
+                    },
+                    "required": ["id", "change", "changed_at"],
+                },
+            },
+            "next_before": { "type": ["integer", "null"] },
+        },
+        "required": ["items"],
+    })
+}
@@ -0,0 +1,610 @@
+// src/Presentation/api/openapi/static_paths.rs
+// Rutas fijas del documento OpenAPI: usuarios, autenticación, salud, entidades lógicas, papelera y GraphQL.
+
+use serde_json::{json, Map, Value};
+
+use crate::Domain::sql_identifiers::{MAX_ATTRIBUTE_NAME_BYTES, MAX_ENTITY_NAME_BYTES};
+
+use super::{
+    add_operation, data_response, error_response, if_match_param, if_none_match_param, json_body, not_modified_response,
+    operation, path_param, precondition_failed_response, query_param, schema_ref, tag, trash_page, trash_page_parameters,
+    trashed, with_body, with_etag, with_parameters,
+};
+
+const USERS: &str = "Users";
+const AUTH: &str = "Auth";
+const HEALTH: &str = "Health";
+const LOGICAL_ENTITIES: &str = "Logical entities";
+const GRAPHQL: &str = "GraphQL";
+const TRASH: &str = "Trash";
+
+pub(super) fn add_static_paths(paths: &mut Map<String, Value>, schemas: &mut Map<String, Value>, tags: &mut Vec<Value>) {
+    tags.push(tag(AUTH, "Authentication"));
+    tags.push(tag(USERS, "User management"));
+    tags.push(tag(HEALTH, "Service and database health"));
+    tags.push(tag(LOGICAL_ENTITIES, "Logical entity definitions and their attributes"));
+    tags.push(tag(GRAPHQL, "GraphQL endpoint generated from the logical entities"));
+    tags.push(tag(TRASH, "Retention-based purge of deleted records, entities and users"));
+
+    add_common_schemas(schemas);
+    add_auth_paths(paths, schemas);
+    add_user_paths(paths, schemas);
+    add_health_paths(paths, schemas);
+    add_logical_entity_paths(paths, schemas);
+    add_graphql_paths(paths, schemas);
+    add_trash_paths(paths, schemas);
+}
+
+fn uuid() -> Value {
+    json!({ "type": "string", "format": "uuid" })
+}
+
+fn nullable(json_type: &str) -> Value {
+    json!({ "type": [json_type, "null"] })
+}
+
+/// Nombres de entidades y atributos: acaban como identificadores en la vista de la entidad.
+fn sql_name(max_length: usize, description: &str) -> Value {
+    json!({
+        "type": "string",
+        "pattern": "^[A-Za-z_][A-Za-z0-9_]*$",
+        "minLength": 1,
+        "maxLength": max_length,
+        "description": description,
+    })
+}
+
+fn add_common_schemas(schemas: &mut Map<String, Value>) {
+    schemas.insert("ApiFieldError".to_string(), json!({
+        "type": "object",
+        "properties": {
+            "field": { "type": "string" },
+            "code": { "type": "string" },
+            "message": { "type": "string" },
+        },
+        "required": ["field", "code", "message"],
+    }));
+    schemas.insert("ApiError".to_string(), json!({
+        "type": "object",
+        "properties": {
+            "code": { "type": "integer", "description": "HTTP status code" },
+            "message": { "type": "string" },
+            "fields": { "type": "array", "items": schema_ref("ApiFieldError") },
+        },
+        "required": ["code", "message"],
+    }));
+    schemas.insert("ErrorResponse".to_string(), json!({
+        "type": "object",
+        "properties": {
+            "success": { "const": false },
+            "data": { "type": "null" },
+            "error": schema_ref("ApiError"),
+        },
+        "required": ["success", "error"],
+    }));
+}
+
+fn add_auth_paths(paths: &mut Map<String, Value>, schemas: &mut Map<String, Value>) {
+    schemas.insert("LoginRequest".to_string(), json!({
+        "type": "object",
+        "properties": {
+            "username": { "type": "string", "minLength": 1 },
+            "password": { "type": "string", "minLength": 1 },
+        },
+        "required": ["username", "password"],
+    }));
+    schemas.insert("Token".to_string(), json!({
+        "type": "object",
+        "properties": {
+            "access_token": { "type": "string" },
+            "token_type": { "type": "string" },
+            "expires_in": { "type": "integer", "description": "Seconds until the token expires" },
+            "user_id": uuid(),
+        },
+        "required": ["access_token", "token_type", "expires_in", "user_id"],
+    }));
+
+    let login = operation(AUTH, "login", "Log in and obtain an access token", vec![
+        ("200", data_response("Access token", Some(schema_ref("Token")))),
+        ("400", error_response("Invalid request")),
+        ("401", error_response("Invalid credentials")),
+    ]);
+    add_operation(paths, "/api/auth/login", "post", with_body(login, json_body(schema_ref("LoginRequest"), true)));
+}
+
+fn add_user_paths(paths: &mut Map<String, Value>, schemas: &mut Map<String, Value>) {
+    schemas.insert("User".to_string(), json!({
+        "type": "object",
+        "properties": {
+            "id": uuid(),
+            "username": { "type": "string" },
+            "first_name": { "type": "string" },
+            "last_name": { "type": "string" },
+            "email": { "type": "string", "format": "email" },
+            "created_by": { "type": ["string", "null"], "format": "uuid" },
+            "created_at": { "type": "string", "format": "date-time" },
+            "updated_by": { "type": ["string", "null"], "format": "uuid" },
+            "updated_at": { "type": ["string", "null"], "format": "date-time" },
+            "status": { "type": "integer" },
+            "version": { "type": "integer", "description": "Same value as the ETag header" },
+        },
+        "required": ["id", "username", "first_name", "last_name", "email", "created_at", "status", "version"],
+    }));
+    schemas.insert("CreateUserRequest".to_string(), json!({
+        "type": "object",
+        "properties": {
+            "username": { "type": "string", "minLength": 3, "maxLength": 50 },
+            "first_name": { "type": "string", "minLength": 1, "maxLength": 100 },
+            "last_name": { "type": "string", "minLength": 1, "maxLength": 100 },
+            "email": { "type": "string", "format": "email" },
+            "password": { "type": "string", "minLength": 8 },
+        },
+        "required": ["username", "first_name", "last_name", "email", "password"],
+    }));
+    schemas.insert("UpdateUserRequest".to_string(), json!({
+        "type": "object",
+        "properties": {
+            "first_name": { "type": "string", "minLength": 3, "maxLength": 50 },
+            "last_name": { "type": "string", "minLength": 3, "maxLength": 50 },
+            "email": { "type": "string", "format": "email" },
+            "password": { "type": "string", "minLength": 8 },
+        },
+    }));
+
+    let id = || path_param("id", uuid(), "User ID");
+
+    let create = operation(USERS, "createUser", "Create a user", vec![
+        ("201", with_etag(data_response("Created user", Some(schema_ref("User"))))),
+        ("400", error_response("Invalid request")),
+        ("409", error_response("The username or email is already in use")),
+    ]);
+    add_operation(paths, "/api/users", "post", with_body(create, json_body(schema_ref("CreateUserRequest"), true)));
+
+    let list = operation(USERS, "listUsers", "List users", vec![
+        ("200", data_response("Users", Some(json!({ "type": "array", "items": schema_ref("User") })))),
+    ]);
+    add_operation(paths, "/api/users", "get", list);
+
+    let find = operation(USERS, "findUserById", "Get a user by ID", vec![
+        ("200", with_etag(data_response("User", Some(schema_ref("User"))))),
+        ("304", not_modified_response()),
+        ("404", error_response("User not found")),
+    ]);
+    add_operation(paths, "/api/users/{id}", "get", with_parameters(find, vec![id(), if_none_match_param()]));
+
+    let update = operation(USERS, "updateUser", "Update a user", vec![
+        ("200", with_etag(data_response("Updated user", Some(schema_ref("User"))))),
+        ("400", error_response("Invalid request")),
+        ("404", error_response("User not found")),
+        ("409", error_response("The email is already in use or the user was modified concurrently")),
+        ("412", precondition_failed_response()),
+    ]);
+    let update = with_body(with_parameters(update, vec![id(), if_match_param()]), json_body(schema_ref("UpdateUserRequest"), true));
+    add_operation(paths, "/api/users/{id}", "put", update);
+
+    let delete = operation(USERS, "deleteUser", "Move a user to the trash", vec![
+        ("200", data_response("User moved to the trash", None)),
+        ("404", error_response("User not found")),
+        ("412", precondition_failed_response()),
+    ]);
+    add_operation(paths, "/api/users/{id}", "delete", with_parameters(delete, vec![id(), if_match_param()]));
+
+    let deleted_user = json!({
+        "type": "object",
+        "properties": {
+            "id": uuid(),
+            "username": { "type": "string" },
+            "email": { "type": "string", "format": "email" },
+        },
+        "required": ["id", "username", "email"],
+    });
+    let list_trash = operation(USERS, "listDeletedUsers", "List users in the trash", vec![
+        ("200", data_response("Page of deleted users", Some(trash_page(trashed(deleted_user))))),
+        ("400", error_response("Invalid pagination parameters")),
+    ]);
+    add_operation(paths, "/api/users/trash", "get", with_parameters(list_trash, trash_page_parameters()));
+
+    let restore = operation(USERS, "restoreUser", "Restore a user from the trash", vec![
+        ("200", with_etag(data_response("Restored user", Some(schema_ref("User"))))),
+        ("404", error_response("User not in the trash")),
+    ]);
+    add_operation(paths, "/api/users/trash/{id}/restore", "post", with_parameters(restore, vec![id()]));
+
+    let purge = operation(USERS, "purgeUser", "Permanently delete a user from the trash", vec![
+        ("200", data_response("User permanently deleted", None)),
+        ("404", error_response("User not in the trash")),
+    ]);
+    add_operation(paths, "/api/users/trash/{id}", "delete", with_parameters(purge, vec![id()]));
+
+    let by_username = operation(USERS, "findUserByUsername", "Get a user by username", vec![
+        ("200", data_response("User", Some(schema_ref("User")))),
+        ("404", error_response("User not found")),
+    ]);
+    let username = path_param("username", json!({ "type": "string" }), "Username");
+    add_operation(paths, "/api/users/username/{username}", "get", with_parameters(by_username, vec![username]));
+}
+
+fn add_health_paths(paths: &mut Map<String, Value>, schemas: &mut Map<String, Value>) {
+    schemas.insert("Health".to_string(), json!({
+        "type": "object",
+        "properties": {
+            "status": { "enum": ["healthy", "degraded"] },
+            "timestamp": { "type": "string", "format": "date-time" },
+            "last_check_seconds_ago": { "type": "integer" },
+            "database_health": { "type": "object" },
+        },
+        "required": ["status", "timestamp"],
+    }));
+
+    // El estado de salud no se envuelve en ApiResponse
+    let health = |description: &str| json!({
+        "description": description,
+        "content": { "application/json": { "schema": schema_ref("Health") } },
+    });
+    let check = operation(HEALTH, "healthCheck", "Service and database health", vec![
+        ("200", health("All databases are healthy")),
+        ("503", health("One or more databases are unavailable")),
+    ]);
+    add_operation(paths, "/api/health", "get", check);
+}
+
+fn add_graphql_paths(paths: &mut Map<String, Value>, schemas: &mut Map<String, Value>) {
+    schemas.insert("GraphQLRequest".to_string(), json!({
+        "type": "object",
+        "properties": {
+            "query": { "type": "string", "minLength": 1 },
+            "operationName": nullable("string"),
+            "variables": { "type": ["object", "null"] },
+        },
+        "required": ["query"],
+    }));
+    schemas.insert("GraphQLResponse".to_string(), json!({
+        "type": "object",
+        "properties": {
+            "data": { "type": ["object", "null"] },
+            "errors": {
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "properties": {
+                        "message": { "type": "string" },
+                        "path": { "type": "array", "items": { "type": ["string", "integer"] } },
+                        "extensions": { "type": "object", "properties": { "code": { "type": "string" } } },
+                    },
+                    "required": ["message"],
+                },
+            },
+        },
+    }));
+
+    // La respuesta sigue el formato GraphQL, no se envuelve en ApiResponse
+    let execute = operation(GRAPHQL, "executeGraphQL", "Execute a GraphQL query or mutation", vec![
+        ("200", json!({
+            "description": "Operation result; query and field errors are reported in `errors`",
+            "content": { "application/json": { "schema": schema_ref("GraphQLResponse") } },
+        })),
+        ("400", error_response("Invalid request body")),
+    ]);
+    add_operation(paths, "/graphql", "post", with_body(execute, json_body(schema_ref("GraphQLRequest"), true)));
+}
+
+fn add_logical_entity_paths(paths: &mut Map<String, Value>, schemas: &mut Map<String, Value>) {
+    schemas.insert("AttributeOption".to_string(), json!({
+        "type": "object",
+        "properties": {
+            "code": { "type": "string" },
+            "label": { "type": "string" },
+            "sort_order": { "type": "integer" },
+            "is_active": { "type": "boolean" },
+        },
+        "required": ["code", "label", "sort_order", "is_active"],
+    }));
+    schemas.insert("AttributeReference".to_string(), json!({
+        "type": "object",
+        "properties": {
+            "entity_id": uuid(),
+            "on_delete": { "enum": ["restrict", "cascade", "set_null"] },
+            "display_attribute_id": { "type": ["string", "null"], "format": "uuid" },
+        },
+        "required": ["entity_id", "on_delete"],
+    }));
+    schemas.insert("Attribute".to_string(), json!({
+        "type": "object",
+        "properties": {
+            "id": uuid(),
+            "name": { "type": "string" },
+            "description": nullable("string"),
+            "data_type_id": uuid(),
+            "data_type_name": { "type": "string" },
+            "position": { "type": "integer" },
+            "is_required": { "type": "boolean" },
+            "is_unique": nullable("integer"),
+            "default_value": nullable("string"),
+            "validation_regex": nullable("string"),
+            "options": { "type": "array", "items": schema_ref("AttributeOption") },
+            "show_option_label": { "type": "boolean" },
+            "is_searchable": { "type": "boolean" },
+            "reference": schema_ref("AttributeReference"),
+            "status": { "type": "integer", "description": "1 = active, 0 = retired" },
+        },
+        "required": ["id", "name", "data_type_id", "data_type_name", "position", "is_required", "status"],
+    }));
+    schemas.insert("LogicalEntity".to_string(), json!({
+        "type": "object",
+        "properties": {
+            "id": uuid(),
+            "name": { "type": "string" },
+            "description": nullable("string"),
+            "assign_view": { "type": ["string", "null"], "description": "Name of the entity view, if any" },
+            "search_language": { "type": "string" },
+            "storage_mode": { "enum": ["eav", "physical"], "description": "eav: one attribute_values row per value; physical: a table of the entity with one typed column per attribute" },
+            "created_by": { "type": ["string", "null"], "format": "uuid" },
+            "created_at": { "type": "string", "format": "date-time" },
+            "updated_by": { "type": ["string", "null"], "format": "uuid" },
+            "updated_at": { "type": ["string", "null"], "format": "date-time" },
+            "status": { "type": "integer" },
+            "attributes": { "type": "array", "items": schema_ref("Attribute") },
+        },
+        "required": ["id", "name", "search_language", "storage_mode", "created_at", "status", "attributes"],
+    }));
+    schemas.insert("LogicalEntityPage".to_string(), json!({
+        "type": "object",
+        "properties": {
+            "items": { "type": "array", "items": schema_ref("LogicalEntity") },
+            "page": { "type": "integer" },
+            "page_size": { "type": "integer" },
+            "total": { "type": "integer" },
+        },
+        "required": ["items", "page", "page_size", "total"],
+    }));
+    // position, is_required, is_unique e is_searchable se reciben como cadenas
+    schemas.insert("AttributeDefinition".to_string(), json!({
+        "type": "object",
+        "properties": {
+            "name": sql_name(MAX_ATTRIBUTE_NAME_BYTES, "SQL reserved words and the view system columns (id, created_by, created_at, updated_by, updated_at, status) are rejected"),
+            "description": nullable("string"),
+            "data_type_id": { "type": "string", "minLength": 1, "description": "Data type name" },
+            "position": { "type": "string", "pattern": "^[0-9]+$" },
+            "is_required": { "enum": ["true", "false", "1", "0"] },
+            "is_unique": { "type": ["string", "null"], "pattern": "^([0-9]|10)?$" },
+            "default_value": nullable("string"),
+            "validation_regex": nullable("string"),
+            "is_searchable": { "enum": ["true", "false", "1", "0"] },
+            "reference": {
+                "type": "object",
+                "properties": {
+                    "entity_id": uuid(),
+                    "on_delete": { "enum": ["restrict", "cascade", "set_null"] },
+                    "display_attribute": { "type": "string" },
+                },
+                "required": ["entity_id"],
+            },
+        },
+        "required": ["name", "data_type_id"],
+    }));
+    schemas.insert("CreateLogicalEntityRequest".to_string(), json!({
+        "type": "object",
+        "properties": {
+            "Entity": sql_name(MAX_ENTITY_NAME_BYTES, "SQL reserved words are rejected"),
+            "Attribute": { "type": "array", "minItems": 1, "items": schema_ref("AttributeDefinition") },
+            "AssignView": { "type": "boolean" },
+        },
+        "required": ["Entity", "Attribute"],
+    }));
+    schemas.insert("UpdateLogicalEntityRequest".to_string(), json!({
+        "type": "object",
+        "properties": {
+            "name": sql_name(MAX_ENTITY_NAME_BYTES, "SQL reserved words are rejected"),
+            "description": { "type": "string" },
+            "assign_view": { "type": "boolean", "description": "true creates or refreshes the entity view, false drops it" },
+            "search_language": { "type": "string", "minLength": 1, "maxLength": 63 },
+            "status": { "enum": [0, 1] },
+        },
+    }));
+    schemas.insert("EntityView".to_string(), json!({
+        "type": "object",
+        "properties": {
+            "entity_id": uuid(),
+            "view_name": { "type": "string" },
+        },
+        "required": ["entity_id", "view_name"],
+    }));
+    schemas.insert("EntityViewStatus".to_string(), json!({
+        "type": "object",
+        "properties": {
+            "entity_id": uuid(),
+            "view_name": { "type": ["string", "null"], "description": "Name of the entity view, if any" },
+            "materialized": { "type": "boolean" },
+            "refresh_interval_secs": { "type": ["integer", "null"], "description": "Scheduled refresh interval (materialized views only)" },
+            "pivot_strategy": { "enum": ["joins", "aggregate", null], "description": "null = chosen automatically by attribute count" },
+            "refresh_status": { "enum": ["never", "refreshing", "ready", "failed"] },
+            "last_refresh_started_at": { "type": ["string", "null"], "format": "date-time" },
+            "last_refreshed_at": { "type": ["string", "null"], "format": "date-time", "description": "End of the last successful refresh" },
+            "last_refresh_duration_ms": nullable("integer"),
+            "last_refresh_error": nullable("string"),
+        },
+        "required": ["entity_id", "materialized", "refresh_status"],
+    }));
+    schemas.insert("UpdateEntityViewSettingsRequest".to_string(), json!({
+        "type": "object",
+        "properties": {
+            "materialized": { "type": "boolean", "description": "true generates a MATERIALIZED VIEW with a unique index on the record id" },
+            "refresh_interval_secs": { "type": ["integer", "null"], "minimum": 1, "maximum": 604800 },
+            "pivot_strategy": {
+                "enum": ["joins", "aggregate", null],
+                "description": "joins: one LEFT JOIN per attribute; aggregate: a single grouped scan of the values. Omitted or null chooses by attribute count",
+            },
+        },
+        "required": ["materialized"],
+    }));
+    schemas.insert("ChangeStorageModeRequest".to_string(), json!({
+        "type": "object",
+        "properties": {
+            "storage_mode": {
+                "enum": ["eav", "physical"],
+                "description": "physical creates the entity table (typed columns with NOT NULL, UNIQUE and CHECK constraints from the attributes) and moves the values into it; eav moves them back and drops the table",
+            },
+        },
+        "required": ["storage_mode"],
+    }));
+    schemas.insert("StorageModeChange".to_string(), json!({
+        "type": "object",
+        "properties": {
+            "entity_id": uuid(),
+            "storage_mode": { "enum": ["eav", "physical"] },
+            "migrated": { "type": "integer", "description": "Records (to physical) or values (to eav) copied; 0 if the entity was already in that mode" },
+        },
+        "required": ["entity_id", "storage_mode", "migrated"],
+    }));
+
+    let id = || path_param("id", uuid(), "Logical entity ID");
+
+    let create = operation(LOGICAL_ENTITIES, "createLogicalEntity", "Create a logical entity with its attributes", vec![
+        ("201", data_response("Created entity", Some(json!({ "type": "object", "properties": { "id": uuid() }, "required": ["id"] })))),
+        ("400", error_response("Invalid request")),
+        ("409", error_response("An entity with the same name already exists")),
+    ]);
+    add_operation(paths, "/api/logical-entities", "post", with_body(create, json_body(schema_ref("CreateLogicalEntityRequest"), true)));
+
+    let list = operation(LOGICAL_ENTITIES, "listLogicalEntities", "List logical entities", vec![
+        ("200", data_response("Page of entities", Some(schema_ref("LogicalEntityPage")))),
+        ("400", error_response("Invalid pagination parameters")),
+    ]);
+    let list = with_parameters(list, vec![
+        query_param("page", json!({ "type": "integer", "minimum": 1, "default": 1 }), "Page number"),
+        query_param("page_size", json!({ "type": "integer", "minimum": 1, "maximum": 100, "default": 20 }), "Page size"),
+    ]);
+    add_operation(paths, "/api/logical-entities", "get", list);
+
+    let find = operation(LOGICAL_ENTITIES, "findLogicalEntityById", "Get a logical entity by ID", vec![
+        ("200", data_response("Entity with its attributes", Some(schema_ref("LogicalEntity")))),
+        ("404", error_response("Entity not found")),
+    ]);
+    add_operation(paths, "/api/logical-entities/{id}", "get", with_parameters(find, vec![id()]));
+
+    let update = operation(LOGICAL_ENTITIES, "updateLogicalEntity", "Update a logical entity", vec![
+        ("200", data_response("Updated entity", Some(schema_ref("LogicalEntity")))),
+        ("400", error_response("Invalid request")),
+        ("404", error_response("Entity not found")),
+        ("409", error_response("An entity with the same name already exists")),
+    ]);
+    let update = with_body(with_parameters(update, vec![id()]), json_body(schema_ref("UpdateLogicalEntityRequest"), true));
+    add_operation(paths, "/api/logical-entities/{id}", "put", update);
+
+    let delete = operation(LOGICAL_ENTITIES, "deleteLogicalEntity", "Move a logical entity to the trash", vec![
+        ("200", data_response("Entity moved to the trash", None)),
+        ("404", error_response("Entity not found")),
+        ("409", error_response("The entity is still in use")),
+    ]);
+    add_operation(paths, "/api/logical-entities/{id}", "delete", with_parameters(delete, vec![id()]));
+
+    let list_trash = operation(LOGICAL_ENTITIES, "listDeletedLogicalEntities", "List logical entities in the trash", vec![
+        ("200", data_response("Page of deleted entities", Some(trash_page(trashed(schema_ref("LogicalEntity")))))),
+        ("400", error_response("Invalid pagination parameters")),
+    ]);
+    add_operation(paths, "/api/logical-entities/trash", "get", with_parameters(list_trash, trash_page_parameters()));
+
+    let restore = operation(LOGICAL_ENTITIES, "restoreLogicalEntity", "Restore a logical entity from the trash", vec![
+        ("200", data_response("Restored entity with its attributes", Some(schema_ref("LogicalEntity")))),
+        ("404", error_response("Entity not in the trash")),
+        ("409", error_response("Another entity already uses its name")),
+    ]);
+    add_operation(paths, "/api/logical-entities/trash/{id}/restore", "post", with_parameters(restore, vec![id()]));
+
+    let purge = operation(LOGICAL_ENTITIES, "purgeLogicalEntity", "Permanently delete a logical entity with its attributes and records", vec![
+        ("200", data_response("Entity permanently deleted", None)),
+        ("404", error_response("Entity not in the trash")),
+        ("409", error_response("Attributes of other entities still reference it")),
+    ]);
+    add_operation(paths, "/api/logical-entities/trash/{id}", "delete", with_parameters(purge, vec![id()]));
+
+    let by_name = operation(LOGICAL_ENTITIES, "findLogicalEntityByName", "Get a logical entity by name", vec![
+        ("200", data_response("Entity with its attributes", Some(schema_ref("LogicalEntity")))),
+        ("404", error_response("Entity not found")),
+    ]);
+    let name = path_param("name", json!({ "type": "string" }), "Logical entity name");
+    add_operation(paths, "/api/logical-entities/by-name/{name}", "get", with_parameters(by_name, vec![name]));
+
+    let view = operation(LOGICAL_ENTITIES, "refreshEntityView", "Regenerate the entity view from its current attributes", vec![
+        ("200", data_response("Regenerated view", Some(schema_ref("EntityView")))),
+        ("404", error_response("Entity not found")),
+    ]);
+    add_operation(paths, "/api/logical-entities/{id}/view", "post", with_parameters(view, vec![id()]));
+
+    let view_status = operation(LOGICAL_ENTITIES, "getEntityViewStatus", "View settings and refresh status of the entity view", vec![
+        ("200", data_response("View settings and last refresh", Some(schema_ref("EntityViewStatus")))),
+        ("404", error_response("Entity not found")),
+    ]);
+    add_operation(paths, "/api/logical-entities/{id}/view", "get", with_parameters(view_status, vec![id()]));
+
+    let view_settings = operation(LOGICAL_ENTITIES, "updateEntityViewSettings", "Choose a plain or materialized view and the pivot strategy; changing them recreates the view", vec![
+        ("200", data_response("Updated view settings", Some(schema_ref("EntityViewStatus")))),
+        ("400", error_response("Invalid settings")),
+        ("404", error_response("Entity not found")),
+    ]);
+    let view_settings = with_body(with_parameters(view_settings, vec![id()]), json_body(schema_ref("UpdateEntityViewSettingsRequest"), true));
+    add_operation(paths, "/api/logical-entities/{id}/view/settings", "put", view_settings);
+
+    let view_refresh = operation(LOGICAL_ENTITIES, "refreshMaterializedView", "Refresh the materialized view data (REFRESH MATERIALIZED VIEW CONCURRENTLY)", vec![
+        ("200", data_response("Refreshed view status", Some(schema_ref("EntityViewStatus")))),
+        ("404", error_response("Entity not found")),
+        ("409", error_response("The entity has no materialized view")),
+    ]);
+    add_operation(paths, "/api/logical-entities/{id}/view/refresh", "post", with_parameters(view_refresh, vec![id()]));
+
+    let storage = operation(LOGICAL_ENTITIES, "changeEntityStorageMode", "Move the entity records between EAV storage and a physical table in one transaction; record writes wait until it finishes", vec![
+        ("200", data_response("New storage mode", Some(schema_ref("StorageModeChange")))),
+        ("400", error_response("Invalid storage mode")),
+        ("404", error_response("Entity not found")),
+        ("409", error_response("The existing records do not satisfy the physical table constraints")),
+    ]);
+    let storage = with_body(with_parameters(storage, vec![id()]), json_body(schema_ref("ChangeStorageModeRequest"), true));
+    add_operation(paths, "/api/logical-entities/{id}/storage", "put", storage);
+
+    // El JSON Schema se devuelve sin envolver en ApiResponse
+    let json_schema = operation(LOGICAL_ENTITIES, "getEntityJsonSchema", "JSON Schema (draft 2020-12) of the entity records", vec![
+        ("200", json!({
+            "description": "JSON Schema document",
+            "content": { "application/schema+json": { "schema": { "type": "object" } } },
+        })),
+        ("404", error_response("Entity not found")),
+    ]);
+    add_operation(paths, "/api/logical-entities/{id}/json-schema", "get", with_parameters(json_schema, vec![id()]));
+}
+
+fn add_trash_paths(paths: &mut Map<String, Value>, schemas: &mut Map<String, Value>) {
+    schemas.insert("PurgeReport".to_string(), json!({
+        "type": "object",
+        "properties": {
+            "deleted_before": { "type": "string", "format": "date-time" },
+            "records": { "type": "integer" },
+            "logical_entities": { "type": "integer" },
+            "users": { "type": "integer" },
+            "skipped": {
+                "type": "array",
+                "description": "Items that could not be purged, e.g. records still referenced by live records",
+                "items": {
+                    "type": "object",
+                    "properties": {
+                        "kind": { "enum": ["record", "logical_entity", "user"] },
+                        "id": uuid(),
+                        "reason": { "type": "string" },
+                    },
+                    "required": ["kind", "id", "reason"],
+                },
+            },
+        },
+        "required": ["deleted_before", "records", "logical_entities", "users", "skipped"],
+    }));
+
+    let purge = operation(TRASH, "purgeTrash", "Permanently delete everything that has been in the trash for longer than the given age", vec![
+        ("200", data_response("Purge report", Some(schema_ref("PurgeReport")))),
+        ("400", error_response("Invalid age")),
+    ]);
+    let older_than_days = query_param(
+        "older_than_days",
+        json!({ "type": "integer", "minimum": 0, "maximum": 3650, "default": 0 }),
+        "Only purge items deleted more than this many days ago; 0 empties the trash",
+    );
+    add_operation(paths, "/api/trash", "delete", with_parameters(purge, vec![older_than_days]));
+}
@@ -1,12 +1,22 @@
 use actix_web::http::StatusCode;
 use serde::{Serialize, Deserialize};
 
+/// Detalle de un error de validación sobre un campo.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiFieldError {
+    pub field: String,
+    pub code: String,
+    pub message: String,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ApiError {
     #[serde(skip)]
     status_code: StatusCode,
     code: u16,  // Para serialización/deserialización
     message: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    fields: Vec<ApiFieldError>,
 }
 
 impl ApiError {
@@ -15,8 +25,15 @@ impl ApiError {
             status_code,
             code: status_code.as_u16(),
             message: message.to_string(),
+            fields: Vec::new(),
         }
     }
+
+    /// Adjunta los errores por campo (respuestas 400 de validación).
+    pub fn with_fields(mut self, fields: Vec<ApiFieldError>) -> Self {
+        self.fields = fields;
+        self
+    }
     
     pub fn status_code(&self) -> StatusCode {
         self.status_code
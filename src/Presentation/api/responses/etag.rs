@@ -0,0 +1,32 @@
+// Cabeceras de control de concurrencia optimista: ETag en las respuestas de usuarios
+// y registros, If-Match en las escrituras (412) e If-None-Match en las lecturas (304).
+use actix_web::http::header::{self, HeaderName};
+use actix_web::{HttpRequest, HttpResponse};
+
+use crate::Domain::concurrency::{entity_tag, if_none_match_matches, VersionPrecondition};
+
+/// Valor de una cabecera de lista (las repeticiones se unen con comas).
+fn header_list(req: &HttpRequest, name: HeaderName) -> Option<String> {
+    let values: Vec<&str> = req.headers()
+        .get_all(name)
+        .filter_map(|value| value.to_str().ok())
+        .collect();
+    if values.is_empty() { None } else { Some(values.join(",")) }
+}
+
+/// Cabecera ETag de una versión, para `HttpResponseBuilder::insert_header`.
+pub fn etag_header(version: i32) -> (HeaderName, String) {
+    (header::ETAG, entity_tag(version))
+}
+
+/// Precondición If-Match de la petición (None si no se envía).
+pub fn if_match(req: &HttpRequest) -> Option<VersionPrecondition> {
+    header_list(req, header::IF_MATCH).map(|value| VersionPrecondition::from_if_match(&value))
+}
+
+/// Respuesta 304 si el If-None-Match de la petición coincide con la versión actual.
+pub fn not_modified(req: &HttpRequest, version: i32) -> Option<HttpResponse> {
+    header_list(req, header::IF_NONE_MATCH)
+        .filter(|value| if_none_match_matches(value, version))
+        .map(|_| HttpResponse::NotModified().insert_header(etag_header(version)).finish())
+}
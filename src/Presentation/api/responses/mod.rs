@@ -1,6 +1,7 @@
 // Este módulo contiene las estructuras y funciones para las respuestas de la API.
 pub mod api_response;
 pub mod api_error;
+pub mod etag;
 
 pub use api_response::ApiResponse;
-pub use api_error::ApiError;
\ No newline at end of file
+pub use api_error::{ApiError, ApiFieldError};
\ No newline at end of file
@@ -1,5 +1,6 @@
 use actix_web::web;
-use crate::Presentation::api::controllers::{user_controller, auth_controller, health_controller, logical_entity_controller};
+use crate::Presentation::api::controllers::{user_controller, auth_controller, health_controller, logical_entity_controller, record_controller, attribute_controller, schema_version_controller, data_type_controller, schema_bundle_controller, docs_controller, graphql_controller, trash_controller};
+use crate::Infrastructure::config::app_config::AppConfig;
 use crate::Presentation::api::middleware::{request_logger::RequestLoggerMiddleware, error_handler::ErrorHandlerMiddleware, auth_middleware::AuthMiddleware};
 
 /// Configura las rutas de la API con middleware aplicado selectivamente.
@@ -17,6 +18,24 @@ pub fn config(cfg: &mut web::ServiceConfig) {
             .configure(user_controller::config)
     );
 
+    // Atributos y versiones de una entidad: se registran antes que /api/logical-entities,
+    // cuyo scope también coincidiría con estas rutas
+    cfg.service(
+        web::scope("/api/logical-entities/{entity_id}/attributes")
+            .wrap(RequestLoggerMiddleware)
+            .wrap(ErrorHandlerMiddleware)
+            //.wrap(auth_middleware.clone()) // PENDIENTE
+            .configure(attribute_controller::config)
+    );
+
+    cfg.service(
+        web::scope("/api/logical-entities/{entity_id}/versions")
+            .wrap(RequestLoggerMiddleware)
+            .wrap(ErrorHandlerMiddleware)
+            //.wrap(auth_middleware.clone()) // PENDIENTE
+            .configure(schema_version_controller::config)
+    );
+
     cfg.service(
         web::scope("/api/logical-entities") // Define el prefijo base
             .wrap(RequestLoggerMiddleware)
@@ -26,6 +45,46 @@ pub fn config(cfg: &mut web::ServiceConfig) {
             .configure(logical_entity_controller::config) // Delega al config del nuevo controlador
     );
 
+    cfg.service(
+        web::scope("/api/data-types") // Registro de tipos de datos
+            .wrap(RequestLoggerMiddleware)
+            .wrap(ErrorHandlerMiddleware)
+            //.wrap(auth_middleware.clone()) // PENDIENTE
+            .configure(data_type_controller::config)
+    );
+
+    cfg.service(
+        web::scope("/api/schema-bundles") // Exportación e importación de definiciones entre entornos
+            .wrap(RequestLoggerMiddleware)
+            .wrap(ErrorHandlerMiddleware)
+            //.wrap(auth_middleware.clone()) // PENDIENTE
+            .configure(schema_bundle_controller::config)
+    );
+
+    cfg.service(
+        web::scope("/api/entities") // Registros (tuplas) de cada entidad lógica
+            .wrap(RequestLoggerMiddleware)
+            .wrap(ErrorHandlerMiddleware)
+            //.wrap(auth_middleware.clone()) // PENDIENTE
+            .configure(record_controller::config)
+    );
+
+    cfg.service(
+        web::scope("/graphql") // Esquema GraphQL generado a partir de las entidades lógicas
+            .wrap(RequestLoggerMiddleware)
+            .wrap(ErrorHandlerMiddleware)
+            //.wrap(auth_middleware.clone()) // PENDIENTE
+            .configure(graphql_controller::config)
+    );
+
+    cfg.service(
+        web::scope("/api/trash") // Purga de la papelera (registros, entidades lógicas y usuarios)
+            .wrap(RequestLoggerMiddleware)
+            .wrap(ErrorHandlerMiddleware)
+            //.wrap(auth_middleware.clone()) // PENDIENTE
+            .configure(trash_controller::config)
+    );
+
     cfg.service(
         web::scope("/api/health")
             .wrap(RequestLoggerMiddleware)
@@ -41,4 +100,13 @@ pub fn config(cfg: &mut web::ServiceConfig) {
             .wrap(ErrorHandlerMiddleware)
             .configure(auth_controller::config)
     );
-}
\ No newline at end of file
+}
+/// Documentación de la API (documento OpenAPI y, opcionalmente, Swagger UI).
+/// Solo se registra con `enable_swagger`. Debe configurarse antes que `config`:
+/// el scope "/api" de login atendería también estas rutas.
+pub fn docs(cfg: &mut web::ServiceConfig, app_config: &AppConfig) {
+    if !app_config.enable_swagger {
+        return;
+    }
+    docs_controller::config(cfg, &app_config.openapi_path, app_config.swagger_ui_path.as_deref());
+}
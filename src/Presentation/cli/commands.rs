@@ -0,0 +1,339 @@
+// src/Presentation/cli/commands.rs
+//
+// Comandos de línea de comandos. Sin subcomando, el binario arranca la API.
+//
+//   anyb import customers ./customers.csv --dry-run --on-error skip_invalid --map "Full Name=name"
+//   anyb bundle export customer invoice --output ./model.yaml
+//   anyb bundle plan ./model.yaml
+//   anyb bundle apply ./model.yaml --prune
+
+use clap::{Arg, ArgAction, ArgMatches, Command};
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
+use uuid::Uuid;
+use log::{info, error};
+
+use crate::Container::app_state::AppState;
+use crate::Application::dtos::record_dto::ImportRecordsDto;
+use crate::Application::dtos::schema_bundle_dto::ImportSchemaBundleDto;
+use crate::Application::errors::application_error::ApplicationError;
+use crate::Domain::record_imports::{ColumnMapping, ImportErrorPolicy, ImportFormat, ImportReader};
+use crate::Domain::record_history::ChangeContext;
+use crate::Domain::schema_bundles::{BundleFormat, BundlePlan, PlanAction, PlanTarget, SchemaBundle};
+use crate::Presentation::api::controllers::logical_entity_controller::placeholder_user_id;
+
+/// Argumentos de `anyb import`.
+#[derive(Debug, Clone)]
+pub struct ImportArgs {
+    pub entity: String,
+    pub file: PathBuf,
+    /// Si no se indica, se deduce de la extensión del archivo.
+    pub format: Option<String>,
+    pub delimiter: char,
+    pub dry_run: bool,
+    pub on_error: String,
+    /// Pares "columna=atributo" (atributo vacío = se ignora la columna).
+    pub mappings: Vec<String>,
+    pub user: Option<String>,
+}
+
+/// Operación de `anyb bundle`.
+#[derive(Debug, Clone)]
+pub enum BundleAction {
+    /// Escribe el bundle de las entidades indicadas (todas si no se indica ninguna).
+    Export { entities: Vec<String>, output: Option<PathBuf> },
+    /// Muestra el plan de cambios del bundle sin aplicarlo.
+    Plan { file: PathBuf },
+    /// Muestra el plan y lo aplica. `prune` retira los atributos que no están en el bundle.
+    Apply { file: PathBuf, prune: bool },
+}
+
+/// Argumentos de `anyb bundle`.
+#[derive(Debug, Clone)]
+pub struct BundleArgs {
+    pub action: BundleAction,
+    /// Si no se indica, se deduce de la extensión del archivo (json por defecto al exportar a stdout).
+    pub format: Option<String>,
+    pub user: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub enum CliCommand {
+    /// Importa registros de un archivo CSV o NDJSON.
+    Import(ImportArgs),
+    /// Exporta o importa definiciones de entidades entre entornos.
+    Bundle(BundleArgs),
+}
+
+fn cli() -> Command {
+    Command::new("anyb")
+        .about("Servicio de metadatos EAV. Sin subcomando arranca la API HTTP.")
+        .subcommand(
+            Command::new("import")
+                .about("Importa registros de una entidad desde un archivo CSV o NDJSON")
+                .arg(Arg::new("entity").required(true).help("Nombre de la entidad lógica"))
+                .arg(Arg::new("file").required(true).help("Ruta del archivo (UTF-8)"))
+                .arg(Arg::new("format").long("format").help("csv o ndjson (por defecto, según la extensión)"))
+                .arg(Arg::new("delimiter").long("delimiter").default_value(",").help("Separador del CSV: ',', ';' o '\\t'"))
+                .arg(Arg::new("dry-run").long("dry-run").action(ArgAction::SetTrue).help("Solo valida; no inserta registros"))
+                .arg(
+                    Arg::new("on-error")
+                        .long("on-error")
+                        .default_value("all_or_nothing")
+                        .value_parser(["all_or_nothing", "skip_invalid"])
+                        .help("Política ante filas inválidas"),
+                )
+                .arg(
+                    Arg::new("map")
+                        .long("map")
+                        .action(ArgAction::Append)
+                        .value_name("COLUMNA=ATRIBUTO")
+                        .help("Asigna una columna del archivo a un atributo (repetible)"),
+                )
+                .arg(Arg::new("user").long("user").value_name("UUID").help("Usuario que figura como creador de los registros")),
+        )
+        .subcommand(
+            Command::new("bundle")
+                .about("Exporta o importa definiciones de entidades (bundle JSON/YAML) entre entornos")
+                .subcommand_required(true)
+                .arg(Arg::new("format").long("format").global(true).help("json o yaml (por defecto, según la extensión)"))
+                .arg(Arg::new("user").long("user").global(true).value_name("UUID").help("Usuario que figura como autor de los cambios"))
+                .subcommand(
+                    Command::new("export")
+                        .about("Exporta entidades, atributos y los tipos de datos que usan")
+                        .arg(Arg::new("entities").num_args(0..).help("Entidades a exportar (todas si no se indica ninguna)"))
+                        .arg(Arg::new("output").long("output").short('o').help("Archivo de salida (por defecto, la salida estándar)")),
+                )
+                .subcommand(
+                    Command::new("plan")
+                        .about("Muestra los cambios que aplicaría el bundle, sin aplicarlos")
+                        .arg(Arg::new("file").required(true).help("Ruta del bundle")),
+                )
+                .subcommand(
+                    Command::new("apply")
+                        .about("Muestra el plan del bundle y lo aplica")
+                        .arg(Arg::new("file").required(true).help("Ruta del bundle"))
+                        .arg(Arg::new("prune").long("prune").action(ArgAction::SetTrue).help("Retira los atributos que no están en el bundle")),
+                ),
+        )
+}
+
+/// Interpreta los argumentos del proceso. `None` = arrancar la API.
+pub fn parse_args() -> Option<CliCommand> {
+    let matches = cli().get_matches();
+    match matches.subcommand() {
+        Some(("import", args)) => Some(CliCommand::Import(import_args(args))),
+        Some(("bundle", args)) => bundle_args(args).map(CliCommand::Bundle),
+        _ => None,
+    }
+}
+
+fn bundle_args(args: &ArgMatches) -> Option<BundleArgs> {
+    let file = |args: &ArgMatches| PathBuf::from(args.get_one::<String>("file").cloned().unwrap_or_default());
+    let action = match args.subcommand()? {
+        ("export", sub) => BundleAction::Export {
+            entities: sub.get_many::<String>("entities").map(|values| values.cloned().collect()).unwrap_or_default(),
+            output: sub.get_one::<String>("output").map(PathBuf::from),
+        },
+        ("plan", sub) => BundleAction::Plan { file: file(sub) },
+        ("apply", sub) => BundleAction::Apply { file: file(sub), prune: sub.get_flag("prune") },
+        _ => return None,
+    };
+    Some(BundleArgs {
+        action,
+        format: args.get_one::<String>("format").cloned(),
+        user: args.get_one::<String>("user").cloned(),
+    })
+}
+
+fn import_args(args: &ArgMatches) -> ImportArgs {
+    let delimiter = match args.get_one::<String>("delimiter").map(String::as_str) {
+        Some("\\t") | Some("tab") => '\t',
+        Some(other) => other.chars().next().unwrap_or(','),
+        None => ',',
+    };
+    ImportArgs {
+        entity: args.get_one::<String>("entity").cloned().unwrap_or_default(),
+        file: PathBuf::from(args.get_one::<String>("file").cloned().unwrap_or_default()),
+        format: args.get_one::<String>("format").cloned(),
+        delimiter,
+        dry_run: args.get_flag("dry-run"),
+        on_error: args.get_one::<String>("on-error").cloned().unwrap_or_else(|| "all_or_nothing".to_string()),
+        mappings: args.get_many::<String>("map").map(|values| values.cloned().collect()).unwrap_or_default(),
+        user: args.get_one::<String>("user").cloned(),
+    }
+}
+
+/// Ejecuta el comando y devuelve el código de salida del proceso: 0 sin errores,
+/// 1 si alguna fila tiene errores (o el plan del bundle tiene conflictos), 2 si el comando falla.
+pub async fn execute_command(command: CliCommand, app_state: &AppState) -> i32 {
+    let (operation, result) = match command {
+        CliCommand::Import(args) => ("importar registros", run_import(args, app_state).await),
+        CliCommand::Bundle(args) => ("procesar el bundle", run_bundle(args, app_state).await),
+    };
+    match result {
+        Ok(code) => code,
+        Err(e) => {
+            error!("Error al {}: {:?}", operation, e);
+            eprintln!("Error: {}", e);
+            2
+        },
+    }
+}
+
+async fn run_import(args: ImportArgs, app_state: &AppState) -> Result<i32, ApplicationError> {
+    let invalid = |message: String| ApplicationError::ValidationError(message);
+
+    let format = match &args.format {
+        Some(name) => ImportFormat::from_name(name),
+        None => format_from_extension(&args.file),
+    }
+    .map_err(|e| invalid(e.to_string()))?;
+    let on_error = ImportErrorPolicy::from_name(&args.on_error).map_err(|e| invalid(e.to_string()))?;
+    let created_by = match &args.user {
+        Some(user) => Uuid::parse_str(user).map_err(|_| invalid(format!("--user '{}' no es un UUID válido", user)))?,
+        None => placeholder_user_id(), // <--- ¡USA EL user_id REAL AQUÍ!
+    };
+
+    let mut mapping = HashMap::new();
+    for pair in &args.mappings {
+        // El atributo no contiene '=': se divide por el último
+        let (column, attribute) = pair
+            .rsplit_once('=')
+            .ok_or_else(|| invalid(format!("--map '{}' debe tener la forma COLUMNA=ATRIBUTO", pair)))?;
+        mapping.insert(column.to_string(), attribute.to_string());
+    }
+
+    let mapping = ColumnMapping::new(mapping).map_err(|e| invalid(e.to_string()))?;
+    let mut reader = ImportReader::new(format, args.delimiter, mapping.clone()).map_err(|e| invalid(e.to_string()))?;
+    let read_error = |e: std::io::Error| invalid(format!("No se pudo leer '{}': {}", args.file.display(), e));
+    let file = std::fs::File::open(&args.file).map_err(read_error)?;
+    info!("Importando '{}' en la entidad '{}'", args.file.display(), args.entity);
+    // El archivo se lee línea a línea, como el cuerpo de POST .../records/import
+    for line in BufReader::new(file).lines() {
+        reader.push_line(&line.map_err(read_error)?).map_err(|e| invalid(e.to_string()))?;
+    }
+    let (rows, issues) = reader.finish().map_err(|e| invalid(e.to_string()))?;
+
+    let import = ImportRecordsDto {
+        format,
+        rows,
+        issues,
+        mapping,
+        dry_run: args.dry_run,
+        on_error,
+        context: ChangeContext::new(created_by, None),
+    };
+    let report = app_state.record_controller_data.import_records_use_case
+        .execute(&args.entity, import)
+        .await?;
+
+    let output = serde_json::to_string_pretty(&report)
+        .map_err(|e| ApplicationError::UnexpectedError(format!("Error al serializar el informe: {}", e)))?;
+    println!("{}", output);
+    Ok(if report.errors.is_empty() { 0 } else { 1 })
+}
+
+fn format_from_extension(file: &Path) -> crate::Domain::errors::DomainResult<ImportFormat> {
+    let extension = file.extension().and_then(|e| e.to_str()).unwrap_or_default();
+    ImportFormat::from_name(extension).map_err(|_| {
+        crate::Domain::errors::DomainError::ValidationError(format!(
+            "no se puede deducir el formato de '{}': indique --format csv|ndjson", file.display()
+        ))
+    })
+}
+
+async fn run_bundle(args: BundleArgs, app_state: &AppState) -> Result<i32, ApplicationError> {
+    let invalid = |message: String| ApplicationError::ValidationError(message);
+    let format = |file: Option<&Path>| -> Result<BundleFormat, ApplicationError> {
+        match (&args.format, file) {
+            (Some(name), _) => BundleFormat::from_name(name),
+            (None, Some(file)) => BundleFormat::from_name(file.extension().and_then(|e| e.to_str()).unwrap_or_default())
+                .map_err(|_| crate::Domain::errors::DomainError::ValidationError(format!(
+                    "no se puede deducir el formato de '{}': indique --format json|yaml", file.display()
+                ))),
+            (None, None) => Ok(BundleFormat::Json),
+        }
+        .map_err(|e| invalid(e.to_string()))
+    };
+    let controller = &app_state.schema_bundle_controller_data;
+
+    let (file, dry_run, prune) = match &args.action {
+        BundleAction::Export { entities, output } => {
+            let format = format(output.as_deref())?;
+            let bundle = controller.export_schema_bundle_use_case.execute(entities.clone()).await?;
+            let content = bundle.render(format).map_err(|e| ApplicationError::UnexpectedError(e.to_string()))?;
+            match output {
+                Some(path) => {
+                    std::fs::write(path, content)
+                        .map_err(|e| invalid(format!("No se pudo escribir '{}': {}", path.display(), e)))?;
+                    info!("Bundle con {} entidades escrito en '{}'", bundle.entities.len(), path.display());
+                },
+                None => print!("{}", content),
+            }
+            return Ok(0);
+        },
+        BundleAction::Plan { file } => (file, true, false),
+        BundleAction::Apply { file, prune } => (file, false, *prune),
+    };
+
+    let updated_by = match &args.user {
+        Some(user) => Uuid::parse_str(user).map_err(|_| invalid(format!("--user '{}' no es un UUID válido", user)))?,
+        None => placeholder_user_id(), // <--- ¡USA EL user_id REAL AQUÍ!
+    };
+    let content = std::fs::read_to_string(file)
+        .map_err(|e| invalid(format!("No se pudo leer '{}': {}", file.display(), e)))?;
+    let bundle = SchemaBundle::parse(&content, format(Some(file.as_path()))?).map_err(|e| invalid(e.to_string()))?;
+
+    let report = controller.import_schema_bundle_use_case
+        .execute(ImportSchemaBundleDto { bundle, dry_run, prune, updated_by })
+        .await?;
+    print_plan(&report.plan, prune);
+    if !report.plan.conflicts.is_empty() {
+        println!("El bundle no se ha aplicado: resuelva los conflictos.");
+        return Ok(1);
+    }
+    if report.applied {
+        println!("Cambios aplicados.");
+    } else if !report.plan.is_empty() {
+        println!("Ejecute 'anyb bundle apply {}' para aplicar los cambios.", file.display());
+    }
+    Ok(0)
+}
+
+/// Plan legible: `+` alta, `~` cambio (con el valor anterior y el nuevo), `-` eliminación.
+fn print_plan(plan: &BundlePlan, prune: bool) {
+    if plan.is_empty() && plan.conflicts.is_empty() {
+        println!("Sin cambios: el destino ya coincide con el bundle.");
+        return;
+    }
+    for step in &plan.steps {
+        let symbol = match step.action {
+            PlanAction::Add => '+',
+            PlanAction::Change => '~',
+            PlanAction::Remove => '-',
+        };
+        let target = match step.target {
+            PlanTarget::DataType => "tipo de dato",
+            PlanTarget::Entity => "entidad",
+            PlanTarget::Attribute => "atributo",
+        };
+        let name = match &step.entity {
+            Some(entity) => format!("{}.{}", entity, step.name),
+            None => step.name.clone(),
+        };
+        let note = if step.action == PlanAction::Remove && !prune { " (solo se retira con --prune)" } else { "" };
+        println!("{} {} {}{}", symbol, target, name, note);
+        for change in &step.changes {
+            println!("    {}: {} -> {}", change.field, change.from, change.to);
+        }
+    }
+    for conflict in &plan.conflicts {
+        println!("! conflicto: {}", conflict);
+    }
+    println!(
+        "Plan: {} altas, {} cambios, {} eliminaciones, {} conflictos.",
+        plan.count(PlanAction::Add), plan.count(PlanAction::Change), plan.count(PlanAction::Remove), plan.conflicts.len()
+    );
+}
@@ -1,3 +1,8 @@
+// Interfaz de línea de comandos (subcomandos del binario).
+pub mod commands;
+
+pub use commands::{parse_args, execute_command, CliCommand, ImportArgs, BundleArgs, BundleAction};
+
 /* 
 
 // Este módulo contiene el código para la interfaz de línea de comandos.
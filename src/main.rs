@@ -11,6 +11,8 @@ async fn main() -> std::io::Result<()> {
     // --- 1. Cargar Configuración ---
     // Ventaja: Necesaria antes que nada para configurar logger, pools, etc.
     dotenv().ok();
+    // Subcomando de línea de comandos (p. ej. `anyb import ...`); sin subcomando se arranca la API
+    let cli_command = anyb::Presentation::cli::parse_args();
     let config = anyb::Infrastructure::config::app_config::get_config(); // Usar PascalCase como en lib.rs
 
     // --- 2. Inicializar Logger ---
@@ -65,10 +67,38 @@ async fn main() -> std::io::Result<()> {
         }
     };
 
+    // --- 5b. Ejecutar el comando de CLI (si se indicó) en lugar del servidor ---
+    if let Some(command) = cli_command {
+        let exit_code = anyb::Presentation::cli::execute_command(command, &app_state).await;
+        std::process::exit(exit_code);
+    }
+
+    // --- 5c. Purga programada de la papelera (solo con TRASH_RETENTION_DAYS) ---
+    if let Some(retention_days) = config.trash_retention_days {
+        let purge_job = anyb::Infrastructure::jobs::TrashPurgeJob::new(
+            app_state.trash_controller_data.purge_trash_use_case.clone(),
+            retention_days,
+            config.trash_purge_interval_secs,
+        );
+        purge_job.start();
+    }
+
+    // --- 5d. Refresco automático de vistas materializadas ---
+    let view_refresh_job = anyb::Infrastructure::jobs::ViewRefreshJob::new(
+        app_state.logical_entity_controller_data.refresh_materialized_view_use_case.clone(),
+        config.view_refresh_check_interval_secs,
+        config.view_refresh_debounce_secs,
+    );
+    view_refresh_job.start();
+
     // --- 6. Preparar Datos para el Servidor ---
     // Ventaja: Clonamos lo necesario ANTES de mover al closure del servidor.
     let server_config = config.clone(); // Clonar config si se usa en el closure
     let app_state_for_server = app_state.clone(); // Clonar AppState para mover
+    let docs_config = server_config.clone(); // Configuración de la documentación para cada worker
+    if server_config.enable_swagger {
+        info!("Documentación de la API en {} (Swagger UI: {:?})", server_config.openapi_path, server_config.swagger_ui_path);
+    }
 
     // --- 7. Iniciar Servidor HTTP ---
     // Ventaja: El servidor solo se inicia si todos los pasos anteriores fueron exitosos.
@@ -76,12 +106,14 @@ async fn main() -> std::io::Result<()> {
     HttpServer::new(move || {
             // El closure 'move' toma posesión de app_state_for_server
             let app_state_clone = app_state_for_server.clone(); // Clonar el Arc para cada worker
+            let docs_config = docs_config.clone();
 
             App::new()
                 .wrap(Logger::default())
                 .app_data(web::Data::new(app_state_clone)) // Pasar el AppState completo
+                // Documentación (OpenAPI/Swagger UI) según ENABLE_SWAGGER; antes que las rutas de la API
+                .configure(move |cfg| anyb::Presentation::api::routes::docs(cfg, &docs_config))
                 .configure(anyb::Presentation::api::routes::config) // Configurar rutas
-                // ... (swagger opcional) ...
             })
     .bind((server_config.http_host.clone(), server_config.http_port))?
     .workers(4) // Ajustar según necesidad